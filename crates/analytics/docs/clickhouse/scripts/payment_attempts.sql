@@ -44,11 +44,14 @@ CREATE TABLE payment_attempt_queue (
     `profile_id` String,
     `card_network` Nullable(String),
     `routing_approach` LowCardinality(Nullable(String)),
+    `routing_algorithm_id` Nullable(String),
+    `expected_processing_fee` Nullable(UInt32),
     `debit_routing_savings` Nullable(UInt32),
     `signature_network` Nullable(String),
     `is_issuer_regulated` Nullable(Bool),
     `processor_merchant_id` Nullable(String),
     `created_by` Nullable(String),
+    `attempts_group_id` Nullable(String),
     `sign_flag` Int8
 ) ENGINE = Kafka SETTINGS kafka_broker_list = 'kafka0:29092',
 kafka_topic_list = 'hyperswitch-payment-attempt-events',
@@ -103,11 +106,15 @@ CREATE TABLE payment_attempts (
     `profile_id` String,
     `card_network` Nullable(String),
     `routing_approach` LowCardinality(Nullable(String)),
+    `routing_algorithm_id` Nullable(String),
+    `expected_processing_fee` Nullable(UInt32),
     `debit_routing_savings` Nullable(UInt32),
     `signature_network` Nullable(String),
     `is_issuer_regulated` Nullable(Bool),
     `processor_merchant_id` Nullable(String),
     `created_by` Nullable(String),
+    `attempts_group_id` Nullable(String),
+    `is_split_payment` Bool ALIAS isNotNull(attempts_group_id),
     `sign_flag` Int8,
     INDEX connectorIndex connector TYPE bloom_filter GRANULARITY 1,
     INDEX paymentMethodIndex payment_method TYPE bloom_filter GRANULARITY 1,
@@ -165,11 +172,14 @@ CREATE MATERIALIZED VIEW payment_attempt_mv TO payment_attempts (
     `profile_id` String,
     `card_network` Nullable(String),
     `routing_approach` LowCardinality(Nullable(String)),
+    `routing_algorithm_id` Nullable(String),
+    `expected_processing_fee` Nullable(UInt32),
     `debit_routing_savings` Nullable(UInt32),
     `signature_network` Nullable(String),
     `is_issuer_regulated` Nullable(Bool),
     `processor_merchant_id` Nullable(String),
     `created_by` Nullable(String),
+    `attempts_group_id` Nullable(String),
     `sign_flag` Int8
 ) AS
 SELECT
@@ -219,11 +229,14 @@ SELECT
     profile_id,
     card_network,
     routing_approach,
+    routing_algorithm_id,
+    expected_processing_fee,
     debit_routing_savings,
     signature_network,
     is_issuer_regulated,
     processor_merchant_id,
     created_by,
+    attempts_group_id,
     sign_flag
 FROM
     payment_attempt_queue
@@ -29,9 +29,11 @@ use crate::{
         metrics::{latency::LatencyAvg, ApiEventMetricRow},
     },
     auth_events::filters::AuthEventFilterRow,
+    connector_error_codes::ConnectorErrorCodeRow,
     connector_events::events::ConnectorEventsResult,
     disputes::{filters::DisputeFilterRow, metrics::DisputeMetricRow},
     outgoing_webhook_event::events::OutgoingWebhookLogsResult,
+    reports::{ReportField, ReportRow},
     routing_events::events::RoutingEventsResult,
     sdk_events::events::SdkEventsResult,
     types::TableEngine,
@@ -201,6 +203,8 @@ impl super::outgoing_webhook_event::events::OutgoingWebhookLogsFilterAnalytics
 }
 impl super::disputes::filters::DisputeFilterAnalytics for ClickhouseClient {}
 impl super::disputes::metrics::DisputeMetricAnalytics for ClickhouseClient {}
+impl super::reports::ReportAnalytics for ClickhouseClient {}
+impl super::connector_error_codes::ConnectorErrorCodeAnalytics for ClickhouseClient {}
 
 #[derive(Debug, serde::Serialize)]
 struct CkhQuery {
@@ -463,6 +467,49 @@ impl TryInto<ActivePaymentsMetricRow> for serde_json::Value {
     }
 }
 
+impl TryInto<ConnectorErrorCodeRow> for serde_json::Value {
+    type Error = Report<ParsingError>;
+
+    fn try_into(self) -> Result<ConnectorErrorCodeRow, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse ConnectorErrorCodeRow in clickhouse results",
+        ))
+    }
+}
+
+impl TryInto<ReportRow> for serde_json::Value {
+    type Error = Report<ParsingError>;
+
+    fn try_into(self) -> Result<ReportRow, Self::Error> {
+        let serde_json::Value::Object(values) = self else {
+            return Err(report!(ParsingError::StructParseFailure(
+                "Failed to parse ReportRow in clickhouse results",
+            )));
+        };
+
+        Ok(ReportRow(
+            values
+                .into_iter()
+                .map(|(column, value)| {
+                    let value = match value {
+                        serde_json::Value::Null => String::new(),
+                        serde_json::Value::String(value) => value,
+                        value => value.to_string(),
+                    };
+                    (column, value)
+                })
+                .collect(),
+        ))
+    }
+}
+
+// ClickHouse results are returned as JSON, so the columns are rendered as text while loading the rows
+impl ToSql<ClickhouseClient> for ReportField {
+    fn to_sql(&self, _table_engine: &TableEngine) -> error_stack::Result<String, ParsingError> {
+        Ok(self.0.to_owned())
+    }
+}
+
 impl ToSql<ClickhouseClient> for PrimitiveDateTime {
     fn to_sql(&self, _table_engine: &TableEngine) -> error_stack::Result<String, ParsingError> {
         Ok(self.assume_utc().unix_timestamp().to_string())
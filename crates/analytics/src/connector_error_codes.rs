@@ -0,0 +1,153 @@
+use api_models::analytics::{Granularity, TimeRange};
+use error_stack::ResultExt;
+use time::PrimitiveDateTime;
+
+use crate::{
+    errors::{AnalyticsError, AnalyticsResult},
+    query::{
+        Aggregate, FilterTypes, GroupByClause, Order, QueryBuilder, QueryFilter, ToSql, Window,
+    },
+    types::{AnalyticsCollection, AnalyticsDataSource, LoadRow},
+};
+
+/// The number of payment attempts which failed with a connector error code and message.
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ConnectorErrorCodeRow {
+    pub connector: Option<String>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub count: Option<i64>,
+}
+
+pub trait ConnectorErrorCodeAnalytics: LoadRow<ConnectorErrorCodeRow> {}
+
+/// Counts the payment attempts created in the time range across all merchants, for every
+/// combination of connector, error code and error message, most frequent first. Only the
+/// attempts of the connector are counted when a connector is specified.
+pub async fn load_connector_error_codes<T>(
+    time_range: &TimeRange,
+    connector: Option<&str>,
+    pool: &T,
+) -> AnalyticsResult<Vec<ConnectorErrorCodeRow>>
+where
+    T: AnalyticsDataSource + ConnectorErrorCodeAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    build_query::<T>(time_range, connector)?
+        .execute_query::<ConnectorErrorCodeRow, _>(pool)
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Error building connector error code query")?
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Error loading connector error codes")
+}
+
+fn build_query<T>(
+    time_range: &TimeRange,
+    connector: Option<&str>,
+) -> AnalyticsResult<QueryBuilder<T>>
+where
+    T: AnalyticsDataSource,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    let mut query_builder: QueryBuilder<T> = QueryBuilder::new(AnalyticsCollection::Payment);
+
+    for column in ["connector", "error_code", "error_message"] {
+        query_builder
+            .add_select_column(column)
+            .change_context(AnalyticsError::UnknownError)?;
+        query_builder
+            .add_group_by_clause(column)
+            .change_context(AnalyticsError::UnknownError)?;
+    }
+
+    // On collapsing tables the count is the sum of the sign of the rows, so that the rows
+    // cancelling out a previous state of an attempt are not counted as attempts of their own
+    query_builder
+        .add_select_column(Aggregate::Count {
+            field: None,
+            alias: Some("count"),
+        })
+        .change_context(AnalyticsError::UnknownError)?;
+
+    query_builder
+        .add_custom_filter_clause("error_code", "NULL", FilterTypes::IsNotNull)
+        .change_context(AnalyticsError::UnknownError)?;
+
+    if let Some(connector) = connector {
+        query_builder
+            .add_filter_clause("connector", connector)
+            .change_context(AnalyticsError::UnknownError)?;
+    }
+
+    time_range
+        .set_filter_clause(&mut query_builder)
+        .attach_printable("Error filtering time range")
+        .change_context(AnalyticsError::UnknownError)?;
+
+    query_builder
+        .add_order_by_clause("count", Order::Descending)
+        .change_context(AnalyticsError::UnknownError)?;
+
+    Ok(query_builder)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+    use crate::{clickhouse::ClickhouseClient, sqlx::SqlxClient};
+
+    fn time_range() -> TimeRange {
+        TimeRange {
+            start_time: common_utils::date_time::now(),
+            end_time: None,
+        }
+    }
+
+    fn build_sql<T>(connector: Option<&str>) -> String
+    where
+        T: AnalyticsDataSource,
+        PrimitiveDateTime: ToSql<T>,
+        AnalyticsCollection: ToSql<T>,
+        Granularity: GroupByClause<T>,
+        Aggregate<&'static str>: ToSql<T>,
+        Window<&'static str>: ToSql<T>,
+    {
+        build_query::<T>(&time_range(), connector)
+            .unwrap()
+            .build_query()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_clickhouse_query_collapses_rows() {
+        let sql = build_sql::<ClickhouseClient>(None);
+
+        assert!(sql.contains("sum(sign_flag) as count"));
+        assert!(sql.contains(" HAVING sum(sign_flag) >= '1'"));
+    }
+
+    #[test]
+    fn test_sqlx_query_counts_rows() {
+        let sql = build_sql::<SqlxClient>(None);
+
+        assert!(sql.contains("count(*) as count"));
+        assert!(!sql.contains("sign_flag"));
+    }
+
+    #[test]
+    fn test_query_filters_connector() {
+        assert!(build_sql::<ClickhouseClient>(Some("stripe")).contains("connector = 'stripe'"));
+        assert!(!build_sql::<ClickhouseClient>(None).contains("connector = "));
+    }
+}
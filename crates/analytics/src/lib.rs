@@ -2,6 +2,7 @@ pub mod active_payments;
 pub mod api_event;
 pub mod auth_events;
 mod clickhouse;
+pub mod connector_error_codes;
 pub mod connector_events;
 pub mod core;
 pub mod disputes;
@@ -16,6 +17,8 @@ pub mod payment_intents;
 pub mod payments;
 mod query;
 pub mod refunds;
+pub mod reports;
+pub mod routing_backtest;
 pub mod routing_events;
 pub mod sdk_events;
 pub mod search;
@@ -989,6 +992,63 @@ impl AnalyticsProvider {
         }
     }
 
+    /// Generates a report as a CSV document, sending it to the receiver of `pages` a page at a
+    /// time. Combined providers read from their primary source.
+    #[instrument(skip_all)]
+    pub async fn generate_report(
+        &self,
+        kind: reports::ReportKind,
+        request: &api_models::analytics::ReportRequest,
+        auth: &AuthInfo,
+        pages: tokio::sync::mpsc::Sender<Vec<u8>>,
+    ) -> errors::AnalyticsResult<()> {
+        match self {
+            Self::Sqlx(pool) | Self::CombinedSqlx(pool, _) => {
+                reports::generate_report(kind, request, auth, pool, pages).await
+            }
+            Self::Clickhouse(pool) | Self::CombinedCkh(_, pool) => {
+                reports::generate_report(kind, request, auth, pool, pages).await
+            }
+        }
+    }
+
+    /// Loads the payment attempts replayed by a routing backtest. Combined providers read from
+    /// their primary source.
+    #[instrument(skip_all)]
+    pub async fn get_routing_backtest_attempts(
+        &self,
+        time_range: &TimeRange,
+        auth: &AuthInfo,
+    ) -> errors::AnalyticsResult<Vec<routing_backtest::BacktestPaymentAttempt>> {
+        match self {
+            Self::Sqlx(pool) | Self::CombinedSqlx(pool, _) => {
+                routing_backtest::load_payment_attempts(time_range, auth, pool).await
+            }
+            Self::Clickhouse(pool) | Self::CombinedCkh(_, pool) => {
+                routing_backtest::load_payment_attempts(time_range, auth, pool).await
+            }
+        }
+    }
+
+    /// Loads the frequency of connector error codes seen in payment attempts across all
+    /// merchants, optionally of a single connector. Combined providers read from their primary
+    /// source.
+    #[instrument(skip_all)]
+    pub async fn get_connector_error_codes(
+        &self,
+        time_range: &TimeRange,
+        connector: Option<&str>,
+    ) -> errors::AnalyticsResult<Vec<connector_error_codes::ConnectorErrorCodeRow>> {
+        match self {
+            Self::Sqlx(pool) | Self::CombinedSqlx(pool, _) => {
+                connector_error_codes::load_connector_error_codes(time_range, connector, pool).await
+            }
+            Self::Clickhouse(pool) | Self::CombinedCkh(_, pool) => {
+                connector_error_codes::load_connector_error_codes(time_range, connector, pool).await
+            }
+        }
+    }
+
     pub async fn from_conf(config: &AnalyticsConfig, tenant: &dyn TenantConfig) -> Self {
         match config {
             AnalyticsConfig::Sqlx { sqlx, .. } => {
@@ -1141,6 +1201,10 @@ pub struct ReportConfig {
     pub payout_function: String,
     pub relay_function: String,
     pub region: String,
+    /// Whether payment, refund and dispute reports are generated by the lambda functions or by
+    /// the scheduler
+    #[serde(default)]
+    pub backend: reports::ReportBackend,
 }
 
 /// Analytics Flow routes Enums
@@ -1172,6 +1236,7 @@ pub enum AnalyticsFlow {
     GenerateAuthenticationReport,
     GeneratePayoutReport,
     GenerateRelayReport,
+    DownloadReport,
     GetApiEventMetrics,
     GetApiEventFilters,
     GetConnectorEvents,
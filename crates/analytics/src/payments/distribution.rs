@@ -41,6 +41,8 @@ pub struct PaymentDistributionRow {
     pub signature_network: Option<String>,
     pub is_issuer_regulated: Option<bool>,
     pub is_debit_routed: Option<bool>,
+    pub routing_algorithm_id: Option<String>,
+    pub is_split_payment: Option<bool>,
     #[serde(with = "common_utils::custom_serde::iso8601::option")]
     pub start_bucket: Option<PrimitiveDateTime>,
     #[serde(with = "common_utils::custom_serde::iso8601::option")]
@@ -69,4 +69,6 @@ pub struct PaymentFilterRow {
     pub signature_network: Option<String>,
     pub is_issuer_regulated: Option<bool>,
     pub is_debit_routed: Option<bool>,
+    pub routing_algorithm_id: Option<String>,
+    pub is_split_payment: Option<bool>,
 }
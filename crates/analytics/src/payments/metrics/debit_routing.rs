@@ -132,6 +132,8 @@ where
                         i.signature_network.clone(),
                         i.is_issuer_regulated,
                         i.is_debit_routed,
+                        i.routing_algorithm_id.clone(),
+                        i.is_split_payment,
                         TimeRange {
                             start_time: match (granularity, i.start_bucket) {
                                 (Some(g), Some(st)) => g.clip_to_start(st)?,
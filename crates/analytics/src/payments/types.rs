@@ -143,6 +143,24 @@ where
                 .attach_printable("Error adding is debit routed filter")?;
         }
 
+        if !self.routing_algorithm_id.is_empty() {
+            builder
+                .add_filter_in_range_clause(
+                    PaymentDimensions::RoutingAlgorithmId,
+                    &self.routing_algorithm_id,
+                )
+                .attach_printable("Error adding routing algorithm id filter")?;
+        }
+
+        if !self.is_split_payment.is_empty() {
+            builder
+                .add_filter_in_range_clause(
+                    PaymentDimensions::IsSplitPayment,
+                    &self.is_split_payment,
+                )
+                .attach_printable("Error adding is split payment filter")?;
+        }
+
         Ok(())
     }
 }
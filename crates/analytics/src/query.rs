@@ -350,6 +350,18 @@ impl fmt::Display for LimitByClause {
     }
 }
 
+#[derive(Debug, Clone, Copy)]
+pub struct LimitClause {
+    limit: u64,
+    offset: u64,
+}
+
+impl fmt::Display for LimitClause {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "LIMIT {} OFFSET {}", self.limit, self.offset)
+    }
+}
+
 #[derive(Debug, Default, Clone, Copy)]
 pub enum FilterCombinator {
     #[default]
@@ -412,6 +424,7 @@ where
     order_by: Vec<String>,
     having: Option<Vec<(String, FilterTypes, String)>>,
     limit_by: Option<LimitByClause>,
+    limit: Option<LimitClause>,
     outer_select: Vec<String>,
     top_n: Option<TopN>,
     table: AnalyticsCollection,
@@ -591,6 +604,7 @@ where
             order_by: Default::default(),
             having: Default::default(),
             limit_by: Default::default(),
+            limit: Default::default(),
             outer_select: Default::default(),
             top_n: Default::default(),
             table,
@@ -767,6 +781,10 @@ where
         Ok(())
     }
 
+    pub fn set_limit(&mut self, limit: u64, offset: u64) {
+        self.limit = Some(LimitClause { limit, offset });
+    }
+
     pub fn add_granularity_in_mins(&mut self, granularity: Granularity) -> QueryResult<()> {
         let interval = match granularity {
             Granularity::OneMin => "1",
@@ -909,6 +927,10 @@ where
             query.push_str(&format!(" {limit_by}"));
         }
 
+        if let Some(limit) = &self.limit {
+            query.push_str(&format!(" {limit}"));
+        }
+
         if !self.outer_select.is_empty() {
             query.insert_str(
                 0,
@@ -0,0 +1,713 @@
+use std::collections::{HashMap, HashSet};
+
+use api_models::analytics::{
+    payments::PaymentReportColumn, Granularity, ReportColumns, ReportRequest, TimeRange,
+};
+use error_stack::{report, ResultExt};
+use strum::IntoEnumIterator;
+use time::PrimitiveDateTime;
+use tokio::sync::mpsc;
+
+use crate::{
+    enums::AuthInfo,
+    errors::{AnalyticsError, AnalyticsResult},
+    query::{Aggregate, GroupByClause, Order, QueryBuilder, QueryFilter, ToSql, Window},
+    types::{AnalyticsCollection, AnalyticsDataSource, LoadRow, TableEngine},
+};
+
+/// The kinds of reports that can be generated without handing the request off to a lambda.
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, strum::Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ReportKind {
+    Payment,
+    Refund,
+    Dispute,
+}
+
+/// Where report generation requests are processed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReportBackend {
+    /// Reports are generated by the configured AWS Lambda functions
+    #[default]
+    Lambda,
+    /// Reports are generated by the scheduler from the configured analytics provider
+    Native,
+}
+
+/// A report row, mapping each selected column to its value rendered as text.
+/// `NULL` values are rendered as empty strings.
+#[derive(Debug, Default, Clone)]
+pub struct ReportRow(pub HashMap<String, String>);
+
+impl ReportRow {
+    pub fn get(&self, column: &str) -> &str {
+        self.0.get(column).map(String::as_str).unwrap_or_default()
+    }
+}
+
+/// A column selected into a report, rendered as text by the data source.
+#[derive(Debug, Clone, Copy)]
+pub struct ReportField(pub &'static str);
+
+pub trait ReportAnalytics: LoadRow<ReportRow> {}
+
+/// Number of rows loaded from the analytics provider at a time. Reports are sent out a page at a
+/// time, so that only a single page of rows is held in memory.
+pub(crate) const REPORT_PAGE_SIZE: u64 = 10_000;
+
+/// Maximum number of payment ids in a single lookup of the payment intents joined into a
+/// payment report, which keeps the query within the size limits of the data sources.
+const PAYMENT_INTENT_LOOKUP_SIZE: usize = 500;
+
+const REFUND_REPORT_COLUMNS: &[&str] = &[
+    "refund_id",
+    "payment_id",
+    "attempt_id",
+    "connector",
+    "connector_transaction_id",
+    "connector_refund_id",
+    "refund_type",
+    "total_amount",
+    "refund_amount",
+    "currency",
+    "refund_status",
+    "refund_reason",
+    "refund_error_code",
+    "refund_error_message",
+    "profile_id",
+    "created_at",
+    "modified_at",
+];
+
+const DISPUTE_REPORT_COLUMNS: &[&str] = &[
+    "dispute_id",
+    "payment_id",
+    "attempt_id",
+    "connector",
+    "connector_dispute_id",
+    "dispute_amount",
+    "currency",
+    "dispute_stage",
+    "dispute_status",
+    "connector_status",
+    "connector_reason",
+    "connector_reason_code",
+    "challenge_required_by",
+    "profile_id",
+    "created_at",
+    "modified_at",
+];
+
+/// The table a payment report column is read from.
+enum PaymentColumnSource {
+    Attempt(&'static str),
+    Intent(&'static str),
+    /// Columns which are not stored by every analytics provider are left empty
+    Unavailable,
+}
+
+fn get_payment_column_source(column: PaymentReportColumn) -> PaymentColumnSource {
+    use PaymentColumnSource::{Attempt, Intent, Unavailable};
+
+    match column {
+        PaymentReportColumn::PaymentId => Attempt("payment_id"),
+        PaymentReportColumn::AttemptId => Attempt("attempt_id"),
+        PaymentReportColumn::Status => Attempt("status"),
+        PaymentReportColumn::Amount => Attempt("amount"),
+        PaymentReportColumn::Currency => Attempt("currency"),
+        PaymentReportColumn::Connector => Attempt("connector"),
+        PaymentReportColumn::ConnectorTransactionId => Attempt("connector_transaction_id"),
+        PaymentReportColumn::AmountToCapture => Attempt("amount_to_capture"),
+        PaymentReportColumn::CreatedAt => Attempt("created_at"),
+        PaymentReportColumn::ErrorMessage => Attempt("error_message"),
+        PaymentReportColumn::CaptureMethod => Attempt("capture_method"),
+        PaymentReportColumn::AuthenticationType => Attempt("authentication_type"),
+        PaymentReportColumn::MandateId => Attempt("mandate_id"),
+        PaymentReportColumn::PaymentMethod => Attempt("payment_method"),
+        PaymentReportColumn::PaymentMethodType => Attempt("payment_method_type"),
+        PaymentReportColumn::PaymentMethodData => Attempt("payment_method_data"),
+        PaymentReportColumn::CardNetwork => Attempt("card_network"),
+        PaymentReportColumn::ModifiedAt => Attempt("modified_at"),
+        PaymentReportColumn::ErrorCode => Attempt("error_code"),
+        PaymentReportColumn::PaymentMethodId => Attempt("payment_method_id"),
+        PaymentReportColumn::ProfileId => Attempt("profile_id"),
+        PaymentReportColumn::CustomerId => Intent("customer_id"),
+        PaymentReportColumn::SetupFutureUsage => Intent("setup_future_usage"),
+        PaymentReportColumn::StatementDescriptorName => Intent("statement_descriptor_name"),
+        PaymentReportColumn::Description => Intent("description"),
+        PaymentReportColumn::OffSession => Intent("off_session"),
+        PaymentReportColumn::BusinessCountry => Intent("business_country"),
+        PaymentReportColumn::BusinessLabel => Intent("business_label"),
+        PaymentReportColumn::OrderDetails
+        | PaymentReportColumn::Metadata
+        | PaymentReportColumn::BusinessSubLabel
+        | PaymentReportColumn::AllowedPaymentMethodTypes
+        | PaymentReportColumn::FingerprintId
+        | PaymentReportColumn::CardHolderName
+        | PaymentReportColumn::MerchantOrderReferenceId => Unavailable,
+    }
+}
+
+/// Generates the report described by `request` as a CSV document, which is sent to the receiver
+/// of `pages` a page at a time. The sender is dropped once the whole report has been sent, the
+/// report is incomplete if an error is returned instead.
+pub async fn generate_report<T>(
+    kind: ReportKind,
+    request: &ReportRequest,
+    auth: &AuthInfo,
+    pool: &T,
+    pages: mpsc::Sender<Vec<u8>>,
+) -> AnalyticsResult<()>
+where
+    T: AnalyticsDataSource + ReportAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+    ReportField: ToSql<T>,
+{
+    let (collection, columns) = match kind {
+        ReportKind::Payment => return generate_payment_report(request, auth, pool, pages).await,
+        ReportKind::Refund => (AnalyticsCollection::Refund, REFUND_REPORT_COLUMNS),
+        ReportKind::Dispute => (AnalyticsCollection::Dispute, DISPUTE_REPORT_COLUMNS),
+    };
+
+    let query = ReportRowsQuery {
+        collection,
+        columns,
+        auth,
+        time_range: Some(&request.time_range),
+        payment_ids: None,
+    };
+    let mut writer = ReportWriter::new(columns, pages)?;
+    let mut offset = Some(0);
+
+    while let Some(page_offset) = offset {
+        let rows = query.load_page(page_offset, pool).await?;
+        writer.write_rows(
+            rows.iter()
+                .map(|row| columns.iter().map(|column| row.get(column)).collect()),
+        )?;
+        writer.send_page().await?;
+        offset = next_page_offset(page_offset, rows.len());
+    }
+
+    Ok(())
+}
+
+/// Payment reports are built from the payment attempts in the time range, with the columns
+/// which are only stored on the payment intent joined in by `payment_id`.
+async fn generate_payment_report<T>(
+    request: &ReportRequest,
+    auth: &AuthInfo,
+    pool: &T,
+    pages: mpsc::Sender<Vec<u8>>,
+) -> AnalyticsResult<()>
+where
+    T: AnalyticsDataSource + ReportAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+    ReportField: ToSql<T>,
+{
+    let columns = match &request.columns {
+        Some(ReportColumns::Payment(columns)) if !columns.is_empty() => columns.clone(),
+        _ => PaymentReportColumn::iter().collect(),
+    };
+    let (attempt_columns, intent_columns) = get_payment_report_columns(&columns);
+
+    let attempt_query = ReportRowsQuery {
+        collection: AnalyticsCollection::Payment,
+        columns: &attempt_columns,
+        auth,
+        time_range: Some(&request.time_range),
+        payment_ids: None,
+    };
+    let header = columns
+        .iter()
+        .map(|column| column.as_ref())
+        .collect::<Vec<_>>();
+    let mut writer = ReportWriter::new(&header, pages)?;
+    let mut offset = Some(0);
+
+    while let Some(page_offset) = offset {
+        let attempts = attempt_query.load_page(page_offset, pool).await?;
+        let intents = if intent_columns.len() > 1 {
+            load_payment_intents(&intent_columns, &attempts, auth, pool).await?
+        } else {
+            HashMap::new()
+        };
+
+        writer.write_rows(
+            attempts
+                .iter()
+                .map(|attempt| get_payment_report_record(&columns, attempt, &intents)),
+        )?;
+        writer.send_page().await?;
+        offset = next_page_offset(page_offset, attempts.len());
+    }
+
+    Ok(())
+}
+
+/// Splits the columns of a payment report into the columns read from the payment attempts and
+/// the columns read from the payment intents, both of which include the `payment_id` they are
+/// joined on.
+fn get_payment_report_columns(
+    columns: &[PaymentReportColumn],
+) -> (Vec<&'static str>, Vec<&'static str>) {
+    let mut attempt_columns = vec!["payment_id", "created_at"];
+    let mut intent_columns = vec!["payment_id"];
+    for column in columns {
+        match get_payment_column_source(*column) {
+            PaymentColumnSource::Attempt(name) if !attempt_columns.contains(&name) => {
+                attempt_columns.push(name)
+            }
+            PaymentColumnSource::Intent(name) if !intent_columns.contains(&name) => {
+                intent_columns.push(name)
+            }
+            PaymentColumnSource::Attempt(_)
+            | PaymentColumnSource::Intent(_)
+            | PaymentColumnSource::Unavailable => {}
+        }
+    }
+
+    (attempt_columns, intent_columns)
+}
+
+fn get_payment_report_record<'a>(
+    columns: &[PaymentReportColumn],
+    attempt: &'a ReportRow,
+    intents: &'a HashMap<String, ReportRow>,
+) -> Vec<&'a str> {
+    let intent = intents.get(attempt.get("payment_id"));
+
+    columns
+        .iter()
+        .map(|column| match get_payment_column_source(*column) {
+            PaymentColumnSource::Attempt(name) => attempt.get(name),
+            PaymentColumnSource::Intent(name) => {
+                intent.map(|intent| intent.get(name)).unwrap_or("")
+            }
+            PaymentColumnSource::Unavailable => "",
+        })
+        .collect()
+}
+
+/// Loads the payment intents of the given payment attempts, keyed by `payment_id`.
+async fn load_payment_intents<T>(
+    intent_columns: &[&'static str],
+    attempts: &[ReportRow],
+    auth: &AuthInfo,
+    pool: &T,
+) -> AnalyticsResult<HashMap<String, ReportRow>>
+where
+    T: AnalyticsDataSource + ReportAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+    ReportField: ToSql<T>,
+{
+    let mut seen_payment_ids = HashSet::new();
+    let payment_ids = attempts
+        .iter()
+        .map(|attempt| attempt.get("payment_id"))
+        .filter(|payment_id| seen_payment_ids.insert(*payment_id))
+        .collect::<Vec<_>>();
+
+    let mut intents = HashMap::new();
+    for payment_ids in payment_ids.chunks(PAYMENT_INTENT_LOOKUP_SIZE) {
+        // The intents of a payment may have been created before the time range of the report,
+        // so they are looked up by the payment ids alone
+        let query = ReportRowsQuery {
+            collection: AnalyticsCollection::PaymentIntent,
+            columns: intent_columns,
+            auth,
+            time_range: None,
+            payment_ids: Some(payment_ids),
+        };
+
+        intents.extend(
+            query
+                .load_page(0, pool)
+                .await?
+                .into_iter()
+                .map(|row| (row.get("payment_id").to_owned(), row)),
+        );
+    }
+
+    Ok(intents)
+}
+
+/// Returns the offset of the page following one that returned `page_len` rows, or `None` if it
+/// was the last page.
+pub(crate) fn next_page_offset(offset: u64, page_len: usize) -> Option<u64> {
+    let page_len = u64::try_from(page_len).unwrap_or(u64::MAX);
+    (page_len >= REPORT_PAGE_SIZE).then(|| offset.saturating_add(page_len))
+}
+
+/// The rows of a collection selected into a report.
+pub(crate) struct ReportRowsQuery<'a> {
+    pub collection: AnalyticsCollection,
+    pub columns: &'a [&'static str],
+    pub auth: &'a AuthInfo,
+    pub time_range: Option<&'a TimeRange>,
+    /// Restricts the rows to the given payments
+    pub payment_ids: Option<&'a [&'a str]>,
+}
+
+impl ReportRowsQuery<'_> {
+    /// Loads the page of at most [`REPORT_PAGE_SIZE`] rows starting at `offset`.
+    pub(crate) async fn load_page<T>(
+        &self,
+        offset: u64,
+        pool: &T,
+    ) -> AnalyticsResult<Vec<ReportRow>>
+    where
+        T: AnalyticsDataSource + ReportAnalytics,
+        PrimitiveDateTime: ToSql<T>,
+        AnalyticsCollection: ToSql<T>,
+        Granularity: GroupByClause<T>,
+        Aggregate<&'static str>: ToSql<T>,
+        Window<&'static str>: ToSql<T>,
+        ReportField: ToSql<T>,
+    {
+        self.build_query(offset)?
+            .execute_query::<ReportRow, _>(pool)
+            .await
+            .change_context(AnalyticsError::UnknownError)
+            .attach_printable("Error building report query")?
+            .change_context(AnalyticsError::UnknownError)
+            .attach_printable_lazy(|| format!("Error loading {} report rows", self.collection))
+    }
+
+    fn build_query<T>(&self, offset: u64) -> AnalyticsResult<QueryBuilder<T>>
+    where
+        T: AnalyticsDataSource,
+        PrimitiveDateTime: ToSql<T>,
+        AnalyticsCollection: ToSql<T>,
+        Granularity: GroupByClause<T>,
+        ReportField: ToSql<T>,
+    {
+        let mut query_builder: QueryBuilder<T> = QueryBuilder::new(self.collection);
+
+        for column in self.columns {
+            query_builder
+                .add_select_column(ReportField(column))
+                .change_context(AnalyticsError::UnknownError)?;
+        }
+
+        if let Some(time_range) = self.time_range {
+            time_range
+                .set_filter_clause(&mut query_builder)
+                .attach_printable("Error filtering time range")
+                .change_context(AnalyticsError::UnknownError)?;
+        }
+
+        if let Some(payment_ids) = self.payment_ids {
+            query_builder
+                .add_filter_in_range_clause("payment_id", payment_ids)
+                .change_context(AnalyticsError::UnknownError)?;
+        }
+
+        self.auth
+            .set_filter_clause(&mut query_builder)
+            .change_context(AnalyticsError::UnknownError)?;
+
+        // Collapsing tables hold a row per update, with the previous state of a record cancelled
+        // out by a row with a negative sign. Grouping on every selected column makes the query
+        // builder keep only the groups with `sum(sign) >= 1`, which is the latest state of each
+        // record.
+        if matches!(
+            T::get_table_engine(self.collection),
+            TableEngine::CollapsingMergeTree { .. }
+        ) {
+            for column in self.columns {
+                query_builder
+                    .add_group_by_clause(*column)
+                    .change_context(AnalyticsError::UnknownError)?;
+            }
+        }
+
+        // Pages are only stable if the rows are in a total order, so every selected column is
+        // used to break ties between rows created at the same time
+        let order_columns = self
+            .columns
+            .iter()
+            .filter(|column| **column == "created_at")
+            .chain(
+                self.columns
+                    .iter()
+                    .filter(|column| **column != "created_at"),
+            );
+        for column in order_columns {
+            query_builder
+                .add_order_by_clause(*column, Order::Ascending)
+                .change_context(AnalyticsError::UnknownError)?;
+        }
+
+        query_builder.set_limit(REPORT_PAGE_SIZE, offset);
+
+        Ok(query_builder)
+    }
+}
+
+/// Writes the rows of a report as CSV as they are loaded, the rows written are sent out as a
+/// page of the report whenever [`ReportWriter::send_page`] is called.
+struct ReportWriter {
+    writer: csv::Writer<Vec<u8>>,
+    pages: mpsc::Sender<Vec<u8>>,
+}
+
+impl ReportWriter {
+    fn new(header: &[&str], pages: mpsc::Sender<Vec<u8>>) -> AnalyticsResult<Self> {
+        let mut writer = csv::Writer::from_writer(Vec::new());
+
+        writer
+            .write_record(header)
+            .change_context(AnalyticsError::UnknownError)
+            .attach_printable("Failed to write report header")?;
+
+        Ok(Self { writer, pages })
+    }
+
+    fn write_rows<'a>(
+        &mut self,
+        records: impl Iterator<Item = Vec<&'a str>>,
+    ) -> AnalyticsResult<()> {
+        for record in records {
+            self.writer
+                .write_record(record)
+                .change_context(AnalyticsError::UnknownError)
+                .attach_printable("Failed to write report row")?;
+        }
+
+        Ok(())
+    }
+
+    /// Sends the rows written since the previous page was sent, waiting for the receiver if it
+    /// has not yet consumed the previous pages.
+    async fn send_page(&mut self) -> AnalyticsResult<()> {
+        self.writer
+            .flush()
+            .change_context(AnalyticsError::UnknownError)
+            .attach_printable("Failed to flush report page")?;
+        let page = std::mem::take(self.writer.get_mut());
+        if page.is_empty() {
+            return Ok(());
+        }
+
+        self.pages
+            .send(page)
+            .await
+            .map_err(|_| report!(AnalyticsError::UnknownError))
+            .attach_printable("Report receiver was dropped before the report was sent")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+
+    use common_utils::id_type;
+
+    use super::*;
+    use crate::{clickhouse::ClickhouseClient, sqlx::SqlxClient};
+
+    fn org_auth() -> AuthInfo {
+        AuthInfo::OrgLevel {
+            org_id: id_type::OrganizationId::try_from_string("org_1".to_string()).unwrap(),
+        }
+    }
+
+    fn build_query<T>(query: &ReportRowsQuery<'_>, offset: u64) -> String
+    where
+        T: AnalyticsDataSource,
+        PrimitiveDateTime: ToSql<T>,
+        AnalyticsCollection: ToSql<T>,
+        Granularity: GroupByClause<T>,
+        Aggregate<&'static str>: ToSql<T>,
+        Window<&'static str>: ToSql<T>,
+        ReportField: ToSql<T>,
+    {
+        query
+            .build_query::<T>(offset)
+            .unwrap()
+            .build_query()
+            .unwrap()
+    }
+
+    fn report_row(values: &[(&str, &str)]) -> ReportRow {
+        ReportRow(
+            values
+                .iter()
+                .map(|(column, value)| (column.to_string(), value.to_string()))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_clickhouse_report_query_collapses_rows() {
+        let auth = org_auth();
+        let query = ReportRowsQuery {
+            collection: AnalyticsCollection::Refund,
+            columns: &["refund_id", "refund_status", "created_at"],
+            auth: &auth,
+            time_range: None,
+            payment_ids: None,
+        };
+
+        let sql = build_query::<ClickhouseClient>(&query, 0);
+
+        assert!(sql.contains(" GROUP BY refund_id, refund_status, created_at"));
+        assert!(sql.contains(" HAVING sum(sign_flag) >= '1'"));
+    }
+
+    #[test]
+    fn test_report_query_pages_in_a_total_order() {
+        let auth = org_auth();
+        let query = ReportRowsQuery {
+            collection: AnalyticsCollection::Refund,
+            columns: &["refund_id", "refund_status", "created_at"],
+            auth: &auth,
+            time_range: None,
+            payment_ids: None,
+        };
+
+        let sql = build_query::<ClickhouseClient>(&query, 2 * REPORT_PAGE_SIZE);
+
+        assert!(sql.contains(" ORDER BY created_at asc, refund_id asc, refund_status asc"));
+        assert!(sql.ends_with(&format!(
+            " LIMIT {REPORT_PAGE_SIZE} OFFSET {}",
+            2 * REPORT_PAGE_SIZE
+        )));
+    }
+
+    #[test]
+    fn test_sqlx_report_query_is_not_grouped() {
+        let auth = org_auth();
+        let query = ReportRowsQuery {
+            collection: AnalyticsCollection::Refund,
+            columns: &["refund_id", "created_at"],
+            auth: &auth,
+            time_range: None,
+            payment_ids: None,
+        };
+
+        let sql = build_query::<SqlxClient>(&query, 0);
+
+        assert!(sql.starts_with(
+            "SELECT CAST(refund_id AS TEXT) AS refund_id, CAST(created_at AS TEXT) AS created_at"
+        ));
+        assert!(!sql.contains("GROUP BY"));
+        assert!(!sql.contains("HAVING"));
+        assert!(sql.ends_with(&format!(" LIMIT {REPORT_PAGE_SIZE} OFFSET 0")));
+    }
+
+    #[test]
+    fn test_report_query_filters_by_payment_ids() {
+        let auth = org_auth();
+        let query = ReportRowsQuery {
+            collection: AnalyticsCollection::PaymentIntent,
+            columns: &["payment_id", "customer_id"],
+            auth: &auth,
+            time_range: None,
+            payment_ids: Some(&["pay_1", "pay_2"]),
+        };
+
+        let sql = build_query::<ClickhouseClient>(&query, 0);
+
+        assert!(sql.contains("payment_id IN ('pay_1', 'pay_2')"));
+        assert!(sql.contains("organization_id = 'org_1'"));
+        assert!(!sql.contains("created_at >="));
+    }
+
+    #[test]
+    fn test_next_page_offset() {
+        let page_size = usize::try_from(REPORT_PAGE_SIZE).unwrap();
+
+        assert_eq!(next_page_offset(0, 0), None);
+        assert_eq!(next_page_offset(0, page_size - 1), None);
+        assert_eq!(next_page_offset(0, page_size), Some(REPORT_PAGE_SIZE));
+        assert_eq!(
+            next_page_offset(REPORT_PAGE_SIZE, page_size),
+            Some(2 * REPORT_PAGE_SIZE)
+        );
+    }
+
+    #[test]
+    fn test_payment_report_columns_are_split_by_source() {
+        let (attempt_columns, intent_columns) = get_payment_report_columns(&[
+            PaymentReportColumn::PaymentId,
+            PaymentReportColumn::Status,
+            PaymentReportColumn::CustomerId,
+            PaymentReportColumn::Metadata,
+            PaymentReportColumn::Status,
+        ]);
+
+        assert_eq!(attempt_columns, vec!["payment_id", "created_at", "status"]);
+        assert_eq!(intent_columns, vec!["payment_id", "customer_id"]);
+    }
+
+    #[test]
+    fn test_payment_report_record_joins_intent_columns() {
+        let columns = [
+            PaymentReportColumn::PaymentId,
+            PaymentReportColumn::Status,
+            PaymentReportColumn::CustomerId,
+            PaymentReportColumn::Metadata,
+        ];
+        let intents = HashMap::from([(
+            "pay_1".to_string(),
+            report_row(&[("payment_id", "pay_1"), ("customer_id", "cus_1")]),
+        )]);
+
+        let attempt = report_row(&[("payment_id", "pay_1"), ("status", "charged")]);
+        assert_eq!(
+            get_payment_report_record(&columns, &attempt, &intents),
+            vec!["pay_1", "charged", "cus_1", ""]
+        );
+
+        let attempt = report_row(&[("payment_id", "pay_2"), ("status", "failure")]);
+        assert_eq!(
+            get_payment_report_record(&columns, &attempt, &intents),
+            vec!["pay_2", "failure", "", ""]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_report_writer_sends_a_page_at_a_time() {
+        let (sender, mut receiver) = mpsc::channel(4);
+        let mut writer = ReportWriter::new(&["id", "description"], sender).unwrap();
+        writer.write_rows([vec!["1", "plain"]].into_iter()).unwrap();
+        writer.send_page().await.unwrap();
+        writer
+            .write_rows([vec!["2", "with, comma"], vec!["3", ""]].into_iter())
+            .unwrap();
+        writer.send_page().await.unwrap();
+        // Empty pages are not sent
+        writer.send_page().await.unwrap();
+        drop(writer);
+
+        let mut pages = Vec::new();
+        while let Some(page) = receiver.recv().await {
+            pages.push(String::from_utf8(page).unwrap());
+        }
+        assert_eq!(
+            pages,
+            vec![
+                "id,description\n1,plain\n".to_string(),
+                "2,\"with, comma\"\n3,\n".to_string(),
+            ]
+        );
+    }
+}
@@ -0,0 +1,191 @@
+use std::{collections::HashSet, str::FromStr};
+
+use api_models::{
+    analytics::{Granularity, TimeRange},
+    enums::{
+        AttemptStatus, AuthenticationType, CaptureMethod, CardNetwork, Currency, PaymentMethod,
+        PaymentMethodType,
+    },
+};
+use common_utils::types::MinorUnit;
+use time::{format_description::well_known::Iso8601, PrimitiveDateTime};
+
+use crate::{
+    enums::AuthInfo,
+    errors::AnalyticsResult,
+    query::{Aggregate, GroupByClause, ToSql, Window},
+    reports::{self, ReportAnalytics, ReportField, ReportRow, ReportRowsQuery},
+    types::{AnalyticsCollection, AnalyticsDataSource},
+};
+
+/// Upper bound on the payments replayed by a single backtest.
+pub const MAX_BACKTEST_PAYMENTS: usize = 100_000;
+
+const BACKTEST_ATTEMPT_COLUMNS: &[&str] = &[
+    "payment_id",
+    "attempt_id",
+    "connector",
+    "status",
+    "amount",
+    "currency",
+    "payment_method",
+    "payment_method_type",
+    "card_network",
+    "authentication_type",
+    "capture_method",
+    "created_at",
+];
+
+/// A historical payment attempt, with the fields that are used as routing inputs.
+#[derive(Debug, Clone)]
+pub struct BacktestPaymentAttempt {
+    pub payment_id: String,
+    pub attempt_id: String,
+    pub connector: Option<String>,
+    pub status: Option<AttemptStatus>,
+    pub amount: MinorUnit,
+    pub currency: Option<Currency>,
+    pub payment_method: Option<PaymentMethod>,
+    pub payment_method_type: Option<PaymentMethodType>,
+    pub card_network: Option<CardNetwork>,
+    pub authentication_type: Option<AuthenticationType>,
+    pub capture_method: Option<CaptureMethod>,
+    pub created_at: Option<PrimitiveDateTime>,
+}
+
+impl From<&ReportRow> for BacktestPaymentAttempt {
+    fn from(row: &ReportRow) -> Self {
+        Self {
+            payment_id: row.get("payment_id").to_owned(),
+            attempt_id: row.get("attempt_id").to_owned(),
+            connector: Some(row.get("connector"))
+                .filter(|connector| !connector.is_empty())
+                .map(ToOwned::to_owned),
+            status: parse_column(row, "status"),
+            amount: MinorUnit::new(parse_column(row, "amount").unwrap_or_default()),
+            currency: parse_column(row, "currency"),
+            payment_method: parse_column(row, "payment_method"),
+            payment_method_type: parse_column(row, "payment_method_type"),
+            card_network: parse_column(row, "card_network"),
+            authentication_type: parse_column(row, "authentication_type"),
+            capture_method: parse_column(row, "capture_method"),
+            created_at: parse_timestamp(row.get("created_at")),
+        }
+    }
+}
+
+/// Values which cannot be parsed are treated as missing, as a routing rule on them would not
+/// match at the time of the payment either.
+fn parse_column<T: FromStr>(row: &ReportRow, column: &str) -> Option<T> {
+    T::from_str(row.get(column)).ok()
+}
+
+/// Data sources render timestamps as `YYYY-MM-DD hh:mm:ss` with varying precision, only the part
+/// up to the seconds is used.
+fn parse_timestamp(value: &str) -> Option<PrimitiveDateTime> {
+    let value = value.get(..19)?.replacen(' ', "T", 1);
+    PrimitiveDateTime::parse(&value, &Iso8601::DEFAULT).ok()
+}
+
+/// Loads the first attempt of each payment created in the time range, oldest first, so that
+/// retries don't count as separate routing decisions. At most [`MAX_BACKTEST_PAYMENTS`] payments
+/// are loaded.
+pub async fn load_payment_attempts<T>(
+    time_range: &TimeRange,
+    auth: &AuthInfo,
+    pool: &T,
+) -> AnalyticsResult<Vec<BacktestPaymentAttempt>>
+where
+    T: AnalyticsDataSource + ReportAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+    ReportField: ToSql<T>,
+{
+    let query = ReportRowsQuery {
+        collection: AnalyticsCollection::Payment,
+        columns: BACKTEST_ATTEMPT_COLUMNS,
+        auth,
+        time_range: Some(time_range),
+        payment_ids: None,
+    };
+    let mut attempts = FirstAttempts::default();
+    let mut offset = Some(0);
+
+    while let Some(page_offset) = offset {
+        let rows = query.load_page(page_offset, pool).await?;
+        for row in &rows {
+            attempts.push(BacktestPaymentAttempt::from(row));
+        }
+        offset = reports::next_page_offset(page_offset, rows.len())
+            .filter(|_| attempts.attempts.len() < MAX_BACKTEST_PAYMENTS);
+    }
+
+    Ok(attempts.attempts)
+}
+
+/// Keeps the first attempt seen of each payment, up to [`MAX_BACKTEST_PAYMENTS`] payments.
+#[derive(Default)]
+struct FirstAttempts {
+    payment_ids: HashSet<String>,
+    attempts: Vec<BacktestPaymentAttempt>,
+}
+
+impl FirstAttempts {
+    fn push(&mut self, attempt: BacktestPaymentAttempt) {
+        if self.attempts.len() < MAX_BACKTEST_PAYMENTS
+            && self.payment_ids.insert(attempt.payment_id.clone())
+        {
+            self.attempts.push(attempt);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attempt(payment_id: &str, attempt_id: &str) -> BacktestPaymentAttempt {
+        BacktestPaymentAttempt {
+            payment_id: payment_id.to_string(),
+            attempt_id: attempt_id.to_string(),
+            connector: None,
+            status: None,
+            amount: MinorUnit::new(100),
+            currency: None,
+            payment_method: None,
+            payment_method_type: None,
+            card_network: None,
+            authentication_type: None,
+            capture_method: None,
+            created_at: None,
+        }
+    }
+
+    #[test]
+    fn test_first_attempts_skips_retries() {
+        let mut attempts = FirstAttempts::default();
+        attempts.push(attempt("pay_1", "pay_1_1"));
+        attempts.push(attempt("pay_2", "pay_2_1"));
+        attempts.push(attempt("pay_1", "pay_1_2"));
+
+        let attempt_ids = attempts
+            .attempts
+            .iter()
+            .map(|attempt| attempt.attempt_id.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(attempt_ids, ["pay_1_1", "pay_2_1"]);
+    }
+
+    #[test]
+    fn test_first_attempts_is_capped() {
+        let mut attempts = FirstAttempts::default();
+        for index in 0..=MAX_BACKTEST_PAYMENTS {
+            attempts.push(attempt(&format!("pay_{index}"), &format!("pay_{index}_1")));
+        }
+
+        assert_eq!(attempts.attempts.len(), MAX_BACKTEST_PAYMENTS);
+    }
+}
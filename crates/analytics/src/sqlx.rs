@@ -18,7 +18,7 @@ use diesel_models::enums::{
 use error_stack::ResultExt;
 use sqlx::{
     postgres::{PgArgumentBuffer, PgPoolOptions, PgRow, PgTypeInfo, PgValueRef},
-    Decode, Encode,
+    Column, Decode, Encode,
     Error::ColumnNotFound,
     FromRow, Pool, Postgres, Row,
 };
@@ -172,6 +172,8 @@ impl super::frm::metrics::FrmMetricAnalytics for SqlxClient {}
 impl super::frm::filters::FrmFilterAnalytics for SqlxClient {}
 impl super::auth_events::metrics::AuthEventMetricAnalytics for SqlxClient {}
 impl super::auth_events::filters::AuthEventFilterAnalytics for SqlxClient {}
+impl super::reports::ReportAnalytics for SqlxClient {}
+impl super::connector_error_codes::ConnectorErrorCodeAnalytics for SqlxClient {}
 
 #[async_trait::async_trait]
 impl AnalyticsDataSource for SqlxClient {
@@ -754,6 +756,16 @@ impl<'a> FromRow<'a, PgRow> for super::payments::metrics::PaymentMetricRow {
                 ColumnNotFound(_) => Ok(Default::default()),
                 e => Err(e),
             })?;
+        let routing_algorithm_id: Option<String> =
+            row.try_get("routing_algorithm_id").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let is_split_payment: Option<bool> =
+            row.try_get("is_split_payment").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
         let total: Option<bigdecimal::BigDecimal> = row.try_get("total").or_else(|e| match e {
             ColumnNotFound(_) => Ok(Default::default()),
             e => Err(e),
@@ -789,6 +801,8 @@ impl<'a> FromRow<'a, PgRow> for super::payments::metrics::PaymentMetricRow {
             signature_network,
             is_issuer_regulated,
             is_debit_routed,
+            routing_algorithm_id,
+            is_split_payment,
             total,
             count,
             start_bucket,
@@ -882,6 +896,16 @@ impl<'a> FromRow<'a, PgRow> for super::payments::distribution::PaymentDistributi
                 ColumnNotFound(_) => Ok(Default::default()),
                 e => Err(e),
             })?;
+        let routing_algorithm_id: Option<String> =
+            row.try_get("routing_algorithm_id").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let is_split_payment: Option<bool> =
+            row.try_get("is_split_payment").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
         let total: Option<bigdecimal::BigDecimal> = row.try_get("total").or_else(|e| match e {
             ColumnNotFound(_) => Ok(Default::default()),
             e => Err(e),
@@ -928,6 +952,8 @@ impl<'a> FromRow<'a, PgRow> for super::payments::distribution::PaymentDistributi
             signature_network,
             is_issuer_regulated,
             is_debit_routed,
+            routing_algorithm_id,
+            is_split_payment,
             start_bucket,
             end_bucket,
         })
@@ -1022,6 +1048,16 @@ impl<'a> FromRow<'a, PgRow> for super::payments::filters::PaymentFilterRow {
                 ColumnNotFound(_) => Ok(Default::default()),
                 e => Err(e),
             })?;
+        let routing_algorithm_id: Option<String> =
+            row.try_get("routing_algorithm_id").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let is_split_payment: Option<bool> =
+            row.try_get("is_split_payment").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
         Ok(Self {
             currency,
             status,
@@ -1042,6 +1078,8 @@ impl<'a> FromRow<'a, PgRow> for super::payments::filters::PaymentFilterRow {
             signature_network,
             is_issuer_regulated,
             is_debit_routed,
+            routing_algorithm_id,
+            is_split_payment,
         })
     }
 }
@@ -1437,6 +1475,55 @@ impl<'a> FromRow<'a, PgRow> for super::disputes::metrics::DisputeMetricRow {
     }
 }
 
+impl<'a> FromRow<'a, PgRow> for super::connector_error_codes::ConnectorErrorCodeRow {
+    fn from_row(row: &'a PgRow) -> sqlx::Result<Self> {
+        let connector: Option<String> = row.try_get("connector").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let error_code: Option<String> = row.try_get("error_code").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let error_message: Option<String> = row.try_get("error_message").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let count: Option<i64> = row.try_get("count").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        Ok(Self {
+            connector,
+            error_code,
+            error_message,
+            count,
+        })
+    }
+}
+
+impl<'a> FromRow<'a, PgRow> for super::reports::ReportRow {
+    fn from_row(row: &'a PgRow) -> sqlx::Result<Self> {
+        row.columns()
+            .iter()
+            .map(|column| {
+                let value: Option<String> = row.try_get(column.ordinal())?;
+                Ok((column.name().to_owned(), value.unwrap_or_default()))
+            })
+            .collect::<sqlx::Result<_>>()
+            .map(Self)
+    }
+}
+
+impl ToSql<SqlxClient> for super::reports::ReportField {
+    fn to_sql(&self, _table_engine: &TableEngine) -> error_stack::Result<String, ParsingError> {
+        Ok(format!(
+            "CAST({column} AS TEXT) AS {column}",
+            column = self.0
+        ))
+    }
+}
+
 impl ToSql<SqlxClient> for PrimitiveDateTime {
     fn to_sql(&self, _table_engine: &TableEngine) -> error_stack::Result<String, ParsingError> {
         Ok(self.to_string())
@@ -1199,6 +1199,10 @@ pub struct MerchantConnectorCreate {
     /// The connector_wallets_details is used to store wallet details such as certificates and wallet credentials
     #[schema(value_type = Option<ConnectorWalletDetails>)]
     pub connector_wallets_details: Option<ConnectorWalletDetails>,
+
+    /// Fees the connector charges for processing payments, used by cost based routing
+    #[schema(value_type = Option<ConnectorFeeSchedule>)]
+    pub fee_schedule: Option<common_types::connector_fee_schedule::ConnectorFeeSchedule>,
 }
 
 #[cfg(feature = "v1")]
@@ -1658,6 +1662,10 @@ pub struct MerchantConnectorResponse {
     #[schema(value_type = Option<WebhookSetupCapabilities>, deprecated)]
     pub webhook_setup_capabilities:
         Option<common_types::connector_webhook_configuration::WebhookSetupCapabilities>,
+
+    /// Fees the connector charges for processing payments, used by cost based routing
+    #[schema(value_type = Option<ConnectorFeeSchedule>)]
+    pub fee_schedule: Option<common_types::connector_fee_schedule::ConnectorFeeSchedule>,
 }
 
 #[cfg(feature = "v1")]
@@ -1921,6 +1929,10 @@ pub struct MerchantConnectorUpdate {
     /// The connector_wallets_details is used to store wallet details such as certificates and wallet credentials
     #[schema(value_type = Option<ConnectorWalletDetails>)]
     pub connector_wallets_details: Option<ConnectorWalletDetails>,
+
+    /// Fees the connector charges for processing payments, used by cost based routing
+    #[schema(value_type = Option<ConnectorFeeSchedule>)]
+    pub fee_schedule: Option<common_types::connector_fee_schedule::ConnectorFeeSchedule>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -2460,6 +2472,11 @@ pub struct ProfileCreate {
     /// Payment method blocking configuration for the profile
     #[schema(value_type = Option<PaymentMethodBlockingConfig>)]
     pub payment_method_blocking: Option<PaymentMethodBlockingConfig>,
+
+    /// The IANA timezone in which time based routing rules, such as the day of week or the hour of
+    /// day of a transaction, are evaluated. Defaults to UTC when not set.
+    #[schema(value_type = Option<String>, max_length = 64, example = "Asia/Kolkata")]
+    pub routing_timezone: Option<String>,
 }
 
 #[nutype::nutype(
@@ -2850,6 +2867,11 @@ pub struct ProfileResponse {
     /// Payment method blocking configuration for the profile
     #[schema(value_type = Option<PaymentMethodBlockingConfig>)]
     pub payment_method_blocking: Option<PaymentMethodBlockingConfig>,
+
+    /// The IANA timezone in which time based routing rules, such as the day of week or the hour of
+    /// day of a transaction, are evaluated. Defaults to UTC when not set.
+    #[schema(value_type = Option<String>, max_length = 64, example = "Asia/Kolkata")]
+    pub routing_timezone: Option<String>,
 }
 
 #[cfg(feature = "v2")]
@@ -3239,6 +3261,11 @@ pub struct ProfileUpdate {
     /// Payment method blocking configuration for the profile
     #[schema(value_type = Option<PaymentMethodBlockingConfig>)]
     pub payment_method_blocking: Option<PaymentMethodBlockingConfig>,
+
+    /// The IANA timezone in which time based routing rules, such as the day of week or the hour of
+    /// day of a transaction, are evaluated. Defaults to UTC when not set.
+    #[schema(value_type = Option<String>, max_length = 64, example = "Asia/Kolkata")]
+    pub routing_timezone: Option<String>,
 }
 #[cfg(feature = "v2")]
 #[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
@@ -51,6 +51,10 @@ pub struct PaymentFilters {
     pub is_issuer_regulated: Vec<bool>,
     #[serde(default)]
     pub is_debit_routed: Vec<bool>,
+    #[serde(default)]
+    pub routing_algorithm_id: Vec<String>,
+    #[serde(default)]
+    pub is_split_payment: Vec<bool>,
 }
 
 #[derive(
@@ -96,6 +100,8 @@ pub enum PaymentDimensions {
     SignatureNetwork,
     IsIssuerRegulated,
     IsDebitRouted,
+    RoutingAlgorithmId,
+    IsSplitPayment,
 }
 
 #[derive(
@@ -273,6 +279,8 @@ pub struct PaymentMetricsBucketIdentifier {
     pub signature_network: Option<String>,
     pub is_issuer_regulated: Option<bool>,
     pub is_debit_routed: Option<bool>,
+    pub routing_algorithm_id: Option<String>,
+    pub is_split_payment: Option<bool>,
     #[serde(rename = "time_range")]
     pub time_bucket: TimeRange,
     // Coz FE sucks
@@ -302,6 +310,8 @@ impl PaymentMetricsBucketIdentifier {
         signature_network: Option<String>,
         is_issuer_regulated: Option<bool>,
         is_debit_routed: Option<bool>,
+        routing_algorithm_id: Option<String>,
+        is_split_payment: Option<bool>,
         normalized_time_range: TimeRange,
     ) -> Self {
         Self {
@@ -323,6 +333,8 @@ impl PaymentMetricsBucketIdentifier {
             signature_network,
             is_issuer_regulated,
             is_debit_routed,
+            routing_algorithm_id,
+            is_split_payment,
             time_bucket: normalized_time_range,
             start_time: normalized_time_range.start_time,
         }
@@ -352,6 +364,8 @@ impl Hash for PaymentMetricsBucketIdentifier {
         self.signature_network.hash(state);
         self.is_issuer_regulated.hash(state);
         self.is_debit_routed.hash(state);
+        self.routing_algorithm_id.hash(state);
+        self.is_split_payment.hash(state);
         self.time_bucket.hash(state);
     }
 }
@@ -1,3 +1,4 @@
+use common_types::api_keys::ApiKeyPermissions;
 use common_utils::custom_serde;
 use hyperswitch_masking::StrongSecret;
 use serde::{Deserialize, Serialize};
@@ -23,6 +24,19 @@ pub struct CreateApiKeyRequest {
     /// rotating your keys once every 6 months.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The permissions granted to the API Key. If not provided, the API Key has full access to
+    /// the merchant account. Permissions cannot be changed once the API Key is created.
+    #[schema(value_type = Option<Vec<ApiKeyPermission>>, example = json!([
+        {"resource": "payment", "scope": "read"},
+        {"resource": "refund", "scope": "write"}
+    ]))]
+    pub permissions: Option<ApiKeyPermissions>,
+
+    /// The profile the API Key is restricted to. If not provided, the API Key can be used with
+    /// all the profiles of the merchant account.
+    #[schema(max_length = 64, example = "pro_abcdefghijklmnop", value_type = Option<String>)]
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
 }
 
 /// The response body for creating an API Key.
@@ -60,6 +74,17 @@ pub struct CreateApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The permissions granted to the API Key. The API Key has full access to the merchant
+    /// account if this is not present.
+    #[schema(value_type = Option<Vec<ApiKeyPermission>>)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<ApiKeyPermissions>,
+
+    /// The profile the API Key is restricted to.
+    #[schema(max_length = 64, example = "pro_abcdefghijklmnop", value_type = Option<String>)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
     /*
     /// The date and time indicating when the API Key was last used.
     #[schema(example = "2022-09-10T10:11:12Z")]
@@ -102,6 +127,17 @@ pub struct RetrieveApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The permissions granted to the API Key. The API Key has full access to the merchant
+    /// account if this is not present.
+    #[schema(value_type = Option<Vec<ApiKeyPermission>>)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<ApiKeyPermissions>,
+
+    /// The profile the API Key is restricted to.
+    #[schema(max_length = 64, example = "pro_abcdefghijklmnop", value_type = Option<String>)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
     /*
     /// The date and time indicating when the API Key was last used.
     #[schema(example = "2022-09-10T10:11:12Z")]
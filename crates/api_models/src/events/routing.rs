@@ -4,12 +4,13 @@ use crate::routing::{
     ContractBasedRoutingPayloadWrapper, ContractBasedRoutingSetupPayloadWrapper,
     CreateDynamicRoutingWrapper, DynamicRoutingUpdateConfigQuery, EliminationRoutingPayloadWrapper,
     LinkedRoutingConfigRetrieveResponse, MerchantRoutingAlgorithm, ProfileDefaultRoutingConfig,
-    RoutingAlgorithmId, RoutingConfigRequest, RoutingDictionaryRecord, RoutingEntryResponse,
-    RoutingKind, RoutingLinkWrapper, RoutingMigrationStatusQuery, RoutingMigrationStatusResponse,
-    RoutingPayloadWrapper, RoutingRetrieveLinkQuery, RoutingRetrieveLinkQueryWrapper,
-    RoutingRetrieveQuery, RoutingVolumeSplit, RoutingVolumeSplitResponse,
-    RoutingVolumeSplitWrapper, RuleMigrationError, RuleMigrationQuery, RuleMigrationRequest,
-    RuleMigrationResponse, RuleMigrationResult, SuccessBasedRoutingConfig,
+    RoutingAlgorithmId, RoutingBacktestRequest, RoutingBacktestResponse, RoutingBacktestWrapper,
+    RoutingCanaryRequest, RoutingCanaryResponse, RoutingConfigRequest, RoutingDictionaryRecord,
+    RoutingEntryResponse, RoutingKind, RoutingLinkWrapper, RoutingMigrationStatusQuery,
+    RoutingMigrationStatusResponse, RoutingPayloadWrapper, RoutingRetrieveLinkQuery,
+    RoutingRetrieveLinkQueryWrapper, RoutingRetrieveQuery, RoutingVolumeSplit,
+    RoutingVolumeSplitResponse, RoutingVolumeSplitWrapper, RuleMigrationError, RuleMigrationQuery,
+    RuleMigrationRequest, RuleMigrationResponse, RuleMigrationResult, SuccessBasedRoutingConfig,
     SuccessBasedRoutingPayloadWrapper, ToggleDynamicRoutingPath, ToggleDynamicRoutingQuery,
     ToggleDynamicRoutingWrapper,
 };
@@ -233,3 +234,33 @@ impl ApiEventMetric for crate::open_router::UpdateScoreResponse {
         Some(ApiEventsType::Routing)
     }
 }
+
+impl ApiEventMetric for RoutingBacktestRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Routing)
+    }
+}
+
+impl ApiEventMetric for RoutingBacktestResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Routing)
+    }
+}
+
+impl ApiEventMetric for RoutingBacktestWrapper {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Routing)
+    }
+}
+
+impl ApiEventMetric for RoutingCanaryRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Routing)
+    }
+}
+
+impl ApiEventMetric for RoutingCanaryResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Routing)
+    }
+}
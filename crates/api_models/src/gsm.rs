@@ -1,4 +1,4 @@
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
 
 use crate::enums as api_enums;
 
@@ -212,3 +212,170 @@ pub struct GsmResponse {
     /// resolve the issue.
     pub user_guidance_message: Option<String>,
 }
+
+/// A GSM rule in a bulk import or export file. The retry behaviour is flattened into columns, so
+/// the same record can be used in JSON and CSV files.
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct GsmBulkRecord {
+    /// The connector through which payment has gone through
+    pub connector: String,
+    /// The flow in which the code and message occurred for a connector
+    pub flow: String,
+    /// The sub_flow in which the code and message occurred  for a connector
+    pub sub_flow: String,
+    /// code received from the connector
+    pub code: String,
+    /// message received from the connector
+    pub message: String,
+    /// status provided by the router
+    pub status: String,
+    /// optional error provided by the router
+    pub router_error: Option<String>,
+    /// decision to be taken for auto retries flow
+    #[schema(value_type = GsmDecision)]
+    pub decision: api_enums::GsmDecision,
+    /// indicates if step_up retry is possible
+    #[serde(default)]
+    pub step_up_possible: bool,
+    /// indicates if retry with pan is possible
+    #[serde(default)]
+    pub clear_pan_possible: bool,
+    /// indicates if retry through an alternate card network is possible
+    #[serde(default)]
+    pub alternate_network_possible: bool,
+    /// error code unified across the connectors
+    pub unified_code: Option<String>,
+    /// error message unified across the connectors
+    pub unified_message: Option<String>,
+    /// category in which error belongs to
+    #[schema(value_type = Option<ErrorCategory>)]
+    pub error_category: Option<api_enums::ErrorCategory>,
+    /// Code that identifies the specific cause for a failure within a broader error category
+    #[schema(value_type = Option<StandardisedCode>)]
+    pub standardised_code: Option<api_enums::StandardisedCode>,
+    /// A detailed description of the error intended for debugging, analytics, and support teams.
+    pub description: Option<String>,
+    /// A user-friendly message that can be safely displayed to the customer.
+    pub user_guidance_message: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct GsmBulkImportRequest {
+    /// The GSM rules to create or update. Rules are matched with existing rules on connector,
+    /// flow, sub_flow, code and message. Optional fields which are not provided keep their
+    /// current value on existing rules.
+    pub rules: Vec<GsmBulkRecord>,
+    /// Validate the rules and report the changes without applying them
+    #[serde(default)]
+    pub dry_run: bool,
+    /// The line of each rule in the CSV file it was read from, which is reported instead of the
+    /// position of the rule for CSV imports
+    #[serde(skip)]
+    pub line_numbers: Option<Vec<usize>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum GsmBulkImportAction {
+    /// No rule exists with the key of the record, a rule will be created
+    Create,
+    /// A rule exists with the key of the record and differs from it, the rule will be updated
+    Update,
+    /// A rule exists with the key of the record and matches it
+    Unchanged,
+    /// The record is invalid, none of the records are applied
+    Invalid,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct GsmBulkImportRowResult {
+    /// The line of the record in the imported CSV file, or the position of the record in the
+    /// list of rules starting from 1
+    pub line_number: usize,
+    pub connector: String,
+    pub flow: String,
+    pub sub_flow: String,
+    pub code: String,
+    pub message: String,
+    pub action: GsmBulkImportAction,
+    /// The fields which differ from the existing rule, for records which update a rule
+    pub changed_fields: Vec<String>,
+    /// The reasons why the record is invalid
+    pub errors: Vec<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct GsmBulkImportResponse {
+    pub dry_run: bool,
+    /// Whether the changes were applied. Changes are not applied for dry runs, or if any of the
+    /// records is invalid.
+    pub applied: bool,
+    pub created: usize,
+    pub updated: usize,
+    pub unchanged: usize,
+    pub invalid: usize,
+    pub rows: Vec<GsmBulkImportRowResult>,
+}
+
+#[derive(Debug, Clone, Copy, Default, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum GsmExportFormat {
+    #[default]
+    Json,
+    Csv,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema, IntoParams)]
+pub struct GsmExportRequest {
+    /// Only export the rules of this connector
+    pub connector: Option<String>,
+    /// The format of the export, defaults to `json`
+    #[param(value_type = Option<GsmExportFormat>)]
+    pub format: Option<GsmExportFormat>,
+}
+
+/// All the GSM rules, in the same shape as a bulk import request
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct GsmExportResponse {
+    pub rules: Vec<GsmBulkRecord>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GsmUnmappedErrorsRequest {
+    /// The number of days of payment attempts to look at, counting back from now
+    #[schema(minimum = 1, maximum = 90, example = 7)]
+    pub lookback_days: u16,
+    /// Only report errors of this connector
+    pub connector: Option<String>,
+    /// The GSM flow the errors are looked up in, defaults to `Payment`
+    pub flow: Option<String>,
+    /// The GSM sub_flow the errors are looked up in, defaults to `Authorize`
+    pub sub_flow: Option<String>,
+    /// The maximum number of errors to report, defaults to 100
+    #[schema(minimum = 1, maximum = 1000, example = 100)]
+    pub limit: Option<u16>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct GsmUnmappedError {
+    pub connector: String,
+    /// code received from the connector
+    pub code: String,
+    /// message received from the connector
+    pub message: String,
+    /// The number of payment attempts which failed with this code and message
+    pub count: u64,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct GsmUnmappedErrorsResponse {
+    pub flow: String,
+    pub sub_flow: String,
+    /// The number of payment attempts which failed with an error that has no GSM rule
+    pub total_unmapped_attempts: u64,
+    /// The number of distinct errors that have no GSM rule
+    pub total_unmapped_errors: usize,
+    /// The most frequent errors that have no GSM rule, most frequent first
+    pub errors: Vec<GsmUnmappedError>,
+}
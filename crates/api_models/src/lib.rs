@@ -41,6 +41,7 @@ pub mod process_tracker;
 pub mod profile_acquirer;
 #[cfg(feature = "v2")]
 pub mod proxy;
+pub mod rate_limit;
 pub mod refunds;
 pub mod relay;
 #[cfg(feature = "v2")]
@@ -58,6 +59,7 @@ pub mod user;
 pub mod user_role;
 pub mod verifications;
 pub mod verify_connector;
+pub mod webhook_endpoints;
 pub mod webhook_events;
 pub mod webhooks;
 
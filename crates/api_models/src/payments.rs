@@ -1442,6 +1442,12 @@ pub struct PaymentsRequest {
     #[schema(value_type = Option<OfferSelection>)]
     pub offer_details: Option<OfferSelection>,
 
+    /// Quote from `/payments/{payment_id}/dcc_quote` to charge the payment in the currency of the
+    /// customer, at the rate locked by the quote
+    #[remove_in(PaymentsCreateRequest, PaymentsUpdateRequest)]
+    #[schema(example = "dcc_quote_Sd8t2xWb4pFq1mZr9yLc")]
+    pub dcc_quote_id: Option<String>,
+
     /// The type of the payment that differentiates between normal and various types of mandate payments
     #[schema(value_type = Option<PaymentType>)]
     #[smithy(value_type = "Option<PaymentType>")]
@@ -1606,6 +1612,26 @@ pub struct PaymentsRequest {
     /// The strategy to use when applying surcharge for this payment.
     #[schema(value_type = Option<SurchargeStrategy>)]
     pub external_surcharge_strategy: Option<common_enums::SurchargeStrategy>,
+
+    /// Captures the payment automatically once it is authorized, at the scheduled time. Can only be set while confirming a payment with `capture_method` set to `manual`. Capturing or cancelling the payment removes the schedule.
+    #[remove_in(PaymentsUpdateRequest)]
+    #[schema(value_type = Option<CaptureSchedule>)]
+    pub capture_schedule: Option<CaptureSchedule>,
+}
+
+/// When a payment authorized with `capture_method` set to `manual` is captured. Exactly one of the fields must be provided.
+#[cfg(feature = "v1")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CaptureSchedule {
+    /// Capture the payment this many seconds after it is confirmed. At most 30 days.
+    #[schema(example = 259200)]
+    pub capture_after: Option<u32>,
+
+    /// Capture the payment at this time. At most 30 days in the future.
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub capture_at: Option<PrimitiveDateTime>,
 }
 
 #[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize, ToSchema, SmithyModel)]
@@ -7654,6 +7680,11 @@ pub struct PaymentsResponse {
     #[schema(value_type = Option<AppliedOffer>)]
     pub applied_offer: Option<AppliedOffer>,
 
+    /// Details of the dynamic currency conversion applied to this payment, if the customer is
+    /// charged in a currency other than the original currency of the payment
+    #[schema(value_type = Option<DccDetails>)]
+    pub dcc_details: Option<common_types::payments::DccDetails>,
+
     /// Total number of attempts associated with this payment
     #[smithy(value_type = "i16")]
     pub attempt_count: i16,
@@ -11369,6 +11400,38 @@ pub struct PaymentsPostCaptureVoidSyncTrackingData {
     pub merchant_id: id_type::MerchantId,
 }
 
+/// Tracking data for the scheduled_capture workflow stored in process_tracker
+#[derive(Default, Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct PaymentsScheduledCaptureTrackingData {
+    /// The identifier for the payment
+    pub payment_id: id_type::PaymentId,
+    /// The identifier for the merchant
+    pub merchant_id: id_type::MerchantId,
+}
+
+/// Tracking data for the authorization_expiry workflow stored in process_tracker
+#[derive(Default, Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct PaymentsAuthorizationExpiryTrackingData {
+    /// The identifier for the payment
+    pub payment_id: id_type::PaymentId,
+    /// The identifier for the merchant
+    pub merchant_id: id_type::MerchantId,
+}
+
+/// Tracking data for the split_payment_reversal workflow stored in process_tracker
+#[cfg(feature = "v2")]
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct SplitPaymentReversalTrackingData {
+    /// The identifier for the payment
+    pub payment_id: id_type::GlobalPaymentId,
+    /// The identifier for the merchant
+    pub merchant_id: id_type::MerchantId,
+    /// The refunds reversing funding legs which were still pending at the connector
+    pub pending_refund_ids: Vec<id_type::GlobalRefundId>,
+    /// The amount of the funding legs which could not be reversed
+    pub unreversed_amount: MinorUnit,
+}
+
 #[derive(Default, Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
 /// Request constructed internally for extending authorization
 pub struct PaymentsExtendAuthorizationRequest {
@@ -11388,6 +11451,52 @@ pub struct PaymentsIncrementalAuthorizationRequest {
     pub reason: Option<String>,
 }
 
+#[cfg(feature = "v1")]
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone, ToSchema)]
+pub struct PaymentsDccQuoteRequest {
+    /// The identifier for the payment
+    #[serde(skip)]
+    pub payment_id: id_type::PaymentId,
+    /// Currency of the customer, in which the payment is to be charged
+    #[schema(value_type = Currency, example = "EUR")]
+    pub currency: enums::Currency,
+}
+
+/// A quote converting the amount of a payment into the currency of the customer. Confirming the
+/// payment with `dcc_quote_id` before the quote expires charges the converted amount.
+#[cfg(feature = "v1")]
+#[derive(Debug, serde::Serialize, Clone, ToSchema)]
+pub struct PaymentsDccQuoteResponse {
+    /// The identifier of the quote, to be passed as `dcc_quote_id` when confirming the payment
+    #[schema(example = "dcc_quote_Sd8t2xWb4pFq1mZr9yLc")]
+    pub quote_id: String,
+    /// The identifier for the payment
+    #[schema(value_type = String)]
+    pub payment_id: id_type::PaymentId,
+    /// Amount of the payment in the original currency, in minor units
+    #[schema(value_type = i64, example = 6540)]
+    pub original_amount: MinorUnit,
+    /// Original currency of the payment
+    #[schema(value_type = Currency, example = "USD")]
+    pub original_currency: enums::Currency,
+    /// Amount the customer is charged in the converted currency, in minor units
+    #[schema(value_type = i64, example = 6197)]
+    pub converted_amount: MinorUnit,
+    /// Currency the customer is charged in
+    #[schema(value_type = Currency, example = "EUR")]
+    pub converted_currency: enums::Currency,
+    /// Rate at which a major unit of the original currency is converted, markup included
+    #[schema(value_type = String, example = "0.947554")]
+    pub exchange_rate: rust_decimal::Decimal,
+    /// Markup over the market rate, in basis points
+    #[schema(example = 300)]
+    pub markup_in_basis_points: u32,
+    /// Time after which the quote can no longer be used
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:26:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub expires_at: PrimitiveDateTime,
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize, Clone, ToSchema)]
 pub struct PaymentsExternalAuthenticationRequest {
     /// The identifier for the payment
@@ -0,0 +1,121 @@
+use common_utils::{events::ApiEventMetric, id_type};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RateLimitRule {
+    /// The API key the limit applies to. When not provided, the limit applies to all API keys of
+    /// the merchant
+    #[schema(value_type = Option<String>, example = "dev_2ojpvnBE4CeT3dhZwsjB")]
+    pub key_id: Option<id_type::ApiKeyId>,
+    /// The group of routes the limit applies to. When not provided, the limit applies to all
+    /// routes
+    #[schema(example = "payments")]
+    pub route_group: Option<String>,
+    /// Number of tokens added back to the bucket every second
+    #[schema(example = 50)]
+    pub requests_per_second: u32,
+    /// Maximum number of tokens the bucket can hold, i.e. the number of requests allowed in a
+    /// single burst
+    #[schema(example = 100)]
+    pub burst: u32,
+    /// Maximum number of requests allowed in a period, in addition to the token bucket limit
+    pub quota: Option<RateLimitQuota>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RateLimitQuota {
+    /// Number of requests allowed in each period
+    #[schema(example = 100000)]
+    pub limit: u32,
+    /// The period the quota is reset after. Periods are aligned to UTC, i.e. a daily quota is
+    /// reset at midnight UTC
+    pub period: QuotaPeriod,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum QuotaPeriod {
+    Minute,
+    Hour,
+    Day,
+}
+
+impl QuotaPeriod {
+    pub fn get_duration_in_seconds(&self) -> u32 {
+        match self {
+            Self::Minute => 60,
+            Self::Hour => 60 * 60,
+            Self::Day => 24 * 60 * 60,
+        }
+    }
+}
+
+impl RateLimitRule {
+    /// Whether the rule is applicable to a request made with the given key on the given group
+    pub fn is_applicable(
+        &self,
+        key_id: Option<&id_type::ApiKeyId>,
+        route_group: Option<&str>,
+    ) -> bool {
+        let key_matches = self
+            .key_id
+            .as_ref()
+            .is_none_or(|rule_key_id| Some(rule_key_id) == key_id);
+        let group_matches = self
+            .route_group
+            .as_deref()
+            .is_none_or(|rule_route_group| Some(rule_route_group) == route_group);
+
+        key_matches && group_matches
+    }
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct RateLimitConfigRequest {
+    #[serde(skip_deserializing)]
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// Rules to be enforced for the merchant, replacing any previously configured rules
+    pub rules: Vec<RateLimitRule>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct RateLimitConfigResponse {
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    pub rules: Vec<RateLimitRule>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct RateLimitConfigDeleteResponse {
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    #[schema(example = true)]
+    pub deleted: bool,
+}
+
+impl ApiEventMetric for RateLimitConfigRequest {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::RateLimit {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for RateLimitConfigResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::RateLimit {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for RateLimitConfigDeleteResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::RateLimit {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
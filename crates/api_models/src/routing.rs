@@ -27,8 +27,10 @@ const DEFAULT_HEDGING_PERCENT: f64 = 5.0;
 const DEFAULT_ELIMINATION_THRESHOLD: f64 = 0.35;
 const DEFAULT_PAYMENT_METHOD: &str = "CARD";
 const MAX_NAME_LENGTH: usize = 64;
+pub const MAX_STRAIGHT_THROUGH_PROGRAM_RULES: usize = 32;
+pub const MAX_STRAIGHT_THROUGH_PROGRAM_COMPARISONS: usize = 256;
 
-#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, PartialEq)]
 #[serde(tag = "type", content = "data", rename_all = "snake_case")]
 pub enum ConnectorSelection {
     Priority(Vec<RoutableConnectorChoice>),
@@ -238,6 +240,18 @@ pub struct RoutingActivatePayload {
     /// ```
     #[schema(example = "payment")]
     pub transaction_type: Option<TransactionType>,
+
+    /// Percentage of the traffic to route through the algorithm.
+    ///
+    /// When provided, the algorithm is activated as a canary alongside the
+    /// currently active algorithm instead of replacing it. Must be between 1 and 99.
+    ///
+    /// Example:
+    /// ```json
+    /// 10
+    /// ```
+    #[schema(example = 10, minimum = 1, maximum = 99)]
+    pub canary_traffic_percentage: Option<u8>,
 }
 
 /// Query parameters used to retrieve routing configuration
@@ -430,6 +444,9 @@ impl EuclidDirFilter for ConnectorSelection {
         DirKeyKind::RealTimePaymentType,
         DirKeyKind::TransactionInitiator,
         DirKeyKind::NetworkTokenType,
+        DirKeyKind::DayOfWeek,
+        DirKeyKind::HourOfDay,
+        DirKeyKind::TransactionDate,
     ];
 }
 
@@ -466,6 +483,44 @@ pub struct ConnectorVolumeSplit {
     pub split: u8,
 }
 
+/// Routes a payment through the eligible connector that is expected to charge the lowest fee,
+/// using the fee schedules configured on the merchant connector accounts
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CostBasedRoutingConfig {
+    /// Connectors eligible for the payment. Connectors without a fee schedule are ranked after
+    /// the ones with a fee schedule, in the order they are listed.
+    pub connectors: Vec<RoutableConnectorChoice>,
+    /// Expected success rates of the connectors. When set, connectors are ranked by the expected
+    /// fee per successful payment, that is, the fee divided by the success rate of the connector.
+    pub success_rates: Option<Vec<ConnectorSuccessRate>>,
+}
+
+/// Expected success rate of a connector, used to weight its expected fee
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct ConnectorSuccessRate {
+    pub connector: RoutableConnectorChoice,
+    /// Success rate of the connector, between 0 (exclusive) and 1
+    #[schema(example = 0.95)]
+    pub success_rate: f64,
+}
+
+impl CostBasedRoutingConfig {
+    /// Success rate configured for the connector, defaulting to 1 so that the expected fee of
+    /// connectors without a success rate is not weighted
+    pub fn get_success_rate(&self, connector: &RoutableConnectorChoice) -> f64 {
+        self.success_rates
+            .as_ref()
+            .and_then(|success_rates| {
+                success_rates
+                    .iter()
+                    .find(|success_rate| success_rate.connector == *connector)
+            })
+            .map(|success_rate| success_rate.success_rate)
+            .unwrap_or(1.0)
+    }
+}
+
 /// Routable Connector chosen for a payment
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
 #[serde(from = "RoutableChoiceSerde", into = "RoutableChoiceSerde")]
@@ -580,6 +635,7 @@ pub enum RoutingAlgorithmKind {
     Advanced,
     Dynamic,
     ThreeDsDecisionRule,
+    CostBased,
 }
 
 impl RoutingAlgorithmKind {
@@ -589,6 +645,9 @@ impl RoutingAlgorithmKind {
     pub fn rule_migration_exclusion(self) -> Option<&'static str> {
         match self {
             Self::Single | Self::Priority | Self::VolumeSplit | Self::Advanced => None,
+            Self::CostBased => {
+                Some("cost based routing is evaluated locally with the connector fee schedules")
+            }
             Self::Dynamic => {
                 Some("dynamic routing configuration, provisioned by the dynamic routing setup")
             }
@@ -634,6 +693,7 @@ pub enum StaticRoutingAlgorithm {
     Advanced(Program<ConnectorSelection>),
     #[schema(value_type=ProgramThreeDsDecisionRule)]
     ThreeDsDecisionRule(Program<ThreeDSDecisionRule>),
+    CostBased(CostBasedRoutingConfig),
 }
 
 #[derive(Serialize, Deserialize, ToSchema)]
@@ -658,7 +718,11 @@ pub struct RuleThreeDsDecisionRule {
 impl StaticRoutingAlgorithm {
     pub fn should_validate_connectors_in_routing_config(&self) -> bool {
         match self {
-            Self::Single(_) | Self::Priority(_) | Self::VolumeSplit(_) | Self::Advanced(_) => true,
+            Self::Single(_)
+            | Self::Priority(_)
+            | Self::VolumeSplit(_)
+            | Self::Advanced(_)
+            | Self::CostBased(_) => true,
             Self::ThreeDsDecisionRule(_) => false,
         }
     }
@@ -672,6 +736,7 @@ pub enum RoutingAlgorithmSerde {
     VolumeSplit(Vec<ConnectorVolumeSplit>),
     Advanced(Program<ConnectorSelection>),
     ThreeDsDecisionRule(Program<ThreeDSDecisionRule>),
+    CostBased(CostBasedRoutingConfig),
 }
 
 impl TryFrom<RoutingAlgorithmSerde> for StaticRoutingAlgorithm {
@@ -689,6 +754,20 @@ impl TryFrom<RoutingAlgorithmSerde> for StaticRoutingAlgorithm {
                     "Connectors list can't be empty for Volume split Algorithm",
                 ))?
             }
+            RoutingAlgorithmSerde::CostBased(config) if config.connectors.is_empty() => {
+                Err(ParsingError::StructParseFailure(
+                    "Connectors list can't be empty for Cost based Algorithm",
+                ))?
+            }
+            RoutingAlgorithmSerde::CostBased(config)
+                if config.success_rates.iter().flatten().any(|success_rate| {
+                    success_rate.success_rate <= 0.0 || success_rate.success_rate > 1.0
+                }) =>
+            {
+                Err(ParsingError::StructParseFailure(
+                    "Success rates must be greater than 0 and at most 1 for Cost based Algorithm",
+                ))?
+            }
             _ => {}
         };
         Ok(match value {
@@ -697,6 +776,7 @@ impl TryFrom<RoutingAlgorithmSerde> for StaticRoutingAlgorithm {
             RoutingAlgorithmSerde::VolumeSplit(i) => Self::VolumeSplit(i),
             RoutingAlgorithmSerde::Advanced(i) => Self::Advanced(i),
             RoutingAlgorithmSerde::ThreeDsDecisionRule(i) => Self::ThreeDsDecisionRule(i),
+            RoutingAlgorithmSerde::CostBased(i) => Self::CostBased(i),
         })
     }
 }
@@ -716,6 +796,10 @@ pub enum StraightThroughAlgorithm {
     Priority(Vec<RoutableConnectorChoice>),
     #[schema(title = "VolumeSplit")]
     VolumeSplit(Vec<ConnectorVolumeSplit>),
+    /// A rule based program, evaluated against the payment the same way as an active advanced
+    /// routing algorithm. It can have at most 32 rules and 256 comparisons.
+    #[schema(title = "Advanced", value_type = ProgramConnectorSelection)]
+    Advanced(Program<ConnectorSelection>),
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -724,6 +808,7 @@ pub enum StraightThroughAlgorithmInner {
     Single(Box<RoutableConnectorChoice>),
     Priority(Vec<RoutableConnectorChoice>),
     VolumeSplit(Vec<ConnectorVolumeSplit>),
+    Advanced(Program<ConnectorSelection>),
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -755,6 +840,21 @@ impl TryFrom<StraightThroughAlgorithmSerde> for StraightThroughAlgorithm {
                     "Connectors list can't be empty for Volume split Algorithm",
                 ))?
             }
+            StraightThroughAlgorithmInner::Advanced(program)
+                if program.rules.len() > MAX_STRAIGHT_THROUGH_PROGRAM_RULES =>
+            {
+                Err(ParsingError::StructParseFailure(
+                    "Too many rules in the Advanced straight through Algorithm",
+                ))?
+            }
+            StraightThroughAlgorithmInner::Advanced(program)
+                if get_program_comparison_count(program)
+                    > MAX_STRAIGHT_THROUGH_PROGRAM_COMPARISONS =>
+            {
+                Err(ParsingError::StructParseFailure(
+                    "Too many comparisons in the Advanced straight through Algorithm",
+                ))?
+            }
             _ => {}
         };
 
@@ -762,6 +862,7 @@ impl TryFrom<StraightThroughAlgorithmSerde> for StraightThroughAlgorithm {
             StraightThroughAlgorithmInner::Single(single) => Self::Single(single),
             StraightThroughAlgorithmInner::Priority(plist) => Self::Priority(plist),
             StraightThroughAlgorithmInner::VolumeSplit(vsplit) => Self::VolumeSplit(vsplit),
+            StraightThroughAlgorithmInner::Advanced(program) => Self::Advanced(program),
         })
     }
 }
@@ -776,6 +877,9 @@ impl From<StraightThroughAlgorithm> for StraightThroughAlgorithmSerde {
             StraightThroughAlgorithm::VolumeSplit(vsplit) => {
                 StraightThroughAlgorithmInner::VolumeSplit(vsplit)
             }
+            StraightThroughAlgorithm::Advanced(program) => {
+                StraightThroughAlgorithmInner::Advanced(program)
+            }
         };
 
         Self::Nested { algorithm: inner }
@@ -788,10 +892,31 @@ impl From<StraightThroughAlgorithm> for StaticRoutingAlgorithm {
             StraightThroughAlgorithm::Single(conn) => Self::Single(conn),
             StraightThroughAlgorithm::Priority(conns) => Self::Priority(conns),
             StraightThroughAlgorithm::VolumeSplit(splits) => Self::VolumeSplit(splits),
+            StraightThroughAlgorithm::Advanced(program) => Self::Advanced(program),
         }
     }
 }
 
+/// The number of comparisons in all the statements of the program, including nested ones
+fn get_program_comparison_count(program: &Program<ConnectorSelection>) -> usize {
+    fn get_statement_comparison_count(statement: &ast::IfStatement) -> usize {
+        statement.condition.len()
+            + statement
+                .nested
+                .iter()
+                .flatten()
+                .map(get_statement_comparison_count)
+                .sum::<usize>()
+    }
+
+    program
+        .rules
+        .iter()
+        .flat_map(|rule| rule.statements.iter())
+        .map(get_statement_comparison_count)
+        .sum()
+}
+
 impl StaticRoutingAlgorithm {
     pub fn get_kind(&self) -> RoutingAlgorithmKind {
         match self {
@@ -800,6 +925,7 @@ impl StaticRoutingAlgorithm {
             Self::VolumeSplit(_) => RoutingAlgorithmKind::VolumeSplit,
             Self::Advanced(_) => RoutingAlgorithmKind::Advanced,
             Self::ThreeDsDecisionRule(_) => RoutingAlgorithmKind::ThreeDsDecisionRule,
+            Self::CostBased(_) => RoutingAlgorithmKind::CostBased,
         }
     }
 }
@@ -810,12 +936,81 @@ pub struct RoutingAlgorithmRef {
     pub timestamp: i64,
     pub config_algo_id: Option<String>,
     pub surcharge_config_algo_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub canary: Option<RoutingCanary>,
+}
+
+/// A routing algorithm activated for a share of the traffic, alongside the active algorithm.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingCanary {
+    /// Identifier of the canary routing algorithm.
+    #[schema(value_type = String, example = "routing_abc123")]
+    pub algorithm_id: common_utils::id_type::RoutingId,
+
+    /// Percentage of the traffic routed through the canary algorithm.
+    #[schema(example = 10)]
+    pub traffic_percentage: u8,
+
+    /// Timestamp at which the canary was activated (seconds since epoch).
+    #[schema(example = 1718000000i64)]
+    pub started_at: i64,
 }
 
 impl RoutingAlgorithmRef {
+    /// Activates the algorithm for all of the traffic, ending any canary rollout.
     pub fn update_algorithm_id(&mut self, new_id: common_utils::id_type::RoutingId) {
         self.algorithm_id = Some(new_id);
+        self.canary = None;
+        self.timestamp = common_utils::date_time::now_unix_timestamp();
+    }
+
+    /// Activates the algorithm for a percentage of the traffic, replacing any existing canary.
+    pub fn update_canary(
+        &mut self,
+        algorithm_id: common_utils::id_type::RoutingId,
+        traffic_percentage: u8,
+    ) {
+        let timestamp = common_utils::date_time::now_unix_timestamp();
+        self.canary = Some(RoutingCanary {
+            algorithm_id,
+            traffic_percentage,
+            started_at: timestamp,
+        });
+        self.timestamp = timestamp;
+    }
+
+    /// Changes the percentage of the traffic routed through the canary algorithm, if any.
+    pub fn ramp_canary(&mut self, traffic_percentage: u8) -> Option<&RoutingCanary> {
+        let canary = self.canary.as_mut()?;
+        canary.traffic_percentage = traffic_percentage;
         self.timestamp = common_utils::date_time::now_unix_timestamp();
+        self.canary.as_ref()
+    }
+
+    /// Makes the canary algorithm the active algorithm for all of the traffic.
+    pub fn promote_canary(&mut self) -> Option<common_utils::id_type::RoutingId> {
+        let canary = self.canary.take()?;
+        self.update_algorithm_id(canary.algorithm_id.clone());
+        Some(canary.algorithm_id)
+    }
+
+    /// Stops routing traffic through the canary algorithm.
+    pub fn rollback_canary(&mut self) -> Option<RoutingCanary> {
+        let canary = self.canary.take()?;
+        self.timestamp = common_utils::date_time::now_unix_timestamp();
+        Some(canary)
+    }
+
+    /// The algorithm to route through for a traffic bucket between 0 and 99. Buckets below the
+    /// canary traffic percentage are routed through the canary algorithm.
+    pub fn get_algorithm_id_for_bucket(
+        &self,
+        bucket: u8,
+    ) -> Option<&common_utils::id_type::RoutingId> {
+        match &self.canary {
+            Some(canary) if bucket < canary.traffic_percentage => Some(&canary.algorithm_id),
+            _ => self.algorithm_id.as_ref(),
+        }
     }
 
     pub fn update_conditional_config_id(&mut self, ids: String) {
@@ -916,6 +1111,99 @@ pub struct RoutingDictionaryRecord {
     /// ```
     #[schema(example = "de_route_456")]
     pub decision_engine_routing_id: Option<String>,
+
+    /// Issues found while analyzing an advanced routing program.
+    ///
+    /// Only returned when the configuration is created. These do not prevent the configuration
+    /// from being saved.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub analysis_warnings: Vec<RoutingAnalysisWarning>,
+}
+
+/// Kind of issue found while analyzing an advanced routing program.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RoutingAnalysisWarningKind {
+    /// Every statement of the rule is already matched by an earlier rule
+    ShadowedRule,
+    /// The statement is already matched by an earlier rule
+    ShadowedStatement,
+    /// None of the statements of the rule can ever hold
+    UnsatisfiableRule,
+    /// The statement can never hold
+    UnsatisfiableStatement,
+    /// Values of a key that no rule matches, which fall through to the default selection
+    UncoveredValues,
+    /// The program is too large to be analysed, no other issues are reported for it
+    AnalysisSkipped,
+}
+
+/// An issue found while analyzing an advanced routing program.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingAnalysisWarning {
+    /// Kind of the issue
+    pub kind: RoutingAnalysisWarningKind,
+
+    /// Name of the rule the issue was found in
+    #[schema(example = "india_card_rule")]
+    pub rule_name: Option<String>,
+
+    /// Index of the statement within the rule the issue was found in
+    #[schema(example = 0)]
+    pub statement_index: Option<usize>,
+
+    /// Human readable description of the issue
+    pub message: String,
+}
+
+impl From<euclid::dssa::types::AnalysisWarning> for RoutingAnalysisWarning {
+    fn from(value: euclid::dssa::types::AnalysisWarning) -> Self {
+        use euclid::dssa::types::AnalysisWarningType;
+
+        let message = value.to_string();
+        let (kind, rule_name, statement_index) = match value.warning_type {
+            AnalysisWarningType::ShadowedRule { rule_name, .. } => (
+                RoutingAnalysisWarningKind::ShadowedRule,
+                Some(rule_name),
+                None,
+            ),
+            AnalysisWarningType::ShadowedStatement {
+                rule_name,
+                statement_index,
+                ..
+            } => (
+                RoutingAnalysisWarningKind::ShadowedStatement,
+                Some(rule_name),
+                Some(statement_index),
+            ),
+            AnalysisWarningType::UnsatisfiableRule { rule_name } => (
+                RoutingAnalysisWarningKind::UnsatisfiableRule,
+                Some(rule_name),
+                None,
+            ),
+            AnalysisWarningType::UnsatisfiableStatement {
+                rule_name,
+                statement_index,
+            } => (
+                RoutingAnalysisWarningKind::UnsatisfiableStatement,
+                Some(rule_name),
+                Some(statement_index),
+            ),
+            AnalysisWarningType::UncoveredValues { .. } => {
+                (RoutingAnalysisWarningKind::UncoveredValues, None, None)
+            }
+            AnalysisWarningType::AnalysisSkipped { .. } => {
+                (RoutingAnalysisWarningKind::AnalysisSkipped, None, None)
+            }
+        };
+
+        Self {
+            kind,
+            rule_name,
+            statement_index,
+            message,
+        }
+    }
 }
 
 /// Routing dictionary for a merchant.
@@ -2354,3 +2642,309 @@ pub struct RoutingEntryResponse {
     pub is_cutover: bool,
     pub redirect_url: Option<String>,
 }
+
+/// Replays the payment attempts of a past time window through a routing algorithm
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RoutingBacktestRequest {
+    /// The number of days of payment attempts to replay, counting back from now
+    #[schema(minimum = 1, maximum = 30, example = 7)]
+    pub lookback_days: u16,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct RoutingBacktestWrapper {
+    pub algorithm_id: common_utils::id_type::RoutingId,
+    pub request: RoutingBacktestRequest,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RoutingBacktestStatus {
+    /// The backtest is queued or running
+    Pending,
+    /// The backtest has completed and the result is available
+    Completed,
+    /// The backtest could not be completed
+    Failed,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingBacktestResponse {
+    /// The identifier of the backtest
+    pub backtest_id: String,
+    /// The routing algorithm which is replayed
+    #[schema(value_type = String)]
+    pub algorithm_id: common_utils::id_type::RoutingId,
+    /// The profile to which the routing algorithm belongs
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub status: RoutingBacktestStatus,
+    /// The outcome of the backtest, available once the backtest has completed
+    pub result: Option<RoutingBacktestResult>,
+}
+
+/// The outcome of replaying the payment attempts through the routing algorithm, compared against
+/// the connectors the payment attempts were actually routed to
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingBacktestResult {
+    /// The number of payment attempts replayed
+    pub total_attempts: u64,
+    /// The number of payment attempts which the routing algorithm routes to a different connector
+    pub changed_connector_count: u64,
+    /// The number of payment attempts which could not be replayed, as the routing algorithm
+    /// did not return a connector for them
+    pub unrouted_count: u64,
+    /// The number of payment attempts routed to each connector
+    pub actual_connector_distribution: std::collections::HashMap<String, u64>,
+    /// The number of payment attempts the routing algorithm routes to each connector
+    pub simulated_connector_distribution: std::collections::HashMap<String, u64>,
+    /// The outcome for each combination of payment method, payment method type and currency
+    pub segments: Vec<RoutingBacktestSegment>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingBacktestSegment {
+    #[schema(value_type = Option<PaymentMethod>)]
+    pub payment_method: Option<crate::enums::PaymentMethod>,
+    #[schema(value_type = Option<PaymentMethodType>)]
+    pub payment_method_type: Option<crate::enums::PaymentMethodType>,
+    #[schema(value_type = Option<Currency>)]
+    pub currency: Option<crate::enums::Currency>,
+    /// The number of payment attempts replayed in the segment
+    pub total_attempts: u64,
+    /// The connectors which the routing algorithm routes the payment attempts of the segment to
+    pub connectors: Vec<RoutingBacktestConnectorStats>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingBacktestConnectorStats {
+    pub connector: String,
+    /// The number of payment attempts of the segment the routing algorithm routes to the connector
+    pub simulated_attempts: u64,
+    /// The number of payment attempts of the segment which were routed to the connector
+    pub actual_attempts: u64,
+    /// The share of payment attempts of the segment routed to the connector which succeeded, as a
+    /// percentage. Absent when no payment attempt of the segment was routed to the connector.
+    pub historical_success_rate: Option<f64>,
+}
+
+/// Changes the canary rollout of a routing algorithm on a profile
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RoutingCanaryRequest {
+    /// Profile on which the canary is active
+    #[schema(value_type = String, example = "profile_123")]
+    pub profile_id: common_utils::id_type::ProfileId,
+    /// Transaction type of the routing algorithm, defaults to `payment`
+    #[schema(example = "payment")]
+    pub transaction_type: Option<TransactionType>,
+    #[serde(flatten)]
+    pub action: RoutingCanaryAction,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum RoutingCanaryAction {
+    /// Change the percentage of the traffic routed through the canary algorithm
+    Ramp {
+        /// Must be between 1 and 99
+        #[schema(example = 50, minimum = 1, maximum = 99)]
+        traffic_percentage: u8,
+    },
+    /// Make the canary algorithm the active algorithm for all of the traffic
+    Promote,
+    /// Stop routing traffic through the canary algorithm
+    Rollback,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct RoutingCanaryResponse {
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    /// The algorithm routing the traffic which is not routed through the canary
+    #[schema(value_type = Option<String>)]
+    pub algorithm_id: Option<common_utils::id_type::RoutingId>,
+    pub canary: Option<RoutingCanary>,
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::borrow::Cow;
+
+    use super::*;
+
+    fn routing_id(id: &'static str) -> common_utils::id_type::RoutingId {
+        common_utils::id_type::RoutingId::try_from(Cow::Borrowed(id)).unwrap()
+    }
+
+    fn algorithm_ref_with_canary() -> RoutingAlgorithmRef {
+        let mut algorithm_ref = RoutingAlgorithmRef::default();
+        algorithm_ref.update_algorithm_id(routing_id("routing_active"));
+        algorithm_ref.update_canary(routing_id("routing_canary"), 10);
+        algorithm_ref
+    }
+
+    #[test]
+    fn test_canary_buckets() {
+        let algorithm_ref = algorithm_ref_with_canary();
+
+        assert_eq!(
+            algorithm_ref.get_algorithm_id_for_bucket(0),
+            Some(&routing_id("routing_canary"))
+        );
+        assert_eq!(
+            algorithm_ref.get_algorithm_id_for_bucket(9),
+            Some(&routing_id("routing_canary"))
+        );
+        assert_eq!(
+            algorithm_ref.get_algorithm_id_for_bucket(10),
+            Some(&routing_id("routing_active"))
+        );
+        assert_eq!(
+            algorithm_ref.get_algorithm_id_for_bucket(99),
+            Some(&routing_id("routing_active"))
+        );
+    }
+
+    #[test]
+    fn test_ramp_canary() {
+        let mut algorithm_ref = algorithm_ref_with_canary();
+
+        let canary = algorithm_ref.ramp_canary(50).unwrap();
+        assert_eq!(canary.traffic_percentage, 50);
+        assert_eq!(
+            algorithm_ref.get_algorithm_id_for_bucket(49),
+            Some(&routing_id("routing_canary"))
+        );
+
+        assert!(RoutingAlgorithmRef::default().ramp_canary(50).is_none());
+    }
+
+    #[test]
+    fn test_promote_canary() {
+        let mut algorithm_ref = algorithm_ref_with_canary();
+
+        assert_eq!(
+            algorithm_ref.promote_canary(),
+            Some(routing_id("routing_canary"))
+        );
+        assert_eq!(
+            algorithm_ref.algorithm_id,
+            Some(routing_id("routing_canary"))
+        );
+        assert!(algorithm_ref.canary.is_none());
+        assert!(algorithm_ref.promote_canary().is_none());
+    }
+
+    #[test]
+    fn test_rollback_canary() {
+        let mut algorithm_ref = algorithm_ref_with_canary();
+
+        let canary = algorithm_ref.rollback_canary().unwrap();
+        assert_eq!(canary.algorithm_id, routing_id("routing_canary"));
+        assert_eq!(
+            algorithm_ref.algorithm_id,
+            Some(routing_id("routing_active"))
+        );
+        assert_eq!(
+            algorithm_ref.get_algorithm_id_for_bucket(0),
+            Some(&routing_id("routing_active"))
+        );
+        assert!(algorithm_ref.rollback_canary().is_none());
+    }
+
+    #[test]
+    fn test_update_algorithm_id_ends_canary() {
+        let mut algorithm_ref = algorithm_ref_with_canary();
+
+        algorithm_ref.update_algorithm_id(routing_id("routing_other"));
+        assert!(algorithm_ref.canary.is_none());
+        assert_eq!(
+            algorithm_ref.get_algorithm_id_for_bucket(0),
+            Some(&routing_id("routing_other"))
+        );
+    }
+
+    fn comparison() -> ast::Comparison {
+        ast::Comparison {
+            lhs: "amount".to_string(),
+            comparison: ast::ComparisonType::GreaterThan,
+            value: ast::ValueType::Number(common_utils::types::MinorUnit::new(100)),
+            metadata: Default::default(),
+        }
+    }
+
+    fn statement(comparisons: usize, nested: Option<Vec<ast::IfStatement>>) -> ast::IfStatement {
+        ast::IfStatement {
+            condition: (0..comparisons).map(|_| comparison()).collect(),
+            nested,
+        }
+    }
+
+    fn program(rules: Vec<Vec<ast::IfStatement>>) -> Program<ConnectorSelection> {
+        Program {
+            default_selection: ConnectorSelection::Priority(Vec::new()),
+            rules: rules
+                .into_iter()
+                .enumerate()
+                .map(|(index, statements)| ast::Rule {
+                    name: format!("rule_{index}"),
+                    connector_selection: ConnectorSelection::Priority(Vec::new()),
+                    statements,
+                })
+                .collect(),
+            metadata: Default::default(),
+        }
+    }
+
+    fn parse_straight_through(
+        program: Program<ConnectorSelection>,
+    ) -> Result<StraightThroughAlgorithm, error_stack::Report<ParsingError>> {
+        StraightThroughAlgorithm::try_from(StraightThroughAlgorithmSerde::Direct(
+            StraightThroughAlgorithmInner::Advanced(program),
+        ))
+    }
+
+    #[test]
+    fn test_program_comparison_count() {
+        let nested_program = program(vec![
+            vec![statement(2, None), statement(1, None)],
+            vec![statement(
+                1,
+                Some(vec![statement(2, Some(vec![statement(3, None)]))]),
+            )],
+        ]);
+
+        assert_eq!(get_program_comparison_count(&nested_program), 9);
+        assert_eq!(get_program_comparison_count(&program(Vec::new())), 0);
+    }
+
+    #[test]
+    fn test_straight_through_program_rule_limit() {
+        let rules = |count: usize| -> Vec<Vec<ast::IfStatement>> {
+            (0..count).map(|_| vec![statement(1, None)]).collect()
+        };
+
+        assert!(parse_straight_through(program(rules(MAX_STRAIGHT_THROUGH_PROGRAM_RULES))).is_ok());
+        assert!(
+            parse_straight_through(program(rules(MAX_STRAIGHT_THROUGH_PROGRAM_RULES + 1))).is_err()
+        );
+    }
+
+    #[test]
+    fn test_straight_through_program_comparison_limit() {
+        let nested = |count: usize| {
+            program(vec![vec![statement(
+                1,
+                Some(vec![statement(count - 1, None)]),
+            )]])
+        };
+
+        assert!(parse_straight_through(nested(MAX_STRAIGHT_THROUGH_PROGRAM_COMPARISONS)).is_ok());
+        assert!(
+            parse_straight_through(nested(MAX_STRAIGHT_THROUGH_PROGRAM_COMPARISONS + 1)).is_err()
+        );
+    }
+}
@@ -0,0 +1,212 @@
+use std::collections::HashSet;
+
+use common_enums::{EventType, OutgoingWebhookEndpointStatus, OutgoingWebhookSigningVersion};
+use common_utils::{
+    events::{ApiEventMetric, ApiEventsType},
+    id_type,
+};
+use hyperswitch_masking::Secret;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+/// The request body for creating an outgoing webhook endpoint for a business profile.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookEndpointCreateRequest {
+    /// The URL to which webhooks are delivered
+    #[schema(value_type = String, example = "https://www.example.com/webhooks")]
+    pub webhook_url: Secret<String>,
+
+    /// The secret used to sign webhooks delivered to the endpoint. A secret is generated when not
+    /// provided. Endpoints signed with `standard_webhooks` require a base64 encoded secret of 24 to
+    /// 64 bytes prefixed with `whsec_`
+    #[schema(value_type = Option<String>)]
+    pub secret: Option<Secret<String>>,
+
+    /// The scheme used to sign webhooks delivered to the endpoint, defaults to `legacy`
+    #[schema(value_type = Option<OutgoingWebhookSigningVersion>, example = "standard_webhooks")]
+    pub signing_version: Option<OutgoingWebhookSigningVersion>,
+
+    /// The events delivered to the endpoint
+    #[schema(value_type = Vec<EventType>, example = json!(["payment_succeeded", "refund_succeeded"]))]
+    pub enabled_events: HashSet<EventType>,
+
+    /// The status of the endpoint, defaults to `active`
+    #[schema(value_type = Option<OutgoingWebhookEndpointStatus>, example = "active")]
+    pub status: Option<OutgoingWebhookEndpointStatus>,
+}
+
+/// The request body for updating an outgoing webhook endpoint of a business profile.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookEndpointUpdateRequest {
+    /// The URL to which webhooks are delivered
+    #[schema(value_type = Option<String>, example = "https://www.example.com/webhooks")]
+    pub webhook_url: Option<Secret<String>>,
+
+    /// The secret used to sign webhooks delivered to the endpoint. The current secret is replaced
+    /// immediately, use secret rotation to keep signing with it for a while
+    #[schema(value_type = Option<String>)]
+    pub secret: Option<Secret<String>>,
+
+    /// The scheme used to sign webhooks delivered to the endpoint
+    #[schema(value_type = Option<OutgoingWebhookSigningVersion>, example = "standard_webhooks")]
+    pub signing_version: Option<OutgoingWebhookSigningVersion>,
+
+    /// The events delivered to the endpoint. Replaces the events currently subscribed to
+    #[schema(value_type = Option<Vec<EventType>>, example = json!(["payment_succeeded"]))]
+    pub enabled_events: Option<HashSet<EventType>>,
+
+    /// The status of the endpoint. Deprecated endpoints cannot be reactivated
+    #[schema(value_type = Option<OutgoingWebhookEndpointStatus>, example = "inactive")]
+    pub status: Option<OutgoingWebhookEndpointStatus>,
+}
+
+/// The response body for an outgoing webhook endpoint of a business profile.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct WebhookEndpointResponse {
+    /// The identifier for the webhook endpoint
+    #[schema(value_type = String, max_length = 64, example = "whe_Ag4bxcWahaDJkPn2u6NQ")]
+    pub webhook_endpoint_id: id_type::WebhookEndpointId,
+
+    /// The identifier for the business profile the endpoint belongs to
+    #[schema(value_type = String, max_length = 64, example = "pro_abcdefghijklmnopqrst")]
+    pub profile_id: id_type::ProfileId,
+
+    /// The URL to which webhooks are delivered
+    #[schema(value_type = String, example = "https://www.example.com/webhooks")]
+    pub webhook_url: Secret<String>,
+
+    /// The events delivered to the endpoint
+    #[schema(value_type = Vec<EventType>, example = json!(["payment_succeeded", "refund_succeeded"]))]
+    pub enabled_events: HashSet<EventType>,
+
+    /// The status of the endpoint
+    #[schema(value_type = OutgoingWebhookEndpointStatus, example = "active")]
+    pub status: OutgoingWebhookEndpointStatus,
+
+    /// The scheme used to sign webhooks delivered to the endpoint
+    #[schema(value_type = OutgoingWebhookSigningVersion, example = "standard_webhooks")]
+    pub signing_version: OutgoingWebhookSigningVersion,
+
+    /// The time until which webhooks are also signed with the secrets replaced by a rotation
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub previous_secrets_expire_at: Option<PrimitiveDateTime>,
+
+    /// The secret used to sign webhooks delivered to the endpoint. Only returned when the
+    /// endpoint is created or its secret is rotated
+    #[schema(value_type = Option<String>)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret: Option<Secret<String>>,
+}
+
+/// The request body for rotating the secret of an outgoing webhook endpoint signed with
+/// `standard_webhooks`.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookEndpointSecretRotateRequest {
+    /// The new secret, a base64 encoded secret of 24 to 64 bytes prefixed with `whsec_`. A secret
+    /// is generated when not provided
+    #[schema(value_type = Option<String>)]
+    pub secret: Option<Secret<String>>,
+
+    /// The number of seconds for which webhooks are also signed with the current secret, defaults
+    /// to a day
+    #[schema(example = 86400)]
+    pub expire_current_secret_in_seconds: Option<u32>,
+}
+
+/// The response body for deleting an outgoing webhook endpoint of a business profile.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct WebhookEndpointDeleteResponse {
+    /// The identifier for the webhook endpoint
+    #[schema(value_type = String, max_length = 64, example = "whe_Ag4bxcWahaDJkPn2u6NQ")]
+    pub webhook_endpoint_id: id_type::WebhookEndpointId,
+
+    /// The identifier for the business profile the endpoint belonged to
+    #[schema(value_type = String, max_length = 64, example = "pro_abcdefghijklmnopqrst")]
+    pub profile_id: id_type::ProfileId,
+
+    /// Whether the endpoint was deleted
+    #[schema(example = true)]
+    pub deleted: bool,
+}
+
+/// The delivery health of an outgoing webhook endpoint
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct WebhookEndpointHealthResponse {
+    /// The identifier for the webhook endpoint
+    #[schema(value_type = String, max_length = 64, example = "whe_Ag4bxcWahaDJkPn2u6NQ")]
+    pub webhook_endpoint_id: id_type::WebhookEndpointId,
+
+    /// The identifier for the business profile the endpoint belongs to
+    #[schema(value_type = String, max_length = 64, example = "pro_abcdefghijklmnopqrst")]
+    pub profile_id: id_type::ProfileId,
+
+    /// The status of the endpoint
+    #[schema(value_type = OutgoingWebhookEndpointStatus, example = "active")]
+    pub status: OutgoingWebhookEndpointStatus,
+
+    /// The number of deliveries which failed since the last successful delivery. The endpoint is
+    /// paused once this reaches the configured threshold
+    #[schema(example = 3)]
+    pub consecutive_failures: u32,
+
+    /// The number of deliveries attempted within the current health window
+    #[schema(example = 120)]
+    pub delivery_count: u32,
+
+    /// The number of deliveries which failed within the current health window
+    #[schema(example = 6)]
+    pub failure_count: u32,
+
+    /// The ratio of failed deliveries to attempted deliveries within the current health window,
+    /// absent when no delivery was attempted
+    #[schema(example = 0.05)]
+    pub failure_rate: Option<f64>,
+
+    /// The time of the last successful delivery
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub last_success_at: Option<PrimitiveDateTime>,
+
+    /// The time of the last failed delivery
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub last_failure_at: Option<PrimitiveDateTime>,
+
+    /// The number of events queued while the endpoint was paused, which are delivered once the
+    /// endpoint is active again
+    #[schema(example = 0)]
+    pub queued_events_count: usize,
+}
+
+impl ApiEventMetric for WebhookEndpointCreateRequest {}
+impl ApiEventMetric for WebhookEndpointUpdateRequest {}
+impl ApiEventMetric for WebhookEndpointSecretRotateRequest {}
+
+impl ApiEventMetric for WebhookEndpointResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::WebhookEndpoint {
+            webhook_endpoint_id: self.webhook_endpoint_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for WebhookEndpointDeleteResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::WebhookEndpoint {
+            webhook_endpoint_id: self.webhook_endpoint_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for WebhookEndpointHealthResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::WebhookEndpoint {
+            webhook_endpoint_id: self.webhook_endpoint_id.clone(),
+        })
+    }
+}
@@ -1,6 +1,9 @@
 use std::collections::HashSet;
 
-use common_enums::{EventClass, EventRecipient, EventType, WebhookDeliveryAttempt};
+use common_enums::{
+    EventClass, EventRecipient, EventType, IncomingWebhookRejectionReason, IncomingWebhookStatus,
+    WebhookDeliveryAttempt,
+};
 use hyperswitch_masking::Secret;
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
@@ -43,6 +46,10 @@ pub struct EventListConstraints {
     pub is_delivered: Option<bool>,
     /// Filter all events by the recipient of the webhook.
     pub recipient: Option<EventRecipient>,
+    /// Filter all events delivered to the specified webhook endpoint. Requires `profile_id` to be
+    /// specified.
+    #[schema(value_type = Option<String>)]
+    pub webhook_endpoint_id: Option<common_utils::id_type::WebhookEndpointId>,
 }
 
 #[derive(Debug)]
@@ -56,6 +63,7 @@ pub enum EventListConstraintsInternal {
         event_types: Option<HashSet<EventType>>,
         is_delivered: Option<bool>,
         recipient: Option<EventRecipient>,
+        webhook_endpoint_id: Option<common_utils::id_type::WebhookEndpointId>,
     },
     ObjectIdFilter {
         object_id: String,
@@ -103,6 +111,11 @@ pub struct EventListItemResponse {
     #[schema(max_length = 64, value_type = Option<String>)]
     pub processor_merchant_id: Option<common_utils::id_type::MerchantId>,
 
+    /// The identifier for the webhook endpoint the event was delivered to, if the event was not
+    /// delivered to the webhook URL configured on the business profile.
+    #[schema(max_length = 64, value_type = Option<String>)]
+    pub webhook_endpoint_id: Option<common_utils::id_type::WebhookEndpointId>,
+
     /// Time at which the event was created.
     #[schema(example = "2022-09-10T10:11:12Z")]
     #[serde(with = "common_utils::custom_serde::iso8601")]
@@ -240,3 +253,272 @@ impl common_utils::events::ApiEventMetric for WebhookDeliveryRetryRequestInterna
         })
     }
 }
+
+/// The request body for redelivering the events matching the specified constraints.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct EventsBulkRedeliveryRequest {
+    /// Redeliver only events of the specified business profile.
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+
+    /// Redeliver only events created after the specified time.
+    #[schema(example = "2022-09-10T10:00:00Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_after: PrimitiveDateTime,
+
+    /// Redeliver only events created before the specified time.
+    #[schema(example = "2022-09-10T14:00:00Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_before: PrimitiveDateTime,
+
+    /// Redeliver only events of the specified types.
+    pub event_types: Option<HashSet<EventType>>,
+
+    /// Redeliver only events whose delivery succeeded (`true`) or failed (`false`).
+    pub is_delivered: Option<bool>,
+
+    /// The number of events to redeliver per minute. Defaults to, and cannot exceed, the maximum
+    /// configured for the deployment.
+    #[schema(minimum = 1, example = 60)]
+    pub events_per_minute: Option<u32>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum EventsBulkRedeliveryStatus {
+    /// The redelivery is queued or running
+    Pending,
+    /// All matching events have been redelivered
+    Completed,
+    /// The redelivery could not be completed
+    Failed,
+}
+
+/// The status and progress of a bulk redelivery of events.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EventsBulkRedeliveryResponse {
+    /// The identifier for the bulk redelivery.
+    #[schema(example = "redelivery_4fZbq6zYd2Jk8Xc1Tn0r")]
+    pub redelivery_id: String,
+
+    /// The identifier for the Merchant Account.
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// The identifier for the Business Profile whose events are redelivered.
+    #[schema(max_length = 64, value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+
+    /// The status of the redelivery.
+    pub status: EventsBulkRedeliveryStatus,
+
+    /// The number of events to redeliver per minute.
+    pub events_per_minute: u32,
+
+    /// The number of events matching the constraints when the redelivery was requested.
+    pub total_count: i64,
+
+    /// The number of events redelivered successfully so far.
+    pub succeeded_count: u32,
+
+    /// The number of events whose redelivery failed so far.
+    pub failed_count: u32,
+
+    /// Time at which the redelivery was requested.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+
+    /// Time at which the progress of the redelivery was last updated.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified: PrimitiveDateTime,
+}
+
+impl common_utils::events::ApiEventMetric for EventsBulkRedeliveryResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct EventsBulkRedeliveryRequestInternal {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub request: EventsBulkRedeliveryRequest,
+}
+
+impl common_utils::events::ApiEventMetric for EventsBulkRedeliveryRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct EventsBulkRedeliveryRetrieveRequestInternal {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub redelivery_id: String,
+}
+
+impl common_utils::events::ApiEventMetric for EventsBulkRedeliveryRetrieveRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+/// The constraints to apply when filtering stored incoming webhooks.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, ToSchema)]
+pub struct IncomingWebhookListConstraints {
+    /// Filter incoming webhooks received after the specified time.
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_after: Option<PrimitiveDateTime>,
+
+    /// Filter incoming webhooks received before the specified time.
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_before: Option<PrimitiveDateTime>,
+
+    /// Include at most the specified number of incoming webhooks.
+    pub limit: Option<u16>,
+
+    /// Include incoming webhooks after the specified offset.
+    pub offset: Option<u16>,
+
+    /// Filter incoming webhooks sent by the specified connector.
+    #[schema(example = "stripe")]
+    pub connector: Option<String>,
+
+    /// Filter incoming webhooks by the outcome of processing them.
+    pub status: Option<IncomingWebhookStatus>,
+}
+
+/// The request information (method, URI, headers and body) of an incoming webhook.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct IncomingWebhookRequestContent {
+    /// The HTTP method of the webhook.
+    #[schema(example = "POST")]
+    pub method: String,
+
+    /// The URI the webhook was received on, including the query string.
+    #[schema(value_type = String)]
+    pub uri: Secret<String>,
+
+    /// The headers of the webhook, with base64 encoded values, since header values are not
+    /// required to be valid UTF-8.
+    #[schema(
+        value_type = Vec<(String, String)>,
+        example = json!([["content-type", "YXBwbGljYXRpb24vanNvbg=="], ["content-length", "MTAyNA=="]]))
+    ]
+    pub headers: Vec<(String, Secret<String>)>,
+
+    /// The base64 encoded body of the webhook, stored as received so that signatures computed
+    /// over the raw bytes can be verified when the webhook is processed again.
+    #[schema(value_type = String)]
+    pub body: Secret<String>,
+}
+
+/// The response body for each item when listing incoming webhooks.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct IncomingWebhookResponse {
+    /// The identifier for the incoming webhook.
+    #[schema(max_length = 64, example = "whlog_4fZbq6zYd2Jk8Xc1Tn0r")]
+    pub incoming_webhook_id: String,
+
+    /// The identifier for the Merchant Account.
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// The connector that sent the webhook, if it could be resolved.
+    #[schema(example = "stripe")]
+    pub connector: Option<String>,
+
+    /// The identifier for the merchant connector account the webhook was processed with.
+    #[schema(max_length = 64, value_type = Option<String>)]
+    pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+
+    /// Indicates whether the webhook was received on the relay webhook URL.
+    pub is_relay_webhook: bool,
+
+    /// Indicates whether the source of the webhook was verified.
+    pub source_verified: Option<bool>,
+
+    /// The outcome of processing the webhook.
+    pub status: IncomingWebhookStatus,
+
+    /// The error that occurred while processing the webhook, if any.
+    pub error_message: Option<String>,
+
+    /// The reason the webhook was rejected, if it was.
+    pub rejection_reason: Option<IncomingWebhookRejectionReason>,
+
+    /// Time at which the webhook was received.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+
+    /// Time at which the webhook was last processed.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified: PrimitiveDateTime,
+}
+
+impl common_utils::events::ApiEventMetric for IncomingWebhookResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+/// The response body of the list incoming webhooks api call.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TotalIncomingWebhooksResponse {
+    /// The list of incoming webhooks
+    pub incoming_webhooks: Vec<IncomingWebhookResponse>,
+    /// Count of total incoming webhooks matching the constraints
+    pub total_count: i64,
+}
+
+impl common_utils::events::ApiEventMetric for TotalIncomingWebhooksResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self
+                .incoming_webhooks
+                .first()
+                .map(|incoming_webhook| incoming_webhook.merchant_id.clone())?,
+        })
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct IncomingWebhookListRequestInternal {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub constraints: IncomingWebhookListConstraints,
+}
+
+impl common_utils::events::ApiEventMetric for IncomingWebhookListRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct IncomingWebhookReprocessRequestInternal {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub incoming_webhook_id: String,
+}
+
+impl common_utils::events::ApiEventMetric for IncomingWebhookReprocessRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
@@ -207,6 +207,10 @@ pub enum WebhookResponseTracker {
         relay_id: common_utils::id_type::RelayId,
         status: common_enums::RelayStatus,
     },
+    /// The webhook was acknowledged but deliberately not applied
+    Rejected {
+        reason: common_enums::IncomingWebhookRejectionReason,
+    },
 }
 
 impl WebhookResponseTracker {
@@ -216,7 +220,10 @@ impl WebhookResponseTracker {
             Self::Payment { payment_id, .. }
             | Self::Refund { payment_id, .. }
             | Self::Dispute { payment_id, .. } => Some(payment_id.to_owned()),
-            Self::NoEffect | Self::Mandate { .. } | Self::PaymentMethod { .. } => None,
+            Self::NoEffect
+            | Self::Mandate { .. }
+            | Self::PaymentMethod { .. }
+            | Self::Rejected { .. } => None,
             #[cfg(feature = "payouts")]
             Self::Payout { .. } => None,
             Self::Relay { .. } => None,
@@ -242,7 +249,8 @@ impl WebhookResponseTracker {
             | Self::Dispute { .. }
             | Self::NoEffect
             | Self::Mandate { .. }
-            | Self::Relay { .. } => None,
+            | Self::Relay { .. }
+            | Self::Rejected { .. } => None,
             #[cfg(feature = "payouts")]
             Self::Payout { .. } => None,
         }
@@ -254,7 +262,7 @@ impl WebhookResponseTracker {
             Self::Payment { payment_id, .. }
             | Self::Refund { payment_id, .. }
             | Self::Dispute { payment_id, .. } => Some(payment_id.to_owned()),
-            Self::NoEffect | Self::Mandate { .. } => None,
+            Self::NoEffect | Self::Mandate { .. } | Self::Rejected { .. } => None,
             #[cfg(feature = "payouts")]
             Self::Payout { .. } => None,
             Self::Relay { .. } => None,
@@ -1895,6 +1895,7 @@ impl EventClass {
                 EventType::PaymentAuthorized,
                 EventType::PaymentCaptured,
                 EventType::PaymentExpired,
+                EventType::PaymentAuthorizationExpiring,
                 EventType::ActionRequired,
                 EventType::SurchargePaymentSucceeded,
             ]),
@@ -1958,6 +1959,8 @@ pub enum EventType {
     PaymentPartiallyAuthorized,
     PaymentCaptured,
     PaymentExpired,
+    /// The authorization of the payment is about to expire without being captured
+    PaymentAuthorizationExpiring,
     ActionRequired,
     RefundSucceeded,
     RefundFailed,
@@ -2028,6 +2031,68 @@ pub enum WebhookDeliveryAttempt {
     ManualRetry,
 }
 
+/// The outcome of processing an incoming connector webhook
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum IncomingWebhookStatus {
+    /// The webhook has been stored and is yet to be processed
+    Received,
+    /// The webhook was applied to the resource it refers to
+    Processed,
+    /// The webhook was acknowledged without being applied, for instance when the event is not
+    /// subscribed to
+    Skipped,
+    /// The webhook could not be processed
+    Failed,
+    /// The webhook was acknowledged but deliberately not applied, see
+    /// `IncomingWebhookRejectionReason`
+    Rejected,
+}
+
+/// The reason an incoming connector webhook was rejected without being applied
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum IncomingWebhookRejectionReason {
+    /// The connector delivered an event with the same ID before
+    DuplicateEvent,
+    /// The event would move the payment attempt back to an earlier status
+    PaymentStatusRegression,
+    /// The event would move the refund back to an earlier status
+    RefundStatusRegression,
+    /// The event would move the dispute back to an earlier stage or status
+    DisputeStatusRegression,
+    /// The event would move the payout back to an earlier status
+    PayoutStatusRegression,
+}
+
 #[derive(
     Clone,
     Copy,
@@ -2049,6 +2114,34 @@ pub enum OutgoingWebhookEndpointStatus {
     Inactive,
     /// The webhook endpoint is deprecated and can no longer be reactivated.
     Deprecated,
+    /// The webhook endpoint was paused after repeated delivery failures. Events are queued and
+    /// delivered once the endpoint is activated again.
+    Paused,
+}
+
+/// The scheme used to sign the webhooks delivered to a webhook endpoint
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum OutgoingWebhookSigningVersion {
+    /// HMAC-SHA512 of the body in the `X-Webhook-Signature-512` header.
+    #[default]
+    Legacy,
+    /// Signatures following the Standard Webhooks specification, in the `webhook-id`,
+    /// `webhook-timestamp` and `webhook-signature` headers.
+    StandardWebhooks,
 }
 
 // TODO: This decision about using KV mode or not,
@@ -10721,6 +10814,13 @@ pub enum ProcessTrackerRunner {
     PayoutSyncWorkFlow,
     BatchBlocklistUpload,
     NetworkTokenizationWorkflow,
+    ReportGenerationWorkflow,
+    RoutingBacktestWorkflow,
+    ScheduledCaptureWorkflow,
+    AuthorizationExpiryWorkflow,
+    OutgoingWebhookBulkRedeliveryWorkflow,
+    OutgoingWebhookEndpointFlushWorkflow,
+    SplitPaymentReversalWorkflow,
 }
 
 #[derive(
@@ -0,0 +1,48 @@
+//! Types for restricted API keys
+
+use common_enums::{PermissionScope, Resource};
+use common_utils::impl_to_sql_from_sql_json;
+use diesel::{sql_types::Jsonb, AsExpression, FromSqlRow};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Access granted to a restricted API key on a single resource
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ApiKeyPermission {
+    /// The resource which can be accessed with the API key
+    #[schema(value_type = String, example = "payment")]
+    pub resource: Resource,
+    /// The level of access granted on the resource, `write` access includes `read` access
+    #[schema(value_type = String, example = "read")]
+    pub scope: PermissionScope,
+}
+
+/// Permissions of a restricted API key. Keys without permissions have full access to the merchant
+/// account.
+#[derive(
+    Clone, Debug, Eq, PartialEq, Serialize, Deserialize, FromSqlRow, AsExpression, ToSchema,
+)]
+#[diesel(sql_type = Jsonb)]
+pub struct ApiKeyPermissions(Vec<ApiKeyPermission>);
+
+impl_to_sql_from_sql_json!(ApiKeyPermissions);
+
+impl ApiKeyPermissions {
+    /// Creates the permissions from the list of permissions granted on each resource
+    pub fn new(permissions: Vec<ApiKeyPermission>) -> Self {
+        Self(permissions)
+    }
+
+    /// Returns the list of permissions granted on each resource
+    pub fn get_permissions(&self) -> &[ApiKeyPermission] {
+        &self.0
+    }
+
+    /// Checks whether `scope` access is granted on `resource`
+    pub fn is_permitted(&self, resource: Resource, scope: PermissionScope) -> bool {
+        self.0
+            .iter()
+            .any(|permission| permission.resource == resource && permission.scope >= scope)
+    }
+}
@@ -0,0 +1,214 @@
+//! Types for the processing fees charged by connectors
+
+use common_enums::{CardNetwork, CardType, CountryAlpha2, Currency};
+use common_utils::{
+    consts::FEE_PERCENTAGE_PRECISION_LENGTH,
+    errors::{CustomResult, PercentageError},
+    impl_to_sql_from_sql_json,
+    types::{MinorUnit, Percentage},
+};
+use diesel::{sql_types::Jsonb, AsExpression, FromSqlRow};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Percentage of the payment amount charged as a fee
+pub type FeePercentage = Percentage<FEE_PERCENTAGE_PRECISION_LENGTH>;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, FromSqlRow, AsExpression, ToSchema)]
+#[diesel(sql_type = Jsonb)]
+#[serde(deny_unknown_fields)]
+/// Fees a connector charges for processing a payment, used to estimate the cost of routing a
+/// payment through the connector
+pub struct ConnectorFeeSchedule {
+    /// Rules evaluated in order, the fee of the first rule that matches the payment is applied
+    #[serde(default)]
+    pub rules: Vec<FeeScheduleRule>,
+
+    /// Fee applied when none of the rules match the payment
+    pub default_fee: ConnectorFee,
+}
+impl_to_sql_from_sql_json!(ConnectorFeeSchedule);
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, ToSchema)]
+#[serde(deny_unknown_fields)]
+/// A fee that applies to the payments matching all of the given conditions. A condition that is
+/// not set matches every payment.
+pub struct FeeScheduleRule {
+    /// Card networks the fee applies to
+    pub card_networks: Option<Vec<CardNetwork>>,
+
+    /// Card types the fee applies to
+    pub card_types: Option<Vec<CardType>>,
+
+    /// Countries of the card issuer the fee applies to
+    pub issuer_countries: Option<Vec<CountryAlpha2>>,
+
+    /// Payment currencies the fee applies to
+    pub currencies: Option<Vec<Currency>>,
+
+    /// Fee applied to the matching payments
+    pub fee: ConnectorFee,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+/// Pricing model of a connector fee
+pub enum ConnectorFee {
+    /// A percentage of the amount plus a fixed fee
+    Blended {
+        /// Percentage of the payment amount
+        #[schema(value_type = Percentage<FEE_PERCENTAGE_PRECISION_LENGTH>)]
+        percentage: FeePercentage,
+        /// Fixed fee in the minor unit of the payment currency
+        #[schema(value_type = i64, example = 30)]
+        fixed_fee: MinorUnit,
+    },
+    /// The interchange and scheme fees passed through, plus the markup of the connector
+    InterchangePlus {
+        /// Expected interchange and scheme fees, as a percentage of the payment amount
+        #[schema(value_type = Percentage<FEE_PERCENTAGE_PRECISION_LENGTH>)]
+        interchange_percentage: FeePercentage,
+        /// Markup of the connector, as a percentage of the payment amount
+        #[schema(value_type = Percentage<FEE_PERCENTAGE_PRECISION_LENGTH>)]
+        markup_percentage: FeePercentage,
+        /// Fixed fee in the minor unit of the payment currency
+        #[schema(value_type = i64, example = 10)]
+        fixed_fee: MinorUnit,
+    },
+}
+
+/// Details of a payment that decide which fee of a fee schedule applies to it
+#[derive(Debug, Clone)]
+pub struct FeeScheduleCriteria {
+    /// Amount of the payment
+    pub amount: MinorUnit,
+    /// Currency of the payment
+    pub currency: Currency,
+    /// Network of the card used for the payment
+    pub card_network: Option<CardNetwork>,
+    /// Type of the card used for the payment
+    pub card_type: Option<CardType>,
+    /// Country of the issuer of the card used for the payment
+    pub issuer_country: Option<CountryAlpha2>,
+}
+
+impl ConnectorFeeSchedule {
+    /// Get the fee that applies to a payment
+    pub fn get_applicable_fee(&self, criteria: &FeeScheduleCriteria) -> &ConnectorFee {
+        self.rules
+            .iter()
+            .find(|rule| rule.matches(criteria))
+            .map(|rule| &rule.fee)
+            .unwrap_or(&self.default_fee)
+    }
+
+    /// Calculate the fee the connector is expected to charge for a payment
+    pub fn calculate_fee(
+        &self,
+        criteria: &FeeScheduleCriteria,
+    ) -> CustomResult<MinorUnit, PercentageError> {
+        self.get_applicable_fee(criteria).calculate(criteria.amount)
+    }
+}
+
+impl FeeScheduleRule {
+    fn matches(&self, criteria: &FeeScheduleCriteria) -> bool {
+        fn matches_condition<T: PartialEq>(condition: Option<&Vec<T>>, value: Option<&T>) -> bool {
+            condition.is_none_or(|allowed| value.is_some_and(|value| allowed.contains(value)))
+        }
+
+        matches_condition(self.card_networks.as_ref(), criteria.card_network.as_ref())
+            && matches_condition(self.card_types.as_ref(), criteria.card_type.as_ref())
+            && matches_condition(
+                self.issuer_countries.as_ref(),
+                criteria.issuer_country.as_ref(),
+            )
+            && matches_condition(self.currencies.as_ref(), Some(&criteria.currency))
+    }
+}
+
+impl ConnectorFee {
+    /// Calculate the fee for the given amount
+    pub fn calculate(&self, amount: MinorUnit) -> CustomResult<MinorUnit, PercentageError> {
+        match self {
+            Self::Blended {
+                percentage,
+                fixed_fee,
+            } => Ok(percentage.apply_and_ceil_result(amount)? + *fixed_fee),
+            Self::InterchangePlus {
+                interchange_percentage,
+                markup_percentage,
+                fixed_fee,
+            } => Ok(interchange_percentage.apply_and_ceil_result(amount)?
+                + markup_percentage.apply_and_ceil_result(amount)?
+                + *fixed_fee),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn percentage(value: &str) -> FeePercentage {
+        FeePercentage::from_string(value.to_string()).expect("valid percentage")
+    }
+
+    fn criteria(card_network: Option<CardNetwork>) -> FeeScheduleCriteria {
+        FeeScheduleCriteria {
+            amount: MinorUnit::new(10000),
+            currency: Currency::USD,
+            card_network,
+            card_type: Some(CardType::Credit),
+            issuer_country: Some(CountryAlpha2::US),
+        }
+    }
+
+    fn fee_schedule() -> ConnectorFeeSchedule {
+        ConnectorFeeSchedule {
+            rules: vec![FeeScheduleRule {
+                card_networks: Some(vec![CardNetwork::AmericanExpress]),
+                card_types: None,
+                issuer_countries: None,
+                currencies: Some(vec![Currency::USD]),
+                fee: ConnectorFee::Blended {
+                    percentage: percentage("3.5"),
+                    fixed_fee: MinorUnit::new(30),
+                },
+            }],
+            default_fee: ConnectorFee::InterchangePlus {
+                interchange_percentage: percentage("1.8"),
+                markup_percentage: percentage("0.25"),
+                fixed_fee: MinorUnit::new(10),
+            },
+        }
+    }
+
+    #[test]
+    fn first_matching_rule_is_applied() {
+        let fee = fee_schedule()
+            .calculate_fee(&criteria(Some(CardNetwork::AmericanExpress)))
+            .expect("fee is calculated");
+
+        assert_eq!(fee, MinorUnit::new(380));
+    }
+
+    #[test]
+    fn default_fee_is_applied_when_no_rule_matches() {
+        let schedule = fee_schedule();
+
+        assert_eq!(
+            schedule
+                .calculate_fee(&criteria(Some(CardNetwork::Visa)))
+                .expect("fee is calculated"),
+            MinorUnit::new(215)
+        );
+        // A rule restricted to card networks doesn't match a payment without one
+        assert_eq!(
+            schedule
+                .calculate_fee(&criteria(None))
+                .expect("fee is calculated"),
+            MinorUnit::new(215)
+        );
+    }
+}
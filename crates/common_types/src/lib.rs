@@ -2,6 +2,8 @@
 
 #![warn(missing_docs, missing_debug_implementations)]
 
+pub mod api_keys;
+pub mod connector_fee_schedule;
 pub mod consts;
 pub mod customers;
 pub mod domain;
@@ -1707,3 +1707,187 @@ pub struct AppliedOfferDetailsV1 {
 }
 
 impl_to_sql_from_sql_json!(AppliedOfferDetails);
+
+/// Details of a dynamic currency conversion applied to a payment, persisted on `payment_attempt`
+/// as JSONB. The payment is charged in the currency of the customer at the rate locked by a
+/// quote, while the original amount and currency of the payment are retained.
+#[derive(
+    Clone,
+    Debug,
+    serde::Deserialize,
+    Eq,
+    ToSchema,
+    PartialEq,
+    serde::Serialize,
+    diesel::AsExpression,
+)]
+#[diesel(sql_type = Jsonb)]
+pub struct DccDetails {
+    /// Identifier of the quote the rate was locked with
+    pub quote_id: String,
+    /// Amount of the payment in the original currency, in minor units
+    #[schema(value_type = i64, example = 6540)]
+    pub original_amount: MinorUnit,
+    /// Original currency of the payment
+    #[schema(value_type = Currency, example = "USD")]
+    pub original_currency: enums::Currency,
+    /// Amount charged to the customer in the converted currency, in minor units
+    #[schema(value_type = i64, example = 6192)]
+    pub converted_amount: MinorUnit,
+    /// Currency the customer is charged in
+    #[schema(value_type = Currency, example = "EUR")]
+    pub converted_currency: enums::Currency,
+    /// Rate at which a major unit of the original currency is converted, markup included
+    #[schema(value_type = String, example = "0.946789")]
+    pub exchange_rate: Decimal,
+    /// Markup over the market rate, in basis points
+    #[schema(example = 300)]
+    pub markup_in_basis_points: u32,
+}
+
+impl_to_sql_from_sql_json!(DccDetails);
+
+impl DccDetails {
+    /// Converts an amount at the market rate and adds the markup. `market_converted_amount` is
+    /// the original amount converted at the market rate, in the major unit of the converted
+    /// currency. Returns `None` when the amounts cannot be represented.
+    pub fn new(
+        quote_id: String,
+        original_amount: MinorUnit,
+        original_currency: enums::Currency,
+        converted_currency: enums::Currency,
+        market_converted_amount: Decimal,
+        markup_in_basis_points: u32,
+    ) -> Option<Self> {
+        let basis_points_scale = Decimal::from(10_000u32);
+        let markup_factor = basis_points_scale
+            .checked_add(Decimal::from(markup_in_basis_points))?
+            .checked_div(basis_points_scale)?;
+
+        let converted_amount = market_converted_amount
+            .checked_mul(minor_unit_scale(converted_currency)?)?
+            .checked_mul(markup_factor)?
+            .round_dp_with_strategy(0, rust_decimal::RoundingStrategy::MidpointAwayFromZero)
+            .to_i64()
+            .map(MinorUnit::new)?;
+
+        let exchange_rate = Decimal::from(converted_amount.get_amount_as_i64())
+            .checked_div(minor_unit_scale(converted_currency)?)?
+            .checked_div(
+                Decimal::from(original_amount.get_amount_as_i64())
+                    .checked_div(minor_unit_scale(original_currency)?)?,
+            )?
+            .round_dp(6);
+
+        Some(Self {
+            quote_id,
+            original_amount,
+            original_currency,
+            converted_amount,
+            converted_currency,
+            exchange_rate,
+            markup_in_basis_points,
+        })
+    }
+
+    /// Converts an amount in the original currency to the converted currency at the locked rate.
+    /// The conversion is proportional to the amounts of the payment, so the full original amount
+    /// always converts to the amount the customer was charged.
+    pub fn convert_from_original_amount(&self, amount: MinorUnit) -> Option<MinorUnit> {
+        Decimal::from(amount.get_amount_as_i64())
+            .checked_mul(Decimal::from(self.converted_amount.get_amount_as_i64()))?
+            .checked_div(Decimal::from(self.original_amount.get_amount_as_i64()))?
+            .round_dp_with_strategy(0, rust_decimal::RoundingStrategy::MidpointAwayFromZero)
+            .to_i64()
+            .map(MinorUnit::new)
+    }
+
+    /// Converts an amount in the converted currency back to the original currency at the locked
+    /// rate, the inverse of [`Self::convert_from_original_amount`]. The amount the customer was
+    /// charged always converts back to the full original amount.
+    pub fn convert_to_original_amount(&self, amount: MinorUnit) -> Option<MinorUnit> {
+        Decimal::from(amount.get_amount_as_i64())
+            .checked_mul(Decimal::from(self.original_amount.get_amount_as_i64()))?
+            .checked_div(Decimal::from(self.converted_amount.get_amount_as_i64()))?
+            .round_dp_with_strategy(0, rust_decimal::RoundingStrategy::MidpointAwayFromZero)
+            .to_i64()
+            .map(MinorUnit::new)
+    }
+}
+
+fn minor_unit_scale(currency: enums::Currency) -> Option<Decimal> {
+    10_i64
+        .checked_pow(u32::from(currency.number_of_digits_after_decimal_point()))
+        .map(Decimal::from)
+}
+
+#[cfg(test)]
+mod dcc_details_tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn usd_to_eur_details() -> DccDetails {
+        // 65.40 USD at a market rate of 0.92 is 60.168 EUR, 3% markup makes it 61.97304 EUR
+        DccDetails::new(
+            "dcc_quote_1".to_string(),
+            MinorUnit::new(6540),
+            enums::Currency::USD,
+            enums::Currency::EUR,
+            Decimal::new(60168, 3),
+            300,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_converted_amount_includes_markup() {
+        let details = usd_to_eur_details();
+
+        assert_eq!(details.converted_amount, MinorUnit::new(6197));
+        assert_eq!(details.exchange_rate, Decimal::new(947554, 6));
+    }
+
+    #[test]
+    fn test_conversion_to_zero_decimal_currency() {
+        let details = DccDetails::new(
+            "dcc_quote_2".to_string(),
+            MinorUnit::new(1000),
+            enums::Currency::USD,
+            enums::Currency::JPY,
+            Decimal::new(149525, 2),
+            0,
+        )
+        .unwrap();
+
+        assert_eq!(details.converted_amount, MinorUnit::new(1495));
+        assert_eq!(details.exchange_rate, Decimal::new(1495, 1));
+    }
+
+    #[test]
+    fn test_convert_from_original_amount_uses_locked_rate() {
+        let details = usd_to_eur_details();
+
+        assert_eq!(
+            details.convert_from_original_amount(MinorUnit::new(6540)),
+            Some(MinorUnit::new(6197))
+        );
+        assert_eq!(
+            details.convert_from_original_amount(MinorUnit::new(1000)),
+            Some(MinorUnit::new(948))
+        );
+    }
+
+    #[test]
+    fn test_convert_to_original_amount_uses_locked_rate() {
+        let details = usd_to_eur_details();
+
+        assert_eq!(
+            details.convert_to_original_amount(MinorUnit::new(6197)),
+            Some(MinorUnit::new(6540))
+        );
+        assert_eq!(
+            details.convert_to_original_amount(MinorUnit::new(948)),
+            Some(MinorUnit::new(1000))
+        );
+    }
+}
@@ -69,6 +69,9 @@ pub const SURCHARGE_PERCENTAGE_PRECISION_LENGTH: u8 = 2;
 /// discount percentage maximum precision length
 pub const DISCOUNT_PERCENTAGE_PRECISION_LENGTH: u8 = 2;
 
+/// connector fee percentage maximum precision length
+pub const FEE_PERCENTAGE_PRECISION_LENGTH: u8 = 4;
+
 /// installment interest rate maximum precision length
 pub const INSTALLMENT_INTEREST_RATE_PRECISION_LENGTH: u8 = 2;
 
@@ -114,6 +114,29 @@ pub enum CryptoError {
     InvalidTagLength,
 }
 
+/// Errors for signing and verifying webhooks following the Standard Webhooks specification
+#[derive(Debug, thiserror::Error)]
+pub enum StandardWebhooksError {
+    /// The secret is not a base64 encoded key prefixed with `whsec_`
+    #[error("Invalid webhook secret")]
+    InvalidSecret,
+    /// A header required for verification is missing
+    #[error("Missing required header: {0}")]
+    MissingHeader(&'static str),
+    /// The timestamp header is not a UNIX timestamp
+    #[error("Invalid webhook timestamp")]
+    InvalidTimestamp,
+    /// The timestamp is too far from the current time
+    #[error("Webhook timestamp is outside the allowed tolerance")]
+    TimestampOutOfTolerance,
+    /// Signing the webhook failed
+    #[error("Failed to sign webhook")]
+    SigningFailed,
+    /// None of the signatures matches any of the secrets
+    #[error("No matching webhook signature found")]
+    NoMatchingSignature,
+}
+
 /// Errors for Qr code handling
 #[derive(Debug, thiserror::Error)]
 pub enum QrCodeError {
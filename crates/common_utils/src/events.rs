@@ -144,6 +144,12 @@ pub enum ApiEventsType {
     Chat,
     Oidc,
     CardIssuers,
+    RateLimit {
+        merchant_id: id_type::MerchantId,
+    },
+    WebhookEndpoint {
+        webhook_endpoint_id: id_type::WebhookEndpointId,
+    },
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Serialize)]
@@ -125,6 +125,11 @@ impl MerchantId {
         format!("guard_blocklist_for_{}", self.get_string_repr())
     }
 
+    /// get_rate_limits_key
+    pub fn get_rate_limits_key(&self) -> String {
+        format!("rate_limits_{}", self.get_string_repr())
+    }
+
     /// get_pre_routing_disabled_pm_pmt_key
     pub fn get_pre_routing_disabled_pm_pmt_key(&self) -> String {
         format!("pre_routing_disabled_pm_pmt_for_{}", self.get_string_repr())
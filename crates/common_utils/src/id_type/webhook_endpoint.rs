@@ -22,3 +22,11 @@ impl WebhookEndpointId {
         Self::try_from(std::borrow::Cow::from(webhook_endpoint_id))
     }
 }
+
+impl crate::events::ApiEventMetric for WebhookEndpointId {
+    fn get_api_event_type(&self) -> Option<crate::events::ApiEventsType> {
+        Some(crate::events::ApiEventsType::WebhookEndpoint {
+            webhook_endpoint_id: self.clone(),
+        })
+    }
+}
@@ -32,6 +32,7 @@ pub mod request;
 pub mod request_context;
 #[cfg(feature = "signals")]
 pub mod signals;
+pub mod standard_webhooks;
 pub mod transformers;
 pub mod types;
 /// Unified Connector Service (UCS) interface definitions.
@@ -0,0 +1,325 @@
+//! Signing and verification of webhooks following the
+//! [Standard Webhooks](https://www.standardwebhooks.com) specification.
+//!
+//! A webhook is signed with HMAC-SHA256 over `{webhook-id}.{webhook-timestamp}.{body}`. While a
+//! secret is being rotated the webhook is signed with every active secret and the signatures are
+//! sent space separated, so that receivers holding either secret accept it.
+
+use base64::Engine;
+use error_stack::{report, ResultExt};
+use hyperswitch_masking::{PeekInterface, Secret};
+
+use crate::{
+    consts::BASE64_ENGINE,
+    crypto::{self, SignMessage, VerifySignature},
+    errors::{CustomResult, StandardWebhooksError},
+    fp_utils,
+};
+
+/// Header carrying the identifier of the webhook, which stays the same across retries
+pub const WEBHOOK_ID_HEADER: &str = "webhook-id";
+
+/// Header carrying the UNIX timestamp at which the webhook was sent
+pub const WEBHOOK_TIMESTAMP_HEADER: &str = "webhook-timestamp";
+
+/// Header carrying the space separated signatures of the webhook
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "webhook-signature";
+
+/// Tolerance recommended by the specification between the timestamp of a webhook and the time it
+/// is verified at
+pub const DEFAULT_TIMESTAMP_TOLERANCE: time::Duration = time::Duration::minutes(5);
+
+const SECRET_PREFIX: &str = "whsec_";
+
+const SIGNATURE_VERSION: &str = "v1";
+
+const GENERATED_SECRET_LENGTH: usize = 32;
+
+const MIN_SECRET_LENGTH: usize = 24;
+
+const MAX_SECRET_LENGTH: usize = 64;
+
+/// A webhook signing secret
+#[derive(Clone, Debug)]
+pub struct WebhookSecret(Secret<Vec<u8>>);
+
+impl WebhookSecret {
+    /// Generates a random secret
+    pub fn generate() -> Self {
+        Self(Secret::new(
+            crypto::generate_cryptographically_secure_random_bytes::<GENERATED_SECRET_LENGTH>()
+                .to_vec(),
+        ))
+    }
+
+    /// Parses a secret from its encoded form, which is the base64 encoded key prefixed with
+    /// `whsec_`
+    pub fn from_encoded(secret: &str) -> CustomResult<Self, StandardWebhooksError> {
+        let key = secret
+            .strip_prefix(SECRET_PREFIX)
+            .ok_or(report!(StandardWebhooksError::InvalidSecret))
+            .attach_printable("Webhook secret is not prefixed with `whsec_`")?;
+        let key = BASE64_ENGINE
+            .decode(key)
+            .change_context(StandardWebhooksError::InvalidSecret)
+            .attach_printable("Webhook secret is not base64 encoded")?;
+
+        fp_utils::when(
+            !(MIN_SECRET_LENGTH..=MAX_SECRET_LENGTH).contains(&key.len()),
+            || {
+                Err(report!(StandardWebhooksError::InvalidSecret)).attach_printable(format!(
+                    "Webhook secret must be between {MIN_SECRET_LENGTH} and {MAX_SECRET_LENGTH} bytes long"
+                ))
+            },
+        )?;
+
+        Ok(Self(Secret::new(key)))
+    }
+
+    /// Encodes the secret as the base64 encoded key prefixed with `whsec_`
+    pub fn encode(&self) -> Secret<String> {
+        Secret::new(format!(
+            "{SECRET_PREFIX}{}",
+            BASE64_ENGINE.encode(self.0.peek())
+        ))
+    }
+
+    /// Signs a webhook, returning the signature in the `v1,{base64 signature}` form
+    pub fn sign(
+        &self,
+        webhook_id: &str,
+        timestamp: i64,
+        payload: &[u8],
+    ) -> CustomResult<String, StandardWebhooksError> {
+        let signed_content = get_signed_content(webhook_id, &timestamp.to_string(), payload);
+        let signature = crypto::HmacSha256
+            .sign_message(self.0.peek(), &signed_content)
+            .change_context(StandardWebhooksError::SigningFailed)?;
+
+        Ok(format!(
+            "{SIGNATURE_VERSION},{}",
+            BASE64_ENGINE.encode(signature)
+        ))
+    }
+
+    fn verify(&self, signature: &[u8], signed_content: &[u8]) -> bool {
+        crypto::HmacSha256
+            .verify_signature(self.0.peek(), signature, signed_content)
+            .unwrap_or(false)
+    }
+}
+
+/// The headers of a webhook signed following the Standard Webhooks specification
+#[derive(Clone, Copy, Debug)]
+pub struct WebhookHeaders<'a> {
+    /// Value of the `webhook-id` header
+    pub id: &'a str,
+    /// Value of the `webhook-timestamp` header
+    pub timestamp: &'a str,
+    /// Value of the `webhook-signature` header
+    pub signature: &'a str,
+}
+
+impl<'a> WebhookHeaders<'a> {
+    /// Reads the headers through a lookup returning the value of a header by its name
+    pub fn from_lookup(
+        lookup: impl Fn(&str) -> Option<&'a str>,
+    ) -> CustomResult<Self, StandardWebhooksError> {
+        let get_header = |name: &'static str| {
+            lookup(name).ok_or(report!(StandardWebhooksError::MissingHeader(name)))
+        };
+
+        Ok(Self {
+            id: get_header(WEBHOOK_ID_HEADER)?,
+            timestamp: get_header(WEBHOOK_TIMESTAMP_HEADER)?,
+            signature: get_header(WEBHOOK_SIGNATURE_HEADER)?,
+        })
+    }
+}
+
+fn get_signed_content(webhook_id: &str, timestamp: &str, payload: &[u8]) -> Vec<u8> {
+    let mut signed_content = format!("{webhook_id}.{timestamp}.").into_bytes();
+    signed_content.extend_from_slice(payload);
+    signed_content
+}
+
+/// Signs a webhook with each of the given secrets, returning the value of the
+/// `webhook-signature` header
+pub fn sign(
+    secrets: &[WebhookSecret],
+    webhook_id: &str,
+    timestamp: i64,
+    payload: &[u8],
+) -> CustomResult<String, StandardWebhooksError> {
+    fp_utils::when(secrets.is_empty(), || {
+        Err(report!(StandardWebhooksError::SigningFailed))
+            .attach_printable("No secret to sign the webhook with")
+    })?;
+
+    let signatures = secrets
+        .iter()
+        .map(|secret| secret.sign(webhook_id, timestamp, payload))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(signatures.join(" "))
+}
+
+/// Verifies a webhook against any of the given secrets, rejecting webhooks whose timestamp is
+/// further than `tolerance` from the current time. Passing both the old and the new secret lets
+/// receivers rotate their secret without rejecting webhooks in the meantime.
+pub fn verify(
+    secrets: &[WebhookSecret],
+    headers: WebhookHeaders<'_>,
+    payload: &[u8],
+    tolerance: time::Duration,
+) -> CustomResult<(), StandardWebhooksError> {
+    verify_at(
+        secrets,
+        headers,
+        payload,
+        tolerance,
+        crate::date_time::now_unix_timestamp(),
+    )
+}
+
+fn verify_at(
+    secrets: &[WebhookSecret],
+    headers: WebhookHeaders<'_>,
+    payload: &[u8],
+    tolerance: time::Duration,
+    now: i64,
+) -> CustomResult<(), StandardWebhooksError> {
+    let timestamp = headers
+        .timestamp
+        .parse::<i64>()
+        .change_context(StandardWebhooksError::InvalidTimestamp)?;
+    fp_utils::when(
+        timestamp.abs_diff(now) > tolerance.whole_seconds().unsigned_abs(),
+        || Err(report!(StandardWebhooksError::TimestampOutOfTolerance)),
+    )?;
+
+    let signed_content = get_signed_content(headers.id, headers.timestamp, payload);
+    let is_verified = headers
+        .signature
+        .split_whitespace()
+        .filter_map(|signature| signature.split_once(','))
+        .filter(|(version, _)| *version == SIGNATURE_VERSION)
+        .filter_map(|(_, signature)| BASE64_ENGINE.decode(signature).ok())
+        .any(|signature| {
+            secrets
+                .iter()
+                .any(|secret| secret.verify(&signature, &signed_content))
+        });
+
+    fp_utils::when(!is_verified, || {
+        Err(report!(StandardWebhooksError::NoMatchingSignature))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    const WEBHOOK_ID: &str = "msg_p5jXN8AQM9LWM0D4loKWxJek";
+    const TIMESTAMP: i64 = 1_614_265_330;
+    const PAYLOAD: &[u8] = br#"{"test": 2432232314}"#;
+
+    fn headers<'a>(timestamp: &'a str, signature: &'a str) -> WebhookHeaders<'a> {
+        WebhookHeaders {
+            id: WEBHOOK_ID,
+            timestamp,
+            signature,
+        }
+    }
+
+    #[test]
+    fn test_sign_matches_specification_example() {
+        let secret = WebhookSecret::from_encoded("whsec_MfKQ9r8GKYqrTwjUPD8ILPZIo2LaLaSw").unwrap();
+
+        assert_eq!(
+            secret.sign(WEBHOOK_ID, TIMESTAMP, PAYLOAD).unwrap(),
+            "v1,g0hM9SsE+OTPJTGt/tmIKtSyZlE3uFJELVlNIOLJ1OE="
+        );
+    }
+
+    #[test]
+    fn test_verify_accepts_any_active_secret() {
+        let old_secret = WebhookSecret::generate();
+        let new_secret = WebhookSecret::generate();
+        let signature = sign(
+            &[new_secret.clone(), old_secret.clone()],
+            WEBHOOK_ID,
+            TIMESTAMP,
+            PAYLOAD,
+        )
+        .unwrap();
+        let timestamp = TIMESTAMP.to_string();
+
+        for secret in [old_secret, new_secret] {
+            assert!(verify_at(
+                &[secret],
+                headers(&timestamp, &signature),
+                PAYLOAD,
+                DEFAULT_TIMESTAMP_TOLERANCE,
+                TIMESTAMP,
+            )
+            .is_ok());
+        }
+    }
+
+    #[test]
+    fn test_verify_rejects_tampered_payload_and_unknown_secret() {
+        let secret = WebhookSecret::generate();
+        let signature = sign(&[secret.clone()], WEBHOOK_ID, TIMESTAMP, PAYLOAD).unwrap();
+        let timestamp = TIMESTAMP.to_string();
+
+        assert!(verify_at(
+            &[secret],
+            headers(&timestamp, &signature),
+            br#"{"test": 1}"#,
+            DEFAULT_TIMESTAMP_TOLERANCE,
+            TIMESTAMP,
+        )
+        .is_err());
+        assert!(verify_at(
+            &[WebhookSecret::generate()],
+            headers(&timestamp, &signature),
+            PAYLOAD,
+            DEFAULT_TIMESTAMP_TOLERANCE,
+            TIMESTAMP,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_verify_rejects_stale_timestamp() {
+        let secret = WebhookSecret::generate();
+        let signature = sign(&[secret.clone()], WEBHOOK_ID, TIMESTAMP, PAYLOAD).unwrap();
+        let timestamp = TIMESTAMP.to_string();
+        let now = TIMESTAMP + DEFAULT_TIMESTAMP_TOLERANCE.whole_seconds() + 1;
+
+        assert!(matches!(
+            verify_at(
+                &[secret],
+                headers(&timestamp, &signature),
+                PAYLOAD,
+                DEFAULT_TIMESTAMP_TOLERANCE,
+                now,
+            )
+            .unwrap_err()
+            .current_context(),
+            StandardWebhooksError::TimestampOutOfTolerance
+        ));
+    }
+
+    #[test]
+    fn test_secret_round_trips_through_encoded_form() {
+        let secret = WebhookSecret::generate();
+        let decoded = WebhookSecret::from_encoded(secret.encode().peek()).unwrap();
+
+        assert_eq!(decoded.0.peek(), secret.0.peek());
+        assert!(WebhookSecret::from_encoded("MfKQ9r8GKYqrTwjUPD8ILPZIo2LaLaSw").is_err());
+    }
+}
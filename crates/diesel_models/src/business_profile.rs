@@ -98,6 +98,7 @@ pub struct Profile {
     pub default_fallback_routing: Option<pii::SecretSerdeValue>,
     pub surcharge_connector_details: Option<SurchargeConnectorDetails>,
     pub order_fulfillment_time: Option<i64>,
+    pub routing_timezone: Option<String>,
 }
 
 #[cfg(feature = "v1")]
@@ -166,6 +167,7 @@ pub struct ProfileNew {
     pub default_fallback_routing: Option<pii::SecretSerdeValue>,
     pub surcharge_connector_details: Option<SurchargeConnectorDetails>,
     pub order_fulfillment_time: Option<i64>,
+    pub routing_timezone: Option<String>,
 }
 
 #[cfg(feature = "v1")]
@@ -236,6 +238,7 @@ pub struct ProfileUpdateInternal {
     pub default_fallback_routing: Option<pii::SecretSerdeValue>,
     pub surcharge_connector_details: Option<SurchargeConnectorDetails>,
     pub order_fulfillment_time: Option<i64>,
+    pub routing_timezone: Option<String>,
 }
 
 /// Note: The order of fields in the struct is important.
@@ -318,6 +321,7 @@ pub struct Profile {
     pub default_fallback_routing: Option<pii::SecretSerdeValue>,
     pub surcharge_connector_details: Option<SurchargeConnectorDetails>,
     pub order_fulfillment_time: Option<i64>,
+    pub routing_timezone: Option<String>,
     pub routing_algorithm_id: Option<common_utils::id_type::RoutingId>,
     pub order_fulfillment_time_origin: Option<common_enums::OrderFulfillmentTimeOrigin>,
     pub frm_routing_algorithm_id: Option<String>,
@@ -393,6 +397,7 @@ pub struct ProfileNew {
     pub default_fallback_routing: Option<pii::SecretSerdeValue>,
     pub surcharge_connector_details: Option<SurchargeConnectorDetails>,
     pub order_fulfillment_time: Option<i64>,
+    pub routing_timezone: Option<String>,
     pub order_fulfillment_time_origin: Option<common_enums::OrderFulfillmentTimeOrigin>,
     pub frm_routing_algorithm_id: Option<String>,
     pub payout_routing_algorithm_id: Option<common_utils::id_type::RoutingId>,
@@ -457,6 +462,7 @@ pub struct ProfileUpdateInternal {
     pub billing_processor_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
     pub routing_algorithm_id: Option<common_utils::id_type::RoutingId>,
     pub order_fulfillment_time: Option<i64>,
+    pub routing_timezone: Option<String>,
     pub order_fulfillment_time_origin: Option<common_enums::OrderFulfillmentTimeOrigin>,
     pub frm_routing_algorithm_id: Option<String>,
     pub payout_routing_algorithm_id: Option<common_utils::id_type::RoutingId>,
@@ -561,6 +567,32 @@ pub struct MultipleWebhookDetail {
     pub webhook_url: Secret<String>,
     pub events: HashSet<common_enums::EventType>,
     pub status: common_enums::OutgoingWebhookEndpointStatus,
+    /// Encrypted with the key store of the merchant
+    pub secret: Option<Encryption>,
+    pub signing_version: Option<common_enums::OutgoingWebhookSigningVersion>,
+    pub previous_secrets: Option<Vec<ExpiringWebhookSecret>>,
+}
+
+impl MultipleWebhookDetail {
+    /// Secrets replaced by a rotation which have not expired at the given time
+    pub fn get_active_previous_secrets(
+        &self,
+        now: time::PrimitiveDateTime,
+    ) -> impl Iterator<Item = &ExpiringWebhookSecret> {
+        self.previous_secrets
+            .iter()
+            .flatten()
+            .filter(move |previous_secret| previous_secret.expires_at > now)
+    }
+}
+
+/// A secret replaced by a rotation, which webhooks keep being signed with until it expires
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct ExpiringWebhookSecret {
+    /// Encrypted with the key store of the merchant
+    pub secret: Encryption,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub expires_at: time::PrimitiveDateTime,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize, diesel::AsExpression)]
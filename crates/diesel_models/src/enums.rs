@@ -59,6 +59,7 @@ pub enum RoutingAlgorithmKind {
     Advanced,
     Dynamic,
     ThreeDsDecisionRule,
+    CostBased,
 }
 
 // Refund
@@ -30,6 +30,7 @@ pub struct EventNew {
     pub processor_merchant_id: Option<common_utils::id_type::MerchantId>,
     pub initiator_merchant_id: Option<common_utils::id_type::MerchantId>,
     pub recipient: Option<storage_enums::EventRecipient>,
+    pub webhook_endpoint_id: Option<common_utils::id_type::WebhookEndpointId>,
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -66,6 +67,7 @@ pub struct Event {
     pub processor_merchant_id: Option<common_utils::id_type::MerchantId>,
     pub initiator_merchant_id: Option<common_utils::id_type::MerchantId>,
     pub recipient: Option<storage_enums::EventRecipient>,
+    pub webhook_endpoint_id: Option<common_utils::id_type::WebhookEndpointId>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, AsExpression, diesel::FromSqlRow)]
@@ -0,0 +1,58 @@
+use common_utils::{custom_serde, encryption::Encryption};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::incoming_webhook_log};
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = incoming_webhook_log)]
+pub struct IncomingWebhookLogNew {
+    pub id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub connector_name_or_mca_id: String,
+    pub connector: Option<String>,
+    pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+    pub is_relay_webhook: bool,
+    pub request: Encryption,
+    pub source_verified: Option<bool>,
+    pub status: storage_enums::IncomingWebhookStatus,
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+    pub rejection_reason: Option<storage_enums::IncomingWebhookRejectionReason>,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = incoming_webhook_log)]
+pub struct IncomingWebhookLogUpdateInternal {
+    pub connector: Option<String>,
+    pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+    pub source_verified: Option<bool>,
+    pub status: Option<storage_enums::IncomingWebhookStatus>,
+    pub error_message: Option<Option<String>>,
+    pub modified_at: PrimitiveDateTime,
+    pub rejection_reason: Option<Option<storage_enums::IncomingWebhookRejectionReason>>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = incoming_webhook_log, primary_key(id), check_for_backend(diesel::pg::Pg))]
+pub struct IncomingWebhookLog {
+    pub id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    /// The connector name or merchant connector account ID the webhook was received for
+    pub connector_name_or_mca_id: String,
+    pub connector: Option<String>,
+    pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+    pub is_relay_webhook: bool,
+    /// The encrypted method, URI, headers and body of the webhook
+    pub request: Encryption,
+    pub source_verified: Option<bool>,
+    pub status: storage_enums::IncomingWebhookStatus,
+    pub error_message: Option<String>,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+    pub rejection_reason: Option<storage_enums::IncomingWebhookRejectionReason>,
+}
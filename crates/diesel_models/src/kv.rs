@@ -68,6 +68,10 @@ impl SerializableQuery {
         self.operation
     }
 
+    pub fn sql(&self) -> &str {
+        &self.sql
+    }
+
     async fn from_query<Q>(
         conn: &mut crate::PgPooledConn,
         query: Q,
@@ -151,6 +155,33 @@ impl SerializableQuery {
             .switch()
     }
 
+    /// Executes the query in a transaction that is always rolled back, returning the number of
+    /// rows the query would have affected
+    pub async fn dry_run(self, conn: &mut crate::PgPooledConn) -> crate::StorageResult<usize> {
+        use common_utils::errors::ReportSwitchExt;
+        use diesel::Connection;
+
+        let query = self.to_collected_query();
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        conn.run(move |c| {
+            let mut rows_affected = 0;
+            let result = c.transaction::<(), diesel::result::Error, _>(|c| {
+                rows_affected = ExecuteDsl::execute(query, c)?;
+                Err(diesel::result::Error::RollbackTransaction)
+            });
+
+            match result {
+                Ok(()) | Err(diesel::result::Error::RollbackTransaction) => Ok(rows_affected),
+                Err(error) => Err(error),
+            }
+        })
+        .await
+        .attach_printable("Failed to dry run drainer query")
+        .switch()
+    }
+
     pub fn to_field_value_pairs(
         &self,
         request_id: String,
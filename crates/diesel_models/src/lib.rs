@@ -26,6 +26,7 @@ pub mod fraud_check;
 pub mod generic_link;
 pub mod gsm;
 pub mod hyperswitch_ai_interaction;
+pub mod incoming_webhook_log;
 pub mod invoice;
 #[cfg(feature = "kv_store")]
 pub mod kv;
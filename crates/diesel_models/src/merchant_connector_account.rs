@@ -2,6 +2,8 @@
 use std::collections::HashMap;
 use std::fmt::Debug;
 
+#[cfg(feature = "v1")]
+use common_types::connector_fee_schedule::ConnectorFeeSchedule;
 use common_utils::{encryption::Encryption, id_type, pii};
 #[cfg(feature = "v2")]
 use diesel::{sql_types::Jsonb, AsExpression};
@@ -56,6 +58,7 @@ pub struct MerchantConnectorAccount {
     pub version: common_enums::ApiVersion,
     pub id: Option<id_type::MerchantConnectorAccountId>,
     pub connector_webhook_registration_details: Option<serde_json::Value>,
+    pub fee_schedule: Option<ConnectorFeeSchedule>,
 }
 
 #[cfg(feature = "v1")]
@@ -149,6 +152,7 @@ pub struct MerchantConnectorAccountNew {
     pub connector_wallets_details: Option<Encryption>,
     pub version: common_enums::ApiVersion,
     pub id: Option<id_type::MerchantConnectorAccountId>,
+    pub fee_schedule: Option<ConnectorFeeSchedule>,
 }
 
 #[cfg(feature = "v2")]
@@ -206,6 +210,7 @@ pub struct MerchantConnectorAccountUpdateInternal {
     pub connector_wallets_details: Option<Encryption>,
     pub additional_merchant_data: Option<Encryption>,
     pub connector_webhook_registration_details: Option<serde_json::Value>,
+    pub fee_schedule: Option<ConnectorFeeSchedule>,
 }
 
 #[cfg(feature = "v2")]
@@ -254,6 +259,7 @@ impl MerchantConnectorAccountUpdateInternal {
             modified_at: self.modified_at.unwrap_or(source.modified_at),
             pm_auth_config: self.pm_auth_config,
             status: self.status.unwrap_or(source.status),
+            fee_schedule: self.fee_schedule.or(source.fee_schedule),
 
             ..source
         }
@@ -312,6 +312,11 @@ pub struct PaymentAttempt {
     pub sender_payment_instrument_id: Option<String>,
     pub external_threeds_authentication_type: Option<common_enums::DecoupledAuthenticationType>,
     pub applied_offer_details: Option<common_types::payments::AppliedOfferDetails>,
+    pub dcc_details: Option<common_types::payments::DccDetails>,
+    pub routing_algorithm_id: Option<id_type::RoutingId>,
+    pub expected_processing_fee: Option<MinorUnit>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub expected_authorization_expiry: Option<PrimitiveDateTime>,
 }
 
 #[cfg(feature = "v1")]
@@ -568,6 +573,11 @@ pub struct PaymentAttemptNew {
     pub sender_payment_instrument_id: Option<String>,
     pub external_threeds_authentication_type: Option<common_enums::DecoupledAuthenticationType>,
     pub applied_offer_details: Option<common_types::payments::AppliedOfferDetails>,
+    pub dcc_details: Option<common_types::payments::DccDetails>,
+    pub routing_algorithm_id: Option<id_type::RoutingId>,
+    pub expected_processing_fee: Option<MinorUnit>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub expected_authorization_expiry: Option<PrimitiveDateTime>,
 }
 
 #[cfg(feature = "v1")]
@@ -606,6 +616,8 @@ pub enum PaymentAttemptUpdate {
         updated_by: String,
         merchant_connector_id: Option<id_type::MerchantConnectorAccountId>,
         routing_approach: Option<storage_enums::RoutingApproach>,
+        routing_algorithm_id: Option<id_type::RoutingId>,
+        expected_processing_fee: Option<MinorUnit>,
         is_stored_credential: Option<bool>,
     },
     AuthenticationTypeUpdate {
@@ -650,6 +662,8 @@ pub enum PaymentAttemptUpdate {
         tokenization: Option<common_enums::Tokenization>,
         card_discovery: Option<storage_enums::CardDiscovery>,
         routing_approach: Option<storage_enums::RoutingApproach>,
+        routing_algorithm_id: Option<id_type::RoutingId>,
+        expected_processing_fee: Option<MinorUnit>,
         connector_request_reference_id: Option<String>,
         network_transaction_id: Option<String>,
         network_transaction_link_id: Option<String>,
@@ -657,6 +671,7 @@ pub enum PaymentAttemptUpdate {
         request_extended_authorization: Option<RequestExtendedAuthorizationBool>,
         external_surcharge_details: Option<common_types::payments::ExternalSurchargeDetails>,
         applied_offer_details: Option<common_types::payments::AppliedOfferDetails>,
+        dcc_details: Option<common_types::payments::DccDetails>,
     },
     VoidUpdate {
         status: storage_enums::AttemptStatus,
@@ -821,6 +836,10 @@ pub enum PaymentAttemptUpdate {
         external_surcharge_details: common_types::payments::ExternalSurchargeDetails,
         updated_by: String,
     },
+    AuthorizationExpiryUpdate {
+        expected_authorization_expiry: PrimitiveDateTime,
+        updated_by: String,
+    },
 }
 
 #[cfg(feature = "v2")]
@@ -1133,6 +1152,8 @@ pub struct PaymentAttemptUpdateInternal {
     pub issuer_error_message: Option<String>,
     pub setup_future_usage_applied: Option<storage_enums::FutureUsage>,
     pub routing_approach: Option<storage_enums::RoutingApproach>,
+    pub routing_algorithm_id: Option<id_type::RoutingId>,
+    pub expected_processing_fee: Option<MinorUnit>,
     pub connector_request_reference_id: Option<String>,
     pub network_transaction_id: Option<String>,
     pub network_transaction_link_id: Option<String>,
@@ -1145,6 +1166,8 @@ pub struct PaymentAttemptUpdateInternal {
     pub sender_payment_instrument_id: Option<String>,
     pub external_surcharge_details: Option<common_types::payments::ExternalSurchargeDetails>,
     pub applied_offer_details: Option<common_types::payments::AppliedOfferDetails>,
+    pub dcc_details: Option<common_types::payments::DccDetails>,
+    pub expected_authorization_expiry: Option<PrimitiveDateTime>,
 }
 
 #[cfg(feature = "v1")]
@@ -2557,6 +2580,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 issuer_error_message: None,
                 setup_future_usage_applied: None,
                 routing_approach: None,
+                routing_algorithm_id: None,
+                expected_processing_fee: None,
                 connector_request_reference_id: None,
                 network_transaction_id,
                 network_transaction_link_id,
@@ -2570,6 +2595,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 error_details: None,
                 external_surcharge_details: None,
                 applied_offer_details: None,
+                dcc_details: None,
+                expected_authorization_expiry: None,
             },
             PaymentAttemptUpdate::AuthenticationTypeUpdate {
                 authentication_type,
@@ -2636,6 +2663,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 issuer_error_message: None,
                 setup_future_usage_applied: None,
                 routing_approach: None,
+                routing_algorithm_id: None,
+                expected_processing_fee: None,
                 connector_request_reference_id: None,
                 network_transaction_id: None,
                 network_transaction_link_id: None,
@@ -2650,6 +2679,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 sender_payment_instrument_id: None,
                 external_surcharge_details: None,
                 applied_offer_details: None,
+                dcc_details: None,
+                expected_authorization_expiry: None,
             },
             PaymentAttemptUpdate::ConfirmUpdate {
                 amount,
@@ -2689,6 +2720,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 tokenization,
                 card_discovery,
                 routing_approach,
+                routing_algorithm_id,
+                expected_processing_fee,
                 connector_request_reference_id,
                 network_transaction_id,
                 network_transaction_link_id,
@@ -2696,6 +2729,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 request_extended_authorization,
                 external_surcharge_details,
                 applied_offer_details,
+                dcc_details,
+                expected_authorization_expiry: None,
             } => Self {
                 amount: Some(amount),
                 currency: Some(currency),
@@ -2759,6 +2794,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 issuer_error_message: None,
                 setup_future_usage_applied: None,
                 routing_approach,
+                routing_algorithm_id,
+                expected_processing_fee,
                 connector_request_reference_id,
                 network_transaction_id,
                 network_transaction_link_id,
@@ -2772,6 +2809,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 error_details: None,
                 external_surcharge_details,
                 applied_offer_details,
+                dcc_details,
+                expected_authorization_expiry: None,
             },
             PaymentAttemptUpdate::VoidUpdate {
                 status,
@@ -2839,6 +2878,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 issuer_error_message: None,
                 setup_future_usage_applied: None,
                 routing_approach: None,
+                routing_algorithm_id: None,
+                expected_processing_fee: None,
                 connector_request_reference_id: None,
                 network_transaction_id: None,
                 network_transaction_link_id: None,
@@ -2853,6 +2894,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 sender_payment_instrument_id: None,
                 external_surcharge_details: None,
                 applied_offer_details: None,
+                dcc_details: None,
+                expected_authorization_expiry: None,
             },
             PaymentAttemptUpdate::RejectUpdate {
                 status,
@@ -2921,6 +2964,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 issuer_error_message: None,
                 setup_future_usage_applied: None,
                 routing_approach: None,
+                routing_algorithm_id: None,
+                expected_processing_fee: None,
                 connector_request_reference_id: None,
                 network_transaction_id: None,
                 network_transaction_link_id: None,
@@ -2935,6 +2980,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 sender_payment_instrument_id: None,
                 external_surcharge_details: None,
                 applied_offer_details: None,
+                dcc_details: None,
+                expected_authorization_expiry: None,
             },
             PaymentAttemptUpdate::BlocklistUpdate {
                 status,
@@ -3003,6 +3050,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 issuer_error_message: None,
                 setup_future_usage_applied: None,
                 routing_approach: None,
+                routing_algorithm_id: None,
+                expected_processing_fee: None,
                 connector_request_reference_id: None,
                 network_transaction_id: None,
                 network_transaction_link_id: None,
@@ -3017,6 +3066,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 sender_payment_instrument_id: None,
                 external_surcharge_details: None,
                 applied_offer_details: None,
+                dcc_details: None,
+                expected_authorization_expiry: None,
             },
             PaymentAttemptUpdate::ConnectorMandateDetailUpdate {
                 connector_mandate_detail,
@@ -3084,6 +3135,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 issuer_error_message: None,
                 setup_future_usage_applied: None,
                 routing_approach: None,
+                routing_algorithm_id: None,
+                expected_processing_fee: None,
                 connector_request_reference_id: None,
                 network_transaction_id: None,
                 network_transaction_link_id: None,
@@ -3098,6 +3151,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 sender_payment_instrument_id: None,
                 external_surcharge_details: None,
                 applied_offer_details: None,
+                dcc_details: None,
+                expected_authorization_expiry: None,
             },
             PaymentAttemptUpdate::PaymentMethodDetailsUpdate {
                 payment_method_id,
@@ -3164,6 +3219,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 issuer_error_message: None,
                 setup_future_usage_applied: None,
                 routing_approach: None,
+                routing_algorithm_id: None,
+                expected_processing_fee: None,
                 connector_request_reference_id: None,
                 network_transaction_id: None,
                 network_transaction_link_id: None,
@@ -3178,6 +3235,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 sender_payment_instrument_id: None,
                 external_surcharge_details: None,
                 applied_offer_details: None,
+                dcc_details: None,
+                expected_authorization_expiry: None,
             },
             PaymentAttemptUpdate::ResponseUpdate {
                 status,
@@ -3282,6 +3341,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     issuer_error_message: None,
                     setup_future_usage_applied,
                     routing_approach: None,
+                    routing_algorithm_id: None,
+                    expected_processing_fee: None,
                     connector_request_reference_id: None,
                     network_transaction_id,
                     network_transaction_link_id,
@@ -3296,6 +3357,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     sender_payment_instrument_id,
                     external_surcharge_details: None,
                     applied_offer_details: None,
+                    dcc_details: None,
+                    expected_authorization_expiry: None,
                 }
             }
             PaymentAttemptUpdate::ErrorUpdate {
@@ -3386,6 +3449,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     charges: None,
                     setup_future_usage_applied: None,
                     routing_approach: None,
+                    routing_algorithm_id: None,
+                    expected_processing_fee: None,
                     connector_request_reference_id: None,
                     network_transaction_id: None,
                     network_transaction_link_id: None,
@@ -3400,6 +3465,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     sender_payment_instrument_id: None,
                     external_surcharge_details: None,
                     applied_offer_details: None,
+                    dcc_details: None,
+                    expected_authorization_expiry: None,
                 }
             }
             PaymentAttemptUpdate::StatusUpdate { status, updated_by } => Self {
@@ -3464,6 +3531,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 issuer_error_message: None,
                 setup_future_usage_applied: None,
                 routing_approach: None,
+                routing_algorithm_id: None,
+                expected_processing_fee: None,
                 connector_request_reference_id: None,
                 network_transaction_id: None,
                 network_transaction_link_id: None,
@@ -3478,6 +3547,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 sender_payment_instrument_id: None,
                 external_surcharge_details: None,
                 applied_offer_details: None,
+                dcc_details: None,
+                expected_authorization_expiry: None,
             },
             PaymentAttemptUpdate::UpdateTrackers {
                 payment_token,
@@ -3489,6 +3560,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 updated_by,
                 merchant_connector_id,
                 routing_approach,
+                routing_algorithm_id,
+                expected_processing_fee,
                 is_stored_credential,
             } => Self {
                 payment_token,
@@ -3553,6 +3626,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 issuer_error_message: None,
                 setup_future_usage_applied: None,
                 routing_approach,
+                routing_algorithm_id,
+                expected_processing_fee,
                 connector_request_reference_id: None,
                 network_transaction_id: None,
                 network_transaction_link_id: None,
@@ -3566,6 +3641,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 error_details: None,
                 external_surcharge_details: None,
                 applied_offer_details: None,
+                dcc_details: None,
+                expected_authorization_expiry: None,
             },
             PaymentAttemptUpdate::UnresolvedResponseUpdate {
                 status,
@@ -3647,6 +3724,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     issuer_error_message: None,
                     setup_future_usage_applied: None,
                     routing_approach: None,
+                    routing_algorithm_id: None,
+                    expected_processing_fee: None,
                     connector_request_reference_id: None,
                     network_transaction_id: None,
                     network_transaction_link_id: None,
@@ -3661,6 +3740,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     sender_payment_instrument_id: None,
                     external_surcharge_details: None,
                     applied_offer_details: None,
+                    dcc_details: None,
+                    expected_authorization_expiry: None,
                 }
             }
             PaymentAttemptUpdate::PreprocessingUpdate {
@@ -3739,6 +3820,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     issuer_error_message: None,
                     setup_future_usage_applied: None,
                     routing_approach: None,
+                    routing_algorithm_id: None,
+                    expected_processing_fee: None,
                     connector_request_reference_id: None,
                     network_transaction_id: None,
                     network_transaction_link_id: None,
@@ -3753,6 +3836,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     sender_payment_instrument_id: None,
                     external_surcharge_details: None,
                     applied_offer_details: None,
+                    dcc_details: None,
+                    expected_authorization_expiry: None,
                 }
             }
             PaymentAttemptUpdate::CaptureUpdate {
@@ -3821,6 +3906,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 issuer_error_message: None,
                 setup_future_usage_applied: None,
                 routing_approach: None,
+                routing_algorithm_id: None,
+                expected_processing_fee: None,
                 connector_request_reference_id: None,
                 network_transaction_id: None,
                 network_transaction_link_id: None,
@@ -3835,6 +3922,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 sender_payment_instrument_id: None,
                 external_surcharge_details: None,
                 applied_offer_details: None,
+                dcc_details: None,
+                expected_authorization_expiry: None,
             },
             PaymentAttemptUpdate::AmountToCaptureUpdate {
                 status,
@@ -3902,6 +3991,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 issuer_error_message: None,
                 setup_future_usage_applied: None,
                 routing_approach: None,
+                routing_algorithm_id: None,
+                expected_processing_fee: None,
                 connector_request_reference_id: None,
                 network_transaction_id: None,
                 network_transaction_link_id: None,
@@ -3916,6 +4007,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 sender_payment_instrument_id: None,
                 external_surcharge_details: None,
                 applied_offer_details: None,
+                dcc_details: None,
+                expected_authorization_expiry: None,
             },
             PaymentAttemptUpdate::ConnectorResponse {
                 authentication_data,
@@ -3992,6 +4085,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     issuer_error_message: None,
                     setup_future_usage_applied: None,
                     routing_approach: None,
+                    routing_algorithm_id: None,
+                    expected_processing_fee: None,
                     connector_request_reference_id: None,
                     network_transaction_id: None,
                     network_transaction_link_id: None,
@@ -4006,6 +4101,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     sender_payment_instrument_id: None,
                     external_surcharge_details: None,
                     applied_offer_details: None,
+                    dcc_details: None,
+                    expected_authorization_expiry: None,
                 }
             }
             PaymentAttemptUpdate::IncrementalAuthorizationAmountUpdate {
@@ -4073,6 +4170,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 issuer_error_message: None,
                 setup_future_usage_applied: None,
                 routing_approach: None,
+                routing_algorithm_id: None,
+                expected_processing_fee: None,
                 connector_request_reference_id: None,
                 network_transaction_id: None,
                 network_transaction_link_id: None,
@@ -4087,6 +4186,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 sender_payment_instrument_id: None,
                 external_surcharge_details: None,
                 applied_offer_details: None,
+                dcc_details: None,
+                expected_authorization_expiry: None,
             },
             PaymentAttemptUpdate::AuthenticationUpdate {
                 status,
@@ -4157,6 +4258,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 issuer_error_message: None,
                 setup_future_usage_applied: None,
                 routing_approach: None,
+                routing_algorithm_id: None,
+                expected_processing_fee: None,
                 connector_request_reference_id: None,
                 network_transaction_id: None,
                 network_transaction_link_id: None,
@@ -4171,6 +4274,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 sender_payment_instrument_id: None,
                 external_surcharge_details: None,
                 applied_offer_details: None,
+                dcc_details: None,
+                expected_authorization_expiry: None,
             },
             PaymentAttemptUpdate::ManualUpdate {
                 status,
@@ -4250,6 +4355,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     issuer_error_message: None,
                     setup_future_usage_applied: None,
                     routing_approach: None,
+                    routing_algorithm_id: None,
+                    expected_processing_fee: None,
                     connector_request_reference_id: None,
                     network_transaction_id: None,
                     network_transaction_link_id: None,
@@ -4264,6 +4371,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     sender_payment_instrument_id: None,
                     external_surcharge_details: None,
                     applied_offer_details: None,
+                    dcc_details: None,
+                    expected_authorization_expiry: None,
                 }
             }
             PaymentAttemptUpdate::PostSessionTokensUpdate {
@@ -4331,6 +4440,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 issuer_error_message: None,
                 setup_future_usage_applied: None,
                 routing_approach: None,
+                routing_algorithm_id: None,
+                expected_processing_fee: None,
                 connector_request_reference_id: None,
                 network_transaction_id: None,
                 network_transaction_link_id: None,
@@ -4345,6 +4456,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 sender_payment_instrument_id: None,
                 external_surcharge_details: None,
                 applied_offer_details: None,
+                dcc_details: None,
+                expected_authorization_expiry: None,
             },
             PaymentAttemptUpdate::RecurrenceUpdate {
                 status,
@@ -4416,6 +4529,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 issuer_error_message: None,
                 setup_future_usage_applied: None,
                 routing_approach: None,
+                routing_algorithm_id: None,
+                expected_processing_fee: None,
                 connector_request_reference_id: None,
                 network_transaction_id: None,
                 network_transaction_link_id: None,
@@ -4429,6 +4544,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 sender_payment_instrument_id: None,
                 external_surcharge_details: None,
                 applied_offer_details: None,
+                dcc_details: None,
+                expected_authorization_expiry: None,
             },
             PaymentAttemptUpdate::ExternalSurchargeUpdate {
                 updated_by,
@@ -4494,6 +4611,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 issuer_error_message: None,
                 setup_future_usage_applied: None,
                 routing_approach: None,
+                routing_algorithm_id: None,
+                expected_processing_fee: None,
                 connector_request_reference_id: None,
                 network_transaction_id: None,
                 network_transaction_link_id: None,
@@ -4509,6 +4628,92 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 external_surcharge_details: Some(external_surcharge_details),
                 external_threeds_authentication_type: None,
                 applied_offer_details: None,
+                dcc_details: None,
+                expected_authorization_expiry: None,
+            },
+            PaymentAttemptUpdate::AuthorizationExpiryUpdate {
+                updated_by,
+                expected_authorization_expiry,
+            } => Self {
+                status: None,
+                error_code: None,
+                modified_at: common_utils::date_time::now(),
+                error_message: None,
+                error_reason: None,
+                updated_by,
+                unified_code: None,
+                unified_message: None,
+                amount: None,
+                net_amount: None,
+                currency: None,
+                connector_transaction_id: None,
+                amount_to_capture: None,
+                connector: None,
+                authentication_type: None,
+                payment_method: None,
+                payment_method_id: None,
+                cancellation_reason: None,
+                mandate_id: None,
+                browser_info: None,
+                payment_token: None,
+                connector_metadata: None,
+                payment_method_data: None,
+                payment_method_type: None,
+                payment_experience: None,
+                business_sub_label: None,
+                straight_through_algorithm: None,
+                preprocessing_step_id: None,
+                capture_method: None,
+                connector_response_reference_id: None,
+                multiple_capture_count: None,
+                surcharge_amount: None,
+                tax_amount: None,
+                amount_capturable: None,
+                merchant_connector_id: None,
+                authentication_data: None,
+                encoded_data: None,
+                external_three_ds_authentication_attempted: None,
+                authentication_connector: None,
+                authentication_id: None,
+                fingerprint_id: None,
+                payment_method_billing_address_id: None,
+                client_source: None,
+                client_version: None,
+                customer_acceptance: None,
+                card_network: None,
+                shipping_cost: None,
+                order_tax_amount: None,
+                capture_before: None,
+                extended_authorization_applied: None,
+                extended_authorization_last_applied_at: None,
+                tokenization: None,
+                processor_transaction_data: None,
+                connector_mandate_detail: None,
+                card_discovery: None,
+                charges: None,
+                issuer_error_code: None,
+                issuer_error_message: None,
+                setup_future_usage_applied: None,
+                routing_approach: None,
+                routing_algorithm_id: None,
+                expected_processing_fee: None,
+                connector_request_reference_id: None,
+                network_transaction_id: None,
+                network_transaction_link_id: None,
+                is_overcapture_enabled: None,
+                network_details: None,
+                is_stored_credential: None,
+                request_extended_authorization: None,
+                authorized_amount: None,
+                installment_data: None,
+                encrypted_payment_method_data: None,
+                error_details: None,
+                sender_payment_instrument_id: None,
+                external_surcharge_details: None,
+                external_threeds_authentication_type: None,
+                applied_offer_details: None,
+                dcc_details: None,
+                expected_authorization_expiry: Some(expected_authorization_expiry),
             },
         }
     }
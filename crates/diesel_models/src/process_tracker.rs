@@ -243,6 +243,10 @@ pub mod business_status {
     /// Further retries of the task are not required.
     pub const INITIAL_DELIVERY_ATTEMPT_SUCCESSFUL: &str = "INITIAL_DELIVERY_ATTEMPT_SUCCESSFUL";
 
+    /// The webhook endpoint the outgoing webhook is delivered to was paused. The event is queued
+    /// and delivered once the endpoint is activated again, instead of being retried by the task.
+    pub const WEBHOOK_ENDPOINT_PAUSED: &str = "WEBHOOK_ENDPOINT_PAUSED";
+
     /// Indicates that an error occurred during the workflow execution.
     /// This status is typically set by the workflow error handler.
     /// A task that reaches this status should not be retried (rescheduled for execution) later.
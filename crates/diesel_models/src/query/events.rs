@@ -393,6 +393,7 @@ impl Event {
         event_types: HashSet<common_enums::EventType>,
         is_delivered: Option<bool>,
         event_recipient: Option<common_enums::EventRecipient>,
+        webhook_endpoint_id: Option<common_utils::id_type::WebhookEndpointId>,
     ) -> StorageResult<Vec<Self>> {
         let mut query = crate::list::into_boxed_list(
             Self::table()
@@ -416,6 +417,10 @@ impl Event {
             event_recipient,
         );
 
+        if let Some(webhook_endpoint_id) = webhook_endpoint_id {
+            query = query.filter(dsl::webhook_endpoint_id.eq(webhook_endpoint_id));
+        }
+
         logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
 
         track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Filter)
@@ -634,6 +639,7 @@ impl Event {
         .attach_printable("Error counting events by constraints")
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn count_initial_attempts_by_profile_id_constraints(
         conn: &PgPooledConn,
         profile_id: &common_utils::id_type::ProfileId,
@@ -642,6 +648,7 @@ impl Event {
         event_types: HashSet<common_enums::EventType>,
         is_delivered: Option<bool>,
         event_recipient: Option<common_enums::EventRecipient>,
+        webhook_endpoint_id: Option<common_utils::id_type::WebhookEndpointId>,
     ) -> StorageResult<i64> {
         let mut query = crate::list::into_boxed_list(
             Self::table().count().filter(
@@ -663,6 +670,10 @@ impl Event {
             event_recipient,
         );
 
+        if let Some(webhook_endpoint_id) = webhook_endpoint_id {
+            query = query.filter(dsl::webhook_endpoint_id.eq(webhook_endpoint_id));
+        }
+
         logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
 
         track_database_call::<Self, _, _>(
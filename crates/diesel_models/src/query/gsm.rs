@@ -1,5 +1,8 @@
-use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
-use error_stack::report;
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, BoolExpressionMethods, ExpressionMethods, QueryDsl,
+};
+use error_stack::{report, ResultExt};
 
 use crate::{
     errors, gsm::*, query::generics, schema::gateway_status_map::dsl, PgPooledConn, StorageResult,
@@ -32,6 +35,31 @@ impl GatewayStatusMap {
         .await
     }
 
+    /// Lists the GSM rules of a connector, or of all connectors when no connector is specified
+    pub async fn list(conn: &PgPooledConn, connector: Option<String>) -> StorageResult<Vec<Self>> {
+        let mut query = crate::list::into_boxed_list(<Self as HasTable>::table()).order((
+            dsl::connector.asc(),
+            dsl::flow.asc(),
+            dsl::sub_flow.asc(),
+            dsl::code.asc(),
+            dsl::message.asc(),
+        ));
+
+        if let Some(connector) = connector {
+            query = query.filter(dsl::connector.eq(connector));
+        }
+
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        generics::db_metrics::track_database_call::<Self, _, _>(
+            query.get_results_async(conn),
+            generics::db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while listing gsm entries")
+    }
+
     pub async fn retrieve_decision(
         conn: &PgPooledConn,
         connector: String,
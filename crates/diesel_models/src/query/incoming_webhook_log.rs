@@ -0,0 +1,145 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, BoolExpressionMethods, ExpressionMethods, QueryDsl,
+};
+use error_stack::ResultExt;
+use router_env::logger;
+
+use super::{
+    generics,
+    generics::db_metrics::{track_database_call, DatabaseOperation},
+};
+use crate::{
+    enums::IncomingWebhookStatus,
+    errors::DatabaseError,
+    incoming_webhook_log::{
+        IncomingWebhookLog, IncomingWebhookLogNew, IncomingWebhookLogUpdateInternal,
+    },
+    schema::incoming_webhook_log::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl IncomingWebhookLogNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<IncomingWebhookLog> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl IncomingWebhookLog {
+    pub async fn find_by_merchant_id_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::id.eq(id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn update_by_merchant_id_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        id: &str,
+        incoming_webhook_log: IncomingWebhookLogUpdateInternal,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::id.eq(id.to_owned())),
+            incoming_webhook_log,
+        )
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn list_by_merchant_id_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+        connector: Option<String>,
+        status: Option<IncomingWebhookStatus>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        let mut query = crate::list::into_boxed_list(
+            Self::table()
+                .filter(
+                    dsl::merchant_id
+                        .eq(merchant_id.to_owned())
+                        .and(dsl::created_at.ge(created_after))
+                        .and(dsl::created_at.lt(created_before)),
+                )
+                .order(dsl::created_at.desc()),
+        );
+
+        if let Some(connector) = connector {
+            query = query.filter(dsl::connector.eq(connector));
+        }
+
+        if let Some(status) = status {
+            query = query.filter(dsl::status.eq(status));
+        }
+
+        if let Some(limit) = limit {
+            query = query.limit(limit);
+        }
+
+        if let Some(offset) = offset {
+            query = query.offset(offset);
+        }
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Filter)
+            .await
+            .change_context(DatabaseError::Others) // Query returns empty Vec when no records are found
+            .attach_printable("Error filtering incoming webhooks by constraints")
+    }
+
+    pub async fn count_by_merchant_id_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+        connector: Option<String>,
+        status: Option<IncomingWebhookStatus>,
+    ) -> StorageResult<i64> {
+        let mut query = crate::list::into_boxed_list(
+            Self::table().count().filter(
+                dsl::merchant_id
+                    .eq(merchant_id.to_owned())
+                    .and(dsl::created_at.ge(created_after))
+                    .and(dsl::created_at.lt(created_before)),
+            ),
+        );
+
+        if let Some(connector) = connector {
+            query = query.filter(dsl::connector.eq(connector));
+        }
+
+        if let Some(status) = status {
+            query = query.filter(dsl::status.eq(status));
+        }
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(
+            query.get_result_async::<i64>(conn),
+            DatabaseOperation::Count,
+        )
+        .await
+        .change_context(DatabaseError::Others)
+        .attach_printable("Error counting incoming webhooks by constraints")
+    }
+}
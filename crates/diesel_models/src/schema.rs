@@ -55,6 +55,9 @@ diesel::table! {
         created_at -> Timestamp,
         expires_at -> Nullable<Timestamp>,
         last_used -> Nullable<Timestamp>,
+        permissions -> Nullable<Jsonb>,
+        #[max_length = 64]
+        profile_id -> Nullable<Varchar>,
     }
 }
 
@@ -333,6 +336,8 @@ diesel::table! {
         default_fallback_routing -> Nullable<Jsonb>,
         surcharge_connector_details -> Nullable<Jsonb>,
         order_fulfillment_time -> Nullable<Int8>,
+        #[max_length = 64]
+        routing_timezone -> Nullable<Varchar>,
     }
 }
 
@@ -646,6 +651,8 @@ diesel::table! {
         initiator_merchant_id -> Nullable<Varchar>,
         #[max_length = 32]
         recipient -> Nullable<Varchar>,
+        #[max_length = 64]
+        webhook_endpoint_id -> Nullable<Varchar>,
     }
 }
 
@@ -840,6 +847,34 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    incoming_webhook_log (id) {
+        #[max_length = 64]
+        id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        connector_name_or_mca_id -> Varchar,
+        #[max_length = 64]
+        connector -> Nullable<Varchar>,
+        #[max_length = 64]
+        merchant_connector_id -> Nullable<Varchar>,
+        is_relay_webhook -> Bool,
+        request -> Bytea,
+        source_verified -> Nullable<Bool>,
+        #[max_length = 32]
+        status -> Varchar,
+        error_message -> Nullable<Text>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+        #[max_length = 64]
+        rejection_reason -> Nullable<Varchar>,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1082,6 +1117,7 @@ diesel::table! {
         #[max_length = 64]
         id -> Nullable<Varchar>,
         connector_webhook_registration_details -> Nullable<Jsonb>,
+        fee_schedule -> Nullable<Jsonb>,
     }
 }
 
@@ -1257,6 +1293,11 @@ diesel::table! {
         #[max_length = 64]
         external_threeds_authentication_type -> Nullable<Varchar>,
         applied_offer_details -> Nullable<Jsonb>,
+        dcc_details -> Nullable<Jsonb>,
+        #[max_length = 64]
+        routing_algorithm_id -> Nullable<Varchar>,
+        expected_processing_fee -> Nullable<Int8>,
+        expected_authorization_expiry -> Nullable<Timestamp>,
     }
 }
 
@@ -2022,6 +2063,7 @@ diesel::allow_tables_to_appear_in_same_query!(
     generic_link,
     hyperswitch_ai_interaction,
     hyperswitch_ai_interaction_default,
+    incoming_webhook_log,
     incremental_authorization,
     invoice,
     locker_mock_up,
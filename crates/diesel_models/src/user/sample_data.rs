@@ -327,8 +327,12 @@ impl PaymentAttemptBatchNew {
             installment_data: None,
             external_surcharge_details: None,
             applied_offer_details: None,
+            dcc_details: None,
             sender_payment_instrument_id: None,
             external_threeds_authentication_type: None,
+            routing_algorithm_id: None,
+            expected_processing_fee: None,
+            expected_authorization_expiry: None,
         }
     }
 }
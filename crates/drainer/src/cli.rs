@@ -0,0 +1,443 @@
+use std::{collections::HashMap, io::Write, sync::Arc};
+
+use common_utils::id_type;
+use error_stack::report;
+use redis_interface as redis;
+
+use crate::{
+    errors, logger, query::ExecuteQuery, stream::StreamEntries, utils, DrainerSettings, Store,
+    StreamData,
+};
+
+/// Commands for inspecting the drainer streams and replaying their entries by hand, the
+/// drainer is started when no command is specified
+#[derive(Debug, Clone, clap::Subcommand)]
+pub enum Command {
+    /// List the stream shards along with the entries pending in them
+    Shards {
+        /// Tenant whose streams are listed, may be omitted if there is a single tenant
+        #[arg(long)]
+        tenant: Option<String>,
+
+        /// List all shards, including the ones with no pending entries
+        #[arg(long)]
+        all: bool,
+    },
+    /// Decode the entries pending in a stream shard
+    Inspect {
+        #[command(flatten)]
+        stream: StreamArgs,
+
+        /// Only decode entries after this entry id
+        #[arg(long)]
+        after: Option<String>,
+
+        /// Maximum number of entries to decode
+        #[arg(long, default_value_t = 10)]
+        count: u64,
+    },
+    /// Execute the entries pending in a stream shard in transactions that are rolled back
+    DryRun {
+        #[command(flatten)]
+        stream: StreamArgs,
+
+        /// Only execute entries after this entry id
+        #[arg(long)]
+        after: Option<String>,
+
+        /// Maximum number of entries to execute
+        #[arg(long, default_value_t = 10)]
+        count: u64,
+    },
+    /// Apply a range of entries of a stream shard and remove them from the stream
+    Replay {
+        #[command(flatten)]
+        stream: StreamArgs,
+
+        /// First entry id of the range, inclusive
+        #[arg(long)]
+        from: String,
+
+        /// Last entry id of the range, inclusive
+        #[arg(long)]
+        to: String,
+    },
+}
+
+#[derive(Debug, Clone, clap::Args)]
+pub struct StreamArgs {
+    /// Tenant whose stream is used, may be omitted if there is a single tenant
+    #[arg(long)]
+    pub tenant: Option<String>,
+
+    /// Index of the stream shard
+    #[arg(long)]
+    pub shard: u8,
+
+    /// Use the dead-letter stream of the shard instead of the shard stream
+    #[arg(long)]
+    pub dead_letter: bool,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct ShardStatus {
+    shard: u8,
+    stream: String,
+    pending_entries: usize,
+    dead_letter_entries: usize,
+    oldest_entry_id: Option<String>,
+    /// Time since the oldest pending entry was pushed to the stream
+    lag_seconds: Option<u64>,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct DecodedEntry {
+    entry_id: String,
+    request_id: Option<String>,
+    global_id: Option<String>,
+    pushed_at: Option<i64>,
+    operation: Option<String>,
+    entity_type: Option<String>,
+    sql: Option<String>,
+    /// Error encountered while decoding the entry, the raw fields are included instead
+    decode_error: Option<String>,
+    raw_fields: Option<HashMap<String, String>>,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct DryRunResult {
+    entry_id: String,
+    operation: Option<String>,
+    entity_type: Option<String>,
+    rows_affected: Option<usize>,
+    error: Option<String>,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct ReplayResult {
+    stream: String,
+    replayed_entries: usize,
+    /// Entry at which the replay stopped because it could not be applied
+    failed_entry_id: Option<String>,
+    error: Option<String>,
+}
+
+pub async fn run_command(
+    stores: HashMap<id_type::TenantId, Arc<Store>>,
+    conf: DrainerSettings,
+    command: Command,
+) -> errors::DrainerResult<()> {
+    match command {
+        Command::Shards { tenant, all } => {
+            let store = get_store(&stores, tenant.as_deref())?;
+            write_output(&list_shards(store, all).await?)
+        }
+        Command::Inspect {
+            stream,
+            after,
+            count,
+        } => {
+            let store = get_store(&stores, stream.tenant.as_deref())?;
+            let stream_name = get_stream_name(store, &stream)?;
+            let entries = read_entries(store, &stream_name, after, count).await?;
+            write_output(&entries.into_iter().map(decode_entry).collect::<Vec<_>>())
+        }
+        Command::DryRun {
+            stream,
+            after,
+            count,
+        } => {
+            let store = get_store(&stores, stream.tenant.as_deref())?;
+            let stream_name = get_stream_name(store, &stream)?;
+            let entries = read_entries(store, &stream_name, after, count).await?;
+
+            let mut results = Vec::with_capacity(entries.len());
+            for (entry_id, entry) in entries {
+                results.push(dry_run_entry(store, entry_id, entry).await);
+            }
+            write_output(&results)
+        }
+        Command::Replay { stream, from, to } => {
+            let store = get_store(&stores, stream.tenant.as_deref())?;
+            let stream_name = get_stream_name(store, &stream)?;
+
+            // The drainer must not pick up the shard while it is being replayed, the dead-letter
+            // streams are never read by the drainer
+            if !stream.dead_letter && !store.is_stream_available(stream.shard).await {
+                return Err(report!(errors::DrainerError::UnexpectedError(format!(
+                    "Shard {} is being drained, retry once the drainer releases it",
+                    stream.shard
+                ))));
+            }
+
+            let result = replay_entries(store, &stream_name, &from, &to, conf.max_read_count).await;
+
+            if !stream.dead_letter {
+                store
+                    .make_stream_available(store.get_stream_key_flag(stream.shard).as_str())
+                    .await?;
+            }
+
+            write_output(&result?)
+        }
+    }
+}
+
+async fn list_shards(store: &Arc<Store>, all: bool) -> errors::DrainerResult<Vec<ShardStatus>> {
+    let redis_conn = store.get_redis_conn();
+    let now = common_utils::date_time::now_unix_timestamp();
+    let mut shards = Vec::new();
+
+    for shard in 0..store.config.drainer_num_partitions {
+        let stream_name = store.get_drainer_stream_name(shard);
+        let dead_letter_stream = store.get_dead_letter_stream_name(&stream_name);
+
+        let pending_entries = redis_conn
+            .stream_get_length(&stream_name.as_str().into())
+            .await
+            .map_err(errors::DrainerError::from)?;
+        let dead_letter_entries = redis_conn
+            .stream_get_length(&dead_letter_stream.as_str().into())
+            .await
+            .map_err(errors::DrainerError::from)?;
+
+        if !all && pending_entries == 0 && dead_letter_entries == 0 {
+            continue;
+        }
+
+        let oldest_entry_id = read_entries(store, &stream_name, None, 1)
+            .await?
+            .into_iter()
+            .next()
+            .map(|(entry_id, _)| entry_id);
+        // Stream entry ids are prefixed with the time the entry was added in milliseconds
+        let lag_seconds = oldest_entry_id
+            .as_deref()
+            .and_then(utils::parse_entry_id)
+            .and_then(|(milliseconds, _)| i64::try_from(milliseconds / 1000).ok())
+            .and_then(|pushed_at| u64::try_from(now - pushed_at).ok());
+
+        shards.push(ShardStatus {
+            shard,
+            stream: stream_name,
+            pending_entries,
+            dead_letter_entries,
+            oldest_entry_id,
+            lag_seconds,
+        });
+    }
+
+    Ok(shards)
+}
+
+fn decode_entry((entry_id, entry): (String, HashMap<String, redis::RedisValue>)) -> DecodedEntry {
+    match StreamData::from_hashmap(entry.clone()) {
+        Ok(data) => DecodedEntry {
+            entry_id,
+            request_id: Some(data.request_id),
+            global_id: Some(data.global_id),
+            pushed_at: Some(data.pushed_at),
+            operation: Some(data.query.operation().to_string()),
+            entity_type: Some(data.query.entity_type()),
+            sql: Some(data.query.sql().to_owned()),
+            decode_error: None,
+            raw_fields: None,
+        },
+        Err(error) => DecodedEntry {
+            entry_id,
+            request_id: None,
+            global_id: None,
+            pushed_at: None,
+            operation: None,
+            entity_type: None,
+            sql: None,
+            decode_error: Some(format!("{error:#}")),
+            raw_fields: Some(
+                entry
+                    .into_iter()
+                    .filter_map(|(field, value)| value.as_string().map(|value| (field, value)))
+                    .collect(),
+            ),
+        },
+    }
+}
+
+async fn dry_run_entry(
+    store: &Arc<Store>,
+    entry_id: String,
+    entry: HashMap<String, redis::RedisValue>,
+) -> DryRunResult {
+    let data = match StreamData::from_hashmap(entry) {
+        Ok(data) => data,
+        Err(error) => {
+            return DryRunResult {
+                entry_id,
+                operation: None,
+                entity_type: None,
+                rows_affected: None,
+                error: Some(format!("{error:#}")),
+            }
+        }
+    };
+
+    let operation = Some(data.query.operation().to_string());
+    let entity_type = Some(data.query.entity_type());
+    let (rows_affected, error) = match data.query.dry_run_query(store).await {
+        Ok(rows_affected) => (Some(rows_affected), None),
+        Err(error) => (None, Some(format!("{error:#}"))),
+    };
+
+    DryRunResult {
+        entry_id,
+        operation,
+        entity_type,
+        rows_affected,
+        error,
+    }
+}
+
+/// Applies the entries of the stream in the given range in order, removing each entry from the
+/// stream once it is applied. The replay stops at the first entry that cannot be applied.
+async fn replay_entries(
+    store: &Arc<Store>,
+    stream_name: &str,
+    from: &str,
+    to: &str,
+    max_read_count: u64,
+) -> errors::DrainerResult<ReplayResult> {
+    let last_entry_id = utils::parse_entry_id(to).ok_or_else(|| invalid_entry_id(to))?;
+    let mut after_entry_id =
+        utils::previous_entry_id(from).ok_or_else(|| invalid_entry_id(from))?;
+    let mut result = ReplayResult {
+        stream: stream_name.to_owned(),
+        replayed_entries: 0,
+        failed_entry_id: None,
+        error: None,
+    };
+
+    loop {
+        let entries = read_entries(
+            store,
+            stream_name,
+            Some(after_entry_id.clone()),
+            max_read_count,
+        )
+        .await?;
+        if entries.is_empty() {
+            return Ok(result);
+        }
+
+        for (entry_id, entry) in entries {
+            if utils::parse_entry_id(&entry_id).is_none_or(|entry_id| entry_id > last_entry_id) {
+                return Ok(result);
+            }
+
+            let applied = match StreamData::from_hashmap(entry) {
+                Ok(data) => match data.query.execute_query(store, data.pushed_at).await {
+                    Ok(()) => Ok(()),
+                    Err(error) => match error.current_context() {
+                        // The entry has already been applied
+                        diesel_models::errors::DatabaseError::UniqueViolation => Ok(()),
+                        _ => Err(format!("{error:#}")),
+                    },
+                },
+                Err(error) => Err(format!("{error:#}")),
+            };
+
+            if let Err(error) = applied {
+                logger::error!(%entry_id, %error, "Failed to replay stream entry");
+                result.failed_entry_id = Some(entry_id);
+                result.error = Some(error);
+                return Ok(result);
+            }
+
+            store.delete_from_stream(stream_name, &entry_id).await?;
+            result.replayed_entries += 1;
+            after_entry_id = entry_id;
+        }
+    }
+}
+
+async fn read_entries(
+    store: &Store,
+    stream_name: &str,
+    after_entry_id: Option<String>,
+    count: u64,
+) -> errors::DrainerResult<StreamEntries> {
+    let redis_conn = store.get_redis_conn();
+
+    match redis_conn
+        .stream_read_entries(
+            &[stream_name.into()],
+            vec![after_entry_id.unwrap_or_else(|| "0-0".to_string())],
+            Some(count),
+        )
+        .await
+    {
+        Ok(stream_read) => Ok(stream_read
+            .get(&redis_conn.add_prefix(stream_name))
+            .cloned()
+            .unwrap_or_default()),
+        Err(error) => match error.current_context() {
+            redis::errors::RedisError::StreamEmptyOrNotAvailable => Ok(Vec::new()),
+            _ => Err(errors::DrainerError::from(error).into()),
+        },
+    }
+}
+
+fn get_store<'a>(
+    stores: &'a HashMap<id_type::TenantId, Arc<Store>>,
+    tenant: Option<&str>,
+) -> errors::DrainerResult<&'a Arc<Store>> {
+    match tenant {
+        Some(tenant) => stores
+            .iter()
+            .find(|(tenant_id, _)| tenant_id.get_string_repr() == tenant)
+            .map(|(_, store)| store)
+            .ok_or_else(|| {
+                report!(errors::DrainerError::UnexpectedError(format!(
+                    "Tenant '{tenant}' is not configured"
+                )))
+            }),
+        None => match stores.values().collect::<Vec<_>>().as_slice() {
+            [store] => Ok(*store),
+            _ => Err(report!(errors::DrainerError::UnexpectedError(
+                "Multiple tenants are configured, specify the tenant with `--tenant`".to_string()
+            ))),
+        },
+    }
+}
+
+fn get_stream_name(store: &Store, stream: &StreamArgs) -> errors::DrainerResult<String> {
+    if stream.shard >= store.config.drainer_num_partitions {
+        return Err(report!(errors::DrainerError::UnexpectedError(format!(
+            "Shard {} is out of range, the drainer has {} partitions",
+            stream.shard, store.config.drainer_num_partitions
+        ))));
+    }
+
+    let stream_name = store.get_drainer_stream_name(stream.shard);
+    Ok(if stream.dead_letter {
+        store.get_dead_letter_stream_name(&stream_name)
+    } else {
+        stream_name
+    })
+}
+
+fn invalid_entry_id(entry_id: &str) -> error_stack::Report<errors::DrainerError> {
+    report!(errors::DrainerError::UnexpectedError(format!(
+        "'{entry_id}' is not a valid stream entry id"
+    )))
+}
+
+fn write_output<T: serde::Serialize>(output: &T) -> errors::DrainerResult<()> {
+    let mut stdout = std::io::stdout().lock();
+    serde_json::to_writer_pretty(&mut stdout, output).map_err(|error| {
+        report!(errors::DrainerError::UnexpectedError(format!(
+            "Failed to serialize output: {error}"
+        )))
+    })?;
+    writeln!(stdout).map_err(errors::DrainerError::from)?;
+
+    Ok(())
+}
@@ -0,0 +1,668 @@
+use std::{collections::HashMap, sync::Arc};
+
+use actix_web::{http::header::HeaderMap, web, HttpRequest, Scope};
+use common_utils::id_type;
+use error_stack::report;
+use hyperswitch_masking::{PeekInterface, Secret};
+use redis_interface as redis;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    errors::{self, DeadLetterError},
+    metrics,
+    services::{self, Store},
+    utils,
+};
+
+pub const DEAD_LETTER_ERROR_FIELD: &str = "dead_letter_error";
+pub const DEAD_LETTER_SOURCE_ENTRY_ID_FIELD: &str = "dead_letter_source_entry_id";
+pub const DEAD_LETTER_FAILURE_COUNT_FIELD: &str = "dead_letter_failure_count";
+pub const DEAD_LETTERED_AT_FIELD: &str = "dead_lettered_at";
+
+/// Fields attached to an entry when it is moved to the dead-letter stream, these are stripped
+/// off again when the entry is requeued
+const DEAD_LETTER_FIELDS: [&str; 4] = [
+    DEAD_LETTER_ERROR_FIELD,
+    DEAD_LETTER_SOURCE_ENTRY_ID_FIELD,
+    DEAD_LETTER_FAILURE_COUNT_FIELD,
+    DEAD_LETTERED_AT_FIELD,
+];
+
+/// Failure counts of entries which are applied before exhausting their retries are not cleared
+/// explicitly, they expire after this interval instead
+const FAILURE_COUNT_TTL: i64 = 24 * 60 * 60; // in seconds
+
+/// Time the drainer waits before reading the stream again when an entry could not be applied
+/// due to a transient error, such as the database being unreachable
+pub(crate) const TRANSIENT_FAILURE_BACKOFF: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// Header carrying the admin API key, same as the one used by the router admin APIs
+const ADMIN_API_KEY_HEADER: &str = "api-key";
+
+const DEFAULT_LIST_COUNT: u64 = 20;
+const MAX_LIST_COUNT: u64 = 100;
+
+/// Admin routes for the dead-letter streams of the drainer.
+///
+/// These are served on the same (internal) port as the health checks, every request must carry
+/// the admin API key in the `api-key` header.
+pub struct DeadLetter;
+
+/// Admin API key the dead-letter routes are authenticated against
+pub struct AdminApiKey(Secret<String>);
+
+impl DeadLetter {
+    pub fn server(
+        stores: HashMap<id_type::TenantId, Arc<Store>>,
+        admin_api_key: Secret<String>,
+    ) -> Scope {
+        web::scope("dead_letter")
+            .app_data(web::Data::new(stores))
+            .app_data(web::Data::new(AdminApiKey(admin_api_key)))
+            .service(web::resource("/{tenant_id}/{shard}").route(web::get().to(list_entries)))
+            .service(
+                web::resource("/{tenant_id}/{shard}/{entry_id}")
+                    .route(web::delete().to(discard_entry)),
+            )
+            .service(
+                web::resource("/{tenant_id}/{shard}/{entry_id}/requeue")
+                    .route(web::post().to(requeue_entry)),
+            )
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct DeadLetterListQuery {
+    /// Maximum number of entries to return
+    pub count: Option<u64>,
+    /// Only return entries after this entry id, used for pagination
+    pub after: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct DeadLetterEntry {
+    pub entry_id: String,
+    pub source_entry_id: Option<String>,
+    pub error: Option<String>,
+    pub failure_count: Option<u32>,
+    pub dead_lettered_at: Option<i64>,
+    /// The fields of the original stream entry
+    pub fields: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct DeadLetterListResponse {
+    pub shard: u8,
+    pub depth: usize,
+    pub entries: Vec<DeadLetterEntry>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct DeadLetterEntryActionResponse {
+    pub shard: u8,
+    pub entry_id: String,
+    pub requeued: bool,
+    pub discarded: bool,
+}
+
+#[instrument(skip_all)]
+pub async fn list_entries(
+    req: HttpRequest,
+    admin_api_key: web::Data<AdminApiKey>,
+    stores: web::Data<HashMap<id_type::TenantId, Arc<Store>>>,
+    path: web::Path<(id_type::TenantId, u8)>,
+    query: web::Query<DeadLetterListQuery>,
+) -> impl actix_web::Responder {
+    let (tenant_id, shard) = path.into_inner();
+    let query = query.into_inner();
+
+    let result = async {
+        authenticate(req.headers(), &admin_api_key)?;
+        let store = get_store(&stores, &tenant_id)?;
+        let dead_letter_stream = get_dead_letter_stream(store, shard)?;
+        let count = query
+            .count
+            .unwrap_or(DEFAULT_LIST_COUNT)
+            .clamp(1, MAX_LIST_COUNT);
+
+        let depth = store
+            .get_redis_conn()
+            .stream_get_length(&dead_letter_stream.as_str().into())
+            .await
+            .map_err(to_dead_letter_error)?;
+        let entries = store
+            .read_dead_letter_entries(
+                &dead_letter_stream,
+                query.after.unwrap_or_else(|| "0-0".to_string()),
+                count,
+            )
+            .await
+            .map_err(to_dead_letter_error)?
+            .into_iter()
+            .map(|(entry_id, fields)| DeadLetterEntry::new(entry_id, fields))
+            .collect();
+
+        Ok::<_, error_stack::Report<DeadLetterError>>(DeadLetterListResponse {
+            shard,
+            depth,
+            entries,
+        })
+    }
+    .await;
+
+    to_http_response(result)
+}
+
+#[instrument(skip_all)]
+pub async fn requeue_entry(
+    req: HttpRequest,
+    admin_api_key: web::Data<AdminApiKey>,
+    stores: web::Data<HashMap<id_type::TenantId, Arc<Store>>>,
+    path: web::Path<(id_type::TenantId, u8, String)>,
+) -> impl actix_web::Responder {
+    let (tenant_id, shard, entry_id) = path.into_inner();
+
+    let result = async {
+        authenticate(req.headers(), &admin_api_key)?;
+        let store = get_store(&stores, &tenant_id)?;
+        let dead_letter_stream = get_dead_letter_stream(store, shard)?;
+        let stream_name = store.get_drainer_stream_name(shard);
+
+        let fields = store
+            .find_dead_letter_entry(&dead_letter_stream, &entry_id)
+            .await
+            .map_err(to_dead_letter_error)?
+            .ok_or_else(|| {
+                report!(DeadLetterError::EntryNotFound {
+                    entry_id: entry_id.clone(),
+                })
+            })?;
+        let original_fields = fields
+            .into_iter()
+            .filter(|(field, _)| !DEAD_LETTER_FIELDS.contains(&field.as_str()))
+            .collect::<Vec<_>>();
+
+        // The requeued entry is appended to the end of the shard stream, and is assigned a new
+        // entry id
+        store
+            .get_redis_conn()
+            .stream_append_entry(
+                &stream_name.as_str().into(),
+                &redis::RedisEntryId::AutoGeneratedID,
+                original_fields,
+            )
+            .await
+            .map_err(to_dead_letter_error)?;
+        store
+            .get_redis_conn()
+            .stream_delete_entries(&dead_letter_stream.as_str().into(), vec![entry_id.clone()])
+            .await
+            .map_err(to_dead_letter_error)?;
+
+        logger::info!(%entry_id, %stream_name, "Requeued dead-letter entry");
+        metrics::DEAD_LETTER_ENTRIES_REQUEUED
+            .add(1, router_env::metric_attributes!(("stream", stream_name)));
+
+        Ok::<_, error_stack::Report<DeadLetterError>>(DeadLetterEntryActionResponse {
+            shard,
+            entry_id,
+            requeued: true,
+            discarded: false,
+        })
+    }
+    .await;
+
+    to_http_response(result)
+}
+
+#[instrument(skip_all)]
+pub async fn discard_entry(
+    req: HttpRequest,
+    admin_api_key: web::Data<AdminApiKey>,
+    stores: web::Data<HashMap<id_type::TenantId, Arc<Store>>>,
+    path: web::Path<(id_type::TenantId, u8, String)>,
+) -> impl actix_web::Responder {
+    let (tenant_id, shard, entry_id) = path.into_inner();
+
+    let result = async {
+        authenticate(req.headers(), &admin_api_key)?;
+        let store = get_store(&stores, &tenant_id)?;
+        let dead_letter_stream = get_dead_letter_stream(store, shard)?;
+
+        let deleted_entries = store
+            .get_redis_conn()
+            .stream_delete_entries(&dead_letter_stream.as_str().into(), vec![entry_id.clone()])
+            .await
+            .map_err(to_dead_letter_error)?;
+        if deleted_entries == 0 {
+            return Err(report!(DeadLetterError::EntryNotFound { entry_id }));
+        }
+
+        logger::info!(%entry_id, %dead_letter_stream, "Discarded dead-letter entry");
+        metrics::DEAD_LETTER_ENTRIES_DISCARDED.add(
+            1,
+            router_env::metric_attributes!(("stream", dead_letter_stream)),
+        );
+
+        Ok::<_, error_stack::Report<DeadLetterError>>(DeadLetterEntryActionResponse {
+            shard,
+            entry_id,
+            requeued: false,
+            discarded: true,
+        })
+    }
+    .await;
+
+    to_http_response(result)
+}
+
+/// Whether applying a stream entry would fail in the same way if it is retried, only such
+/// failures count towards the retries of the entry. Constraint violations and serialization
+/// failures are deterministic, connection errors and other database errors are assumed to be
+/// transient.
+pub(crate) fn is_deterministic_failure(
+    error: &error_stack::Report<diesel_models::errors::DatabaseError>,
+) -> bool {
+    use diesel::result::{DatabaseErrorKind, Error as DieselError};
+    use diesel_models::errors::DatabaseError;
+
+    match error.current_context() {
+        DatabaseError::UniqueViolation
+        | DatabaseError::NotFound
+        | DatabaseError::NoFieldsToUpdate
+        | DatabaseError::QueryGenerationFailed => true,
+        DatabaseError::DatabaseConnectionError => false,
+        DatabaseError::Others => matches!(
+            error.downcast_ref::<DieselError>(),
+            Some(
+                DieselError::DatabaseError(
+                    DatabaseErrorKind::UniqueViolation
+                        | DatabaseErrorKind::ForeignKeyViolation
+                        | DatabaseErrorKind::NotNullViolation
+                        | DatabaseErrorKind::CheckViolation,
+                    _,
+                ) | DieselError::SerializationError(_)
+                    | DieselError::DeserializationError(_)
+            )
+        ),
+    }
+}
+
+impl Store {
+    /// Records a failed attempt at applying a stream entry. Only deterministic failures are to be
+    /// recorded, see [`is_deterministic_failure`].
+    ///
+    /// Returns `true` if the entry has exhausted its retries and has been moved to the
+    /// dead-letter stream of the shard, in which case it can be cleared from the shard stream.
+    #[instrument(skip_all)]
+    pub(crate) async fn record_entry_failure(
+        &self,
+        stream_name: &str,
+        entry_id: &str,
+        entry: HashMap<String, redis::RedisValue>,
+        error: String,
+    ) -> errors::DrainerResult<bool> {
+        let redis_conn = self.get_redis_conn();
+        let failure_count_key = self.get_failure_count_key(stream_name);
+
+        let failure_count = redis_conn
+            .increment_fields_in_hash(&failure_count_key.as_str().into(), &[(entry_id, 1)])
+            .await
+            .map_err(errors::DrainerError::from)?
+            .first()
+            .map(|count| u32::try_from(*count).unwrap_or(u32::MAX))
+            .unwrap_or(1);
+        redis_conn
+            .set_expiry(&failure_count_key.as_str().into(), FAILURE_COUNT_TTL)
+            .await
+            .map_err(errors::DrainerError::from)?;
+
+        metrics::STREAM_ENTRY_APPLY_FAILED.add(
+            1,
+            router_env::metric_attributes!(("stream", stream_name.to_owned())),
+        );
+
+        if failure_count < self.config.drainer_max_retry_count {
+            logger::warn!(
+                %entry_id,
+                %failure_count,
+                max_retry_count = %self.config.drainer_max_retry_count,
+                %error,
+                "Failed to apply stream entry"
+            );
+            return Ok(false);
+        }
+
+        let dead_letter_stream = self.get_dead_letter_stream_name(stream_name);
+        let mut fields = entry
+            .into_iter()
+            .filter_map(|(field, value)| value.as_string().map(|value| (field, value)))
+            .collect::<Vec<_>>();
+        fields.extend([
+            (DEAD_LETTER_ERROR_FIELD.to_string(), error.clone()),
+            (
+                DEAD_LETTER_SOURCE_ENTRY_ID_FIELD.to_string(),
+                entry_id.to_string(),
+            ),
+            (
+                DEAD_LETTER_FAILURE_COUNT_FIELD.to_string(),
+                failure_count.to_string(),
+            ),
+            (
+                DEAD_LETTERED_AT_FIELD.to_string(),
+                common_utils::date_time::now_unix_timestamp().to_string(),
+            ),
+        ]);
+
+        redis_conn
+            .stream_append_entry(
+                &dead_letter_stream.as_str().into(),
+                &redis::RedisEntryId::AutoGeneratedID,
+                fields,
+            )
+            .await
+            .map_err(errors::DrainerError::from)?;
+
+        // The entry is out of the shard stream once the caller clears it, a failure to remove
+        // its count only leaves it to expire
+        let _ = redis_conn
+            .delete_hash_fields(&failure_count_key.as_str().into(), entry_id.to_string())
+            .await
+            .map_err(|error| logger::error!(operation = "clear_failure_count", ?error));
+
+        logger::error!(
+            %entry_id,
+            %failure_count,
+            %dead_letter_stream,
+            %error,
+            "Moved stream entry to the dead-letter stream"
+        );
+        metrics::STREAM_ENTRIES_DEAD_LETTERED.add(
+            1,
+            router_env::metric_attributes!(("stream", stream_name.to_owned())),
+        );
+
+        Ok(true)
+    }
+
+    /// Returns the total number of entries in the dead-letter streams of all shards
+    pub(crate) async fn get_dead_letter_depth(&self) -> errors::DrainerResult<usize> {
+        let redis_conn = self.get_redis_conn();
+        let mut depth = 0;
+
+        for stream_index in 0..self.config.drainer_num_partitions {
+            let dead_letter_stream =
+                self.get_dead_letter_stream_name(&self.get_drainer_stream_name(stream_index));
+            depth += redis_conn
+                .stream_get_length(&dead_letter_stream.as_str().into())
+                .await
+                .map_err(errors::DrainerError::from)?;
+        }
+
+        Ok(depth)
+    }
+
+    async fn read_dead_letter_entries(
+        &self,
+        dead_letter_stream: &str,
+        after_entry_id: String,
+        count: u64,
+    ) -> errors::DrainerResult<Vec<(String, HashMap<String, String>)>> {
+        let redis_conn = self.get_redis_conn();
+
+        let stream_read = match redis_conn
+            .stream_read_entries(
+                &[dead_letter_stream.into()],
+                vec![after_entry_id],
+                Some(count),
+            )
+            .await
+        {
+            Ok(stream_read) => stream_read,
+            Err(error) => {
+                return match error.current_context() {
+                    redis::errors::RedisError::StreamEmptyOrNotAvailable => Ok(Vec::new()),
+                    _ => Err(errors::DrainerError::from(error).into()),
+                }
+            }
+        };
+
+        Ok(stream_read
+            .get(&redis_conn.add_prefix(dead_letter_stream))
+            .cloned()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(entry_id, entry)| {
+                let fields = entry
+                    .into_iter()
+                    .filter_map(|(field, value)| value.as_string().map(|value| (field, value)))
+                    .collect();
+                (entry_id, fields)
+            })
+            .collect())
+    }
+
+    async fn find_dead_letter_entry(
+        &self,
+        dead_letter_stream: &str,
+        entry_id: &str,
+    ) -> errors::DrainerResult<Option<HashMap<String, String>>> {
+        // Streams can only be read after a given id, so read a single entry after the id
+        // immediately preceding the one being looked up
+        let Some(previous_entry_id) = utils::previous_entry_id(entry_id) else {
+            return Ok(None);
+        };
+
+        Ok(self
+            .read_dead_letter_entries(dead_letter_stream, previous_entry_id, 1)
+            .await?
+            .into_iter()
+            .find(|(id, _)| id == entry_id)
+            .map(|(_, fields)| fields))
+    }
+}
+
+impl DeadLetterEntry {
+    fn new(entry_id: String, mut fields: HashMap<String, String>) -> Self {
+        Self {
+            entry_id,
+            source_entry_id: fields.remove(DEAD_LETTER_SOURCE_ENTRY_ID_FIELD),
+            error: fields.remove(DEAD_LETTER_ERROR_FIELD),
+            failure_count: fields
+                .remove(DEAD_LETTER_FAILURE_COUNT_FIELD)
+                .and_then(|count| count.parse().ok()),
+            dead_lettered_at: fields
+                .remove(DEAD_LETTERED_AT_FIELD)
+                .and_then(|timestamp| timestamp.parse().ok()),
+            fields,
+        }
+    }
+}
+
+fn authenticate(
+    headers: &HeaderMap,
+    admin_api_key: &AdminApiKey,
+) -> Result<(), error_stack::Report<DeadLetterError>> {
+    let request_api_key = headers
+        .get(ADMIN_API_KEY_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .ok_or_else(|| report!(DeadLetterError::Unauthorized))?;
+
+    common_utils::fp_utils::when(request_api_key != admin_api_key.0.peek(), || {
+        Err(report!(DeadLetterError::Unauthorized)
+            .attach_printable("Dead-letter admin authentication failure"))
+    })
+}
+
+fn get_store<'a>(
+    stores: &'a HashMap<id_type::TenantId, Arc<Store>>,
+    tenant_id: &id_type::TenantId,
+) -> Result<&'a Arc<Store>, error_stack::Report<DeadLetterError>> {
+    stores.get(tenant_id).ok_or_else(|| {
+        report!(DeadLetterError::TenantNotFound {
+            tenant_id: tenant_id.get_string_repr().to_owned(),
+        })
+    })
+}
+
+fn get_dead_letter_stream(
+    store: &Store,
+    shard: u8,
+) -> Result<String, error_stack::Report<DeadLetterError>> {
+    let num_partitions = store.config.drainer_num_partitions;
+    if shard >= num_partitions {
+        return Err(report!(DeadLetterError::InvalidShard {
+            shard,
+            num_partitions,
+        }));
+    }
+
+    Ok(store.get_dead_letter_stream_name(&store.get_drainer_stream_name(shard)))
+}
+
+fn to_dead_letter_error<E>(error: error_stack::Report<E>) -> error_stack::Report<DeadLetterError>
+where
+    E: error_stack::Context,
+{
+    let message = error.to_string();
+    error.change_context(DeadLetterError::RedisError { message })
+}
+
+fn to_http_response<T: serde::Serialize>(
+    result: Result<T, error_stack::Report<DeadLetterError>>,
+) -> actix_web::HttpResponse {
+    match result {
+        Ok(response) => services::http_response_json(
+            serde_json::to_string(&response)
+                .map_err(|err| {
+                    logger::error!(serialization_error=?err);
+                })
+                .unwrap_or_default(),
+        ),
+        Err(error) => {
+            logger::error!(?error);
+            actix_web::ResponseError::error_response(error.current_context())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+
+    use actix_web::{
+        http::{
+            header::{HeaderName, HeaderValue},
+            Method, StatusCode,
+        },
+        test::{call_service, init_service, TestRequest},
+        App,
+    };
+
+    use super::*;
+
+    const ADMIN_API_KEY: &str = "test_admin";
+
+    fn routes() -> [(Method, &'static str); 3] {
+        [
+            (Method::GET, "/dead_letter/public/0"),
+            (Method::DELETE, "/dead_letter/public/0/1-0"),
+            (Method::POST, "/dead_letter/public/0/1-0/requeue"),
+        ]
+    }
+
+    async fn call(method: Method, path: &str, api_key: Option<&str>) -> StatusCode {
+        let app = init_service(App::new().service(DeadLetter::server(
+            HashMap::new(),
+            Secret::new(ADMIN_API_KEY.to_string()),
+        )))
+        .await;
+
+        let mut request = TestRequest::default().method(method).uri(path);
+        if let Some(api_key) = api_key {
+            request = request.insert_header((ADMIN_API_KEY_HEADER, api_key));
+        }
+
+        call_service(&app, request.to_request()).await.status()
+    }
+
+    #[actix_web::test]
+    async fn test_routes_reject_missing_admin_api_key() {
+        for (method, path) in routes() {
+            assert_eq!(call(method, path, None).await, StatusCode::UNAUTHORIZED);
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_routes_reject_invalid_admin_api_key() {
+        for (method, path) in routes() {
+            assert_eq!(
+                call(method, path, Some("not_the_admin_key")).await,
+                StatusCode::UNAUTHORIZED
+            );
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_routes_accept_admin_api_key() {
+        // No stores are configured, so an authenticated request fails on the tenant lookup
+        for (method, path) in routes() {
+            assert_eq!(
+                call(method, path, Some(ADMIN_API_KEY)).await,
+                StatusCode::NOT_FOUND
+            );
+        }
+    }
+
+    #[test]
+    fn test_authenticate() {
+        let admin_api_key = AdminApiKey(Secret::new(ADMIN_API_KEY.to_string()));
+
+        let mut headers = HeaderMap::new();
+        assert!(authenticate(&headers, &admin_api_key).is_err());
+
+        headers.insert(
+            HeaderName::from_static(ADMIN_API_KEY_HEADER),
+            HeaderValue::from_static(""),
+        );
+        assert!(authenticate(&headers, &admin_api_key).is_err());
+
+        headers.insert(
+            HeaderName::from_static(ADMIN_API_KEY_HEADER),
+            HeaderValue::from_static(ADMIN_API_KEY),
+        );
+        assert!(authenticate(&headers, &admin_api_key).is_ok());
+    }
+
+    #[test]
+    fn test_only_deterministic_failures_are_counted() {
+        use diesel::result::{DatabaseErrorKind, Error as DieselError};
+        use diesel_models::errors::DatabaseError;
+
+        let from_diesel = |error: DieselError| report!(error).change_context(DatabaseError::Others);
+
+        assert!(is_deterministic_failure(&report!(
+            DatabaseError::QueryGenerationFailed
+        )));
+        assert!(is_deterministic_failure(&from_diesel(
+            DieselError::DatabaseError(
+                DatabaseErrorKind::ForeignKeyViolation,
+                Box::new(String::from("violates foreign key constraint")),
+            )
+        )));
+        assert!(is_deterministic_failure(&from_diesel(
+            DieselError::DeserializationError("invalid value".into())
+        )));
+
+        assert!(!is_deterministic_failure(&report!(
+            DatabaseError::DatabaseConnectionError
+        )));
+        assert!(!is_deterministic_failure(&report!(DatabaseError::Others)));
+        assert!(!is_deterministic_failure(&from_diesel(
+            DieselError::DatabaseError(
+                DatabaseErrorKind::ClosedConnection,
+                Box::new(String::from("connection closed")),
+            )
+        )));
+    }
+}
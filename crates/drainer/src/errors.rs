@@ -27,6 +27,20 @@ pub enum HealthCheckError {
     RedisError { message: String },
 }
 
+#[derive(Debug, Error, Clone, serde::Serialize)]
+pub enum DeadLetterError {
+    #[error("Missing or invalid admin API key")]
+    Unauthorized,
+    #[error("Tenant '{tenant_id}' is not configured")]
+    TenantNotFound { tenant_id: String },
+    #[error("Shard {shard} is out of range, the drainer has {num_partitions} partitions")]
+    InvalidShard { shard: u8, num_partitions: u8 },
+    #[error("Entry '{entry_id}' does not exist in the dead-letter stream")]
+    EntryNotFound { entry_id: String },
+    #[error("Dead-letter stream operation failed with error: {message}")]
+    RedisError { message: String },
+}
+
 impl From<std::io::Error> for DrainerError {
     fn from(err: std::io::Error) -> Self {
         Self::IoError(err)
@@ -56,3 +70,16 @@ impl actix_web::ResponseError for HealthCheckError {
         }
     }
 }
+
+impl actix_web::ResponseError for DeadLetterError {
+    fn status_code(&self) -> reqwest::StatusCode {
+        use reqwest::StatusCode;
+
+        match self {
+            Self::Unauthorized => StatusCode::UNAUTHORIZED,
+            Self::TenantNotFound { .. } | Self::EntryNotFound { .. } => StatusCode::NOT_FOUND,
+            Self::InvalidShard { .. } => StatusCode::BAD_REQUEST,
+            Self::RedisError { .. } => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
@@ -11,8 +11,8 @@ use tokio::{
 };
 
 use crate::{
-    errors, instrument, logger, metrics, query::ExecuteQuery, tracing, utils, DrainerSettings,
-    Store, StreamData,
+    dead_letter, errors, instrument, logger, metrics, query::ExecuteQuery, tracing, utils,
+    DrainerSettings, Store, StreamData,
 };
 
 /// Handler handles the spawning and closing of drainer
@@ -246,39 +246,69 @@ async fn drainer(
     let mut last_processed_id = String::new();
 
     for (entry_id, entry) in entries.clone() {
-        let data = match StreamData::from_hashmap(entry) {
-            Ok(data) => data,
+        let failure = match StreamData::from_hashmap(entry.clone()) {
+            Ok(data) => {
+                tracing::Span::current().record("request_id", data.request_id);
+                tracing::Span::current().record("global_id", data.global_id);
+                logger::debug!("Processing stream entry");
+
+                match data.query.execute_query(&store, data.pushed_at).await {
+                    Ok(_) => None,
+                    Err(err) => match err.current_context() {
+                        // In case of Uniqueviolation we can't really do anything to fix it so
+                        // just clear it from the stream
+                        diesel_models::errors::DatabaseError::UniqueViolation => None,
+                        _ => Some((
+                            format!("{err:#}"),
+                            dead_letter::is_deterministic_failure(&err),
+                        )),
+                    },
+                }
+            }
             Err(error) => {
                 logger::error!(operation = "deserialization", ?error);
                 metrics::STREAM_PARSE_FAIL.add(
                     1,
                     router_env::metric_attributes!(("operation", "deserialization")),
                 );
-
-                // break from the loop in case of a deserialization error
-                break;
+                Some((format!("{error:#}"), true))
             }
         };
 
-        tracing::Span::current().record("request_id", data.request_id);
-        tracing::Span::current().record("global_id", data.global_id);
-        logger::debug!("Processing stream entry");
-
-        match data.query.execute_query(&store, data.pushed_at).await {
-            Ok(_) => {
-                last_processed_id = entry_id;
+        match failure {
+            None => {}
+            // Transient failures are not counted towards the retries of the entry, the stream is
+            // read again after backing off
+            Some((error, false)) => {
+                logger::warn!(
+                    %entry_id,
+                    %error,
+                    "Failed to apply stream entry due to a transient error, backing off"
+                );
+                metrics::STREAM_ENTRY_APPLY_FAILED.add(
+                    1,
+                    router_env::metric_attributes!(("stream", stream_name.to_owned())),
+                );
+                time::sleep(dead_letter::TRANSIENT_FAILURE_BACKOFF).await;
+                break;
             }
-            Err(err) => match err.current_context() {
-                // In case of Uniqueviolation we can't really do anything to fix it so just clear
-                // it from the stream
-                diesel_models::errors::DatabaseError::UniqueViolation => {
-                    last_processed_id = entry_id;
+            // break from the loop unless the entry has exhausted its retries and has been moved
+            // to the dead-letter stream, so that the entries after it are applied in order
+            Some((error, true)) => match store
+                .record_entry_failure(stream_name, &entry_id, entry, error)
+                .await
+            {
+                Ok(true) => {}
+                Ok(false) => break,
+                Err(error) => {
+                    logger::error!(operation = "dead_letter", ?error);
+                    break;
                 }
-                // break from the loop in case of an error in query
-                _ => break,
             },
         }
 
+        last_processed_id = entry_id;
+
         if store.use_legacy_version() {
             store
                 .delete_from_stream(stream_name, &last_processed_id)
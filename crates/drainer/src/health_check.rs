@@ -96,9 +96,15 @@ pub async fn deep_health_check_func(
 
     logger::debug!("Redis health check end");
 
+    let dead_letter_depth = store.get_dead_letter_depth().await.map_err(|error| {
+        let message = error.to_string();
+        error.change_context(HealthCheckError::RedisError { message })
+    })?;
+
     Ok(DrainerHealthCheckResponse {
         database: db_status,
         redis: redis_status,
+        dead_letter_depth,
     })
 }
 
@@ -106,6 +112,8 @@ pub async fn deep_health_check_func(
 pub struct DrainerHealthCheckResponse {
     pub database: bool,
     pub redis: bool,
+    /// Total number of entries in the dead-letter streams of all shards
+    pub dead_letter_depth: usize,
 }
 
 #[async_trait::async_trait]
@@ -1,4 +1,6 @@
+pub mod cli;
 mod connection;
+mod dead_letter;
 pub mod errors;
 mod handler;
 mod health_check;
@@ -60,13 +62,27 @@ pub async fn start_drainer(
     Ok(())
 }
 
+pub async fn run_command(
+    stores: HashMap<id_type::TenantId, Arc<Store>>,
+    conf: DrainerSettings,
+    command: cli::Command,
+) -> errors::DrainerResult<()> {
+    cli::run_command(stores, conf, command).await
+}
+
 pub async fn start_web_server(
     conf: Settings,
     stores: HashMap<id_type::TenantId, Arc<Store>>,
 ) -> Result<Server, errors::DrainerError> {
     let server = conf.server.clone();
+    let admin_api_key = conf.secrets.get_inner().admin_api_key.clone();
     let web_server = actix_web::HttpServer::new(move || {
-        actix_web::App::new().service(health_check::Health::server(conf.clone(), stores.clone()))
+        actix_web::App::new()
+            .service(health_check::Health::server(conf.clone(), stores.clone()))
+            .service(dead_letter::DeadLetter::server(
+                stores.clone(),
+                admin_api_key.clone(),
+            ))
     })
     .bind((server.host.as_str(), server.port))?
     .run();
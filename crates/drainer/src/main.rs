@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 
-use drainer::{errors::DrainerResult, logger, services, settings, start_drainer, start_web_server};
+use drainer::{
+    errors::DrainerResult, logger, run_command, services, settings, start_drainer, start_web_server,
+};
 use router_env::tracing::Instrument;
 
 #[tokio::main]
@@ -41,6 +43,10 @@ async fn main() -> DrainerResult<()> {
         ],
     );
 
+    if let Some(command) = cmd_line.command {
+        return run_command(stores, conf.drainer, command).await;
+    }
+
     #[allow(clippy::expect_used)]
     let web_server = Box::pin(start_web_server(
         state.conf.as_ref().clone(),
@@ -12,6 +12,10 @@ pub trait ExecuteQuery {
         store: &Arc<Store>,
         pushed_at: i64,
     ) -> CustomResult<(), DatabaseError>;
+
+    /// Executes the query in a transaction that is rolled back, returning the number of rows the
+    /// query would have affected
+    async fn dry_run_query(self, store: &Arc<Store>) -> CustomResult<usize, DatabaseError>;
 }
 
 #[async_trait::async_trait]
@@ -49,6 +53,20 @@ impl ExecuteQuery for kv::SerializableQuery {
             }
         }
     }
+
+    async fn dry_run_query(self, store: &Arc<Store>) -> CustomResult<usize, DatabaseError> {
+        let mut conn = pg_connection(&store.master_pool).await;
+        let operation = self.operation().to_string();
+        let entity_type = self.entity_type();
+
+        let result = self.dry_run(&mut conn).await;
+        match &result {
+            Ok(rows_affected) => logger::info!(operation, entity_type, ?rows_affected),
+            Err(error) => logger::error!(operation, entity_type, ?error),
+        }
+
+        result
+    }
 }
 
 #[inline(always)]
@@ -30,6 +30,7 @@ pub struct StoreConfig {
     pub drainer_stream_name: String,
     pub drainer_num_partitions: u8,
     pub use_legacy_version: bool,
+    pub drainer_max_retry_count: u32,
 }
 
 impl Store {
@@ -54,6 +55,7 @@ impl Store {
                 drainer_stream_name: config.drainer.stream_name.clone(),
                 drainer_num_partitions: config.drainer.num_partitions,
                 use_legacy_version: config.redis.use_legacy_version,
+                drainer_max_retry_count: config.drainer.max_retry_count,
             },
             request_id: None,
         }
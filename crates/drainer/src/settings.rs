@@ -26,6 +26,9 @@ pub struct CmdLineConf {
     /// Application will look for "config/config.toml" if this option isn't specified.
     #[arg(short = 'f', long, value_name = "FILE")]
     pub config_path: Option<PathBuf>,
+
+    #[command(subcommand)]
+    pub command: Option<crate::cli::Command>,
 }
 
 #[derive(Clone)]
@@ -68,6 +71,7 @@ impl AppState {
 pub struct Settings<S: SecretState> {
     pub server: Server,
     pub master_database: SecretStateContainer<Database, S>,
+    pub secrets: SecretStateContainer<Secrets, S>,
     pub redis: redis::RedisSettings,
     pub log: Log,
     pub drainer: DrainerSettings,
@@ -76,6 +80,13 @@ pub struct Settings<S: SecretState> {
     pub multitenancy: Multitenancy,
 }
 
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct Secrets {
+    /// API key required by the dead-letter admin routes
+    pub admin_api_key: Secret<String>,
+}
+
 #[derive(Debug, Deserialize, Clone)]
 #[serde(default)]
 pub struct Database {
@@ -114,6 +125,7 @@ pub struct DrainerSettings {
     pub max_read_count: u64,
     pub shutdown_interval: u32, // in milliseconds
     pub loop_interval: u32,     // in milliseconds
+    pub max_retry_count: u32,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -224,6 +236,7 @@ impl Default for DrainerSettings {
             max_read_count: 100,
             shutdown_interval: 1000, // in milliseconds
             loop_interval: 100,      // in milliseconds
+            max_retry_count: 5,
         }
     }
 }
@@ -268,12 +281,30 @@ impl Database {
     }
 }
 
+impl Secrets {
+    fn validate(&self) -> Result<(), errors::DrainerError> {
+        common_utils::fp_utils::when(self.admin_api_key.is_default_or_empty(), || {
+            Err(errors::DrainerError::ConfigParsingError(
+                "admin API key must not be empty".into(),
+            ))
+        })
+    }
+}
+
 impl DrainerSettings {
     fn validate(&self) -> Result<(), errors::DrainerError> {
-        common_utils::fp_utils::when(self.stream_name.is_default_or_empty(), || {
+        use common_utils::fp_utils::when;
+
+        when(self.stream_name.is_default_or_empty(), || {
             Err(errors::DrainerError::ConfigParsingError(
                 "drainer stream name must not be empty".into(),
             ))
+        })?;
+
+        when(self.max_retry_count == 0, || {
+            Err(errors::DrainerError::ConfigParsingError(
+                "drainer max retry count must be greater than zero".into(),
+            ))
         })
     }
 }
@@ -323,6 +354,7 @@ impl Settings<SecuredSecret> {
     pub fn validate(&self) -> Result<(), errors::DrainerError> {
         self.server.validate()?;
         self.master_database.get_inner().validate()?;
+        self.secrets.get_inner().validate()?;
 
         // The logger may not yet be initialized when validating the application configuration
         #[allow(clippy::print_stderr)]
@@ -23,6 +23,17 @@ impl Store {
         self.drainer_stream(format!("shard_{stream_index}").as_str())
     }
 
+    #[inline(always)]
+    pub(crate) fn get_dead_letter_stream_name(&self, stream_name: &str) -> String {
+        // Example: {shard_5}_drainer_stream_dead_letter
+        format!("{stream_name}_dead_letter")
+    }
+
+    #[inline(always)]
+    pub(crate) fn get_failure_count_key(&self, stream_name: &str) -> String {
+        format!("{stream_name}_failures")
+    }
+
     #[router_env::instrument(skip_all)]
     pub async fn is_stream_available(&self, stream_index: u8) -> bool {
         let stream_key_flag = self.get_stream_key_flag(stream_index);
@@ -20,6 +20,25 @@ pub fn parse_stream_entries<'a>(
     })
 }
 
+/// Parses a stream entry id of the form `<milliseconds>-<sequence_number>`
+pub(crate) fn parse_entry_id(entry_id: &str) -> Option<(u64, u64)> {
+    let (milliseconds, sequence_number) = entry_id.split_once('-')?;
+    Some((milliseconds.parse().ok()?, sequence_number.parse().ok()?))
+}
+
+/// Returns the entry id immediately preceding the given one. Streams can only be read after a
+/// given entry id, reading after this id yields the given entry first.
+pub(crate) fn previous_entry_id(entry_id: &str) -> Option<String> {
+    let (milliseconds, sequence_number) = parse_entry_id(entry_id)?;
+
+    match sequence_number.checked_sub(1) {
+        Some(sequence_number) => Some(format!("{milliseconds}-{sequence_number}")),
+        None => milliseconds
+            .checked_sub(1)
+            .map(|milliseconds| format!("{milliseconds}-{}", u64::MAX)),
+    }
+}
+
 pub(crate) fn deserialize_i64<'de, D>(deserializer: D) -> Result<i64, D::Error>
 where
     D: serde::Deserializer<'de>,
@@ -72,3 +91,67 @@ pub async fn increment_stream_index(
         index + 1
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_entry_id() {
+        assert_eq!(
+            parse_entry_id("1526919030474-55"),
+            Some((1526919030474, 55))
+        );
+        assert_eq!(parse_entry_id("0-0"), Some((0, 0)));
+        assert_eq!(
+            parse_entry_id(&format!("{}-{}", u64::MAX, u64::MAX)),
+            Some((u64::MAX, u64::MAX))
+        );
+    }
+
+    #[test]
+    fn test_parse_entry_id_rejects_malformed_ids() {
+        for entry_id in [
+            "",
+            "-",
+            "1526919030474",
+            "1526919030474-",
+            "-55",
+            "abc-55",
+            "1526919030474-abc",
+            "1526919030474-55-1",
+            "-1-55",
+            "18446744073709551616-0",
+        ] {
+            assert_eq!(parse_entry_id(entry_id), None, "{entry_id}");
+        }
+    }
+
+    #[test]
+    fn test_previous_entry_id_decrements_sequence_number() {
+        assert_eq!(
+            previous_entry_id("1526919030474-55").as_deref(),
+            Some("1526919030474-54")
+        );
+        assert_eq!(
+            previous_entry_id("1526919030474-1").as_deref(),
+            Some("1526919030474-0")
+        );
+    }
+
+    #[test]
+    fn test_previous_entry_id_borrows_from_milliseconds() {
+        assert_eq!(
+            previous_entry_id("1526919030474-0"),
+            Some(format!("1526919030473-{}", u64::MAX))
+        );
+        assert_eq!(previous_entry_id("1-0"), Some(format!("0-{}", u64::MAX)));
+    }
+
+    #[test]
+    fn test_previous_entry_id_of_first_or_malformed_id() {
+        assert_eq!(previous_entry_id("0-0"), None);
+        assert_eq!(previous_entry_id("not-an-id"), None);
+        assert_eq!(previous_entry_id("1526919030474"), None);
+    }
+}
@@ -3,7 +3,7 @@
 use common_utils::types::MinorUnit;
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use euclid::{
-    backend::{inputs, EuclidBackend, InterpreterBackend, VirInterpreterBackend},
+    backend::{inputs, CompiledBackend, EuclidBackend, InterpreterBackend, VirInterpreterBackend},
     enums,
     frontend::ast::{self, parser},
     types::DummyOutput,
@@ -65,6 +65,7 @@ fn get_program_data() -> (ast::Program<DummyOutput>, inputs::BackendInput) {
         issuer_data: None,
         acquirer_data: None,
         customer_device_data: None,
+        transaction_time: None,
     };
 
     let (_, program) = parser::program(code1).expect("Parser");
@@ -78,7 +79,9 @@ fn interpreter_vs_jit_vs_vir_interpreter(c: &mut Criterion) {
     let interp_b = InterpreterBackend::with_program(program.clone()).expect("Interpreter backend");
 
     let vir_interp_b =
-        VirInterpreterBackend::with_program(program).expect("Vir Interpreter Backend");
+        VirInterpreterBackend::with_program(program.clone()).expect("Vir Interpreter Backend");
+
+    let compiled_b = CompiledBackend::with_program(program).expect("Compiled Backend");
 
     c.bench_function("Raw Interpreter Backend", |b| {
         b.iter(|| {
@@ -95,6 +98,14 @@ fn interpreter_vs_jit_vs_vir_interpreter(c: &mut Criterion) {
                 .expect("Vir Interpreter execution");
         })
     });
+
+    c.bench_function("Compiled Backend", |b| {
+        b.iter(|| {
+            compiled_b
+                .execute(binputs.clone())
+                .expect("Compiled Backend execution");
+        })
+    });
 }
 
 criterion_group!(benches, interpreter_vs_jit_vs_vir_interpreter);
@@ -1,8 +1,12 @@
+#[cfg(feature = "valued_jit")]
+pub mod compiled_backend;
 pub mod inputs;
 pub mod interpreter;
 #[cfg(feature = "valued_jit")]
 pub mod vir_interpreter;
 
+#[cfg(feature = "valued_jit")]
+pub use compiled_backend::CompiledBackend;
 pub use inputs::BackendInput;
 pub use interpreter::InterpreterBackend;
 #[cfg(feature = "valued_jit")]
@@ -0,0 +1,748 @@
+//! A backend that compiles a program once into an index over the values it compares against.
+//!
+//! Every statement of a rule is flattened into the comparisons along each of its root to leaf
+//! paths, and each distinct comparison in the program is evaluated at most once per execution.
+//! Comparisons on enum, string and metadata keys are resolved by looking up the values present in
+//! the input in a value to comparison index, while comparisons on numeric keys are evaluated
+//! directly. The first rule having a path whose comparisons are all satisfied is selected, which
+//! produces the same output as the [`super::VirInterpreterBackend`].
+
+pub mod types;
+
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    backend::{self, inputs, vir_interpreter, EuclidBackend},
+    frontend::{
+        ast,
+        dir::{self, EuclidDirFilter},
+        vir,
+    },
+    types::{DataType, EuclidValue},
+};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct CompiledComparison {
+    values: Vec<EuclidValue>,
+    /// Whether the comparison holds when any of its values is present, as opposed to when none of
+    /// them are
+    positive: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct CompiledRule<O> {
+    name: String,
+    connector_selection: O,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct CompiledPath {
+    rule: usize,
+    comparison_count: usize,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CompiledBackend<O> {
+    default_selection: O,
+    rules: Vec<CompiledRule<O>>,
+    comparisons: Vec<CompiledComparison>,
+    /// Comparisons on non numeric keys, indexed by each of the values they compare against
+    value_index: FxHashMap<EuclidValue, Vec<usize>>,
+    /// Comparisons on numeric keys, which are evaluated against the input directly
+    numeric_comparisons: Vec<usize>,
+    paths: Vec<CompiledPath>,
+    /// The paths that each comparison is a part of
+    comparison_paths: Vec<Vec<usize>>,
+    /// The first rule having a statement without any comparisons, which always matches
+    unconditional_rule: Option<usize>,
+}
+
+#[derive(Default)]
+struct Compiler {
+    comparisons: Vec<CompiledComparison>,
+    interned: FxHashMap<(bool, Vec<EuclidValue>), usize>,
+}
+
+impl Compiler {
+    fn intern_comparison(&mut self, comparison: vir::ValuedComparison) -> usize {
+        let positive = matches!(
+            comparison.logic,
+            vir::ValuedComparisonLogic::PositiveDisjunction
+        );
+        let key = (positive, comparison.values);
+
+        if let Some(id) = self.interned.get(&key) {
+            return *id;
+        }
+
+        let id = self.comparisons.len();
+        self.comparisons.push(CompiledComparison {
+            values: key.1.clone(),
+            positive,
+        });
+        self.interned.insert(key, id);
+        id
+    }
+
+    /// Flattens the statement into the comparisons along each of its root to leaf paths. A
+    /// statement with an empty list of nested statements can never match and has no paths.
+    fn flatten_statement(
+        &mut self,
+        statement: vir::ValuedIfStatement,
+        prefix: &[usize],
+        paths: &mut Vec<Vec<usize>>,
+    ) {
+        let mut path = prefix.to_vec();
+        path.extend(
+            statement
+                .condition
+                .into_iter()
+                .map(|comparison| self.intern_comparison(comparison)),
+        );
+
+        match statement.nested {
+            None => paths.push(path),
+            Some(nested) => {
+                for nested_statement in nested {
+                    self.flatten_statement(nested_statement, &path, paths);
+                }
+            }
+        }
+    }
+}
+
+impl<O> CompiledBackend<O>
+where
+    O: Clone,
+{
+    fn compile(program: vir::ValuedProgram<O>) -> Self {
+        let mut compiler = Compiler::default();
+        let mut rules = Vec::with_capacity(program.rules.len());
+        let mut paths = Vec::new();
+        let mut path_comparisons = Vec::new();
+        let mut unconditional_rule = None;
+
+        for (rule_index, rule) in program.rules.into_iter().enumerate() {
+            let mut rule_paths = Vec::new();
+            for statement in rule.statements {
+                compiler.flatten_statement(statement, &[], &mut rule_paths);
+            }
+
+            for mut comparisons in rule_paths {
+                comparisons.sort_unstable();
+                comparisons.dedup();
+
+                if comparisons.is_empty() {
+                    unconditional_rule.get_or_insert(rule_index);
+                } else {
+                    paths.push(CompiledPath {
+                        rule: rule_index,
+                        comparison_count: comparisons.len(),
+                    });
+                    path_comparisons.push(comparisons);
+                }
+            }
+
+            rules.push(CompiledRule {
+                name: rule.name,
+                connector_selection: rule.connector_selection,
+            });
+        }
+
+        let mut comparison_paths = vec![Vec::new(); compiler.comparisons.len()];
+        for (path_index, comparisons) in path_comparisons.iter().enumerate() {
+            for comparison in comparisons {
+                if let Some(paths) = comparison_paths.get_mut(*comparison) {
+                    paths.push(path_index);
+                }
+            }
+        }
+
+        let mut value_index: FxHashMap<EuclidValue, Vec<usize>> = FxHashMap::default();
+        let mut numeric_comparisons = Vec::new();
+        for (id, comparison) in compiler.comparisons.iter().enumerate() {
+            let is_numeric = comparison
+                .values
+                .iter()
+                .any(|value| matches!(value.get_key().key_type(), DataType::Number));
+
+            if is_numeric {
+                numeric_comparisons.push(id);
+            } else {
+                for value in &comparison.values {
+                    value_index.entry(value.clone()).or_default().push(id);
+                }
+            }
+        }
+
+        Self {
+            default_selection: program.default_selection,
+            rules,
+            comparisons: compiler.comparisons,
+            value_index,
+            numeric_comparisons,
+            paths,
+            comparison_paths,
+            unconditional_rule,
+        }
+    }
+
+    fn eval_comparisons(&self, ctx: &vir_interpreter::types::Context) -> Vec<bool> {
+        let mut satisfied = self
+            .comparisons
+            .iter()
+            .map(|comparison| !comparison.positive)
+            .collect::<Vec<_>>();
+
+        for value in ctx.atomic_values() {
+            for id in self.value_index.get(value).into_iter().flatten() {
+                if let Some((is_satisfied, comparison)) =
+                    satisfied.get_mut(*id).zip(self.comparisons.get(*id))
+                {
+                    *is_satisfied = comparison.positive;
+                }
+            }
+        }
+
+        for id in &self.numeric_comparisons {
+            if let Some((is_satisfied, comparison)) =
+                satisfied.get_mut(*id).zip(self.comparisons.get(*id))
+            {
+                let is_any_present = comparison
+                    .values
+                    .iter()
+                    .any(|value| ctx.check_presence(value));
+                *is_satisfied = is_any_present == comparison.positive;
+            }
+        }
+
+        satisfied
+    }
+
+    fn eval_program(&self, ctx: &vir_interpreter::types::Context) -> backend::BackendOutput<O> {
+        let satisfied = self.eval_comparisons(ctx);
+        let mut remaining = self
+            .paths
+            .iter()
+            .map(|path| path.comparison_count)
+            .collect::<Vec<_>>();
+        let mut selected_rule = self.unconditional_rule;
+
+        for (paths, _) in self
+            .comparison_paths
+            .iter()
+            .zip(satisfied)
+            .filter(|(_, is_satisfied)| *is_satisfied)
+        {
+            for path_index in paths {
+                if let Some((count, path)) = remaining
+                    .get_mut(*path_index)
+                    .zip(self.paths.get(*path_index))
+                {
+                    *count = count.saturating_sub(1);
+                    if *count == 0 && selected_rule.is_none_or(|rule| path.rule < rule) {
+                        selected_rule = Some(path.rule);
+                    }
+                }
+            }
+        }
+
+        selected_rule
+            .and_then(|rule| self.rules.get(rule))
+            .map_or_else(
+                || backend::BackendOutput {
+                    connector_selection: self.default_selection.clone(),
+                    rule_name: None,
+                },
+                |rule| backend::BackendOutput {
+                    connector_selection: rule.connector_selection.clone(),
+                    rule_name: Some(rule.name.clone()),
+                },
+            )
+    }
+}
+
+impl<O> EuclidBackend<O> for CompiledBackend<O>
+where
+    O: Clone + EuclidDirFilter,
+{
+    type Error = types::CompiledBackendError;
+
+    fn with_program(program: ast::Program<O>) -> Result<Self, Self::Error> {
+        let dir_program = ast::lowering::lower_program(program)
+            .map_err(types::CompiledBackendError::LoweringError)?;
+
+        let vir_program = dir::lowering::lower_program(dir_program)
+            .map_err(types::CompiledBackendError::LoweringError)?;
+
+        Ok(Self::compile(vir_program))
+    }
+
+    fn execute(
+        &self,
+        input: inputs::BackendInput,
+    ) -> Result<backend::BackendOutput<O>, Self::Error> {
+        let ctx = vir_interpreter::types::Context::from_input(input);
+        Ok(self.eval_program(&ctx))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use common_utils::types::MinorUnit;
+    use proptest::{
+        collection::vec,
+        option,
+        prelude::*,
+        sample::{select, subsequence},
+        strategy::Union,
+    };
+    use rustc_hash::FxHashMap;
+
+    use super::*;
+    use crate::{
+        backend::{InterpreterBackend, VirInterpreterBackend},
+        enums,
+        types::DummyOutput,
+    };
+
+    fn comparison(
+        lhs: &str,
+        comparison: ast::ComparisonType,
+        value: ast::ValueType,
+    ) -> ast::Comparison {
+        ast::Comparison {
+            lhs: lhs.to_string(),
+            comparison,
+            value,
+            metadata: Default::default(),
+        }
+    }
+
+    fn enum_comparison(
+        lhs: &'static str,
+        variants: Vec<String>,
+    ) -> impl Strategy<Value = ast::Comparison> {
+        let variant_count = variants.len();
+        (any::<bool>(), subsequence(variants, 1..=variant_count)).prop_map(
+            move |(is_negated, mut values)| {
+                let comparison_type = if is_negated {
+                    ast::ComparisonType::NotEqual
+                } else {
+                    ast::ComparisonType::Equal
+                };
+                let value = if values.len() == 1 {
+                    ast::ValueType::EnumVariant(values.swap_remove(0))
+                } else {
+                    ast::ValueType::EnumVariantArray(values)
+                };
+
+                comparison(lhs, comparison_type, value)
+            },
+        )
+    }
+
+    /// The interpreter and the valued interpreter disagree on negated number comparisons, so
+    /// those are only generated when comparing against the valued interpreter
+    fn amount_comparison(is_interpreter_compatible: bool) -> BoxedStrategy<ast::Comparison> {
+        let relational_types = vec![
+            ast::ComparisonType::Equal,
+            ast::ComparisonType::LessThan,
+            ast::ComparisonType::LessThanEqual,
+            ast::ComparisonType::GreaterThan,
+            ast::ComparisonType::GreaterThanEqual,
+        ];
+        let mut equality_types = vec![ast::ComparisonType::Equal];
+        if !is_interpreter_compatible {
+            equality_types.push(ast::ComparisonType::NotEqual);
+        }
+        let single_number_types = equality_types
+            .iter()
+            .skip(1)
+            .chain(relational_types.iter())
+            .cloned()
+            .collect::<Vec<_>>();
+        let mut all_types = relational_types;
+        all_types.push(ast::ComparisonType::NotEqual);
+
+        prop_oneof![
+            (select(single_number_types), 0i64..100).prop_map(|(comparison_type, number)| {
+                comparison(
+                    "amount",
+                    comparison_type,
+                    ast::ValueType::Number(MinorUnit::new(number)),
+                )
+            }),
+            (select(equality_types), vec(0i64..100, 1..4)).prop_map(
+                |(comparison_type, numbers)| {
+                    comparison(
+                        "amount",
+                        comparison_type,
+                        ast::ValueType::NumberArray(
+                            numbers.into_iter().map(MinorUnit::new).collect(),
+                        ),
+                    )
+                }
+            ),
+            vec((select(all_types), 0i64..100), 1..4).prop_map(|comparisons| {
+                comparison(
+                    "amount",
+                    ast::ComparisonType::Equal,
+                    ast::ValueType::NumberComparisonArray(
+                        comparisons
+                            .into_iter()
+                            .map(|(comparison_type, number)| ast::NumberComparison {
+                                comparison_type,
+                                number: MinorUnit::new(number),
+                            })
+                            .collect(),
+                    ),
+                )
+            }),
+        ]
+        .boxed()
+    }
+
+    fn metadata_comparison() -> impl Strategy<Value = ast::Comparison> {
+        (
+            select(vec!["merchant_tier", "channel"]),
+            select(vec!["gold", "web"]),
+        )
+            .prop_map(|(key, value)| {
+                comparison(
+                    "metadata",
+                    ast::ComparisonType::Equal,
+                    ast::ValueType::MetadataVariant(ast::MetadataValue {
+                        key: key.to_string(),
+                        value: value.to_string(),
+                    }),
+                )
+            })
+    }
+
+    fn any_comparison(is_interpreter_compatible: bool) -> BoxedStrategy<ast::Comparison> {
+        let mut strategies = vec![
+            enum_comparison(
+                "payment_method",
+                [
+                    enums::PaymentMethod::Card,
+                    enums::PaymentMethod::Wallet,
+                    enums::PaymentMethod::PayLater,
+                    enums::PaymentMethod::BankRedirect,
+                ]
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            )
+            .boxed(),
+            enum_comparison(
+                "currency",
+                [
+                    enums::Currency::USD,
+                    enums::Currency::EUR,
+                    enums::Currency::GBP,
+                ]
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            )
+            .boxed(),
+            enum_comparison(
+                "capture_method",
+                [
+                    enums::CaptureMethod::Automatic,
+                    enums::CaptureMethod::Manual,
+                ]
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            )
+            .boxed(),
+            enum_comparison(
+                "authentication_type",
+                [
+                    enums::AuthenticationType::ThreeDs,
+                    enums::AuthenticationType::NoThreeDs,
+                ]
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            )
+            .boxed(),
+            amount_comparison(is_interpreter_compatible),
+        ];
+        if !is_interpreter_compatible {
+            strategies.push(metadata_comparison().boxed());
+        }
+
+        Union::new(strategies).boxed()
+    }
+
+    fn any_statement(is_interpreter_compatible: bool) -> impl Strategy<Value = ast::IfStatement> {
+        let condition = vec(any_comparison(is_interpreter_compatible), 0..4);
+
+        condition
+            .clone()
+            .prop_map(|condition| ast::IfStatement {
+                condition,
+                nested: None,
+            })
+            .prop_recursive(3, 16, 3, move |nested| {
+                (condition.clone(), vec(nested, 0..3)).prop_map(|(condition, nested)| {
+                    ast::IfStatement {
+                        condition,
+                        nested: Some(nested),
+                    }
+                })
+            })
+    }
+
+    fn any_program(
+        is_interpreter_compatible: bool,
+    ) -> impl Strategy<Value = ast::Program<DummyOutput>> {
+        vec(vec(any_statement(is_interpreter_compatible), 1..4), 0..8).prop_map(|rules| {
+            ast::Program {
+                default_selection: DummyOutput {
+                    outputs: vec!["default".to_string()],
+                },
+                rules: rules
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, statements)| ast::Rule {
+                        name: format!("rule_{index}"),
+                        connector_selection: DummyOutput {
+                            outputs: vec![format!("output_{index}")],
+                        },
+                        statements,
+                    })
+                    .collect(),
+                metadata: Default::default(),
+            }
+        })
+    }
+
+    /// Generates inputs for the keys used in the generated programs. The interpreter evaluates any
+    /// comparison on a missing key to false, including negated ones, so those keys are always
+    /// present when comparing against it.
+    fn any_input(is_interpreter_compatible: bool) -> impl Strategy<Value = inputs::BackendInput> {
+        let may_be_missing = !is_interpreter_compatible;
+
+        (
+            (
+                select(vec![
+                    enums::PaymentMethod::Card,
+                    enums::PaymentMethod::Wallet,
+                    enums::PaymentMethod::PayLater,
+                    enums::PaymentMethod::BankRedirect,
+                ]),
+                any::<bool>(),
+            ),
+            select(vec![
+                enums::Currency::USD,
+                enums::Currency::EUR,
+                enums::Currency::GBP,
+            ]),
+            (
+                select(vec![
+                    enums::CaptureMethod::Automatic,
+                    enums::CaptureMethod::Manual,
+                ]),
+                any::<bool>(),
+            ),
+            (
+                select(vec![
+                    enums::AuthenticationType::ThreeDs,
+                    enums::AuthenticationType::NoThreeDs,
+                ]),
+                any::<bool>(),
+            ),
+            0i64..100,
+            option::of(select(vec![
+                ("merchant_tier", "gold"),
+                ("merchant_tier", "web"),
+                ("channel", "web"),
+            ])),
+        )
+            .prop_map(
+                move |(
+                    (payment_method, is_payment_method_missing),
+                    currency,
+                    (capture_method, is_capture_method_missing),
+                    (authentication_type, is_authentication_type_missing),
+                    amount,
+                    metadata,
+                )| inputs::BackendInput {
+                    metadata: metadata.filter(|_| may_be_missing).map(|(key, value)| {
+                        FxHashMap::from_iter([(key.to_string(), value.to_string())])
+                    }),
+                    payment: inputs::PaymentInput {
+                        amount: MinorUnit::new(amount),
+                        transaction_initiator: None,
+                        card_bin: None,
+                        extended_card_bin: None,
+                        currency,
+                        authentication_type: Some(authentication_type)
+                            .filter(|_| !(may_be_missing && is_authentication_type_missing)),
+                        capture_method: Some(capture_method)
+                            .filter(|_| !(may_be_missing && is_capture_method_missing)),
+                        business_country: None,
+                        billing_country: None,
+                        business_label: None,
+                        setup_future_usage: None,
+                        surcharge_amount: None,
+                    },
+                    payment_method: inputs::PaymentMethodInput {
+                        payment_method: Some(payment_method)
+                            .filter(|_| !(may_be_missing && is_payment_method_missing)),
+                        payment_method_type: None,
+                        card_network: None,
+                        card_discovery: None,
+                    },
+                    mandate: inputs::MandateData {
+                        mandate_acceptance_type: None,
+                        mandate_type: None,
+                        payment_type: None,
+                    },
+                    acquirer_data: None,
+                    customer_device_data: None,
+                    issuer_data: None,
+                    transaction_time: None,
+                },
+            )
+    }
+
+    proptest! {
+        #[test]
+        fn compiled_backend_matches_vir_interpreter(
+            program in any_program(false),
+            input in any_input(false)
+        ) {
+            let vir_backend =
+                VirInterpreterBackend::with_program(program.clone()).expect("Vir backend");
+            let compiled_backend = CompiledBackend::with_program(program).expect("Compiled backend");
+
+            let expected = vir_backend.execute(input.clone()).expect("Vir execution");
+            let result = compiled_backend.execute(input).expect("Compiled execution");
+
+            prop_assert_eq!(result.rule_name, expected.rule_name);
+            prop_assert_eq!(
+                result.connector_selection.outputs,
+                expected.connector_selection.outputs
+            );
+        }
+
+        #[test]
+        fn compiled_backend_matches_interpreter(
+            program in any_program(true),
+            input in any_input(true)
+        ) {
+            let interpreter_backend =
+                InterpreterBackend::with_program(program.clone()).expect("Interpreter backend");
+            let vir_backend =
+                VirInterpreterBackend::with_program(program.clone()).expect("Vir backend");
+            let compiled_backend = CompiledBackend::with_program(program).expect("Compiled backend");
+
+            let expected = interpreter_backend
+                .execute(input.clone())
+                .expect("Interpreter execution");
+            let vir_result = vir_backend.execute(input.clone()).expect("Vir execution");
+            let result = compiled_backend.execute(input).expect("Compiled execution");
+
+            prop_assert_eq!(&vir_result.rule_name, &expected.rule_name);
+            prop_assert_eq!(result.rule_name, expected.rule_name);
+            prop_assert_eq!(
+                result.connector_selection.outputs,
+                expected.connector_selection.outputs
+            );
+        }
+    }
+
+    #[test]
+    fn test_nested_statements_and_shared_comparisons() {
+        let card = comparison(
+            "payment_method",
+            ast::ComparisonType::Equal,
+            ast::ValueType::EnumVariant("card".to_string()),
+        );
+        let large_amount = comparison(
+            "amount",
+            ast::ComparisonType::GreaterThan,
+            ast::ValueType::Number(MinorUnit::new(50)),
+        );
+        let program = ast::Program {
+            default_selection: DummyOutput {
+                outputs: vec!["default".to_string()],
+            },
+            rules: vec![
+                ast::Rule {
+                    name: "large_card".to_string(),
+                    connector_selection: DummyOutput {
+                        outputs: vec!["stripe".to_string()],
+                    },
+                    statements: vec![ast::IfStatement {
+                        condition: vec![card.clone()],
+                        nested: Some(vec![ast::IfStatement {
+                            condition: vec![large_amount, card.clone()],
+                            nested: None,
+                        }]),
+                    }],
+                },
+                ast::Rule {
+                    name: "card".to_string(),
+                    connector_selection: DummyOutput {
+                        outputs: vec!["adyen".to_string()],
+                    },
+                    statements: vec![ast::IfStatement {
+                        condition: vec![card],
+                        nested: Some(vec![]),
+                    }],
+                },
+            ],
+            metadata: Default::default(),
+        };
+
+        let backend = CompiledBackend::with_program(program).expect("Program");
+        assert_eq!(backend.comparisons.len(), 2);
+
+        let input = |amount| inputs::BackendInput {
+            metadata: None,
+            payment: inputs::PaymentInput {
+                amount: MinorUnit::new(amount),
+                transaction_initiator: None,
+                card_bin: None,
+                extended_card_bin: None,
+                currency: enums::Currency::USD,
+                authentication_type: None,
+                capture_method: None,
+                business_country: None,
+                billing_country: None,
+                business_label: None,
+                setup_future_usage: None,
+                surcharge_amount: None,
+            },
+            payment_method: inputs::PaymentMethodInput {
+                payment_method: Some(enums::PaymentMethod::Card),
+                payment_method_type: None,
+                card_network: None,
+                card_discovery: None,
+            },
+            mandate: inputs::MandateData {
+                mandate_acceptance_type: None,
+                mandate_type: None,
+                payment_type: None,
+            },
+            acquirer_data: None,
+            customer_device_data: None,
+            issuer_data: None,
+            transaction_time: None,
+        };
+
+        let result = backend.execute(input(80)).expect("Execution");
+        assert_eq!(result.rule_name.as_deref(), Some("large_card"));
+
+        // A statement with an empty list of nested statements never matches
+        let result = backend.execute(input(20)).expect("Execution");
+        assert_eq!(result.rule_name, None);
+    }
+}
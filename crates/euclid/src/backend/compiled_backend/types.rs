@@ -0,0 +1,7 @@
+use crate::dssa;
+
+#[derive(Debug, Clone, serde::Serialize, thiserror::Error)]
+pub enum CompiledBackendError {
+    #[error("Error when lowering the program: {0:?}")]
+    LoweringError(dssa::types::AnalysisError),
+}
@@ -4,7 +4,8 @@ use serde::{Deserialize, Serialize};
 use crate::{
     enums,
     frontend::dir::enums::{
-        CustomerDeviceDisplaySize, CustomerDevicePlatform, CustomerDeviceType, TransactionInitiator,
+        CustomerDeviceDisplaySize, CustomerDevicePlatform, CustomerDeviceType, DayOfWeek,
+        TransactionInitiator,
     },
 };
 
@@ -58,6 +59,16 @@ pub struct IssuerDataInput {
     pub country: Option<enums::Country>,
 }
 
+/// Time of the transaction, in the timezone the routing rules are evaluated in.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransactionTimeInput {
+    pub day_of_week: DayOfWeek,
+    /// Hour of the day, from 0 to 23
+    pub hour_of_day: u8,
+    /// Date as a number of the form YYYYMMDD
+    pub date: u32,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BackendInput {
     pub metadata: Option<FxHashMap<String, String>>,
@@ -67,4 +78,6 @@ pub struct BackendInput {
     pub customer_device_data: Option<CustomerDeviceDataInput>,
     pub issuer_data: Option<IssuerDataInput>,
     pub mandate: MandateData,
+    #[serde(default)]
+    pub transaction_time: Option<TransactionTimeInput>,
 }
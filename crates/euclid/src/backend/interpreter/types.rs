@@ -1,5 +1,6 @@
 use std::{collections::HashMap, fmt, ops::Deref, string::ToString};
 
+use common_utils::types::MinorUnit;
 use serde::Serialize;
 
 use crate::{backend::inputs, frontend::ast::ValueType, types::EuclidKey};
@@ -74,6 +75,27 @@ impl From<inputs::BackendInput> for Context {
                 EuclidKey::PaymentCurrency.to_string(),
                 Some(ValueType::EnumVariant(input.payment.currency.to_string())),
             ),
+            (
+                EuclidKey::DayOfWeek.to_string(),
+                input
+                    .transaction_time
+                    .as_ref()
+                    .map(|time| ValueType::EnumVariant(time.day_of_week.to_string())),
+            ),
+            (
+                EuclidKey::HourOfDay.to_string(),
+                input
+                    .transaction_time
+                    .as_ref()
+                    .map(|time| ValueType::Number(MinorUnit::new(time.hour_of_day.into()))),
+            ),
+            (
+                EuclidKey::TransactionDate.to_string(),
+                input
+                    .transaction_time
+                    .as_ref()
+                    .map(|time| ValueType::Number(MinorUnit::new(time.date.into()))),
+            ),
         ]);
 
         Self(ctx)
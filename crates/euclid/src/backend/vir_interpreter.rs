@@ -159,6 +159,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            transaction_time: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -206,6 +207,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            transaction_time: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -254,6 +256,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            transaction_time: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -302,6 +305,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            transaction_time: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -350,6 +354,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            transaction_time: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -398,6 +403,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            transaction_time: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -446,6 +452,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            transaction_time: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -494,6 +501,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            transaction_time: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -542,6 +550,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            transaction_time: None,
         };
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
         let result = backend.execute(inp).expect("Execution");
@@ -591,6 +600,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            transaction_time: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -639,6 +649,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            transaction_time: None,
         };
         let mut inp_equal = inp_greater.clone();
         inp_equal.payment.amount = MinorUnit::new(123);
@@ -696,6 +707,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            transaction_time: None,
         };
         let mut inp_equal = inp_lower.clone();
         inp_equal.payment.amount = MinorUnit::new(123);
@@ -711,4 +723,100 @@ mod test {
             "rule_1"
         );
     }
+
+    #[test]
+    fn test_transaction_time() {
+        let program_str = r#"
+        default: ["stripe", "adyen"]
+
+        rule_1: ["adyen"]
+        {
+           day_of_week = (saturday, sunday)
+        }
+
+        rule_2: ["paypal"]
+        {
+           hour_of_day >= 0 & hour_of_day < 4
+           transaction_date >= 20261224 & transaction_date <= 20261226
+        }
+        "#;
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let inp_weekday_noon = inputs::BackendInput {
+            metadata: None,
+            payment: inputs::PaymentInput {
+                amount: MinorUnit::new(120),
+                transaction_initiator: None,
+                card_bin: None,
+                extended_card_bin: None,
+                currency: enums::Currency::USD,
+                authentication_type: Some(enums::AuthenticationType::NoThreeDs),
+                capture_method: Some(enums::CaptureMethod::Automatic),
+                business_country: Some(enums::Country::UnitedStatesOfAmerica),
+                billing_country: Some(enums::Country::France),
+                business_label: None,
+                setup_future_usage: None,
+                surcharge_amount: None,
+            },
+            payment_method: inputs::PaymentMethodInput {
+                payment_method: Some(enums::PaymentMethod::PayLater),
+                payment_method_type: Some(enums::PaymentMethodType::Affirm),
+                card_network: None,
+                card_discovery: None,
+            },
+            mandate: inputs::MandateData {
+                mandate_acceptance_type: None,
+                mandate_type: None,
+                payment_type: None,
+            },
+            acquirer_data: None,
+            customer_device_data: None,
+            issuer_data: None,
+            transaction_time: Some(inputs::TransactionTimeInput {
+                day_of_week: dir::enums::DayOfWeek::Wednesday,
+                hour_of_day: 12,
+                date: 20261021,
+            }),
+        };
+        let mut inp_weekend = inp_weekday_noon.clone();
+        inp_weekend.transaction_time = Some(inputs::TransactionTimeInput {
+            day_of_week: dir::enums::DayOfWeek::Sunday,
+            hour_of_day: 12,
+            date: 20261025,
+        });
+        let mut inp_weekday_night = inp_weekday_noon.clone();
+        inp_weekday_night.transaction_time = Some(inputs::TransactionTimeInput {
+            day_of_week: dir::enums::DayOfWeek::Wednesday,
+            hour_of_day: 3,
+            date: 20261021,
+        });
+        let mut inp_holiday = inp_weekday_noon.clone();
+        inp_holiday.transaction_time = Some(inputs::TransactionTimeInput {
+            day_of_week: dir::enums::DayOfWeek::Friday,
+            hour_of_day: 12,
+            date: 20261225,
+        });
+        let mut inp_no_time = inp_weekday_noon.clone();
+        inp_no_time.transaction_time = None;
+
+        let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
+        let result_weekday_noon = backend.execute(inp_weekday_noon).expect("Execution");
+        let result_weekend = backend.execute(inp_weekend).expect("Execution");
+        let result_weekday_night = backend.execute(inp_weekday_night).expect("Execution");
+        let result_holiday = backend.execute(inp_holiday).expect("Execution");
+        let result_no_time = backend.execute(inp_no_time).expect("Execution");
+        assert!(result_weekday_noon.rule_name.is_none());
+        assert_eq!(
+            result_weekend.rule_name.expect("Rule Name").as_str(),
+            "rule_1"
+        );
+        assert_eq!(
+            result_weekday_night.rule_name.expect("Rule Name").as_str(),
+            "rule_2"
+        );
+        assert_eq!(
+            result_holiday.rule_name.expect("Rule Name").as_str(),
+            "rule_2"
+        );
+        assert!(result_no_time.rule_name.is_none());
+    }
 }
@@ -18,6 +18,11 @@ pub struct Context {
 }
 
 impl Context {
+    /// The non numeric values present in the input
+    pub fn atomic_values(&self) -> impl Iterator<Item = &EuclidValue> {
+        self.atomic_values.iter()
+    }
+
     pub fn check_presence(&self, value: &EuclidValue) -> bool {
         let key = value.get_key();
 
@@ -58,6 +63,7 @@ impl Context {
         let customer_device_data = input.customer_device_data;
         let issuer_data = input.issuer_data;
         let payment_mandate = input.mandate;
+        let transaction_time = input.transaction_time;
 
         let mut enum_values: FxHashSet<EuclidValue> =
             FxHashSet::from_iter([EuclidValue::PaymentCurrency(payment.currency)]);
@@ -156,7 +162,11 @@ impl Context {
             }
         }
 
-        let numeric_values: FxHashMap<EuclidKey, EuclidValue> = FxHashMap::from_iter([
+        if let Some(time) = &transaction_time {
+            enum_values.insert(EuclidValue::DayOfWeek(time.day_of_week));
+        }
+
+        let mut numeric_values: FxHashMap<EuclidKey, EuclidValue> = FxHashMap::from_iter([
             (
                 EuclidKey::PaymentAmount,
                 EuclidValue::PaymentAmount(types::NumValue {
@@ -175,6 +185,23 @@ impl Context {
             ),
         ]);
 
+        if let Some(time) = transaction_time {
+            numeric_values.insert(
+                EuclidKey::HourOfDay,
+                EuclidValue::HourOfDay(types::NumValue {
+                    number: common_utils::types::MinorUnit::new(time.hour_of_day.into()),
+                    refinement: None,
+                }),
+            );
+            numeric_values.insert(
+                EuclidKey::TransactionDate,
+                EuclidValue::TransactionDate(types::NumValue {
+                    number: common_utils::types::MinorUnit::new(time.date.into()),
+                    refinement: None,
+                }),
+            );
+        }
+
         Self {
             atomic_values: enum_values,
             numeric_values,
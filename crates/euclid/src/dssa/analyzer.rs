@@ -18,7 +18,7 @@ use crate::{
         dir::{self, EuclidDirFilter},
         vir,
     },
-    types::{DataType, Metadata},
+    types::{DataType, Metadata, NumValueRefinement},
 };
 
 /// Analyses conflicting assertions on the same key in a conjunctive context.
@@ -101,6 +101,76 @@ pub fn analyze_exhaustive_negations(
     Ok(())
 }
 
+/// Narrows down the inclusive range of numbers that satisfy all the given numeric assertions on
+/// a key. `NotEqual` refinements are not ranges and are left out.
+fn get_numeric_bounds<'a>(
+    kind: &dir::DirKeyKind,
+    values: impl Iterator<Item = &'a dir::DirValue>,
+) -> (i64, i64) {
+    let (mut lower, mut upper) = match kind {
+        dir::DirKeyKind::HourOfDay => (0, 23),
+        _ => (i64::MIN, i64::MAX),
+    };
+
+    for num_value in values.filter_map(|val| val.get_num_value()) {
+        let number = num_value.number.get_amount_as_i64();
+
+        match num_value.refinement {
+            None => {
+                lower = lower.max(number);
+                upper = upper.min(number);
+            }
+            Some(NumValueRefinement::GreaterThan) => lower = lower.max(number.saturating_add(1)),
+            Some(NumValueRefinement::GreaterThanEqual) => lower = lower.max(number),
+            Some(NumValueRefinement::LessThan) => upper = upper.min(number.saturating_sub(1)),
+            Some(NumValueRefinement::LessThanEqual) => upper = upper.min(number),
+            Some(NumValueRefinement::NotEqual) => {}
+        }
+    }
+
+    (lower, upper)
+}
+
+/// Analyses assertions on the same time window key in a conjunctive context that no single
+/// transaction time can satisfy together.
+///
+/// For example,
+/// ```notrust
+/// hour_of_day >= 22 && ... && hour_of_day < 4
+/// ```notrust
+/// A window that wraps around midnight has to be written as two separate statements, and a
+/// condition like this one will never evaluate to `true`.
+pub fn analyze_unsatisfiable_time_windows(
+    keywise_windows: &FxHashMap<dir::DirKey, Vec<&dir::DirValue>>,
+    assertion_metadata: &FxHashMap<&dir::DirValue, &Metadata>,
+) -> Result<(), types::AnalysisError> {
+    for (key, values) in keywise_windows {
+        let (lower, upper) = get_numeric_bounds(&key.kind, values.iter().copied());
+
+        if lower > upper {
+            let error_type = types::AnalysisErrorType::UnsatisfiableTimeWindow {
+                key: key.clone(),
+                values: values
+                    .iter()
+                    .map(|val| types::ValueData {
+                        value: (*val).clone(),
+                        metadata: assertion_metadata
+                            .get(val)
+                            .map(|meta| (*meta).clone())
+                            .unwrap_or_default(),
+                    })
+                    .collect(),
+            };
+
+            Err(types::AnalysisError {
+                error_type,
+                metadata: Default::default(),
+            })?;
+        }
+    }
+    Ok(())
+}
+
 fn analyze_negated_assertions(
     keywise_assertions: &FxHashMap<dir::DirKey, FxHashSet<&dir::DirValue>>,
     assertion_metadata: &FxHashMap<&dir::DirValue, &Metadata>,
@@ -151,6 +221,7 @@ fn perform_condition_analyses(
         FxHashMap::default();
     let mut keywise_negations: FxHashMap<dir::DirKey, FxHashSet<&dir::DirValue>> =
         FxHashMap::default();
+    let mut keywise_windows: FxHashMap<dir::DirKey, Vec<&dir::DirValue>> = FxHashMap::default();
 
     for ctx_val in context {
         let key = if let Some(k) = ctx_val.value.get_key() {
@@ -163,6 +234,16 @@ fn perform_condition_analyses(
             continue;
         }
 
+        if let (
+            dir::DirKeyKind::HourOfDay | dir::DirKeyKind::TransactionDate,
+            types::CtxValueKind::Assertion(val),
+        ) = (&key.kind, &ctx_val.value)
+        {
+            keywise_windows.entry(key.clone()).or_default().push(*val);
+            assertion_metadata.insert(*val, ctx_val.metadata);
+            continue;
+        }
+
         if !matches!(key.kind.get_type(), DataType::EnumVariant) {
             continue;
         }
@@ -195,6 +276,7 @@ fn perform_condition_analyses(
 
     analyze_conflicting_assertions(&keywise_assertions, &assertion_metadata)?;
     analyze_exhaustive_negations(&keywise_negations, &keywise_negation_metadata)?;
+    analyze_unsatisfiable_time_windows(&keywise_windows, &assertion_metadata)?;
     analyze_negated_assertions(
         &keywise_assertions,
         &assertion_metadata,
@@ -238,6 +320,424 @@ pub fn analyze<O: EuclidAnalysable + EuclidDirFilter>(
     dir::lowering::lower_program(dir_program)
 }
 
+/// Upper bound on the conjunctive contexts a single statement may expand into.
+const MAX_STATEMENT_CONTEXTS: usize = 256;
+
+/// Upper bound on the conjunctive contexts of a whole program. The shadowing analysis compares
+/// every pair of contexts, so the warning analyses are skipped for larger programs.
+const MAX_PROGRAM_CONTEXTS: usize = 2048;
+
+/// Expands a statement, along with its nested statements, into the conjunctive contexts that
+/// make it hold, the same way the [`state_machine`] walks them. Returns `None` when the statement
+/// expands into more than [`MAX_STATEMENT_CONTEXTS`] contexts.
+fn get_statement_contexts(
+    stmt: &dir::DirIfStatement,
+) -> Option<Vec<types::ConjunctiveContext<'_>>> {
+    let mut contexts: Vec<types::ConjunctiveContext<'_>> = vec![Vec::new()];
+
+    for comparison in &stmt.condition {
+        contexts = match comparison.logic {
+            dir::DirComparisonLogic::NegativeConjunction => contexts
+                .into_iter()
+                .map(|mut ctx| {
+                    ctx.push(types::ContextValue::negation(
+                        &comparison.values,
+                        &comparison.metadata,
+                    ));
+                    ctx
+                })
+                .collect(),
+
+            dir::DirComparisonLogic::PositiveDisjunction => {
+                if contexts.len().saturating_mul(comparison.values.len()) > MAX_STATEMENT_CONTEXTS {
+                    return None;
+                }
+
+                contexts
+                    .iter()
+                    .flat_map(|ctx| {
+                        comparison.values.iter().map(move |value| {
+                            let mut ctx = ctx.clone();
+                            ctx.push(types::ContextValue::assertion(value, &comparison.metadata));
+                            ctx
+                        })
+                    })
+                    .collect()
+            }
+        };
+    }
+
+    match stmt.nested.as_ref().filter(|nested| !nested.is_empty()) {
+        None => Some(contexts),
+        Some(nested) => {
+            let mut nested_contexts: Vec<types::ConjunctiveContext<'_>> = Vec::new();
+            for nested_stmt in nested {
+                nested_contexts.extend(get_statement_contexts(nested_stmt)?);
+            }
+
+            if contexts.len().saturating_mul(nested_contexts.len()) > MAX_STATEMENT_CONTEXTS {
+                return None;
+            }
+
+            Some(
+                contexts
+                    .iter()
+                    .flat_map(|ctx| {
+                        nested_contexts.iter().map(move |nested_ctx| {
+                            let mut ctx = ctx.clone();
+                            ctx.extend(nested_ctx.iter().cloned());
+                            ctx
+                        })
+                    })
+                    .collect(),
+            )
+        }
+    }
+}
+
+/// Expands every statement of the program into its conjunctive contexts, see
+/// [`get_statement_contexts`]. Returns `None` when the program expands into more than
+/// [`MAX_PROGRAM_CONTEXTS`] contexts.
+fn get_program_contexts<O>(
+    program: &dir::DirProgram<O>,
+) -> Option<Vec<Vec<Vec<types::ConjunctiveContext<'_>>>>> {
+    let mut total_contexts: usize = 0;
+
+    program
+        .rules
+        .iter()
+        .map(|rule| {
+            rule.statements
+                .iter()
+                .map(|stmt| {
+                    let contexts = get_statement_contexts(stmt)?;
+                    total_contexts = total_contexts.saturating_add(contexts.len());
+                    (total_contexts <= MAX_PROGRAM_CONTEXTS).then_some(contexts)
+                })
+                .collect::<Option<Vec<_>>>()
+        })
+        .collect()
+}
+
+/// Checks whether every payment that satisfies `context` also satisfies the single condition
+/// `value`.
+fn context_implies_value(
+    context: &types::ConjunctiveContext<'_>,
+    value: &types::CtxValueKind<'_>,
+) -> bool {
+    let Some(key) = value.get_key() else {
+        return false;
+    };
+
+    let key_assertions: Vec<&dir::DirValue> = context
+        .iter()
+        .filter_map(|ctx_val| ctx_val.value.get_assertion())
+        .filter(|val| val.get_key() == key)
+        .collect();
+    let is_negated = |val: &dir::DirValue| {
+        context.iter().any(|ctx_val| {
+            ctx_val
+                .value
+                .get_negation()
+                .is_some_and(|negated| negated.contains(val))
+        })
+    };
+
+    match value {
+        types::CtxValueKind::Assertion(val) => match val.get_num_value() {
+            Some(num_value) => {
+                let (lower, upper) = get_numeric_bounds(&key.kind, key_assertions.iter().copied());
+                let number = num_value.number.get_amount_as_i64();
+
+                match num_value.refinement {
+                    None => lower == number && upper == number,
+                    Some(NumValueRefinement::GreaterThan) => lower > number,
+                    Some(NumValueRefinement::GreaterThanEqual) => lower >= number,
+                    Some(NumValueRefinement::LessThan) => upper < number,
+                    Some(NumValueRefinement::LessThanEqual) => upper <= number,
+                    Some(NumValueRefinement::NotEqual) => {
+                        upper < number || lower > number || key_assertions.iter().any(|v| v == val)
+                    }
+                }
+            }
+            None => key_assertions.iter().any(|v| v == val),
+        },
+
+        types::CtxValueKind::Negation(vals) => vals.iter().all(|val| {
+            is_negated(val)
+                || (val.get_num_value().is_none()
+                    && key_assertions
+                        .iter()
+                        .any(|v| v.get_num_value().is_none() && *v != val))
+        }),
+    }
+}
+
+/// Checks whether every payment that satisfies `context` also satisfies `earlier_context`,
+/// ignoring the connectors selected by either of them.
+fn context_implies_context(
+    context: &types::ConjunctiveContext<'_>,
+    earlier_context: &types::ConjunctiveContext<'_>,
+) -> bool {
+    earlier_context
+        .iter()
+        .filter(|ctx_val| {
+            !matches!(
+                ctx_val.value.get_key().map(|key| key.kind),
+                Some(dir::DirKeyKind::Connector) | None
+            )
+        })
+        .all(|ctx_val| context_implies_value(context, &ctx_val.value))
+}
+
+fn get_statement_metadata(stmt: &dir::DirIfStatement) -> Metadata {
+    stmt.condition
+        .first()
+        .map(|comparison| comparison.metadata.clone())
+        .unwrap_or_default()
+}
+
+/// Analyses statements that can never be selected because every payment they match is already
+/// matched by an earlier rule.
+///
+/// For example,
+/// ```notrust
+/// rule_1: payment_method = card
+/// rule_2: payment_method = card & amount > 500
+/// ```notrust
+/// Any payment that satisfies `rule_2` selects `rule_1`, since rules are evaluated in order.
+/// Only shadowing by a single earlier statement is detected, statements that are covered by a
+/// combination of earlier rules are not reported.
+fn analyze_shadowed_statements<O>(
+    program: &dir::DirProgram<O>,
+    rule_contexts: &[Vec<Vec<types::ConjunctiveContext<'_>>>],
+) -> Vec<types::AnalysisWarning> {
+    let mut warnings = Vec::new();
+
+    for (rule_idx, (rule, statement_contexts)) in
+        program.rules.iter().zip(rule_contexts).enumerate()
+    {
+        let earlier_rules = program.rules.iter().zip(rule_contexts).take(rule_idx);
+        let mut rule_shadowed_by: Vec<String> = Vec::new();
+        let mut statement_warnings = Vec::new();
+
+        for (statement_index, (stmt, contexts)) in
+            rule.statements.iter().zip(statement_contexts).enumerate()
+        {
+            let mut shadowed_by: Vec<String> = Vec::new();
+
+            let is_shadowed = !contexts.is_empty()
+                && contexts.iter().all(|ctx| {
+                    let shadowing_rule = earlier_rules.clone().find(|(_, earlier_statements)| {
+                        earlier_statements
+                            .iter()
+                            .flatten()
+                            .any(|earlier_ctx| context_implies_context(ctx, earlier_ctx))
+                    });
+
+                    match shadowing_rule {
+                        Some((earlier_rule, _)) => {
+                            if !shadowed_by.contains(&earlier_rule.name) {
+                                shadowed_by.push(earlier_rule.name.clone());
+                            }
+                            true
+                        }
+                        None => false,
+                    }
+                });
+
+            if !is_shadowed {
+                continue;
+            }
+
+            for name in &shadowed_by {
+                if !rule_shadowed_by.contains(name) {
+                    rule_shadowed_by.push(name.clone());
+                }
+            }
+
+            statement_warnings.push(types::AnalysisWarning {
+                warning_type: types::AnalysisWarningType::ShadowedStatement {
+                    rule_name: rule.name.clone(),
+                    statement_index,
+                    shadowed_by,
+                },
+                metadata: get_statement_metadata(stmt),
+            });
+        }
+
+        if !rule.statements.is_empty() && statement_warnings.len() == rule.statements.len() {
+            warnings.push(types::AnalysisWarning {
+                warning_type: types::AnalysisWarningType::ShadowedRule {
+                    rule_name: rule.name.clone(),
+                    shadowed_by: rule_shadowed_by,
+                },
+                metadata: Default::default(),
+            });
+        } else {
+            warnings.extend(statement_warnings);
+        }
+    }
+
+    warnings
+}
+
+/// Analyses statements for which every conjunctive context, along with the connectors selected
+/// by the rule, is rejected by the constraint graph.
+fn analyze_unsatisfiable_statements<O>(
+    program: &dir::DirProgram<O>,
+    rule_contexts: &[Vec<Vec<types::ConjunctiveContext<'_>>>],
+    selection_data: &[Vec<(dir::DirValue, Metadata)>],
+    knowledge_graph: &ConstraintGraph<dir::DirValue>,
+) -> Vec<types::AnalysisWarning> {
+    let mut warnings = Vec::new();
+
+    for ((rule, statement_contexts), connector_selection_data) in
+        program.rules.iter().zip(rule_contexts).zip(selection_data)
+    {
+        let mut statement_warnings = Vec::new();
+
+        for (statement_index, (stmt, contexts)) in
+            rule.statements.iter().zip(statement_contexts).enumerate()
+        {
+            let is_unsatisfiable = !contexts.is_empty()
+                && contexts.iter().all(|ctx| {
+                    let mut full_ctx: types::ConjunctiveContext<'_> = connector_selection_data
+                        .iter()
+                        .map(|(dir_val, metadata)| {
+                            types::ContextValue::assertion(dir_val, metadata)
+                        })
+                        .collect();
+                    full_ctx.extend(ctx.iter().cloned());
+
+                    knowledge_graph
+                        .perform_context_analysis(&full_ctx, &mut Memoization::new(), None)
+                        .is_err()
+                });
+
+            if is_unsatisfiable {
+                statement_warnings.push(types::AnalysisWarning {
+                    warning_type: types::AnalysisWarningType::UnsatisfiableStatement {
+                        rule_name: rule.name.clone(),
+                        statement_index,
+                    },
+                    metadata: get_statement_metadata(stmt),
+                });
+            }
+        }
+
+        if !rule.statements.is_empty() && statement_warnings.len() == rule.statements.len() {
+            warnings.push(types::AnalysisWarning {
+                warning_type: types::AnalysisWarningType::UnsatisfiableRule {
+                    rule_name: rule.name.clone(),
+                },
+                metadata: Default::default(),
+            });
+        } else {
+            warnings.extend(statement_warnings);
+        }
+    }
+
+    warnings
+}
+
+/// Analyses values of the enum keys used in the program that no statement of any rule matches.
+///
+/// For example,
+/// ```notrust
+/// rule_1: capture_method = automatic
+/// rule_2: capture_method = manual
+/// ```notrust
+/// Payments with any other capture method silently fall through to the default selection.
+fn analyze_uncovered_values(
+    rule_contexts: &[Vec<Vec<types::ConjunctiveContext<'_>>>],
+) -> Vec<types::AnalysisWarning> {
+    let contexts: Vec<&types::ConjunctiveContext<'_>> =
+        rule_contexts.iter().flatten().flatten().collect();
+
+    let mut keys: Vec<dir::DirKey> = Vec::new();
+    for key in contexts
+        .iter()
+        .flat_map(|ctx| ctx.iter())
+        .filter_map(|ctx_val| ctx_val.value.get_key())
+    {
+        if !matches!(key.kind, dir::DirKeyKind::Connector)
+            && matches!(key.kind.get_type(), DataType::EnumVariant)
+            && !keys.contains(&key)
+        {
+            keys.push(key);
+        }
+    }
+
+    let mut warnings = Vec::new();
+
+    for key in keys {
+        let Some(value_set) = key.kind.get_value_set() else {
+            continue;
+        };
+
+        let uncovered_values: Vec<dir::DirValue> = value_set
+            .into_iter()
+            .filter(|value| {
+                !contexts.iter().any(|ctx| {
+                    ctx.iter().all(|ctx_val| match &ctx_val.value {
+                        types::CtxValueKind::Assertion(val) => {
+                            val.get_key() != key || *val == value
+                        }
+                        types::CtxValueKind::Negation(vals) => !vals.contains(value),
+                    })
+                })
+            })
+            .collect();
+
+        if !uncovered_values.is_empty() {
+            warnings.push(types::AnalysisWarning {
+                warning_type: types::AnalysisWarningType::UncoveredValues {
+                    key,
+                    values: uncovered_values,
+                },
+                metadata: Default::default(),
+            });
+        }
+    }
+
+    warnings
+}
+
+/// Reports the rules and statements of a program that can never be selected, along with the
+/// payments that no rule matches. Unlike [`analyze`], none of these make the program invalid.
+/// Programs whose conditions expand into more than [`MAX_PROGRAM_CONTEXTS`] conjunctive contexts
+/// are not analysed, a single [`types::AnalysisWarningType::AnalysisSkipped`] warning is reported
+/// for them instead.
+pub fn analyze_warnings<O: EuclidAnalysable + EuclidDirFilter>(
+    program: ast::Program<O>,
+    knowledge_graph: Option<&ConstraintGraph<dir::DirValue>>,
+) -> Result<Vec<types::AnalysisWarning>, types::AnalysisError> {
+    let dir_program = ast::lowering::lower_program(program)?;
+
+    let Some(rule_contexts) = get_program_contexts(&dir_program) else {
+        return Ok(vec![types::AnalysisWarning {
+            warning_type: types::AnalysisWarningType::AnalysisSkipped {
+                max_statement_contexts: MAX_STATEMENT_CONTEXTS,
+                max_program_contexts: MAX_PROGRAM_CONTEXTS,
+            },
+            metadata: Default::default(),
+        }]);
+    };
+    let selection_data = state_machine::make_connector_selection_data(&dir_program);
+
+    let mut warnings = analyze_unsatisfiable_statements(
+        &dir_program,
+        &rule_contexts,
+        &selection_data,
+        knowledge_graph.unwrap_or(&truth::ANALYSIS_GRAPH),
+    );
+    warnings.extend(analyze_shadowed_statements(&dir_program, &rule_contexts));
+    warnings.extend(analyze_uncovered_values(&rule_contexts));
+
+    Ok(warnings)
+}
+
 #[cfg(all(test, feature = "ast_parser"))]
 mod tests {
     use std::{ops::Deref, sync::Weak};
@@ -374,6 +874,53 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_unsatisfiable_time_window_detection() {
+        let program_str = r#"
+            default: ["stripe"]
+
+            rule_1: ["adyen"]
+            {
+                day_of_week = saturday {
+                    hour_of_day >= 22 & hour_of_day < 4
+                }
+            }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let analysis_result = analyze(program, None);
+
+        if let Err(types::AnalysisError {
+            error_type: types::AnalysisErrorType::UnsatisfiableTimeWindow { key, values },
+            ..
+        }) = analysis_result
+        {
+            assert!(
+                matches!(key.kind, dir::DirKeyKind::HourOfDay),
+                "Expected key to be hour_of_day"
+            );
+            assert_eq!(values.len(), 2, "There should be 2 conflicting conditions");
+        } else {
+            panic!("Expected unsatisfiable time window error");
+        }
+    }
+
+    #[test]
+    fn test_split_time_window_is_valid() {
+        let program_str = r#"
+            default: ["stripe"]
+
+            rule_1: ["adyen"]
+            {
+                hour_of_day >= 22
+                hour_of_day < 4
+            }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        assert!(analyze(program, None).is_ok());
+    }
+
     #[test]
     fn test_negation_graph_analysis() {
         let graph = knowledge! {
@@ -452,4 +999,196 @@ mod tests {
             }
         ));
     }
+
+    #[test]
+    fn test_shadowed_rule_detection() {
+        let program_str = r#"
+            default: ["stripe"]
+
+            rule_1: ["adyen"]
+            {
+                payment_method = card
+            }
+
+            rule_2: ["stripe"]
+            {
+                payment_method = card & amount > 500
+                payment_method = card {
+                    capture_method = manual
+                }
+            }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let warnings = analyze_warnings(program, None).expect("Warnings");
+
+        assert!(
+            warnings.iter().any(|warning| matches!(
+                &warning.warning_type,
+                types::AnalysisWarningType::ShadowedRule { rule_name, shadowed_by }
+                    if rule_name == "rule_2" && shadowed_by == &vec!["rule_1".to_string()]
+            )),
+            "Expected rule_2 to be shadowed by rule_1"
+        );
+        assert!(
+            !warnings.iter().any(|warning| matches!(
+                warning.warning_type,
+                types::AnalysisWarningType::ShadowedStatement { .. }
+            )),
+            "Statements of a shadowed rule should not be reported separately"
+        );
+    }
+
+    #[test]
+    fn test_warnings_skipped_for_large_programs() {
+        let program_str = r#"
+            default: ["stripe"]
+
+            rule_1: ["adyen"]
+            {
+                payment_method = card
+            }
+
+            rule_2: ["stripe"]
+            {
+                payment_method = card & amount > 500
+            }
+
+            rule_3: ["stripe"]
+            {
+                amount = (1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20) & currency = (USD, EUR, GBP, INR, JPY, AUD, CAD, CHF, SGD, HKD, NZD, SEK, NOK, DKK)
+            }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let dir_program = ast::lowering::lower_program(program.clone()).expect("Lowering");
+        assert!(get_program_contexts(&dir_program).is_none());
+
+        let warnings = analyze_warnings(program, None).expect("Warnings");
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings.iter().all(|warning| matches!(
+            warning.warning_type,
+            types::AnalysisWarningType::AnalysisSkipped {
+                max_statement_contexts: MAX_STATEMENT_CONTEXTS,
+                max_program_contexts: MAX_PROGRAM_CONTEXTS,
+            }
+        )));
+    }
+
+    #[test]
+    fn test_shadowed_statement_detection() {
+        let program_str = r#"
+            default: ["stripe"]
+
+            rule_1: ["adyen"]
+            {
+                amount > 1000 & currency = USD
+            }
+
+            rule_2: ["stripe"]
+            {
+                amount >= 2000 & currency = USD & payment_method = card
+                amount > 1000 & currency = EUR
+                amount > 500 & currency = USD
+            }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let warnings = analyze_warnings(program, None).expect("Warnings");
+
+        let shadowed_statements: Vec<usize> = warnings
+            .iter()
+            .filter_map(|warning| match &warning.warning_type {
+                types::AnalysisWarningType::ShadowedStatement {
+                    rule_name,
+                    statement_index,
+                    ..
+                } if rule_name == "rule_2" => Some(*statement_index),
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(
+            shadowed_statements,
+            vec![0],
+            "Only the first statement of rule_2 should be shadowed"
+        );
+    }
+
+    #[test]
+    fn test_unsatisfiable_statement_detection() {
+        let graph = knowledge! {
+            CaptureMethod(Automatic) ->> PaymentMethod(Card);
+        };
+
+        let program_str = r#"
+            default: ["stripe"]
+
+            rule_1: ["adyen"]
+            {
+                amount > 500 {
+                    payment_method = pay_later
+                }
+
+                amount < 500 {
+                    payment_method /= wallet & payment_method /= pay_later
+                }
+            }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let warnings = analyze_warnings(program, Some(&graph)).expect("Warnings");
+
+        assert!(
+            warnings.iter().any(|warning| matches!(
+                &warning.warning_type,
+                types::AnalysisWarningType::UnsatisfiableStatement {
+                    rule_name,
+                    statement_index: 1,
+                } if rule_name == "rule_1"
+            )),
+            "Expected the second statement of rule_1 to be unsatisfiable"
+        );
+    }
+
+    #[test]
+    fn test_uncovered_values_detection() {
+        let program_str = r#"
+            default: ["stripe"]
+
+            rule_1: ["adyen"]
+            {
+                capture_method = automatic
+            }
+
+            rule_2: ["stripe"]
+            {
+                capture_method = manual & payment_method = card
+            }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let warnings = analyze_warnings(program, None).expect("Warnings");
+
+        let (capture_methods, payment_methods) = warnings.iter().fold(
+            (None, None),
+            |(capture_methods, payment_methods), warning| match &warning.warning_type {
+                types::AnalysisWarningType::UncoveredValues { key, values } => match key.kind {
+                    dir::DirKeyKind::CaptureMethod => (Some(values.clone()), payment_methods),
+                    dir::DirKeyKind::PaymentMethod => (capture_methods, Some(values.clone())),
+                    _ => (capture_methods, payment_methods),
+                },
+                _ => (capture_methods, payment_methods),
+            },
+        );
+
+        let capture_methods = capture_methods.expect("Expected uncovered capture methods");
+        assert!(capture_methods.contains(&dirval!(CaptureMethod = ManualMultiple)));
+        assert!(!capture_methods.contains(&dirval!(CaptureMethod = Automatic)));
+        assert!(!capture_methods.contains(&dirval!(CaptureMethod = Manual)));
+        assert!(
+            payment_methods.is_none(),
+            "rule_1 matches every payment method"
+        );
+    }
 }
@@ -87,6 +87,9 @@ impl cgraph::NodeViz for dir::DirValue {
             Self::TransactionInitiator(transaction_initiator) => transaction_initiator.to_string(),
             Self::NetworkTokenType(ntt) => ntt.to_string(),
             Self::CardDiscovery(card_discovery) => card_discovery.to_string(),
+            Self::DayOfWeek(day_of_week) => day_of_week.to_string(),
+            Self::HourOfDay(hour_of_day) => hour_of_day.number.to_string(),
+            Self::TransactionDate(transaction_date) => transaction_date.number.to_string(),
         }
     }
 }
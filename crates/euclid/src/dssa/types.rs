@@ -139,6 +139,11 @@ pub enum AnalysisErrorType {
         assertion_metadata: Metadata,
         negation_metadata: Metadata,
     },
+    #[error("Conditions on key '{}' can never hold at the same time", .key.kind)]
+    UnsatisfiableTimeWindow {
+        key: dir::DirKey,
+        values: Vec<ValueData>,
+    },
     #[error("Graph analysis error: {0:#?}")]
     GraphAnalysis(
         graph::AnalysisError<dir::DirValue>,
@@ -154,6 +159,101 @@ pub enum AnalysisErrorType {
     NotSupported,
 }
 
+/// A finding from the analyzer that does not make the program invalid, but most likely does
+/// not do what its author intended.
+#[derive(Debug, Clone, Serialize)]
+pub struct AnalysisWarning {
+    #[serde(flatten)]
+    pub warning_type: AnalysisWarningType,
+    pub metadata: Metadata,
+}
+
+impl fmt::Display for AnalysisWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.warning_type.fmt(f)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", content = "info", rename_all = "snake_case")]
+pub enum AnalysisWarningType {
+    /// Every statement of the rule is already matched by one of the earlier rules.
+    ShadowedRule {
+        rule_name: String,
+        shadowed_by: Vec<String>,
+    },
+    /// The statement is already matched by one of the earlier rules.
+    ShadowedStatement {
+        rule_name: String,
+        statement_index: usize,
+        shadowed_by: Vec<String>,
+    },
+    /// None of the statements of the rule can hold under the constraint graph.
+    UnsatisfiableRule { rule_name: String },
+    /// The statement can never hold under the constraint graph.
+    UnsatisfiableStatement {
+        rule_name: String,
+        statement_index: usize,
+    },
+    /// Values of a key that no rule matches, payments with these values always fall through to
+    /// the default selection.
+    UncoveredValues {
+        key: dir::DirKey,
+        values: Vec<dir::DirValue>,
+    },
+    /// The conditions of the program expand into too many combinations to be analysed, none of
+    /// the other warnings are reported for it.
+    AnalysisSkipped {
+        max_statement_contexts: usize,
+        max_program_contexts: usize,
+    },
+}
+
+impl fmt::Display for AnalysisWarningType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ShadowedRule {
+                rule_name,
+                shadowed_by,
+            } => write!(
+                f,
+                "Rule '{rule_name}' is never selected since earlier rules {shadowed_by:?} match first"
+            ),
+            Self::ShadowedStatement {
+                rule_name,
+                statement_index,
+                shadowed_by,
+            } => write!(
+                f,
+                "Statement {statement_index} of rule '{rule_name}' is never selected since earlier rules {shadowed_by:?} match first"
+            ),
+            Self::UnsatisfiableRule { rule_name } => {
+                write!(f, "Rule '{rule_name}' can never be satisfied")
+            }
+            Self::UnsatisfiableStatement {
+                rule_name,
+                statement_index,
+            } => write!(
+                f,
+                "Statement {statement_index} of rule '{rule_name}' can never be satisfied"
+            ),
+            Self::UncoveredValues { key, values } => write!(
+                f,
+                "No rule matches {} of the values of '{}', payments with these fall through to the default selection",
+                values.len(),
+                key.kind
+            ),
+            Self::AnalysisSkipped {
+                max_statement_contexts,
+                max_program_contexts,
+            } => write!(
+                f,
+                "Analysis skipped: program too large, a statement expands into more than {max_statement_contexts} or the program into more than {max_program_contexts} combinations of conditions"
+            ),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum ValueType {
     EnumVariants(Vec<EuclidValue>),
@@ -1,6 +1,8 @@
 pub mod lowering;
 #[cfg(feature = "ast_parser")]
 pub mod parser;
+#[cfg(feature = "ast_parser")]
+pub mod printer;
 
 use common_utils::types::MinorUnit;
 use serde::{Deserialize, Serialize};
@@ -82,7 +84,7 @@ pub enum ComparisonType {
 }
 
 /// Represents a single comparison condition.
-#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct Comparison {
     /// The left hand side which will always be a domain input identifier like "payment.method.cardtype"
@@ -115,7 +117,7 @@ pub type IfCondition = Vec<Comparison>;
 ///     }
 /// }
 /// ```
-#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct IfStatement {
     #[schema(value_type=Vec<Comparison>)]
@@ -137,7 +139,7 @@ pub struct IfStatement {
 ///     }
 /// }
 /// ```
-#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 #[aliases(RuleConnectorSelection = Rule<ConnectorSelection>)]
 pub struct Rule<O> {
@@ -149,7 +151,7 @@ pub struct Rule<O> {
 
 /// The program, having a default connector selection and
 /// a bunch of rules. Also can hold arbitrary metadata.
-#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 #[aliases(ProgramConnectorSelection = Program<ConnectorSelection>)]
 pub struct Program<O> {
@@ -160,7 +162,7 @@ pub struct Program<O> {
     pub metadata: Metadata,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
 pub struct RoutableConnectorChoice {
     #[serde(skip)]
     pub choice_kind: RoutableChoiceKind,
@@ -168,20 +170,20 @@ pub struct RoutableConnectorChoice {
     pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
 }
 
-#[derive(Debug, Default, Clone, Deserialize, Serialize, ToSchema)]
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize, ToSchema)]
 pub enum RoutableChoiceKind {
     OnlyConnector,
     #[default]
     FullStruct,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
 pub struct ConnectorVolumeSplit {
     pub connector: RoutableConnectorChoice,
     pub split: u8,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
 #[serde(tag = "type", content = "data", rename_all = "snake_case")]
 pub enum ConnectorSelection {
     Priority(Vec<RoutableConnectorChoice>),
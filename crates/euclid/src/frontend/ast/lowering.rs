@@ -5,6 +5,8 @@
 
 use std::str::FromStr;
 
+use common_utils::types::MinorUnit;
+
 use crate::{
     dssa::types::{AnalysisError, AnalysisErrorType},
     enums::CollectVariants,
@@ -70,16 +72,20 @@ macro_rules! lower_enum {
 /// accordingly on basis of the supplied key, currently payment_amount is the only key having this
 /// use case
 macro_rules! lower_number {
-    ($key:ident, $value:ident, $comp:ident) => {
+    ($key:ident, $value:ident, $comp:ident $(, $validation_closure:expr)?) => {
         match $value {
-            ast::ValueType::Number(num) => Ok(vec![dir::DirValue::$key(types::NumValue {
-                number: num,
-                refinement: $comp.into(),
-            })]),
+            ast::ValueType::Number(num) => {
+                $($validation_closure(&num)?;)?
+                Ok(vec![dir::DirValue::$key(types::NumValue {
+                    number: num,
+                    refinement: $comp.into(),
+                })])
+            }
 
             ast::ValueType::NumberArray(na) => na
                 .into_iter()
                 .map(|num| {
+                    $($validation_closure(&num)?;)?
                     Ok(dir::DirValue::$key(types::NumValue {
                         number: num,
                         refinement: $comp.clone().into(),
@@ -90,6 +96,7 @@ macro_rules! lower_number {
             ast::ValueType::NumberComparisonArray(nca) => nca
                 .into_iter()
                 .map(|nc| {
+                    $($validation_closure(&nc.number)?;)?
                     Ok(dir::DirValue::$key(types::NumValue {
                         number: nc.number,
                         refinement: nc.comparison_type.into(),
@@ -293,9 +300,53 @@ fn lower_comparison_inner<O: EuclidDirFilter>(
         dir::DirKeyKind::TransactionInitiator => lower_enum!(TransactionInitiator, value),
         dir::DirKeyKind::NetworkTokenType => lower_enum!(NetworkTokenType, value),
         dir::DirKeyKind::CardDiscovery => lower_enum!(CardDiscovery, value),
+        dir::DirKeyKind::DayOfWeek => lower_enum!(DayOfWeek, value),
+        dir::DirKeyKind::HourOfDay => {
+            let validation_closure = |num: &MinorUnit| -> Result<(), AnalysisErrorType> {
+                if (0..24).contains(&num.get_amount_as_i64()) {
+                    Ok(())
+                } else {
+                    Err(AnalysisErrorType::InvalidValue {
+                        key: dir::DirKeyKind::HourOfDay,
+                        value: num.to_string(),
+                        message: Some("Expected an hour between 0 and 23".to_string()),
+                    })
+                }
+            };
+            lower_number!(HourOfDay, value, comparison, validation_closure)
+        }
+        dir::DirKeyKind::TransactionDate => {
+            let validation_closure = |num: &MinorUnit| -> Result<(), AnalysisErrorType> {
+                if is_valid_date(num.get_amount_as_i64()) {
+                    Ok(())
+                } else {
+                    Err(AnalysisErrorType::InvalidValue {
+                        key: dir::DirKeyKind::TransactionDate,
+                        value: num.to_string(),
+                        message: Some("Expected a date in the format YYYYMMDD".to_string()),
+                    })
+                }
+            };
+            lower_number!(TransactionDate, value, comparison, validation_closure)
+        }
     }
 }
 
+/// checks that a number read as YYYYMMDD is a valid calendar date
+fn is_valid_date(date: i64) -> bool {
+    let (year, month, day) = (date / 10000, (date / 100) % 100, date % 100);
+    let is_leap_year = (year % 4 == 0 && year % 100 != 0) || year % 400 == 0;
+    let days_in_month = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year => 29,
+        2 => 28,
+        _ => return false,
+    };
+
+    (1000..=9999).contains(&year) && (1..=days_in_month).contains(&day)
+}
+
 /// returns all the comparison values by matching them appropriately to ComparisonTypes and in turn
 /// calls the lower_comparison_inner function
 fn lower_comparison<O: EuclidDirFilter>(
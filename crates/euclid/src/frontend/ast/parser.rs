@@ -1,9 +1,11 @@
-use common_utils::types::MinorUnit;
+use std::str::FromStr;
+
+use common_utils::{id_type, types::MinorUnit};
 use nom::{
     branch, bytes::complete, character::complete as pchar, combinator, error, multi, sequence,
 };
 
-use crate::{frontend::ast, types::DummyOutput};
+use crate::{enums::RoutableConnectors, frontend::ast, types::DummyOutput};
 pub type ParseResult<T, U> = nom::IResult<T, U, error::VerboseError<T>>;
 
 pub enum EuclidError {
@@ -51,6 +53,72 @@ impl EuclidParsable for DummyOutput {
         )(input)
     }
 }
+
+/// Parses a connector, optionally followed by its merchant connector account id in parentheses,
+/// for example `stripe` or `adyen(mca_123)`
+pub fn routable_connector_choice(input: &str) -> ParseResult<&str, ast::RoutableConnectorChoice> {
+    let connector = combinator::map_res(
+        complete::take_while1(|c: char| c.is_ascii_alphanumeric() || c == '_'),
+        |o: &str| {
+            RoutableConnectors::from_str(o)
+                .map_err(|_| EuclidError::InvalidConnector(o.to_string()))
+        },
+    );
+    let merchant_connector_id = sequence::delimited(
+        skip_ws(complete::tag("(")),
+        skip_ws(combinator::map_res(
+            complete::take_while1(|c: char| c.is_ascii_alphanumeric() || c == '_' || c == '-'),
+            |o: &str| id_type::MerchantConnectorAccountId::wrap(o.to_string()),
+        )),
+        skip_ws(complete::tag(")")),
+    );
+
+    error::context(
+        "routable_connector_choice",
+        combinator::map(
+            sequence::pair(skip_ws(connector), combinator::opt(merchant_connector_id)),
+            |(connector, merchant_connector_id)| ast::RoutableConnectorChoice {
+                choice_kind: ast::RoutableChoiceKind::FullStruct,
+                connector,
+                merchant_connector_id,
+            },
+        ),
+    )(input)
+}
+
+/// Parses a list of connectors, for example `[stripe, adyen(mca_123)]` for priority routing or
+/// `[stripe: 70%, adyen: 30%]` for a volume split
+impl EuclidParsable for ast::ConnectorSelection {
+    fn parse_output(input: &str) -> ParseResult<&str, Self> {
+        let volume_split = combinator::map(
+            sequence::pair(
+                routable_connector_choice,
+                sequence::preceded(skip_ws(complete::tag(":")), skip_ws(percentage)),
+            ),
+            |(connector, split)| ast::ConnectorVolumeSplit { connector, split },
+        );
+
+        error::context(
+            "connector_selection",
+            branch::alt((
+                combinator::map(bracketed_list(volume_split), Self::VolumeSplit),
+                combinator::map(bracketed_list(routable_connector_choice), Self::Priority),
+            )),
+        )(input)
+    }
+}
+
+fn bracketed_list<'a, F, O>(inner: F) -> impl FnMut(&'a str) -> ParseResult<&'a str, Vec<O>>
+where
+    F: FnMut(&'a str) -> ParseResult<&'a str, O> + 'a,
+{
+    sequence::delimited(
+        skip_ws(complete::tag("[")),
+        multi::separated_list1(skip_ws(complete::tag(",")), inner),
+        skip_ws(complete::tag("]")),
+    )
+}
+
 pub fn skip_ws<'a, F, O>(inner: F) -> impl FnMut(&'a str) -> ParseResult<&'a str, O>
 where
     F: FnMut(&'a str) -> ParseResult<&'a str, O> + 'a,
@@ -102,12 +170,14 @@ pub fn percentage(input: &str) -> ParseResult<&str, u8> {
         "volume_split_percentage",
         combinator::map_res(
             sequence::terminated(
-                complete::take_while_m_n(1, 2, |c: char| c.is_ascii_digit()),
+                complete::take_while_m_n(1, 3, |c: char| c.is_ascii_digit()),
                 complete::tag("%"),
             ),
             |o: &str| {
                 o.parse::<u8>()
-                    .map_err(|_| EuclidError::InvalidPercentage(o.to_string()))
+                    .ok()
+                    .filter(|percentage| *percentage <= 100)
+                    .ok_or_else(|| EuclidError::InvalidPercentage(o.to_string()))
             },
         ),
     )(input)
@@ -0,0 +1,479 @@
+//! Prints a program in the canonical text form accepted by [`super::parser`]
+//!
+//! Printing a parsed program and parsing the printed text again always yields the same program,
+//! so the printer doubles as a formatter for rule files.
+
+use crate::{frontend::ast, types::DummyOutput};
+
+const INDENT: &str = "    ";
+
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum PrinterError {
+    #[error("'{0}' is not a valid identifier")]
+    InvalidIdentifier(String),
+    #[error("Rule '{0}' does not have any statements")]
+    EmptyRule(String),
+    #[error("Program does not have any rules")]
+    EmptyProgram,
+    #[error("Statement does not have any conditions")]
+    EmptyCondition,
+    #[error("Only one metadata comparison is allowed per condition")]
+    MultipleMetadataComparisons,
+    #[error("Value cannot be represented in the text format: {0}")]
+    UnrepresentableValue(String),
+    #[error("Metadata maps cannot be represented in the text format")]
+    UnsupportedMetadata,
+}
+
+pub trait EuclidPrintable {
+    fn print_output(&self) -> Result<String, PrinterError>;
+}
+
+impl EuclidPrintable for DummyOutput {
+    fn print_output(&self) -> Result<String, PrinterError> {
+        let outputs = self
+            .outputs
+            .iter()
+            .map(|output| {
+                if output.contains('"') {
+                    Err(PrinterError::UnrepresentableValue(output.clone()))
+                } else {
+                    Ok(format!("\"{output}\""))
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if outputs.is_empty() {
+            return Err(PrinterError::UnrepresentableValue(
+                "empty output list".to_string(),
+            ));
+        }
+
+        Ok(format!("[{}]", outputs.join(", ")))
+    }
+}
+
+impl EuclidPrintable for ast::ConnectorSelection {
+    fn print_output(&self) -> Result<String, PrinterError> {
+        let choices = match self {
+            Self::Priority(choices) => choices.iter().map(connector_choice).collect::<Vec<_>>(),
+            Self::VolumeSplit(splits) => splits
+                .iter()
+                .map(|split| format!("{}: {}%", connector_choice(&split.connector), split.split))
+                .collect(),
+        };
+
+        if choices.is_empty() {
+            return Err(PrinterError::UnrepresentableValue(
+                "empty connector list".to_string(),
+            ));
+        }
+
+        Ok(format!("[{}]", choices.join(", ")))
+    }
+}
+
+fn connector_choice(choice: &ast::RoutableConnectorChoice) -> String {
+    match &choice.merchant_connector_id {
+        Some(merchant_connector_id) => format!(
+            "{}({})",
+            choice.connector,
+            merchant_connector_id.get_string_repr()
+        ),
+        None => choice.connector.to_string(),
+    }
+}
+
+/// Prints the program in its canonical form
+pub fn program<O: EuclidPrintable>(program: &ast::Program<O>) -> Result<String, PrinterError> {
+    if !program.metadata.is_empty() {
+        return Err(PrinterError::UnsupportedMetadata);
+    }
+
+    if program.rules.is_empty() {
+        return Err(PrinterError::EmptyProgram);
+    }
+
+    let mut out = format!("default: {}\n", program.default_selection.print_output()?);
+
+    for rule in &program.rules {
+        out.push('\n');
+        print_rule(rule, &mut out)?;
+    }
+
+    Ok(out)
+}
+
+fn print_rule<O: EuclidPrintable>(
+    rule: &ast::Rule<O>,
+    out: &mut String,
+) -> Result<(), PrinterError> {
+    if !is_identifier(&rule.name) {
+        return Err(PrinterError::InvalidIdentifier(rule.name.clone()));
+    }
+
+    if rule.statements.is_empty() {
+        return Err(PrinterError::EmptyRule(rule.name.clone()));
+    }
+
+    out.push_str(&format!(
+        "{}: {}\n{{\n",
+        rule.name,
+        rule.connector_selection.print_output()?
+    ));
+    print_statements(&rule.statements, 1, out)?;
+    out.push_str("}\n");
+
+    Ok(())
+}
+
+fn print_statements(
+    statements: &[ast::IfStatement],
+    depth: usize,
+    out: &mut String,
+) -> Result<(), PrinterError> {
+    let indent = INDENT.repeat(depth);
+
+    for statement in statements {
+        out.push_str(&indent);
+        out.push_str(&print_condition(&statement.condition)?);
+
+        match &statement.nested {
+            Some(nested) => {
+                out.push_str(" {\n");
+                print_statements(nested, depth + 1, out)?;
+                out.push_str(&indent);
+                out.push_str("}\n");
+            }
+            None => out.push('\n'),
+        }
+    }
+
+    Ok(())
+}
+
+/// The parser only accepts a metadata comparison at the start of a condition, so it is moved
+/// there. Conditions are conjunctions, which makes the reordering harmless.
+fn print_condition(condition: &ast::IfCondition) -> Result<String, PrinterError> {
+    let (metadata, rest): (Vec<_>, Vec<_>) = condition
+        .iter()
+        .partition(|comparison| matches!(comparison.value, ast::ValueType::MetadataVariant(_)));
+
+    if metadata.len() > 1 {
+        return Err(PrinterError::MultipleMetadataComparisons);
+    }
+
+    let comparisons = metadata
+        .into_iter()
+        .chain(rest)
+        .map(print_comparison)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if comparisons.is_empty() {
+        return Err(PrinterError::EmptyCondition);
+    }
+
+    Ok(comparisons.join(" & "))
+}
+
+fn print_comparison(comparison: &ast::Comparison) -> Result<String, PrinterError> {
+    if !comparison.metadata.is_empty() {
+        return Err(PrinterError::UnsupportedMetadata);
+    }
+
+    let operator = comparison_operator(&comparison.comparison);
+
+    if let ast::ValueType::MetadataVariant(metadata) = &comparison.value {
+        return Ok(format!(
+            "{} {operator} {}",
+            quoted(&metadata.key)?,
+            quoted(&metadata.value)?
+        ));
+    }
+
+    if comparison.lhs.is_empty()
+        || !comparison
+            .lhs
+            .chars()
+            .all(|c| c.is_ascii_alphabetic() || c == '.' || c == '_')
+    {
+        return Err(PrinterError::InvalidIdentifier(comparison.lhs.clone()));
+    }
+
+    Ok(format!(
+        "{} {operator} {}",
+        comparison.lhs,
+        print_value(&comparison.value)?
+    ))
+}
+
+fn print_value(value: &ast::ValueType) -> Result<String, PrinterError> {
+    match value {
+        ast::ValueType::Number(number) => number_literal(number.get_amount_as_i64()),
+        ast::ValueType::EnumVariant(variant) => enum_variant(variant),
+        ast::ValueType::MetadataVariant(metadata) => Err(PrinterError::UnrepresentableValue(
+            format!("metadata '{}' outside a metadata comparison", metadata.key),
+        )),
+        ast::ValueType::StrValue(value) => quoted(value),
+        ast::ValueType::NumberArray(numbers) => parenthesized(
+            numbers
+                .iter()
+                .map(|number| number_literal(number.get_amount_as_i64())),
+        ),
+        ast::ValueType::EnumVariantArray(variants) => {
+            parenthesized(variants.iter().map(|variant| enum_variant(variant)))
+        }
+        ast::ValueType::NumberComparisonArray(comparisons) => parenthesized(
+            comparisons
+                .iter()
+                .map(|comparison| match comparison.comparison_type {
+                    ast::ComparisonType::Equal | ast::ComparisonType::NotEqual => {
+                        Err(PrinterError::UnrepresentableValue(format!(
+                            "'{}' inside a number comparison array",
+                            comparison_operator(&comparison.comparison_type)
+                        )))
+                    }
+                    _ => Ok(format!(
+                        "{}{}",
+                        comparison_operator(&comparison.comparison_type),
+                        number_literal(comparison.number.get_amount_as_i64())?
+                    )),
+                }),
+        ),
+    }
+}
+
+fn parenthesized(
+    values: impl Iterator<Item = Result<String, PrinterError>>,
+) -> Result<String, PrinterError> {
+    let values = values.collect::<Result<Vec<_>, _>>()?;
+
+    if values.is_empty() {
+        return Err(PrinterError::UnrepresentableValue(
+            "empty array".to_string(),
+        ));
+    }
+
+    Ok(format!("({})", values.join(", ")))
+}
+
+fn comparison_operator(comparison: &ast::ComparisonType) -> &'static str {
+    match comparison {
+        ast::ComparisonType::Equal => "=",
+        ast::ComparisonType::NotEqual => "/=",
+        ast::ComparisonType::LessThan => "<",
+        ast::ComparisonType::LessThanEqual => "<=",
+        ast::ComparisonType::GreaterThan => ">",
+        ast::ComparisonType::GreaterThanEqual => ">=",
+    }
+}
+
+fn number_literal(number: i64) -> Result<String, PrinterError> {
+    if number < 0 {
+        Err(PrinterError::UnrepresentableValue(number.to_string()))
+    } else {
+        Ok(number.to_string())
+    }
+}
+
+fn enum_variant(variant: &str) -> Result<String, PrinterError> {
+    if is_identifier(variant) {
+        Ok(variant.to_string())
+    } else {
+        Err(PrinterError::InvalidIdentifier(variant.to_string()))
+    }
+}
+
+fn quoted(value: &str) -> Result<String, PrinterError> {
+    if value.is_empty() || value.contains('"') {
+        Err(PrinterError::UnrepresentableValue(value.to_string()))
+    } else {
+        Ok(format!("\"{value}\""))
+    }
+}
+
+fn is_identifier(value: &str) -> bool {
+    let mut chars = value.chars();
+
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+
+    use super::*;
+    use crate::frontend::ast::parser;
+
+    fn round_trip<O>(source: &str) -> ast::Program<O>
+    where
+        O: EuclidPrintable + parser::EuclidParsable + PartialEq + std::fmt::Debug + 'static,
+    {
+        let (_, parsed) = parser::program::<O>(source).expect("Parsing failed");
+        let printed = program(&parsed).expect("Printing failed");
+        let (remaining, reparsed) = parser::program::<O>(&printed).expect("Reparsing failed");
+
+        assert!(remaining.trim().is_empty());
+        assert_eq!(parsed, reparsed);
+        assert_eq!(printed, program(&reparsed).expect("Reprinting failed"));
+
+        parsed
+    }
+
+    #[test]
+    fn test_round_trip_dummy_output() {
+        let source = r#"
+        default: ["stripe", "adyen"]
+
+        rule_1: ["stripe"]
+        {
+            payment_method = card & card_type = (credit, debit) {
+                amount >= 100 & amount = (>=200, <5000) {
+                    currency /= USD
+                }
+                card_bin = "424242"
+            }
+            amount = (10, 20, 30) {}
+        }
+
+        rule_2: ["adyen", "checkout"] {
+            "merchant_tier" = "gold" & payment_method = wallet
+        }
+        "#;
+
+        round_trip::<DummyOutput>(source);
+    }
+
+    #[test]
+    fn test_round_trip_connector_selection() {
+        let source = r#"
+        default: [stripe, adyen(mca_default)]
+
+        split_rule: [stripe: 70%, adyen(mca_adyen): 30%]
+        {
+            payment_method = card
+        }
+
+        priority_rule: [checkout]
+        {
+            amount > 1000 & currency = (USD, EUR)
+        }
+
+        full_split: [stripe: 100%] {
+            payment_method = wallet
+        }
+        "#;
+
+        let parsed = round_trip::<ast::ConnectorSelection>(source);
+
+        assert!(matches!(
+            &parsed.rules[0].connector_selection,
+            ast::ConnectorSelection::VolumeSplit(splits) if splits.len() == 2
+        ));
+        assert_eq!(
+            parsed.rules[2].connector_selection,
+            ast::ConnectorSelection::VolumeSplit(vec![ast::ConnectorVolumeSplit {
+                connector: ast::RoutableConnectorChoice {
+                    choice_kind: ast::RoutableChoiceKind::FullStruct,
+                    connector: crate::enums::RoutableConnectors::Stripe,
+                    merchant_connector_id: None,
+                },
+                split: 100,
+            }])
+        );
+    }
+
+    #[test]
+    fn test_percentages_above_hundred_are_rejected() {
+        assert_eq!(parser::percentage("100%"), Ok(("", 100)));
+        assert!(parser::percentage("101%").is_err());
+        assert!(parser::percentage("255%").is_err());
+        assert!(parser::percentage("1000%").is_err());
+    }
+
+    #[test]
+    fn test_canonical_layout() {
+        let source = r#"default: ["a"] rule_1: ["b"] { payment_method = card { amount > 10 } }"#;
+        let (_, parsed) = parser::program::<DummyOutput>(source).expect("Parsing failed");
+
+        assert_eq!(
+            program(&parsed).expect("Printing failed"),
+            concat!(
+                "default: [\"a\"]\n",
+                "\n",
+                "rule_1: [\"b\"]\n",
+                "{\n",
+                "    payment_method = card {\n",
+                "        amount > 10\n",
+                "    }\n",
+                "}\n",
+            )
+        );
+    }
+
+    #[test]
+    fn test_metadata_comparison_is_moved_first() {
+        let (_, mut parsed) = parser::program::<DummyOutput>(
+            r#"default: ["a"] rule_1: ["b"] { "tier" = "gold" & payment_method = card }"#,
+        )
+        .expect("Parsing failed");
+        parsed.rules[0].statements[0].condition.reverse();
+
+        let printed = program(&parsed).expect("Printing failed");
+
+        assert!(printed.contains(r#""tier" = "gold" & payment_method = card"#));
+    }
+
+    #[test]
+    fn test_unrepresentable_programs() {
+        let (_, parsed) = parser::program::<DummyOutput>(
+            r#"default: ["a"] rule_1: ["b"] { amount = (>10, <20) & currency = USD }"#,
+        )
+        .expect("Parsing failed");
+
+        let mut negative = parsed.clone();
+        negative.rules[0].statements[0].condition[1].value =
+            ast::ValueType::Number(common_utils::types::MinorUnit::new(-5));
+        assert_eq!(
+            program(&negative),
+            Err(PrinterError::UnrepresentableValue("-5".to_string()))
+        );
+
+        let mut equal_in_array = parsed.clone();
+        equal_in_array.rules[0].statements[0].condition[0].value =
+            ast::ValueType::NumberComparisonArray(vec![ast::NumberComparison {
+                comparison_type: ast::ComparisonType::Equal,
+                number: common_utils::types::MinorUnit::new(10),
+            }]);
+        assert!(matches!(
+            program(&equal_in_array),
+            Err(PrinterError::UnrepresentableValue(_))
+        ));
+
+        let mut bad_name = parsed.clone();
+        bad_name.rules[0].name = "rule one".to_string();
+        assert_eq!(
+            program(&bad_name),
+            Err(PrinterError::InvalidIdentifier("rule one".to_string()))
+        );
+
+        let mut empty_rule = parsed.clone();
+        empty_rule.rules[0].statements.clear();
+        assert_eq!(
+            program(&empty_rule),
+            Err(PrinterError::EmptyRule("rule_1".to_string()))
+        );
+
+        let mut with_metadata = parsed;
+        with_metadata
+            .metadata
+            .insert("key".to_string(), serde_json::json!("value"));
+        assert_eq!(
+            program(&with_metadata),
+            Err(PrinterError::UnsupportedMetadata)
+        );
+    }
+}
@@ -372,6 +372,27 @@ pub enum DirKeyKind {
     )]
     #[serde(rename = "card_discovery")]
     CardDiscovery,
+    #[strum(
+        serialize = "day_of_week",
+        detailed_message = "Day of the week of the transaction, in the timezone of the profile",
+        props(Category = "Time")
+    )]
+    #[serde(rename = "day_of_week")]
+    DayOfWeek,
+    #[strum(
+        serialize = "hour_of_day",
+        detailed_message = "Hour of the day of the transaction (0 to 23), in the timezone of the profile",
+        props(Category = "Time")
+    )]
+    #[serde(rename = "hour_of_day")]
+    HourOfDay,
+    #[strum(
+        serialize = "transaction_date",
+        detailed_message = "Date of the transaction as YYYYMMDD (eg. 20261224), in the timezone of the profile",
+        props(Category = "Time")
+    )]
+    #[serde(rename = "transaction_date")]
+    TransactionDate,
 }
 
 pub trait EuclidDirFilter: Sized
@@ -434,6 +455,9 @@ impl DirKeyKind {
             Self::TransactionInitiator => types::DataType::EnumVariant,
             Self::NetworkTokenType => types::DataType::EnumVariant,
             Self::CardDiscovery => types::DataType::EnumVariant,
+            Self::DayOfWeek => types::DataType::EnumVariant,
+            Self::HourOfDay => types::DataType::Number,
+            Self::TransactionDate => types::DataType::Number,
         }
     }
     pub fn get_value_set(&self) -> Option<Vec<DirValue>> {
@@ -615,6 +639,9 @@ impl DirKeyKind {
                     .map(DirValue::CardDiscovery)
                     .collect(),
             ),
+            Self::DayOfWeek => Some(enums::DayOfWeek::iter().map(DirValue::DayOfWeek).collect()),
+            Self::HourOfDay => None,
+            Self::TransactionDate => None,
         }
     }
 }
@@ -720,6 +747,12 @@ pub enum DirValue {
     NetworkTokenType(enums::NetworkTokenType),
     #[serde(rename = "card_discovery")]
     CardDiscovery(enums::CardDiscovery),
+    #[serde(rename = "day_of_week")]
+    DayOfWeek(enums::DayOfWeek),
+    #[serde(rename = "hour_of_day")]
+    HourOfDay(types::NumValue),
+    #[serde(rename = "transaction_date")]
+    TransactionDate(types::NumValue),
 }
 
 impl DirValue {
@@ -768,6 +801,9 @@ impl DirValue {
             Self::TransactionInitiator(_) => (DirKeyKind::TransactionInitiator, None),
             Self::NetworkTokenType(_) => (DirKeyKind::NetworkTokenType, None),
             Self::CardDiscovery(_) => (DirKeyKind::CardDiscovery, None),
+            Self::DayOfWeek(_) => (DirKeyKind::DayOfWeek, None),
+            Self::HourOfDay(_) => (DirKeyKind::HourOfDay, None),
+            Self::TransactionDate(_) => (DirKeyKind::TransactionDate, None),
         };
 
         DirKey::new(kind, data)
@@ -817,6 +853,9 @@ impl DirValue {
             Self::TransactionInitiator(_) => None,
             Self::NetworkTokenType(_) => None,
             Self::CardDiscovery(_) => None,
+            Self::DayOfWeek(_) => None,
+            Self::HourOfDay(_) => None,
+            Self::TransactionDate(_) => None,
         }
     }
 
@@ -834,6 +873,8 @@ impl DirValue {
             Self::PaymentAmount(val) => Some(val.clone()),
             Self::SurchargeAmount(val) => Some(val.clone()),
             Self::AcquirerFraudRate(val) => Some(val.clone()),
+            Self::HourOfDay(val) => Some(val.clone()),
+            Self::TransactionDate(val) => Some(val.clone()),
             _ => None,
         }
     }
@@ -876,6 +917,9 @@ impl DirValue {
             (Self::AcquirerFraudRate(r1), Self::AcquirerFraudRate(r2)) => r1 == r2,
             (Self::TransactionInitiator(ti1), Self::TransactionInitiator(ti2)) => ti1 == ti2,
             (Self::NetworkTokenType(ntt1), Self::NetworkTokenType(ntt2)) => ntt1 == ntt2,
+            (Self::DayOfWeek(d1), Self::DayOfWeek(d2)) => d1 == d2,
+            (Self::HourOfDay(h1), Self::HourOfDay(h2)) => h1 == h2,
+            (Self::TransactionDate(td1), Self::TransactionDate(td2)) => td1 == td2,
             _ => false,
         }
     }
@@ -1069,6 +1113,9 @@ mod test {
             dirval!(CaptureMethod = Manual),
             dirval!(BillingCountry = UnitedStatesOfAmerica),
             dirval!(BusinessCountry = France),
+            dirval!(DayOfWeek = Saturday),
+            dirval!(HourOfDay = 4),
+            dirval!(TransactionDate = 20261224),
         ];
 
         for val in values {
@@ -445,6 +445,33 @@ pub enum TransactionInitiator {
     Merchant,
 }
 
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Hash,
+    PartialEq,
+    Eq,
+    strum::Display,
+    strum::VariantNames,
+    strum::EnumIter,
+    strum::EnumString,
+    serde::Serialize,
+    serde::Deserialize,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DayOfWeek {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
 #[derive(
     Clone,
     Debug,
@@ -559,3 +586,4 @@ collect_variants!(CustomerDeviceType);
 collect_variants!(CustomerDevicePlatform);
 collect_variants!(CustomerDeviceDisplaySize);
 collect_variants!(TransactionInitiator);
+collect_variants!(DayOfWeek);
@@ -309,6 +309,9 @@ fn lower_value(dir_value: dir::DirValue) -> Result<EuclidValue, AnalysisErrorTyp
         dir::DirValue::TransactionInitiator(ti) => EuclidValue::TransactionInitiator(ti),
         dir::DirValue::NetworkTokenType(nt) => EuclidValue::PaymentMethodType(nt.into()),
         dir::DirValue::CardDiscovery(cd) => EuclidValue::CardDiscovery(cd),
+        dir::DirValue::DayOfWeek(dow) => EuclidValue::DayOfWeek(dow),
+        dir::DirValue::HourOfDay(hod) => EuclidValue::HourOfDay(hod),
+        dir::DirValue::TransactionDate(td) => EuclidValue::TransactionDate(td),
     })
 }
 
@@ -12,7 +12,7 @@ use crate::{
         ast,
         dir::{
             enums::{
-                CustomerDeviceDisplaySize, CustomerDevicePlatform, CustomerDeviceType,
+                CustomerDeviceDisplaySize, CustomerDevicePlatform, CustomerDeviceType, DayOfWeek,
                 TransactionInitiator,
             },
             DirKeyKind, DirValue, EuclidDirFilter,
@@ -91,6 +91,12 @@ pub enum EuclidKey {
     TransactionInitiator,
     #[strum(serialize = "card_discovery")]
     CardDiscovery,
+    #[strum(serialize = "day_of_week")]
+    DayOfWeek,
+    #[strum(serialize = "hour_of_day")]
+    HourOfDay,
+    #[strum(serialize = "transaction_date")]
+    TransactionDate,
 }
 
 impl EuclidDirFilter for DummyOutput {
@@ -111,6 +117,9 @@ impl EuclidDirFilter for DummyOutput {
         DirKeyKind::PaymentType,
         DirKeyKind::SetupFutureUsage,
         DirKeyKind::TransactionInitiator,
+        DirKeyKind::DayOfWeek,
+        DirKeyKind::HourOfDay,
+        DirKeyKind::TransactionDate,
     ];
 }
 impl EuclidAnalysable for DummyOutput {
@@ -138,7 +147,7 @@ impl EuclidAnalysable for DummyOutput {
             .collect()
     }
 }
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct DummyOutput {
     pub outputs: Vec<String>,
 }
@@ -185,6 +194,9 @@ impl EuclidKey {
             Self::CustomerDevicePlatform => DataType::EnumVariant,
             Self::TransactionInitiator => DataType::EnumVariant,
             Self::CardDiscovery => DataType::EnumVariant,
+            Self::DayOfWeek => DataType::EnumVariant,
+            Self::HourOfDay => DataType::Number,
+            Self::TransactionDate => DataType::Number,
         }
     }
 }
@@ -313,6 +325,9 @@ pub enum EuclidValue {
     CustomerDevicePlatform(CustomerDevicePlatform),
     TransactionInitiator(TransactionInitiator),
     CardDiscovery(enums::CardDiscovery),
+    DayOfWeek(DayOfWeek),
+    HourOfDay(NumValue),
+    TransactionDate(NumValue),
 }
 
 impl EuclidValue {
@@ -320,6 +335,8 @@ impl EuclidValue {
         match self {
             Self::PaymentAmount(val) => Some(val.clone()),
             Self::SurchargeAmount(val) => Some(val.clone()),
+            Self::HourOfDay(val) => Some(val.clone()),
+            Self::TransactionDate(val) => Some(val.clone()),
             _ => None,
         }
     }
@@ -355,6 +372,9 @@ impl EuclidValue {
             Self::CustomerDevicePlatform(_) => EuclidKey::CustomerDevicePlatform,
             Self::TransactionInitiator(_) => EuclidKey::TransactionInitiator,
             Self::CardDiscovery(_) => EuclidKey::CardDiscovery,
+            Self::DayOfWeek(_) => EuclidKey::DayOfWeek,
+            Self::HourOfDay(_) => EuclidKey::HourOfDay,
+            Self::TransactionDate(_) => EuclidKey::TransactionDate,
         }
     }
 }
@@ -0,0 +1,63 @@
+use std::path::PathBuf;
+
+/// Utility to format and lint routing rule files written in the Euclid DSL, and to convert them
+/// to and from the JSON accepted by the routing API.
+#[derive(clap::Parser, Debug)]
+#[command(arg_required_else_help = true)]
+pub(crate) struct Args {
+    #[command(subcommand)]
+    pub(crate) command: Command,
+}
+
+#[derive(clap::Subcommand, Debug)]
+pub(crate) enum Command {
+    /// Rewrites rule files in their canonical form.
+    Fmt {
+        /// Only check whether the files are formatted, without modifying them. Exits with a
+        /// non-zero status if any file is not in its canonical form.
+        #[arg(long)]
+        check: bool,
+
+        /// Rule files to format.
+        #[arg(required = true, value_name = "FILE")]
+        files: Vec<PathBuf>,
+    },
+
+    /// Runs the static analyzer on rule files and reports errors and warnings. Exits with a
+    /// non-zero status if any file has errors.
+    Lint {
+        /// JSON file containing the list of merchant connector accounts, as returned by the
+        /// merchant connector account list API. When specified, the rules are also checked
+        /// against the payment methods enabled on these accounts.
+        #[arg(long, value_name = "FILE")]
+        mcas: Option<PathBuf>,
+
+        /// Rule files to lint.
+        #[arg(required = true, value_name = "FILE")]
+        files: Vec<PathBuf>,
+    },
+
+    /// Converts a rule file to an advanced routing algorithm in JSON.
+    ToJson {
+        /// Input rule file.
+        #[arg(value_name = "FILE")]
+        input_file: PathBuf,
+
+        /// Output file. Output will be written to stdout if not specified.
+        #[arg(short, long, value_name = "FILE")]
+        output_file: Option<PathBuf>,
+    },
+
+    /// Converts an advanced routing algorithm in JSON to a rule file. Accepts either the
+    /// algorithm itself or any object containing it under the `algorithm` key, such as a routing
+    /// config request or response.
+    FromJson {
+        /// Input JSON file.
+        #[arg(value_name = "FILE")]
+        input_file: PathBuf,
+
+        /// Output file. Output will be written to stdout if not specified.
+        #[arg(short, long, value_name = "FILE")]
+        output_file: Option<PathBuf>,
+    },
+}
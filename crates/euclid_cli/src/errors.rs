@@ -0,0 +1,27 @@
+pub(crate) type CliResult<T> = error_stack::Result<T, CliError>;
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum CliError {
+    #[error("Failed to read input")]
+    ReadFailed,
+    #[error("Failed to write output")]
+    WriteFailed,
+    #[error("Failed to parse routing program")]
+    ParseFailed,
+    #[error("Failed to print routing program")]
+    PrintFailed,
+    #[error("Failed to parse JSON input")]
+    DeserializationFailed,
+    #[error("Failed to serialize routing algorithm as JSON")]
+    SerializationFailed,
+    #[error("Only advanced routing algorithms can be converted to rule files")]
+    UnsupportedAlgorithm,
+    #[error("Failed to build knowledge graph")]
+    KnowledgeGraphFailed,
+    #[error("Failed to analyze routing program")]
+    AnalysisFailed,
+    #[error("{0} file(s) are not formatted")]
+    UnformattedFiles(usize),
+    #[error("{0} file(s) have errors")]
+    FilesWithErrors(usize),
+}
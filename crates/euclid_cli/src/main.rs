@@ -0,0 +1,410 @@
+mod cli;
+mod errors;
+
+use std::{
+    collections::HashMap,
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+use api_models::routing as routing_types;
+use error_stack::{report, ResultExt};
+use euclid::{
+    dssa::{analyzer, truth},
+    frontend::ast::{self, parser, printer},
+};
+use hyperswitch_constraint_graph::ConstraintGraph;
+
+use crate::errors::{CliError, CliResult};
+
+fn main() -> CliResult<()> {
+    let args = <cli::Args as clap::Parser>::parse();
+
+    match args.command {
+        cli::Command::Fmt { check, files } => format_files(&files, check),
+        cli::Command::Lint { mcas, files } => lint_files(mcas.as_deref(), &files),
+        cli::Command::ToJson {
+            input_file,
+            output_file,
+        } => {
+            let json = program_to_json(read_program(&input_file)?)?;
+
+            let mut writer = open_writer(output_file)?;
+            writeln!(writer, "{json}").change_context(CliError::WriteFailed)?;
+            writer.flush().change_context(CliError::WriteFailed)
+        }
+        cli::Command::FromJson {
+            input_file,
+            output_file,
+        } => {
+            let contents = std::fs::read_to_string(&input_file)
+                .change_context(CliError::ReadFailed)
+                .attach_printable_lazy(|| input_file.display().to_string())?;
+            let text = printer::program(&json_to_program(&contents)?)
+                .change_context(CliError::PrintFailed)?;
+
+            let mut writer = open_writer(output_file)?;
+            writer
+                .write_all(text.as_bytes())
+                .change_context(CliError::WriteFailed)?;
+            writer.flush().change_context(CliError::WriteFailed)
+        }
+    }
+}
+
+fn format_files(files: &[PathBuf], check: bool) -> CliResult<()> {
+    let mut stdout = std::io::stdout().lock();
+    let mut unformatted = 0;
+
+    for file in files {
+        let source = std::fs::read_to_string(file)
+            .change_context(CliError::ReadFailed)
+            .attach_printable_lazy(|| file.display().to_string())?;
+        let formatted = format_source(file, &source)?;
+
+        if formatted == source {
+            continue;
+        }
+
+        if check {
+            unformatted += 1;
+            writeln!(stdout, "{}: not formatted", file.display())
+                .change_context(CliError::WriteFailed)?;
+        } else {
+            std::fs::write(file, formatted)
+                .change_context(CliError::WriteFailed)
+                .attach_printable_lazy(|| file.display().to_string())?;
+            writeln!(stdout, "{}: formatted", file.display())
+                .change_context(CliError::WriteFailed)?;
+        }
+    }
+
+    if unformatted > 0 {
+        return Err(report!(CliError::UnformattedFiles(unformatted)));
+    }
+
+    Ok(())
+}
+
+fn format_source(file: &Path, source: &str) -> CliResult<String> {
+    let program = parse_program(file, source)?;
+
+    printer::program(&program)
+        .change_context(CliError::PrintFailed)
+        .attach_printable_lazy(|| file.display().to_string())
+}
+
+fn lint_files(mcas: Option<&Path>, files: &[PathBuf]) -> CliResult<()> {
+    let knowledge_graph = mcas.map(build_knowledge_graph).transpose()?;
+    let mut stdout = std::io::stdout().lock();
+    let mut files_with_errors = 0;
+
+    for file in files {
+        let program = map_program(read_program(file)?, to_api_connector_selection);
+
+        match analyzer::analyze(program.clone(), knowledge_graph.as_ref()) {
+            Ok(_) => {
+                let warnings = analyzer::analyze_warnings(program, knowledge_graph.as_ref())
+                    .map_err(|error| {
+                        report!(CliError::AnalysisFailed)
+                            .attach_printable(format!("{}: {error}", file.display()))
+                    })?;
+
+                for warning in warnings {
+                    writeln!(stdout, "{}: warning: {warning}", file.display())
+                        .change_context(CliError::WriteFailed)?;
+                }
+            }
+            Err(error) => {
+                files_with_errors += 1;
+                writeln!(stdout, "{}: error: {error}", file.display())
+                    .change_context(CliError::WriteFailed)?;
+            }
+        }
+    }
+
+    if files_with_errors > 0 {
+        return Err(report!(CliError::FilesWithErrors(files_with_errors)));
+    }
+
+    Ok(())
+}
+
+/// Builds the same graph that the dashboard uses for analysis: the graph of payment methods
+/// enabled on the merchant connector accounts, combined with the static analysis graph
+fn build_knowledge_graph(
+    mcas: &Path,
+) -> CliResult<ConstraintGraph<euclid::frontend::dir::DirValue>> {
+    let contents = std::fs::read_to_string(mcas)
+        .change_context(CliError::ReadFailed)
+        .attach_printable_lazy(|| mcas.display().to_string())?;
+    let mca_graph_data = serde_json::from_str::<Vec<api_models::admin::MCACGraphData>>(&contents)
+        .change_context(CliError::DeserializationFailed)
+        .attach_printable("Failed to parse MCA file contents")?;
+
+    let config = kgraph_utils::types::CountryCurrencyFilter {
+        connector_configs: HashMap::new(),
+        default_configs: Some(kgraph_utils::types::PaymentMethodFilters(HashMap::new())),
+    };
+    let mca_graph =
+        kgraph_utils::mca::make_mca_graph(mca_graph_data, &config).map_err(|error| {
+            report!(CliError::KnowledgeGraphFailed)
+                .attach_printable(format!("Failed to build MCA graph: {error}"))
+        })?;
+
+    ConstraintGraph::combine(&mca_graph, &truth::ANALYSIS_GRAPH).map_err(|error| {
+        report!(CliError::KnowledgeGraphFailed)
+            .attach_printable(format!("Failed to combine knowledge graphs: {error}"))
+    })
+}
+
+fn read_program(file: &Path) -> CliResult<ast::Program<ast::ConnectorSelection>> {
+    let source = std::fs::read_to_string(file)
+        .change_context(CliError::ReadFailed)
+        .attach_printable_lazy(|| file.display().to_string())?;
+
+    parse_program(file, &source)
+}
+
+fn parse_program(file: &Path, source: &str) -> CliResult<ast::Program<ast::ConnectorSelection>> {
+    let error = match parser::program::<ast::ConnectorSelection>(source) {
+        Ok((remaining, program)) if remaining.trim().is_empty() => return Ok(program),
+        // The rules parser stops at the first rule it cannot parse, parse that rule again to
+        // report why
+        Ok((remaining, _)) => match parser::rule::<ast::ConnectorSelection>(remaining) {
+            Err(nom::Err::Error(error) | nom::Err::Failure(error)) => error,
+            _ => {
+                return Err(report!(CliError::ParseFailed)
+                    .attach_printable(format!("{}: unexpected trailing input", file.display())))
+            }
+        },
+        Err(nom::Err::Error(error) | nom::Err::Failure(error)) => error,
+        Err(nom::Err::Incomplete(_)) => {
+            return Err(report!(CliError::ParseFailed)
+                .attach_printable(format!("{}: incomplete input", file.display())))
+        }
+    };
+
+    Err(report!(CliError::ParseFailed).attach_printable(format!(
+        "{}\n{}",
+        file.display(),
+        nom::error::convert_error(source, error)
+    )))
+}
+
+/// Converts a program to the JSON of an `advanced` routing algorithm
+fn program_to_json(program: ast::Program<ast::ConnectorSelection>) -> CliResult<String> {
+    let algorithm = routing_types::StaticRoutingAlgorithm::Advanced(map_program(
+        program,
+        to_api_connector_selection,
+    ));
+
+    serde_json::to_string_pretty(&algorithm).change_context(CliError::SerializationFailed)
+}
+
+/// Converts the JSON of an `advanced` routing algorithm back to a program. Routing config
+/// requests and responses are accepted in addition to bare algorithms.
+fn json_to_program(contents: &str) -> CliResult<ast::Program<ast::ConnectorSelection>> {
+    let mut value = serde_json::from_str::<serde_json::Value>(contents)
+        .change_context(CliError::DeserializationFailed)?;
+
+    if let Some(algorithm) = value.get_mut("algorithm") {
+        value = algorithm.take();
+    }
+
+    let algorithm = serde_json::from_value::<routing_types::StaticRoutingAlgorithm>(value)
+        .change_context(CliError::DeserializationFailed)
+        .attach_printable("Failed to parse routing algorithm")?;
+    let routing_types::StaticRoutingAlgorithm::Advanced(program) = algorithm else {
+        return Err(report!(CliError::UnsupportedAlgorithm));
+    };
+
+    Ok(map_program(program, to_ast_connector_selection))
+}
+
+fn open_writer(output_file: Option<PathBuf>) -> CliResult<BufWriter<Box<dyn Write>>> {
+    Ok(match output_file {
+        // Write to file if output file is specified
+        Some(file) => BufWriter::new(Box::new(
+            std::fs::OpenOptions::new()
+                .create(true)
+                .write(true)
+                .truncate(true)
+                .open(&file)
+                .change_context(CliError::WriteFailed)
+                .attach_printable_lazy(|| file.display().to_string())?,
+        )),
+        // Write to stdout otherwise
+        None => BufWriter::new(Box::new(std::io::stdout().lock())),
+    })
+}
+
+fn map_program<A, B>(program: ast::Program<A>, f: impl Fn(A) -> B) -> ast::Program<B> {
+    ast::Program {
+        default_selection: f(program.default_selection),
+        rules: program
+            .rules
+            .into_iter()
+            .map(|rule| ast::Rule {
+                name: rule.name,
+                connector_selection: f(rule.connector_selection),
+                statements: rule.statements,
+            })
+            .collect(),
+        metadata: program.metadata,
+    }
+}
+
+fn to_api_connector_selection(
+    selection: ast::ConnectorSelection,
+) -> routing_types::ConnectorSelection {
+    let to_api_choice =
+        |choice: ast::RoutableConnectorChoice| routing_types::RoutableConnectorChoice {
+            choice_kind: routing_types::RoutableChoiceKind::FullStruct,
+            connector: choice.connector,
+            merchant_connector_id: choice.merchant_connector_id,
+        };
+
+    match selection {
+        ast::ConnectorSelection::Priority(choices) => routing_types::ConnectorSelection::Priority(
+            choices.into_iter().map(to_api_choice).collect(),
+        ),
+        ast::ConnectorSelection::VolumeSplit(splits) => {
+            routing_types::ConnectorSelection::VolumeSplit(
+                splits
+                    .into_iter()
+                    .map(|split| routing_types::ConnectorVolumeSplit {
+                        connector: to_api_choice(split.connector),
+                        split: split.split,
+                    })
+                    .collect(),
+            )
+        }
+    }
+}
+
+fn to_ast_connector_selection(
+    selection: routing_types::ConnectorSelection,
+) -> ast::ConnectorSelection {
+    let to_ast_choice =
+        |choice: routing_types::RoutableConnectorChoice| ast::RoutableConnectorChoice {
+            choice_kind: ast::RoutableChoiceKind::FullStruct,
+            connector: choice.connector,
+            merchant_connector_id: choice.merchant_connector_id,
+        };
+
+    match selection {
+        routing_types::ConnectorSelection::Priority(choices) => {
+            ast::ConnectorSelection::Priority(choices.into_iter().map(to_ast_choice).collect())
+        }
+        routing_types::ConnectorSelection::VolumeSplit(splits) => {
+            ast::ConnectorSelection::VolumeSplit(
+                splits
+                    .into_iter()
+                    .map(|split| ast::ConnectorVolumeSplit {
+                        connector: to_ast_choice(split.connector),
+                        split: split.split,
+                    })
+                    .collect(),
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    const SOURCE: &str = r#"
+    default: [stripe, adyen]
+
+    card_split: [stripe: 70%, adyen(mca_adyen): 30%] {
+        payment_method = card & amount >= 1000
+    }
+
+    wallets: [checkout]
+    {
+        payment_method = wallet
+    }
+    "#;
+
+    fn file() -> &'static Path {
+        Path::new("rules.euclid")
+    }
+
+    #[test]
+    fn test_format_source_is_idempotent() {
+        let formatted = format_source(file(), SOURCE).unwrap();
+
+        assert_ne!(formatted, SOURCE);
+        assert_eq!(format_source(file(), &formatted).unwrap(), formatted);
+    }
+
+    #[test]
+    fn test_parse_program_reports_invalid_rule() {
+        let source = r#"
+        default: [stripe]
+
+        valid: [adyen] { payment_method = card }
+
+        invalid: [adyen] { payment_method = }
+        "#;
+
+        let error = parse_program(file(), source).unwrap_err();
+
+        assert!(matches!(error.current_context(), CliError::ParseFailed));
+        assert!(format!("{error:?}").contains("rules.euclid"));
+    }
+
+    #[test]
+    fn test_parse_program_rejects_split_over_two_digits() {
+        let source = r#"
+        default: [stripe]
+
+        full_split: [stripe: 100%] { payment_method = card }
+        "#;
+
+        let error = parse_program(file(), source).unwrap_err();
+
+        assert!(matches!(error.current_context(), CliError::ParseFailed));
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let program = parse_program(file(), SOURCE).unwrap();
+        let json = program_to_json(program.clone()).unwrap();
+
+        assert_eq!(json_to_program(&json).unwrap(), program);
+    }
+
+    #[test]
+    fn test_json_to_program_accepts_routing_config() {
+        let program = parse_program(file(), SOURCE).unwrap();
+        let algorithm =
+            serde_json::from_str::<serde_json::Value>(&program_to_json(program.clone()).unwrap())
+                .unwrap();
+        let config = serde_json::json!({
+            "name": "card rules",
+            "algorithm": algorithm,
+        });
+
+        assert_eq!(json_to_program(&config.to_string()).unwrap(), program);
+    }
+
+    #[test]
+    fn test_json_to_program_rejects_non_advanced_algorithm() {
+        let json = serde_json::json!({
+            "type": "priority",
+            "data": [{ "connector": "stripe", "merchant_connector_id": null }],
+        });
+
+        let error = json_to_program(&json.to_string()).unwrap_err();
+
+        assert!(matches!(
+            error.current_context(),
+            CliError::UnsupportedAlgorithm
+        ));
+    }
+}
@@ -257,11 +257,15 @@ pub fn get_valid_connectors_for_rule(rule: JsValue) -> JsResult {
     Ok(serde_wasm_bindgen::to_value(&valid_connectors)?)
 }
 
+/// Analyzes the program and fails if it is invalid. Otherwise returns the list of warnings
+/// about rules that can never be selected and payments that fall through to the default.
 #[wasm_bindgen(js_name = analyzeProgram)]
 pub fn analyze_program(js_program: JsValue) -> JsResult {
     let program: ast::Program<ConnectorSelection> = serde_wasm_bindgen::from_value(js_program)?;
-    analyzer::analyze(program, SEED_DATA.get().map(|sd| &sd.cgraph)).err_to_js()?;
-    Ok(JsValue::NULL)
+    let cgraph = SEED_DATA.get().map(|sd| &sd.cgraph);
+    analyzer::analyze(program.clone(), cgraph).err_to_js()?;
+    let warnings = analyzer::analyze_warnings(program, cgraph).err_to_js()?;
+    Ok(serde_wasm_bindgen::to_value(&warnings)?)
 }
 
 #[wasm_bindgen(js_name = runProgram)]
@@ -374,6 +378,7 @@ pub fn get_variant_values(key: &str) -> Result<JsValue, JsValue> {
         }
         dir::DirKeyKind::NetworkTokenType => dir_enums::NetworkTokenType::VARIANTS,
         dir::DirKeyKind::CardDiscovery => dir_enums::CardDiscovery::VARIANTS,
+        dir::DirKeyKind::DayOfWeek => dir_enums::DayOfWeek::VARIANTS,
 
         dir::DirKeyKind::PaymentAmount
         | dir::DirKeyKind::SurchargeAmount
@@ -383,7 +388,9 @@ pub fn get_variant_values(key: &str) -> Result<JsValue, JsValue> {
         | dir::DirKeyKind::BusinessLabel
         | dir::DirKeyKind::MetaData
         | dir::DirKeyKind::IssuerName
-        | dir::DirKeyKind::AcquirerFraudRate => Err("Key does not have variants".to_string())?,
+        | dir::DirKeyKind::AcquirerFraudRate
+        | dir::DirKeyKind::HourOfDay
+        | dir::DirKeyKind::TransactionDate => Err("Key does not have variants".to_string())?,
     };
 
     Ok(serde_wasm_bindgen::to_value(variants)?)
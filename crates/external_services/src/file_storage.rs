@@ -59,6 +59,15 @@ pub trait FileStorageInterface: dyn_clone::DynClone + Sync + Send {
         file: Vec<u8>,
     ) -> CustomResult<(), FileStorageError>;
 
+    /// Uploads a file received a chunk at a time to the selected storage scheme. The file is only
+    /// stored once the sender of `file_chunks` is dropped, and is not stored if the upload is
+    /// cancelled before that.
+    async fn upload_file_stream(
+        &self,
+        file_key: &str,
+        file_chunks: tokio::sync::mpsc::Receiver<Vec<u8>>,
+    ) -> CustomResult<(), FileStorageError>;
+
     /// Deletes a file from the selected storage scheme.
     async fn delete_file(&self, file_key: &str) -> CustomResult<(), FileStorageError>;
 
@@ -1,8 +1,11 @@
 use aws_config::meta::region::RegionProviderChain;
 use aws_sdk_s3::{
     operation::{
-        delete_object::DeleteObjectError, get_object::GetObjectError, put_object::PutObjectError,
+        complete_multipart_upload::CompleteMultipartUploadError,
+        create_multipart_upload::CreateMultipartUploadError, delete_object::DeleteObjectError,
+        get_object::GetObjectError, put_object::PutObjectError, upload_part::UploadPartError,
     },
+    types::{CompletedMultipartUpload, CompletedPart},
     Client,
 };
 use aws_sdk_sts::config::Region;
@@ -12,6 +15,10 @@ use error_stack::ResultExt;
 use super::InvalidFileStorageConfig;
 use crate::file_storage::{FileStorageError, FileStorageInterface};
 
+/// Size of the parts a streamed file is uploaded in, S3 requires every part of a multipart upload
+/// but the last one to be at least 5 MiB in size.
+const MULTIPART_UPLOAD_PART_SIZE: usize = 8 * 1024 * 1024;
+
 /// Configuration for AWS S3 file storage.
 #[derive(Debug, serde::Deserialize, Clone, Default)]
 #[serde(default)]
@@ -76,6 +83,108 @@ impl AwsFileStorageClient {
         Ok(())
     }
 
+    /// Uploads a file received a chunk at a time to AWS S3 using a multipart upload. The upload is
+    /// aborted if any of its parts fail to upload, uploads which are cancelled are left to the
+    /// lifecycle rules of the bucket to clean up.
+    async fn upload_file_stream(
+        &self,
+        file_key: &str,
+        mut file_chunks: tokio::sync::mpsc::Receiver<Vec<u8>>,
+    ) -> CustomResult<(), AwsS3StorageError> {
+        let upload_id = self
+            .inner_client
+            .create_multipart_upload()
+            .bucket(&self.bucket_name)
+            .key(file_key)
+            .send()
+            .await
+            .map_err(AwsS3StorageError::CreateMultipartUploadFailure)?
+            .upload_id
+            .ok_or(AwsS3StorageError::MissingUploadId)?;
+
+        let parts = match self
+            .upload_parts(file_key, &upload_id, &mut file_chunks)
+            .await
+        {
+            Ok(parts) => parts,
+            Err(error) => {
+                // A failure to abort the upload only leaves it to the lifecycle rules, the error
+                // of the upload itself is the one returned
+                let _ = self
+                    .inner_client
+                    .abort_multipart_upload()
+                    .bucket(&self.bucket_name)
+                    .key(file_key)
+                    .upload_id(&upload_id)
+                    .send()
+                    .await;
+                return Err(error);
+            }
+        };
+
+        self.inner_client
+            .complete_multipart_upload()
+            .bucket(&self.bucket_name)
+            .key(file_key)
+            .upload_id(&upload_id)
+            .multipart_upload(
+                CompletedMultipartUpload::builder()
+                    .set_parts(Some(parts))
+                    .build(),
+            )
+            .send()
+            .await
+            .map_err(AwsS3StorageError::CompleteMultipartUploadFailure)?;
+        Ok(())
+    }
+
+    /// Uploads the chunks received as parts of at least [`MULTIPART_UPLOAD_PART_SIZE`] bytes,
+    /// until the sender of the chunks is dropped.
+    async fn upload_parts(
+        &self,
+        file_key: &str,
+        upload_id: &str,
+        file_chunks: &mut tokio::sync::mpsc::Receiver<Vec<u8>>,
+    ) -> CustomResult<Vec<CompletedPart>, AwsS3StorageError> {
+        let mut parts = Vec::new();
+        let mut part = Vec::new();
+
+        loop {
+            let chunk = file_chunks.recv().await;
+            let is_last_chunk = chunk.is_none();
+            part.extend(chunk.unwrap_or_default());
+
+            // A multipart upload has at least one part, even if the file is empty
+            if part.len() >= MULTIPART_UPLOAD_PART_SIZE
+                || (is_last_chunk && (!part.is_empty() || parts.is_empty()))
+            {
+                let part_number =
+                    i32::try_from(parts.len() + 1).map_err(|_| AwsS3StorageError::TooManyParts)?;
+                let uploaded_part = self
+                    .inner_client
+                    .upload_part()
+                    .bucket(&self.bucket_name)
+                    .key(file_key)
+                    .upload_id(upload_id)
+                    .part_number(part_number)
+                    .body(std::mem::take(&mut part).into())
+                    .send()
+                    .await
+                    .map_err(AwsS3StorageError::UploadPartFailure)?;
+                parts.push(
+                    CompletedPart::builder()
+                        .set_e_tag(uploaded_part.e_tag)
+                        .part_number(part_number)
+                        .build(),
+                );
+            }
+
+            if is_last_chunk {
+                return Ok(parts);
+            }
+        }
+    }
+
     /// Deletes a file from AWS S3.
     async fn delete_file(&self, file_key: &str) -> CustomResult<(), AwsS3StorageError> {
         self.inner_client
@@ -120,6 +229,18 @@ impl FileStorageInterface for AwsFileStorageClient {
         Ok(())
     }
 
+    /// Uploads a file received a chunk at a time to AWS S3.
+    async fn upload_file_stream(
+        &self,
+        file_key: &str,
+        file_chunks: tokio::sync::mpsc::Receiver<Vec<u8>>,
+    ) -> CustomResult<(), FileStorageError> {
+        self.upload_file_stream(file_key, file_chunks)
+            .await
+            .change_context(FileStorageError::UploadFailed)?;
+        Ok(())
+    }
+
     /// Deletes a file from AWS S3.
     async fn delete_file(&self, file_key: &str) -> CustomResult<(), FileStorageError> {
         self.delete_file(file_key)
@@ -144,6 +265,26 @@ enum AwsS3StorageError {
     #[error("File upload to S3 failed: {0:?}")]
     UploadFailure(aws_sdk_s3::error::SdkError<PutObjectError>),
 
+    /// Error indicating that a multipart upload to S3 could not be started.
+    #[error("Multipart upload creation on S3 failed: {0:?}")]
+    CreateMultipartUploadFailure(aws_sdk_s3::error::SdkError<CreateMultipartUploadError>),
+
+    /// Error indicating that S3 did not return the id of a multipart upload.
+    #[error("Multipart upload id is missing in the S3 response")]
+    MissingUploadId,
+
+    /// Error indicating that a part of a multipart upload to S3 failed.
+    #[error("Multipart upload part to S3 failed: {0:?}")]
+    UploadPartFailure(aws_sdk_s3::error::SdkError<UploadPartError>),
+
+    /// Error indicating that a file has more parts than a multipart upload allows.
+    #[error("File has too many parts to upload to S3")]
+    TooManyParts,
+
+    /// Error indicating that a multipart upload to S3 could not be completed.
+    #[error("Multipart upload completion on S3 failed: {0:?}")]
+    CompleteMultipartUploadFailure(aws_sdk_s3::error::SdkError<CompleteMultipartUploadError>),
+
     /// Error indicating that file retrieval from S3 failed.
     #[error("File retrieve from S3 failed: {0:?}")]
     RetrieveFailure(aws_sdk_s3::error::SdkError<GetObjectError>),
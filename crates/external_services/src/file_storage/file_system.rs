@@ -1,7 +1,7 @@
 //! Module for local file system storage operations
 
 use std::{
-    fs::{remove_file, File},
+    fs::{remove_file, rename, File},
     io::{Read, Write},
     path::PathBuf,
 };
@@ -52,6 +52,36 @@ impl FileSystem {
         Ok(())
     }
 
+    /// Saves the chunks of a file to the file system as they are received. The chunks are written
+    /// to a temporary file, which is moved to the file key once all of them have been received.
+    async fn upload_file_stream(
+        &self,
+        file_key: &str,
+        mut file_chunks: tokio::sync::mpsc::Receiver<Vec<u8>>,
+    ) -> CustomResult<(), FileSystemStorageError> {
+        let file_path = get_file_path(file_key);
+        let partial_file_path = get_file_path(format!("{file_key}.part"));
+
+        std::fs::create_dir_all(
+            file_path
+                .parent()
+                .ok_or(FileSystemStorageError::CreateDirFailed)
+                .attach_printable("Failed to obtain parent directory")?,
+        )
+        .change_context(FileSystemStorageError::CreateDirFailed)?;
+
+        let mut file_handler = File::create(&partial_file_path)
+            .change_context(FileSystemStorageError::CreateFailure)?;
+        while let Some(chunk) = file_chunks.recv().await {
+            file_handler
+                .write_all(&chunk)
+                .change_context(FileSystemStorageError::WriteFailure)?;
+        }
+        rename(partial_file_path, file_path)
+            .change_context(FileSystemStorageError::WriteFailure)?;
+        Ok(())
+    }
+
     /// Deletes the file associated with the specified file key from the file system.
     async fn delete_file(&self, file_key: &str) -> CustomResult<(), FileSystemStorageError> {
         let file_path = get_file_path(file_key);
@@ -85,6 +115,18 @@ impl FileStorageInterface for FileSystem {
         Ok(())
     }
 
+    /// Saves the chunks of a file to the file system as they are received.
+    async fn upload_file_stream(
+        &self,
+        file_key: &str,
+        file_chunks: tokio::sync::mpsc::Receiver<Vec<u8>>,
+    ) -> CustomResult<(), FileStorageError> {
+        self.upload_file_stream(file_key, file_chunks)
+            .await
+            .change_context(FileStorageError::UploadFailed)?;
+        Ok(())
+    }
+
     /// Deletes the file associated with the specified file key from the file system.
     async fn delete_file(&self, file_key: &str) -> CustomResult<(), FileStorageError> {
         self.delete_file(file_key)
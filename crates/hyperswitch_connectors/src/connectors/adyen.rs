@@ -2138,6 +2138,25 @@ impl IncomingWebhook for Adyen {
         Err(report!(errors::ConnectorError::WebhookReferenceIdNotFound))
     }
 
+    fn get_webhook_event_id(
+        &self,
+        request: &IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        if request.body.is_empty() {
+            return Ok(None);
+        }
+
+        let notif = get_webhook_object_from_body(request.body)
+            .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+
+        // Adyen doesn't assign an ID to notifications, a notification delivered again has the
+        // same PSP reference, event code and outcome
+        Ok(Some(format!(
+            "{}_{}_{}",
+            notif.psp_reference, notif.event_code, notif.success
+        )))
+    }
+
     fn get_webhook_event_type(
         &self,
         request: &IncomingWebhookRequestDetails<'_>,
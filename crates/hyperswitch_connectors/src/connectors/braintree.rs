@@ -1088,6 +1088,25 @@ impl IncomingWebhook for Braintree {
         }
     }
 
+    fn get_webhook_event_id(
+        &self,
+        request: &IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let notif = get_webhook_object_from_body(request.body)
+            .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)?;
+
+        let response = decode_webhook_payload(notif.bt_payload.replace('\n', "").as_bytes())?;
+
+        // Braintree doesn't assign an ID to notifications, a notification delivered again has
+        // the same kind, subject and timestamp
+        Ok(response.dispute.map(|dispute_data| {
+            format!(
+                "{}_{}_{}",
+                response.kind, dispute_data.id, response.timestamp
+            )
+        }))
+    }
+
     fn get_webhook_event_type(
         &self,
         request: &IncomingWebhookRequestDetails<'_>,
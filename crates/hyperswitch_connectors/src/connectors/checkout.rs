@@ -1349,6 +1349,22 @@ impl webhooks::IncomingWebhook for Checkout {
         Ok(ref_id)
     }
 
+    fn get_webhook_event_id(
+        &self,
+        request: &webhooks::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        if request.body.is_empty() {
+            return Ok(None);
+        }
+
+        let details: checkout::CheckoutWebhookEventIdBody = request
+            .body
+            .parse_struct("CheckoutWebhookEventIdBody")
+            .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+
+        Ok(Some(details.id))
+    }
+
     fn get_webhook_event_type(
         &self,
         request: &webhooks::IncomingWebhookRequestDetails<'_>,
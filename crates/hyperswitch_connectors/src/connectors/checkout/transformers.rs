@@ -2147,6 +2147,11 @@ pub enum CheckoutWebhookEventType {
     Unknown,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct CheckoutWebhookEventIdBody {
+    pub id: String,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct CheckoutWebhookEventTypeBody {
     #[serde(rename = "type")]
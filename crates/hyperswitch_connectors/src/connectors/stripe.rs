@@ -2898,6 +2898,22 @@ impl IncomingWebhook for Stripe {
         }
     }
 
+    fn get_webhook_event_id(
+        &self,
+        request: &IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Option<String>, ConnectorError> {
+        if request.body.is_empty() {
+            return Ok(None);
+        }
+
+        let details: stripe::WebhookEventIdBody = request
+            .body
+            .parse_struct("WebhookEventIdBody")
+            .change_context(ConnectorError::WebhookEventTypeNotFound)?;
+
+        Ok(Some(details.id))
+    }
+
     fn get_webhook_event_type(
         &self,
         request: &IncomingWebhookRequestDetails<'_>,
@@ -25,6 +25,9 @@ pub enum ApplicationResponse<R> {
         response: Box<WebhookResponse<R>>,
         metadata: IncomingWebhookEventMetadata,
     },
+    /// A response recorded for an earlier request with the same idempotency key, sent back as is
+    /// instead of running the flow again.
+    IdempotentReplay(Box<StoredResponse>),
 }
 
 /// A narrowed subset of [`ApplicationResponse`] representing the valid HTTP responses
@@ -50,6 +53,13 @@ impl<R> From<WebhookResponse<R>> for ApplicationResponse<R> {
     }
 }
 
+/// A JSON response along with the HTTP status code it was sent with.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct StoredResponse {
+    pub status_code: u16,
+    pub body: SecretSerdeValue,
+}
+
 #[derive(Debug, PartialEq)]
 pub struct IncomingWebhookEventMetadata {
     pub event_type: ApiEventsType,
@@ -72,6 +82,7 @@ impl<R> ApplicationResponse<R> {
             | Self::PaymentLinkForm(_)
             | Self::FileData(_)
             | Self::GenericLinkForm(_)
+            | Self::IdempotentReplay(_)
             | Self::StatusOk => Err(common_utils::errors::ValidationError::InvalidValue {
                 message: "expected either Json or JsonWithHeaders Response".to_string(),
             }
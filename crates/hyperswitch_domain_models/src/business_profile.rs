@@ -90,6 +90,7 @@ pub struct Profile {
     pub network_tokenization_credentials: OptionalEncryptableValue,
     pub payment_method_blocking: Option<PaymentMethodBlockingConfig>,
     pub default_fallback_routing: Option<pii::SecretSerdeValue>,
+    pub routing_timezone: Option<String>,
 }
 
 #[cfg(feature = "v1")]
@@ -271,6 +272,7 @@ pub struct ProfileSetter {
     pub network_tokenization_credentials: OptionalEncryptableValue,
     pub payment_method_blocking: Option<PaymentMethodBlockingConfig>,
     pub default_fallback_routing: Option<pii::SecretSerdeValue>,
+    pub routing_timezone: Option<String>,
 }
 
 #[cfg(feature = "v1")]
@@ -343,6 +345,7 @@ impl From<ProfileSetter> for Profile {
             network_tokenization_credentials: value.network_tokenization_credentials,
             payment_method_blocking: value.payment_method_blocking,
             default_fallback_routing: value.default_fallback_routing,
+            routing_timezone: value.routing_timezone,
         }
     }
 }
@@ -413,6 +416,7 @@ pub struct ProfileDbBuilder {
     pub network_tokenization_credentials: OptionalEncryptableValue,
     pub payment_method_blocking: Option<PaymentMethodBlockingConfig>,
     pub default_fallback_routing: Option<pii::SecretSerdeValue>,
+    pub routing_timezone: Option<String>,
 }
 
 #[cfg(feature = "v1")]
@@ -485,6 +489,7 @@ impl From<ProfileDbBuilder> for Profile {
             network_tokenization_credentials: value.network_tokenization_credentials,
             payment_method_blocking: value.payment_method_blocking,
             default_fallback_routing: value.default_fallback_routing,
+            routing_timezone: value.routing_timezone,
         }
     }
 }
@@ -499,6 +504,16 @@ impl Profile {
     pub fn get_id(&self) -> &common_utils::id_type::ProfileId {
         &self.id
     }
+
+    #[cfg(feature = "v1")]
+    pub fn get_routing_timezone(&self) -> Option<&str> {
+        self.routing_timezone.as_deref()
+    }
+
+    #[cfg(feature = "v2")]
+    pub fn get_routing_timezone(&self) -> Option<&str> {
+        None
+    }
 }
 
 #[cfg(feature = "v1")]
@@ -559,6 +574,7 @@ pub struct ProfileGeneralUpdate {
     pub surcharge_connector_details: Option<SurchargeConnectorDetails>,
     pub network_tokenization_credentials: OptionalEncryptableValue,
     pub payment_method_blocking: Option<PaymentMethodBlockingConfig>,
+    pub routing_timezone: Option<String>,
 }
 
 #[cfg(feature = "v1")]
@@ -592,6 +608,9 @@ pub enum ProfileUpdate {
     DefaultRoutingFallbackUpdate {
         default_fallback_routing: Option<pii::SecretSerdeValue>,
     },
+    WebhookDetailsUpdate {
+        webhook_details: Option<WebhookDetails>,
+    },
 }
 
 #[cfg(feature = "v2")]
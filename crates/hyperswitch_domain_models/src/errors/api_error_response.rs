@@ -316,6 +316,10 @@ pub enum ApiErrorResponse {
     InvalidBasicAuth,
     #[error(error_type = ErrorType::InvalidRequestError, code = "IR_52", message = "Payment Session has expired")]
     PaymentSessionExpired,
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_53", message = "Idempotency key '{idempotency_key}' was already used with a different request")]
+    IdempotencyKeyReused { idempotency_key: String },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_54", message = "Too many requests, rate limit exceeded")]
+    RateLimitExceeded,
     #[error(error_type = ErrorType::InvalidRequestError, code = "WE_01", message = "Failed to authenticate the webhook")]
     WebhookAuthenticationFailed,
     #[error(error_type = ErrorType::InvalidRequestError, code = "WE_02", message = "Bad request received in webhook")]
@@ -754,6 +758,16 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
                 52,
                 "The provided payment session has expired", None
             )),
+            Self::IdempotencyKeyReused { idempotency_key } => AER::Conflict(ApiError::new(
+                "IR",
+                53,
+                format!("Idempotency key '{idempotency_key}' was already used with a different request"), None
+            )),
+            Self::RateLimitExceeded => AER::TooManyRequests(ApiError::new(
+                "IR",
+                54,
+                "Too many requests, rate limit exceeded", None
+            )),
         }
     }
 }
@@ -37,6 +37,33 @@ pub struct GatewayStatusMappingUpdate {
     pub user_guidance_message: Option<String>,
 }
 
+/// Replaces every field of an existing rule with the fields of the rule
+impl From<GatewayStatusMap> for GatewayStatusMappingUpdate {
+    fn from(rule: GatewayStatusMap) -> Self {
+        let retry_feature_data = rule.feature_data.get_retry_feature_data();
+
+        Self {
+            status: Some(rule.status),
+            router_error: Some(rule.router_error),
+            decision: Some(rule.feature_data.get_decision()),
+            step_up_possible: retry_feature_data
+                .as_ref()
+                .map(|retry_feature_data| retry_feature_data.is_step_up_possible()),
+            unified_code: rule.unified_code,
+            unified_message: rule.unified_message,
+            error_category: rule.error_category,
+            clear_pan_possible: retry_feature_data
+                .as_ref()
+                .map(|retry_feature_data| retry_feature_data.is_clear_pan_possible()),
+            feature_data: Some(rule.feature_data),
+            feature: Some(rule.feature),
+            standardised_code: rule.standardised_code,
+            description: rule.description,
+            user_guidance_message: rule.user_guidance_message,
+        }
+    }
+}
+
 impl TryFrom<GatewayStatusMap> for diesel_models::gsm::GatewayStatusMappingNew {
     type Error = error_stack::Report<ValidationError>;
 
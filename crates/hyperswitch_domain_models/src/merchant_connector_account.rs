@@ -1,6 +1,8 @@
 #[cfg(feature = "v2")]
 use std::collections::HashMap;
 
+#[cfg(feature = "v1")]
+use common_types::connector_fee_schedule::ConnectorFeeSchedule;
 use common_utils::{
     crypto::Encryptable,
     date_time,
@@ -69,6 +71,7 @@ pub struct MerchantConnectorAccount {
     pub additional_merchant_data: Option<Encryptable<Secret<Value>>>,
     pub version: common_enums::ApiVersion,
     pub connector_webhook_registration_details: Option<Value>,
+    pub fee_schedule: Option<ConnectorFeeSchedule>,
 }
 
 #[cfg(feature = "v1")]
@@ -129,6 +132,10 @@ impl MerchantConnectorAccount {
     pub fn get_connector_webhook_registration_details(&self) -> Option<Value> {
         self.connector_webhook_registration_details.clone()
     }
+
+    pub fn get_fee_schedule(&self) -> Option<&ConnectorFeeSchedule> {
+        self.fee_schedule.as_ref()
+    }
 }
 
 #[cfg(feature = "v2")]
@@ -497,6 +504,7 @@ pub enum MerchantConnectorAccountUpdate {
         status: Option<enums::ConnectorStatus>,
         connector_wallets_details: Box<Option<Encryptable<pii::SecretSerdeValue>>>,
         additional_merchant_data: Box<Option<Encryptable<pii::SecretSerdeValue>>>,
+        fee_schedule: Box<Option<ConnectorFeeSchedule>>,
     },
     ConnectorWalletDetailsUpdate {
         connector_wallets_details: Encryptable<pii::SecretSerdeValue>,
@@ -567,6 +575,7 @@ impl behaviour::Conversion for MerchantConnectorAccount {
             additional_merchant_data: self.additional_merchant_data.map(|data| data.into()),
             version: self.version,
             connector_webhook_registration_details: self.connector_webhook_registration_details,
+            fee_schedule: self.fee_schedule,
         })
     }
 
@@ -632,6 +641,7 @@ impl behaviour::Conversion for MerchantConnectorAccount {
             additional_merchant_data: decrypted_data.additional_merchant_data,
             version: other.version,
             connector_webhook_registration_details: other.connector_webhook_registration_details,
+            fee_schedule: other.fee_schedule,
         })
     }
 
@@ -664,6 +674,7 @@ impl behaviour::Conversion for MerchantConnectorAccount {
             connector_wallets_details: self.connector_wallets_details.map(Encryption::from),
             additional_merchant_data: self.additional_merchant_data.map(|data| data.into()),
             version: self.version,
+            fee_schedule: self.fee_schedule,
         })
     }
 }
@@ -808,6 +819,7 @@ impl From<MerchantConnectorAccountUpdate> for MerchantConnectorAccountUpdateInte
                 status,
                 connector_wallets_details,
                 additional_merchant_data,
+                fee_schedule,
             } => Self {
                 connector_type,
                 connector_name,
@@ -828,6 +840,7 @@ impl From<MerchantConnectorAccountUpdate> for MerchantConnectorAccountUpdateInte
                 connector_wallets_details: connector_wallets_details.map(Encryption::from),
                 additional_merchant_data: additional_merchant_data.map(Encryption::from),
                 connector_webhook_registration_details: None,
+                fee_schedule: *fee_schedule,
             },
             MerchantConnectorAccountUpdate::ConnectorWalletDetailsUpdate {
                 connector_wallets_details,
@@ -851,6 +864,7 @@ impl From<MerchantConnectorAccountUpdate> for MerchantConnectorAccountUpdateInte
                 status: None,
                 additional_merchant_data: None,
                 connector_webhook_registration_details: None,
+                fee_schedule: None,
             },
             MerchantConnectorAccountUpdate::ConnectorWebhookRegisterationUpdate {
                 connector_webhook_registration_details,
@@ -876,6 +890,7 @@ impl From<MerchantConnectorAccountUpdate> for MerchantConnectorAccountUpdateInte
                 connector_wallets_details: None,
                 additional_merchant_data: None,
                 connector_webhook_registration_details,
+                fee_schedule: None,
             },
         }
     }
@@ -1020,6 +1035,7 @@ pub struct MerchantConnectorAccountWithoutEncrypted {
     pub status: enums::ConnectorStatus,
     pub version: common_enums::ApiVersion,
     pub connector_webhook_registration_details: Option<Value>,
+    pub fee_schedule: Option<ConnectorFeeSchedule>,
 }
 
 #[cfg(feature = "v1")]
@@ -1054,6 +1070,7 @@ impl TryFrom<storage::MerchantConnectorAccount> for MerchantConnectorAccountWith
             status: other.status,
             version: other.version,
             connector_webhook_registration_details: other.connector_webhook_registration_details,
+            fee_schedule: other.fee_schedule,
         })
     }
 }
@@ -1071,6 +1088,10 @@ impl MerchantConnectorAccountWithoutEncrypted {
     pub fn get_metadata(&self) -> Option<pii::SecretSerdeValue> {
         self.metadata.clone()
     }
+
+    pub fn get_fee_schedule(&self) -> Option<&ConnectorFeeSchedule> {
+        self.fee_schedule.as_ref()
+    }
 }
 
 /// A lightweight view of [`MerchantConnectorAccount`] containing only the
@@ -1423,6 +1423,9 @@ pub struct PaymentAttempt {
     pub created_by: Option<CreatedBy>,
     pub setup_future_usage_applied: Option<storage_enums::FutureUsage>,
     pub routing_approach: Option<storage_enums::RoutingApproach>,
+    pub routing_algorithm_id: Option<id_type::RoutingId>,
+    pub expected_processing_fee: Option<MinorUnit>,
+    pub expected_authorization_expiry: Option<PrimitiveDateTime>,
     pub connector_request_reference_id: Option<String>,
     pub debit_routing_savings: Option<MinorUnit>,
     pub network_transaction_id: Option<String>,
@@ -1444,6 +1447,7 @@ pub struct PaymentAttempt {
     pub external_surcharge_details: Option<common_types::payments::ExternalSurchargeDetails>,
     /// Normalized applied-offer details from Offer Engine
     pub applied_offer_details: Option<common_types::payments::AppliedOfferDetails>,
+    pub dcc_details: Option<common_types::payments::DccDetails>,
     /// Sender payment instrument ID
     pub sender_payment_instrument_id: Option<String>,
 }
@@ -1988,6 +1992,8 @@ pub enum PaymentAttemptUpdate {
         updated_by: String,
         merchant_connector_id: Option<id_type::MerchantConnectorAccountId>,
         routing_approach: Option<storage_enums::RoutingApproach>,
+        routing_algorithm_id: Option<id_type::RoutingId>,
+        expected_processing_fee: Option<MinorUnit>,
         is_stored_credential: Option<bool>,
     },
     AuthenticationTypeUpdate {
@@ -2028,6 +2034,8 @@ pub enum PaymentAttemptUpdate {
         tokenization: Option<common_enums::Tokenization>,
         card_discovery: Option<common_enums::CardDiscovery>,
         routing_approach: Option<storage_enums::RoutingApproach>,
+        routing_algorithm_id: Option<id_type::RoutingId>,
+        expected_processing_fee: Option<MinorUnit>,
         connector_request_reference_id: Option<String>,
         network_transaction_id: Option<String>,
         network_transaction_link_id: Option<String>,
@@ -2035,6 +2043,7 @@ pub enum PaymentAttemptUpdate {
         request_extended_authorization: Option<RequestExtendedAuthorizationBool>,
         external_surcharge_details: Option<common_types::payments::ExternalSurchargeDetails>,
         applied_offer_details: Option<common_types::payments::AppliedOfferDetails>,
+        dcc_details: Option<common_types::payments::DccDetails>,
     },
     RejectUpdate {
         status: storage_enums::AttemptStatus,
@@ -2214,6 +2223,10 @@ pub enum PaymentAttemptUpdate {
         external_surcharge_details: common_types::payments::ExternalSurchargeDetails,
         updated_by: String,
     },
+    AuthorizationExpiryUpdate {
+        expected_authorization_expiry: PrimitiveDateTime,
+        updated_by: String,
+    },
 }
 
 #[cfg(feature = "v1")]
@@ -2273,6 +2286,8 @@ impl PaymentAttemptUpdate {
                 tax_amount,
                 merchant_connector_id,
                 routing_approach,
+                routing_algorithm_id,
+                expected_processing_fee,
                 is_stored_credential,
             } => DieselPaymentAttemptUpdate::UpdateTrackers {
                 payment_token,
@@ -2291,6 +2306,8 @@ impl PaymentAttemptUpdate {
                     }
                     _ => approach,
                 }),
+                routing_algorithm_id,
+                expected_processing_fee,
                 is_stored_credential,
             },
             Self::AuthenticationTypeUpdate {
@@ -2361,6 +2378,8 @@ impl PaymentAttemptUpdate {
                 tokenization,
                 card_discovery,
                 routing_approach,
+                routing_algorithm_id,
+                expected_processing_fee,
                 connector_request_reference_id,
                 network_transaction_id,
                 network_transaction_link_id,
@@ -2368,6 +2387,7 @@ impl PaymentAttemptUpdate {
                 request_extended_authorization,
                 external_surcharge_details,
                 applied_offer_details,
+                dcc_details,
             } => DieselPaymentAttemptUpdate::ConfirmUpdate {
                 amount: net_amount.get_order_amount(),
                 currency,
@@ -2413,6 +2433,8 @@ impl PaymentAttemptUpdate {
                     }
                     _ => approach,
                 }),
+                routing_algorithm_id,
+                expected_processing_fee,
                 connector_request_reference_id,
                 network_transaction_id,
                 network_transaction_link_id,
@@ -2420,6 +2442,7 @@ impl PaymentAttemptUpdate {
                 request_extended_authorization,
                 external_surcharge_details,
                 applied_offer_details,
+                dcc_details,
             },
             Self::VoidUpdate {
                 status,
@@ -2806,6 +2829,13 @@ impl PaymentAttemptUpdate {
                 external_surcharge_details,
                 updated_by,
             },
+            Self::AuthorizationExpiryUpdate {
+                expected_authorization_expiry,
+                updated_by,
+            } => DieselPaymentAttemptUpdate::AuthorizationExpiryUpdate {
+                expected_authorization_expiry,
+                updated_by,
+            },
         }
     }
 
@@ -2836,7 +2866,8 @@ impl PaymentAttemptUpdate {
             | Self::ManualUpdate { .. }
             | Self::PostSessionTokensUpdate { .. }
             | Self::RecurrenceUpdate { .. }
-            | Self::ExternalSurchargeUpdate { .. } => None,
+            | Self::ExternalSurchargeUpdate { .. }
+            | Self::AuthorizationExpiryUpdate { .. } => None,
         }
     }
 }
@@ -3035,6 +3066,9 @@ impl behaviour::Conversion for PaymentAttempt {
             processor_merchant_id: Some(self.processor_merchant_id),
             created_by: self.created_by.map(|created_by| created_by.to_string()),
             routing_approach: self.routing_approach,
+            routing_algorithm_id: self.routing_algorithm_id,
+            expected_processing_fee: self.expected_processing_fee,
+            expected_authorization_expiry: self.expected_authorization_expiry,
             connector_request_reference_id: self.connector_request_reference_id,
             network_transaction_id: self.network_transaction_id,
             network_transaction_link_id: self.network_transaction_link_id,
@@ -3046,6 +3080,7 @@ impl behaviour::Conversion for PaymentAttempt {
             retry_type: self.retry_type,
             external_surcharge_details: self.external_surcharge_details,
             applied_offer_details: self.applied_offer_details,
+            dcc_details: self.dcc_details,
             sender_payment_instrument_id: self.sender_payment_instrument_id,
         })
     }
@@ -3175,6 +3210,9 @@ impl behaviour::Conversion for PaymentAttempt {
                     .and_then(|created_by| created_by.parse::<CreatedBy>().ok()),
                 setup_future_usage_applied: storage_model.setup_future_usage_applied,
                 routing_approach: storage_model.routing_approach,
+                routing_algorithm_id: storage_model.routing_algorithm_id,
+                expected_processing_fee: storage_model.expected_processing_fee,
+                expected_authorization_expiry: storage_model.expected_authorization_expiry,
                 connector_request_reference_id: storage_model.connector_request_reference_id,
                 debit_routing_savings: None,
                 network_transaction_id: storage_model.network_transaction_id,
@@ -3189,6 +3227,7 @@ impl behaviour::Conversion for PaymentAttempt {
                 installment_data: storage_model.installment_data,
                 external_surcharge_details: storage_model.external_surcharge_details,
                 applied_offer_details: storage_model.applied_offer_details,
+                dcc_details: storage_model.dcc_details,
                 sender_payment_instrument_id: storage_model.sender_payment_instrument_id,
             })
         }
@@ -3282,6 +3321,9 @@ impl behaviour::Conversion for PaymentAttempt {
             created_by: self.created_by.map(|created_by| created_by.to_string()),
             setup_future_usage_applied: self.setup_future_usage_applied,
             routing_approach: self.routing_approach,
+            routing_algorithm_id: self.routing_algorithm_id,
+            expected_processing_fee: self.expected_processing_fee,
+            expected_authorization_expiry: self.expected_authorization_expiry,
             connector_request_reference_id: self.connector_request_reference_id,
             network_transaction_id: self.network_transaction_id,
             network_transaction_link_id: self.network_transaction_link_id,
@@ -3294,6 +3336,7 @@ impl behaviour::Conversion for PaymentAttempt {
             installment_data: self.installment_data,
             external_surcharge_details: self.external_surcharge_details,
             applied_offer_details: self.applied_offer_details,
+            dcc_details: self.dcc_details,
             sender_payment_instrument_id: self.sender_payment_instrument_id,
         })
     }
@@ -433,6 +433,7 @@ pub enum PaymentIntentUpdate {
     },
     SplitPaymentStatusUpdate {
         status: common_enums::IntentStatus,
+        amount_captured: Option<MinorUnit>,
         updated_by: String,
     },
 }
@@ -1013,9 +1014,13 @@ impl TryFrom<PaymentIntentUpdate> for diesel_models::PaymentIntentUpdateInternal
                 external_surcharge_strategy: None,
                 external_surcharge_applicable: None,
             }),
-            PaymentIntentUpdate::SplitPaymentStatusUpdate { status, updated_by } => Ok(Self {
+            PaymentIntentUpdate::SplitPaymentStatusUpdate {
+                status,
+                amount_captured,
+                updated_by,
+            } => Ok(Self {
                 status: Some(status),
-                amount_captured: None,
+                amount_captured,
                 active_attempt_id: None,
                 active_attempt_id_type: None,
                 active_attempts_group_id: None,
@@ -18,3 +18,15 @@ pub struct PaymentMethodDetailsWithSplitAmount {
     pub payment_method_details: PaymentMethodDetails,
     pub split_amount: MinorUnit,
 }
+
+impl PaymentMethodAmountSplit {
+    /// Returns the funding legs in the order in which they are charged: the balance payment
+    /// methods in the order they were provided, followed by the payment method covering the
+    /// remaining amount
+    pub fn into_funding_legs(self) -> Vec<PaymentMethodDetailsWithSplitAmount> {
+        self.balance_pm_split
+            .into_iter()
+            .chain(self.non_balance_pm_split)
+            .collect()
+    }
+}
@@ -1,6 +1,10 @@
 //! Webhooks interface
 
-use common_utils::{crypto, errors::CustomResult, ext_traits::ValueExt};
+use common_utils::{
+    crypto::{self, GenerateDigest},
+    errors::CustomResult,
+    ext_traits::ValueExt,
+};
 use error_stack::ResultExt;
 use hyperswitch_domain_models::{
     api::WebhookResponse, errors::api_error_response::ApiErrorResponse,
@@ -326,6 +330,25 @@ pub trait IncomingWebhook: ConnectorCommon + Sync {
         _context: Option<&WebhookContext>,
     ) -> CustomResult<api_models::webhooks::IncomingWebhookEvent, errors::ConnectorError>;
 
+    /// fn get_webhook_event_id
+    /// Returns the identifier the connector assigns to the event, which stays the same when the
+    /// event is delivered again. Used to skip duplicate deliveries. Connectors which don't assign
+    /// one fall back to a digest of the request body, since a delivery is sent again unchanged.
+    fn get_webhook_event_id(
+        &self,
+        request: &IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        if request.body.is_empty() {
+            return Ok(None);
+        }
+
+        let digest = crypto::Sha256
+            .generate_digest(request.body)
+            .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)?;
+
+        Ok(Some(hex::encode(digest)))
+    }
+
     /// fn get_webhook_resource_object
     fn get_webhook_resource_object(
         &self,
@@ -85,6 +85,7 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::payments::payments_extend_authorization,
         routes::payments::payments_list,
         routes::payments::payments_incremental_authorization,
+        routes::payments::payments_dcc_quote,
         routes::payment_link::payment_link_retrieve,
         routes::payments::payments_external_authentication,
         routes::payments::payments_complete_authorize,
@@ -130,6 +131,9 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::gsm::get_gsm_rule,
         routes::gsm::update_gsm_rule,
         routes::gsm::delete_gsm_rule,
+        routes::gsm::import_gsm_rules,
+        routes::gsm::export_gsm_rules,
+        routes::gsm::retrieve_unmapped_errors,
 
         // Routes for mandates
         routes::mandates::get_mandate,
@@ -182,6 +186,7 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::routing::routing_retrieve_config,
         routes::routing::list_routing_configs,
         routes::routing::routing_unlink_config,
+        routes::routing::routing_update_canary,
         routes::routing::routing_update_default_config,
         routes::routing::routing_retrieve_default_config,
         routes::routing::routing_retrieve_linked_config,
@@ -230,6 +235,19 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::webhook_events::list_initial_webhook_delivery_attempts_with_jwtauth,
         routes::webhook_events::list_webhook_delivery_attempts,
         routes::webhook_events::retry_webhook_delivery_attempt,
+        routes::webhook_events::create_webhook_bulk_redelivery,
+        routes::webhook_events::retrieve_webhook_bulk_redelivery,
+        routes::webhook_events::list_incoming_webhooks,
+        routes::webhook_events::reprocess_incoming_webhook,
+
+        // Routes for webhook endpoints
+        routes::webhook_endpoints::webhook_endpoint_create,
+        routes::webhook_endpoints::webhook_endpoint_list,
+        routes::webhook_endpoints::webhook_endpoint_retrieve,
+        routes::webhook_endpoints::webhook_endpoint_update,
+        routes::webhook_endpoints::webhook_endpoint_rotate_secret,
+        routes::webhook_endpoints::webhook_endpoint_health,
+        routes::webhook_endpoints::webhook_endpoint_delete,
 
         // Routes for poll apis
         routes::poll::retrieve_poll_status,
@@ -271,6 +289,11 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::card_issuer::update_card_issuer,
         routes::card_issuer::delete_card_issuer,
         routes::card_issuer::list_card_issuers,
+
+        // Routes for rate limits
+        routes::rate_limit::upsert_rate_limit_config,
+        routes::rate_limit::retrieve_rate_limit_config,
+        routes::rate_limit::delete_rate_limit_config,
     ),
     components(schemas(
         common_utils::types::MinorUnit,
@@ -297,8 +320,12 @@ Never share your secret api keys. Keep them guarded and secure.
         common_utils::payout_method_utils::GooglePayDecryptAdditionalData,
         common_utils::payout_method_utils::PassthroughAdditionalData,
         common_types::payments::SplitPaymentsRequest,
+        common_types::payments::DccDetails,
         common_types::connector_webhook_configuration::WebhookSetupCapabilities,
         common_types::connector_webhook_configuration::WebhookConfigType,
+        common_types::connector_fee_schedule::ConnectorFeeSchedule,
+        common_types::connector_fee_schedule::FeeScheduleRule,
+        common_types::connector_fee_schedule::ConnectorFee,
         common_types::payments::GpayTokenizationData,
         common_types::payments::GPayPredecryptData,
         common_types::payments::GpayEcryptedTokenizationData,
@@ -556,12 +583,29 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::card_issuer::CardIssuerDeleteResponse,
         api_models::card_issuer::CardIssuerListQuery,
         api_models::card_issuer::CardIssuerListResponse,
+        api_models::rate_limit::RateLimitRule,
+        api_models::rate_limit::RateLimitQuota,
+        api_models::rate_limit::QuotaPeriod,
+        api_models::rate_limit::RateLimitConfigRequest,
+        api_models::rate_limit::RateLimitConfigResponse,
+        api_models::rate_limit::RateLimitConfigDeleteResponse,
         api_models::gsm::GsmCreateRequest,
         api_models::gsm::GsmRetrieveRequest,
         api_models::gsm::GsmUpdateRequest,
         api_models::gsm::GsmDeleteRequest,
         api_models::gsm::GsmDeleteResponse,
         api_models::gsm::GsmResponse,
+        api_models::gsm::GsmBulkRecord,
+        api_models::gsm::GsmBulkImportRequest,
+        api_models::gsm::GsmBulkImportAction,
+        api_models::gsm::GsmBulkImportRowResult,
+        api_models::gsm::GsmBulkImportResponse,
+        api_models::gsm::GsmExportFormat,
+        api_models::gsm::GsmExportRequest,
+        api_models::gsm::GsmExportResponse,
+        api_models::gsm::GsmUnmappedErrorsRequest,
+        api_models::gsm::GsmUnmappedError,
+        api_models::gsm::GsmUnmappedErrorsResponse,
         api_models::enums::GsmDecision,
         api_models::enums::GsmFeature,
         api_models::enums::StandardisedCode,
@@ -665,6 +709,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::PaymentRetrieveBody,
         api_models::payments::PaymentsRetrieveRequest,
         api_models::payments::PaymentsCaptureRequest,
+        api_models::payments::CaptureSchedule,
         api_models::payments::PaymentsSessionRequest,
         api_models::payments::PaymentsSessionResponse,
         api_models::payments::PazeWalletData,
@@ -785,6 +830,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::PaymentAttemptResponse,
         api_models::payments::CaptureResponse,
         api_models::payments::PaymentsIncrementalAuthorizationRequest,
+        api_models::payments::PaymentsDccQuoteRequest,
+        api_models::payments::PaymentsDccQuoteResponse,
         api_models::payments::IncrementalAuthorizationResponse,
         api_models::payments::PaymentsCompleteAuthorizeRequest,
         api_models::payments::PaymentsExternalAuthenticationRequest,
@@ -942,6 +989,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::api_keys::CreateApiKeyResponse,
         api_models::api_keys::RetrieveApiKeyResponse,
         api_models::api_keys::RevokeApiKeyResponse,
+        common_types::api_keys::ApiKeyPermission,
         api_models::api_keys::UpdateApiKeyRequest,
         api_models::payments::RetrievePaymentLinkRequest,
         api_models::payments::PaymentLinkResponse,
@@ -952,6 +1000,12 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::GooglePayAssuranceDetails,
         api_models::routing::RoutingConfigRequest,
         api_models::routing::RoutingDictionaryRecord,
+        api_models::routing::RoutingAnalysisWarning,
+        api_models::routing::RoutingAnalysisWarningKind,
+        api_models::routing::RoutingCanary,
+        api_models::routing::RoutingCanaryAction,
+        api_models::routing::RoutingCanaryRequest,
+        api_models::routing::RoutingCanaryResponse,
         api_models::routing::RoutingKind,
         api_models::routing::RoutableConnectorChoice,
         api_models::routing::DynamicRoutingFeatures,
@@ -977,6 +1031,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::routing::StaticRoutingAlgorithm,
         api_models::routing::StraightThroughAlgorithm,
         api_models::routing::ConnectorVolumeSplit,
+        api_models::routing::CostBasedRoutingConfig,
+        api_models::routing::ConnectorSuccessRate,
         api_models::routing::ConnectorSelection,
         api_models::routing::SuccessRateSpecificityLevel,
         api_models::routing::ToggleDynamicRoutingQuery,
@@ -1014,6 +1070,22 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::webhook_events::OutgoingWebhookRequestContent,
         api_models::webhook_events::OutgoingWebhookResponseContent,
         api_models::webhook_events::TotalEventsResponse,
+        api_models::webhook_events::EventsBulkRedeliveryRequest,
+        api_models::webhook_events::EventsBulkRedeliveryResponse,
+        api_models::webhook_events::EventsBulkRedeliveryStatus,
+        api_models::webhook_events::IncomingWebhookListConstraints,
+        api_models::webhook_events::IncomingWebhookResponse,
+        api_models::webhook_events::TotalIncomingWebhooksResponse,
+        api_models::enums::IncomingWebhookStatus,
+        api_models::enums::IncomingWebhookRejectionReason,
+        api_models::webhook_endpoints::WebhookEndpointCreateRequest,
+        api_models::webhook_endpoints::WebhookEndpointUpdateRequest,
+        api_models::webhook_endpoints::WebhookEndpointSecretRotateRequest,
+        api_models::webhook_endpoints::WebhookEndpointResponse,
+        api_models::webhook_endpoints::WebhookEndpointDeleteResponse,
+        api_models::webhook_endpoints::WebhookEndpointHealthResponse,
+        api_models::enums::OutgoingWebhookEndpointStatus,
+        api_models::enums::OutgoingWebhookSigningVersion,
         api_models::enums::WebhookDeliveryAttempt,
         api_models::enums::PaymentChargeType,
         api_models::enums::StripeChargeType,
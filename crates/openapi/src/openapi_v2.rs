@@ -838,6 +838,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::api_keys::CreateApiKeyResponse,
         api_models::api_keys::RetrieveApiKeyResponse,
         api_models::api_keys::RevokeApiKeyResponse,
+        common_types::api_keys::ApiKeyPermission,
         api_models::api_keys::UpdateApiKeyRequest,
         api_models::payments::RetrievePaymentLinkRequest,
         api_models::payments::PaymentLinkResponse,
@@ -848,6 +849,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::GooglePayAssuranceDetails,
         api_models::routing::RoutingConfigRequest,
         api_models::routing::RoutingDictionaryRecord,
+        api_models::routing::RoutingAnalysisWarning,
+        api_models::routing::RoutingAnalysisWarningKind,
         api_models::routing::RoutingKind,
         api_models::routing::RoutableConnectorChoice,
         api_models::routing::LinkedRoutingConfigRetrieveResponse,
@@ -877,6 +880,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::routing::StaticRoutingAlgorithm,
         api_models::routing::StraightThroughAlgorithm,
         api_models::routing::ConnectorVolumeSplit,
+        api_models::routing::CostBasedRoutingConfig,
+        api_models::routing::ConnectorSuccessRate,
         api_models::routing::ConnectorSelection,
         api_models::routing::ast::RoutableChoiceKind,
         api_models::routing::ProgramThreeDsDecisionRule,
@@ -20,6 +20,7 @@ pub mod poll;
 pub mod profile;
 pub mod profile_acquirer;
 pub mod proxy;
+pub mod rate_limit;
 pub mod refunds;
 pub mod relay;
 pub mod revenue_recovery;
@@ -27,4 +28,5 @@ pub mod routing;
 pub mod subscriptions;
 pub mod three_ds_decision_rule;
 pub mod tokenization;
+pub mod webhook_endpoints;
 pub mod webhook_events;
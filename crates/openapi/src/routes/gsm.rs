@@ -73,3 +73,57 @@ pub async fn update_gsm_rule() {}
     security(("admin_api_key" = [])),
 )]
 pub async fn delete_gsm_rule() {}
+
+/// Gsm - Import
+///
+/// Creates or updates GSM Rules in bulk. Records are matched with existing rules on connector, flow, sub_flow, code and message. All records are validated first, and no changes are applied if any record is invalid or if `dry_run` is set. The response lists the change made for every record.
+#[utoipa::path(
+    post,
+    path = "/gsm/import",
+    request_body(
+        content = GsmBulkImportRequest,
+    ),
+    responses(
+        (status = 200, description = "Gsm rules imported", body = GsmBulkImportResponse),
+        (status = 400, description = "Missing Mandatory fields")
+    ),
+    tag = "Gsm",
+    operation_id = "Import Gsm Rules",
+    security(("admin_api_key" = [])),
+)]
+pub async fn import_gsm_rules() {}
+
+/// Gsm - Export
+///
+/// Exports GSM Rules as JSON or CSV, in the format accepted by the import
+#[utoipa::path(
+    get,
+    path = "/gsm/export",
+    params(GsmExportRequest),
+    responses(
+        (status = 200, description = "Gsm rules exported", body = GsmExportResponse),
+    ),
+    tag = "Gsm",
+    operation_id = "Export Gsm Rules",
+    security(("admin_api_key" = [])),
+)]
+pub async fn export_gsm_rules() {}
+
+/// Gsm - Unmapped Errors
+///
+/// Lists the connector error code and message combinations seen in payment attempts over the lookback window for which no GSM Rule exists in the flow, most frequent first
+#[utoipa::path(
+    post,
+    path = "/gsm/unmapped_errors",
+    request_body(
+        content = GsmUnmappedErrorsRequest,
+    ),
+    responses(
+        (status = 200, description = "Unmapped errors retrieved", body = GsmUnmappedErrorsResponse),
+        (status = 400, description = "Missing Mandatory fields")
+    ),
+    tag = "Gsm",
+    operation_id = "Retrieve Unmapped Gsm Errors",
+    security(("admin_api_key" = [])),
+)]
+pub async fn retrieve_unmapped_errors() {}
@@ -940,6 +940,26 @@ pub async fn profile_payments_list() {}
 )]
 pub fn payments_incremental_authorization() {}
 
+/// Payments - Currency Conversion Quote
+///
+/// Quotes the amount of a payment in the currency of the customer, at an exchange rate that is locked for the validity of the quote. The payment is charged in that currency when it is confirmed with the `dcc_quote_id`
+#[utoipa::path(
+  post,
+  path = "/payments/{payment_id}/dcc_quote",
+  request_body=PaymentsDccQuoteRequest,
+  params(
+      ("payment_id" = String, Path, description = "The identifier for payment")
+  ),
+  responses(
+      (status = 200, description = "Currency conversion quote created", body = PaymentsDccQuoteResponse),
+      (status = 400, description = "Missing mandatory fields", body = GenericErrorResponseOpenApi)
+  ),
+  tag = "Payments",
+  operation_id = "Create a currency conversion quote for a Payment",
+  security(("api_key" = []))
+)]
+pub fn payments_dcc_quote() {}
+
 /// Payments - Extended Authorization
 ///
 /// Extended authorization is available for payments currently in the `requires_capture` status
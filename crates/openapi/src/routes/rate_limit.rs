@@ -0,0 +1,83 @@
+/// Rate Limits - Upsert
+///
+/// Replace the rate limit rules of the Merchant Account. Requests exceeding the limits are
+/// rejected with status 429 and a `Retry-After` header.
+#[utoipa::path(
+    post,
+    path = "/accounts/{account_id}/rate_limits",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+    ),
+    request_body(
+        content = RateLimitConfigRequest,
+        examples(
+            ("Limit the merchant and one of its API keys" = (
+                value = json!({
+                    "rules": [
+                        {
+                            "requests_per_second": 100,
+                            "burst": 200,
+                            "quota": {
+                                "limit": 1000000,
+                                "period": "day"
+                            }
+                        },
+                        {
+                            "key_id": "dev_2ojpvnBE4CeT3dhZwsjB",
+                            "route_group": "payments",
+                            "requests_per_second": 10,
+                            "burst": 20
+                        }
+                    ]
+                })
+            ))
+        )
+    ),
+    responses(
+        (status = 200, description = "Rate limit rules updated", body = RateLimitConfigResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Merchant account not found"),
+    ),
+    tag = "Merchant Account",
+    operation_id = "Upsert Rate Limits for a Merchant Account",
+    security(("admin_api_key" = [])),
+)]
+pub async fn upsert_rate_limit_config() {}
+
+/// Rate Limits - Retrieve
+///
+/// Retrieve the rate limit rules of the Merchant Account
+#[utoipa::path(
+    get,
+    path = "/accounts/{account_id}/rate_limits",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+    ),
+    responses(
+        (status = 200, description = "Rate limit rules retrieved", body = RateLimitConfigResponse),
+        (status = 404, description = "Rate limit rules not found"),
+    ),
+    tag = "Merchant Account",
+    operation_id = "Retrieve Rate Limits for a Merchant Account",
+    security(("admin_api_key" = [])),
+)]
+pub async fn retrieve_rate_limit_config() {}
+
+/// Rate Limits - Delete
+///
+/// Delete the rate limit rules of the Merchant Account
+#[utoipa::path(
+    delete,
+    path = "/accounts/{account_id}/rate_limits",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+    ),
+    responses(
+        (status = 200, description = "Rate limit rules deleted", body = RateLimitConfigDeleteResponse),
+        (status = 404, description = "Rate limit rules not found"),
+    ),
+    tag = "Merchant Account",
+    operation_id = "Delete Rate Limits for a Merchant Account",
+    security(("admin_api_key" = [])),
+)]
+pub async fn delete_rate_limit_config() {}
@@ -152,6 +152,27 @@ pub async fn list_routing_configs() {}
 )]
 pub async fn routing_unlink_config() {}
 
+#[cfg(feature = "v1")]
+/// Routing - Update Canary
+///
+/// Ramps, promotes or rolls back the canary routing config of a profile
+#[utoipa::path(
+    post,
+    path = "/routing/canary",
+    request_body = RoutingCanaryRequest,
+    responses(
+        (status = 200, description = "Successfully updated the canary routing config", body = RoutingCanaryResponse),
+        (status = 500, description = "Internal server error"),
+        (status = 400, description = "Malformed request"),
+        (status = 403, description = "Malformed request"),
+        (status = 422, description = "Unprocessable request")
+    ),
+   tag = "Routing",
+   operation_id = "Update the canary routing config",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_update_canary() {}
+
 #[cfg(feature = "v1")]
 /// Routing - Update Default Config
 ///
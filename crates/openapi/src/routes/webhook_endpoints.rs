@@ -0,0 +1,185 @@
+/// Webhook Endpoints - Create
+///
+/// Create an outgoing webhook endpoint for the *profile*. Webhooks for the events the endpoint is
+/// subscribed to are delivered to it, in addition to the webhook URL configured on the profile.
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_endpoints",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    request_body(
+        content = WebhookEndpointCreateRequest,
+        examples(
+            (
+                "Create a webhook endpoint for payment events" = (
+                    value = json!({
+                        "webhook_url": "https://www.example.com/webhooks",
+                        "enabled_events": ["payment_succeeded", "payment_failed"]
+                    })
+                )
+            )
+    )),
+    responses(
+        (status = 200, description = "Webhook endpoint created", body = WebhookEndpointResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Profile not found")
+    ),
+    tag = "Profile",
+    operation_id = "Create a Webhook Endpoint",
+    security(("api_key" = []))
+)]
+pub async fn webhook_endpoint_create() {}
+
+/// Webhook Endpoints - List
+///
+/// List the outgoing webhook endpoints of the *profile*
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_endpoints",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    responses(
+        (status = 200, description = "Webhook endpoints retrieved", body = Vec<WebhookEndpointResponse>),
+        (status = 404, description = "Profile not found")
+    ),
+    tag = "Profile",
+    operation_id = "List Webhook Endpoints",
+    security(("api_key" = []))
+)]
+pub async fn webhook_endpoint_list() {}
+
+/// Webhook Endpoints - Retrieve
+///
+/// Retrieve an outgoing webhook endpoint of the *profile*
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_endpoints/{webhook_endpoint_id}",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile"),
+        ("webhook_endpoint_id" = String, Path, description = "The unique identifier for the webhook endpoint")
+    ),
+    responses(
+        (status = 200, description = "Webhook endpoint retrieved", body = WebhookEndpointResponse),
+        (status = 404, description = "Webhook endpoint not found")
+    ),
+    tag = "Profile",
+    operation_id = "Retrieve a Webhook Endpoint",
+    security(("api_key" = []))
+)]
+pub async fn webhook_endpoint_retrieve() {}
+
+/// Webhook Endpoints - Update
+///
+/// Update an outgoing webhook endpoint of the *profile*
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_endpoints/{webhook_endpoint_id}",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile"),
+        ("webhook_endpoint_id" = String, Path, description = "The unique identifier for the webhook endpoint")
+    ),
+    request_body(
+        content = WebhookEndpointUpdateRequest,
+        examples(
+            (
+                "Disable a webhook endpoint" = (
+                    value = json!({
+                        "status": "inactive"
+                    })
+                )
+            )
+    )),
+    responses(
+        (status = 200, description = "Webhook endpoint updated", body = WebhookEndpointResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Webhook endpoint not found")
+    ),
+    tag = "Profile",
+    operation_id = "Update a Webhook Endpoint",
+    security(("api_key" = []))
+)]
+pub async fn webhook_endpoint_update() {}
+
+/// Webhook Endpoints - Rotate Secret
+///
+/// Rotate the secret of an outgoing webhook endpoint signed with `standard_webhooks`. Webhooks are
+/// also signed with the current secret until it expires
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_endpoints/{webhook_endpoint_id}/rotate_secret",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile"),
+        ("webhook_endpoint_id" = String, Path, description = "The unique identifier for the webhook endpoint")
+    ),
+    request_body(
+        content = WebhookEndpointSecretRotateRequest,
+        examples(
+            (
+                "Rotate the secret, keeping the current secret for an hour" = (
+                    value = json!({
+                        "expire_current_secret_in_seconds": 3600
+                    })
+                )
+            )
+    )),
+    responses(
+        (status = 200, description = "Webhook endpoint secret rotated", body = WebhookEndpointResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Webhook endpoint not found")
+    ),
+    tag = "Profile",
+    operation_id = "Rotate the Secret of a Webhook Endpoint",
+    security(("api_key" = []))
+)]
+pub async fn webhook_endpoint_rotate_secret() {}
+
+/// Webhook Endpoints - Health
+///
+/// Retrieve the delivery health of an outgoing webhook endpoint. Endpoints are paused once their
+/// consecutive delivery failures reach a threshold, and the events queued while an endpoint is
+/// paused are delivered once it is activated again
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_endpoints/{webhook_endpoint_id}/health",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile"),
+        ("webhook_endpoint_id" = String, Path, description = "The unique identifier for the webhook endpoint")
+    ),
+    responses(
+        (status = 200, description = "Webhook endpoint health retrieved", body = WebhookEndpointHealthResponse),
+        (status = 404, description = "Webhook endpoint not found")
+    ),
+    tag = "Profile",
+    operation_id = "Retrieve the Health of a Webhook Endpoint",
+    security(("api_key" = []))
+)]
+pub async fn webhook_endpoint_health() {}
+
+/// Webhook Endpoints - Delete
+///
+/// Delete an outgoing webhook endpoint of the *profile*
+#[utoipa::path(
+    delete,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_endpoints/{webhook_endpoint_id}",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile"),
+        ("webhook_endpoint_id" = String, Path, description = "The unique identifier for the webhook endpoint")
+    ),
+    responses(
+        (status = 200, description = "Webhook endpoint deleted", body = WebhookEndpointDeleteResponse),
+        (status = 404, description = "Webhook endpoint not found")
+    ),
+    tag = "Profile",
+    operation_id = "Delete a Webhook Endpoint",
+    security(("api_key" = []))
+)]
+pub async fn webhook_endpoint_delete() {}
@@ -115,3 +115,97 @@ pub fn list_webhook_delivery_attempts() {}
     security(("jwt_key" = []))
 )]
 pub fn retry_webhook_delivery_attempt() {}
+
+/// Events - Bulk Redelivery
+///
+/// Redeliver the Events of a Business Profile matching the specified constraints. The Events are
+/// redelivered asynchronously at the specified rate, the progress can be retrieved with the
+/// returned `redelivery_id`. Only one redelivery of the Events of a Business Profile can be pending
+/// at a time.
+#[utoipa::path(
+    post,
+    path = "/events/{merchant_id}/redeliver",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the Merchant Account."),
+    ),
+    request_body = EventsBulkRedeliveryRequest,
+    responses(
+        (status = 200, description = "The redelivery of the Events was scheduled", body = EventsBulkRedeliveryResponse),
+        (status = 400, description = "Invalid request data"),
+        (status = 412, description = "A redelivery of the Events of the Business Profile is still pending"),
+    ),
+    tag = "Event",
+    operation_id = "Redeliver Events in bulk",
+    security(("jwt_key" = []))
+)]
+pub fn create_webhook_bulk_redelivery() {}
+
+/// Events - Retrieve Bulk Redelivery
+///
+/// Retrieve the status and progress of a bulk redelivery of Events.
+#[utoipa::path(
+    get,
+    path = "/events/{merchant_id}/redeliver/{redelivery_id}",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the Merchant Account."),
+        ("redelivery_id" = String, Path, description = "The unique identifier for the bulk redelivery"),
+    ),
+    responses(
+        (status = 200, description = "Bulk redelivery retrieved successfully", body = EventsBulkRedeliveryResponse),
+        (status = 404, description = "Bulk redelivery not found"),
+    ),
+    tag = "Event",
+    operation_id = "Retrieve a bulk redelivery of Events",
+    security(("jwt_key" = []))
+)]
+pub fn retrieve_webhook_bulk_redelivery() {}
+
+/// Incoming Webhooks - List
+///
+/// List the incoming webhooks received from connectors for a merchant, optionally filtered by
+/// connector and processing status.
+#[utoipa::path(
+    post,
+    path = "/events/{merchant_id}/incoming/list",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the Merchant Account."),
+    ),
+    request_body(
+        content = IncomingWebhookListConstraints,
+        description = "The constraints that can be applied when listing incoming webhooks.",
+    ),
+    responses(
+        (status = 200, description = "List of incoming webhooks retrieved successfully", body = TotalIncomingWebhooksResponse),
+    ),
+    tag = "Event",
+    operation_id = "List all incoming webhooks",
+    security(("jwt_key" = []))
+)]
+pub fn list_incoming_webhooks() {}
+
+/// Incoming Webhooks - Reprocess
+///
+/// Process a stored incoming webhook again, as if it was just received from the connector. Only
+/// webhooks that were not applied, that is webhooks with status `received` or `failed`, can be
+/// processed again.
+#[utoipa::path(
+    post,
+    path = "/events/{merchant_id}/incoming/{incoming_webhook_id}/reprocess",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the Merchant Account."),
+        ("incoming_webhook_id" = String, Path, description = "The unique identifier for the incoming webhook"),
+    ),
+    responses(
+        (
+            status = 200,
+            description = "The incoming webhook was processed again. \
+                           Check the `status` field in the response payload to identify the outcome.",
+            body = IncomingWebhookResponse
+        ),
+        (status = 412, description = "The incoming webhook was already applied or acknowledged"),
+    ),
+    tag = "Event",
+    operation_id = "Reprocess an incoming webhook",
+    security(("jwt_key" = []))
+)]
+pub fn reprocess_incoming_webhook() {}
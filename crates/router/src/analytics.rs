@@ -15,6 +15,7 @@ pub mod routes {
         lambda_utils::invoke_lambda,
         opensearch::OpenSearchError,
         outgoing_webhook_event::outgoing_webhook_events_core,
+        reports::ReportKind,
         routing_events::routing_events_core,
         sdk_events::sdk_events_core,
         AnalyticsFlow,
@@ -42,7 +43,7 @@ pub mod routes {
     use crate::{
         analytics_validator::{request_validator, validate_report_request},
         consts::opensearch::SEARCH_INDEXES,
-        core::{api_locking, errors::user::UserErrors, verification::utils},
+        core::{api_locking, errors::user::UserErrors, reports, verification::utils},
         db::user_role::ListUserRolesByUserIdPayload,
         routes::{metrics, AppState},
         services::{
@@ -145,6 +146,10 @@ pub mod routes {
                             web::resource("report/authentications")
                                 .route(web::post().to(generate_merchant_authentication_report)),
                         )
+                        .service(
+                            web::resource("report/{report_id}/download")
+                                .route(web::get().to(download_merchant_report)),
+                        )
                         .service(
                             web::resource("metrics/sdk_events")
                                 .route(web::post().to(get_sdk_event_metrics)),
@@ -294,6 +299,10 @@ pub mod routes {
                                         web::post().to(generate_merchant_authentication_report),
                                     ),
                                 )
+                                .service(
+                                    web::resource("report/{report_id}/download")
+                                        .route(web::get().to(download_merchant_report)),
+                                )
                                 .service(
                                     web::resource("metrics/api_events")
                                         .route(web::post().to(get_merchant_api_events_metrics)),
@@ -384,6 +393,10 @@ pub mod routes {
                                     web::resource("report/authentications")
                                         .route(web::post().to(generate_org_authentication_report)),
                                 )
+                                .service(
+                                    web::resource("report/{report_id}/download")
+                                        .route(web::get().to(download_org_report)),
+                                )
                                 .service(
                                     web::resource("metrics/sankey")
                                         .route(web::post().to(get_org_sankey)),
@@ -479,6 +492,10 @@ pub mod routes {
                                         web::post().to(generate_profile_authentication_report),
                                     ),
                                 )
+                                .service(
+                                    web::resource("report/{report_id}/download")
+                                        .route(web::get().to(download_profile_report)),
+                                )
                                 .service(
                                     web::resource("api_event_logs")
                                         .route(web::get().to(get_profile_api_events)),
@@ -1943,7 +1960,7 @@ pub mod routes {
                     None => None,
                 };
 
-                let lambda_req = GenerateReportRequest {
+                let report_request = GenerateReportRequest {
                     request: payload,
                     merchant_id: Some(auth.platform.get_processor().get_account().get_id().clone()),
                     auth: auth_info,
@@ -1951,15 +1968,9 @@ pub mod routes {
                     payment_response_hash_key: hash_key,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.refund_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
-                )
-                .await
-                .map(ApplicationResponse::Json)
+                reports::generate_report(&state, ReportKind::Refund, report_request)
+                    .await
+                    .map(ApplicationResponse::Json)
             },
             auth::auth_type(
                 &auth::ApiKeyAuth {
@@ -2035,7 +2046,7 @@ pub mod routes {
 
                 let auth_info = auth.platform.to_org_level_auth_info();
 
-                let lambda_req = GenerateReportRequest {
+                let report_request = GenerateReportRequest {
                     request: payload,
                     merchant_id: None,
                     auth: auth_info,
@@ -2043,15 +2054,9 @@ pub mod routes {
                     payment_response_hash_key: None,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.refund_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
-                )
-                .await
-                .map(ApplicationResponse::Json)
+                reports::generate_report(&state, ReportKind::Refund, report_request)
+                    .await
+                    .map(ApplicationResponse::Json)
             },
             &auth::JWTAuth {
                 permission: Permission::OrganizationReportRead,
@@ -2138,7 +2143,7 @@ pub mod routes {
                     None => None,
                 };
 
-                let lambda_req = GenerateReportRequest {
+                let report_request = GenerateReportRequest {
                     request: payload,
                     merchant_id: Some(auth.platform.get_processor().get_account().get_id().clone()),
                     auth: auth_info,
@@ -2146,15 +2151,9 @@ pub mod routes {
                     payment_response_hash_key: hash_key,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.refund_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
-                )
-                .await
-                .map(ApplicationResponse::Json)
+                reports::generate_report(&state, ReportKind::Refund, report_request)
+                    .await
+                    .map(ApplicationResponse::Json)
             },
             auth::auth_type(
                 &auth::ApiKeyAuth {
@@ -2481,7 +2480,7 @@ pub mod routes {
                     None => None,
                 };
 
-                let lambda_req = GenerateReportRequest {
+                let report_request = GenerateReportRequest {
                     request: payload,
                     merchant_id: Some(auth.platform.get_processor().get_account().get_id().clone()),
                     auth: auth_info,
@@ -2489,15 +2488,9 @@ pub mod routes {
                     payment_response_hash_key: hash_key,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.dispute_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
-                )
-                .await
-                .map(ApplicationResponse::Json)
+                reports::generate_report(&state, ReportKind::Dispute, report_request)
+                    .await
+                    .map(ApplicationResponse::Json)
             },
             auth::auth_type(
                 &auth::ApiKeyAuth {
@@ -2572,7 +2565,7 @@ pub mod routes {
 
                 let auth_info = auth.platform.to_org_level_auth_info();
 
-                let lambda_req = GenerateReportRequest {
+                let report_request = GenerateReportRequest {
                     request: payload,
                     merchant_id: None,
                     auth: auth_info,
@@ -2580,15 +2573,9 @@ pub mod routes {
                     payment_response_hash_key: None,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.dispute_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
-                )
-                .await
-                .map(ApplicationResponse::Json)
+                reports::generate_report(&state, ReportKind::Dispute, report_request)
+                    .await
+                    .map(ApplicationResponse::Json)
             },
             &auth::JWTAuth {
                 permission: Permission::OrganizationReportRead,
@@ -2674,7 +2661,7 @@ pub mod routes {
                     None => None,
                 };
 
-                let lambda_req = GenerateReportRequest {
+                let report_request = GenerateReportRequest {
                     request: payload,
                     merchant_id: Some(auth.platform.get_processor().get_account().get_id().clone()),
                     auth: auth_info,
@@ -2682,15 +2669,9 @@ pub mod routes {
                     payment_response_hash_key: hash_key,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.dispute_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
-                )
-                .await
-                .map(ApplicationResponse::Json)
+                reports::generate_report(&state, ReportKind::Dispute, report_request)
+                    .await
+                    .map(ApplicationResponse::Json)
             },
             auth::auth_type(
                 &auth::ApiKeyAuth {
@@ -3072,7 +3053,7 @@ pub mod routes {
                     None => None,
                 };
 
-                let lambda_req = GenerateReportRequest {
+                let report_request = GenerateReportRequest {
                     request: payload,
                     merchant_id: Some(auth.platform.get_processor().get_account().get_id().clone()),
                     auth: auth_info,
@@ -3080,15 +3061,9 @@ pub mod routes {
                     payment_response_hash_key: hash_key,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.payment_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
-                )
-                .await
-                .map(ApplicationResponse::Json)
+                reports::generate_report(&state, ReportKind::Payment, report_request)
+                    .await
+                    .map(ApplicationResponse::Json)
             },
             auth::auth_type::<auth::AuthenticationDataWithUserId, _>(
                 &auth::ApiKeyAuth {
@@ -3163,7 +3138,7 @@ pub mod routes {
 
                 let auth_info = auth.platform.to_org_level_auth_info();
 
-                let lambda_req = GenerateReportRequest {
+                let report_request = GenerateReportRequest {
                     request: payload,
                     merchant_id: None,
                     auth: auth_info,
@@ -3171,15 +3146,9 @@ pub mod routes {
                     payment_response_hash_key: None,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.payment_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
-                )
-                .await
-                .map(ApplicationResponse::Json)
+                reports::generate_report(&state, ReportKind::Payment, report_request)
+                    .await
+                    .map(ApplicationResponse::Json)
             },
             &auth::JWTAuth {
                 permission: Permission::OrganizationReportRead,
@@ -3272,7 +3241,7 @@ pub mod routes {
                     None => None,
                 };
 
-                let lambda_req = GenerateReportRequest {
+                let report_request = GenerateReportRequest {
                     request: payload,
                     merchant_id: Some(auth.platform.get_processor().get_account().get_id().clone()),
                     auth: auth_info,
@@ -3280,15 +3249,9 @@ pub mod routes {
                     payment_response_hash_key: hash_key,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.payment_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
-                )
-                .await
-                .map(ApplicationResponse::Json)
+                reports::generate_report(&state, ReportKind::Payment, report_request)
+                    .await
+                    .map(ApplicationResponse::Json)
             },
             auth::auth_type::<auth::AuthenticationDataWithUserId, _>(
                 &auth::ApiKeyAuth {
@@ -3603,6 +3566,114 @@ pub mod routes {
         .await
     }
 
+    #[cfg(feature = "v1")]
+    pub async fn download_merchant_report(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        path: web::Path<String>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::DownloadReport;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            path.into_inner(),
+            |state, auth: AuthenticationData, report_id, _| {
+                reports::download_report(
+                    state,
+                    auth.platform.to_merchant_level_auth_info(),
+                    report_id,
+                )
+            },
+            auth::auth_type(
+                &auth::ApiKeyAuth {
+                    allow_connected_scope_operation: true,
+                    allow_platform_self_operation: true,
+                },
+                &auth::JWTAuth {
+                    permission: Permission::MerchantReportRead,
+                    allow_connected: true,
+                    allow_platform: true,
+                },
+                req.headers(),
+            ),
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    pub async fn download_org_report(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        path: web::Path<String>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::DownloadReport;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            path.into_inner(),
+            |state, auth: AuthenticationData, report_id, _| {
+                reports::download_report(state, auth.platform.to_org_level_auth_info(), report_id)
+            },
+            &auth::JWTAuth {
+                permission: Permission::OrganizationReportRead,
+                allow_connected: true,
+                allow_platform: true,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    pub async fn download_profile_report(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        path: web::Path<String>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::DownloadReport;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            path.into_inner(),
+            |state, auth: AuthenticationData, report_id, _| async move {
+                let profile_id = auth
+                    .profile
+                    .ok_or(report!(
+                        crate::core::errors::ApiErrorResponse::AccessForbidden {
+                            resource: "report".to_string(),
+                        }
+                    ))?
+                    .get_id()
+                    .clone();
+
+                reports::download_report(
+                    state,
+                    auth.platform.to_profile_level_auth_info(profile_id),
+                    report_id,
+                )
+                .await
+            },
+            auth::auth_type(
+                &auth::ApiKeyAuth {
+                    allow_connected_scope_operation: true,
+                    allow_platform_self_operation: false,
+                },
+                &auth::JWTAuth {
+                    permission: Permission::ProfileReportRead,
+                    allow_connected: true,
+                    allow_platform: false,
+                },
+                req.headers(),
+            ),
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
     /// # Panics
     ///
     /// Panics if `json_payload` array does not contain one `GetApiEventMetricRequest` element.
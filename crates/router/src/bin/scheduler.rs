@@ -396,6 +396,90 @@ impl ProcessTrackerWorkflows<routes::SessionState> for WorkflowRunner {
                 storage::ProcessTrackerRunner::NetworkTokenizationWorkflow => Ok(Box::new(
                     workflows::network_tokenization::NetworkTokenizationWorkflow,
                 )),
+                storage::ProcessTrackerRunner::ReportGenerationWorkflow => {
+                    #[cfg(feature = "olap")]
+                    {
+                        Ok(Box::new(
+                            workflows::report_generation::ReportGenerationWorkflow,
+                        ))
+                    }
+
+                    #[cfg(not(feature = "olap"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run report generation workflow when olap feature is disabled",
+                            )
+                    }
+                }
+                storage::ProcessTrackerRunner::RoutingBacktestWorkflow => {
+                    #[cfg(all(feature = "olap", feature = "v1"))]
+                    {
+                        Ok(Box::new(workflows::routing_backtest::RoutingBacktestWorkflow))
+                    }
+
+                    #[cfg(not(all(feature = "olap", feature = "v1")))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run routing backtest workflow when olap or v1 feature is disabled",
+                            )
+                    }
+                }
+                storage::ProcessTrackerRunner::ScheduledCaptureWorkflow => Ok(Box::new(
+                    workflows::scheduled_capture::ScheduledCaptureWorkflow,
+                )),
+                storage::ProcessTrackerRunner::AuthorizationExpiryWorkflow => Ok(Box::new(
+                    workflows::authorization_expiry::AuthorizationExpiryWorkflow,
+                )),
+                storage::ProcessTrackerRunner::OutgoingWebhookBulkRedeliveryWorkflow => {
+                    #[cfg(all(feature = "olap", feature = "v1"))]
+                    {
+                        Ok(Box::new(
+                            workflows::outgoing_webhook_bulk_redelivery::OutgoingWebhookBulkRedeliveryWorkflow,
+                        ))
+                    }
+
+                    #[cfg(not(all(feature = "olap", feature = "v1")))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run outgoing webhook bulk redelivery workflow when olap or v1 feature is disabled",
+                            )
+                    }
+                }
+                storage::ProcessTrackerRunner::OutgoingWebhookEndpointFlushWorkflow => {
+                    #[cfg(all(feature = "olap", feature = "v1"))]
+                    {
+                        Ok(Box::new(
+                            workflows::outgoing_webhook_endpoint_flush::OutgoingWebhookEndpointFlushWorkflow,
+                        ))
+                    }
+
+                    #[cfg(not(all(feature = "olap", feature = "v1")))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run outgoing webhook endpoint flush workflow when olap or v1 feature is disabled",
+                            )
+                    }
+                }
+                storage::ProcessTrackerRunner::SplitPaymentReversalWorkflow => {
+                    #[cfg(feature = "v2")]
+                    {
+                        Ok(Box::new(
+                            workflows::split_payment_reversal::SplitPaymentReversalWorkflow,
+                        ))
+                    }
+
+                    #[cfg(not(feature = "v2"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run split payment reversal workflow when v2 feature is disabled",
+                            )
+                    }
+                }
             }
         };
 
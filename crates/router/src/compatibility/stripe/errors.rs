@@ -294,6 +294,10 @@ pub enum StripeErrorCode {
     ProfileAcquirerNotFound,
     #[error(error_type = StripeErrorType::HyperswitchError, code = "Subscription Error", message = "Subscription operation: {operation} failed with connector")]
     SubscriptionError { operation: String },
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "idempotency_error", message = "Keys for idempotent requests can only be used with the same parameters they were first used with")]
+    IdempotencyKeyReused,
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "rate_limit", message = "Too many requests hit the API too quickly")]
+    RateLimit,
     // [#216]: https://github.com/juspay/hyperswitch/issues/216
     // Implement the remaining stripe error codes
 
@@ -402,7 +406,6 @@ pub enum StripeErrorCode {
         PostalCodeInvalid,
         ProcessingError,
         ProductInactive,
-        RateLimit,
         ReferToCustomer,
         RefundDisputedPayment,
         ResourceAlreadyExists,
@@ -718,6 +721,8 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
                 Self::SubscriptionError { operation }
             }
             errors::ApiErrorResponse::PaymentSessionExpired => Self::PaymentNotFound,
+            errors::ApiErrorResponse::IdempotencyKeyReused { .. } => Self::IdempotencyKeyReused,
+            errors::ApiErrorResponse::RateLimitExceeded => Self::RateLimit,
         }
     }
 }
@@ -795,7 +800,8 @@ impl actix_web::ResponseError for StripeErrorCode {
             | Self::ExtendedCardInfoNotFound
             | Self::PlatformBadRequest
             | Self::ConnectedBadRequest
-            | Self::LinkConfigurationError { .. } => StatusCode::BAD_REQUEST,
+            | Self::LinkConfigurationError { .. }
+            | Self::IdempotencyKeyReused => StatusCode::BAD_REQUEST,
             Self::RefundFailed
             | Self::PayoutFailed
             | Self::PaymentLinkNotFound
@@ -818,6 +824,7 @@ impl actix_web::ResponseError for StripeErrorCode {
             }
             Self::LockTimeout => StatusCode::LOCKED,
             Self::ProfileAcquirerNotFound => StatusCode::NOT_FOUND,
+            Self::RateLimit => StatusCode::TOO_MANY_REQUESTS,
         }
     }
 
@@ -6,7 +6,7 @@ use router_env::{instrument, tracing, Tag};
 use serde::Serialize;
 
 use crate::{
-    core::{api_locking, errors},
+    core::{api_locking, errors, idempotency},
     events::api_logs::ApiEventMetric,
     routes::{
         app::{AppStateInfo, ReqState},
@@ -52,6 +52,8 @@ where
         func,
         api_authentication,
         lock_action,
+        // Stored responses are in the native API format and cannot be replayed here
+        idempotency::IdempotencyAction::NotApplicable,
     )
     .await
     .map(|response| {
@@ -184,8 +186,12 @@ where
         // This match arm should never be reached as we map the inner field of IncomingWebhookEvent
         // to ApplicationResponse above
         Ok(api::ApplicationResponse::IncomingWebhookEvent { .. }) => api::http_response_ok(),
+        Ok(api::ApplicationResponse::IdempotentReplay(stored_response)) => {
+            api::http_response_idempotent_replay(*stored_response)
+        }
         Err(error) => api::log_and_return_error_response(error),
     };
+    let res = api::add_rate_limit_headers(request, res);
 
     let response_code = res.status().as_u16();
     let end_instant = Instant::now();
@@ -102,6 +102,16 @@ impl Default for super::settings::EphemeralConfig {
     }
 }
 
+impl Default for super::settings::AuthorizationExpiryConfig {
+    fn default() -> Self {
+        Self {
+            default_validity_in_hours: 7 * 24,
+            lead_time_in_hours: 24,
+            rules: Vec::new(),
+        }
+    }
+}
+
 #[cfg(feature = "kv_store")]
 impl Default for super::settings::DrainerSettings {
     fn default() -> Self {
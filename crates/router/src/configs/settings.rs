@@ -151,6 +151,8 @@ pub struct Settings<S: SecretState> {
     pub multiple_api_version_supported_connectors: MultipleApiVersionSupportedConnectors,
     pub applepay_merchant_configs: SecretStateContainer<ApplepayMerchantConfigs, S>,
     pub lock_settings: LockSettings,
+    pub idempotency: IdempotencySettings,
+    pub rate_limit: RateLimitSettings,
     pub temp_locker_enable_config: TempLockerEnableConfig,
     pub generic_link: GenericLink,
     pub payment_link: PaymentLink,
@@ -204,6 +206,8 @@ pub struct Settings<S: SecretState> {
     pub authentication_service_enabled_connectors: AuthenticationServiceEnabledConnectors,
     pub save_payment_method_on_session: OnSessionConfig,
     pub account_updater: Option<SecretStateContainer<AccountUpdaterConfig, S>>,
+    #[serde(default)]
+    pub authorization_expiry: AuthorizationExpiryConfig,
 }
 
 #[cfg(feature = "deja")]
@@ -1088,6 +1092,92 @@ pub struct EphemeralConfig {
     pub validity: i64,
 }
 
+/// Expected lifetime of authorizations held for manual capture, used to act on payments before
+/// their authorization expires
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct AuthorizationExpiryConfig {
+    /// Validity of an authorization when none of the rules match the payment
+    pub default_validity_in_hours: u32,
+    /// How long before the expected expiry of an authorization the action configured for the
+    /// profile is taken
+    pub lead_time_in_hours: u32,
+    pub rules: Vec<AuthorizationExpiryRule>,
+}
+
+/// Validity of authorizations for a connector, card network and merchant category code. A field
+/// that is not set matches any value, the rule matching the most fields is used.
+#[derive(Debug, Deserialize, Clone)]
+pub struct AuthorizationExpiryRule {
+    pub connector: Option<enums::Connector>,
+    pub card_network: Option<enums::CardNetwork>,
+    pub merchant_category_code: Option<enums::MerchantCategoryCode>,
+    pub validity_in_hours: u32,
+}
+
+impl AuthorizationExpiryRule {
+    /// Returns the number of fields of the rule that match the payment, if none of them conflict
+    fn get_specificity(
+        &self,
+        connector: Option<&str>,
+        card_network: Option<&enums::CardNetwork>,
+        merchant_category_code: Option<&enums::MerchantCategoryCode>,
+    ) -> Option<usize> {
+        let connector_matches = self
+            .connector
+            .as_ref()
+            .map(|rule_connector| connector == Some(rule_connector.to_string().as_str()));
+        let card_network_matches = self
+            .card_network
+            .as_ref()
+            .map(|rule_card_network| card_network == Some(rule_card_network));
+        let merchant_category_code_matches =
+            self.merchant_category_code
+                .as_ref()
+                .map(|rule_merchant_category_code| {
+                    merchant_category_code == Some(rule_merchant_category_code)
+                });
+
+        [
+            connector_matches,
+            card_network_matches,
+            merchant_category_code_matches,
+        ]
+        .into_iter()
+        .flatten()
+        .try_fold(0, |specificity, matches| matches.then_some(specificity + 1))
+    }
+}
+
+impl AuthorizationExpiryConfig {
+    /// Returns the validity of an authorization from the most specific rule matching the payment,
+    /// rules listed earlier take precedence over equally specific ones
+    pub fn get_validity(
+        &self,
+        connector: Option<&str>,
+        card_network: Option<&enums::CardNetwork>,
+        merchant_category_code: Option<&enums::MerchantCategoryCode>,
+    ) -> time::Duration {
+        let validity_in_hours = self
+            .rules
+            .iter()
+            .filter_map(|rule| {
+                rule.get_specificity(connector, card_network, merchant_category_code)
+                    .map(|specificity| (specificity, rule.validity_in_hours))
+            })
+            .rev()
+            .max_by_key(|(specificity, _)| *specificity)
+            .map(|(_, validity_in_hours)| validity_in_hours)
+            .unwrap_or(self.default_validity_in_hours);
+
+        time::Duration::hours(validity_in_hours.into())
+    }
+
+    pub fn get_lead_time(&self) -> time::Duration {
+        time::Duration::hours(self.lead_time_in_hours.into())
+    }
+}
+
 #[derive(Debug, Deserialize, Clone, Default)]
 #[serde(default)]
 pub struct Jwekey {
@@ -1236,6 +1326,21 @@ pub struct WebhooksSettings {
     pub outgoing_enabled: bool,
     pub ignore_error: WebhookIgnoreErrorSettings,
     pub redis_lock_expiry_seconds: u32,
+    /// Duration in seconds for which deliveries of an incoming webhook with the same connector
+    /// event ID are treated as duplicates. Deduplication is disabled when set to 0. Deliveries
+    /// from connectors without an event ID are compared by a digest of the request body.
+    pub incoming_dedup_window_in_seconds: u32,
+    /// Maximum number of events redelivered per minute by a bulk redelivery of outgoing webhooks.
+    /// Bulk redelivery is disabled when set to 0.
+    pub bulk_redelivery_max_events_per_minute: u32,
+    /// Number of consecutive failed deliveries to a webhook endpoint after which the endpoint is
+    /// paused. Endpoints are never paused when set to 0.
+    pub endpoint_pause_consecutive_failures: u32,
+    /// Duration in seconds over which the failure rate of a webhook endpoint is computed
+    pub endpoint_health_window_in_seconds: u32,
+    /// Number of events queued for a paused webhook endpoint delivered per minute once the
+    /// endpoint is activated again
+    pub paused_endpoint_flush_events_per_minute: u32,
 }
 
 #[derive(Debug, Clone, Deserialize, Default)]
@@ -1514,7 +1619,10 @@ impl Settings<SecuredSecret> {
             .map_err(|err| ApplicationError::InvalidConfigurationValueError(err.to_string()))?;
 
         self.lock_settings.validate()?;
+        self.idempotency.validate()?;
+        self.rate_limit.validate()?;
         self.events.validate()?;
+        self.authorization_expiry.validate()?;
 
         #[cfg(feature = "olap")]
         self.opensearch.validate()?;
@@ -1656,6 +1764,39 @@ impl<'de> Deserialize<'de> for LockSettings {
     }
 }
 
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct IdempotencySettings {
+    /// Whether `Idempotency-Key` headers are honoured on mutating APIs
+    pub enabled: bool,
+    /// Duration for which the first response for an idempotency key is replayed
+    pub ttl_in_seconds: u32,
+}
+
+impl Default for IdempotencySettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            ttl_in_seconds: 86400, // 24 * 60 * 60 seconds
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct RateLimitSettings {
+    /// Whether API requests are throttled using the merchant's rate limit rules
+    pub enabled: bool,
+    /// Limit applied to merchants which have no rate limit rules configured
+    pub default_limit: Option<DefaultRateLimit>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct DefaultRateLimit {
+    pub requests_per_second: u32,
+    pub burst: u32,
+}
+
 #[cfg(feature = "olap")]
 #[derive(Debug, Deserialize, Clone, Default)]
 pub struct ConnectorOnboarding {
@@ -2054,3 +2195,83 @@ mod hashset_deserialization_test {
         assert!(payment_methods.is_err());
     }
 }
+
+#[cfg(test)]
+mod authorization_expiry_config_test {
+    #![allow(clippy::unwrap_used)]
+    use std::str::FromStr;
+
+    use super::{enums, AuthorizationExpiryConfig, AuthorizationExpiryRule};
+
+    fn get_config() -> AuthorizationExpiryConfig {
+        AuthorizationExpiryConfig {
+            default_validity_in_hours: 168,
+            lead_time_in_hours: 24,
+            rules: vec![
+                AuthorizationExpiryRule {
+                    connector: None,
+                    card_network: Some(enums::CardNetwork::Visa),
+                    merchant_category_code: None,
+                    validity_in_hours: 120,
+                },
+                AuthorizationExpiryRule {
+                    connector: None,
+                    card_network: Some(enums::CardNetwork::Visa),
+                    merchant_category_code: Some(
+                        enums::MerchantCategoryCode::from_str("7011").unwrap(),
+                    ),
+                    validity_in_hours: 744,
+                },
+                AuthorizationExpiryRule {
+                    connector: Some(enums::Connector::Adyen),
+                    card_network: None,
+                    merchant_category_code: None,
+                    validity_in_hours: 600,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_most_specific_rule_is_used() {
+        let config = get_config();
+        let lodging = enums::MerchantCategoryCode::from_str("7011").unwrap();
+
+        assert_eq!(
+            config.get_validity(
+                Some("stripe"),
+                Some(&enums::CardNetwork::Visa),
+                Some(&lodging)
+            ),
+            time::Duration::hours(744)
+        );
+        assert_eq!(
+            config.get_validity(Some("stripe"), Some(&enums::CardNetwork::Visa), None),
+            time::Duration::hours(120)
+        );
+    }
+
+    #[test]
+    fn test_earlier_rule_wins_between_equally_specific_rules() {
+        let config = get_config();
+
+        assert_eq!(
+            config.get_validity(Some("adyen"), Some(&enums::CardNetwork::Visa), None),
+            time::Duration::hours(120)
+        );
+    }
+
+    #[test]
+    fn test_default_validity_when_no_rule_matches() {
+        let config = get_config();
+
+        assert_eq!(
+            config.get_validity(Some("stripe"), Some(&enums::CardNetwork::Mastercard), None),
+            time::Duration::hours(168)
+        );
+        assert_eq!(
+            config.get_validity(None, None, None),
+            time::Duration::hours(168)
+        );
+    }
+}
@@ -56,6 +56,27 @@ impl super::settings::Server {
     }
 }
 
+impl super::settings::AuthorizationExpiryConfig {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.default_validity_in_hours == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "default authorization validity must be greater than 0".into(),
+            ))
+        })?;
+
+        when(
+            self.rules.iter().any(|rule| rule.validity_in_hours == 0),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "authorization validity of every rule must be greater than 0".into(),
+                ))
+            },
+        )
+    }
+}
+
 impl super::settings::Database {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
@@ -172,6 +193,39 @@ impl super::settings::LockSettings {
     }
 }
 
+impl super::settings::IdempotencySettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(
+            self.enabled && self.ttl_in_seconds.is_default_or_empty(),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "idempotency ttl_in_seconds must not be 0 when idempotency is enabled".into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::RateLimitSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        self.default_limit.as_ref().map_or(Ok(()), |default_limit| {
+            when(
+                default_limit.requests_per_second.is_default_or_empty()
+                    || default_limit.burst.is_default_or_empty(),
+                || {
+                    Err(ApplicationError::InvalidConfigurationValueError(
+                        "rate limit requests_per_second and burst must not be 0".into(),
+                    ))
+                },
+            )
+        })
+    }
+}
+
 impl super::settings::WebhooksSettings {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
@@ -81,6 +81,8 @@ pub const EMAIL_TOKEN_TIME_IN_SECS: u64 = 60 * 60 * 24; // 1 day
 pub const EMAIL_TOKEN_BLACKLIST_PREFIX: &str = "BET_";
 
 pub const EMAIL_SUBJECT_API_KEY_EXPIRY: &str = "API Key Expiry Notice";
+pub const EMAIL_SUBJECT_REPORT_GENERATED: &str = "Your Hyperswitch Report is Ready";
+pub const EMAIL_SUBJECT_WEBHOOK_ENDPOINT_PAUSED: &str = "Your Webhook Endpoint was Paused";
 pub const EMAIL_SUBJECT_DASHBOARD_FEATURE_REQUEST: &str = "Dashboard Pro Feature Request by";
 pub const EMAIL_SUBJECT_APPROVAL_RECON_REQUEST: &str =
     "Approval of Recon Request - Access Granted to Recon Dashboard";
@@ -494,6 +496,10 @@ pub mod superposition {
     pub const INCOMING_WEBHOOK_DISABLED_EVENTS: &str = "webhooks.incoming_webhook_disabled_events";
     /// save wallet decrypted data in locker
     pub const SAVE_WALLET_DECRYPTED_DATA: &str = "vaulting.save_wallet_decrypted_data";
+    /// action taken before the authorization of a payment held for manual capture expires
+    pub const AUTHORIZATION_EXPIRY_ACTION: &str = "payments.authorization_expiry_action";
+    /// markup over the market rate applied to dynamic currency conversion quotes, in basis points
+    pub const DCC_MARKUP_IN_BASIS_POINTS: &str = "payments.dcc_markup_in_basis_points";
 }
 
 #[cfg(test)]
@@ -34,6 +34,7 @@ pub mod files;
 pub mod fraud_check;
 pub mod gsm;
 pub mod health_check;
+pub mod idempotency;
 pub mod mandate;
 pub mod merchant_connector_webhook_management;
 pub mod metrics;
@@ -56,11 +57,14 @@ pub mod poll;
 pub mod profile_acquirer;
 #[cfg(feature = "v2")]
 pub mod proxy;
+pub mod rate_limit;
 #[cfg(feature = "v1")]
 pub mod refunds;
 #[cfg(feature = "v2")]
 pub mod refunds_v2;
 pub mod relay;
+#[cfg(feature = "olap")]
+pub mod reports;
 #[cfg(feature = "v2")]
 pub mod revenue_recovery;
 #[cfg(feature = "v2")]
@@ -41,7 +41,7 @@ use crate::{
         payment_methods::{cards, transformers, vault},
         payments::helpers::{self},
         pm_auth::helpers::PaymentAuthConnectorDataExt,
-        routing, utils as core_utils,
+        routing, utils as core_utils, webhooks,
     },
     db::{AccountsStorageInterface, StorageInterface},
     logger,
@@ -2292,6 +2292,7 @@ impl MerchantConnectorAccountUpdateBridge for api_models::admin::MerchantConnect
             status: Some(connector_status),
             additional_merchant_data: Box::new(encrypted_data.additional_merchant_data),
             connector_wallets_details: Box::new(encrypted_data.connector_wallets_details),
+            fee_schedule: Box::new(self.fee_schedule),
         })
     }
 }
@@ -2648,6 +2649,7 @@ impl MerchantConnectorAccountCreateBridge for api::MerchantConnectorCreate {
             additional_merchant_data: encrypted_data.additional_merchant_data,
             version: common_types::consts::API_VERSION,
             connector_webhook_registration_details: None,
+            fee_schedule: self.fee_schedule.clone(),
         })
     }
 
@@ -3590,6 +3592,8 @@ impl ProfileCreateBridge for api::ProfileCreate {
                 message: "Invalid merchant country code".to_string(),
             })?;
 
+        validate_routing_timezone(self.routing_timezone.as_deref())?;
+
         Ok(domain::Profile::from(domain::ProfileSetter {
             profile_id,
             merchant_id: processor.get_account().get_id().clone(),
@@ -3704,6 +3708,7 @@ impl ProfileCreateBridge for api::ProfileCreate {
             network_tokenization_credentials,
             payment_method_blocking: self.payment_method_blocking.map(ForeignInto::foreign_into),
             default_fallback_routing: None,
+            routing_timezone: self.routing_timezone,
             surcharge_connector_details: self
                 .surcharge_connector_details
                 .map(ForeignInto::foreign_into),
@@ -3919,6 +3924,22 @@ fn validate_external_vault_config_for_merchant_account_type(
     )
 }
 
+#[cfg(all(feature = "olap", feature = "v1"))]
+/// Time based routing rules are evaluated in the routing timezone of the profile, which must be a
+/// valid IANA timezone name.
+fn validate_routing_timezone(routing_timezone: Option<&str>) -> RouterResult<()> {
+    common_utils::fp_utils::when(
+        routing_timezone.is_some_and(|routing_timezone| {
+            crate::core::payments::routing::parse_routing_timezone(routing_timezone).is_none()
+        }),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Invalid routing timezone, expected an IANA timezone name".to_string(),
+            }))
+        },
+    )
+}
+
 pub async fn create_profile(
     state: SessionState,
     request: api::ProfileCreate,
@@ -4084,7 +4105,17 @@ impl ProfileUpdateBridge for api::ProfileUpdate {
                     None => webhook_details,
                 }
             })
-            .map(ForeignInto::foreign_into);
+            .map(|webhook_details| {
+                // Webhook endpoints are managed through their own APIs, retain them across
+                // profile updates
+                diesel_models::business_profile::WebhookDetails {
+                    multiple_webhooks_list: business_profile
+                        .webhook_details
+                        .as_ref()
+                        .and_then(|details| details.multiple_webhooks_list.clone()),
+                    ..ForeignInto::foreign_into(webhook_details)
+                }
+            });
 
         if let Some(ref routing_algorithm) = self.routing_algorithm {
             let _: api_models::routing::StaticRoutingAlgorithm = routing_algorithm
@@ -4220,6 +4251,8 @@ impl ProfileUpdateBridge for api::ProfileUpdate {
                 message: "Invalid merchant country code".to_string(),
             })?;
 
+        validate_routing_timezone(self.routing_timezone.as_deref())?;
+
         Ok(domain::ProfileUpdate::Update(Box::new(
             domain::ProfileGeneralUpdate {
                 profile_name: self.profile_name,
@@ -4289,6 +4322,7 @@ impl ProfileUpdateBridge for api::ProfileUpdate {
                 payment_method_blocking: self
                     .payment_method_blocking
                     .map(ForeignInto::foreign_into),
+                routing_timezone: self.routing_timezone,
                 surcharge_connector_details: self
                     .surcharge_connector_details
                     .map(ForeignInto::foreign_into),
@@ -4471,23 +4505,33 @@ pub async fn update_profile(
         request.external_vault_connector_details.is_some(),
     )?;
 
-    let business_profile = db
-        .find_business_profile_by_merchant_id_profile_id(&key_store, &merchant_id, profile_id)
-        .await
-        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
-            id: profile_id.get_string_repr().to_owned(),
-        })?;
+    // The webhook details hold the webhook endpoints of the profile, which are updated
+    // concurrently by the webhook endpoint APIs, so they are read and written under their lock
+    let is_webhook_details_updated = request.webhook_details.is_some();
+    let update_business_profile = async {
+        let business_profile = db
+            .find_business_profile_by_merchant_id_profile_id(&key_store, &merchant_id, profile_id)
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+                id: profile_id.get_string_repr().to_owned(),
+            })?;
 
-    let profile_update = request
-        .get_update_profile_object(&state, &key_store, &business_profile)
-        .await?;
+        let profile_update = request
+            .get_update_profile_object(&state, &key_store, &business_profile)
+            .await?;
 
-    let updated_business_profile = db
-        .update_profile_by_profile_id(&key_store, business_profile, profile_update)
-        .await
-        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
-            id: profile_id.get_string_repr().to_owned(),
-        })?;
+        db.update_profile_by_profile_id(&key_store, business_profile, profile_update)
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+                id: profile_id.get_string_repr().to_owned(),
+            })
+    };
+    let updated_business_profile = if is_webhook_details_updated {
+        webhooks::utils::with_webhook_endpoints_lock(&state, profile_id, update_business_profile)
+            .await?
+    } else {
+        update_business_profile.await?
+    };
 
     Ok(service_api::ApplicationResponse::Json(
         api_models::admin::ProfileResponse::foreign_try_from(updated_business_profile)
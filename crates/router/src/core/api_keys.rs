@@ -1,3 +1,4 @@
+use common_enums::EntityType;
 use common_utils::date_time;
 #[cfg(feature = "email")]
 use diesel_models::{api_keys::ApiKey, enums as storage_enums};
@@ -11,7 +12,7 @@ use crate::{
     core::errors::{self, RouterResponse, StorageErrorExt},
     db::domain,
     routes::{metrics, SessionState},
-    services::{authentication, ApplicationResponse},
+    services::{authentication, authorization::permissions::ResourceExt, ApplicationResponse},
     types::{api, storage, transformers::ForeignInto},
 };
 
@@ -108,6 +109,58 @@ impl PlaintextApiKey {
     }
 }
 
+/// Restricted API keys must be granted at least one permission, and keys restricted to a profile
+/// can only be granted permissions on resources which are available at the profile level.
+async fn validate_api_key_restrictions(
+    state: &SessionState,
+    api_key: &api::CreateApiKeyRequest,
+    key_store: &domain::MerchantKeyStore,
+) -> errors::RouterResult<()> {
+    if let Some(profile_id) = api_key.profile_id.as_ref() {
+        state
+            .store
+            .find_business_profile_by_merchant_id_profile_id(
+                key_store,
+                &key_store.merchant_id,
+                profile_id,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+                id: profile_id.get_string_repr().to_owned(),
+            })?;
+    }
+
+    let Some(permissions) = api_key.permissions.as_ref() else {
+        return Ok(());
+    };
+
+    if permissions.get_permissions().is_empty() {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`permissions` must not be empty".to_string(),
+        }));
+    }
+
+    let entity_type = if api_key.profile_id.is_some() {
+        EntityType::Profile
+    } else {
+        EntityType::Merchant
+    };
+    if let Some(permission) = permissions
+        .get_permissions()
+        .iter()
+        .find(|permission| !permission.resource.entities().contains(&entity_type))
+    {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "Permissions on `{:?}` cannot be granted to API keys restricted to a {entity_type:?}",
+                permission.resource
+            ),
+        }));
+    }
+
+    Ok(())
+}
+
 #[instrument(skip_all)]
 pub async fn create_api_key(
     state: SessionState,
@@ -119,6 +172,8 @@ pub async fn create_api_key(
 
     let merchant_id = key_store.merchant_id.clone();
 
+    validate_api_key_restrictions(&state, &api_key, &key_store).await?;
+
     let hash_key = api_key_config.get_hash_key()?;
     let plaintext_api_key = PlaintextApiKey::new(consts::API_KEY_LENGTH);
     let api_key = storage::ApiKeyNew {
@@ -131,6 +186,8 @@ pub async fn create_api_key(
         created_at: date_time::now(),
         expires_at: api_key.expiration.into(),
         last_used: None,
+        permissions: api_key.permissions,
+        profile_id: api_key.profile_id,
     };
 
     let api_key = store
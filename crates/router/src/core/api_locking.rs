@@ -52,6 +52,25 @@ impl LockingInput {
 }
 
 impl LockAction {
+    /// Adds a locking input to be held along with the ones already held by this action. Actions
+    /// that do not wait on locks (`QueueWithOk` and `Drop`) are kept as they are, so that the
+    /// behaviour requested by the flow is not changed.
+    pub fn with_additional_input(self, additional_input: LockingInput) -> Self {
+        match self {
+            Self::Hold { input } => Self::HoldMultiple {
+                inputs: vec![input, additional_input],
+            },
+            Self::HoldMultiple { mut inputs } => {
+                inputs.push(additional_input);
+                Self::HoldMultiple { inputs }
+            }
+            Self::QueueWithOk | Self::Drop => self,
+            Self::NotApplicable => Self::Hold {
+                input: additional_input,
+            },
+        }
+    }
+
     // deja: NO boundary — the lock outcome replays from the recorded redis reply.
     // See `docs/design/deja-non-boundaries.md`.
     #[instrument(skip_all)]
@@ -809,6 +809,43 @@ impl DatabaseBackedConfig for SaveWalletDecryptedData {
     }
 }
 
+#[cfg(feature = "v1")]
+config! {
+    superposition_key = AUTHORIZATION_EXPIRY_ACTION,
+    output = crate::core::payments::authorization_expiry::AuthorizationExpiryAction,
+    default = crate::core::payments::authorization_expiry::AuthorizationExpiryAction::None,
+    string_enum = true,
+    requires = dimension_state::DimensionsWithProcessorAndProviderMerchantIdAndProfileId,
+    targeting_key = id_type::ProfileId
+}
+
+#[cfg(feature = "v1")]
+impl DatabaseBackedConfig for AuthorizationExpiryAction {
+    const KEY: &'static str = "authorization_expiry_action";
+    fn db_key(dimensions: &impl dimension_state::DimensionsBase) -> Option<String> {
+        dimensions
+            .get_profile_id()
+            .map(|id| format!("{}_{}", Self::KEY, id.get_string_repr()))
+    }
+}
+
+config! {
+    superposition_key = DCC_MARKUP_IN_BASIS_POINTS,
+    output = u32,
+    default = 0u32,
+    requires = dimension_state::DimensionsWithProcessorAndProviderMerchantIdAndProfileId,
+    targeting_key = id_type::ProfileId
+}
+
+impl DatabaseBackedConfig for DccMarkupInBasisPoints {
+    const KEY: &'static str = "dcc_markup_in_basis_points";
+    fn db_key(dimensions: &impl dimension_state::DimensionsBase) -> Option<String> {
+        dimensions
+            .get_profile_id()
+            .map(|id| format!("{}_{}", Self::KEY, id.get_string_repr()))
+    }
+}
+
 config! {
     superposition_key = OFFER_ENGINE_ENABLED,
     output = bool,
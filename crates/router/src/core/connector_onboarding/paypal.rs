@@ -166,6 +166,7 @@ pub async fn update_mca(
         test_mode: None,
         additional_merchant_data: None,
         connector_wallets_details: None,
+        fee_schedule: None,
     };
     #[cfg(feature = "v2")]
     let request = MerchantConnectorUpdate {
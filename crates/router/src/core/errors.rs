@@ -169,6 +169,10 @@ pub enum WebhooksFlowError {
     WebhookCallFailed,
     #[error("Webhook request construction failed")]
     WebhookRequestConstructionFailed,
+    #[error("Webhook endpoint is paused")]
+    WebhookEndpointPaused,
+    #[error("Failed to queue webhook for paused webhook endpoint")]
+    WebhookQueueingFailed,
 }
 
 impl WebhooksFlowError {
@@ -178,7 +182,8 @@ impl WebhooksFlowError {
             | Self::MerchantWebhookDetailsNotFound
             | Self::MerchantWebhookUrlNotConfigured
             | Self::OutgoingWebhookResponseEncodingFailed
-            | Self::WebhookRequestConstructionFailed => false,
+            | Self::WebhookRequestConstructionFailed
+            | Self::WebhookEndpointPaused => false,
 
             Self::WebhookEventUpdationFailed
             | Self::OutgoingWebhookSigningFailed
@@ -190,7 +195,8 @@ impl WebhooksFlowError {
             | Self::OutgoingWebhookRetrySchedulingFailed
             | Self::IdGenerationFailed
             | Self::WebhookCallFailed
-            | Self::NotReceivedByRecipient => true,
+            | Self::NotReceivedByRecipient
+            | Self::WebhookQueueingFailed => true,
         }
     }
 }
@@ -1,3 +1,5 @@
+pub mod bulk;
+
 use api_models::gsm as gsm_api_types;
 use error_stack::ResultExt;
 use router_env::{instrument, tracing};
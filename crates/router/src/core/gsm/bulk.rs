@@ -0,0 +1,647 @@
+//! Bulk import and export of GSM rules, and discovery of connector errors without a GSM rule.
+#[cfg(feature = "olap")]
+use std::collections::HashSet;
+use std::{collections::HashMap, str::FromStr};
+
+use actix_multipart::form::{bytes::Bytes, text::Text, MultipartForm};
+use api_models::{enums as api_enums, gsm as gsm_api_types};
+#[cfg(feature = "olap")]
+use common_utils::{date_time, types::TimeRange};
+use error_stack::ResultExt;
+use hyperswitch_domain_models::gsm::GatewayStatusMap;
+use router_env::{instrument, tracing};
+
+#[cfg(feature = "olap")]
+use crate::consts;
+use crate::{
+    core::errors::{self, RouterResponse},
+    db::gsm::GsmInterface,
+    services::ApplicationResponse,
+    SessionState,
+};
+
+const MAX_BULK_IMPORT_RECORDS: usize = 5_000;
+#[cfg(feature = "olap")]
+const MAX_UNMAPPED_ERRORS_LOOKBACK_DAYS: u16 = 90;
+#[cfg(feature = "olap")]
+const DEFAULT_UNMAPPED_ERRORS_LIMIT: u16 = 100;
+#[cfg(feature = "olap")]
+const MAX_UNMAPPED_ERRORS_LIMIT: u16 = 1_000;
+
+/// The key on which GSM rules are looked up
+type GsmKey = (String, String, String, String, String);
+
+fn gsm_key(rule: &GatewayStatusMap) -> GsmKey {
+    (
+        rule.connector.clone(),
+        rule.flow.clone(),
+        rule.sub_flow.clone(),
+        rule.code.clone(),
+        rule.message.clone(),
+    )
+}
+
+#[derive(Debug, MultipartForm)]
+pub struct GsmBulkImportForm {
+    #[multipart(limit = "5MB")]
+    pub file: Bytes,
+    pub dry_run: Option<Text<bool>>,
+}
+
+/// Reads the records of a CSV import, the header row names the fields of [`GsmBulkRecord`].
+///
+/// [`GsmBulkRecord`]: gsm_api_types::GsmBulkRecord
+pub fn get_bulk_import_request(
+    form: GsmBulkImportForm,
+) -> Result<gsm_api_types::GsmBulkImportRequest, errors::ApiErrorResponse> {
+    let mut csv_reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_reader(form.file.data.as_ref());
+    let headers = csv_reader
+        .headers()
+        .map_err(|error| errors::ApiErrorResponse::PreconditionFailed {
+            message: error.to_string(),
+        })?
+        .clone();
+
+    let mut rules = Vec::new();
+    let mut line_numbers = Vec::new();
+    for record in csv_reader.records() {
+        let record = record.map_err(|error| errors::ApiErrorResponse::PreconditionFailed {
+            message: error.to_string(),
+        })?;
+        let line_number = record
+            .position()
+            .and_then(|position| usize::try_from(position.line()).ok())
+            .unwrap_or_default();
+        let rule = record
+            .deserialize::<gsm_api_types::GsmBulkRecord>(Some(&headers))
+            .map_err(|error| errors::ApiErrorResponse::PreconditionFailed {
+                message: format!("Line {line_number}: {error}"),
+            })?;
+
+        rules.push(rule);
+        line_numbers.push(line_number);
+    }
+
+    Ok(gsm_api_types::GsmBulkImportRequest {
+        rules,
+        dry_run: form.dry_run.is_some_and(|dry_run| dry_run.into_inner()),
+        line_numbers: Some(line_numbers),
+    })
+}
+
+#[instrument(skip_all)]
+pub async fn import_gsm_rules(
+    state: SessionState,
+    request: gsm_api_types::GsmBulkImportRequest,
+) -> RouterResponse<gsm_api_types::GsmBulkImportResponse> {
+    if request.rules.len() > MAX_BULK_IMPORT_RECORDS {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("At most {MAX_BULK_IMPORT_RECORDS} GSM rules can be imported at once"),
+        }
+        .into());
+    }
+
+    let db = state.store.as_ref();
+    let existing_rules = db
+        .list_gsm_rules(None)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list GSM rules")?
+        .into_iter()
+        .map(|rule| (gsm_key(&rule), rule))
+        .collect::<HashMap<_, _>>();
+
+    let mut seen_keys = HashMap::new();
+    let mut rows = Vec::with_capacity(request.rules.len());
+    let mut changes = Vec::new();
+
+    for (index, record) in request.rules.into_iter().enumerate() {
+        let line_number = request
+            .line_numbers
+            .as_ref()
+            .and_then(|line_numbers| line_numbers.get(index).copied())
+            .unwrap_or(index + 1);
+        let mut row = gsm_api_types::GsmBulkImportRowResult {
+            line_number,
+            connector: record.connector.clone(),
+            flow: record.flow.clone(),
+            sub_flow: record.sub_flow.clone(),
+            code: record.code.clone(),
+            message: record.message.clone(),
+            action: gsm_api_types::GsmBulkImportAction::Invalid,
+            changed_fields: Vec::new(),
+            errors: validate_record(&record),
+        };
+
+        let connector = api_enums::Connector::from_str(&record.connector).ok();
+        if connector.is_none() {
+            row.errors
+                .push(format!("Unknown connector `{}`", record.connector));
+        }
+
+        if let Some(connector) = connector.filter(|_| row.errors.is_empty()) {
+            let key = (
+                connector.to_string(),
+                record.flow.clone(),
+                record.sub_flow.clone(),
+                record.code.clone(),
+                record.message.clone(),
+            );
+
+            if let Some(first_line_number) = seen_keys.get(&key) {
+                row.errors.push(format!(
+                    "Duplicate of the GSM rule at line {first_line_number}"
+                ));
+            } else {
+                let existing_rule = existing_rules.get(&key);
+                seen_keys.insert(key, line_number);
+                let rule = to_gateway_status_map(connector, record, existing_rule);
+
+                row.action = match existing_rule {
+                    None => gsm_api_types::GsmBulkImportAction::Create,
+                    Some(existing_rule) => {
+                        row.changed_fields = get_changed_fields(existing_rule, &rule);
+                        if row.changed_fields.is_empty() {
+                            gsm_api_types::GsmBulkImportAction::Unchanged
+                        } else {
+                            gsm_api_types::GsmBulkImportAction::Update
+                        }
+                    }
+                };
+                changes.push((row.action, rule));
+            }
+        }
+
+        rows.push(row);
+    }
+
+    let count = |action| rows.iter().filter(|row| row.action == action).count();
+    let invalid = count(gsm_api_types::GsmBulkImportAction::Invalid);
+    let applied = !request.dry_run && invalid == 0;
+
+    if applied {
+        let (new_rules, updated_rules) = changes.into_iter().fold(
+            (Vec::new(), Vec::new()),
+            |(mut new_rules, mut updated_rules), (action, rule)| {
+                match action {
+                    gsm_api_types::GsmBulkImportAction::Create => new_rules.push(rule),
+                    gsm_api_types::GsmBulkImportAction::Update => updated_rules.push(rule),
+                    gsm_api_types::GsmBulkImportAction::Unchanged
+                    | gsm_api_types::GsmBulkImportAction::Invalid => {}
+                }
+                (new_rules, updated_rules)
+            },
+        );
+
+        db.apply_gsm_rules(new_rules, updated_rules)
+            .await
+            .map_err(|error| {
+                let response = if error.current_context().is_db_unique_violation()
+                    || error.current_context().is_db_not_found()
+                {
+                    errors::ApiErrorResponse::PreconditionFailed {
+                        message: "GSM rules were changed during the import, none of the rules \
+                                  were applied"
+                            .to_string(),
+                    }
+                } else {
+                    errors::ApiErrorResponse::InternalServerError
+                };
+                error.change_context(response)
+            })
+            .attach_printable("Failed to apply imported GSM rules")?;
+    }
+
+    Ok(ApplicationResponse::Json(
+        gsm_api_types::GsmBulkImportResponse {
+            dry_run: request.dry_run,
+            applied,
+            created: count(gsm_api_types::GsmBulkImportAction::Create),
+            updated: count(gsm_api_types::GsmBulkImportAction::Update),
+            unchanged: count(gsm_api_types::GsmBulkImportAction::Unchanged),
+            invalid,
+            rows,
+        },
+    ))
+}
+
+fn validate_record(record: &gsm_api_types::GsmBulkRecord) -> Vec<String> {
+    [
+        ("flow", &record.flow),
+        ("sub_flow", &record.sub_flow),
+        ("code", &record.code),
+        ("message", &record.message),
+        ("status", &record.status),
+    ]
+    .into_iter()
+    .filter(|(_, value)| value.is_empty())
+    .map(|(field, _)| format!("`{field}` must not be empty"))
+    .collect()
+}
+
+/// Optional fields which are not provided in the record keep the value of the existing rule, as
+/// the update of a GSM rule does not clear them either.
+fn to_gateway_status_map(
+    connector: api_enums::Connector,
+    record: gsm_api_types::GsmBulkRecord,
+    existing_rule: Option<&GatewayStatusMap>,
+) -> GatewayStatusMap {
+    GatewayStatusMap {
+        connector: connector.to_string(),
+        flow: record.flow,
+        sub_flow: record.sub_flow,
+        code: record.code,
+        message: record.message,
+        status: record.status,
+        router_error: record
+            .router_error
+            .or_else(|| existing_rule.and_then(|rule| rule.router_error.clone())),
+        unified_code: record
+            .unified_code
+            .or_else(|| existing_rule.and_then(|rule| rule.unified_code.clone())),
+        unified_message: record
+            .unified_message
+            .or_else(|| existing_rule.and_then(|rule| rule.unified_message.clone())),
+        error_category: record
+            .error_category
+            .or_else(|| existing_rule.and_then(|rule| rule.error_category)),
+        feature_data: common_types::domain::GsmFeatureData::Retry(
+            common_types::domain::RetryFeatureData {
+                step_up_possible: record.step_up_possible,
+                clear_pan_possible: record.clear_pan_possible,
+                alternate_network_possible: record.alternate_network_possible,
+                decision: record.decision,
+            },
+        ),
+        feature: api_enums::GsmFeature::Retry,
+        standardised_code: record
+            .standardised_code
+            .or_else(|| existing_rule.and_then(|rule| rule.standardised_code)),
+        description: record
+            .description
+            .or_else(|| existing_rule.and_then(|rule| rule.description.clone())),
+        user_guidance_message: record
+            .user_guidance_message
+            .or_else(|| existing_rule.and_then(|rule| rule.user_guidance_message.clone())),
+    }
+}
+
+fn get_changed_fields(existing_rule: &GatewayStatusMap, rule: &GatewayStatusMap) -> Vec<String> {
+    let existing_retry_data = existing_rule.feature_data.get_retry_feature_data();
+    let retry_data = rule.feature_data.get_retry_feature_data();
+    let retry_field_changed = |field: fn(&common_types::domain::RetryFeatureData) -> bool| {
+        existing_retry_data.as_ref().map(field) != retry_data.as_ref().map(field)
+    };
+
+    [
+        ("status", existing_rule.status != rule.status),
+        (
+            "router_error",
+            existing_rule.router_error != rule.router_error,
+        ),
+        (
+            "decision",
+            existing_rule.feature_data.get_decision() != rule.feature_data.get_decision(),
+        ),
+        (
+            "step_up_possible",
+            retry_field_changed(|data| data.is_step_up_possible()),
+        ),
+        (
+            "clear_pan_possible",
+            retry_field_changed(|data| data.is_clear_pan_possible()),
+        ),
+        (
+            "alternate_network_possible",
+            retry_field_changed(|data| data.is_alternate_network_possible()),
+        ),
+        (
+            "unified_code",
+            existing_rule.unified_code != rule.unified_code,
+        ),
+        (
+            "unified_message",
+            existing_rule.unified_message != rule.unified_message,
+        ),
+        (
+            "error_category",
+            existing_rule.error_category != rule.error_category,
+        ),
+        ("feature", existing_rule.feature != rule.feature),
+        (
+            "standardised_code",
+            existing_rule.standardised_code != rule.standardised_code,
+        ),
+        ("description", existing_rule.description != rule.description),
+        (
+            "user_guidance_message",
+            existing_rule.user_guidance_message != rule.user_guidance_message,
+        ),
+    ]
+    .into_iter()
+    .filter(|(_, changed)| *changed)
+    .map(|(field, _)| field.to_string())
+    .collect()
+}
+
+fn to_bulk_record(rule: GatewayStatusMap) -> gsm_api_types::GsmBulkRecord {
+    let retry_feature_data = rule.feature_data.get_retry_feature_data();
+
+    gsm_api_types::GsmBulkRecord {
+        connector: rule.connector,
+        flow: rule.flow,
+        sub_flow: rule.sub_flow,
+        code: rule.code,
+        message: rule.message,
+        status: rule.status,
+        router_error: rule.router_error,
+        decision: rule.feature_data.get_decision(),
+        step_up_possible: retry_feature_data
+            .as_ref()
+            .is_some_and(|data| data.is_step_up_possible()),
+        clear_pan_possible: retry_feature_data
+            .as_ref()
+            .is_some_and(|data| data.is_clear_pan_possible()),
+        alternate_network_possible: retry_feature_data
+            .as_ref()
+            .is_some_and(|data| data.is_alternate_network_possible()),
+        unified_code: rule.unified_code,
+        unified_message: rule.unified_message,
+        error_category: rule.error_category,
+        standardised_code: rule.standardised_code,
+        description: rule.description,
+        user_guidance_message: rule.user_guidance_message,
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn export_gsm_rules(
+    state: SessionState,
+    request: gsm_api_types::GsmExportRequest,
+) -> RouterResponse<gsm_api_types::GsmExportResponse> {
+    let rules = state
+        .store
+        .list_gsm_rules(request.connector)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list GSM rules")?
+        .into_iter()
+        .map(to_bulk_record)
+        .collect::<Vec<_>>();
+
+    match request.format.unwrap_or_default() {
+        gsm_api_types::GsmExportFormat::Json => Ok(ApplicationResponse::Json(
+            gsm_api_types::GsmExportResponse { rules },
+        )),
+        gsm_api_types::GsmExportFormat::Csv => {
+            let mut writer = csv::Writer::from_writer(Vec::new());
+            for rule in &rules {
+                writer
+                    .serialize(rule)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to serialize GSM rule as CSV")?;
+            }
+            let csv = writer
+                .into_inner()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to write GSM rules CSV")?;
+
+            Ok(ApplicationResponse::FileData((csv, mime::TEXT_CSV)))
+        }
+    }
+}
+
+/// Reports the connector errors seen in payment attempts for which no GSM rule exists in the
+/// flow, most frequent first, so that the most common ones can be mapped first.
+#[cfg(feature = "olap")]
+#[instrument(skip_all)]
+pub async fn retrieve_unmapped_errors(
+    state: SessionState,
+    request: gsm_api_types::GsmUnmappedErrorsRequest,
+) -> RouterResponse<gsm_api_types::GsmUnmappedErrorsResponse> {
+    if !(1..=MAX_UNMAPPED_ERRORS_LOOKBACK_DAYS).contains(&request.lookback_days) {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "`lookback_days` must be between 1 and {MAX_UNMAPPED_ERRORS_LOOKBACK_DAYS}"
+            ),
+        }
+        .into());
+    }
+
+    let limit = request.limit.unwrap_or(DEFAULT_UNMAPPED_ERRORS_LIMIT);
+    if !(1..=MAX_UNMAPPED_ERRORS_LIMIT).contains(&limit) {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("`limit` must be between 1 and {MAX_UNMAPPED_ERRORS_LIMIT}"),
+        }
+        .into());
+    }
+
+    let flow = request
+        .flow
+        .unwrap_or_else(|| consts::PAYMENT_FLOW_STR.to_string());
+    let sub_flow = request
+        .sub_flow
+        .unwrap_or_else(|| consts::AUTHORIZE_FLOW_STR.to_string());
+
+    let mapped_errors = state
+        .store
+        .list_gsm_rules(request.connector.clone())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list GSM rules")?
+        .into_iter()
+        .filter(|rule| rule.flow == flow && rule.sub_flow == sub_flow)
+        .map(|rule| (rule.connector, rule.code, rule.message))
+        .collect::<HashSet<_>>();
+
+    let end_time = date_time::now();
+    let time_range = TimeRange {
+        start_time: end_time - time::Duration::days(request.lookback_days.into()),
+        end_time: Some(end_time),
+    };
+    let error_codes = state
+        .pool
+        .get_connector_error_codes(&time_range, request.connector.as_deref())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to load connector error codes of payment attempts")?;
+
+    let mut unmapped_errors = HashMap::<(String, String, String), u64>::new();
+    for row in error_codes {
+        let (Some(connector), Some(code)) = (row.connector, row.error_code) else {
+            continue;
+        };
+        let key = (connector, code, row.error_message.unwrap_or_default());
+        if !mapped_errors.contains(&key) {
+            *unmapped_errors.entry(key).or_default() +=
+                u64::try_from(row.count.unwrap_or_default()).unwrap_or_default();
+        }
+    }
+
+    let mut errors = unmapped_errors
+        .into_iter()
+        .map(
+            |((connector, code, message), count)| gsm_api_types::GsmUnmappedError {
+                connector,
+                code,
+                message,
+                count,
+            },
+        )
+        .collect::<Vec<_>>();
+    errors.sort_by(|a, b| {
+        b.count.cmp(&a.count).then_with(|| {
+            (&a.connector, &a.code, &a.message).cmp(&(&b.connector, &b.code, &b.message))
+        })
+    });
+
+    let total_unmapped_attempts = errors.iter().map(|error| error.count).sum();
+    let total_unmapped_errors = errors.len();
+    errors.truncate(limit.into());
+
+    Ok(ApplicationResponse::Json(
+        gsm_api_types::GsmUnmappedErrorsResponse {
+            flow,
+            sub_flow,
+            total_unmapped_attempts,
+            total_unmapped_errors,
+            errors,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn csv_form(csv: &str) -> GsmBulkImportForm {
+        GsmBulkImportForm {
+            file: Bytes {
+                data: bytes::Bytes::from(csv.to_string()),
+                content_type: None,
+                file_name: None,
+            },
+            dry_run: None,
+        }
+    }
+
+    fn bulk_record() -> gsm_api_types::GsmBulkRecord {
+        gsm_api_types::GsmBulkRecord {
+            connector: "stripe".to_string(),
+            flow: "Authorize".to_string(),
+            sub_flow: "sub_flow".to_string(),
+            code: "card_declined".to_string(),
+            message: "Your card was declined".to_string(),
+            status: "Failure".to_string(),
+            router_error: None,
+            decision: api_enums::GsmDecision::Retry,
+            step_up_possible: false,
+            clear_pan_possible: false,
+            alternate_network_possible: false,
+            unified_code: None,
+            unified_message: None,
+            error_category: None,
+            standardised_code: None,
+            description: None,
+            user_guidance_message: None,
+        }
+    }
+
+    #[test]
+    fn test_csv_line_numbers_account_for_header_and_multiline_fields() {
+        let csv = concat!(
+            "connector,flow,sub_flow,code,message,status,decision\n",
+            "stripe,Authorize,sub_flow,card_declined,Your card was declined,Failure,retry\n",
+            "stripe,Authorize,sub_flow,expired_card,\"Your card\nhas expired\",Failure,do_default\n",
+            "adyen,Authorize,sub_flow,2,Refused,Failure,retry\n",
+        );
+
+        let request = get_bulk_import_request(csv_form(csv)).unwrap();
+
+        assert_eq!(request.rules.len(), 3);
+        assert_eq!(request.line_numbers, Some(vec![2, 3, 5]));
+        assert_eq!(
+            request.rules.get(1).map(|rule| rule.message.as_str()),
+            Some("Your card\nhas expired")
+        );
+        assert!(!request.dry_run);
+    }
+
+    #[test]
+    fn test_csv_errors_report_line_number() {
+        let csv = concat!(
+            "connector,flow,sub_flow,code,message,status,decision\n",
+            "stripe,Authorize,sub_flow,card_declined,Your card was declined,Failure,retry\n",
+            "stripe,Authorize,sub_flow,expired_card,Your card has expired,Failure,maybe\n",
+        );
+
+        let error = get_bulk_import_request(csv_form(csv)).unwrap_err();
+
+        assert!(matches!(
+            error,
+            errors::ApiErrorResponse::PreconditionFailed { message } if message.starts_with("Line 3:")
+        ));
+    }
+
+    #[test]
+    fn test_validate_record_reports_empty_fields() {
+        let record = gsm_api_types::GsmBulkRecord {
+            code: String::new(),
+            status: String::new(),
+            ..bulk_record()
+        };
+
+        assert_eq!(
+            validate_record(&record),
+            vec!["`code` must not be empty", "`status` must not be empty"]
+        );
+        assert!(validate_record(&bulk_record()).is_empty());
+    }
+
+    #[test]
+    fn test_missing_optional_fields_keep_existing_values() {
+        let existing_rule = GatewayStatusMap {
+            unified_code: Some("UE_1000".to_string()),
+            description: Some("Declined by the issuer".to_string()),
+            ..to_gateway_status_map(api_enums::Connector::Stripe, bulk_record(), None)
+        };
+        let record = gsm_api_types::GsmBulkRecord {
+            unified_message: Some("Card declined".to_string()),
+            ..bulk_record()
+        };
+
+        let rule =
+            to_gateway_status_map(api_enums::Connector::Stripe, record, Some(&existing_rule));
+
+        assert_eq!(rule.unified_code, existing_rule.unified_code);
+        assert_eq!(rule.description, existing_rule.description);
+        assert_eq!(
+            get_changed_fields(&existing_rule, &rule),
+            vec!["unified_message"]
+        );
+    }
+
+    #[test]
+    fn test_changed_fields_include_retry_feature_data() {
+        let existing_rule =
+            to_gateway_status_map(api_enums::Connector::Stripe, bulk_record(), None);
+        let record = gsm_api_types::GsmBulkRecord {
+            decision: api_enums::GsmDecision::DoDefault,
+            step_up_possible: true,
+            ..bulk_record()
+        };
+
+        let rule =
+            to_gateway_status_map(api_enums::Connector::Stripe, record, Some(&existing_rule));
+
+        assert_eq!(
+            get_changed_fields(&existing_rule, &rule),
+            vec!["decision", "step_up_possible"]
+        );
+        assert!(get_changed_fields(&existing_rule, &existing_rule).is_empty());
+    }
+}
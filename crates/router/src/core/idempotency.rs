@@ -0,0 +1,424 @@
+use actix_web::HttpRequest;
+use common_utils::{
+    crypto::{self, GenerateDigest},
+    fp_utils::when,
+};
+use error_stack::{report, ResultExt};
+use hyperswitch_masking::Secret;
+use redis_interface::errors::RedisError;
+use router_env::{instrument, logger, tracing};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    api_locking,
+    errors::{self, RouterResult},
+};
+use crate::{
+    configs::settings::IdempotencySettings,
+    headers,
+    routes::{lock_utils, SessionState},
+    services::{api::StoredResponse, ApplicationResponse},
+};
+
+pub const IDEMPOTENCY_PREFIX: &str = "IDEMPOTENCY";
+
+const MAX_IDEMPOTENCY_KEY_LENGTH: usize = 255;
+
+#[derive(Clone, Debug)]
+pub enum IdempotencyAction {
+    // Replay the first response recorded for the key to later requests carrying the same key
+    Enforce { idempotency_key: String },
+    // Idempotency not applicable
+    NotApplicable,
+}
+
+impl IdempotencyAction {
+    /// Idempotency keys are honoured only on methods which are not idempotent by themselves
+    pub fn from_request(request: &HttpRequest, idempotency_settings: &IdempotencySettings) -> Self {
+        let idempotency_key = request
+            .headers()
+            .get(headers::IDEMPOTENCY_KEY)
+            .and_then(|value| value.to_str().ok())
+            .map(str::trim)
+            .filter(|value| !value.is_empty());
+
+        match idempotency_key {
+            Some(idempotency_key)
+                if idempotency_settings.enabled && !request.method().is_idempotent() =>
+            {
+                Self::Enforce {
+                    idempotency_key: idempotency_key.to_owned(),
+                }
+            }
+            _ => Self::NotApplicable,
+        }
+    }
+
+    pub fn get_idempotency_input<T: Serialize>(
+        self,
+        flow: &impl router_env::types::FlowMetric,
+        request: &HttpRequest,
+        payload: &T,
+    ) -> RouterResult<Option<IdempotencyInput>> {
+        match self {
+            Self::Enforce { idempotency_key } => {
+                when(idempotency_key.len() > MAX_IDEMPOTENCY_KEY_LENGTH, || {
+                    Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                        message: format!(
+                            "`{}` header must not be longer than {MAX_IDEMPOTENCY_KEY_LENGTH} characters",
+                            headers::IDEMPOTENCY_KEY
+                        ),
+                    }))
+                })?;
+
+                let serialized_payload = serde_json::to_vec(payload)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to serialize request for idempotency fingerprint")?;
+                let fingerprint_input = [
+                    flow.to_string().as_bytes(),
+                    request.path().as_bytes(),
+                    serialized_payload.as_slice(),
+                ]
+                .join(&b'\n');
+                let request_fingerprint = crypto::Sha256
+                    .generate_digest(&fingerprint_input)
+                    .map(hex::encode)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to generate idempotency fingerprint")?;
+
+                Ok(Some(IdempotencyInput {
+                    idempotency_key,
+                    request_fingerprint,
+                }))
+            }
+            Self::NotApplicable => Ok(None),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct IdempotencyInput {
+    pub idempotency_key: String,
+    pub request_fingerprint: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct IdempotencyRecord {
+    request_fingerprint: String,
+    response: StoredResponse,
+}
+
+impl IdempotencyInput {
+    fn get_redis_key(&self, merchant_id: &common_utils::id_type::MerchantId) -> String {
+        format!(
+            "{}_{}_{}",
+            IDEMPOTENCY_PREFIX,
+            merchant_id.get_string_repr(),
+            self.idempotency_key
+        )
+    }
+
+    /// Locking on the key makes concurrent retries wait for the first request to complete, so
+    /// that they can be served its response.
+    pub fn get_locking_input(&self) -> api_locking::LockingInput {
+        api_locking::LockingInput {
+            unique_locking_key: self.idempotency_key.clone(),
+            api_identifier: lock_utils::ApiIdentifier::Idempotency,
+            override_lock_retries: None,
+        }
+    }
+
+    #[instrument(skip_all)]
+    pub async fn get_stored_response(
+        &self,
+        state: &SessionState,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> RouterResult<Option<StoredResponse>> {
+        let redis_conn = state
+            .store
+            .get_redis_conn()
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+        match redis_conn
+            .get_and_deserialize_key::<IdempotencyRecord>(
+                &self.get_redis_key(merchant_id).as_str().into(),
+                "IdempotencyRecord",
+            )
+            .await
+        {
+            Ok(record) if record.request_fingerprint == self.request_fingerprint => {
+                logger::info!(
+                    "Replaying stored response for idempotency key {}",
+                    self.idempotency_key
+                );
+                Ok(Some(record.response))
+            }
+            Ok(_) => Err(report!(errors::ApiErrorResponse::IdempotencyKeyReused {
+                idempotency_key: self.idempotency_key.clone(),
+            })),
+            Err(err) if err.current_context() == &RedisError::NotFound => Ok(None),
+            Err(err) => Err(err).change_context(errors::ApiErrorResponse::InternalServerError),
+        }
+    }
+
+    /// Only JSON responses are recorded, requests which failed can be retried with the same key.
+    #[instrument(skip_all)]
+    pub async fn store_response<Q: Serialize>(
+        &self,
+        state: &SessionState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        response: &ApplicationResponse<Q>,
+    ) -> RouterResult<()> {
+        // The status code must match the one `server_wrap` sends the response with
+        let (data, status_code) = match response {
+            ApplicationResponse::Json(data) => (data, actix_web::http::StatusCode::OK),
+            ApplicationResponse::JsonWithHeaders((data, headers)) => (
+                data,
+                state
+                    .conf
+                    .proxy_status_mapping
+                    .extract_connector_http_status_code(headers)
+                    .unwrap_or(actix_web::http::StatusCode::OK),
+            ),
+            ApplicationResponse::StatusOk
+            | ApplicationResponse::TextPlain(_)
+            | ApplicationResponse::JsonForRedirection(_)
+            | ApplicationResponse::Form(_)
+            | ApplicationResponse::PaymentLinkForm(_)
+            | ApplicationResponse::FileData(_)
+            | ApplicationResponse::GenericLinkForm(_)
+            | ApplicationResponse::IncomingWebhookEvent { .. }
+            | ApplicationResponse::IdempotentReplay(_) => return Ok(()),
+        };
+        let body = serde_json::to_value(data)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to serialize response for idempotency record")?;
+
+        let record = IdempotencyRecord {
+            request_fingerprint: self.request_fingerprint.clone(),
+            response: StoredResponse {
+                status_code: status_code.as_u16(),
+                body: Secret::new(body),
+            },
+        };
+
+        state
+            .store
+            .get_redis_conn()
+            .change_context(errors::ApiErrorResponse::InternalServerError)?
+            .serialize_and_set_key_with_expiry(
+                &self.get_redis_key(merchant_id).as_str().into(),
+                record,
+                i64::from(state.conf.idempotency.ttl_in_seconds),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to store idempotency record in redis")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+    use std::{borrow::Cow, sync::Arc};
+
+    use hyperswitch_masking::Maskable;
+    use router_env::RequestId;
+    use tokio::sync::oneshot;
+
+    use super::*;
+    use crate::{
+        routes::{
+            self,
+            app::{settings::Settings, SessionStateInfo, StorageImpl},
+        },
+        services,
+    };
+
+    async fn get_session_state(proxy_connector_http_status_code: bool) -> SessionState {
+        let mut conf = Settings::new().expect("invalid settings");
+        conf.proxy_status_mapping.proxy_connector_http_status_code =
+            proxy_connector_http_status_code;
+        let tx: oneshot::Sender<()> = oneshot::channel().0;
+        let app_state = Box::pin(routes::AppState::with_storage(
+            conf,
+            StorageImpl::Mock,
+            tx,
+            Box::new(services::MockApiClient),
+            env!("CARGO_PKG_NAME"),
+        ))
+        .await;
+        let mut state = Arc::new(app_state)
+            .get_session_state(
+                &common_utils::id_type::TenantId::try_from_string("public".to_string()).unwrap(),
+                None,
+                || {},
+            )
+            .unwrap();
+        state.add_request_id(RequestId::new_generated());
+        state
+    }
+
+    fn get_idempotency_input(request_fingerprint: &str) -> IdempotencyInput {
+        IdempotencyInput {
+            idempotency_key: format!(
+                "idempotency_{}",
+                common_utils::generate_time_ordered_id("key")
+            ),
+            request_fingerprint: request_fingerprint.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stored_response_is_replayed_with_its_status() {
+        let state = get_session_state(true).await;
+        let merchant_id =
+            common_utils::id_type::MerchantId::try_from(Cow::from("merchant_1")).unwrap();
+        let input = get_idempotency_input("fingerprint");
+
+        assert!(input
+            .get_stored_response(&state, &merchant_id)
+            .await
+            .unwrap()
+            .is_none());
+
+        let response = ApplicationResponse::JsonWithHeaders((
+            serde_json::json!({ "status": "failed" }),
+            vec![(
+                headers::X_CONNECTOR_HTTP_STATUS_CODE.to_string(),
+                Maskable::new_normal("402".to_string()),
+            )],
+        ));
+        input
+            .store_response(&state, &merchant_id, &response)
+            .await
+            .unwrap();
+
+        let stored_response = input
+            .get_stored_response(&state, &merchant_id)
+            .await
+            .unwrap()
+            .expect("stored response must be replayed");
+        assert_eq!(stored_response.status_code, 402);
+        assert_eq!(
+            hyperswitch_masking::ExposeInterface::expose(stored_response.body),
+            serde_json::json!({ "status": "failed" })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_json_response_is_stored_with_ok_status() {
+        let state = get_session_state(false).await;
+        let merchant_id =
+            common_utils::id_type::MerchantId::try_from(Cow::from("merchant_1")).unwrap();
+        let input = get_idempotency_input("fingerprint");
+
+        input
+            .store_response(
+                &state,
+                &merchant_id,
+                &ApplicationResponse::Json(serde_json::json!({ "status": "succeeded" })),
+            )
+            .await
+            .unwrap();
+
+        let stored_response = input
+            .get_stored_response(&state, &merchant_id)
+            .await
+            .unwrap()
+            .expect("stored response must be replayed");
+        assert_eq!(stored_response.status_code, 200);
+    }
+
+    #[tokio::test]
+    async fn test_key_reused_with_different_request_is_rejected() {
+        let state = get_session_state(false).await;
+        let merchant_id =
+            common_utils::id_type::MerchantId::try_from(Cow::from("merchant_1")).unwrap();
+        let input = get_idempotency_input("fingerprint");
+
+        input
+            .store_response(
+                &state,
+                &merchant_id,
+                &ApplicationResponse::Json(serde_json::json!({ "status": "succeeded" })),
+            )
+            .await
+            .unwrap();
+
+        let conflicting_input = IdempotencyInput {
+            request_fingerprint: "other_fingerprint".to_string(),
+            ..input
+        };
+        let error = conflicting_input
+            .get_stored_response(&state, &merchant_id)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::IdempotencyKeyReused { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_request_waits_for_in_flight_request() {
+        let in_flight_state = get_session_state(false).await;
+        let concurrent_state = get_session_state(false).await;
+        let merchant_id =
+            common_utils::id_type::MerchantId::try_from(Cow::from("merchant_1")).unwrap();
+        let input = get_idempotency_input("fingerprint");
+        let lock_action = api_locking::LockAction::Hold {
+            input: api_locking::LockingInput {
+                override_lock_retries: Some(1),
+                ..input.get_locking_input()
+            },
+        };
+
+        lock_action
+            .clone()
+            .perform_locking_action(&in_flight_state, merchant_id.clone())
+            .await
+            .unwrap();
+
+        let error = lock_action
+            .clone()
+            .perform_locking_action(&concurrent_state, merchant_id.clone())
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::ResourceBusy
+        ));
+
+        // Once the in-flight request records its response and releases the lock, the concurrent
+        // request is served the recorded response
+        input
+            .store_response(
+                &in_flight_state,
+                &merchant_id,
+                &ApplicationResponse::Json(serde_json::json!({ "status": "succeeded" })),
+            )
+            .await
+            .unwrap();
+        lock_action
+            .clone()
+            .free_lock_action(&in_flight_state, merchant_id.clone())
+            .await
+            .unwrap();
+
+        lock_action
+            .clone()
+            .perform_locking_action(&concurrent_state, merchant_id.clone())
+            .await
+            .unwrap();
+        assert!(input
+            .get_stored_response(&concurrent_state, &merchant_id)
+            .await
+            .unwrap()
+            .is_some());
+        lock_action
+            .free_lock_action(&concurrent_state, merchant_id)
+            .await
+            .unwrap();
+    }
+}
@@ -43,10 +43,12 @@ counter_metric!(
 
 counter_metric!(WEBHOOK_INCOMING_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_INCOMING_FILTERED_COUNT, GLOBAL_METER);
+counter_metric!(WEBHOOK_INCOMING_REJECTED_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_SOURCE_VERIFIED_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_OUTGOING_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_OUTGOING_RECEIVED_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_OUTGOING_NOT_RECEIVED_COUNT, GLOBAL_METER);
+counter_metric!(WEBHOOK_ENDPOINT_PAUSED_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_PAYMENT_NOT_FOUND, GLOBAL_METER);
 counter_metric!(
     WEBHOOK_EVENT_TYPE_IDENTIFICATION_FAILURE_COUNT,
@@ -74,6 +76,8 @@ counter_metric!(ROUTING_RETRIEVE_LINK_CONFIG, GLOBAL_METER);
 counter_metric!(ROUTING_RETRIEVE_LINK_CONFIG_SUCCESS_RESPONSE, GLOBAL_METER);
 counter_metric!(ROUTING_UNLINK_CONFIG, GLOBAL_METER);
 counter_metric!(ROUTING_UNLINK_CONFIG_SUCCESS_RESPONSE, GLOBAL_METER);
+counter_metric!(ROUTING_UPDATE_CANARY, GLOBAL_METER);
+counter_metric!(ROUTING_UPDATE_CANARY_SUCCESS_RESPONSE, GLOBAL_METER);
 counter_metric!(ROUTING_UPDATE_CONFIG, GLOBAL_METER);
 counter_metric!(ROUTING_UPDATE_CONFIG_SUCCESS_RESPONSE, GLOBAL_METER);
 counter_metric!(ROUTING_UPDATE_CONFIG_FOR_PROFILE, GLOBAL_METER);
@@ -4622,6 +4622,9 @@ pub async fn build_merchant_enabled_pms_context(
             surcharge_amount: None,
             tax_amount: None,
             routing_approach,
+            routing_algorithm_id: None,
+            expected_processing_fee: None,
+            dcc_details: None,
             is_stored_credential: None,
         };
 
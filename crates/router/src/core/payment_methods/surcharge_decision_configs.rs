@@ -36,18 +36,18 @@ use crate::{
 };
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-pub struct VirInterpreterBackendCacheWrapper {
-    cached_algorithm: backend::VirInterpreterBackend<SurchargeDecisionConfigs>,
+pub struct CompiledBackendCacheWrapper {
+    cached_algorithm: backend::CompiledBackend<SurchargeDecisionConfigs>,
     merchant_surcharge_configs: surcharge_decision_configs::MerchantSurchargeConfigs,
 }
 
-impl TryFrom<SurchargeDecisionManagerRecord> for VirInterpreterBackendCacheWrapper {
+impl TryFrom<SurchargeDecisionManagerRecord> for CompiledBackendCacheWrapper {
     type Error = error_stack::Report<ConfigError>;
 
     fn try_from(value: SurchargeDecisionManagerRecord) -> Result<Self, Self::Error> {
-        let cached_algorithm = backend::VirInterpreterBackend::with_program(value.algorithm)
+        let cached_algorithm = backend::CompiledBackend::with_program(value.algorithm)
             .change_context(ConfigError::DslBackendInitError)
-            .attach_printable("Error initializing DSL interpreter backend")?;
+            .attach_printable("Error compiling DSL program")?;
         let merchant_surcharge_configs = value.merchant_surcharge_configs;
         Ok(Self {
             cached_algorithm,
@@ -58,7 +58,7 @@ impl TryFrom<SurchargeDecisionManagerRecord> for VirInterpreterBackendCacheWrapp
 
 enum SurchargeSource {
     /// Surcharge will be generated through the surcharge rules
-    Generate(VirInterpreterBackendCacheWrapper),
+    Generate(CompiledBackendCacheWrapper),
     /// Surcharge is predefined by the merchant through payment create request
     Predetermined(payments::RequestSurchargeDetails),
 }
@@ -491,7 +491,7 @@ pub async fn ensure_algorithm_cached(
     store: &dyn StorageInterface,
     merchant_id: &common_utils::id_type::MerchantId,
     algorithm_id: &str,
-) -> ConditionalConfigResult<VirInterpreterBackendCacheWrapper> {
+) -> ConditionalConfigResult<CompiledBackendCacheWrapper> {
     let key = merchant_id.get_surcharge_dsk_key();
 
     let value_to_cache = || async {
@@ -501,9 +501,9 @@ pub async fn ensure_algorithm_cached(
             .parse_struct("Program")
             .change_context(errors::StorageError::DeserializationFailed)
             .attach_printable("Error parsing routing algorithm from configs")?;
-        VirInterpreterBackendCacheWrapper::try_from(record)
+        CompiledBackendCacheWrapper::try_from(record)
             .change_context(errors::StorageError::ValueNotFound("Program".to_string()))
-            .attach_printable("Error initializing DSL interpreter backend")
+            .attach_printable("Error compiling DSL program")
     };
     let interpreter = cache::get_or_populate_in_memory(
         store.get_cache_store().as_ref(),
@@ -519,7 +519,7 @@ pub async fn ensure_algorithm_cached(
 
 pub fn execute_dsl_and_get_conditional_config(
     backend_input: dsl_inputs::BackendInput,
-    interpreter: &backend::VirInterpreterBackend<SurchargeDecisionConfigs>,
+    interpreter: &backend::CompiledBackend<SurchargeDecisionConfigs>,
 ) -> ConditionalConfigResult<SurchargeDecisionConfigs> {
     let routing_output = interpreter
         .execute(backend_input)
@@ -1,7 +1,11 @@
 use hyperswitch_domain_models::mandates;
 pub mod access_token;
+#[cfg(feature = "v1")]
+pub mod authorization_expiry;
 pub mod conditional_configs;
 pub mod customers;
+#[cfg(feature = "v1")]
+pub mod dynamic_currency_conversion;
 pub mod flows;
 pub mod gateway;
 pub mod helpers;
@@ -12,6 +16,8 @@ pub mod client_session;
 #[cfg(feature = "retry")]
 pub mod retry;
 pub mod routing;
+#[cfg(feature = "v1")]
+pub mod scheduled_capture;
 #[cfg(feature = "v2")]
 pub mod session_operation;
 pub mod tokenization;
@@ -2242,7 +2248,7 @@ where
 pub async fn call_decision_manager<F, D>(
     state: &SessionState,
     processor: &domain::Processor,
-    _business_profile: &domain::Profile,
+    business_profile: &domain::Profile,
     payment_data: &D,
 ) -> RouterResult<Option<enums::AuthenticationType>>
 where
@@ -2259,7 +2265,8 @@ where
         payment_data.get_address(),
         payment_data.get_recurring_details(),
         payment_data.get_currency(),
-    );
+    )
+    .with_routing_timezone(business_profile.get_routing_timezone());
     let algorithm_ref: api::routing::RoutingAlgorithmRef = processor
         .get_account()
         .routing_algorithm
@@ -9847,6 +9854,9 @@ where
     /// Fields from the update request payload used to compare against
     /// the stored payment intent. Populated only for the payment-update flow.
     pub update_request_fields: Option<PaymentDataUpdateRequestFields>,
+    /// The time at which the payment must be captured, when a capture schedule is set while
+    /// confirming the payment. The capture is scheduled once the attempt is updated.
+    pub scheduled_capture_time: Option<time::PrimitiveDateTime>,
 }
 
 /// Decrypts an Apple Pay wallet token for the pre-confirm eligibility check, using the specific
@@ -11319,7 +11329,8 @@ where
         payment_data.get_address(),
         payment_data.get_recurring_details(),
         payment_data.get_currency(),
-    );
+    )
+    .with_routing_timezone(business_profile.get_routing_timezone());
 
     let fallback_config = routing_helpers::get_merchant_default_config(
         &*state.clone().store,
@@ -11957,9 +11968,15 @@ where
         payment_data.get_address(),
         payment_data.get_recurring_details(),
         payment_data.get_currency(),
-    );
+    )
+    .with_routing_timezone(business_profile.get_routing_timezone());
 
     // Straight through routing block
+    // The algorithm passed in the request is recorded in the routing info of the attempt
+    if let Some(algorithm) = &request_straight_through {
+        routing_data.routing_info.algorithm = Some(algorithm.clone());
+    }
+
     let request_straight_through_routing_stage =
         request_straight_through.map(|algo| StraightThroughRoutingStage {
             algorithm: Arc::new(algo),
@@ -11977,6 +11994,7 @@ where
         request_straight_through_routing_stage.or(algorithmic_straight_through_routing_stage);
 
     let creds_identifier = payment_data.get_creds_identifier();
+    let straight_through_backend_input = backend_input.clone();
     let txn = TransactionData::Payment(transaction_data.clone());
     let txn_data = transaction_data.clone();
     let fallback = fallback_config.clone();
@@ -11985,6 +12003,8 @@ where
         fallback.clone(),
         common_enums::RoutingApproach::DefaultFallback,
         true,
+        None,
+        routing::ExpectedProcessingFees::new(),
     );
 
     let routing_future: BoxFuture<
@@ -11993,12 +12013,17 @@ where
             Vec<api_models::routing::RoutableConnectorChoice>,
             common_enums::RoutingApproach,
             bool,
+            Option<id_type::RoutingId>,
+            routing::ExpectedProcessingFees,
         )>,
     > = straight_through_routing_stage
         .map(|stage| {
             async move {
                 stage
-                    .route(StraightThroughRoutingInput { creds_identifier })
+                    .route(StraightThroughRoutingInput {
+                        creds_identifier,
+                        backend_input: &straight_through_backend_input,
+                    })
                     .await
                     .inspect_err(|err| {
                         logger::error!(error=?err, "straight-through routing failed");
@@ -12009,6 +12034,8 @@ where
                             out.connectors.connectors,
                             stage.routing_approach(),
                             out.check_eligibility,
+                            None,
+                            routing::ExpectedProcessingFees::new(),
                         )
                     })
             }
@@ -12034,14 +12061,21 @@ where
                         out.connectors,
                         out.routing_approach,
                         out.requires_eligibility,
+                        out.routing_algorithm_id,
+                        out.expected_processing_fees,
                     )
                 })
             }
             .boxed()
         });
 
-    let (connectors, routing_approach, requires_eligibility) =
-        routing_future.await.unwrap_or(fallback_outcome);
+    let (
+        connectors,
+        routing_approach,
+        requires_eligibility,
+        routing_algorithm_id,
+        expected_processing_fees,
+    ) = routing_future.await.unwrap_or(fallback_outcome);
 
     let final_connectors = if requires_eligibility {
         routing::perform_eligibility_analysis_with_fallback(
@@ -12066,6 +12100,12 @@ where
 
     core_routing::log_connectors("eligibility", &final_connectors);
 
+    let expected_processing_fee = final_connectors
+        .first()
+        .and_then(|connector| connector.merchant_connector_id.as_ref())
+        .and_then(|merchant_connector_id| expected_processing_fees.get(merchant_connector_id))
+        .copied();
+
     let connector_data = final_connectors
         .into_iter()
         .map(|conn| {
@@ -12082,6 +12122,8 @@ where
         .attach_printable("euclid: Invalid connector name received")?;
 
     payment_data.set_routing_approach_in_attempt(Some(routing_approach));
+    payment_data.set_routing_algorithm_id_in_attempt(routing_algorithm_id);
+    payment_data.set_expected_processing_fee_in_attempt(expected_processing_fee);
 
     plan_payment_execution_after_routing(
         &state,
@@ -13008,15 +13050,20 @@ pub async fn static_dynamic_routing_v1_for_payments(
     backend_input: euclid::backend::BackendInput,
     fallback_config: Vec<api_models::routing::RoutableConnectorChoice>,
 ) -> RouterResult<routing::RoutingConnectorOutcomeWithApproachAndEligibility> {
-    let (static_connectors, static_approach, static_is_volume_split) =
-        routing::perform_static_routing_locally(
-            state,
-            business_profile,
-            &payment_dsl_input,
-            &backend_input,
-            &fallback_config,
-        )
-        .await?;
+    let (
+        static_connectors,
+        static_approach,
+        static_is_volume_split,
+        routing_algorithm_id,
+        expected_processing_fees,
+    ) = routing::perform_static_routing_locally(
+        state,
+        business_profile,
+        &payment_dsl_input,
+        &backend_input,
+        &fallback_config,
+    )
+    .await?;
 
     let (connectors, routing_approach) = routing::perform_hybrid_routing_if_enabled(
         state,
@@ -13035,6 +13082,8 @@ pub async fn static_dynamic_routing_v1_for_payments(
         connectors,
         routing_approach,
         requires_eligibility: true,
+        routing_algorithm_id,
+        expected_processing_fees,
     })
 }
 
@@ -13073,7 +13122,10 @@ pub async fn route_connector_v1_for_payouts(
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Could not decode merchant routing algorithm ref")?
             .unwrap_or_default();
-        algorithm_ref.algorithm_id
+        routing::get_routing_algorithm_id_for_transaction(
+            &algorithm_ref,
+            transaction_data.payout_attempt.payout_id.get_string_repr(),
+        )
     };
 
     let (connectors, _) = routing::perform_static_routing_v1(
@@ -15241,6 +15293,16 @@ pub trait OperationSessionSetters<F> {
         external_vault_session_details: Option<api::VaultDetails>,
     );
     fn set_routing_approach_in_attempt(&mut self, routing_approach: Option<enums::RoutingApproach>);
+    #[cfg(feature = "v1")]
+    fn set_routing_algorithm_id_in_attempt(
+        &mut self,
+        routing_algorithm_id: Option<id_type::RoutingId>,
+    );
+    #[cfg(feature = "v1")]
+    fn set_expected_processing_fee_in_attempt(
+        &mut self,
+        expected_processing_fee: Option<MinorUnit>,
+    );
 
     fn set_connector_request_reference_id_in_payment_attempt(
         &mut self,
@@ -15615,6 +15677,20 @@ impl<F: Clone> OperationSessionSetters<F> for PaymentData<F> {
         self.payment_attempt.routing_approach = routing_approach;
     }
 
+    fn set_routing_algorithm_id_in_attempt(
+        &mut self,
+        routing_algorithm_id: Option<id_type::RoutingId>,
+    ) {
+        self.payment_attempt.routing_algorithm_id = routing_algorithm_id;
+    }
+
+    fn set_expected_processing_fee_in_attempt(
+        &mut self,
+        expected_processing_fee: Option<MinorUnit>,
+    ) {
+        self.payment_attempt.expected_processing_fee = expected_processing_fee;
+    }
+
     fn set_connector_response_reference_id(&mut self, reference_id: Option<String>) {
         self.payment_attempt.connector_response_reference_id = reference_id;
     }
@@ -0,0 +1,376 @@
+use api_models::webhooks;
+use common_utils::{date_time, id_type};
+use diesel_models::process_tracker::business_status;
+use error_stack::{report, ResultExt};
+use hyperswitch_domain_models::platform::ProviderMerchantId;
+use hyperswitch_interfaces::webhooks::WebhookResourceData;
+use router_env::{instrument, logger, tracing};
+use scheduler::utils as pt_utils;
+
+use crate::{
+    configs::settings::AuthorizationExpiryConfig,
+    core::{
+        configs::dimension_state,
+        errors::{self, RouterResult, StorageErrorExt},
+        payments::{self as payment_flows, operations},
+        webhooks as webhooks_core,
+    },
+    db::StorageInterface,
+    routes::SessionState,
+    services,
+    types::{
+        api, domain,
+        storage::{self, enums},
+    },
+};
+
+const AUTHORIZATION_EXPIRY_TASK: &str = "AUTHORIZATION_EXPIRY";
+const AUTHORIZATION_EXPIRY_TAGS: [&str; 2] = ["AUTHORIZATION_EXPIRY", "PAYMENT"];
+
+/// Action taken on a payment held for manual capture shortly before its authorization expires.
+/// When the connector supports it, the authorization is extended instead.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuthorizationExpiryAction {
+    /// The authorization is left to expire
+    None,
+    /// A `payment_authorization_expiring` webhook is sent to the merchant
+    Notify,
+    /// The authorized amount is captured
+    Capture,
+    /// The authorization is voided
+    Void,
+}
+
+impl AuthorizationExpiryAction {
+    pub fn is_enabled(self) -> bool {
+        !matches!(self, Self::None)
+    }
+}
+
+/// Returns the action configured for the profile to be taken before authorizations expire.
+pub async fn get_authorization_expiry_action(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    profile_id: &id_type::ProfileId,
+) -> AuthorizationExpiryAction {
+    let dimensions = dimension_state::Dimensions::new()
+        .with_processor_merchant_id(merchant_id.clone().into())
+        .with_provider_merchant_id(ProviderMerchantId::new(merchant_id.clone()))
+        .with_profile_id(profile_id.clone());
+
+    dimensions
+        .get_authorization_expiry_action(
+            state.store.as_ref(),
+            state.superposition_service.as_ref(),
+            Some(profile_id),
+        )
+        .await
+}
+
+/// Returns the time at which the authorization of a payment is expected to expire. The expiry
+/// reported by the connector is preferred, otherwise it is derived from the authorization
+/// validity configured for the connector, card network and merchant category code.
+pub fn get_expected_authorization_expiry(
+    config: &AuthorizationExpiryConfig,
+    payment_attempt: &storage::PaymentAttempt,
+    merchant_category_code: Option<&enums::MerchantCategoryCode>,
+    authorized_at: time::PrimitiveDateTime,
+) -> time::PrimitiveDateTime {
+    payment_attempt.capture_before.unwrap_or_else(|| {
+        let validity = config.get_validity(
+            payment_attempt.connector.as_deref(),
+            payment_attempt.extract_card_network().as_ref(),
+            merchant_category_code,
+        );
+        authorized_at.saturating_add(validity)
+    })
+}
+
+/// Returns the time at which the action before the expiry of an authorization is to be taken,
+/// right away if the authorization is already within the lead time.
+pub fn get_authorization_expiry_schedule_time(
+    config: &AuthorizationExpiryConfig,
+    expires_at: time::PrimitiveDateTime,
+) -> time::PrimitiveDateTime {
+    expires_at
+        .saturating_sub(config.get_lead_time())
+        .max(date_time::now())
+}
+
+/// Returns whether the action before the expiry of an authorization is due, i.e. the
+/// authorization expires within the lead time.
+pub fn is_authorization_expiry_action_due(
+    config: &AuthorizationExpiryConfig,
+    expires_at: time::PrimitiveDateTime,
+    now: time::PrimitiveDateTime,
+) -> bool {
+    expires_at.saturating_sub(config.get_lead_time()) <= now
+}
+
+/// Stores the time at which the authorization of a payment is expected to expire on its attempt,
+/// for the authorization expiry task to act on.
+pub async fn update_expected_authorization_expiry(
+    db: &dyn StorageInterface,
+    key_store: &domain::MerchantKeyStore,
+    storage_scheme: enums::MerchantStorageScheme,
+    payment_attempt: storage::PaymentAttempt,
+    expires_at: time::PrimitiveDateTime,
+) -> RouterResult<storage::PaymentAttempt> {
+    let payment_attempt_update = storage::PaymentAttemptUpdate::AuthorizationExpiryUpdate {
+        expected_authorization_expiry: expires_at,
+        updated_by: storage_scheme.to_string(),
+    };
+
+    db.update_payment_attempt_with_attempt_id(
+        payment_attempt,
+        payment_attempt_update,
+        storage_scheme,
+        key_store,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+    .attach_printable("Failed to store the expected authorization expiry")
+}
+
+fn get_authorization_expiry_process_tracker_id(
+    payment_id: &id_type::PaymentId,
+    merchant_id: &id_type::MerchantId,
+) -> String {
+    pt_utils::get_process_tracker_id(
+        storage::ProcessTrackerRunner::AuthorizationExpiryWorkflow,
+        AUTHORIZATION_EXPIRY_TASK,
+        payment_id.get_string_repr(),
+        merchant_id,
+    )
+}
+
+/// Schedules the action to be taken before the authorization of a payment expires, when the
+/// payment has been authorized for manual capture and an action is configured for its profile.
+/// The expected expiry is stored on the payment attempt. Failures are only logged since the
+/// authorization itself has succeeded.
+#[instrument(skip_all)]
+pub async fn schedule_authorization_expiry_task(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    storage_scheme: enums::MerchantStorageScheme,
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &mut storage::PaymentAttempt,
+) {
+    Box::pin(add_authorization_expiry_task(
+        state,
+        key_store,
+        storage_scheme,
+        payment_intent,
+        payment_attempt,
+    ))
+    .await
+    .map_err(|error| logger::error!(?error, "Failed to schedule the authorization expiry task"))
+    .ok();
+}
+
+async fn add_authorization_expiry_task(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    storage_scheme: enums::MerchantStorageScheme,
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &mut storage::PaymentAttempt,
+) -> RouterResult<()> {
+    let Some(profile_id) = payment_intent.profile_id.as_ref() else {
+        return Ok(());
+    };
+
+    let action =
+        get_authorization_expiry_action(state, &payment_intent.merchant_id, profile_id).await;
+    if !action.is_enabled() {
+        return Ok(());
+    }
+
+    let db = &*state.store;
+    let business_profile = db
+        .find_business_profile_by_profile_id(key_store, profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+
+    let expires_at = get_expected_authorization_expiry(
+        &state.conf.authorization_expiry,
+        payment_attempt,
+        business_profile.merchant_category_code.as_ref(),
+        date_time::now(),
+    );
+    let schedule_time =
+        get_authorization_expiry_schedule_time(&state.conf.authorization_expiry, expires_at);
+
+    *payment_attempt = update_expected_authorization_expiry(
+        db,
+        key_store,
+        storage_scheme,
+        payment_attempt.clone(),
+        expires_at,
+    )
+    .await?;
+
+    logger::info!(
+        payment_id = ?payment_attempt.payment_id,
+        ?expires_at,
+        ?action,
+        "Scheduling the action before the authorization expires"
+    );
+
+    let process_tracker_id = get_authorization_expiry_process_tracker_id(
+        &payment_attempt.payment_id,
+        &payment_attempt.merchant_id,
+    );
+
+    let existing_process = db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the authorization expiry task")?;
+
+    // A later authorization of the payment, for instance by a new attempt, replaces the previous
+    // schedule
+    if let Some(process) = existing_process {
+        return db
+            .as_scheduler()
+            .reset_process(process, schedule_time)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to reschedule the authorization expiry task");
+    }
+
+    let tracking_data = api::PaymentsAuthorizationExpiryTrackingData {
+        payment_id: payment_attempt.payment_id.clone(),
+        merchant_id: payment_attempt.merchant_id.clone(),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        AUTHORIZATION_EXPIRY_TASK,
+        storage::ProcessTrackerRunner::AuthorizationExpiryWorkflow,
+        AUTHORIZATION_EXPIRY_TAGS,
+        tracking_data,
+        None,
+        schedule_time,
+        common_types::consts::API_VERSION,
+        state.conf.application_source,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct the authorization expiry task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to add the authorization expiry task")?;
+
+    Ok(())
+}
+
+/// Removes the task acting before the authorization of a payment expires, if any, once the
+/// payment is captured or cancelled.
+#[instrument(skip_all)]
+pub async fn revoke_authorization_expiry_task(
+    db: &dyn StorageInterface,
+    payment_id: &id_type::PaymentId,
+    merchant_id: &id_type::MerchantId,
+) {
+    let process_tracker_id = get_authorization_expiry_process_tracker_id(payment_id, merchant_id);
+    let process_tracker_update = storage::ProcessTrackerUpdate::StatusUpdate {
+        status: enums::ProcessTrackerStatus::Finish,
+        business_status: Some(String::from(business_status::REVOKED)),
+    };
+
+    db.process_tracker_update_process_status_by_ids(
+        vec![process_tracker_id],
+        process_tracker_update,
+    )
+    .await
+    .map_err(|error| logger::error!(?error, "Failed to revoke the authorization expiry task"))
+    .ok();
+}
+
+/// Sends a `payment_authorization_expiring` webhook with the current details of the payment.
+#[instrument(skip_all)]
+pub async fn trigger_authorization_expiring_webhook(
+    state: &SessionState,
+    platform: &domain::Platform,
+    business_profile: domain::Profile,
+    payment_attempt: storage::PaymentAttempt,
+) -> RouterResult<()> {
+    let payment_id = payment_attempt.payment_id.clone();
+    let request = api::PaymentsRetrieveRequest {
+        resource_id: api::PaymentIdType::PaymentIntentId(payment_id.clone()),
+        merchant_id: Some(payment_attempt.merchant_id.clone()),
+        force_sync: false,
+        ..Default::default()
+    };
+
+    let payments_response = match Box::pin(payment_flows::payments_core::<
+        api::PSync,
+        api::PaymentsResponse,
+        _,
+        _,
+        _,
+        payment_flows::PaymentData<api::PSync>,
+    >(
+        state.clone(),
+        state.get_req_state(),
+        platform.clone(),
+        None,
+        operations::PaymentStatus,
+        request,
+        services::AuthFlow::Merchant,
+        payment_flows::CallConnectorAction::Avoid,
+        None,
+        None,
+        hyperswitch_domain_models::payments::HeaderPayload::default(),
+        None,
+    ))
+    .await?
+    {
+        services::ApplicationResponse::Json(payments_response)
+        | services::ApplicationResponse::JsonWithHeaders((payments_response, _)) => {
+            payments_response
+        }
+        _ => {
+            return Err(report!(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Unexpected response while retrieving the payment"))
+        }
+    };
+
+    let webhook_recipient = webhooks_core::utils::resolve_webhook_recipient_from_created_by(
+        state,
+        platform,
+        &business_profile,
+        payment_attempt.created_by.as_ref(),
+    )
+    .await?;
+    let primary_object_created_at = payments_response.created;
+
+    Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        platform.clone(),
+        enums::EventType::PaymentAuthorizationExpiring,
+        enums::EventClass::Payments,
+        payment_id.get_string_repr().to_owned(),
+        enums::EventObjectType::PaymentDetails,
+        webhooks::OutgoingWebhookContent::PaymentDetails(Box::new(payments_response)),
+        primary_object_created_at,
+        webhook_recipient,
+        Some(WebhookResourceData::Payment { payment_attempt }),
+        business_profile,
+    ))
+    .await
+}
@@ -40,9 +40,9 @@ pub async fn perform_decision_management(
             .change_context(errors::StorageError::DeserializationFailed)
             .attach_printable("Error parsing routing algorithm from configs")?;
 
-        backend::VirInterpreterBackend::with_program(rec.program)
+        backend::CompiledBackend::with_program(rec.program)
             .change_context(errors::StorageError::ValueNotFound("Program".to_string()))
-            .attach_printable("Error initializing DSL interpreter backend")
+            .attach_printable("Error compiling DSL program")
     };
 
     let interpreter = cache::get_or_populate_in_memory(
@@ -65,9 +65,9 @@ pub fn perform_decision_management(
     record: common_types::payments::DecisionManagerRecord,
     payment_data: &core_routing::PaymentsDslInput<'_>,
 ) -> RouterResult<common_types::payments::ConditionalConfigs> {
-    let interpreter = backend::VirInterpreterBackend::with_program(record.program)
+    let interpreter = backend::CompiledBackend::with_program(record.program)
         .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Error initializing DSL interpreter backend")?;
+        .attach_printable("Error compiling DSL program")?;
 
     let backend_input = make_dsl_input(payment_data)
         .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -79,7 +79,7 @@ pub fn perform_decision_management(
 
 pub fn execute_dsl_and_get_conditional_config(
     backend_input: dsl_inputs::BackendInput,
-    interpreter: &backend::VirInterpreterBackend<common_types::payments::ConditionalConfigs>,
+    interpreter: &backend::CompiledBackend<common_types::payments::ConditionalConfigs>,
 ) -> ConditionalConfigResult<common_types::payments::ConditionalConfigs> {
     let routing_output = interpreter
         .execute(backend_input)
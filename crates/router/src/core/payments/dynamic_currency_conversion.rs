@@ -0,0 +1,445 @@
+use api_models::payments as payment_types;
+use common_types::payments::DccDetails;
+use common_utils::{date_time, fp_utils, id_type, types::MinorUnit};
+use error_stack::{report, ResultExt};
+use hyperswitch_domain_models::platform::ProviderMerchantId;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    consts,
+    core::{
+        configs::dimension_state,
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments::{helpers, PaymentData},
+        utils as core_utils,
+    },
+    routes::SessionState,
+    services,
+    types::{
+        api, domain,
+        storage::{self, enums},
+    },
+    utils::{currency, OptionExt},
+};
+
+/// Time for which the rate of a quote is locked
+const DCC_QUOTE_VALIDITY: time::Duration = time::Duration::minutes(15);
+
+/// Markups above this are treated as a misconfiguration rather than charged to the customer
+const MAX_DCC_MARKUP_IN_BASIS_POINTS: u32 = 2_000;
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct DccQuote {
+    payment_id: id_type::PaymentId,
+    details: DccDetails,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    expires_at: time::PrimitiveDateTime,
+}
+
+fn get_dcc_quote_key(merchant_id: &id_type::MerchantId, quote_id: &str) -> String {
+    format!("dcc_quote_{}_{}", merchant_id.get_string_repr(), quote_id)
+}
+
+/// Returns the markup configured for the profile, in basis points.
+async fn get_dcc_markup_in_basis_points(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    profile_id: &id_type::ProfileId,
+) -> u32 {
+    let dimensions = dimension_state::Dimensions::new()
+        .with_processor_merchant_id(merchant_id.clone().into())
+        .with_provider_merchant_id(ProviderMerchantId::new(merchant_id.clone()))
+        .with_profile_id(profile_id.clone());
+
+    dimensions
+        .get_dcc_markup_in_basis_points(
+            state.store.as_ref(),
+            state.superposition_service.as_ref(),
+            Some(profile_id),
+        )
+        .await
+}
+
+/// Converts the amount of a payment into the currency of the customer at the current market
+/// rate plus the markup configured for the profile. The rate is locked for the validity of the
+/// quote.
+#[instrument(skip_all)]
+pub async fn create_dcc_quote(
+    state: SessionState,
+    platform: domain::Platform,
+    profile_id: Option<id_type::ProfileId>,
+    req: payment_types::PaymentsDccQuoteRequest,
+) -> RouterResponse<payment_types::PaymentsDccQuoteResponse> {
+    let db = &*state.store;
+    let processor = platform.get_processor();
+    let merchant_id = processor.get_account().get_id();
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_processor_merchant_id(
+            &req.payment_id,
+            merchant_id,
+            processor.get_key_store(),
+            processor.get_account().storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &payment_intent)?;
+
+    helpers::validate_payment_status_against_allowed_statuses(
+        payment_intent.status,
+        &[
+            enums::IntentStatus::RequiresPaymentMethod,
+            enums::IntentStatus::RequiresConfirmation,
+        ],
+        "create a currency conversion quote for",
+    )?;
+
+    let original_currency = payment_intent.currency.get_required_value("currency")?;
+    fp_utils::when(original_currency == req.currency, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "The payment is already in the requested currency".to_string(),
+        }))
+    })?;
+
+    let profile_id = payment_intent
+        .profile_id
+        .as_ref()
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("profile_id is not set in payment_intent")?;
+
+    let markup_in_basis_points =
+        get_dcc_markup_in_basis_points(&state, merchant_id, profile_id).await;
+    fp_utils::when(
+        markup_in_basis_points > MAX_DCC_MARKUP_IN_BASIS_POINTS,
+        || {
+            Err(report!(errors::ApiErrorResponse::InternalServerError)).attach_printable(format!(
+                "Configured currency conversion markup of {markup_in_basis_points} basis points \
+                    exceeds the maximum of {MAX_DCC_MARKUP_IN_BASIS_POINTS}"
+            ))
+        },
+    )?;
+
+    let forex_api = state.conf.forex_api.get_inner();
+    let rates = currency::get_forex_rates(&state, forex_api.data_expiration_delay_in_seconds)
+        .await
+        .change_context(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Unable to fetch forex rates".to_string(),
+        })?;
+
+    let market_converted_amount = currency_conversion::conversion::convert(
+        &rates.data,
+        original_currency,
+        req.currency,
+        payment_intent.amount.get_amount_as_i64(),
+    )
+    .change_context(errors::ApiErrorResponse::InvalidRequestData {
+        message: "Conversion to the requested currency is not supported".to_string(),
+    })?;
+
+    let quote_id = common_utils::generate_id(consts::ID_LENGTH, "dcc_quote");
+    let details = DccDetails::new(
+        quote_id.clone(),
+        payment_intent.amount,
+        original_currency,
+        req.currency,
+        market_converted_amount,
+        markup_in_basis_points,
+    )
+    .ok_or(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to convert the payment amount")?;
+
+    let expires_at = date_time::now().saturating_add(DCC_QUOTE_VALIDITY);
+    let quote = DccQuote {
+        payment_id: req.payment_id.clone(),
+        details,
+        expires_at,
+    };
+
+    db.get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .serialize_and_set_key_with_expiry(
+            &get_dcc_quote_key(merchant_id, &quote_id).into(),
+            &quote,
+            DCC_QUOTE_VALIDITY.whole_seconds(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to store the currency conversion quote")?;
+
+    logger::info!(
+        payment_id = ?req.payment_id,
+        %quote_id,
+        converted_currency = %quote.details.converted_currency,
+        exchange_rate = %quote.details.exchange_rate,
+        "Created currency conversion quote"
+    );
+
+    Ok(services::ApplicationResponse::Json(
+        payment_types::PaymentsDccQuoteResponse {
+            quote_id,
+            payment_id: quote.payment_id,
+            original_amount: quote.details.original_amount,
+            original_currency: quote.details.original_currency,
+            converted_amount: quote.details.converted_amount,
+            converted_currency: quote.details.converted_currency,
+            exchange_rate: quote.details.exchange_rate,
+            markup_in_basis_points: quote.details.markup_in_basis_points,
+            expires_at,
+        },
+    ))
+}
+
+async fn get_dcc_quote(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+    quote_id: &str,
+) -> RouterResult<DccDetails> {
+    let quote = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .get_and_deserialize_key::<DccQuote>(
+            &get_dcc_quote_key(merchant_id, quote_id).into(),
+            "DccQuote",
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Currency conversion quote not found or expired".to_string(),
+        })?;
+
+    fp_utils::when(&quote.payment_id != payment_id, || {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Currency conversion quote not found or expired".to_string(),
+        }))
+    })?;
+
+    Ok(quote.details)
+}
+
+/// Charges the payment in the currency of the customer at the rate locked by the quote in the
+/// confirm request. No-op when the request carries no quote. Only the attempt is converted, the
+/// intent stays in the currency of the merchant and the original amount and currency are kept in
+/// the `dcc_details` of the attempt, which the confirm update tracker persists.
+pub async fn apply_dcc_quote<F: Clone>(
+    state: &SessionState,
+    request: &api::PaymentsRequest,
+    processor: &domain::Processor,
+    mut payment_data: PaymentData<F>,
+) -> RouterResult<PaymentData<F>> {
+    let Some(quote_id) = request.dcc_quote_id.as_deref() else {
+        return Ok(payment_data);
+    };
+
+    match payment_data.payment_attempt.dcc_details.as_ref() {
+        Some(existing) if existing.quote_id == quote_id => return Ok(payment_data),
+        Some(_) => {
+            return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "A different currency conversion quote is already applied to this \
+                    payment attempt"
+                    .to_string(),
+            }))
+        }
+        None => {}
+    }
+
+    let details = get_dcc_quote(
+        state,
+        processor.get_account().get_id(),
+        &payment_data.payment_attempt.payment_id,
+        quote_id,
+    )
+    .await?;
+
+    let order_amount = payment_data.payment_attempt.net_amount.get_order_amount();
+    fp_utils::when(
+        order_amount != details.original_amount
+            || payment_data.currency != details.original_currency,
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "The amount or currency of the payment changed after the currency \
+                    conversion quote was created"
+                    .to_string(),
+            }))
+        },
+    )?;
+
+    // Only the order amount is converted, so a quote cannot be combined with components that
+    // are expressed in the original currency
+    fp_utils::when(
+        payment_data.payment_attempt.get_total_amount() != order_amount,
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "A currency conversion quote cannot be applied to a payment with taxes, \
+                    surcharges or offers"
+                    .to_string(),
+            }))
+        },
+    )?;
+
+    payment_data
+        .payment_attempt
+        .net_amount
+        .set_order_amount(details.converted_amount);
+    payment_data.payment_attempt.currency = Some(details.converted_currency);
+    payment_data.currency = details.converted_currency;
+    payment_data.amount = payment_data.payment_attempt.get_total_amount().into();
+    payment_data.payment_attempt.dcc_details = Some(details);
+
+    Ok(payment_data)
+}
+
+/// Returns the currency of the payment intent, which is not converted along with the attempt.
+pub fn get_intent_currency<F: Clone>(payment_data: &PaymentData<F>) -> enums::Currency {
+    payment_data
+        .payment_attempt
+        .dcc_details
+        .as_ref()
+        .map_or(payment_data.currency, |dcc_details| {
+            dcc_details.original_currency
+        })
+}
+
+/// Returns the amount captured for the payment intent, which is kept in the currency of the
+/// merchant, from the amount captured in the currency the customer was charged in.
+pub fn get_intent_amount_captured(
+    dcc_details: Option<&DccDetails>,
+    amount_captured: Option<MinorUnit>,
+) -> RouterResult<Option<MinorUnit>> {
+    match dcc_details {
+        Some(dcc_details) => amount_captured
+            .map(|amount_captured| {
+                dcc_details
+                    .convert_to_original_amount(amount_captured)
+                    .ok_or(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to convert the captured amount at the locked rate")
+            })
+            .transpose(),
+        None => Ok(amount_captured),
+    }
+}
+
+/// Returns the amount in the currency the customer was charged in for an amount in the currency
+/// of the merchant, such as the amount of a refund.
+pub fn get_attempt_amount(
+    dcc_details: Option<&DccDetails>,
+    amount: MinorUnit,
+) -> RouterResult<MinorUnit> {
+    match dcc_details {
+        Some(dcc_details) => dcc_details
+            .convert_from_original_amount(amount)
+            .ok_or(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to convert the amount at the locked rate"),
+        None => Ok(amount),
+    }
+}
+
+/// Reverts the conversion of an attempt, so that a new attempt created from it is charged in the
+/// currency of the merchant unless a quote is applied to it again.
+pub fn remove_dcc_quote(payment_attempt: &mut storage::PaymentAttempt) {
+    if let Some(details) = payment_attempt.dcc_details.take() {
+        payment_attempt
+            .net_amount
+            .set_order_amount(details.original_amount);
+        payment_attempt.currency = Some(details.original_currency);
+    }
+}
+
+/// Deletes the quote applied to an attempt once the attempt is authorized, so that the locked
+/// rate cannot be used for another payment attempt. Failures are only logged, since the quote
+/// expires anyway.
+pub async fn consume_dcc_quote(state: &SessionState, payment_attempt: &storage::PaymentAttempt) {
+    let Some(details) = payment_attempt.dcc_details.as_ref() else {
+        return;
+    };
+    if !payment_attempt.status.is_authorization_success() {
+        return;
+    }
+
+    let result = async {
+        state
+            .store
+            .get_redis_conn()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to get redis connection")?
+            .delete_key(&get_dcc_quote_key(&payment_attempt.merchant_id, &details.quote_id).into())
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to delete the currency conversion quote")
+    }
+    .await;
+
+    if let Err(error) = result {
+        logger::error!(
+            ?error,
+            payment_id = ?payment_attempt.payment_id,
+            quote_id = %details.quote_id,
+            "Unable to consume currency conversion quote"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use rust_decimal::Decimal;
+
+    use super::*;
+
+    fn usd_to_eur_details() -> DccDetails {
+        // 65.40 USD is charged as 61.97 EUR
+        DccDetails::new(
+            "dcc_quote_1".to_string(),
+            MinorUnit::new(6540),
+            enums::Currency::USD,
+            enums::Currency::EUR,
+            Decimal::new(60168, 3),
+            300,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_full_capture_and_refund_are_in_the_right_currencies() {
+        let details = usd_to_eur_details();
+
+        let intent_amount_captured =
+            get_intent_amount_captured(Some(&details), Some(MinorUnit::new(6197))).unwrap();
+        assert_eq!(intent_amount_captured, Some(MinorUnit::new(6540)));
+
+        // A refund without an amount refunds the amount captured of the intent
+        let refund_amount =
+            get_attempt_amount(Some(&details), intent_amount_captured.unwrap()).unwrap();
+        assert_eq!(refund_amount, MinorUnit::new(6197));
+    }
+
+    #[test]
+    fn test_partial_capture_and_refund_round_trip() {
+        let details = usd_to_eur_details();
+
+        let intent_amount_captured =
+            get_intent_amount_captured(Some(&details), Some(MinorUnit::new(3000))).unwrap();
+        assert_eq!(intent_amount_captured, Some(MinorUnit::new(3166)));
+
+        let refund_amount =
+            get_attempt_amount(Some(&details), intent_amount_captured.unwrap()).unwrap();
+        assert_eq!(refund_amount, MinorUnit::new(3000));
+    }
+
+    #[test]
+    fn test_amounts_are_unchanged_without_conversion() {
+        assert_eq!(
+            get_intent_amount_captured(None, Some(MinorUnit::new(6540))).unwrap(),
+            Some(MinorUnit::new(6540))
+        );
+        assert_eq!(get_intent_amount_captured(None, None).unwrap(), None);
+        assert_eq!(
+            get_attempt_amount(None, MinorUnit::new(6540)).unwrap(),
+            MinorUnit::new(6540)
+        );
+    }
+}
@@ -5590,11 +5590,14 @@ impl AttemptType {
     #[inline(always)]
     fn make_new_manual_retry_payment_attempt(
         request: &api_models::payments::PaymentsRequest,
-        old_payment_attempt: PaymentAttempt,
+        mut old_payment_attempt: PaymentAttempt,
         new_attempt_count: i16,
         storage_scheme: enums::MerchantStorageScheme,
     ) -> PaymentAttempt {
         let created_at @ modified_at @ last_synced = common_utils::date_time::now();
+        // The conversion of the previous attempt is not carried over, a quote is applied again
+        // while confirming the new attempt
+        super::dynamic_currency_conversion::remove_dcc_quote(&mut old_payment_attempt);
         let is_token_based_retry = request.payment_token.is_some();
         let (payment_method, browser_info, payment_method_type, setup_future_usage_applied) =
             if is_token_based_retry {
@@ -5710,6 +5713,10 @@ impl AttemptType {
             created_by: old_payment_attempt.created_by,
             setup_future_usage_applied,
             routing_approach: old_payment_attempt.routing_approach,
+            routing_algorithm_id: old_payment_attempt.routing_algorithm_id.clone(),
+            expected_processing_fee: old_payment_attempt.expected_processing_fee,
+            expected_authorization_expiry: None,
+            dcc_details: old_payment_attempt.dcc_details.clone(),
             connector_request_reference_id: None,
             network_transaction_id: None,
             network_transaction_link_id: None,
@@ -9161,6 +9168,22 @@ pub async fn validate_merchant_connector_ids_in_connector_mandate_details(
     Ok(())
 }
 
+/// Validates an advanced program passed as the straight through algorithm with the euclid
+/// analyzer, the other algorithms are validated when they are parsed
+pub fn validate_straight_through_algorithm(
+    algorithm: &api::routing::StraightThroughAlgorithm,
+) -> RouterResult<()> {
+    if let api::routing::StraightThroughAlgorithm::Advanced(program) = algorithm {
+        euclid::dssa::analyzer::analyze(program.clone(), None).map_err(|error| {
+            report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("Invalid advanced straight through routing program: {error}"),
+            })
+        })?;
+    }
+
+    Ok(())
+}
+
 pub fn validate_platform_request_for_marketplace(
     amount: api::Amount,
     split_payments: Option<common_types::payments::SplitPaymentsRequest>,
@@ -214,6 +214,7 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsCaptureR
             vault_session_details: None,
             external_vault_pmd: None,
             update_request_fields: None,
+            scheduled_capture_time: None,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
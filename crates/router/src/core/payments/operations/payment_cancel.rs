@@ -11,7 +11,7 @@ use crate::{
     core::{
         configs::dimension_state,
         errors::{self, RouterResult, StorageErrorExt},
-        payments::{helpers, operations, PaymentData},
+        payments::{authorization_expiry, helpers, operations, scheduled_capture, PaymentData},
     },
     events::audit_events::{AuditEvent, AuditEventType},
     routes::{app::ReqState, SessionState},
@@ -223,6 +223,7 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsCancelRe
             vault_session_details: None,
             external_vault_pmd: None,
             update_request_fields: None,
+            scheduled_capture_time: None,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -299,6 +300,20 @@ impl<F: Clone + Sync> UpdateTracker<F, PaymentData<F>, api::PaymentsCancelReques
             )
             .await
             .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        scheduled_capture::revoke_scheduled_capture_task(
+            state.store.as_ref(),
+            &payment_data.payment_attempt.payment_id,
+            &payment_data.payment_attempt.merchant_id,
+        )
+        .await;
+        authorization_expiry::revoke_authorization_expiry_task(
+            state.store.as_ref(),
+            &payment_data.payment_attempt.payment_id,
+            &payment_data.payment_attempt.merchant_id,
+        )
+        .await;
+
         req_state
             .event_context
             .event(AuditEvent::new(AuditEventType::PaymentCancelled {
@@ -278,6 +278,7 @@ impl<F: Send + Clone + Sync> GetTracker<F, payments::PaymentData<F>, api::Paymen
             vault_session_details: None,
             external_vault_pmd: None,
             update_request_fields: None,
+            scheduled_capture_time: None,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -338,6 +339,7 @@ impl<F: Clone + Sync> UpdateTracker<F, payments::PaymentData<F>, api::PaymentsCa
         } else {
             payment_data.payment_attempt
         };
+
         let capture_amount = payment_data.payment_attempt.amount_to_capture;
         let multiple_capture_count = payment_data.payment_attempt.multiple_capture_count;
         req_state
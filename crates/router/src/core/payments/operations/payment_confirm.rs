@@ -43,8 +43,9 @@ use crate::{
         metrics, offer_engine,
         payment_methods::{transformers as pm_transformers, vault},
         payments::{
-            self, helpers, operations, populate_installment_details, CustomerDetails,
-            OperationSessionGetters, OperationSessionSetters, PaymentAddress, PaymentData,
+            self, dynamic_currency_conversion, helpers, operations, populate_installment_details,
+            scheduled_capture, CustomerDetails, OperationSessionGetters, OperationSessionSetters,
+            PaymentAddress, PaymentData,
         },
         three_ds_decision_rule,
         unified_authentication_service::{
@@ -447,6 +448,18 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
 
         payment_attempt.capture_method = request.capture_method.or(payment_attempt.capture_method);
 
+        let scheduled_capture_time = request
+            .capture_schedule
+            .as_ref()
+            .map(|capture_schedule| {
+                scheduled_capture::get_scheduled_capture_time(
+                    capture_schedule,
+                    payment_attempt.capture_method,
+                    true,
+                )
+            })
+            .transpose()?;
+
         payment_attempt.customer_acceptance = request
             .customer_acceptance
             .clone()
@@ -965,6 +978,7 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
             vault_session_details: None,
             external_vault_pmd: None,
             update_request_fields: None,
+            scheduled_capture_time,
         };
 
         let payment_data = Box::pin(apply_offer_engine_offer(
@@ -975,6 +989,14 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
         ))
         .await?;
 
+        let payment_data = dynamic_currency_conversion::apply_dcc_quote(
+            state,
+            request,
+            platform.get_processor(),
+            payment_data,
+        )
+        .await?;
+
         let get_trackers_response = operations::GetTrackerResponse {
             operation: Box::new(self),
             customer_details: Some(customer_details),
@@ -2882,6 +2904,13 @@ impl<F: Clone + Sync> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for
                             .clone(),
                         card_discovery,
                         routing_approach: payment_data.payment_attempt.routing_approach.clone(),
+                        routing_algorithm_id: payment_data
+                            .payment_attempt
+                            .routing_algorithm_id
+                            .clone(),
+                        expected_processing_fee: payment_data
+                            .payment_attempt
+                            .expected_processing_fee,
                         connector_request_reference_id,
                         network_transaction_id: payment_data
                             .payment_attempt
@@ -2905,6 +2934,7 @@ impl<F: Clone + Sync> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for
                             .payment_attempt
                             .applied_offer_details
                             .clone(),
+                        dcc_details: payment_data.payment_attempt.dcc_details.clone(),
                     },
                     storage_scheme,
                     &cloned_key_store,
@@ -2970,7 +3000,7 @@ impl<F: Clone + Sync> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for
                     m_payment_data_payment_intent,
                     storage::PaymentIntentUpdate::Update(Box::new(PaymentIntentUpdateFields {
                         amount: payment_data.payment_intent.amount,
-                        currency: payment_data.currency,
+                        currency: dynamic_currency_conversion::get_intent_currency(&payment_data),
                         setup_future_usage,
                         status: intent_status,
                         customer_id: m_customer_id,
@@ -3047,6 +3077,16 @@ impl<F: Clone + Sync> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for
         payment_data.payment_intent = payment_intent;
         payment_data.payment_attempt = payment_attempt;
 
+        if let Some(scheduled_capture_time) = payment_data.scheduled_capture_time {
+            scheduled_capture::add_scheduled_capture_task(
+                state.store.as_ref(),
+                &payment_data.payment_attempt,
+                scheduled_capture_time,
+                state.conf.application_source,
+            )
+            .await?;
+        }
+
         let client_src = payment_data.payment_attempt.client_source.clone();
         let client_ver = payment_data.payment_attempt.client_version.clone();
 
@@ -3109,7 +3149,7 @@ impl<F: Send + Clone + Sync> ValidateRequest<F, api::PaymentsRequest, PaymentDat
             .clone()
             .ok_or(report!(errors::ApiErrorResponse::PaymentNotFound))?;
 
-        let _request_straight_through: Option<api::routing::StraightThroughAlgorithm> = request
+        let request_straight_through: Option<api::routing::StraightThroughAlgorithm> = request
             .routing
             .clone()
             .map(|val| val.parse_value("RoutingAlgorithm"))
@@ -3118,6 +3158,9 @@ impl<F: Send + Clone + Sync> ValidateRequest<F, api::PaymentsRequest, PaymentDat
                 message: "Invalid straight through routing rules format".to_string(),
             })
             .attach_printable("Invalid straight through routing rules format")?;
+        if let Some(algorithm) = &request_straight_through {
+            helpers::validate_straight_through_algorithm(algorithm)?;
+        }
 
         request.validate_installment_options().map_err(|err| {
             let message = format!("invalid installment options: {err}");
@@ -434,6 +434,7 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, PaymentsRequest>
             external_vault_pmd,
             client_session_id: None,
             update_request_fields: None,
+            scheduled_capture_time: None,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -606,6 +607,9 @@ impl<F: Clone + Sync> UpdateTracker<F, PaymentData<F>, PaymentsRequest>
                         .clone(),
                     card_discovery: None,
                     routing_approach: payment_data.payment_attempt.routing_approach.clone(),
+                    routing_algorithm_id: payment_data.payment_attempt.routing_algorithm_id.clone(),
+                    expected_processing_fee: payment_data.payment_attempt.expected_processing_fee,
+                    dcc_details: payment_data.payment_attempt.dcc_details.clone(),
                     connector_request_reference_id,
                     network_transaction_id: payment_data
                         .payment_attempt
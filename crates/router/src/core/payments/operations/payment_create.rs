@@ -39,8 +39,9 @@ use crate::{
         payment_link,
         payment_methods::transformers as pm_transformers,
         payments::{
-            self, client_session::ClientSessionManager, helpers, operations, CustomerDetails,
-            OperationSessionGetters, OperationSessionSetters, PaymentAddress, PaymentData,
+            self, client_session::ClientSessionManager, helpers, operations, scheduled_capture,
+            CustomerDetails, OperationSessionGetters, OperationSessionSetters, PaymentAddress,
+            PaymentData,
         },
         utils as core_utils,
     },
@@ -98,6 +99,18 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
             .get_payment_intent_id()
             .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
 
+        let scheduled_capture_time = request
+            .capture_schedule
+            .as_ref()
+            .map(|capture_schedule| {
+                scheduled_capture::get_scheduled_capture_time(
+                    capture_schedule,
+                    request.capture_method,
+                    request.confirm.unwrap_or(false),
+                )
+            })
+            .transpose()?;
+
         #[cfg(feature = "v1")]
         helpers::validate_business_details(
             request.business_country,
@@ -754,6 +767,7 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
             vault_session_details: None,
             external_vault_pmd,
             update_request_fields: None,
+            scheduled_capture_time,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -1139,6 +1153,8 @@ impl<F: Clone + Sync> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for
             .map(|surcharge_details| surcharge_details.tax_on_surcharge_amount);
 
         let routing_approach = payment_data.payment_attempt.routing_approach.clone();
+        let routing_algorithm_id = payment_data.payment_attempt.routing_algorithm_id.clone();
+        let expected_processing_fee = payment_data.payment_attempt.expected_processing_fee;
         let is_stored_credential = helpers::is_stored_credential(
             &payment_data.recurring_details,
             &payment_data.pm_token,
@@ -1162,6 +1178,8 @@ impl<F: Clone + Sync> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for
                     updated_by: storage_scheme.to_string(),
                     merchant_connector_id,
                     routing_approach,
+                    routing_algorithm_id,
+                    expected_processing_fee,
                     is_stored_credential,
                 },
                 storage_scheme,
@@ -1192,6 +1210,17 @@ impl<F: Clone + Sync> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for
             )
             .await
             .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        if let Some(scheduled_capture_time) = payment_data.scheduled_capture_time {
+            scheduled_capture::add_scheduled_capture_task(
+                state.store.as_ref(),
+                &payment_data.payment_attempt,
+                scheduled_capture_time,
+                state.conf.application_source,
+            )
+            .await?;
+        }
+
         req_state
             .event_context
             .event(AuditEvent::new(AuditEventType::PaymentCreate))
@@ -1322,7 +1351,7 @@ impl<F: Send + Clone + Sync> ValidateRequest<F, api::PaymentsRequest, PaymentDat
             )?;
         };
 
-        let _request_straight_through: Option<api::routing::StraightThroughAlgorithm> = request
+        let request_straight_through: Option<api::routing::StraightThroughAlgorithm> = request
             .routing
             .clone()
             .map(|val| val.parse_value("RoutingAlgorithm"))
@@ -1331,6 +1360,9 @@ impl<F: Send + Clone + Sync> ValidateRequest<F, api::PaymentsRequest, PaymentDat
                 message: "Invalid straight through routing rules format".to_string(),
             })
             .attach_printable("Invalid straight through routing rules format")?;
+        if let Some(algorithm) = &request_straight_through {
+            helpers::validate_straight_through_algorithm(algorithm)?;
+        }
 
         Ok((
             Box::new(self),
@@ -1730,6 +1762,10 @@ impl PaymentCreate {
                 created_by: platform.get_initiator().and_then(|initiator| initiator.to_created_by()),
                 setup_future_usage_applied: request.setup_future_usage,
                 routing_approach: Some(common_enums::RoutingApproach::default()),
+                routing_algorithm_id: None,
+                expected_processing_fee: None,
+                expected_authorization_expiry: None,
+                dcc_details: None,
                 connector_request_reference_id: None,
                 network_transaction_id: None,
                 network_transaction_link_id: None,
@@ -40,6 +40,8 @@ use super::payment_update::PaymentUpdate;
 use super::{Operation, OperationSessionSetters, PostUpdateTracker};
 #[cfg(feature = "v1")]
 use crate::core::payment_methods::transformers::call_modular_payment_method_update;
+#[cfg(feature = "v1")]
+use crate::core::payments::{authorization_expiry, dynamic_currency_conversion, scheduled_capture};
 #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
 use crate::core::routing::helpers as routing_helpers;
 #[cfg(feature = "v2")]
@@ -592,6 +594,8 @@ impl<F: Send + Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsAuthor
         ))
         .await?;
 
+        dynamic_currency_conversion::consume_dcc_quote(db, &payment_data.payment_attempt).await;
+
         Ok(payment_data)
     }
 
@@ -1634,6 +1638,28 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsCaptureData>
         ))
         .await?;
 
+        // A failed capture keeps the schedule and the authorization expiry tracking, so that the
+        // payment is still captured on time
+        if matches!(
+            payment_data.payment_attempt.status,
+            enums::AttemptStatus::Charged
+                | enums::AttemptStatus::PartialCharged
+                | enums::AttemptStatus::PartialChargedAndChargeable
+        ) {
+            scheduled_capture::revoke_scheduled_capture_task(
+                db.store.as_ref(),
+                &payment_data.payment_attempt.payment_id,
+                &payment_data.payment_attempt.merchant_id,
+            )
+            .await;
+            authorization_expiry::revoke_authorization_expiry_task(
+                db.store.as_ref(),
+                &payment_data.payment_attempt.payment_id,
+                &payment_data.payment_attempt.merchant_id,
+            )
+            .await;
+        }
+
         Ok(payment_data)
     }
 }
@@ -2159,7 +2185,7 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::CompleteAuthorizeData
     where
         F: 'b + Send,
     {
-        Box::pin(payment_response_update_tracker(
+        let payment_data = Box::pin(payment_response_update_tracker(
             db,
             payment_data,
             response,
@@ -2170,7 +2196,11 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::CompleteAuthorizeData
             #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
             business_profile,
         ))
-        .await
+        .await?;
+
+        dynamic_currency_conversion::consume_dcc_quote(db, &payment_data.payment_attempt).await;
+
+        Ok(payment_data)
     }
 
     async fn save_pm_and_mandate<'b>(
@@ -2305,6 +2335,7 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
     #[cfg(all(feature = "v1", feature = "dynamic_routing"))] business_profile: &domain::Profile,
 ) -> RouterResult<PaymentData<F>> {
     let key_manager_state = &state.into();
+    let previous_intent_status = payment_data.payment_intent.status;
     // Update additional payment data with the payment method response that we received from connector
     // This is for details like whether 3ds was upgraded and which version of 3ds was used
     // also some connectors might send card network details in the response, which is captured and stored
@@ -3079,12 +3110,15 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
         };
     }
 
-    let amount_captured = get_total_amount_captured(
-        &router_data.request,
-        router_data.amount_captured.map(MinorUnit::new),
-        router_data.status,
-        &payment_data,
-    );
+    let amount_captured = dynamic_currency_conversion::get_intent_amount_captured(
+        payment_data.payment_attempt.dcc_details.as_ref(),
+        get_total_amount_captured(
+            &router_data.request,
+            router_data.amount_captured.map(MinorUnit::new),
+            router_data.status,
+            &payment_data,
+        ),
+    )?;
 
     let payment_intent_update = get_payment_intent_update_data::<_, _>(
         payment_data.clone(),
@@ -3197,6 +3231,28 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
 
     payment_data.payment_intent = payment_intent;
     payment_data.payment_attempt = payment_attempt;
+
+    let is_authorization_held = |status| {
+        matches!(
+            status,
+            enums::IntentStatus::RequiresCapture
+                | enums::IntentStatus::PartiallyAuthorizedAndRequiresCapture
+        )
+    };
+    // Act on the payment before its authorization expires, once it is held for manual capture
+    if is_authorization_held(payment_data.payment_intent.status)
+        && !is_authorization_held(previous_intent_status)
+    {
+        authorization_expiry::schedule_authorization_expiry_task(
+            state,
+            processor.get_key_store(),
+            processor.get_account().storage_scheme,
+            &payment_data.payment_intent,
+            &mut payment_data.payment_attempt,
+        )
+        .await;
+    }
+
     payment_method_status.and_then(|status| {
         payment_data
             .payment_method_info
@@ -229,6 +229,7 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsSessionR
             vault_operation: None,
             vault_session_details: None,
             update_request_fields: None,
+            scheduled_capture_time: None,
             threeds_method_comp_ind: None,
             whole_connector_response: None,
             is_manual_retry_enabled: None,
@@ -583,6 +583,7 @@ async fn get_tracker_for_sync<
         vault_session_details: None,
         external_vault_pmd: None,
         update_request_fields: None,
+        scheduled_capture_time: None,
     };
 
     let get_trackers_response = operations::GetTrackerResponse {
@@ -7,6 +7,7 @@ use api_models::{
 use async_trait::async_trait;
 use common_utils::{
     ext_traits::{AsyncExt, Encode, ValueExt},
+    fp_utils,
     pii::Email,
     types::MinorUnit,
 };
@@ -563,6 +564,7 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
             vault_session_details: None,
             external_vault_pmd: None,
             update_request_fields: Some(Self::extract_update_request_fields(request)),
+            scheduled_capture_time: None,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -1009,6 +1011,12 @@ impl<F: Send + Clone + Sync> ValidateRequest<F, api::PaymentsRequest, PaymentDat
 
         helpers::validate_payment_method_fields_present(request)?;
 
+        fp_utils::when(request.capture_schedule.is_some(), || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "`capture_schedule` can only be set while confirming a payment".into()
+            }))
+        })?;
+
         let _mandate_type = helpers::validate_mandate(request, false)?;
 
         helpers::validate_recurring_details_and_token(
@@ -1016,7 +1024,7 @@ impl<F: Send + Clone + Sync> ValidateRequest<F, api::PaymentsRequest, PaymentDat
             &request.payment_token,
             &request.mandate_id,
         )?;
-        let _request_straight_through: Option<api::routing::StraightThroughAlgorithm> = request
+        let request_straight_through: Option<api::routing::StraightThroughAlgorithm> = request
             .routing
             .clone()
             .map(|val| val.parse_value("RoutingAlgorithm"))
@@ -1025,6 +1033,9 @@ impl<F: Send + Clone + Sync> ValidateRequest<F, api::PaymentsRequest, PaymentDat
                 message: "Invalid straight through routing rules format".to_string(),
             })
             .attach_printable("Invalid straight through routing rules format")?;
+        if let Some(algorithm) = &request_straight_through {
+            helpers::validate_straight_through_algorithm(algorithm)?;
+        }
 
         request.validate_installment_options().map_err(|err| {
             let message = format!("invalid installment options: {err}");
@@ -857,6 +857,10 @@ pub fn make_new_auto_retry_payment_attempt(
         created_by: old_payment_attempt.created_by,
         setup_future_usage_applied: setup_future_usage_intent, // setup future usage is picked from intent for new payment attempt
         routing_approach: old_payment_attempt.routing_approach,
+        routing_algorithm_id: old_payment_attempt.routing_algorithm_id.clone(),
+        expected_processing_fee: old_payment_attempt.expected_processing_fee,
+        expected_authorization_expiry: None,
+        dcc_details: old_payment_attempt.dcc_details.clone(),
         connector_request_reference_id: Default::default(),
         network_transaction_id: old_payment_attempt.network_transaction_id,
         network_transaction_link_id: old_payment_attempt.network_transaction_link_id,
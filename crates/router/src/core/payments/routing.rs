@@ -85,7 +85,51 @@ pub enum CachedAlgorithm {
     Single(Box<routing_types::RoutableConnectorChoice>),
     Priority(Vec<routing_types::RoutableConnectorChoice>),
     VolumeSplit(Vec<routing_types::ConnectorVolumeSplit>),
-    Advanced(backend::VirInterpreterBackend<ConnectorSelection>),
+    Advanced(backend::CompiledBackend<ConnectorSelection>),
+    CostBased(routing_types::CostBasedRoutingConfig),
+}
+
+impl CachedAlgorithm {
+    pub fn try_from_static_algorithm(
+        algorithm: routing_types::StaticRoutingAlgorithm,
+    ) -> RoutingResult<Self> {
+        Ok(match algorithm {
+            routing_types::StaticRoutingAlgorithm::Single(conn) => Self::Single(conn),
+            routing_types::StaticRoutingAlgorithm::Priority(plist) => Self::Priority(plist),
+            routing_types::StaticRoutingAlgorithm::VolumeSplit(splits) => Self::VolumeSplit(splits),
+            routing_types::StaticRoutingAlgorithm::Advanced(program) => {
+                let interpreter = backend::CompiledBackend::with_program(program)
+                    .change_context(errors::RoutingError::DslBackendInitError)
+                    .attach_printable("Error compiling DSL program")?;
+
+                Self::Advanced(interpreter)
+            }
+            routing_types::StaticRoutingAlgorithm::CostBased(config) => Self::CostBased(config),
+            routing_types::StaticRoutingAlgorithm::ThreeDsDecisionRule(_program) => {
+                Err(errors::RoutingError::InvalidRoutingAlgorithmStructure)
+                    .attach_printable("Unsupported algorithm received")?
+            }
+        })
+    }
+
+    /// The connectors the algorithm selects for the given input, in order of preference.
+    pub fn evaluate(
+        &self,
+        backend_input: dsl_inputs::BackendInput,
+    ) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
+        match self {
+            Self::Single(conn) => Ok(vec![(**conn).clone()]),
+            Self::Priority(plist) => Ok(plist.clone()),
+            Self::VolumeSplit(splits) => perform_volume_split(splits.to_vec())
+                .change_context(errors::RoutingError::ConnectorSelectionFailed),
+            Self::Advanced(interpreter) => {
+                execute_dsl_and_get_connector_v1(backend_input, interpreter)
+            }
+            // The connectors are ranked by cost once the fee schedules are fetched, see
+            // `rank_connectors_by_cost`
+            Self::CostBased(config) => Ok(config.connectors.clone()),
+        }
+    }
 }
 
 #[cfg(feature = "v1")]
@@ -222,6 +266,69 @@ pub fn make_dsl_input_for_payouts(
         acquirer_data: None,
         customer_device_data: None,
         issuer_data: None,
+        transaction_time: None,
+    })
+}
+
+/// Routing timezones are IANA timezone names, such as `Asia/Kolkata`.
+pub fn parse_routing_timezone(routing_timezone: &str) -> Option<chrono_tz::Tz> {
+    chrono_tz::Tz::from_str(routing_timezone).ok()
+}
+
+/// The UTC offset of the timezone at the given time, which changes over the year in timezones
+/// observing daylight saving time.
+fn get_routing_timezone_offset(
+    timezone: chrono_tz::Tz,
+    time: time::OffsetDateTime,
+) -> Option<time::UtcOffset> {
+    use chrono::{Offset, TimeZone};
+
+    let utc_time = chrono::DateTime::from_timestamp(time.unix_timestamp(), 0)?.naive_utc();
+    let offset = timezone.offset_from_utc_datetime(&utc_time).fix();
+
+    time::UtcOffset::from_whole_seconds(offset.local_minus_utc()).ok()
+}
+
+/// The time of the transaction in the given routing timezone, against which the time based
+/// routing keys are evaluated. The offset of the timezone is resolved at the time of the
+/// transaction, and time is evaluated in UTC when no timezone is configured.
+pub fn get_transaction_time_input(
+    routing_timezone: Option<&str>,
+    transaction_time: time::PrimitiveDateTime,
+) -> Option<dsl_inputs::TransactionTimeInput> {
+    let transaction_time = transaction_time.assume_utc();
+    let offset = match routing_timezone {
+        Some(routing_timezone) => {
+            let timezone = parse_routing_timezone(routing_timezone).or_else(|| {
+                logger::error!(
+                    routing_timezone,
+                    "euclid_routing: invalid routing timezone configured"
+                );
+                None
+            })?;
+            get_routing_timezone_offset(timezone, transaction_time)?
+        }
+        None => time::UtcOffset::UTC,
+    };
+
+    let now = transaction_time.to_offset(offset);
+
+    let day_of_week = match now.weekday() {
+        time::Weekday::Monday => euclid_dir::enums::DayOfWeek::Monday,
+        time::Weekday::Tuesday => euclid_dir::enums::DayOfWeek::Tuesday,
+        time::Weekday::Wednesday => euclid_dir::enums::DayOfWeek::Wednesday,
+        time::Weekday::Thursday => euclid_dir::enums::DayOfWeek::Thursday,
+        time::Weekday::Friday => euclid_dir::enums::DayOfWeek::Friday,
+        time::Weekday::Saturday => euclid_dir::enums::DayOfWeek::Saturday,
+        time::Weekday::Sunday => euclid_dir::enums::DayOfWeek::Sunday,
+    };
+
+    Some(dsl_inputs::TransactionTimeInput {
+        day_of_week,
+        hour_of_day: now.hour(),
+        date: u32::try_from(now.year()).ok()? * 10000
+            + u32::from(u8::from(now.month())) * 100
+            + u32::from(now.day()),
     })
 }
 
@@ -349,6 +456,10 @@ pub fn make_dsl_input(
         acquirer_data: None,
         customer_device_data: None,
         issuer_data: None,
+        transaction_time: get_transaction_time_input(
+            payments_dsl_input.routing_timezone,
+            common_utils::date_time::now(),
+        ),
     })
 }
 
@@ -676,6 +787,10 @@ pub fn make_dsl_input(
         acquirer_data: None,
         customer_device_data: None,
         issuer_data,
+        transaction_time: get_transaction_time_input(
+            payments_dsl_input.routing_timezone,
+            common_utils::date_time::now(),
+        ),
     })
 }
 
@@ -766,6 +881,7 @@ pub struct StraightThroughRoutingStage {
 
 pub struct StraightThroughRoutingInput<'a> {
     pub creds_identifier: Option<&'a str>,
+    pub backend_input: &'a backend::BackendInput,
 }
 
 pub struct ConnectorOutcomeWithEligibilityRequirement {
@@ -780,10 +896,13 @@ impl RoutingStage for StraightThroughRoutingStage {
 
     fn route<'a>(&'a self, input: Self::Input<'a>) -> Self::Fut<'a> {
         Box::pin(async move {
-            let (connectors, check_eligibility) =
-                perform_straight_through_routing(&self.algorithm.clone(), input.creds_identifier)
-                    .change_context(errors::RoutingError::DslExecutionError)
-                    .attach_printable("euclid: unable to perform straight through routing")?;
+            let (connectors, check_eligibility) = perform_straight_through_routing(
+                &self.algorithm.clone(),
+                input.creds_identifier,
+                Some(input.backend_input),
+            )
+            .change_context(errors::RoutingError::DslExecutionError)
+            .attach_printable("euclid: unable to perform straight through routing")?;
 
             Ok(ConnectorOutcomeWithEligibilityRequirement {
                 connectors: connectors.into(),
@@ -837,24 +956,33 @@ pub async fn perform_static_routing_locally(
     Vec<routing_types::RoutableConnectorChoice>,
     common_enums::RoutingApproach,
     bool,
+    Option<common_utils::id_type::RoutingId>,
+    ExpectedProcessingFees,
 )> {
     let txn_type = routing::transaction_type_from_payments_dsl(payment_dsl_input);
 
-    let routing_algorithm_id = business_profile
+    let algorithm_ref = business_profile
         .routing_algorithm
         .clone()
         .map(|ra| ra.parse_value::<api::routing::RoutingAlgorithmRef>("RoutingAlgorithmRef"))
         .transpose()
         .change_context(errors::ApiErrorResponse::InternalServerError)?
-        .unwrap_or_default()
-        .algorithm_id;
+        .unwrap_or_default();
+    let routing_algorithm_id = get_routing_algorithm_id_for_transaction(
+        &algorithm_ref,
+        payment_dsl_input
+            .payment_attempt
+            .payment_id
+            .get_string_repr(),
+    );
 
     let cached_algorithm = routing_algorithm_id
+        .as_ref()
         .async_and_then(|routing_algorithm_id| async move {
             try_ensure_algorithm_cached_v1(
                 state,
                 &business_profile.merchant_id,
-                &routing_algorithm_id,
+                routing_algorithm_id,
                 business_profile.get_id(),
                 &txn_type,
             )
@@ -887,6 +1015,24 @@ pub async fn perform_static_routing_locally(
         .await
         .unwrap_or_else(RoutingConnectorOutcome::empty);
 
+    let (outcome, expected_processing_fees) = match static_stage
+        .as_ref()
+        .map(|stage| stage.ctx.routing_algorithm.as_ref())
+    {
+        Some(CachedAlgorithm::CostBased(config)) => {
+            let (connectors, expected_processing_fees) =
+                rank_connectors_by_cost(state, business_profile, config, backend_input).await;
+            (
+                RoutingConnectorOutcome {
+                    connectors,
+                    ..outcome
+                },
+                expected_processing_fees,
+            )
+        }
+        _ => (outcome, ExpectedProcessingFees::new()),
+    };
+
     let is_volume_split = outcome.is_volume_split;
 
     let (static_connectors, static_approach) = outcome.resolve_or_fallback_with_approach(
@@ -899,7 +1045,198 @@ pub async fn perform_static_routing_locally(
         common_enums::RoutingApproach::DefaultFallback,
     );
 
-    Ok((static_connectors, static_approach, is_volume_split))
+    Ok((
+        static_connectors,
+        static_approach,
+        is_volume_split,
+        routing_algorithm_id,
+        expected_processing_fees,
+    ))
+}
+
+/// Expected processing fee of the payment on each merchant connector account ranked by cost
+/// based routing
+pub type ExpectedProcessingFees =
+    HashMap<common_utils::id_type::MerchantConnectorAccountId, common_utils::types::MinorUnit>;
+
+/// Ranks the connectors of a cost based routing algorithm by the fee they are expected to charge
+/// for the payment, weighted by their success rate when one is configured. Connectors without a
+/// fee schedule keep their configured order after the ranked ones, and the configured order is
+/// kept entirely when the merchant connector accounts can't be fetched.
+#[cfg(feature = "v1")]
+pub async fn rank_connectors_by_cost(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+    config: &routing_types::CostBasedRoutingConfig,
+    backend_input: &backend::BackendInput,
+) -> (
+    Vec<routing_types::RoutableConnectorChoice>,
+    ExpectedProcessingFees,
+) {
+    let merchant_connector_accounts = match get_cost_based_routing_mcas(state, business_profile)
+        .await
+    {
+        Ok(merchant_connector_accounts) => merchant_connector_accounts,
+        Err(err) => {
+            logger::error!(
+                error = ?err,
+                "cost_based_routing: failed to fetch merchant connector accounts, keeping the configured order"
+            );
+            return (config.connectors.clone(), ExpectedProcessingFees::new());
+        }
+    };
+
+    rank_connectors_by_expected_fee(config, &merchant_connector_accounts, backend_input)
+}
+
+/// The enabled merchant connector accounts of the profile, whose fee schedules cost based routing
+/// ranks connectors by. They are cached, so that they aren't listed on every payment.
+#[cfg(feature = "v1")]
+async fn get_cost_based_routing_mcas(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+) -> RoutingResult<Arc<domain::MerchantConnectorAccountsWithoutEncrypted>> {
+    let cache_key = CacheKey {
+        key: storage_impl::redis::cache::get_cost_based_mcas_cache_key(
+            &business_profile.merchant_id,
+            business_profile.get_id(),
+        ),
+        prefix: state.tenant.redis_key_prefix.clone(),
+    };
+
+    if let Some(merchant_connector_accounts) = CGRAPH_CACHE
+        .get_val::<Arc<domain::MerchantConnectorAccountsWithoutEncrypted>>(cache_key.clone())
+        .await
+    {
+        return Ok(merchant_connector_accounts);
+    }
+
+    let merchant_connector_accounts = Arc::new(
+        state
+            .store
+            .list_enabled_merchant_connector_accounts_without_encrypted_by_merchant_id_profile_id(
+                &business_profile.merchant_id,
+                business_profile.get_id(),
+            )
+            .await
+            .change_context(errors::RoutingError::MerchantConnectorAccountsFetchFailed)?,
+    );
+
+    CGRAPH_CACHE
+        .push(cache_key, Arc::clone(&merchant_connector_accounts))
+        .await;
+
+    Ok(merchant_connector_accounts)
+}
+
+/// Ranks the connectors of a cost based routing algorithm against the fee schedules of the given
+/// merchant connector accounts, see [`rank_connectors_by_cost`].
+#[cfg(feature = "v1")]
+pub fn rank_connectors_by_expected_fee(
+    config: &routing_types::CostBasedRoutingConfig,
+    merchant_connector_accounts: &[domain::MerchantConnectorAccountWithoutEncrypted],
+    backend_input: &backend::BackendInput,
+) -> (
+    Vec<routing_types::RoutableConnectorChoice>,
+    ExpectedProcessingFees,
+) {
+    let criteria = get_fee_schedule_criteria(backend_input);
+    let mut expected_processing_fees = ExpectedProcessingFees::new();
+
+    let mut ranked_connectors = config
+        .connectors
+        .iter()
+        .map(|choice| {
+            let merchant_connector_account = merchant_connector_accounts.iter().find(|mca| {
+                match &choice.merchant_connector_id {
+                    Some(merchant_connector_id) => {
+                        mca.merchant_connector_id == *merchant_connector_id
+                    }
+                    None => mca.connector_name == choice.connector.to_string(),
+                }
+            });
+
+            let expected_fee = merchant_connector_account.and_then(|mca| {
+                let fee = mca
+                    .get_fee_schedule()?
+                    .calculate_fee(&criteria)
+                    .inspect_err(|err| {
+                        logger::error!(
+                            error = ?err,
+                            merchant_connector_id = ?mca.merchant_connector_id,
+                            "cost_based_routing: failed to calculate the expected fee"
+                        );
+                    })
+                    .ok()?;
+                expected_processing_fees.insert(mca.merchant_connector_id.clone(), fee);
+                Some(fee)
+            });
+
+            // Precision loss is acceptable, the weighted fee is only used to order connectors
+            #[allow(clippy::as_conversions)]
+            let weighted_fee = expected_fee
+                .map(|fee| fee.get_amount_as_i64() as f64 / config.get_success_rate(choice));
+
+            (choice.clone(), weighted_fee)
+        })
+        .collect::<Vec<_>>();
+
+    // The sort is stable, so connectors with the same weighted fee keep their configured order
+    ranked_connectors.sort_by(|(_, first), (_, second)| match (first, second) {
+        (Some(first), Some(second)) => first.total_cmp(second),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => std::cmp::Ordering::Equal,
+    });
+
+    (
+        ranked_connectors
+            .into_iter()
+            .map(|(choice, _)| choice)
+            .collect(),
+        expected_processing_fees,
+    )
+}
+
+#[cfg(feature = "v1")]
+fn get_fee_schedule_criteria(
+    backend_input: &backend::BackendInput,
+) -> common_types::connector_fee_schedule::FeeScheduleCriteria {
+    common_types::connector_fee_schedule::FeeScheduleCriteria {
+        amount: backend_input.payment.amount,
+        currency: backend_input.payment.currency,
+        card_network: backend_input.payment_method.card_network.clone(),
+        card_type: match backend_input.payment_method.payment_method_type {
+            Some(api_enums::PaymentMethodType::Credit) => Some(common_enums::CardType::Credit),
+            Some(api_enums::PaymentMethodType::Debit) => Some(common_enums::CardType::Debit),
+            _ => None,
+        },
+        issuer_country: backend_input
+            .issuer_data
+            .as_ref()
+            .and_then(|issuer_data| issuer_data.country)
+            .map(|country| country.to_alpha2()),
+    }
+}
+
+/// Picks the algorithm a payment or payout is routed through. While a canary is active, the
+/// transaction id decides whether the transaction is routed through the canary, so that all the
+/// attempts of a transaction are routed through the same algorithm. The id is hashed with blake3,
+/// which, unlike the std hasher, is stable across releases and processes.
+#[cfg(feature = "v1")]
+pub fn get_routing_algorithm_id_for_transaction(
+    algorithm_ref: &routing_types::RoutingAlgorithmRef,
+    transaction_id: &str,
+) -> Option<common_utils::id_type::RoutingId> {
+    let hash = blake3::hash(transaction_id.as_bytes());
+    let bucket = hash
+        .as_bytes()
+        .first_chunk::<8>()
+        .map(|bytes| u64::from_le_bytes(*bytes) % 100)
+        .and_then(|bucket| u8::try_from(bucket).ok())
+        .unwrap_or_default();
+
+    algorithm_ref.get_algorithm_id_for_bucket(bucket).cloned()
 }
 
 pub struct SessionRoutingInput<'a> {
@@ -1098,6 +1435,8 @@ pub struct RoutingConnectorOutcomeWithApproachAndEligibility {
     pub connectors: Vec<routing_types::RoutableConnectorChoice>,
     pub routing_approach: common_enums::RoutingApproach,
     pub requires_eligibility: bool,
+    pub routing_algorithm_id: Option<common_utils::id_type::RoutingId>,
+    pub expected_processing_fees: ExpectedProcessingFees,
 }
 
 pub struct PreRoutingInput<'a> {
@@ -1752,6 +2091,10 @@ pub async fn static_routing_v1(
                 is_volume_split,
             }
         }
+        CachedAlgorithm::CostBased(config) => RoutingConnectorOutcome {
+            connectors: config.connectors.clone(),
+            is_volume_split: false,
+        },
     };
     Ok(outcome)
 }
@@ -1829,11 +2172,21 @@ pub async fn perform_static_routing_v1(
 
     // A routing failure must never fail the payment: any error building the routing input, or
     // evaluating the active algorithm, falls back to the merchant default connectors.
+    #[cfg(feature = "v1")]
+    let routing_timezone = business_profile.routing_timezone.as_deref();
+    #[cfg(feature = "v2")]
+    let routing_timezone = None;
+
     let backend_input = match transaction_data {
         routing::TransactionData::Payment(payment_data) => make_dsl_input(payment_data),
         #[cfg(feature = "payouts")]
         routing::TransactionData::Payout(payout_data) => make_dsl_input_for_payouts(payout_data),
-    };
+    }
+    .map(|mut backend_input| {
+        backend_input.transaction_time =
+            get_transaction_time_input(routing_timezone, common_utils::date_time::now());
+        backend_input
+    });
 
     let (routable_connectors, routing_approach, is_volume_split, de_evaluated_connector) =
         match backend_input {
@@ -1859,6 +2212,18 @@ pub async fn perform_static_routing_v1(
                     .unwrap_or_default()
                 };
 
+                #[cfg(feature = "v1")]
+                let cost_ranked_connectors = match cached_algorithm.as_ref() {
+                    CachedAlgorithm::CostBased(config) => Some(
+                        rank_connectors_by_cost(state, business_profile, config, &backend_input)
+                            .await
+                            .0,
+                    ),
+                    _ => None,
+                };
+                #[cfg(feature = "v2")]
+                let cost_ranked_connectors = None;
+
                 let evaluated = (|| -> RoutingResult<(
                     Vec<routing_types::RoutableConnectorChoice>,
                     Option<common_enums::RoutingApproach>,
@@ -1889,6 +2254,11 @@ pub async fn perform_static_routing_v1(
                                 is_volume_split,
                             )
                         }
+                        CachedAlgorithm::CostBased(config) => (
+                            cost_ranked_connectors.unwrap_or_else(|| config.connectors.clone()),
+                            None,
+                            false,
+                        ),
                     })
                 })();
 
@@ -2001,9 +2371,12 @@ pub async fn try_ensure_algorithm_cached_v1(
     .ok()
 }
 
+/// Performs the routing for the straight through algorithm passed in the request. The backend
+/// input is required to evaluate an advanced program and is not available for payouts.
 pub fn perform_straight_through_routing(
     algorithm: &routing_types::StraightThroughAlgorithm,
     creds_identifier: Option<&str>,
+    backend_input: Option<&backend::BackendInput>,
 ) -> RoutingResult<(Vec<routing_types::RoutableConnectorChoice>, bool)> {
     Ok(match algorithm {
         routing_types::StraightThroughAlgorithm::Single(conn) => {
@@ -2020,6 +2393,22 @@ pub fn perform_straight_through_routing(
                 )?,
             true,
         ),
+
+        routing_types::StraightThroughAlgorithm::Advanced(program) => {
+            let backend_input = backend_input
+                .ok_or(errors::RoutingError::DslIncorrectSelectionAlgorithm)
+                .attach_printable(
+                    "Advanced straight through routing is not supported for this transaction",
+                )?;
+            let interpreter = backend::CompiledBackend::with_program(program.clone())
+                .change_context(errors::RoutingError::DslBackendInitError)
+                .attach_printable("Error compiling straight through DSL program")?;
+
+            (
+                execute_dsl_and_get_connector_v1(backend_input.clone(), &interpreter)?,
+                true,
+            )
+        }
     })
 }
 
@@ -2030,7 +2419,8 @@ pub fn perform_routing_for_single_straight_through_algorithm(
         routing_types::StraightThroughAlgorithm::Single(connector) => vec![(**connector).clone()],
 
         routing_types::StraightThroughAlgorithm::Priority(_)
-        | routing_types::StraightThroughAlgorithm::VolumeSplit(_) => {
+        | routing_types::StraightThroughAlgorithm::VolumeSplit(_)
+        | routing_types::StraightThroughAlgorithm::Advanced(_) => {
             Err(errors::RoutingError::DslIncorrectSelectionAlgorithm)
                 .attach_printable("Unsupported algorithm received as a result of static routing")?
         }
@@ -2039,7 +2429,7 @@ pub fn perform_routing_for_single_straight_through_algorithm(
 
 fn execute_dsl_v1(
     backend_input: dsl_inputs::BackendInput,
-    interpreter: &backend::VirInterpreterBackend<ConnectorSelection>,
+    interpreter: &backend::CompiledBackend<ConnectorSelection>,
 ) -> RoutingResult<routing_types::StaticRoutingAlgorithm> {
     interpreter
         .execute(backend_input)
@@ -2063,7 +2453,7 @@ fn dsl_output_to_connectors(
 
 fn execute_dsl_and_get_connector_v1(
     backend_input: dsl_inputs::BackendInput,
-    interpreter: &backend::VirInterpreterBackend<ConnectorSelection>,
+    interpreter: &backend::CompiledBackend<ConnectorSelection>,
 ) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
     dsl_output_to_connectors(execute_dsl_v1(backend_input, interpreter)?)
 }
@@ -2087,26 +2477,7 @@ pub async fn refresh_routing_cache_v1(
         algorithm
     };
 
-    let cached_algorithm = match algorithm {
-        routing_types::StaticRoutingAlgorithm::Single(conn) => CachedAlgorithm::Single(conn),
-        routing_types::StaticRoutingAlgorithm::Priority(plist) => CachedAlgorithm::Priority(plist),
-        routing_types::StaticRoutingAlgorithm::VolumeSplit(splits) => {
-            CachedAlgorithm::VolumeSplit(splits)
-        }
-        routing_types::StaticRoutingAlgorithm::Advanced(program) => {
-            let interpreter = backend::VirInterpreterBackend::with_program(program)
-                .change_context(errors::RoutingError::DslBackendInitError)
-                .attach_printable("Error initializing DSL interpreter backend")?;
-
-            CachedAlgorithm::Advanced(interpreter)
-        }
-        api_models::routing::StaticRoutingAlgorithm::ThreeDsDecisionRule(_program) => {
-            Err(errors::RoutingError::InvalidRoutingAlgorithmStructure)
-                .attach_printable("Unsupported algorithm received")?
-        }
-    };
-
-    let arc_cached_algorithm = Arc::new(cached_algorithm);
+    let arc_cached_algorithm = Arc::new(CachedAlgorithm::try_from_static_algorithm(algorithm)?);
 
     ROUTING_CACHE
         .push(
@@ -2731,6 +3102,7 @@ pub async fn perform_session_flow_routing<'a>(
         acquirer_data: None,
         customer_device_data: None,
         issuer_data: None,
+        transaction_time: get_transaction_time_input(None, common_utils::date_time::now()),
     };
 
     for connector_data in session_input.chosen.iter() {
@@ -2894,6 +3266,10 @@ pub async fn perform_session_flow_routing(
         acquirer_data: None,
         customer_device_data: None,
         issuer_data: None,
+        transaction_time: get_transaction_time_input(
+            business_profile.routing_timezone.as_deref(),
+            common_utils::date_time::now(),
+        ),
     };
 
     for connector_data in session_input.chosen.iter() {
@@ -2979,7 +3355,7 @@ pub async fn perform_session_flow_routing(
 async fn perform_session_routing_for_pm_type(
     session_pm_input: &SessionRoutingPmTypeInput<'_>,
     transaction_type: &api_enums::TransactionType,
-    _business_profile: &domain::Profile,
+    business_profile: &domain::Profile,
     active_mca_ids: &std::collections::HashSet<common_utils::id_type::MerchantConnectorAccountId>,
 ) -> RoutingResult<(
     Option<Vec<api_models::routing::RoutableConnectorChoice>>,
@@ -3019,6 +3395,17 @@ async fn perform_session_routing_for_pm_type(
                 )?,
                 Some(common_enums::RoutingApproach::RuleBasedRouting),
             ),
+            CachedAlgorithm::CostBased(config) => (
+                rank_connectors_by_cost(
+                    session_pm_input.state,
+                    business_profile,
+                    config,
+                    &session_pm_input.backend_input,
+                )
+                .await
+                .0,
+                None,
+            ),
         }
     } else {
         (
@@ -3105,6 +3492,7 @@ async fn get_chosen_connectors<'a>(
                 session_pm_input.backend_input.clone(),
                 interpreter,
             )?,
+            CachedAlgorithm::CostBased(config) => config.connectors.clone(),
         }
     } else {
         profile_wrapper
@@ -3238,6 +3626,7 @@ pub fn make_dsl_input_for_surcharge(
         acquirer_data: None,
         customer_device_data: None,
         issuer_data: None,
+        transaction_time: None,
     };
     Ok(backend_input)
 }
@@ -4331,3 +4720,57 @@ pub async fn get_active_mca_ids_for_session(
         }
     }
 }
+
+#[cfg(all(test, feature = "v1"))]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn test_transaction_time_is_evaluated_in_the_routing_timezone() {
+        let transaction_time =
+            get_transaction_time_input(Some("Asia/Kolkata"), datetime!(2024-01-15 20:00:00))
+                .unwrap();
+
+        assert_eq!(transaction_time.hour_of_day, 1);
+        assert_eq!(transaction_time.date, 20240116);
+        assert_eq!(
+            transaction_time.day_of_week,
+            euclid_dir::enums::DayOfWeek::Tuesday
+        );
+    }
+
+    #[test]
+    fn test_routing_timezone_offset_follows_daylight_saving_time() {
+        let winter =
+            get_transaction_time_input(Some("America/New_York"), datetime!(2024-01-15 12:00:00))
+                .unwrap();
+        let summer =
+            get_transaction_time_input(Some("America/New_York"), datetime!(2024-07-15 12:00:00))
+                .unwrap();
+
+        assert_eq!(winter.hour_of_day, 7);
+        assert_eq!(summer.hour_of_day, 8);
+    }
+
+    #[test]
+    fn test_transaction_time_defaults_to_utc() {
+        let transaction_time =
+            get_transaction_time_input(None, datetime!(2024-01-15 20:00:00)).unwrap();
+
+        assert_eq!(transaction_time.hour_of_day, 20);
+        assert_eq!(transaction_time.date, 20240115);
+    }
+
+    #[test]
+    fn test_invalid_routing_timezones_are_rejected() {
+        assert!(parse_routing_timezone("+05:30").is_none());
+        assert!(parse_routing_timezone("Mars/Olympus_Mons").is_none());
+        assert!(
+            get_transaction_time_input(Some("+05:30"), datetime!(2024-01-15 20:00:00)).is_none()
+        );
+    }
+}
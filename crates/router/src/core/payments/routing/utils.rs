@@ -1444,6 +1444,22 @@ pub fn convert_backend_input_to_routing_eval(
         );
     }
 
+    // Transaction time
+    if let Some(time) = input.transaction_time {
+        params.insert(
+            "day_of_week".to_string(),
+            Some(ValueType::EnumVariant(time.day_of_week.to_string())),
+        );
+        params.insert(
+            "hour_of_day".to_string(),
+            Some(ValueType::Number(time.hour_of_day.into())),
+        );
+        params.insert(
+            "transaction_date".to_string(),
+            Some(ValueType::Number(time.date.into())),
+        );
+    }
+
     // Metadata
     if let Some(meta) = input.metadata {
         for (k, v) in meta.into_iter() {
@@ -0,0 +1,290 @@
+use common_utils::{date_time, fp_utils};
+use diesel_models::process_tracker::business_status;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+use scheduler::utils as pt_utils;
+
+use crate::{
+    core::errors::{self, RouterResult},
+    db::StorageInterface,
+    types::{
+        api,
+        storage::{self, enums},
+    },
+};
+
+const SCHEDULED_CAPTURE_TASK: &str = "SCHEDULED_CAPTURE";
+const SCHEDULED_CAPTURE_TAGS: [&str; 2] = ["SCHEDULED_CAPTURE", "PAYMENT"];
+
+/// Authorizations are held for at most 30 days by card networks, a capture scheduled later than
+/// this would always fail.
+const MAX_CAPTURE_SCHEDULE_DELAY: time::Duration = time::Duration::days(30);
+
+/// Validates a capture schedule in a payment request and returns the time at which the payment
+/// must be captured. Payments are only captured according to a schedule when they are confirmed
+/// with manual capture.
+pub fn get_scheduled_capture_time(
+    capture_schedule: &api::CaptureSchedule,
+    capture_method: Option<enums::CaptureMethod>,
+    is_confirm_request: bool,
+) -> RouterResult<time::PrimitiveDateTime> {
+    fp_utils::when(!is_confirm_request, || {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "`capture_schedule` can only be set while confirming a payment".to_string(),
+        }))
+    })?;
+
+    fp_utils::when(capture_method != Some(enums::CaptureMethod::Manual), || {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "`capture_schedule` is supported only with manual capture".to_string(),
+        }))
+    })?;
+
+    let now = date_time::now();
+    let capture_time = match (capture_schedule.capture_after, capture_schedule.capture_at) {
+        (Some(capture_after), None) => {
+            now.saturating_add(time::Duration::seconds(capture_after.into()))
+        }
+        (None, Some(capture_at)) => capture_at,
+        _ => {
+            return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Exactly one of `capture_after` or `capture_at` must be provided in \
+                    `capture_schedule`"
+                    .to_string(),
+            }))
+        }
+    };
+
+    fp_utils::when(
+        capture_time <= now || capture_time > now.saturating_add(MAX_CAPTURE_SCHEDULE_DELAY),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "The scheduled capture time must be in the future and at most 30 days \
+                    from now"
+                    .to_string(),
+            }))
+        },
+    )?;
+
+    Ok(capture_time)
+}
+
+fn get_scheduled_capture_process_tracker_id(
+    payment_id: &common_utils::id_type::PaymentId,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> String {
+    pt_utils::get_process_tracker_id(
+        storage::ProcessTrackerRunner::ScheduledCaptureWorkflow,
+        SCHEDULED_CAPTURE_TASK,
+        payment_id.get_string_repr(),
+        merchant_id,
+    )
+}
+
+/// Adds the task capturing the payment at the scheduled time. If the payment already has a
+/// scheduled capture, for instance when a failed confirm is retried, it is rescheduled instead.
+#[instrument(skip_all)]
+pub async fn add_scheduled_capture_task(
+    db: &dyn StorageInterface,
+    payment_attempt: &storage::PaymentAttempt,
+    schedule_time: time::PrimitiveDateTime,
+    application_source: enums::ApplicationSource,
+) -> RouterResult<()> {
+    let process_tracker_id = get_scheduled_capture_process_tracker_id(
+        &payment_attempt.payment_id,
+        &payment_attempt.merchant_id,
+    );
+
+    let existing_process = db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the scheduled capture task")?;
+
+    if let Some(process) = existing_process {
+        return db
+            .as_scheduler()
+            .reset_process(process, schedule_time)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to reschedule the scheduled capture task");
+    }
+
+    let tracking_data = api::PaymentsScheduledCaptureTrackingData {
+        payment_id: payment_attempt.payment_id.clone(),
+        merchant_id: payment_attempt.merchant_id.clone(),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        SCHEDULED_CAPTURE_TASK,
+        storage::ProcessTrackerRunner::ScheduledCaptureWorkflow,
+        SCHEDULED_CAPTURE_TAGS,
+        tracking_data,
+        None,
+        schedule_time,
+        common_types::consts::API_VERSION,
+        application_source,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct the scheduled capture task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to add the scheduled capture task")?;
+
+    Ok(())
+}
+
+/// Removes the scheduled capture of a payment, if any, once the payment is captured or cancelled.
+#[instrument(skip_all)]
+pub async fn revoke_scheduled_capture_task(
+    db: &dyn StorageInterface,
+    payment_id: &common_utils::id_type::PaymentId,
+    merchant_id: &common_utils::id_type::MerchantId,
+) {
+    let process_tracker_id = get_scheduled_capture_process_tracker_id(payment_id, merchant_id);
+    let process_tracker_update = storage::ProcessTrackerUpdate::StatusUpdate {
+        status: enums::ProcessTrackerStatus::Finish,
+        business_status: Some(String::from(business_status::REVOKED)),
+    };
+
+    db.process_tracker_update_process_status_by_ids(
+        vec![process_tracker_id],
+        process_tracker_update,
+    )
+    .await
+    .map_err(|error| logger::error!(?error, "Failed to revoke the scheduled capture task"))
+    .ok();
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn capture_after(seconds: u32) -> api::CaptureSchedule {
+        api::CaptureSchedule {
+            capture_after: Some(seconds),
+            capture_at: None,
+        }
+    }
+
+    fn capture_at(capture_at: time::PrimitiveDateTime) -> api::CaptureSchedule {
+        api::CaptureSchedule {
+            capture_after: None,
+            capture_at: Some(capture_at),
+        }
+    }
+
+    fn is_invalid_request(result: RouterResult<time::PrimitiveDateTime>) -> bool {
+        matches!(
+            result.unwrap_err().current_context(),
+            errors::ApiErrorResponse::InvalidRequestData { .. }
+        )
+    }
+
+    fn is_precondition_failed(result: RouterResult<time::PrimitiveDateTime>) -> bool {
+        matches!(
+            result.unwrap_err().current_context(),
+            errors::ApiErrorResponse::PreconditionFailed { .. }
+        )
+    }
+
+    #[test]
+    fn test_capture_after_is_relative_to_now() {
+        let before = date_time::now();
+        let capture_time = get_scheduled_capture_time(
+            &capture_after(3600),
+            Some(enums::CaptureMethod::Manual),
+            true,
+        )
+        .unwrap();
+
+        assert!(capture_time >= before + time::Duration::hours(1));
+        assert!(capture_time <= date_time::now() + time::Duration::hours(1));
+    }
+
+    #[test]
+    fn test_capture_at_is_used_as_is() {
+        let scheduled_time = date_time::now() + time::Duration::days(7);
+
+        assert_eq!(
+            get_scheduled_capture_time(
+                &capture_at(scheduled_time),
+                Some(enums::CaptureMethod::Manual),
+                true,
+            )
+            .unwrap(),
+            scheduled_time
+        );
+    }
+
+    #[test]
+    fn test_exactly_one_of_capture_after_or_capture_at_is_required() {
+        let both = api::CaptureSchedule {
+            capture_after: Some(3600),
+            capture_at: Some(date_time::now() + time::Duration::days(1)),
+        };
+        let neither = api::CaptureSchedule {
+            capture_after: None,
+            capture_at: None,
+        };
+
+        for capture_schedule in [both, neither] {
+            assert!(is_invalid_request(get_scheduled_capture_time(
+                &capture_schedule,
+                Some(enums::CaptureMethod::Manual),
+                true,
+            )));
+        }
+    }
+
+    #[test]
+    fn test_capture_time_is_bounded_to_30_days() {
+        let within_bound = capture_at(date_time::now() + time::Duration::days(29));
+        let beyond_bound = capture_at(date_time::now() + time::Duration::days(31));
+        let beyond_bound_after = capture_after(31 * 24 * 60 * 60);
+        let in_the_past = capture_at(date_time::now() - time::Duration::minutes(1));
+
+        assert!(get_scheduled_capture_time(
+            &within_bound,
+            Some(enums::CaptureMethod::Manual),
+            true
+        )
+        .is_ok());
+        for capture_schedule in [beyond_bound, beyond_bound_after, in_the_past] {
+            assert!(is_invalid_request(get_scheduled_capture_time(
+                &capture_schedule,
+                Some(enums::CaptureMethod::Manual),
+                true,
+            )));
+        }
+    }
+
+    #[test]
+    fn test_capture_schedule_requires_manual_capture() {
+        for capture_method in [
+            None,
+            Some(enums::CaptureMethod::Automatic),
+            Some(enums::CaptureMethod::ManualMultiple),
+            Some(enums::CaptureMethod::Scheduled),
+        ] {
+            assert!(is_precondition_failed(get_scheduled_capture_time(
+                &capture_after(3600),
+                capture_method,
+                true,
+            )));
+        }
+    }
+
+    #[test]
+    fn test_capture_schedule_requires_confirm() {
+        assert!(is_precondition_failed(get_scheduled_capture_time(
+            &capture_after(3600),
+            Some(enums::CaptureMethod::Manual),
+            false,
+        )));
+    }
+}
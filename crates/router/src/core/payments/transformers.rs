@@ -3175,6 +3175,16 @@ impl GenerateResponse<api_models::payments::PaymentsResponse>
         profile: &domain::Profile,
         connector_response_data: Option<common_types::domain::ConnectorResponseData>,
     ) -> RouterResponse<api_models::payments::PaymentsResponse> {
+        // Each funding leg of the split payment is a separate attempt
+        let attempts = self
+            .get_funding_legs()
+            .map(|payment_data| {
+                api_models::payments::PaymentAttemptResponse::foreign_from(
+                    &payment_data.payment_attempt,
+                )
+            })
+            .collect::<Vec<_>>();
+
         let payment_intent = self.primary_payment_response_data.payment_intent.clone();
         let payment_attempt = self.primary_payment_response_data.payment_attempt.clone();
 
@@ -3195,7 +3205,7 @@ impl GenerateResponse<api_models::payments::PaymentsResponse>
             net_amount,
             amount_to_capture: attempt_amount_details.get_amount_to_capture(),
             amount_capturable: attempt_amount_details.get_amount_capturable(),
-            amount_captured: Some(net_amount),
+            amount_captured: payment_intent.amount_captured,
         };
 
         let connector = payment_attempt
@@ -3299,7 +3309,7 @@ impl GenerateResponse<api_models::payments::PaymentsResponse>
             authentication_type: payment_intent.authentication_type,
             authentication_type_applied: Some(payment_attempt.authentication_type),
             payment_method_id: payment_attempt.payment_method_id,
-            attempts: None,
+            attempts: Some(attempts),
             billing: None,  //TODO: add this
             shipping: None, //TODO: add this
             is_iframe_redirection_enabled: None,
@@ -4277,6 +4287,7 @@ where
             merchant_id: payment_intent.merchant_id,
             status: payment_intent.status,
             applied_offer,
+            dcc_details: payment_attempt.dcc_details.clone(),
             connector_customer_id: payment_data.get_connector_customer_id(),
             amount: payment_attempt.net_amount.get_order_amount(),
             net_amount: payment_attempt.get_total_amount(),
@@ -4675,6 +4686,7 @@ impl ForeignFrom<(storage::PaymentIntent, storage::PaymentAttempt)> for api::Pay
         Self {
             connector_response_metadata: pa.get_connector_response_metadata_from_attempt_metadata(),
             applied_offer: applied_offer_response(pa.applied_offer_details.clone()),
+            dcc_details: pa.dcc_details.clone(),
             payment_id: pi.payment_id,
             merchant_id: pi.merchant_id,
             status: pi.status,
@@ -1083,7 +1083,7 @@ pub async fn decide_payout_connector(
     // 2. Check routing algorithm passed in the request
     if let Some(routing_algorithm) = request_straight_through {
         let (mut connectors, check_eligibility) =
-            routing::perform_straight_through_routing(&routing_algorithm, None)
+            routing::perform_straight_through_routing(&routing_algorithm, None, None)
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Failed execution of straight through routing")?;
 
@@ -1132,7 +1132,7 @@ pub async fn decide_payout_connector(
     // 3. Check algorithm passed in routing data
     if let Some(ref routing_algorithm) = routing_data.algorithm {
         let (mut connectors, check_eligibility) =
-            routing::perform_straight_through_routing(routing_algorithm, None)
+            routing::perform_straight_through_routing(routing_algorithm, None, None)
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Failed execution of straight through routing")?;
 
@@ -26,7 +26,7 @@ use crate::{
     routes::SessionState,
     types::{api::payouts, domain, storage},
     utils,
-    utils::OptionExt,
+    utils::{OptionExt, ValueExt},
 };
 
 #[instrument(skip(db))]
@@ -97,6 +97,8 @@ pub async fn validate_create_request(
         }
     };
 
+    validate_payout_routing(req.routing.as_ref())?;
+
     // Merchant ID
     let predicate = req.merchant_id.as_ref().map(|mid| mid != merchant_id);
     utils::when(predicate.unwrap_or(false), || {
@@ -333,6 +335,34 @@ pub async fn validate_create_request(
     ))
 }
 
+/// Validates the straight through routing algorithm of a payout request. Advanced programs are
+/// rejected, as payouts are routed without the routing input that a program is evaluated against.
+pub fn validate_payout_routing(routing: Option<&serde_json::Value>) -> RouterResult<()> {
+    let Some(routing) = routing else {
+        return Ok(());
+    };
+
+    let algorithm: api_models::routing::StraightThroughAlgorithm = routing
+        .clone()
+        .parse_value("StraightThroughAlgorithm")
+        .change_context(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Invalid straight through routing rules format".to_string(),
+        })?;
+
+    utils::when(
+        matches!(
+            algorithm,
+            api_models::routing::StraightThroughAlgorithm::Advanced(_)
+        ),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Advanced straight through routing is not supported for payouts"
+                    .to_string(),
+            }))
+        },
+    )
+}
+
 pub fn validate_payout_link_request(
     req: &payouts::PayoutCreateRequest,
 ) -> Result<(), errors::ApiErrorResponse> {
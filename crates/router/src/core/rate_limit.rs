@@ -0,0 +1,648 @@
+use std::{collections::HashSet, str::FromStr};
+
+use actix_web::http::header::{self, HeaderName, HeaderValue};
+use api_models::rate_limit as rate_limit_api;
+use common_utils::{errors::CustomResult, ext_traits::StringExt, fp_utils::when, id_type};
+use diesel_models::configs;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+use storage_impl::redis::cache::{self, CacheKind, CONFIG_CACHE};
+
+use super::errors::{self, RouterResponse, RouterResult, StorageErrorExt};
+use crate::{
+    headers,
+    routes::{lock_utils, SessionState},
+    services::{authentication::AuthenticationType, ApplicationResponse},
+};
+
+pub const RATE_LIMIT_PREFIX: &str = "RATE_LIMIT";
+
+/// Refills and consumes a token from every bucket in `KEYS`, the request is allowed only if all
+/// of the buckets have a token available and none of their quotas are used up. `ARGV` holds the
+/// refill rate per second, the burst, the quota limit (`0` when the rule has no quota) and the
+/// quota period in milliseconds of each bucket, in the same order as `KEYS`. The quota usage is
+/// kept in the hash of the bucket, so that every key of a request maps to the same cluster slot.
+///
+/// Returns `[allowed, limit, remaining, retry_after_ms, reset_after_ms]` for the bucket or quota
+/// closest to being exhausted.
+const TOKEN_BUCKET_SCRIPT: &str = r#"
+local time = redis.call('TIME')
+local now = tonumber(time[1]) * 1000 + math.floor(tonumber(time[2]) / 1000)
+local buckets = {}
+local allowed = 1
+for index, key in ipairs(KEYS) do
+    local rate = tonumber(ARGV[index * 4 - 3])
+    local burst = tonumber(ARGV[index * 4 - 2])
+    local quota = tonumber(ARGV[index * 4 - 1])
+    local quota_period = tonumber(ARGV[index * 4])
+    local state = redis.call('HMGET', key, 'tokens', 'updated_at', 'quota_used', 'quota_window')
+    local tokens = tonumber(state[1]) or burst
+    local updated_at = tonumber(state[2]) or now
+    tokens = math.min(burst, tokens + math.max(0, now - updated_at) * rate / 1000)
+    if tokens < 1 then
+        allowed = 0
+    end
+    local quota_used, quota_window, quota_reset_after = 0, 0, 0
+    if quota > 0 then
+        quota_window = math.floor(now / quota_period)
+        if tonumber(state[4]) == quota_window then
+            quota_used = tonumber(state[3]) or 0
+        end
+        quota_reset_after = (quota_window + 1) * quota_period - now
+        if quota_used >= quota then
+            allowed = 0
+        end
+    end
+    buckets[index] = {
+        key = key, rate = rate, burst = burst, tokens = tokens, quota = quota,
+        quota_used = quota_used, quota_window = quota_window, quota_reset_after = quota_reset_after
+    }
+end
+local limit, remaining, retry_after, reset_after = 0, -1, 0, 0
+for _, bucket in ipairs(buckets) do
+    if allowed == 1 then
+        bucket.tokens = bucket.tokens - 1
+        bucket.quota_used = bucket.quota_used + 1
+    else
+        if bucket.tokens < 1 then
+            retry_after = math.max(retry_after, math.ceil((1 - bucket.tokens) * 1000 / bucket.rate))
+        end
+        if bucket.quota > 0 and bucket.quota_used >= bucket.quota then
+            retry_after = math.max(retry_after, bucket.quota_reset_after)
+        end
+    end
+    local bucket_reset_after = math.ceil((bucket.burst - bucket.tokens) * 1000 / bucket.rate)
+    local expire_after = bucket_reset_after
+    redis.call('HSET', bucket.key, 'tokens', bucket.tokens, 'updated_at', now)
+    if bucket.quota > 0 then
+        redis.call('HSET', bucket.key, 'quota_used', bucket.quota_used, 'quota_window', bucket.quota_window)
+        expire_after = math.max(expire_after, bucket.quota_reset_after)
+    end
+    redis.call('PEXPIRE', bucket.key, math.max(expire_after, 1))
+    if remaining == -1 or math.floor(bucket.tokens) < remaining then
+        limit, remaining, reset_after = bucket.burst, math.floor(bucket.tokens), bucket_reset_after
+    end
+    if bucket.quota > 0 and bucket.quota - bucket.quota_used < remaining then
+        limit, remaining, reset_after = bucket.quota, bucket.quota - bucket.quota_used, bucket.quota_reset_after
+    end
+end
+return { allowed, limit, remaining, retry_after, reset_after }
+"#;
+
+/// Outcome of the rate limit check, used to populate the `X-RateLimit-*` response headers
+#[derive(Clone, Debug)]
+pub struct RateLimitStatus {
+    pub limit: i64,
+    pub remaining: i64,
+    pub reset_after_seconds: i64,
+    pub retry_after_seconds: Option<i64>,
+}
+
+impl RateLimitStatus {
+    pub fn is_limited(&self) -> bool {
+        self.retry_after_seconds.is_some()
+    }
+
+    pub fn get_headers(&self) -> Vec<(HeaderName, HeaderValue)> {
+        let mut rate_limit_headers = vec![
+            (
+                HeaderName::from_static(headers::X_RATELIMIT_LIMIT),
+                HeaderValue::from(self.limit),
+            ),
+            (
+                HeaderName::from_static(headers::X_RATELIMIT_REMAINING),
+                HeaderValue::from(self.remaining),
+            ),
+            (
+                HeaderName::from_static(headers::X_RATELIMIT_RESET),
+                HeaderValue::from(self.reset_after_seconds),
+            ),
+        ];
+        if let Some(retry_after_seconds) = self.retry_after_seconds {
+            rate_limit_headers.push((header::RETRY_AFTER, HeaderValue::from(retry_after_seconds)));
+        }
+        rate_limit_headers
+    }
+}
+
+/// Admin, internal and connector webhook calls are not throttled, API keys are throttled
+/// individually in addition to the merchant wide rules.
+fn get_rate_limit_subject(
+    auth_type: &AuthenticationType,
+) -> Option<(&id_type::MerchantId, Option<&id_type::ApiKeyId>)> {
+    match auth_type {
+        AuthenticationType::ApiKey {
+            merchant_id,
+            key_id,
+            ..
+        } => Some((merchant_id, Some(key_id))),
+        AuthenticationType::MerchantJwt { merchant_id, .. }
+        | AuthenticationType::MerchantJwtWithProfileId { merchant_id, .. }
+        | AuthenticationType::MerchantId { merchant_id }
+        | AuthenticationType::PublishableKey { merchant_id }
+        | AuthenticationType::SdkAuthorization { merchant_id }
+        | AuthenticationType::EmbeddedJwt { merchant_id, .. } => Some((merchant_id, None)),
+        AuthenticationType::AdminApiKey
+        | AuthenticationType::AdminApiAuthWithMerchantId { .. }
+        | AuthenticationType::OrganizationJwt { .. }
+        | AuthenticationType::BasicAuth { .. }
+        | AuthenticationType::UserJwt { .. }
+        | AuthenticationType::SinglePurposeJwt { .. }
+        | AuthenticationType::SinglePurposeOrLoginJwt { .. }
+        | AuthenticationType::WebhookAuth { .. }
+        | AuthenticationType::InternalMerchantIdProfileId { .. }
+        | AuthenticationType::InternalApiKey
+        | AuthenticationType::NoAuth => None,
+    }
+}
+
+/// Route groups are the API identifiers used for locking, flows which do not belong to any group
+/// are only subject to rules which apply to all routes.
+fn get_route_group(flow: &impl router_env::types::FlowMetric) -> Option<String> {
+    lock_utils::ApiIdentifier::from_flow_metric(flow)
+        .map(|api_identifier| api_identifier.to_string())
+}
+
+fn get_rate_limit_rules_cache_key(merchant_id: &id_type::MerchantId) -> String {
+    format!("{}_rules", merchant_id.get_rate_limits_key())
+}
+
+/// The parsed rules are cached in memory as they are looked up on every request, the cache entry
+/// is invalidated whenever the rules are updated or deleted.
+async fn get_rate_limit_rules(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<Vec<rate_limit_api::RateLimitRule>> {
+    let key = merchant_id.get_rate_limits_key();
+    let find_rules_from_db = || async {
+        match state.store.find_config_by_key_from_db(&key).await {
+            Ok(config) => config
+                .config
+                .parse_struct::<Vec<rate_limit_api::RateLimitRule>>("Vec<RateLimitRule>")
+                .change_context(errors::StorageError::DeserializationFailed)
+                .attach_printable("Failed to parse rate limit rules"),
+            Err(error) if error.current_context().is_db_not_found() => Ok(Vec::new()),
+            Err(error) => Err(error),
+        }
+    };
+    let rules = cache::get_or_populate_in_memory(
+        state.store.get_cache_store().as_ref(),
+        &get_rate_limit_rules_cache_key(merchant_id),
+        find_rules_from_db,
+        &CONFIG_CACHE,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to fetch rate limit rules")?;
+
+    Ok(if rules.is_empty() {
+        state
+            .conf
+            .rate_limit
+            .default_limit
+            .as_ref()
+            .map(|default_limit| rate_limit_api::RateLimitRule {
+                key_id: None,
+                route_group: None,
+                requests_per_second: default_limit.requests_per_second,
+                burst: default_limit.burst,
+                quota: None,
+            })
+            .into_iter()
+            .collect()
+    } else {
+        rules
+    })
+}
+
+/// The merchant id is used as the hash tag of the key, so that all the buckets checked for a
+/// request belong to the same slot when Redis runs in cluster mode.
+fn get_bucket_key(
+    merchant_id: &id_type::MerchantId,
+    rule: &rate_limit_api::RateLimitRule,
+) -> String {
+    format!(
+        "{}_{{{}}}_{}_{}",
+        RATE_LIMIT_PREFIX,
+        merchant_id.get_string_repr(),
+        rule.key_id
+            .as_ref()
+            .map(|key_id| key_id.get_string_repr())
+            .unwrap_or("*"),
+        rule.route_group.as_deref().unwrap_or("*")
+    )
+}
+
+/// Consumes a token from every bucket applicable to the request. Returns `None` if the request is
+/// not subject to rate limiting.
+#[instrument(skip_all)]
+pub async fn check_rate_limit(
+    state: &SessionState,
+    flow: &impl router_env::types::FlowMetric,
+    auth_type: &AuthenticationType,
+) -> RouterResult<Option<RateLimitStatus>> {
+    if !state.conf.rate_limit.enabled {
+        return Ok(None);
+    }
+    let Some((merchant_id, key_id)) = get_rate_limit_subject(auth_type) else {
+        return Ok(None);
+    };
+
+    let route_group = get_route_group(flow);
+    let applicable_rules = get_rate_limit_rules(state, merchant_id)
+        .await?
+        .into_iter()
+        .filter(|rule| rule.is_applicable(key_id, route_group.as_deref()))
+        .collect::<Vec<_>>();
+    if applicable_rules.is_empty() {
+        return Ok(None);
+    }
+
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    let buckets = applicable_rules
+        .iter()
+        .map(|rule| (get_bucket_key(merchant_id, rule), rule))
+        .collect::<Vec<_>>();
+
+    consume_tokens(&redis_conn, &buckets).await.map(Some)
+}
+
+async fn consume_tokens(
+    redis_conn: &redis_interface::RedisConnectionWithContext,
+    buckets: &[(String, &rate_limit_api::RateLimitRule)],
+) -> RouterResult<RateLimitStatus> {
+    let keys = buckets
+        .iter()
+        .map(|(bucket_key, _)| redis_conn.add_prefix(bucket_key))
+        .collect::<Vec<_>>();
+    let values = buckets
+        .iter()
+        .flat_map(|(_, rule)| {
+            let (quota_limit, quota_period_in_milliseconds) = rule
+                .quota
+                .as_ref()
+                .map(|quota| {
+                    (
+                        quota.limit,
+                        u64::from(quota.period.get_duration_in_seconds()) * 1000,
+                    )
+                })
+                .unwrap_or_default();
+            [
+                rule.requests_per_second.to_string(),
+                rule.burst.to_string(),
+                quota_limit.to_string(),
+                quota_period_in_milliseconds.to_string(),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    let result = redis_conn
+        .evaluate_redis_script::<_, Vec<i64>>(TOKEN_BUCKET_SCRIPT, keys, values)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to evaluate rate limit token bucket")?;
+    let [allowed, limit, remaining, retry_after_ms, reset_after_ms] = result.as_slice() else {
+        return Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Unexpected response from rate limit token bucket");
+    };
+
+    Ok(RateLimitStatus {
+        limit: *limit,
+        remaining: *remaining,
+        reset_after_seconds: milliseconds_to_seconds(*reset_after_ms),
+        retry_after_seconds: (*allowed == 0).then(|| milliseconds_to_seconds(*retry_after_ms)),
+    })
+}
+
+fn milliseconds_to_seconds(milliseconds: i64) -> i64 {
+    milliseconds.saturating_add(999) / 1000
+}
+
+fn validate_rate_limit_rules(
+    rules: &[rate_limit_api::RateLimitRule],
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let mut scopes = HashSet::new();
+    for rule in rules {
+        when(rule.requests_per_second == 0 || rule.burst == 0, || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "`requests_per_second` and `burst` must be greater than 0".to_string(),
+            }))
+        })?;
+        when(
+            rule.quota.as_ref().is_some_and(|quota| quota.limit == 0),
+            || {
+                Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "`quota.limit` must be greater than 0".to_string(),
+                }))
+            },
+        )?;
+        if let Some(route_group) = rule.route_group.as_deref() {
+            lock_utils::ApiIdentifier::from_str(route_group).map_err(|_| {
+                report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!("`{route_group}` is not a valid route group"),
+                })
+            })?;
+        }
+        when(
+            !scopes.insert((rule.key_id.clone(), rule.route_group.clone())),
+            || {
+                Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "Multiple rules must not have the same `key_id` and `route_group`"
+                        .to_string(),
+                }))
+            },
+        )?;
+    }
+    Ok(())
+}
+
+async fn invalidate_rate_limit_rules(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<()> {
+    cache::redact_from_redis_and_publish(
+        state.store.get_cache_store().as_ref(),
+        [CacheKind::Config(
+            get_rate_limit_rules_cache_key(merchant_id).into(),
+        )],
+    )
+    .await
+    .map(|_| ())
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to invalidate the cached rate limit rules")
+}
+
+pub async fn upsert_rate_limit_config(
+    state: SessionState,
+    request: rate_limit_api::RateLimitConfigRequest,
+) -> RouterResponse<rate_limit_api::RateLimitConfigResponse> {
+    validate_rate_limit_rules(&request.rules)?;
+
+    let db = state.store.as_ref();
+    // check if the merchant account exists
+    db.get_merchant_key_store_by_merchant_id(
+        &request.merchant_id,
+        &db.get_master_key().to_vec().into(),
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let key = request.merchant_id.get_rate_limits_key();
+    let config = serde_json::to_string(&request.rules)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize rate limit rules")?;
+
+    match db.find_config_by_key_from_db(&key).await {
+        Ok(_) => db
+            .update_config_by_key(
+                &key,
+                configs::ConfigUpdate::Update {
+                    config: Some(config),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update rate limit rules")?,
+        Err(error) if error.current_context().is_db_not_found() => db
+            .insert_config(configs::ConfigNew { key, config })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert rate limit rules")?,
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch rate limit rules")?,
+    };
+
+    invalidate_rate_limit_rules(&state, &request.merchant_id).await?;
+
+    Ok(ApplicationResponse::Json(
+        rate_limit_api::RateLimitConfigResponse {
+            merchant_id: request.merchant_id,
+            rules: request.rules,
+        },
+    ))
+}
+
+pub async fn retrieve_rate_limit_config(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+) -> RouterResponse<rate_limit_api::RateLimitConfigResponse> {
+    let rules = state
+        .store
+        .find_config_by_key(&merchant_id.get_rate_limits_key())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ConfigNotFound)?
+        .config
+        .parse_struct::<Vec<rate_limit_api::RateLimitRule>>("Vec<RateLimitRule>")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse rate limit rules")?;
+
+    Ok(ApplicationResponse::Json(
+        rate_limit_api::RateLimitConfigResponse { merchant_id, rules },
+    ))
+}
+
+pub async fn delete_rate_limit_config(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+) -> RouterResponse<rate_limit_api::RateLimitConfigDeleteResponse> {
+    state
+        .store
+        .delete_config_by_key(&merchant_id.get_rate_limits_key())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ConfigNotFound)?;
+    invalidate_rate_limit_rules(&state, &merchant_id).await?;
+
+    Ok(ApplicationResponse::Json(
+        rate_limit_api::RateLimitConfigDeleteResponse {
+            merchant_id,
+            deleted: true,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+    use std::borrow::Cow;
+
+    use common_utils::types::keymanager::KeyManagerState;
+    use storage_impl::redis::kv_store::RedisConnInterface;
+
+    use super::*;
+    use crate::db::MockDb;
+
+    fn get_rule(
+        key_id: Option<&'static str>,
+        route_group: Option<&str>,
+        requests_per_second: u32,
+        burst: u32,
+    ) -> rate_limit_api::RateLimitRule {
+        rate_limit_api::RateLimitRule {
+            key_id: key_id.map(|key_id| id_type::ApiKeyId::try_from(Cow::from(key_id)).unwrap()),
+            route_group: route_group.map(str::to_string),
+            requests_per_second,
+            burst,
+            quota: None,
+        }
+    }
+
+    fn get_rule_with_quota(limit: u32) -> rate_limit_api::RateLimitRule {
+        rate_limit_api::RateLimitRule {
+            quota: Some(rate_limit_api::RateLimitQuota {
+                limit,
+                period: rate_limit_api::QuotaPeriod::Day,
+            }),
+            ..get_rule(None, None, 100, 100)
+        }
+    }
+
+    fn get_bucket_key_for_test(name: &str) -> String {
+        format!(
+            "{RATE_LIMIT_PREFIX}_{{test}}_{}",
+            common_utils::generate_time_ordered_id(name)
+        )
+    }
+
+    async fn get_redis_conn() -> redis_interface::RedisConnectionWithContext {
+        MockDb::new(
+            &redis_interface::RedisSettings::default(),
+            KeyManagerState::mock(),
+        )
+        .await
+        .expect("Failed to create mock store")
+        .get_redis_conn()
+        .unwrap()
+    }
+
+    #[test]
+    fn test_valid_rules_are_accepted() {
+        let rules = [
+            get_rule(None, None, 10, 20),
+            get_rule(Some("key_1"), None, 5, 5),
+            get_rule(None, Some("payments"), 1, 1),
+            get_rule(Some("key_1"), Some("payments"), 1, 1),
+        ];
+        assert!(validate_rate_limit_rules(&rules).is_ok());
+    }
+
+    #[test]
+    fn test_rules_with_zero_rate_or_burst_are_rejected() {
+        assert!(validate_rate_limit_rules(&[get_rule(None, None, 0, 10)]).is_err());
+        assert!(validate_rate_limit_rules(&[get_rule(None, None, 10, 0)]).is_err());
+    }
+
+    #[test]
+    fn test_rule_with_zero_quota_is_rejected() {
+        assert!(validate_rate_limit_rules(&[get_rule_with_quota(0)]).is_err());
+        assert!(validate_rate_limit_rules(&[get_rule_with_quota(1)]).is_ok());
+    }
+
+    #[test]
+    fn test_bucket_keys_of_a_merchant_share_the_hash_tag() {
+        let merchant_id = id_type::MerchantId::try_from(Cow::from("merchant_1")).unwrap();
+        let merchant_key = get_bucket_key(&merchant_id, &get_rule(None, None, 1, 1));
+        let api_key_key = get_bucket_key(
+            &merchant_id,
+            &get_rule(Some("key_1"), Some("payments"), 1, 1),
+        );
+
+        assert_eq!(merchant_key, "RATE_LIMIT_{merchant_1}_*_*");
+        assert_eq!(api_key_key, "RATE_LIMIT_{merchant_1}_key_1_payments");
+    }
+
+    #[test]
+    fn test_rule_with_unknown_route_group_is_rejected() {
+        assert!(validate_rate_limit_rules(&[get_rule(None, Some("not_a_group"), 1, 1)]).is_err());
+    }
+
+    #[test]
+    fn test_rules_with_the_same_scope_are_rejected() {
+        let rules = [
+            get_rule(Some("key_1"), Some("payments"), 1, 1),
+            get_rule(Some("key_1"), Some("payments"), 2, 2),
+        ];
+        assert!(validate_rate_limit_rules(&rules).is_err());
+    }
+
+    #[test]
+    fn test_milliseconds_are_rounded_up_to_seconds() {
+        assert_eq!(milliseconds_to_seconds(0), 0);
+        assert_eq!(milliseconds_to_seconds(1), 1);
+        assert_eq!(milliseconds_to_seconds(1000), 1);
+        assert_eq!(milliseconds_to_seconds(1001), 2);
+    }
+
+    #[tokio::test]
+    async fn test_token_bucket_allows_burst_and_then_limits() {
+        let redis_conn = get_redis_conn().await;
+        let rule = get_rule(None, None, 1, 2);
+        let buckets = [(get_bucket_key_for_test("burst"), &rule)];
+
+        let first = consume_tokens(&redis_conn, &buckets).await.unwrap();
+        assert!(!first.is_limited());
+        assert_eq!(first.limit, 2);
+        assert_eq!(first.remaining, 1);
+
+        let second = consume_tokens(&redis_conn, &buckets).await.unwrap();
+        assert!(!second.is_limited());
+        assert_eq!(second.remaining, 0);
+
+        let third = consume_tokens(&redis_conn, &buckets).await.unwrap();
+        assert!(third.is_limited());
+        assert_eq!(third.remaining, 0);
+        assert_eq!(third.retry_after_seconds, Some(1));
+    }
+
+    #[tokio::test]
+    async fn test_token_bucket_does_not_consume_when_any_bucket_is_exhausted() {
+        let redis_conn = get_redis_conn().await;
+        let merchant_rule = get_rule(None, None, 1, 5);
+        let key_rule = get_rule(Some("key_1"), None, 1, 1);
+        let merchant_bucket = (get_bucket_key_for_test("merchant"), &merchant_rule);
+        let key_bucket = (get_bucket_key_for_test("key"), &key_rule);
+        let buckets = [merchant_bucket.clone(), key_bucket];
+
+        let first = consume_tokens(&redis_conn, &buckets).await.unwrap();
+        assert!(!first.is_limited());
+        // The status reports the bucket closest to being exhausted
+        assert_eq!(first.limit, 1);
+        assert_eq!(first.remaining, 0);
+
+        let second = consume_tokens(&redis_conn, &buckets).await.unwrap();
+        assert!(second.is_limited());
+
+        // The limited request did not consume a token from the merchant wide bucket
+        let merchant_only = consume_tokens(&redis_conn, &[merchant_bucket])
+            .await
+            .unwrap();
+        assert!(!merchant_only.is_limited());
+        assert_eq!(merchant_only.remaining, 3);
+    }
+
+    #[tokio::test]
+    async fn test_quota_limits_requests_once_used_up() {
+        let redis_conn = get_redis_conn().await;
+        let rule = get_rule_with_quota(2);
+        let buckets = [(get_bucket_key_for_test("quota"), &rule)];
+
+        let first = consume_tokens(&redis_conn, &buckets).await.unwrap();
+        assert!(!first.is_limited());
+        // The quota is closer to being exhausted than the token bucket
+        assert_eq!(first.limit, 2);
+        assert_eq!(first.remaining, 1);
+
+        let second = consume_tokens(&redis_conn, &buckets).await.unwrap();
+        assert!(!second.is_limited());
+        assert_eq!(second.remaining, 0);
+
+        let third = consume_tokens(&redis_conn, &buckets).await.unwrap();
+        assert!(third.is_limited());
+        assert_eq!(third.remaining, 0);
+        assert!(third.retry_after_seconds.unwrap() > 0);
+    }
+}
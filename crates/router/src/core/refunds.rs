@@ -100,14 +100,14 @@ pub async fn refund_create_core(
     payment_intent.prevent_refund_after_post_capture_void()?;
 
     // Amount is not passed in request refer from payment intent.
-    amount = req
+    let requested_amount = req
         .amount
         .or(payment_intent.amount_captured)
         .ok_or(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("amount captured is none in a successful payment")?;
 
     //[#299]: Can we change the flow based on some workflow idea
-    utils::when(amount <= MinorUnit::new(0), || {
+    utils::when(requested_amount <= MinorUnit::new(0), || {
         Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
             field_name: "amount".to_string(),
             expected_format: "positive integer".to_string(),
@@ -124,6 +124,13 @@ pub async fn refund_create_core(
         ).await
         .to_not_found_response(errors::ApiErrorResponse::SuccessfulPaymentNotFound)?;
 
+    // Refund amounts of payments charged with dynamic currency conversion are given in the
+    // original currency and refunded in the charged currency at the rate locked for the payment
+    amount = payments::dynamic_currency_conversion::get_attempt_amount(
+        payment_attempt.dcc_details.as_ref(),
+        requested_amount,
+    )?;
+
     let creds_identifier = req
         .merchant_connector_details
         .as_ref()
@@ -1339,8 +1346,17 @@ pub async fn validate_and_create_refund(
             ),
         })?;
 
+    // The intent is kept in the currency of the merchant, while refunds are made in the currency
+    // the customer was charged in
     let total_amount_captured = payment_intent
         .amount_captured
+        .map(|amount_captured| {
+            payments::dynamic_currency_conversion::get_attempt_amount(
+                payment_attempt.dcc_details.as_ref(),
+                amount_captured,
+            )
+        })
+        .transpose()?
         .unwrap_or(payment_attempt.get_total_amount());
 
     validator::validate_refund_amount(
@@ -55,19 +55,23 @@ pub async fn refund_create_core(
         .await
         .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
 
-    utils::when(
-        !(payment_intent.status == enums::IntentStatus::Succeeded
-            || payment_intent.status == enums::IntentStatus::PartiallyCaptured),
-        || {
-            Err(report!(errors::ApiErrorResponse::PaymentUnexpectedState {
-                current_flow: "refund".into(),
-                field_name: "status".into(),
-                current_value: payment_intent.status.to_string(),
-                states: "succeeded, partially_captured".to_string()
-            })
-            .attach_printable("unable to refund for a unsuccessful payment intent"))
-        },
-    )?;
+    let is_refundable_status = match payment_intent.status {
+        enums::IntentStatus::Succeeded | enums::IntentStatus::PartiallyCaptured => true,
+        // A split payment is conflicted when some of its funding legs could not be reversed after
+        // a later leg failed, the amount still captured on those legs can be refunded
+        enums::IntentStatus::Conflicted => payment_intent.is_split_payment(),
+        _ => false,
+    };
+
+    utils::when(!is_refundable_status, || {
+        Err(report!(errors::ApiErrorResponse::PaymentUnexpectedState {
+            current_flow: "refund".into(),
+            field_name: "status".into(),
+            current_value: payment_intent.status.to_string(),
+            states: "succeeded, partially_captured".to_string()
+        })
+        .attach_printable("unable to refund for a unsuccessful payment intent"))
+    })?;
 
     let captured_amount = payment_intent
         .amount_captured
@@ -85,6 +89,24 @@ pub async fn refund_create_core(
         .attach_printable("amount less than or equal to zero"))
     })?;
 
+    if payment_intent.is_split_payment() {
+        let refund_legs =
+            get_split_payment_refund_legs(&state, &platform, &payment_intent, amount).await?;
+
+        tracing::Span::current().record("global_refund_id", global_refund_id.get_string_repr());
+
+        return Box::pin(create_split_payment_refunds(
+            &state,
+            &platform,
+            &payment_intent,
+            refund_legs,
+            req,
+            global_refund_id,
+        ))
+        .await
+        .map(services::ApplicationResponse::Json);
+    }
+
     payment_attempt = db
         .find_payment_attempt_last_successful_or_partially_captured_attempt_by_payment_id(
             platform.get_processor().get_key_store(),
@@ -112,6 +134,165 @@ pub async fn refund_create_core(
     .map(services::ApplicationResponse::Json)
 }
 
+/// Splits the refund of a split payment across its charged funding legs. Legs are refunded in
+/// the reverse of the order in which they were charged, so that the payment method covering the
+/// remaining amount is refunded before the balance payment methods such as gift cards.
+#[instrument(skip_all)]
+async fn get_split_payment_refund_legs(
+    state: &SessionState,
+    platform: &domain::Platform,
+    payment_intent: &storage::PaymentIntent,
+    refund_amount: common_utils_types::MinorUnit,
+) -> errors::RouterResult<Vec<(storage::PaymentAttempt, common_utils_types::MinorUnit)>> {
+    let db = &*state.store;
+
+    let payment_attempts = db
+        .find_payment_attempts_by_payment_intent_id(
+            &payment_intent.id,
+            platform.get_processor().get_key_store(),
+            platform.get_processor().get_account().storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::SuccessfulPaymentNotFound)?;
+
+    let mut charged_legs: Vec<_> = payment_attempts
+        .into_iter()
+        .filter(|attempt| {
+            attempt.attempts_group_id.is_some()
+                && attempt.attempts_group_id == payment_intent.active_attempts_group_id
+                && matches!(
+                    attempt.status,
+                    enums::AttemptStatus::Charged | enums::AttemptStatus::PartialCharged
+                )
+        })
+        .collect();
+    charged_legs.sort_by(|leg, other_leg| other_leg.created_at.cmp(&leg.created_at));
+
+    let mut refundable_legs = Vec::with_capacity(charged_legs.len());
+    for payment_attempt in charged_legs {
+        let Some(connector_payment_id) = payment_attempt.connector_payment_id.as_ref() else {
+            continue;
+        };
+
+        let leg_refunds = db
+            .find_refund_by_processor_merchant_id_connector_transaction_id(
+                platform.get_processor().get_account().get_id(),
+                connector_payment_id,
+                platform.get_processor().get_account().storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::RefundNotFound)?;
+
+        let refunded_amount = leg_refunds
+            .iter()
+            .filter(|refund| {
+                !matches!(
+                    refund.refund_status,
+                    enums::RefundStatus::Failure | enums::RefundStatus::TransactionFailure
+                )
+            })
+            .fold(common_utils_types::MinorUnit::zero(), |amount, refund| {
+                amount + refund.refund_amount
+            });
+
+        let refundable_amount = payment_attempt.get_total_amount() - refunded_amount;
+        refundable_legs.push((payment_attempt, refundable_amount));
+    }
+
+    allocate_split_payment_refund(refundable_legs, refund_amount)
+        .ok_or(errors::ApiErrorResponse::RefundAmountExceedsPaymentAmount)
+        .attach_printable("refund amount exceeds the refundable amount of the split payment")
+}
+
+/// Allocates the refund amount to the legs, given in the order in which they are to be refunded
+/// along with their refundable amount. Returns `None` when the legs cannot cover the refund.
+fn allocate_split_payment_refund<T>(
+    refundable_legs: Vec<(T, common_utils_types::MinorUnit)>,
+    refund_amount: common_utils_types::MinorUnit,
+) -> Option<Vec<(T, common_utils_types::MinorUnit)>> {
+    let mut remaining_amount = refund_amount;
+    let mut refund_legs = Vec::new();
+
+    for (leg, refundable_amount) in refundable_legs {
+        if remaining_amount <= common_utils_types::MinorUnit::zero() {
+            break;
+        }
+        if refundable_amount <= common_utils_types::MinorUnit::zero() {
+            continue;
+        }
+
+        let leg_refund_amount = refundable_amount.min(remaining_amount);
+        remaining_amount = remaining_amount - leg_refund_amount;
+        refund_legs.push((leg, leg_refund_amount));
+    }
+
+    (remaining_amount <= common_utils_types::MinorUnit::zero()).then_some(refund_legs)
+}
+
+/// Creates a refund on each of the funding legs of a split payment. The refund on the first leg
+/// carries the identifiers of the request and is returned, the refunds on the other legs are
+/// listed along with the other refunds of the payment.
+#[instrument(skip_all)]
+async fn create_split_payment_refunds(
+    state: &SessionState,
+    platform: &domain::Platform,
+    payment_intent: &storage::PaymentIntent,
+    refund_legs: Vec<(storage::PaymentAttempt, common_utils_types::MinorUnit)>,
+    req: refunds::RefundsCreateRequest,
+    global_refund_id: id_type::GlobalRefundId,
+) -> errors::RouterResult<refunds::RefundResponse> {
+    let mut refund_legs = refund_legs.into_iter();
+    let (payment_attempt, refund_amount) = refund_legs
+        .next()
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("no funding leg to refund in split payment")?;
+
+    let leg_refund_request = req.clone();
+    let merchant_connector_details = req.merchant_connector_details.clone();
+    let refund_response = Box::pin(validate_and_create_refund(
+        state,
+        platform,
+        &payment_attempt,
+        payment_intent,
+        refund_amount,
+        req,
+        global_refund_id,
+        merchant_connector_details,
+    ))
+    .await?;
+
+    for (leg_payment_attempt, leg_refund_amount) in refund_legs {
+        let leg_refund_id = id_type::GlobalRefundId::generate(&state.conf.cell_information.id);
+        let leg_merchant_reference_id = id_type::RefundReferenceId::try_from(
+            std::borrow::Cow::Owned(leg_refund_id.get_string_repr().to_owned()),
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to construct the refund reference id")?;
+
+        let leg_refund_request = refunds::RefundsCreateRequest {
+            merchant_reference_id: leg_merchant_reference_id,
+            amount: Some(leg_refund_amount),
+            return_raw_connector_response: None,
+            ..leg_refund_request.clone()
+        };
+        let merchant_connector_details = leg_refund_request.merchant_connector_details.clone();
+
+        Box::pin(validate_and_create_refund(
+            state,
+            platform,
+            &leg_payment_attempt,
+            payment_intent,
+            leg_refund_amount,
+            leg_refund_request,
+            leg_refund_id,
+            merchant_connector_details,
+        ))
+        .await?;
+    }
+
+    Ok(refund_response)
+}
+
 #[allow(clippy::too_many_arguments)]
 #[instrument(skip_all)]
 pub async fn trigger_refund_to_gateway(
@@ -724,16 +905,12 @@ pub async fn refund_retrieve_core(
         .await
         .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
 
-    let active_attempt_id = payment_intent
-        .active_attempt_id
-        .clone()
-        .ok_or(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Active attempt id not found")?;
-
+    // The refund of a split payment is processed on one of its funding legs, which need not be
+    // the active attempt of the payment intent
     let payment_attempt = db
         .find_payment_attempt_by_id(
             processor.get_key_store(),
-            &active_attempt_id,
+            &refund.attempt_id,
             processor.get_account().storage_scheme,
         )
         .await
@@ -1200,9 +1377,14 @@ pub async fn validate_and_create_refund(
         ),
     })?;
 
-    let total_amount_captured = payment_intent
-        .amount_captured
-        .unwrap_or(payment_attempt.get_total_amount());
+    // Each funding leg of a split payment is refunded on its own
+    let total_amount_captured = if payment_intent.is_split_payment() {
+        payment_attempt.get_total_amount()
+    } else {
+        payment_intent
+            .amount_captured
+            .unwrap_or(payment_attempt.get_total_amount())
+    };
 
     refunds_validator::validate_refund_amount(
         total_amount_captured.get_amount_as_i64(),
@@ -1577,3 +1759,56 @@ pub async fn add_refund_sync_task(
 
     Ok(response)
 }
+
+#[cfg(test)]
+mod tests {
+    use common_utils::types::MinorUnit;
+
+    use super::*;
+
+    #[test]
+    fn test_split_payment_refund_is_allocated_in_leg_order() {
+        let refund_legs = allocate_split_payment_refund(
+            vec![
+                ("card", MinorUnit::new(700)),
+                ("gift_card", MinorUnit::new(300)),
+            ],
+            MinorUnit::new(800),
+        );
+
+        assert_eq!(
+            refund_legs,
+            Some(vec![
+                ("card", MinorUnit::new(700)),
+                ("gift_card", MinorUnit::new(100))
+            ])
+        );
+    }
+
+    #[test]
+    fn test_split_payment_refund_skips_refunded_legs() {
+        let refund_legs = allocate_split_payment_refund(
+            vec![
+                ("card", MinorUnit::zero()),
+                ("gift_card", MinorUnit::new(300)),
+                ("other_gift_card", MinorUnit::new(200)),
+            ],
+            MinorUnit::new(300),
+        );
+
+        assert_eq!(refund_legs, Some(vec![("gift_card", MinorUnit::new(300))]));
+    }
+
+    #[test]
+    fn test_split_payment_refund_exceeding_legs_is_rejected() {
+        let refund_legs = allocate_split_payment_refund(
+            vec![
+                ("card", MinorUnit::new(700)),
+                ("gift_card", MinorUnit::new(300)),
+            ],
+            MinorUnit::new(1001),
+        );
+
+        assert_eq!(refund_legs, None);
+    }
+}
@@ -0,0 +1,235 @@
+use analytics::{
+    errors::AnalyticsError,
+    lambda_utils::invoke_lambda,
+    reports::{ReportBackend, ReportKind},
+};
+use api_models::analytics::GenerateReportRequest;
+use common_utils::{date_time, errors::CustomResult, types::authentication::AuthInfo};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult},
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::storage,
+};
+
+const REPORT_GENERATION_TASK: &str = "REPORT_GENERATION";
+const REPORT_GENERATION_TAGS: [&str; 1] = ["REPORT"];
+const REPORT_ID_PREFIX: &str = "report";
+
+/// Tracking data of the process tracker task generating a report.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ReportGenerationTrackingData {
+    pub report_id: String,
+    pub kind: ReportKind,
+    pub request: GenerateReportRequest,
+}
+
+/// The payload posted to the `return_url` of a report request once the report is available.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ReportGeneratedNotification {
+    pub report_id: String,
+    pub report_type: ReportKind,
+    pub download_url: String,
+}
+
+/// Hands a report request off to the configured report backend.
+#[instrument(skip_all)]
+pub async fn generate_report(
+    state: &SessionState,
+    kind: ReportKind,
+    request: GenerateReportRequest,
+) -> CustomResult<(), AnalyticsError> {
+    let report_config = &state.conf.report_download_config;
+
+    match report_config.backend {
+        ReportBackend::Lambda => {
+            let function_name = match kind {
+                ReportKind::Payment => &report_config.payment_function,
+                ReportKind::Refund => &report_config.refund_function,
+                ReportKind::Dispute => &report_config.dispute_function,
+            };
+            let json_bytes =
+                serde_json::to_vec(&request).map_err(|_| AnalyticsError::UnknownError)?;
+            invoke_lambda(function_name, &report_config.region, &json_bytes).await
+        }
+        ReportBackend::Native => schedule_report_generation(state, kind, request).await,
+    }
+}
+
+async fn schedule_report_generation(
+    state: &SessionState,
+    kind: ReportKind,
+    request: GenerateReportRequest,
+) -> CustomResult<(), AnalyticsError> {
+    let report_id = common_utils::generate_id(consts::ID_LENGTH, REPORT_ID_PREFIX);
+    let runner = storage::ProcessTrackerRunner::ReportGenerationWorkflow;
+    let process_tracker_id = format!("{runner}_{REPORT_GENERATION_TASK}_{report_id}");
+
+    // The payment response hash key is looked up again when the report notification is sent,
+    // rather than being stored in the tracking data
+    let tracking_data = ReportGenerationTrackingData {
+        report_id: report_id.clone(),
+        kind,
+        request: GenerateReportRequest {
+            payment_response_hash_key: None,
+            ..request
+        },
+    };
+
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        REPORT_GENERATION_TASK,
+        runner,
+        REPORT_GENERATION_TAGS,
+        tracking_data,
+        None,
+        date_time::now(),
+        common_types::consts::API_VERSION,
+        common_enums::ApplicationSource::Main,
+    )
+    .change_context(AnalyticsError::UnknownError)
+    .attach_printable("Failed to create ProcessTrackerNew for report generation")?;
+
+    state
+        .store
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to enqueue report generation ProcessTracker task")?;
+
+    logger::info!(%report_id, report_type = %kind, "Scheduled report generation");
+
+    Ok(())
+}
+
+/// Report ids are only ever generated by [`schedule_report_generation`], any other value is
+/// rejected before it is used in a file key.
+fn validate_report_id(report_id: &str) -> RouterResult<()> {
+    let is_valid = report_id
+        .strip_prefix(REPORT_ID_PREFIX)
+        .and_then(|id| id.strip_prefix('_'))
+        .is_some_and(|id| {
+            id.len() == consts::ID_LENGTH && id.chars().all(|c| c.is_ascii_alphanumeric())
+        });
+
+    common_utils::fp_utils::when(!is_valid, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Invalid report id".to_string(),
+        }))
+    })
+}
+
+/// Key of a generated report in the file storage. Reports are stored under the entity which
+/// requested them, so that they can only be downloaded by the same entity.
+pub fn get_report_file_key(auth: &AuthInfo, report_id: &str) -> RouterResult<String> {
+    validate_report_id(report_id)?;
+
+    let scope = match auth {
+        AuthInfo::OrgLevel { org_id } => org_id.get_string_repr().to_owned(),
+        AuthInfo::MerchantLevel {
+            org_id,
+            merchant_ids,
+            processor_merchant_ids,
+        } => [org_id.get_string_repr()]
+            .into_iter()
+            .chain(merchant_ids.iter().map(|id| id.get_string_repr()))
+            .chain(
+                processor_merchant_ids
+                    .iter()
+                    .flatten()
+                    .map(|id| id.get_string_repr()),
+            )
+            .collect::<Vec<_>>()
+            .join("/"),
+        AuthInfo::ProfileLevel {
+            org_id,
+            merchant_id,
+            profile_ids,
+            processor_merchant_id,
+        } => [org_id.get_string_repr(), merchant_id.get_string_repr()]
+            .into_iter()
+            .chain(processor_merchant_id.iter().map(|id| id.get_string_repr()))
+            .chain(profile_ids.iter().map(|id| id.get_string_repr()))
+            .collect::<Vec<_>>()
+            .join("/"),
+    };
+
+    Ok(format!("reports/{scope}/{report_id}.csv"))
+}
+
+/// URL of the download endpoint of a generated report, for the entity which requested it.
+pub fn get_report_download_url(base_url: &str, auth: &AuthInfo, report_id: &str) -> String {
+    let scope = match auth {
+        AuthInfo::OrgLevel { .. } => "org/",
+        AuthInfo::MerchantLevel { .. } => "",
+        AuthInfo::ProfileLevel { .. } => "profile/",
+    };
+
+    format!("{base_url}/analytics/v1/{scope}report/{report_id}/download")
+}
+
+#[instrument(skip_all)]
+pub async fn download_report(
+    state: SessionState,
+    auth: AuthInfo,
+    report_id: String,
+) -> RouterResponse<()> {
+    let file_key = get_report_file_key(&auth, &report_id)?;
+    let report = state
+        .file_storage_client
+        .retrieve_file(&file_key)
+        .await
+        .change_context(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Report with the given id '{report_id}' does not exist"),
+        })?;
+
+    Ok(ApplicationResponse::FileData((report, mime::TEXT_CSV)))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+
+    use common_utils::id_type;
+
+    use super::*;
+
+    fn org_auth() -> AuthInfo {
+        AuthInfo::OrgLevel {
+            org_id: id_type::OrganizationId::try_from_string("org_1".to_string()).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_generated_report_ids_are_valid() {
+        let report_id = common_utils::generate_id(consts::ID_LENGTH, REPORT_ID_PREFIX);
+
+        assert_eq!(
+            get_report_file_key(&org_auth(), &report_id).unwrap(),
+            format!("reports/org_1/{report_id}.csv")
+        );
+    }
+
+    #[test]
+    fn test_invalid_report_ids_are_rejected() {
+        for report_id in [
+            "",
+            "report_",
+            "report_abc",
+            "payment_0123456789abcdefghij",
+            "report_0123456789abcdefghi/",
+            "report_../../0123456789abcd",
+            "report_0123456789abcdefghijk",
+            "../report_0123456789abcdefghij",
+        ] {
+            assert!(
+                get_report_file_key(&org_auth(), report_id).is_err(),
+                "{report_id}"
+            );
+        }
+    }
+}
@@ -1,3 +1,5 @@
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod backtest;
 pub mod helpers;
 pub mod transformers;
 use std::collections::{HashMap, HashSet};
@@ -277,6 +279,8 @@ pub struct PaymentsDslInput<'a> {
     pub address: &'a payment_address::PaymentAddress,
     pub recurring_details: Option<&'a mandates_api::RecurringDetails>,
     pub currency: storage_enums::Currency,
+    /// Timezone the time based routing keys are evaluated in, UTC when not set
+    pub routing_timezone: Option<&'a str>,
 }
 
 impl<'a> PaymentsDslInput<'a> {
@@ -297,6 +301,14 @@ impl<'a> PaymentsDslInput<'a> {
             address,
             recurring_details,
             currency,
+            routing_timezone: None,
+        }
+    }
+
+    pub fn with_routing_timezone(self, routing_timezone: Option<&'a str>) -> Self {
+        Self {
+            routing_timezone,
+            ..self
         }
     }
 }
@@ -516,6 +528,15 @@ pub async fn create_routing_algorithm_under_profile(
 
     algorithm_helper.validate_connectors_in_routing_config()?;
 
+    let analysis_warnings = helpers::get_routing_algorithm_analysis_warnings(
+        &state,
+        processor.get_key_store(),
+        business_profile.get_id(),
+        &transaction_type,
+        &request.algorithm,
+    )
+    .await;
+
     let algo = RoutingAlgorithmUpdate::create_new_routing_algorithm(
         &request,
         &platform,
@@ -530,7 +551,10 @@ pub async fn create_routing_algorithm_under_profile(
         .await
         .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
 
-    let new_record = record.foreign_into();
+    let new_record = routing_types::RoutingDictionaryRecord {
+        analysis_warnings,
+        ..record.foreign_into()
+    };
 
     metrics::ROUTING_CREATE_SUCCESS_RESPONSE.add(1, &[]);
     Ok(service_api::ApplicationResponse::Json(new_record))
@@ -591,6 +615,15 @@ pub async fn create_routing_algorithm_under_profile(
 
     core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
 
+    let analysis_warnings = helpers::get_routing_algorithm_analysis_warnings(
+        &state,
+        processor.get_key_store(),
+        &profile_id,
+        &transaction_type,
+        &algorithm,
+    )
+    .await;
+
     if algorithm.should_validate_connectors_in_routing_config() {
         helpers::validate_connectors_in_routing_config(
             &state,
@@ -654,6 +687,12 @@ pub async fn create_routing_algorithm_under_profile(
                 );
                 None
             }
+            EuclidAlgorithm::CostBased(_) => {
+                router_env::logger::debug!(
+                    "decision_engine_euclid: cost based routing is evaluated locally, skipping dual-write"
+                );
+                None
+            }
         };
 
         if let Some(static_algorithm) = maybe_static_algorithm {
@@ -729,7 +768,10 @@ pub async fn create_routing_algorithm_under_profile(
         .await
         .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
 
-    let new_record = record.foreign_into();
+    let new_record = routing_types::RoutingDictionaryRecord {
+        analysis_warnings,
+        ..record.foreign_into()
+    };
 
     metrics::ROUTING_CREATE_SUCCESS_RESPONSE.add(1, &[]);
     Ok(service_api::ApplicationResponse::Json(new_record))
@@ -809,6 +851,7 @@ pub async fn link_routing_config(
     authentication_profile_id: Option<common_utils::id_type::ProfileId>,
     algorithm_id: common_utils::id_type::RoutingId,
     transaction_type: enums::TransactionType,
+    canary_traffic_percentage: Option<u8>,
 ) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
     metrics::ROUTING_LINK_CONFIG.add(1, &[]);
     let db = state.store.as_ref();
@@ -834,6 +877,19 @@ pub async fn link_routing_config(
 
     core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
 
+    if let Some(traffic_percentage) = canary_traffic_percentage {
+        validate_canary_traffic_percentage(traffic_percentage)?;
+        utils::when(
+            routing_algorithm.kind == diesel_models::enums::RoutingAlgorithmKind::Dynamic,
+            || {
+                Err(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "Dynamic routing algorithms cannot be activated as a canary"
+                        .to_string(),
+                })
+            },
+        )?;
+    }
+
     match routing_algorithm.kind {
         diesel_models::enums::RoutingAlgorithmKind::Dynamic => {
             let mut dynamic_routing_ref: routing_types::DynamicRoutingAlgorithmRef =
@@ -1046,7 +1102,8 @@ pub async fn link_routing_config(
         | diesel_models::enums::RoutingAlgorithmKind::Priority
         | diesel_models::enums::RoutingAlgorithmKind::Advanced
         | diesel_models::enums::RoutingAlgorithmKind::VolumeSplit
-        | diesel_models::enums::RoutingAlgorithmKind::ThreeDsDecisionRule => {
+        | diesel_models::enums::RoutingAlgorithmKind::ThreeDsDecisionRule
+        | diesel_models::enums::RoutingAlgorithmKind::CostBased => {
             let mut routing_ref: routing_types::RoutingAlgorithmRef = business_profile
                 .routing_algorithm
                 .clone()
@@ -1075,7 +1132,19 @@ pub async fn link_routing_config(
                     })
                 },
             )?;
-            routing_ref.update_algorithm_id(algorithm_id);
+
+            match canary_traffic_percentage {
+                Some(traffic_percentage) => {
+                    utils::when(routing_ref.algorithm_id.is_none(), || {
+                        Err(errors::ApiErrorResponse::PreconditionFailed {
+                            message: "A canary can only be activated alongside an active algorithm"
+                                .to_string(),
+                        })
+                    })?;
+                    routing_ref.update_canary(algorithm_id, traffic_percentage);
+                }
+                None => routing_ref.update_algorithm_id(algorithm_id),
+            }
             helpers::update_profile_active_algorithm_ref(
                 db,
                 processor.get_key_store(),
@@ -1086,7 +1155,12 @@ pub async fn link_routing_config(
             .await?;
         }
     };
-    if let Some(euclid_routing_id) = routing_algorithm.decision_engine_routing_id.clone() {
+    // a canary is evaluated locally, the decision engine only mirrors the active algorithm
+    if let Some(euclid_routing_id) = routing_algorithm
+        .decision_engine_routing_id
+        .clone()
+        .filter(|_| canary_traffic_percentage.is_none())
+    {
         let routing_algo = ActivateRoutingConfigRequest {
             created_by: business_profile.get_id().get_string_repr().to_string(),
             routing_algorithm_id: euclid_routing_id,
@@ -1308,6 +1382,7 @@ pub async fn unlink_routing_config(
                             timestamp,
                             config_algo_id: routing_algo_ref.config_algo_id.clone(),
                             surcharge_config_algo_id: routing_algo_ref.surcharge_config_algo_id,
+                            canary: None,
                         };
 
                     let record = db
@@ -1358,6 +1433,126 @@ pub async fn unlink_routing_config(
     }
 }
 
+#[cfg(feature = "v1")]
+fn validate_canary_traffic_percentage(traffic_percentage: u8) -> RouterResult<()> {
+    utils::when(!(1..=99).contains(&traffic_percentage), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Canary traffic percentage must be between 1 and 99".to_string(),
+        }
+        .into())
+    })
+}
+
+#[cfg(feature = "v1")]
+pub async fn update_routing_canary(
+    state: SessionState,
+    processor: domain::Processor,
+    request: routing_types::RoutingCanaryRequest,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+) -> RouterResponse<routing_types::RoutingCanaryResponse> {
+    metrics::ROUTING_UPDATE_CANARY.add(1, &[]);
+    let db = state.store.as_ref();
+    let transaction_type = request
+        .transaction_type
+        .unwrap_or(enums::TransactionType::Payment);
+
+    let business_profile =
+        core_utils::validate_and_get_business_profile(db, &processor, Some(&request.profile_id))
+            .await?
+            .get_required_value("Profile")
+            .change_context(errors::ApiErrorResponse::ProfileNotFound {
+                id: request.profile_id.get_string_repr().to_owned(),
+            })?;
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    let mut routing_ref: routing_types::RoutingAlgorithmRef = match transaction_type {
+        enums::TransactionType::Payment => business_profile.routing_algorithm.clone(),
+        #[cfg(feature = "payouts")]
+        enums::TransactionType::Payout => business_profile.payout_routing_algorithm.clone(),
+        enums::TransactionType::ThreeDsAuthentication => {
+            business_profile.three_ds_decision_rule_algorithm.clone()
+        }
+    }
+    .map(|val| val.parse_value("RoutingAlgorithmRef"))
+    .transpose()
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("unable to deserialize routing algorithm ref from business profile")?
+    .unwrap_or_default();
+
+    let no_canary = || errors::ApiErrorResponse::PreconditionFailed {
+        message: "No canary is active on the profile".to_string(),
+    };
+    let promoted_decision_engine_routing_id = match request.action {
+        routing_types::RoutingCanaryAction::Ramp { traffic_percentage } => {
+            validate_canary_traffic_percentage(traffic_percentage)?;
+            routing_ref
+                .ramp_canary(traffic_percentage)
+                .ok_or_else(no_canary)?;
+            None
+        }
+        routing_types::RoutingCanaryAction::Promote => {
+            let algorithm_id = routing_ref.promote_canary().ok_or_else(no_canary)?;
+            db.find_routing_algorithm_by_profile_id_algorithm_id(
+                business_profile.get_id(),
+                &algorithm_id,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?
+            .decision_engine_routing_id
+        }
+        routing_types::RoutingCanaryAction::Rollback => {
+            routing_ref.rollback_canary().ok_or_else(no_canary)?;
+            None
+        }
+    };
+
+    let response = routing_types::RoutingCanaryResponse {
+        profile_id: business_profile.get_id().clone(),
+        algorithm_id: routing_ref.algorithm_id.clone(),
+        canary: routing_ref.canary.clone(),
+    };
+
+    helpers::update_profile_active_algorithm_ref(
+        db,
+        processor.get_key_store(),
+        business_profile.clone(),
+        routing_ref,
+        &transaction_type,
+    )
+    .await?;
+
+    if let Some(euclid_routing_id) = promoted_decision_engine_routing_id {
+        let routing_algo = ActivateRoutingConfigRequest {
+            created_by: business_profile.get_id().get_string_repr().to_string(),
+            routing_algorithm_id: euclid_routing_id,
+        };
+        if let Err(error) = link_de_euclid_routing_algorithm(&state, routing_algo).await {
+            router_env::logger::info!(
+                routing_flow=?"promote_routing_canary",
+                error=?error,
+                "decision_engine_euclid"
+            );
+        }
+    }
+
+    helpers::redact_cgraph_cache(
+        &state,
+        processor.get_account().get_id(),
+        business_profile.get_id(),
+    )
+    .await?;
+
+    helpers::redact_routing_cache(
+        &state,
+        processor.get_account().get_id(),
+        business_profile.get_id(),
+    )
+    .await?;
+
+    metrics::ROUTING_UPDATE_CANARY_SUCCESS_RESPONSE.add(1, &[]);
+    Ok(service_api::ApplicationResponse::Json(response))
+}
+
 #[cfg(feature = "v2")]
 pub async fn update_default_fallback_routing(
     state: SessionState,
@@ -2555,7 +2750,8 @@ impl GetRoutableConnectorsForChoice for StraightThroughAlgorithmTypeSingle {
             }
 
             api::routing::StraightThroughAlgorithm::Priority(_)
-            | api::routing::StraightThroughAlgorithm::VolumeSplit(_) => {
+            | api::routing::StraightThroughAlgorithm::VolumeSplit(_)
+            | api::routing::StraightThroughAlgorithm::Advanced(_) => {
                 Err(errors::RoutingError::DslIncorrectSelectionAlgorithm)
                     .change_context(errors::ApiErrorResponse::InternalServerError)
                     .attach_printable(
@@ -2590,7 +2786,29 @@ impl GetRoutableConnectorsForChoice for DecideConnector {
             payment_data.get_address(),
             payment_data.get_recurring_details(),
             payment_data.get_currency(),
-        );
+        )
+        .with_routing_timezone(business_profile.get_routing_timezone());
+        #[cfg(feature = "v1")]
+        let routing_algorithm_id = {
+            let algorithm_ref: routing_types::RoutingAlgorithmRef = business_profile
+                .routing_algorithm
+                .clone()
+                .map(|val| val.parse_value("RoutingAlgorithmRef"))
+                .transpose()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable(
+                    "unable to deserialize routing algorithm ref from business profile",
+                )?
+                .unwrap_or_default();
+            payments_routing::get_routing_algorithm_id_for_transaction(
+                &algorithm_ref,
+                payment_data
+                    .get_payment_attempt()
+                    .payment_id
+                    .get_string_repr(),
+            )
+        };
+        #[cfg(feature = "v2")]
         let routing_algorithm_id = business_profile.get_payment_routing_algorithm_id()?;
 
         let (connectors, routing_approach) = payments_routing::perform_static_routing_v1(
@@ -2646,7 +2864,8 @@ impl RoutableConnectors {
             payment_data.get_address(),
             payment_data.get_recurring_details(),
             payment_data.get_currency(),
-        );
+        )
+        .with_routing_timezone(business_profile.get_routing_timezone());
 
         let routable_connector_choice = self.0.clone();
 
@@ -3034,6 +3253,17 @@ async fn migrate_rules_for_profile(
                 push_error(algorithm_id.clone(), "3DS migration not implemented".into());
                 None
             }
+            Ok(EuclidAlgorithm::CostBased(_)) => {
+                router_env::logger::info!(
+                    ?algorithm_id,
+                    "Skipping cost based rule migration (evaluated locally)"
+                );
+                push_error(
+                    algorithm_id.clone(),
+                    "Cost based migration not supported".into(),
+                );
+                None
+            }
             Err(e) => {
                 router_env::logger::error!(?e, ?algorithm_id, "Failed to parse algorithm");
                 push_error(algorithm_id.clone(), format!("Parse error: {e:?}"));
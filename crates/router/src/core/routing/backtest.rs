@@ -0,0 +1,544 @@
+use std::collections::HashMap;
+
+use analytics::routing_backtest::BacktestPaymentAttempt;
+use api_models::routing::{
+    self as routing_types, RoutingBacktestConnectorStats, RoutingBacktestRequest,
+    RoutingBacktestResponse, RoutingBacktestResult, RoutingBacktestSegment, RoutingBacktestStatus,
+};
+use common_utils::{
+    date_time, ext_traits::ValueExt, types::authentication::AuthInfo, types::TimeRange,
+};
+use diesel_models::process_tracker::business_status;
+use error_stack::{report, ResultExt};
+use euclid::backend::inputs as dsl_inputs;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments::routing::{self as payments_routing, CachedAlgorithm},
+        routing::helpers,
+        utils as core_utils,
+    },
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{domain, storage},
+    utils::OptionExt,
+};
+
+const ROUTING_BACKTEST_TASK: &str = "ROUTING_BACKTEST";
+const ROUTING_BACKTEST_TAGS: [&str; 1] = ["ROUTING"];
+const MAX_BACKTEST_LOOKBACK_DAYS: u16 = 30;
+
+/// Tracking data of the process tracker task running a routing backtest.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RoutingBacktestTrackingData {
+    pub backtest_id: String,
+    pub org_id: common_utils::id_type::OrganizationId,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub algorithm_id: common_utils::id_type::RoutingId,
+    pub routing_timezone: Option<String>,
+    pub time_range: TimeRange,
+}
+
+impl core_utils::GetProfileId for RoutingBacktestTrackingData {
+    fn get_profile_id(&self) -> Option<&common_utils::id_type::ProfileId> {
+        Some(&self.profile_id)
+    }
+}
+
+fn get_process_tracker_id(backtest_id: &str) -> String {
+    let runner = storage::ProcessTrackerRunner::RoutingBacktestWorkflow;
+    format!("{runner}_{ROUTING_BACKTEST_TASK}_{backtest_id}")
+}
+
+/// Key of the result of a backtest in the file storage.
+pub fn get_backtest_result_file_key(tracking_data: &RoutingBacktestTrackingData) -> String {
+    format!(
+        "routing_backtests/{}/{}/{}.json",
+        tracking_data.merchant_id.get_string_repr(),
+        tracking_data.profile_id.get_string_repr(),
+        tracking_data.backtest_id
+    )
+}
+
+#[instrument(skip_all)]
+pub async fn create_routing_backtest(
+    state: SessionState,
+    processor: domain::Processor,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    algorithm_id: common_utils::id_type::RoutingId,
+    request: RoutingBacktestRequest,
+) -> RouterResponse<RoutingBacktestResponse> {
+    let db = state.store.as_ref();
+
+    if !(1..=MAX_BACKTEST_LOOKBACK_DAYS).contains(&request.lookback_days) {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("lookback_days must be between 1 and {MAX_BACKTEST_LOOKBACK_DAYS}"),
+        }));
+    }
+
+    let routing_algorithm = db
+        .find_routing_algorithm_by_algorithm_id_processor_merchant_id(
+            &algorithm_id,
+            processor.get_account().get_id(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        &processor,
+        Some(&routing_algorithm.profile_id),
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    if !matches!(
+        routing_algorithm.algorithm_for,
+        common_enums::TransactionType::Payment
+    ) {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Only payment routing algorithms can be backtested".to_string(),
+        }));
+    }
+
+    // Validated upfront, so that the algorithm is known to be replayable by the scheduler
+    routing_algorithm
+        .algorithm_data
+        .parse_value::<routing_types::StaticRoutingAlgorithm>("StaticRoutingAlgorithm")
+        .ok()
+        .and_then(|algorithm| CachedAlgorithm::try_from_static_algorithm(algorithm).ok())
+        .ok_or(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Only static routing algorithms can be backtested".to_string(),
+        })?;
+
+    let backtest_id = common_utils::generate_id(consts::ID_LENGTH, "backtest");
+    let end_time = date_time::now();
+    let tracking_data = RoutingBacktestTrackingData {
+        backtest_id: backtest_id.clone(),
+        org_id: processor.get_account().get_org_id().clone(),
+        merchant_id: processor.get_account().get_id().clone(),
+        profile_id: business_profile.get_id().clone(),
+        algorithm_id: algorithm_id.clone(),
+        routing_timezone: business_profile.routing_timezone.clone(),
+        time_range: TimeRange {
+            start_time: end_time - time::Duration::days(request.lookback_days.into()),
+            end_time: Some(end_time),
+        },
+    };
+
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        get_process_tracker_id(&backtest_id),
+        ROUTING_BACKTEST_TASK,
+        storage::ProcessTrackerRunner::RoutingBacktestWorkflow,
+        ROUTING_BACKTEST_TAGS,
+        tracking_data,
+        None,
+        end_time,
+        common_types::consts::API_VERSION,
+        common_enums::ApplicationSource::Main,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to create ProcessTrackerNew for routing backtest")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to enqueue routing backtest ProcessTracker task")?;
+
+    logger::info!(%backtest_id, ?algorithm_id, "Scheduled routing backtest");
+
+    Ok(ApplicationResponse::Json(RoutingBacktestResponse {
+        backtest_id,
+        algorithm_id,
+        profile_id: business_profile.get_id().clone(),
+        status: RoutingBacktestStatus::Pending,
+        result: None,
+    }))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_routing_backtest(
+    state: SessionState,
+    processor: domain::Processor,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    backtest_id: String,
+) -> RouterResponse<RoutingBacktestResponse> {
+    let not_found = || errors::ApiErrorResponse::GenericNotFoundError {
+        message: format!("Routing backtest with the given id '{backtest_id}' does not exist"),
+    };
+
+    let process = state
+        .store
+        .find_process_by_id(&get_process_tracker_id(&backtest_id))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch routing backtest ProcessTracker task")?
+        .ok_or_else(not_found)?;
+
+    let tracking_data: RoutingBacktestTrackingData = process
+        .tracking_data
+        .clone()
+        .parse_value("RoutingBacktestTrackingData")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    if &tracking_data.merchant_id != processor.get_account().get_id() {
+        return Err(report!(not_found()));
+    }
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &tracking_data)?;
+
+    let (status, result) = match process.business_status.as_str() {
+        business_status::COMPLETED_BY_PT => {
+            let result = state
+                .file_storage_client
+                .retrieve_file(&get_backtest_result_file_key(&tracking_data))
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to retrieve routing backtest result")?;
+            let result = serde_json::from_slice::<RoutingBacktestResult>(&result)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to deserialize routing backtest result")?;
+
+            (RoutingBacktestStatus::Completed, Some(result))
+        }
+        _ if process.status == common_enums::ProcessTrackerStatus::Finish => {
+            (RoutingBacktestStatus::Failed, None)
+        }
+        _ => (RoutingBacktestStatus::Pending, None),
+    };
+
+    Ok(ApplicationResponse::Json(RoutingBacktestResponse {
+        backtest_id,
+        algorithm_id: tracking_data.algorithm_id,
+        profile_id: tracking_data.profile_id,
+        status,
+        result,
+    }))
+}
+
+/// Replays the payment attempts of the time range through the routing algorithm, and stores the
+/// result in the file storage.
+pub async fn run_routing_backtest(
+    state: &SessionState,
+    tracking_data: &RoutingBacktestTrackingData,
+) -> RouterResult<()> {
+    let db = state.store.as_ref();
+
+    let algorithm = db
+        .find_routing_algorithm_by_profile_id_algorithm_id(
+            &tracking_data.profile_id,
+            &tracking_data.algorithm_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch routing algorithm")?
+        .algorithm_data
+        .parse_value::<routing_types::StaticRoutingAlgorithm>("StaticRoutingAlgorithm")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    let algorithm = CachedAlgorithm::try_from_static_algorithm(algorithm)
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    let fallback_connectors = helpers::get_merchant_default_config(
+        db,
+        tracking_data.profile_id.get_string_repr(),
+        &common_enums::TransactionType::Payment,
+    )
+    .await?;
+
+    let auth = AuthInfo::ProfileLevel {
+        org_id: tracking_data.org_id.clone(),
+        merchant_id: tracking_data.merchant_id.clone(),
+        profile_ids: vec![tracking_data.profile_id.clone()],
+        processor_merchant_id: None,
+    };
+    let attempts = state
+        .pool
+        .get_routing_backtest_attempts(&tracking_data.time_range, &auth)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to load payment attempts for routing backtest")?;
+
+    // Fetched once, so that every payment is ranked against the same fee schedules
+    let merchant_connector_accounts = match &algorithm {
+        CachedAlgorithm::CostBased(_) => db
+            .list_enabled_merchant_connector_accounts_without_encrypted_by_merchant_id_profile_id(
+                &tracking_data.merchant_id,
+                &tracking_data.profile_id,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch merchant connector accounts")?,
+        _ => domain::MerchantConnectorAccountsWithoutEncrypted::new(Vec::new()),
+    };
+
+    let result = evaluate_backtest(&attempts, |attempt| {
+        let backend_input = make_backtest_dsl_input(attempt, tracking_data)?;
+        let connectors = match &algorithm {
+            CachedAlgorithm::CostBased(config) => {
+                Ok(payments_routing::rank_connectors_by_expected_fee(
+                    config,
+                    &merchant_connector_accounts,
+                    &backend_input,
+                )
+                .0)
+            }
+            _ => algorithm.evaluate(backend_input),
+        };
+
+        connectors
+            .map_err(|error| {
+                logger::debug!(
+                    ?error,
+                    attempt_id = %attempt.attempt_id,
+                    "Routing algorithm evaluation failed"
+                );
+            })
+            .ok()
+            .and_then(|connectors| connectors.into_iter().next())
+            .or_else(|| fallback_connectors.first().cloned())
+            .map(|choice| choice.connector.to_string())
+    });
+
+    let result = serde_json::to_vec(&result)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize routing backtest result")?;
+
+    state
+        .file_storage_client
+        .upload_file(&get_backtest_result_file_key(tracking_data), result)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to upload routing backtest result")
+}
+
+/// Builds the routing input of a historical payment attempt. Attempts without a currency cannot
+/// be routed and are skipped.
+fn make_backtest_dsl_input(
+    attempt: &BacktestPaymentAttempt,
+    tracking_data: &RoutingBacktestTrackingData,
+) -> Option<dsl_inputs::BackendInput> {
+    Some(dsl_inputs::BackendInput {
+        metadata: None,
+        payment: dsl_inputs::PaymentInput {
+            amount: attempt.amount,
+            currency: attempt.currency?,
+            authentication_type: attempt.authentication_type,
+            card_bin: None,
+            extended_card_bin: None,
+            capture_method: attempt.capture_method,
+            business_country: None,
+            billing_country: None,
+            business_label: None,
+            setup_future_usage: None,
+            transaction_initiator: None,
+            surcharge_amount: None,
+        },
+        payment_method: dsl_inputs::PaymentMethodInput {
+            payment_method: attempt.payment_method,
+            payment_method_type: attempt.payment_method_type,
+            card_network: attempt.card_network.clone(),
+            card_discovery: None,
+        },
+        acquirer_data: None,
+        customer_device_data: None,
+        issuer_data: None,
+        mandate: dsl_inputs::MandateData {
+            mandate_acceptance_type: None,
+            mandate_type: None,
+            payment_type: None,
+        },
+        transaction_time: attempt.created_at.and_then(|created_at| {
+            payments_routing::get_transaction_time_input(
+                tracking_data.routing_timezone.as_deref(),
+                created_at,
+            )
+        }),
+    })
+}
+
+#[derive(Default)]
+struct SegmentConnectorCounts {
+    simulated: u64,
+    actual: u64,
+    actual_succeeded: u64,
+}
+
+/// Compares the connector chosen by `simulate` for each attempt against the connector the
+/// attempt was actually routed to.
+fn evaluate_backtest(
+    attempts: &[BacktestPaymentAttempt],
+    simulate: impl Fn(&BacktestPaymentAttempt) -> Option<String>,
+) -> RoutingBacktestResult {
+    let mut result = RoutingBacktestResult::default();
+    let mut segments: HashMap<_, (u64, HashMap<String, SegmentConnectorCounts>)> = HashMap::new();
+
+    for attempt in attempts {
+        result.total_attempts += 1;
+
+        let (segment_attempts, connectors) = segments
+            .entry((
+                attempt.payment_method,
+                attempt.payment_method_type,
+                attempt.currency,
+            ))
+            .or_default();
+        *segment_attempts += 1;
+
+        if let Some(actual) = &attempt.connector {
+            *result
+                .actual_connector_distribution
+                .entry(actual.clone())
+                .or_default() += 1;
+
+            let counts = connectors.entry(actual.clone()).or_default();
+            counts.actual += 1;
+            if attempt.status.is_some_and(is_successful_attempt) {
+                counts.actual_succeeded += 1;
+            }
+        }
+
+        match simulate(attempt) {
+            Some(simulated) => {
+                if attempt
+                    .connector
+                    .as_ref()
+                    .is_some_and(|actual| *actual != simulated)
+                {
+                    result.changed_connector_count += 1;
+                }
+                *result
+                    .simulated_connector_distribution
+                    .entry(simulated.clone())
+                    .or_default() += 1;
+                connectors.entry(simulated).or_default().simulated += 1;
+            }
+            None => result.unrouted_count += 1,
+        }
+    }
+
+    result.segments = segments
+        .into_iter()
+        .map(
+            |((payment_method, payment_method_type, currency), (total_attempts, connectors))| {
+                let mut connectors = connectors
+                    .into_iter()
+                    .filter(|(_, counts)| counts.simulated > 0)
+                    .map(|(connector, counts)| RoutingBacktestConnectorStats {
+                        connector,
+                        simulated_attempts: counts.simulated,
+                        actual_attempts: counts.actual,
+                        historical_success_rate: (counts.actual > 0)
+                            .then(|| counts.actual_succeeded as f64 * 100.0 / counts.actual as f64),
+                    })
+                    .collect::<Vec<_>>();
+                connectors.sort_by(|a, b| b.simulated_attempts.cmp(&a.simulated_attempts));
+
+                RoutingBacktestSegment {
+                    payment_method,
+                    payment_method_type,
+                    currency,
+                    total_attempts,
+                    connectors,
+                }
+            },
+        )
+        .collect();
+    result
+        .segments
+        .sort_by(|a, b| b.total_attempts.cmp(&a.total_attempts));
+
+    result
+}
+
+fn is_successful_attempt(status: common_enums::AttemptStatus) -> bool {
+    matches!(
+        status,
+        common_enums::AttemptStatus::Charged
+            | common_enums::AttemptStatus::Authorized
+            | common_enums::AttemptStatus::PartialCharged
+            | common_enums::AttemptStatus::PartialChargedAndChargeable
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn attempt(
+        connector: &str,
+        status: common_enums::AttemptStatus,
+        currency: common_enums::Currency,
+    ) -> BacktestPaymentAttempt {
+        BacktestPaymentAttempt {
+            payment_id: "pay_1".to_string(),
+            attempt_id: "pay_1_1".to_string(),
+            connector: Some(connector.to_string()),
+            status: Some(status),
+            amount: common_utils::types::MinorUnit::new(1000),
+            currency: Some(currency),
+            payment_method: Some(common_enums::PaymentMethod::Card),
+            payment_method_type: Some(common_enums::PaymentMethodType::Credit),
+            card_network: None,
+            authentication_type: None,
+            capture_method: None,
+            created_at: None,
+        }
+    }
+
+    #[test]
+    fn test_evaluate_backtest() {
+        use common_enums::{AttemptStatus, Currency};
+
+        let attempts = vec![
+            attempt("stripe", AttemptStatus::Charged, Currency::USD),
+            attempt("stripe", AttemptStatus::Failure, Currency::USD),
+            attempt("adyen", AttemptStatus::Charged, Currency::USD),
+            attempt("adyen", AttemptStatus::Charged, Currency::EUR),
+        ];
+
+        // Routes USD payments to adyen, and leaves EUR payments unrouted
+        let result = evaluate_backtest(&attempts, |attempt| {
+            (attempt.currency == Some(Currency::USD)).then(|| "adyen".to_string())
+        });
+
+        assert_eq!(result.total_attempts, 4);
+        assert_eq!(result.changed_connector_count, 2);
+        assert_eq!(result.unrouted_count, 1);
+        assert_eq!(result.actual_connector_distribution.get("stripe"), Some(&2));
+        assert_eq!(result.actual_connector_distribution.get("adyen"), Some(&2));
+        assert_eq!(
+            result.simulated_connector_distribution.get("adyen"),
+            Some(&3)
+        );
+        assert_eq!(result.simulated_connector_distribution.get("stripe"), None);
+
+        let usd_segment = result
+            .segments
+            .iter()
+            .find(|segment| segment.currency == Some(Currency::USD))
+            .unwrap();
+        assert_eq!(usd_segment.total_attempts, 3);
+        assert_eq!(usd_segment.connectors.len(), 1);
+        assert_eq!(usd_segment.connectors[0].connector, "adyen");
+        assert_eq!(usd_segment.connectors[0].simulated_attempts, 3);
+        assert_eq!(usd_segment.connectors[0].actual_attempts, 1);
+        assert_eq!(
+            usd_segment.connectors[0].historical_success_rate,
+            Some(100.0)
+        );
+
+        let eur_segment = result
+            .segments
+            .iter()
+            .find(|segment| segment.currency == Some(Currency::EUR))
+            .unwrap();
+        assert!(eur_segment.connectors.is_empty());
+    }
+}
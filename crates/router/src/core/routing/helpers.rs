@@ -45,7 +45,10 @@ use crate::types::transformers::ForeignFrom;
 use crate::{
     core::{
         errors::{self, RouterResult},
-        payments::routing::utils::{self as routing_utils, DecisionEngineApiHandler},
+        payments::routing::{
+            self as payments_routing,
+            utils::{self as routing_utils, DecisionEngineApiHandler},
+        },
     },
     db::StorageInterface,
     routes::SessionState,
@@ -447,6 +450,13 @@ impl RoutingAlgorithmHelpers<'_> {
                 }
             }
 
+            // Fee schedules are only configured on v1 merchant connector accounts
+            routing_types::StaticRoutingAlgorithm::CostBased(_) => {
+                return Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "Cost based routing is not supported".to_string(),
+                })?;
+            }
+
             routing_types::StaticRoutingAlgorithm::ThreeDsDecisionRule(_) => {
                 return Err(errors::ApiErrorResponse::InternalServerError).attach_printable(
                     "Invalid routing algorithm three_ds decision rule received",
@@ -524,6 +534,50 @@ pub async fn validate_contract_based_label_info(
     Ok(())
 }
 
+/// Runs the warning analyses of the euclid analyzer over an advanced routing program, against the
+/// constraint graph of the merchant connector accounts of the profile. These never fail the
+/// request, a program that cannot be analyzed is only logged.
+pub async fn get_routing_algorithm_analysis_warnings(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: &id_type::ProfileId,
+    transaction_type: &storage::enums::TransactionType,
+    algorithm: &routing_types::StaticRoutingAlgorithm,
+) -> Vec<routing_types::RoutingAnalysisWarning> {
+    let routing_types::StaticRoutingAlgorithm::Advanced(program) = algorithm else {
+        return Vec::new();
+    };
+
+    let knowledge_graph = payments_routing::get_merchant_cgraph(
+        state,
+        key_store,
+        profile_id,
+        transaction_type,
+    )
+    .await
+    .and_then(|mca_graph| {
+        hyperswitch_constraint_graph::ConstraintGraph::combine(
+            &mca_graph,
+            &euclid::dssa::truth::ANALYSIS_GRAPH,
+        )
+        .change_context(errors::RoutingError::KgraphAnalysisError)
+    })
+    .inspect_err(|error| {
+        logger::warn!(
+            ?error,
+            "Failed to build the constraint graph of the profile, analyzing the routing program without it"
+        );
+    })
+    .ok();
+
+    euclid::dssa::analyzer::analyze_warnings(program.clone(), knowledge_graph.as_ref())
+        .map(|warnings| warnings.into_iter().map(Into::into).collect())
+        .unwrap_or_else(|error| {
+            logger::warn!(analysis_error = ?error, "Failed to analyze routing program");
+            Vec::new()
+        })
+}
+
 #[cfg(feature = "v1")]
 pub async fn validate_connectors_in_routing_config(
     state: &SessionState,
@@ -622,6 +676,12 @@ pub async fn validate_connectors_in_routing_config(
             }
         }
 
+        routing_types::StaticRoutingAlgorithm::CostBased(config) => {
+            for choice in &config.connectors {
+                connector_choice(choice)?;
+            }
+        }
+
         routing_types::StaticRoutingAlgorithm::ThreeDsDecisionRule(_) => {
             Err(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Invalid routing algorithm three_ds decision rule received")?
@@ -2920,11 +2980,14 @@ pub async fn redact_cgraph_cache(
         profile_id.get_string_repr(),
     );
 
+    let cost_based_mcas_key = cache::get_cost_based_mcas_cache_key(merchant_id, profile_id);
+
     let config_payouts_key = cache::CacheKind::CGraph(cgraph_payouts_key.clone().into());
     let config_payments_key = cache::CacheKind::CGraph(cgraph_payments_key.clone().into());
+    let cost_based_mcas_key = cache::CacheKind::CGraph(cost_based_mcas_key.into());
     cache::redact_from_redis_and_publish(
         state.store.get_cache_store().as_ref(),
-        [config_payouts_key, config_payments_key],
+        [config_payouts_key, config_payments_key, cost_based_mcas_key],
     )
     .await
     .change_context(errors::ApiErrorResponse::InternalServerError)
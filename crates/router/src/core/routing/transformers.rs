@@ -34,6 +34,7 @@ impl ForeignFrom<RoutingProfileMetadata> for RoutingDictionaryRecord {
             modified_at: value.modified_at.assume_utc().unix_timestamp(),
             algorithm_for: Some(value.algorithm_for),
             decision_engine_routing_id: None,
+            analysis_warnings: Vec::new(),
         }
     }
 }
@@ -51,6 +52,7 @@ impl ForeignFrom<RoutingAlgorithm> for RoutingDictionaryRecord {
             modified_at: value.modified_at.assume_utc().unix_timestamp(),
             algorithm_for: Some(value.algorithm_for),
             decision_engine_routing_id: value.decision_engine_routing_id,
+            analysis_warnings: Vec::new(),
         }
     }
 }
@@ -92,6 +94,7 @@ impl ForeignFrom<storage_enums::RoutingAlgorithmKind> for RoutingAlgorithmKind {
             storage_enums::RoutingAlgorithmKind::Advanced => Self::Advanced,
             storage_enums::RoutingAlgorithmKind::Dynamic => Self::Dynamic,
             storage_enums::RoutingAlgorithmKind::ThreeDsDecisionRule => Self::ThreeDsDecisionRule,
+            storage_enums::RoutingAlgorithmKind::CostBased => Self::CostBased,
         }
     }
 }
@@ -105,6 +108,7 @@ impl ForeignFrom<RoutingAlgorithmKind> for storage_enums::RoutingAlgorithmKind {
             RoutingAlgorithmKind::Advanced => Self::Advanced,
             RoutingAlgorithmKind::Dynamic => Self::Dynamic,
             RoutingAlgorithmKind::ThreeDsDecisionRule => Self::ThreeDsDecisionRule,
+            RoutingAlgorithmKind::CostBased => Self::CostBased,
         }
     }
 }
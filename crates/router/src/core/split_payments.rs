@@ -1,3 +1,5 @@
+use std::{borrow::Cow, marker::PhantomData};
+
 use api_models::{
     enums,
     payments::{
@@ -13,9 +15,11 @@ use common_utils::{
 };
 use error_stack::{report, Report, ResultExt};
 use hyperswitch_domain_models::payments::{
-    split_payments, HeaderPayload, PaymentConfirmData, PaymentIntent,
+    split_payments, HeaderPayload, PaymentCancelData, PaymentConfirmData, PaymentIntent,
 };
 use hyperswitch_masking::ExposeInterface;
+use router_env::logger;
+use scheduler::utils as pt_utils;
 
 use super::errors::StorageErrorExt;
 use crate::{
@@ -23,47 +27,36 @@ use crate::{
         errors::{self, RouterResponse},
         payment_method_balance,
         payments::{
-            operations::{self, Operation, PaymentIntentConfirm},
+            operations::{self, payment_cancel_v2, Operation, PaymentIntentConfirm},
             payments_operation_core,
             transformers::GenerateResponse,
+            OperationSessionSetters,
         },
+        refunds_v2,
     },
-    db::errors::RouterResult,
+    db::{errors::RouterResult, StorageInterface},
     routes::{app::ReqState, SessionState},
-    types::{api, domain},
+    types::{self, api, domain, storage},
 };
 
+const SPLIT_PAYMENT_REVERSAL_TASK: &str = "SPLIT_PAYMENT_REVERSAL";
+const SPLIT_PAYMENT_REVERSAL_TAGS: [&str; 2] = ["SPLIT_PAYMENT", "PAYMENT"];
+
+/// The interval at which the pending refunds of a split payment reversal are synced with the
+/// connector.
+pub(crate) const SPLIT_PAYMENT_REVERSAL_SYNC_INTERVAL: time::Duration = time::Duration::minutes(15);
+
 pub(crate) struct SplitPaymentResponseData {
     pub primary_payment_response_data: PaymentConfirmData<api::Authorize>,
     pub secondary_payment_response_data: Vec<PaymentConfirmData<api::Authorize>>,
 }
 
 impl SplitPaymentResponseData {
-    fn get_intent_status(&self) -> common_enums::IntentStatus {
-        let primary_status = common_enums::IntentStatus::from(
-            self.primary_payment_response_data.payment_attempt.status,
-        );
-
-        let secondary_status_vec: Vec<common_enums::IntentStatus> = self
-            .secondary_payment_response_data
-            .iter()
-            .map(|elem| common_enums::IntentStatus::from(elem.payment_attempt.status))
-            .collect();
-
-        // If all statuses are the same, return that status
-        let all_same = secondary_status_vec
-            .iter()
-            .all(|status| *status == primary_status);
-
-        if all_same {
-            primary_status
-        } else {
-            // Return the last secondary status if array is not empty, otherwise primary status
-            secondary_status_vec
-                .last()
-                .copied()
-                .unwrap_or(primary_status)
-        }
+    pub(crate) fn get_funding_legs(
+        &self,
+    ) -> impl Iterator<Item = &PaymentConfirmData<api::Authorize>> {
+        std::iter::once(&self.primary_payment_response_data)
+            .chain(self.secondary_payment_response_data.iter())
     }
 }
 
@@ -276,25 +269,19 @@ pub(crate) async fn split_payments_execute_core(
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Unable to update payment intent")?;
 
-    let pm_amount_split =
-        get_payment_method_amount_split(&state, &payment_id, &request, &payment_intent).await?;
+    let funding_legs =
+        get_payment_method_amount_split(&state, &payment_id, &request, &payment_intent)
+            .await?
+            .into_funding_legs();
+    let funding_leg_count = funding_legs.len();
 
-    let (
-        primary_pm_response,
-        connector_http_status_code,
-        external_latency,
-        connector_response_data,
-    ) = {
-        // If a non-balance Payment Method is present, we will execute that first, otherwise we will execute
-        // a balance Payment Method
-        let payment_method_amount_details = pm_amount_split
-            .non_balance_pm_split
-            .clone()
-            .or_else(|| pm_amount_split.balance_pm_split.first().cloned())
-            .get_required_value("payment method amount split")
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("At least one payment method is required")?;
+    let mut payment_intent = payment_intent;
+    let mut executed_legs = Vec::with_capacity(funding_leg_count);
+    let mut primary_connector_response = None;
 
+    // The legs are charged one after the other in funding order, a leg is only charged once all
+    // the previous legs have been charged
+    for payment_method_amount_details in funding_legs {
         let operation = PaymentIntentConfirm;
 
         let get_tracker_response: operations::GetTrackerResponse<
@@ -333,166 +320,545 @@ pub(crate) async fn split_payments_execute_core(
         ))
         .await?;
 
-        // payments_operation_core marks the intent as succeeded when the attempt is succesful
-        // However, for split case, we can't mark the intent as succesful until all the attempts
-        // have succeeded, so reverting the state of Payment Intent
-        if payment_data.payment_intent.is_succeeded() {
-            let payment_intent_update =
-            hyperswitch_domain_models::payments::payment_intent::PaymentIntentUpdate::SplitPaymentStatusUpdate {
-                status: common_enums::IntentStatus::RequiresPaymentMethod,
-                updated_by: platform
-                    .get_processor()
-                    .get_account()
-                    .storage_scheme
-                    .to_string(),
-            };
-
-            let updated_payment_intent = db
-                .update_payment_intent(
-                    payment_data.payment_intent.clone(),
-                    payment_intent_update,
-                    platform.get_processor().get_key_store(),
-                    platform.get_processor().get_account().storage_scheme,
-                )
-                .await
-                .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("Unable to update payment intent")?;
-        }
-
-        (
-            payment_data,
+        primary_connector_response.get_or_insert((
             connector_http_status_code,
             external_latency,
             connector_response_data,
-        )
-    };
+        ));
+
+        let is_leg_funded = is_funded_leg_status(payment_data.payment_attempt.status);
+
+        // payments_operation_core marks the intent as succeeded (or as requiring capture) when the
+        // attempt is succesful. However, for split case, we can't mark the intent as succesful until
+        // all the attempts have succeeded, so reverting the state of Payment Intent
+        payment_intent = if is_leg_funded {
+            update_split_payment_status(
+                &state,
+                platform.get_processor(),
+                payment_data.payment_intent.clone(),
+                common_enums::IntentStatus::RequiresPaymentMethod,
+                None,
+            )
+            .await?
+        } else {
+            payment_data.payment_intent.clone()
+        };
+
+        executed_legs.push(payment_data);
+
+        // Exit the loop if a payment failed
+        if !is_leg_funded {
+            break;
+        }
+    }
+
+    let (connector_http_status_code, external_latency, connector_response_data) =
+        primary_connector_response
+            .get_required_value("primary connector response")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("At least one payment method is required")?;
 
+    let mut executed_legs = executed_legs.into_iter();
     let mut split_pm_response_data = SplitPaymentResponseData {
-        primary_payment_response_data: primary_pm_response,
-        secondary_payment_response_data: vec![],
+        primary_payment_response_data: executed_legs
+            .next()
+            .get_required_value("primary payment response data")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("At least one payment method is required")?,
+        secondary_payment_response_data: executed_legs.collect(),
     };
 
-    // We have executed the primary payment method, now get a vector of the secondary payment methods
-    // If we had a non-balance payment method, it was executed first, so the remaining ones are the balance PMs
-    // otherwise the first balance payment method was executed, so remove it from the remaining PMs
-    let remaining_pm_amount_split = if pm_amount_split.non_balance_pm_split.is_some() {
-        pm_amount_split.balance_pm_split
+    let (intent_status, amount_captured) = if split_pm_response_data
+        .get_funding_legs()
+        .filter(|leg| is_funded_leg_status(leg.payment_attempt.status))
+        .count()
+        == funding_leg_count
+    {
+        get_funded_intent_status(split_pm_response_data.get_funding_legs().map(|leg| {
+            (
+                leg.payment_attempt.status,
+                leg.payment_attempt.get_total_amount(),
+            )
+        }))
     } else {
-        pm_amount_split
-            .balance_pm_split
-            .iter()
-            .skip(1)
-            .cloned()
-            .collect()
-    };
+        // The payment cannot be completed anymore, so the legs which were already funded are
+        // reversed. If any of them could not be reversed, the payment needs manual intervention.
+        // A last leg which is still pending, for instance when it requires customer action, is
+        // handled like a failed one: the flows completing it later do not finalize the split
+        // payment, so the funded legs cannot stay committed in the meantime
+        let mut reversal = Box::pin(reverse_funding_legs(
+            &state,
+            req_state,
+            &platform,
+            &profile,
+            &header_payload,
+            &payment_intent,
+            &split_pm_response_data,
+        ))
+        .await;
 
-    for payment_method_amount_details in remaining_pm_amount_split {
-        let operation = PaymentIntentConfirm;
+        if !reversal.pending_refund_ids.is_empty() {
+            if let Err(error) = add_split_payment_reversal_task(
+                &*state.store,
+                &payment_intent,
+                &reversal,
+                state.conf.application_source,
+            )
+            .await
+            {
+                logger::error!(
+                    ?error,
+                    payment_id = ?payment_intent.id,
+                    "Unable to track the pending reversals of the split payment"
+                );
+                reversal.mark_pending_as_unreversed();
+            }
+        }
 
-        let get_tracker_response: operations::GetTrackerResponse<
-            PaymentConfirmData<api::Authorize>,
-        > = operation
-            .to_get_tracker()?
-            .get_trackers_for_split_payments(
-                &state,
+        // Voiding a leg updates the payment intent
+        payment_intent = db
+            .find_payment_intent_by_id(
                 &payment_id,
-                &request,
-                &platform,
-                &profile,
-                &header_payload,
-                payment_method_amount_details,
-                &attempts_group_id,
+                platform.get_processor().get_key_store(),
+                platform.get_processor().get_account().storage_scheme,
             )
-            .await?;
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
 
-        let (
-            payment_data,
-            _req,
-            _customer,
-            _connector_http_status_code,
-            _external_latency,
-            _connector_response_data,
-        ) = Box::pin(payments_operation_core(
-            &state,
-            req_state.clone(),
-            platform.clone(),
-            &profile,
-            operation,
-            request.clone(),
-            get_tracker_response,
-            CallConnectorAction::Trigger,
-            header_payload.clone(),
-        ))
-        .await?;
+        reversal.get_intent_status()
+    };
 
-        // payments_operation_core marks the intent as succeeded when the attempt is succesful
-        // However, for split case, we can't mark the intent as succesful until all the attempts
-        // have succeeded, so reverting the state of Payment Intent
-        if payment_data.payment_intent.is_succeeded() {
-            let payment_intent_update =
-            hyperswitch_domain_models::payments::payment_intent::PaymentIntentUpdate::SplitPaymentStatusUpdate {
-                status: common_enums::IntentStatus::RequiresPaymentMethod,
-                updated_by: platform
-                    .get_processor()
-                    .get_account()
-                    .storage_scheme
-                    .to_string(),
-            };
+    split_pm_response_data
+        .primary_payment_response_data
+        .payment_intent = update_split_payment_status(
+        &state,
+        platform.get_processor(),
+        payment_intent,
+        intent_status,
+        amount_captured,
+    )
+    .await?;
+
+    split_pm_response_data.generate_response(
+        &state,
+        connector_http_status_code,
+        external_latency,
+        header_payload.x_hs_latency,
+        &platform,
+        &profile,
+        Some(connector_response_data),
+    )
+}
+
+/// A funding leg is funded once it is charged, or authorized when the payment is captured
+/// manually. The next leg is processed only after the previous one is funded.
+fn is_funded_leg_status(status: common_enums::AttemptStatus) -> bool {
+    matches!(
+        status,
+        common_enums::AttemptStatus::Charged | common_enums::AttemptStatus::Authorized
+    )
+}
+
+/// A funding leg is pending when it is neither funded nor failed, for instance when it requires
+/// customer action or is still being processed by the connector.
+fn is_pending_leg_status(status: common_enums::AttemptStatus) -> bool {
+    !is_funded_leg_status(status) && !status.is_payment_terminal_failure()
+}
+
+/// Returns the status and the captured amount of a split payment whose funding legs are all
+/// funded.
+fn get_funded_intent_status(
+    funding_legs: impl Iterator<Item = (common_enums::AttemptStatus, MinorUnit)>,
+) -> (common_enums::IntentStatus, Option<MinorUnit>) {
+    let (charged_amount, authorized_amount) = funding_legs.fold(
+        (MinorUnit::zero(), MinorUnit::zero()),
+        |(charged_amount, authorized_amount), (status, amount)| match status {
+            common_enums::AttemptStatus::Charged => (charged_amount + amount, authorized_amount),
+            _ => (charged_amount, authorized_amount + amount),
+        },
+    );
+
+    if authorized_amount == MinorUnit::zero() {
+        (common_enums::IntentStatus::Succeeded, Some(charged_amount))
+    } else if charged_amount == MinorUnit::zero() {
+        (common_enums::IntentStatus::RequiresCapture, None)
+    } else {
+        (
+            common_enums::IntentStatus::PartiallyCapturedAndCapturable,
+            Some(charged_amount),
+        )
+    }
+}
+
+/// The outcome of reversing the funding legs of a split payment after a later leg failed.
+#[derive(Debug, Default)]
+pub(crate) struct SplitPaymentReversal {
+    /// The refunds which are yet to reach a final status at the connector
+    pub pending_refund_ids: Vec<id_type::GlobalRefundId>,
+    /// The amount of the pending refunds
+    pub pending_amount: MinorUnit,
+    /// The amount of the legs which could not be reversed
+    pub unreversed_amount: MinorUnit,
+}
+
+impl SplitPaymentReversal {
+    /// Records the refund reversing a charged leg. A leg is reversed only once its refund has
+    /// succeeded.
+    pub(crate) fn add_refund(
+        &mut self,
+        refund_id: id_type::GlobalRefundId,
+        refund_status: api_models::refunds::RefundStatus,
+        amount: MinorUnit,
+    ) {
+        match refund_status {
+            api_models::refunds::RefundStatus::Succeeded => {}
+            api_models::refunds::RefundStatus::Pending
+            | api_models::refunds::RefundStatus::Review => {
+                self.pending_refund_ids.push(refund_id);
+                self.pending_amount = self.pending_amount + amount;
+            }
+            api_models::refunds::RefundStatus::Failed => {
+                self.unreversed_amount = self.unreversed_amount + amount;
+            }
+        }
+    }
+
+    pub(crate) fn add_unreversed_amount(&mut self, amount: MinorUnit) {
+        self.unreversed_amount = self.unreversed_amount + amount;
+    }
 
-            let _updated_payment_intent = db
-                .update_payment_intent(
-                    payment_data.payment_intent.clone(),
-                    payment_intent_update,
-                    platform.get_processor().get_key_store(),
-                    platform.get_processor().get_account().storage_scheme,
-                )
-                .await
-                .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("Unable to update payment intent")?;
+    /// Treats the pending refunds as failed, when they can no longer be tracked to completion.
+    pub(crate) fn mark_pending_as_unreversed(&mut self) {
+        self.unreversed_amount = self.unreversed_amount + self.pending_amount;
+        self.pending_amount = MinorUnit::zero();
+        self.pending_refund_ids.clear();
+    }
+
+    /// Returns the status and the captured amount of the payment intent after the reversal.
+    ///
+    /// The payment is processing while any of the refunds is pending, and failed once every leg
+    /// has been reversed. When some of the legs could not be reversed, the payment is conflicted
+    /// and the captured amount is the amount which is still captured, it can be refunded like the
+    /// captured amount of a succeeded payment.
+    pub(crate) fn get_intent_status(&self) -> (common_enums::IntentStatus, Option<MinorUnit>) {
+        if !self.pending_refund_ids.is_empty() {
+            (
+                common_enums::IntentStatus::Processing,
+                Some(self.pending_amount + self.unreversed_amount),
+            )
+        } else if self.unreversed_amount > MinorUnit::zero() {
+            (
+                common_enums::IntentStatus::Conflicted,
+                Some(self.unreversed_amount),
+            )
         } else {
-            split_pm_response_data
-                .secondary_payment_response_data
-                .push(payment_data);
+            (common_enums::IntentStatus::Failed, None)
+        }
+    }
+}
 
-            // Exit the loop if a payment failed
-            break;
+/// Reverses the legs of a split payment which were funded before a later leg failed or was left
+/// pending, in the reverse of the order in which they were funded. A pending last leg is voided
+/// first, so that it cannot complete after the other legs are reversed. Charged legs are refunded
+/// and authorized legs are voided.
+#[allow(clippy::too_many_arguments)]
+async fn reverse_funding_legs(
+    state: &SessionState,
+    req_state: ReqState,
+    platform: &domain::Platform,
+    profile: &domain::Profile,
+    header_payload: &HeaderPayload,
+    payment_intent: &PaymentIntent,
+    split_pm_response_data: &SplitPaymentResponseData,
+) -> SplitPaymentReversal {
+    let funded_legs: Vec<_> = split_pm_response_data
+        .get_funding_legs()
+        .filter(|leg| is_funded_leg_status(leg.payment_attempt.status))
+        .collect();
+
+    let mut reversal = SplitPaymentReversal::default();
+
+    let pending_leg = split_pm_response_data
+        .get_funding_legs()
+        .last()
+        .filter(|leg| is_pending_leg_status(leg.payment_attempt.status));
+    if let Some(leg) = pending_leg {
+        let payment_attempt = &leg.payment_attempt;
+        match Box::pin(void_funding_leg(
+            state,
+            req_state.clone(),
+            platform,
+            profile,
+            header_payload,
+            payment_intent,
+            payment_attempt,
+        ))
+        .await
+        {
+            Ok(common_enums::AttemptStatus::Voided) => {
+                logger::info!(
+                    attempt_id = ?payment_attempt.id,
+                    "Voided the pending leg of the split payment"
+                );
+            }
+            // The leg may still be completed at the connector, so it is treated as not reversed
+            Ok(status) => {
+                logger::error!(
+                    attempt_id = ?payment_attempt.id,
+                    ?status,
+                    "Void of the pending leg of the split payment did not succeed"
+                );
+                reversal.add_unreversed_amount(payment_attempt.get_total_amount());
+            }
+            Err(error) => {
+                logger::error!(
+                    ?error,
+                    attempt_id = ?payment_attempt.id,
+                    "Unable to void the pending leg of the split payment"
+                );
+                reversal.add_unreversed_amount(payment_attempt.get_total_amount());
+            }
+        }
+    }
+
+    for leg in funded_legs.into_iter().rev() {
+        let payment_attempt = &leg.payment_attempt;
+        let reversal_amount = payment_attempt.get_total_amount();
+
+        if payment_attempt.status == common_enums::AttemptStatus::Authorized {
+            match Box::pin(void_funding_leg(
+                state,
+                req_state.clone(),
+                platform,
+                profile,
+                header_payload,
+                payment_intent,
+                payment_attempt,
+            ))
+            .await
+            {
+                Ok(common_enums::AttemptStatus::Voided) => {
+                    logger::info!(
+                        attempt_id = ?payment_attempt.id,
+                        "Voided a leg of the split payment"
+                    );
+                }
+                Ok(status) => {
+                    logger::error!(
+                        attempt_id = ?payment_attempt.id,
+                        ?status,
+                        "Void of a leg of the split payment did not succeed"
+                    );
+                    reversal.add_unreversed_amount(reversal_amount);
+                }
+                Err(error) => {
+                    logger::error!(
+                        ?error,
+                        attempt_id = ?payment_attempt.id,
+                        "Unable to void a leg of the split payment"
+                    );
+                    reversal.add_unreversed_amount(reversal_amount);
+                }
+            }
+            continue;
         }
 
-        split_pm_response_data
-            .secondary_payment_response_data
-            .push(payment_data);
+        match Box::pin(refund_funding_leg(
+            state,
+            platform,
+            payment_intent,
+            payment_attempt,
+        ))
+        .await
+        {
+            Ok(refund) => {
+                logger::info!(
+                    attempt_id = ?payment_attempt.id,
+                    refund_id = ?refund.id,
+                    refund_status = ?refund.status,
+                    "Refunded a leg of the split payment"
+                );
+                reversal.add_refund(refund.id, refund.status, reversal_amount);
+            }
+            Err(error) => {
+                logger::error!(
+                    ?error,
+                    attempt_id = ?payment_attempt.id,
+                    "Unable to refund a leg of the split payment"
+                );
+                reversal.add_unreversed_amount(reversal_amount);
+            }
+        }
     }
 
+    reversal
+}
+
+async fn refund_funding_leg(
+    state: &SessionState,
+    platform: &domain::Platform,
+    payment_intent: &PaymentIntent,
+    payment_attempt: &domain::PaymentAttempt,
+) -> RouterResult<api_models::refunds::RefundResponse> {
+    let reversal_amount = payment_attempt.get_total_amount();
+    let global_refund_id = id_type::GlobalRefundId::generate(&state.conf.cell_information.id);
+    let merchant_reference_id = id_type::RefundReferenceId::try_from(Cow::Owned(
+        global_refund_id.get_string_repr().to_owned(),
+    ))
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Unable to construct the refund reference id")?;
+
+    let refund_request = api::refunds::RefundsCreateRequest {
+        payment_id: payment_intent.id.clone(),
+        merchant_reference_id,
+        merchant_id: None,
+        amount: Some(reversal_amount),
+        reason: Some("Reversal of a split payment leg".to_string()),
+        refund_type: Some(api_models::refunds::RefundType::Instant),
+        metadata: None,
+        merchant_connector_details: None,
+        return_raw_connector_response: None,
+    };
+
+    Box::pin(refunds_v2::validate_and_create_refund(
+        state,
+        platform,
+        payment_attempt,
+        payment_intent,
+        reversal_amount,
+        refund_request,
+        global_refund_id,
+        None,
+    ))
+    .await
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn void_funding_leg(
+    state: &SessionState,
+    req_state: ReqState,
+    platform: &domain::Platform,
+    profile: &domain::Profile,
+    header_payload: &HeaderPayload,
+    payment_intent: &PaymentIntent,
+    payment_attempt: &domain::PaymentAttempt,
+) -> RouterResult<common_enums::AttemptStatus> {
+    let cancellation_reason = Some("Reversal of a split payment leg".to_string());
+
+    // The leg to be voided is not the active attempt of the payment intent, so the trackers are
+    // constructed here instead of being fetched by the operation
+    let mut payment_data = PaymentCancelData {
+        flow: PhantomData,
+        payment_intent: payment_intent.clone(),
+        payment_attempt: payment_attempt.clone(),
+    };
+    payment_data.set_cancellation_reason(cancellation_reason.clone());
+
+    let (payment_data, _req, _customer, _, _, _) = Box::pin(payments_operation_core::<
+        api::Void,
+        _,
+        _,
+        types::PaymentsCancelData,
+        _,
+    >(
+        state,
+        req_state,
+        platform.clone(),
+        profile,
+        payment_cancel_v2::PaymentsCancel,
+        payments_api::PaymentsCancelRequest {
+            cancellation_reason,
+            return_raw_connector_response: None,
+        },
+        operations::GetTrackerResponse { payment_data },
+        CallConnectorAction::Trigger,
+        header_payload.clone(),
+    ))
+    .await?;
+
+    Ok(payment_data.payment_attempt.status)
+}
+
+fn get_split_payment_reversal_process_tracker_id(
+    payment_id: &id_type::GlobalPaymentId,
+    merchant_id: &id_type::MerchantId,
+) -> String {
+    pt_utils::get_process_tracker_id(
+        storage::ProcessTrackerRunner::SplitPaymentReversalWorkflow,
+        SPLIT_PAYMENT_REVERSAL_TASK,
+        payment_id.get_string_repr(),
+        merchant_id,
+    )
+}
+
+/// Adds the task tracking the refunds of a split payment reversal which are still pending, until
+/// all of them reach a final status.
+async fn add_split_payment_reversal_task(
+    db: &dyn StorageInterface,
+    payment_intent: &PaymentIntent,
+    reversal: &SplitPaymentReversal,
+    application_source: common_enums::ApplicationSource,
+) -> RouterResult<()> {
+    let process_tracker_id = get_split_payment_reversal_process_tracker_id(
+        &payment_intent.id,
+        &payment_intent.merchant_id,
+    );
+    let tracking_data = payments_api::SplitPaymentReversalTrackingData {
+        payment_id: payment_intent.id.clone(),
+        merchant_id: payment_intent.merchant_id.clone(),
+        pending_refund_ids: reversal.pending_refund_ids.clone(),
+        unreversed_amount: reversal.unreversed_amount,
+    };
+    let schedule_time = common_utils::date_time::now() + SPLIT_PAYMENT_REVERSAL_SYNC_INTERVAL;
+
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        SPLIT_PAYMENT_REVERSAL_TASK,
+        storage::ProcessTrackerRunner::SplitPaymentReversalWorkflow,
+        SPLIT_PAYMENT_REVERSAL_TAGS,
+        tracking_data,
+        None,
+        schedule_time,
+        common_types::consts::API_VERSION,
+        application_source,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct the split payment reversal task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to add the split payment reversal task")?;
+
+    Ok(())
+}
+
+/// Updates the status of a split payment, along with the amount captured across its legs.
+pub(crate) async fn update_split_payment_status(
+    state: &SessionState,
+    processor: &domain::Processor,
+    payment_intent: PaymentIntent,
+    status: common_enums::IntentStatus,
+    amount_captured: Option<MinorUnit>,
+) -> RouterResult<PaymentIntent> {
     let payment_intent_update =
         hyperswitch_domain_models::payments::payment_intent::PaymentIntentUpdate::SplitPaymentStatusUpdate {
-            status: split_pm_response_data.get_intent_status(),
-            updated_by: platform
-                .get_processor()
-                .get_account()
-                .storage_scheme
-                .to_string(),
+            status,
+            amount_captured,
+            updated_by: processor.get_account().storage_scheme.to_string(),
         };
 
-    let _updated_payment_intent = db
+    state
+        .store
         .update_payment_intent(
             payment_intent,
             payment_intent_update,
-            platform.get_processor().get_key_store(),
-            platform.get_processor().get_account().storage_scheme,
+            processor.get_key_store(),
+            processor.get_account().storage_scheme,
         )
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Unable to update payment intent")?;
-
-    split_pm_response_data.generate_response(
-        &state,
-        connector_http_status_code,
-        external_latency,
-        header_payload.x_hs_latency,
-        &platform,
-        &profile,
-        Some(connector_response_data),
-    )
+        .attach_printable("Unable to update payment intent")
 }
 
 /// Construct the domain model from the ConfirmIntentRequest and PaymentIntent
@@ -601,3 +967,149 @@ pub async fn create_domain_model_for_split_payment(
         applied_offer_details: None,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn refund_id() -> id_type::GlobalRefundId {
+        id_type::GlobalRefundId::generate(&id_type::CellId::from_string("12345").unwrap())
+    }
+
+    #[test]
+    fn test_funded_leg_status() {
+        assert!(is_funded_leg_status(common_enums::AttemptStatus::Charged));
+        assert!(is_funded_leg_status(
+            common_enums::AttemptStatus::Authorized
+        ));
+        assert!(!is_funded_leg_status(common_enums::AttemptStatus::Failure));
+        assert!(!is_funded_leg_status(
+            common_enums::AttemptStatus::AuthenticationPending
+        ));
+    }
+
+    #[test]
+    fn test_pending_leg_status() {
+        assert!(is_pending_leg_status(
+            common_enums::AttemptStatus::AuthenticationPending
+        ));
+        assert!(is_pending_leg_status(common_enums::AttemptStatus::Pending));
+        assert!(!is_pending_leg_status(common_enums::AttemptStatus::Charged));
+        assert!(!is_pending_leg_status(
+            common_enums::AttemptStatus::Authorized
+        ));
+        assert!(!is_pending_leg_status(common_enums::AttemptStatus::Failure));
+        assert!(!is_pending_leg_status(
+            common_enums::AttemptStatus::AuthorizationFailed
+        ));
+    }
+
+    #[test]
+    fn test_funded_intent_status() {
+        let charged = common_enums::AttemptStatus::Charged;
+        let authorized = common_enums::AttemptStatus::Authorized;
+
+        assert_eq!(
+            get_funded_intent_status(
+                [
+                    (charged, MinorUnit::new(300)),
+                    (charged, MinorUnit::new(700))
+                ]
+                .into_iter()
+            ),
+            (
+                common_enums::IntentStatus::Succeeded,
+                Some(MinorUnit::new(1000))
+            )
+        );
+        assert_eq!(
+            get_funded_intent_status(
+                [
+                    (authorized, MinorUnit::new(300)),
+                    (authorized, MinorUnit::new(700))
+                ]
+                .into_iter()
+            ),
+            (common_enums::IntentStatus::RequiresCapture, None)
+        );
+        assert_eq!(
+            get_funded_intent_status(
+                [
+                    (charged, MinorUnit::new(300)),
+                    (authorized, MinorUnit::new(700))
+                ]
+                .into_iter()
+            ),
+            (
+                common_enums::IntentStatus::PartiallyCapturedAndCapturable,
+                Some(MinorUnit::new(300))
+            )
+        );
+    }
+
+    #[test]
+    fn test_reversal_fails_payment_only_when_refunds_succeed() {
+        let mut reversal = SplitPaymentReversal::default();
+        reversal.add_refund(
+            refund_id(),
+            api_models::refunds::RefundStatus::Succeeded,
+            MinorUnit::new(300),
+        );
+
+        assert_eq!(
+            reversal.get_intent_status(),
+            (common_enums::IntentStatus::Failed, None)
+        );
+    }
+
+    #[test]
+    fn test_reversal_with_pending_refund_is_processing() {
+        let mut reversal = SplitPaymentReversal::default();
+        reversal.add_refund(
+            refund_id(),
+            api_models::refunds::RefundStatus::Succeeded,
+            MinorUnit::new(300),
+        );
+        reversal.add_refund(
+            refund_id(),
+            api_models::refunds::RefundStatus::Pending,
+            MinorUnit::new(200),
+        );
+        reversal.add_unreversed_amount(MinorUnit::new(100));
+
+        assert_eq!(reversal.pending_refund_ids.len(), 1);
+        assert_eq!(
+            reversal.get_intent_status(),
+            (
+                common_enums::IntentStatus::Processing,
+                Some(MinorUnit::new(300))
+            )
+        );
+    }
+
+    #[test]
+    fn test_reversal_with_unreversed_legs_is_conflicted() {
+        let mut reversal = SplitPaymentReversal::default();
+        reversal.add_refund(
+            refund_id(),
+            api_models::refunds::RefundStatus::Failed,
+            MinorUnit::new(300),
+        );
+        reversal.add_refund(
+            refund_id(),
+            api_models::refunds::RefundStatus::Review,
+            MinorUnit::new(200),
+        );
+        reversal.mark_pending_as_unreversed();
+
+        assert!(reversal.pending_refund_ids.is_empty());
+        assert_eq!(
+            reversal.get_intent_status(),
+            (
+                common_enums::IntentStatus::Conflicted,
+                Some(MinorUnit::new(500))
+            )
+        );
+    }
+}
@@ -64,10 +64,10 @@ pub async fn get_three_ds_decision_rule_output(
         .attach_printable("Error parsing program from three_ds_decision rule algorithm")?;
     // Construct backend input from request
     let backend_input = dsl_inputs::BackendInput::foreign_from(request.clone());
-    // Initialize interpreter with the rule program
-    let interpreter = backend::VirInterpreterBackend::with_program(program)
+    // Compile the rule program
+    let interpreter = backend::CompiledBackend::with_program(program)
         .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Error initializing DSL interpreter backend")?;
+        .attach_printable("Error compiling DSL program")?;
     // Execute the rule
     let result = interpreter
         .execute(backend_input)
@@ -156,6 +156,7 @@ impl ForeignFrom<api_threedsecure::ThreeDsDecisionRuleExecuteRequest> for dsl_in
             acquirer_data: request.acquirer.map(ForeignFrom::foreign_from),
             customer_device_data: request.customer_device.map(ForeignFrom::foreign_from),
             issuer_data: request.issuer.map(ForeignFrom::foreign_from),
+            transaction_time: None,
         }
     }
 }
@@ -1,3 +1,7 @@
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod bulk_redelivery;
+#[cfg(feature = "v1")]
+pub mod endpoint_health;
 #[cfg(feature = "v1")]
 pub mod gateway;
 #[cfg(feature = "v1")]
@@ -5,6 +9,8 @@ pub mod incoming;
 #[cfg(feature = "v2")]
 mod incoming_v2;
 #[cfg(feature = "v1")]
+mod incoming_webhook_log;
+#[cfg(feature = "v1")]
 mod network_tokenization_incoming;
 #[cfg(feature = "v1")]
 mod outgoing;
@@ -14,6 +20,8 @@ mod outgoing_v2;
 pub mod recovery_incoming;
 pub mod types;
 pub mod utils;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod webhook_endpoints;
 #[cfg(feature = "olap")]
 pub mod webhook_events;
 
@@ -0,0 +1,614 @@
+use std::collections::HashSet;
+
+use api_models::webhook_events::{
+    EventsBulkRedeliveryRequest, EventsBulkRedeliveryResponse, EventsBulkRedeliveryStatus,
+};
+use common_utils::{date_time, ext_traits::ValueExt, fp_utils, id_type};
+use diesel_models::process_tracker::business_status;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+use time::PrimitiveDateTime;
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::storage,
+};
+
+const BULK_REDELIVERY_TASK: &str = "OUTGOING_WEBHOOK_BULK_REDELIVERY";
+const BULK_REDELIVERY_TAGS: [&str; 1] = ["OUTGOING_WEBHOOKS"];
+const BULK_REDELIVERY_MAX_DAYS: i64 = 90;
+const ACTIVE_BULK_REDELIVERY_PREFIX: &str = "ACTIVE_BULK_REDELIVERY";
+
+/// Time between two batches of a bulk redelivery, the send rate is expressed per this interval
+pub const BULK_REDELIVERY_INTERVAL: time::Duration = time::Duration::minutes(1);
+
+/// Tracking data of the process tracker task redelivering events in bulk. Progress is recorded
+/// after each batch, so that the status API reports it and an interrupted redelivery resumes
+/// where it stopped.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct EventsBulkRedeliveryTrackingData {
+    pub redelivery_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: id_type::ProfileId,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_after: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_before: PrimitiveDateTime,
+    pub event_types: HashSet<common_enums::EventType>,
+    pub is_delivered: Option<bool>,
+    pub events_per_minute: u32,
+    pub total_count: i64,
+    pub succeeded_count: u32,
+    pub failed_count: u32,
+    /// Events are redelivered from the most recent one, this is the creation time of the last
+    /// event redelivered
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub cursor: Option<PrimitiveDateTime>,
+    /// The events created at the cursor that were redelivered already
+    #[serde(default)]
+    pub redelivered_at_cursor: Vec<String>,
+}
+
+impl EventsBulkRedeliveryTrackingData {
+    /// The events of the next batch are created at or before the cursor
+    fn get_next_batch_created_before(&self) -> PrimitiveDateTime {
+        self.cursor.unwrap_or(self.created_before)
+    }
+
+    /// The events at the cursor are listed again, since the creation time bound is inclusive
+    fn get_next_batch_list_limit(&self, batch_size: usize) -> usize {
+        batch_size.saturating_add(self.redelivered_at_cursor.len())
+    }
+
+    fn is_redelivered_at_cursor(&self, event_id: &str) -> bool {
+        self.redelivered_at_cursor
+            .iter()
+            .any(|redelivered_event_id| redelivered_event_id == event_id)
+    }
+
+    fn record_redelivered_event(&mut self, event_id: String, created_at: PrimitiveDateTime) {
+        if self.cursor != Some(created_at) {
+            self.cursor = Some(created_at);
+            self.redelivered_at_cursor.clear();
+        }
+        self.redelivered_at_cursor.push(event_id);
+    }
+}
+
+/// Picks the events to redeliver among the events listed for the next batch, most recent first,
+/// and returns whether no events are left to redeliver after them.
+fn get_next_batch<T>(
+    tracking_data: &EventsBulkRedeliveryTrackingData,
+    listed_events: Vec<T>,
+    batch_size: usize,
+    get_event_id: impl Fn(&T) -> &str,
+) -> (Vec<T>, bool) {
+    let is_last_page = listed_events.len() < tracking_data.get_next_batch_list_limit(batch_size);
+    let pending_events = listed_events
+        .into_iter()
+        .filter(|event| !tracking_data.is_redelivered_at_cursor(get_event_id(event)))
+        .collect::<Vec<_>>();
+    let is_complete = is_last_page && pending_events.len() <= batch_size;
+
+    (
+        pending_events.into_iter().take(batch_size).collect(),
+        is_complete,
+    )
+}
+
+fn get_process_tracker_id(redelivery_id: &str) -> String {
+    let runner = storage::ProcessTrackerRunner::OutgoingWebhookBulkRedeliveryWorkflow;
+    format!("{runner}_{BULK_REDELIVERY_TASK}_{redelivery_id}")
+}
+
+fn get_active_bulk_redelivery_key(profile_id: &id_type::ProfileId) -> String {
+    format!(
+        "{ACTIVE_BULK_REDELIVERY_PREFIX}_{}",
+        profile_id.get_string_repr()
+    )
+}
+
+/// Returns an error if a bulk redelivery of the events of the profile is pending, since two
+/// redeliveries running at the same time would deliver the same events twice.
+async fn validate_no_pending_bulk_redelivery(
+    state: &SessionState,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<()> {
+    let active_redelivery_id = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .get_key::<Option<String>>(&get_active_bulk_redelivery_key(profile_id).into())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the active bulk redelivery of the profile")?;
+
+    let Some(active_redelivery_id) = active_redelivery_id else {
+        return Ok(());
+    };
+    let active_process = state
+        .store
+        .find_process_by_id(&get_process_tracker_id(&active_redelivery_id))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the active bulk redelivery task")?;
+
+    fp_utils::when(
+        active_process
+            .is_some_and(|process| process.status != common_enums::ProcessTrackerStatus::Finish),
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "Bulk redelivery '{active_redelivery_id}' of the events of the profile is \
+                     still pending"
+                ),
+            }))
+        },
+    )
+}
+
+/// Records the bulk redelivery as the active one of the profile. The record expires once the
+/// redelivery would have completed even if some batches were delayed, and a failure to write it
+/// is only logged, since the redelivery has been scheduled already.
+async fn set_active_bulk_redelivery(
+    state: &SessionState,
+    tracking_data: &EventsBulkRedeliveryTrackingData,
+) {
+    let batch_count = tracking_data
+        .total_count
+        .saturating_div(i64::from(tracking_data.events_per_minute))
+        .saturating_add(1);
+    let expiry = BULK_REDELIVERY_INTERVAL
+        .whole_seconds()
+        .saturating_mul(batch_count)
+        .saturating_add(time::Duration::days(1).whole_seconds());
+
+    let result = match state.store.get_redis_conn() {
+        Ok(redis_conn) => {
+            redis_conn
+                .set_key_with_expiry(
+                    &get_active_bulk_redelivery_key(&tracking_data.profile_id).into(),
+                    tracking_data.redelivery_id.as_str(),
+                    expiry,
+                )
+                .await
+        }
+        Err(error) => Err(error),
+    };
+
+    result
+        .map_err(|error| logger::error!(?error, "Failed to record the active bulk redelivery"))
+        .ok();
+}
+
+fn get_bulk_redelivery_response(
+    process: &storage::ProcessTracker,
+    tracking_data: EventsBulkRedeliveryTrackingData,
+) -> EventsBulkRedeliveryResponse {
+    let status = match process.business_status.as_str() {
+        business_status::COMPLETED_BY_PT => EventsBulkRedeliveryStatus::Completed,
+        _ if process.status == common_enums::ProcessTrackerStatus::Finish => {
+            EventsBulkRedeliveryStatus::Failed
+        }
+        _ => EventsBulkRedeliveryStatus::Pending,
+    };
+
+    EventsBulkRedeliveryResponse {
+        redelivery_id: tracking_data.redelivery_id,
+        merchant_id: tracking_data.merchant_id,
+        profile_id: tracking_data.profile_id,
+        status,
+        events_per_minute: tracking_data.events_per_minute,
+        total_count: tracking_data.total_count,
+        succeeded_count: tracking_data.succeeded_count,
+        failed_count: tracking_data.failed_count,
+        created: process.created_at,
+        modified: process.updated_at,
+    }
+}
+
+#[instrument(skip(state))]
+pub async fn create_bulk_redelivery(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    request: EventsBulkRedeliveryRequest,
+) -> RouterResponse<EventsBulkRedeliveryResponse> {
+    let store = state.store.as_ref();
+    let master_key = &store.get_master_key().to_vec().into();
+    let key_store = store
+        .get_merchant_key_store_by_merchant_id(&merchant_id, master_key)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    store
+        .find_business_profile_by_merchant_id_profile_id(
+            &key_store,
+            &merchant_id,
+            &request.profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: request.profile_id.get_string_repr().to_owned(),
+        })?;
+
+    let max_events_per_minute = state.conf.webhooks.bulk_redelivery_max_events_per_minute;
+    fp_utils::when(max_events_per_minute == 0, || {
+        Err(report!(errors::ApiErrorResponse::NotSupported {
+            message: "Bulk redelivery of events".to_string(),
+        }))
+    })?;
+    let events_per_minute = request.events_per_minute.unwrap_or(max_events_per_minute);
+    fp_utils::when(
+        !(1..=max_events_per_minute).contains(&events_per_minute),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "`events_per_minute` must be between 1 and {max_events_per_minute}"
+                ),
+            }))
+        },
+    )?;
+
+    fp_utils::when(request.created_after > request.created_before, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "The `created_after` timestamp must be an earlier timestamp compared to \
+                the `created_before` timestamp"
+                .to_string(),
+        }))
+    })?;
+
+    validate_no_pending_bulk_redelivery(&state, &request.profile_id).await?;
+
+    // Events are retained for a limited time, and the ones created after the redelivery was
+    // requested are delivered as usual
+    let now = date_time::now();
+    let begin_time = (now.date() - time::Duration::days(BULK_REDELIVERY_MAX_DAYS)).midnight();
+    let created_after = request.created_after.max(begin_time);
+    let created_before = request.created_before.min(now);
+    let event_types = request.event_types.unwrap_or_default();
+
+    let total_count = store
+        .count_initial_events_by_profile_id_constraints(
+            &request.profile_id,
+            created_after,
+            created_before,
+            event_types.clone(),
+            request.is_delivered,
+            Some(common_enums::EventRecipient::Merchant),
+            None,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get total events count")?;
+
+    let redelivery_id = common_utils::generate_id(consts::ID_LENGTH, "redelivery");
+    let tracking_data = EventsBulkRedeliveryTrackingData {
+        redelivery_id: redelivery_id.clone(),
+        merchant_id,
+        profile_id: request.profile_id,
+        created_after,
+        created_before,
+        event_types,
+        is_delivered: request.is_delivered,
+        events_per_minute,
+        total_count,
+        succeeded_count: 0,
+        failed_count: 0,
+        cursor: None,
+        redelivered_at_cursor: Vec::new(),
+    };
+
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        get_process_tracker_id(&redelivery_id),
+        BULK_REDELIVERY_TASK,
+        storage::ProcessTrackerRunner::OutgoingWebhookBulkRedeliveryWorkflow,
+        BULK_REDELIVERY_TAGS,
+        tracking_data.clone(),
+        None,
+        now,
+        common_types::consts::API_VERSION,
+        state.conf.application_source,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct the bulk redelivery task")?;
+
+    let process = store
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to add the bulk redelivery task")?;
+
+    set_active_bulk_redelivery(&state, &tracking_data).await;
+
+    logger::info!(%redelivery_id, total_count, "Scheduled bulk redelivery of events");
+
+    Ok(ApplicationResponse::Json(get_bulk_redelivery_response(
+        &process,
+        tracking_data,
+    )))
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_bulk_redelivery(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    redelivery_id: String,
+) -> RouterResponse<EventsBulkRedeliveryResponse> {
+    let not_found = || errors::ApiErrorResponse::GenericNotFoundError {
+        message: format!("Bulk redelivery with the given id '{redelivery_id}' does not exist"),
+    };
+
+    let process = state
+        .store
+        .find_process_by_id(&get_process_tracker_id(&redelivery_id))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the bulk redelivery task")?
+        .ok_or_else(not_found)?;
+
+    let tracking_data: EventsBulkRedeliveryTrackingData = process
+        .tracking_data
+        .clone()
+        .parse_value("EventsBulkRedeliveryTrackingData")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    if tracking_data.merchant_id != merchant_id {
+        return Err(report!(not_found()));
+    }
+
+    Ok(ApplicationResponse::Json(get_bulk_redelivery_response(
+        &process,
+        tracking_data,
+    )))
+}
+
+/// Redelivers the next batch of events of a bulk redelivery and records the progress in the
+/// tracking data. Returns whether all matching events have been redelivered. Events whose
+/// redelivery fails are counted rather than retried, as each attempt is recorded on the event.
+pub async fn redeliver_next_batch(
+    state: &SessionState,
+    tracking_data: &mut EventsBulkRedeliveryTrackingData,
+) -> RouterResult<bool> {
+    let store = state.store.as_ref();
+    let master_key = &store.get_master_key().to_vec().into();
+    let key_store = store
+        .get_merchant_key_store_by_merchant_id(&tracking_data.merchant_id, master_key)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let business_profile = store
+        .find_business_profile_by_merchant_id_profile_id(
+            &key_store,
+            &tracking_data.merchant_id,
+            &tracking_data.profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: tracking_data.profile_id.get_string_repr().to_owned(),
+        })?;
+
+    let batch_size = usize::try_from(tracking_data.events_per_minute)
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    let limit = tracking_data.get_next_batch_list_limit(batch_size);
+    let events = store
+        .list_initial_events_by_profile_id_constraints(
+            &tracking_data.profile_id,
+            tracking_data.created_after,
+            tracking_data.get_next_batch_created_before(),
+            Some(
+                i64::try_from(limit)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)?,
+            ),
+            None,
+            tracking_data.event_types.clone(),
+            tracking_data.is_delivered,
+            &key_store,
+            Some(common_enums::EventRecipient::Merchant),
+            None,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list events to redeliver")?;
+
+    let (batch, is_complete) = get_next_batch(tracking_data, events, batch_size, |event| {
+        event.event_id.as_str()
+    });
+
+    for event in batch {
+        let event_id = event.event_id.clone();
+        let created_at = event.created_at;
+
+        match super::webhook_events::redeliver_event(
+            state,
+            &key_store,
+            &tracking_data.merchant_id,
+            business_profile.clone(),
+            event,
+        )
+        .await
+        {
+            Ok(redelivered_event) if redelivered_event.is_webhook_notified => {
+                tracking_data.succeeded_count = tracking_data.succeeded_count.saturating_add(1);
+            }
+            Ok(_) => {
+                tracking_data.failed_count = tracking_data.failed_count.saturating_add(1);
+            }
+            Err(error) => {
+                logger::error!(?error, %event_id, "Failed to redeliver event");
+                tracking_data.failed_count = tracking_data.failed_count.saturating_add(1);
+            }
+        }
+
+        tracking_data.record_redelivered_event(event_id, created_at);
+    }
+
+    logger::info!(
+        redelivery_id = %tracking_data.redelivery_id,
+        succeeded_count = tracking_data.succeeded_count,
+        failed_count = tracking_data.failed_count,
+        is_complete,
+        "Redelivered batch of events"
+    );
+
+    Ok(is_complete)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::borrow::Cow;
+
+    use time::macros::datetime;
+
+    use super::*;
+
+    struct TestEvent {
+        event_id: String,
+        created_at: PrimitiveDateTime,
+    }
+
+    fn get_tracking_data(events_per_minute: u32) -> EventsBulkRedeliveryTrackingData {
+        EventsBulkRedeliveryTrackingData {
+            redelivery_id: "redelivery_test".to_string(),
+            merchant_id: id_type::MerchantId::try_from(Cow::from("merchant_test")).unwrap(),
+            profile_id: id_type::ProfileId::try_from(Cow::from("profile_test")).unwrap(),
+            created_after: datetime!(2024-01-01 00:00),
+            created_before: datetime!(2024-01-02 00:00),
+            event_types: HashSet::new(),
+            is_delivered: None,
+            events_per_minute,
+            total_count: 0,
+            succeeded_count: 0,
+            failed_count: 0,
+            cursor: None,
+            redelivered_at_cursor: Vec::new(),
+        }
+    }
+
+    /// Lists the events the way the store does: most recent first, within the inclusive creation
+    /// time bounds and up to the limit
+    fn list_events<'a>(
+        events: &'a [TestEvent],
+        tracking_data: &EventsBulkRedeliveryTrackingData,
+        limit: usize,
+    ) -> Vec<&'a TestEvent> {
+        let mut listed_events = events
+            .iter()
+            .filter(|event| {
+                event.created_at >= tracking_data.created_after
+                    && event.created_at <= tracking_data.get_next_batch_created_before()
+            })
+            .collect::<Vec<_>>();
+        listed_events.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        listed_events.truncate(limit);
+        listed_events
+    }
+
+    /// Runs batches until the redelivery completes, returning the redelivered event IDs in order,
+    /// or `None` if it does not complete within one batch per event
+    fn redeliver_all(
+        events: &[TestEvent],
+        tracking_data: &mut EventsBulkRedeliveryTrackingData,
+    ) -> Option<Vec<String>> {
+        let batch_size = usize::try_from(tracking_data.events_per_minute).unwrap();
+        let mut redelivered_event_ids = Vec::new();
+
+        for _ in 0..=events.len() {
+            let limit = tracking_data.get_next_batch_list_limit(batch_size);
+            let listed_events = list_events(events, tracking_data, limit);
+            let (batch, is_complete) =
+                get_next_batch(tracking_data, listed_events, batch_size, |event| {
+                    event.event_id.as_str()
+                });
+            assert!(batch.len() <= batch_size);
+
+            for event in batch {
+                redelivered_event_ids.push(event.event_id.clone());
+                tracking_data.record_redelivered_event(event.event_id.clone(), event.created_at);
+            }
+
+            if is_complete {
+                return Some(redelivered_event_ids);
+            }
+        }
+
+        None
+    }
+
+    fn get_event(event_id: &str, created_at: PrimitiveDateTime) -> TestEvent {
+        TestEvent {
+            event_id: event_id.to_string(),
+            created_at,
+        }
+    }
+
+    #[test]
+    fn test_redelivers_events_most_recent_first() {
+        let events = [
+            get_event("evt_1", datetime!(2024-01-01 01:00)),
+            get_event("evt_2", datetime!(2024-01-01 02:00)),
+            get_event("evt_3", datetime!(2024-01-01 03:00)),
+            get_event("evt_4", datetime!(2024-01-01 04:00)),
+            get_event("evt_5", datetime!(2024-01-01 05:00)),
+            // Outside of the requested time range
+            get_event("evt_6", datetime!(2024-01-03 00:00)),
+        ];
+        let mut tracking_data = get_tracking_data(2);
+
+        let redelivered_event_ids = redeliver_all(&events, &mut tracking_data).unwrap();
+
+        assert_eq!(
+            redelivered_event_ids,
+            ["evt_5", "evt_4", "evt_3", "evt_2", "evt_1"]
+        );
+        assert_eq!(tracking_data.cursor, Some(datetime!(2024-01-01 01:00)));
+    }
+
+    #[test]
+    fn test_redelivers_events_created_at_the_same_time_once() {
+        let created_at = datetime!(2024-01-01 12:00);
+        let events = [
+            get_event("evt_1", datetime!(2024-01-01 13:00)),
+            get_event("evt_2", created_at),
+            get_event("evt_3", created_at),
+            get_event("evt_4", created_at),
+            get_event("evt_5", created_at),
+            get_event("evt_6", datetime!(2024-01-01 11:00)),
+        ];
+        let mut tracking_data = get_tracking_data(2);
+
+        let mut redelivered_event_ids = redeliver_all(&events, &mut tracking_data).unwrap();
+
+        assert_eq!(redelivered_event_ids.first().unwrap(), "evt_1");
+        assert_eq!(redelivered_event_ids.last().unwrap(), "evt_6");
+        redelivered_event_ids.sort();
+        assert_eq!(
+            redelivered_event_ids,
+            ["evt_1", "evt_2", "evt_3", "evt_4", "evt_5", "evt_6"]
+        );
+    }
+
+    #[test]
+    fn test_completes_when_the_last_page_is_full() {
+        let events = [
+            get_event("evt_1", datetime!(2024-01-01 01:00)),
+            get_event("evt_2", datetime!(2024-01-01 02:00)),
+        ];
+        let mut tracking_data = get_tracking_data(2);
+
+        let redelivered_event_ids = redeliver_all(&events, &mut tracking_data).unwrap();
+
+        assert_eq!(redelivered_event_ids, ["evt_2", "evt_1"]);
+    }
+
+    #[test]
+    fn test_completes_without_events() {
+        let mut tracking_data = get_tracking_data(10);
+
+        assert!(redeliver_all(&[], &mut tracking_data).unwrap().is_empty());
+        assert_eq!(tracking_data.cursor, None);
+    }
+}
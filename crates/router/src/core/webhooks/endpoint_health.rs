@@ -0,0 +1,929 @@
+//! Delivery health of outgoing webhook endpoints.
+//!
+//! Every delivery to a webhook endpoint is recorded in Redis: the number of consecutive failures
+//! and the time of the last success and failure, along with delivery and failure counts over a
+//! configurable window. Once the consecutive failures reach the configured threshold, the
+//! endpoint is paused and the merchant is notified by email. Events for a paused endpoint are
+//! queued instead of delivered, and the queue is flushed by a process tracker task once the
+//! merchant activates the endpoint again.
+
+use std::collections::HashMap;
+
+#[cfg(feature = "olap")]
+use common_utils::fp_utils;
+use common_utils::{date_time, id_type};
+#[cfg(feature = "olap")]
+use diesel_models::process_tracker::business_status;
+#[cfg(feature = "olap")]
+use error_stack::report;
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+
+use super::{utils, MERCHANT_ID};
+#[cfg(feature = "olap")]
+use crate::{consts, types::storage};
+use crate::{
+    core::{
+        errors::{self, RouterResult, StorageErrorExt},
+        metrics,
+    },
+    routes::SessionState,
+    types::domain,
+};
+
+const HEALTH_REDIS_KEY_PREFIX: &str = "webhook_endpoint_health";
+const DELIVERIES_REDIS_KEY_PREFIX: &str = "webhook_endpoint_deliveries";
+const QUEUE_REDIS_KEY_PREFIX: &str = "webhook_endpoint_queue";
+
+const CONSECUTIVE_FAILURES_FIELD: &str = "consecutive_failures";
+const LAST_SUCCESS_AT_FIELD: &str = "last_success_at";
+const LAST_FAILURE_AT_FIELD: &str = "last_failure_at";
+const DELIVERY_COUNT_FIELD: &str = "delivery_count";
+const FAILURE_COUNT_FIELD: &str = "failure_count";
+
+/// Health and queued events of an endpoint are kept for as long as events are retried
+const HEALTH_TTL_IN_SECONDS: i64 = 30 * 24 * 60 * 60;
+
+#[cfg(feature = "olap")]
+const FLUSH_TASK: &str = "OUTGOING_WEBHOOK_ENDPOINT_FLUSH";
+#[cfg(feature = "olap")]
+const FLUSH_TAGS: [&str; 1] = ["OUTGOING_WEBHOOKS"];
+
+/// Time between two batches of a flush, the send rate is expressed per this interval
+#[cfg(feature = "olap")]
+pub const FLUSH_INTERVAL: time::Duration = time::Duration::minutes(1);
+
+/// Number of times the delivery of a queued event is attempted by a flush, events whose delivery
+/// failed are queued again until then
+#[cfg(feature = "olap")]
+const MAX_FLUSH_DELIVERY_ATTEMPTS: u32 = 3;
+
+/// Tracking data of the process tracker task delivering the events queued while an endpoint was
+/// paused
+#[cfg(feature = "olap")]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct WebhookEndpointFlushTrackingData {
+    pub flush_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: id_type::ProfileId,
+    pub webhook_endpoint_id: id_type::WebhookEndpointId,
+    pub events_per_minute: u32,
+    pub succeeded_count: u32,
+    pub failed_count: u32,
+    /// Failed deliveries of the events queued again, by initial attempt ID
+    #[serde(default)]
+    pub failed_attempts: HashMap<String, u32>,
+}
+
+#[cfg(feature = "olap")]
+impl WebhookEndpointFlushTrackingData {
+    fn record_delivered(&mut self, initial_attempt_id: &str) {
+        self.succeeded_count = self.succeeded_count.saturating_add(1);
+        self.failed_attempts.remove(initial_attempt_id);
+    }
+
+    /// Records a failed delivery of a queued event, returning whether the event is to be queued
+    /// again
+    fn record_failed_delivery(&mut self, initial_attempt_id: &str) -> bool {
+        let failed_attempts = self
+            .failed_attempts
+            .entry(initial_attempt_id.to_owned())
+            .or_default();
+        *failed_attempts = failed_attempts.saturating_add(1);
+        if *failed_attempts < MAX_FLUSH_DELIVERY_ATTEMPTS {
+            return true;
+        }
+
+        self.failed_attempts.remove(initial_attempt_id);
+        self.failed_count = self.failed_count.saturating_add(1);
+        false
+    }
+}
+
+/// A single flush task exists per endpoint, so that events are not delivered twice by flushes
+/// running at the same time
+#[cfg(feature = "olap")]
+fn get_flush_process_tracker_id(
+    merchant_id: &id_type::MerchantId,
+    webhook_endpoint_id: &id_type::WebhookEndpointId,
+) -> String {
+    scheduler::utils::get_process_tracker_id(
+        storage::ProcessTrackerRunner::OutgoingWebhookEndpointFlushWorkflow,
+        FLUSH_TASK,
+        webhook_endpoint_id.get_string_repr(),
+        merchant_id,
+    )
+}
+
+fn get_redis_key(prefix: &str, webhook_endpoint_id: &id_type::WebhookEndpointId) -> String {
+    format!("{prefix}_{}", webhook_endpoint_id.get_string_repr())
+}
+
+fn parse_field<T: std::str::FromStr>(fields: &HashMap<String, String>, field: &str) -> Option<T> {
+    fields.get(field).and_then(|value| value.parse().ok())
+}
+
+fn parse_timestamp_field(
+    fields: &HashMap<String, String>,
+    field: &str,
+) -> Option<time::PrimitiveDateTime> {
+    parse_field::<i64>(fields, field)
+        .and_then(|timestamp| time::OffsetDateTime::from_unix_timestamp(timestamp).ok())
+        .map(date_time::convert_to_pdt)
+}
+
+/// Records the outcome of a delivery to a webhook endpoint, pausing the endpoint once its
+/// consecutive failures reach the configured threshold.
+///
+/// Never returns an error: the delivery has already happened and its outcome is stored on the
+/// event, so failing to record it must not fail the delivery.
+#[instrument(skip_all)]
+pub async fn record_delivery(
+    state: &SessionState,
+    merchant_key_store: &domain::MerchantKeyStore,
+    business_profile: &domain::Profile,
+    webhook_endpoint_id: &id_type::WebhookEndpointId,
+    is_delivered: bool,
+) {
+    let consecutive_failures =
+        match write_delivery_outcome(state, webhook_endpoint_id, is_delivered).await {
+            Ok(consecutive_failures) => consecutive_failures,
+            Err(error) => {
+                logger::error!(
+                    ?error,
+                    webhook_endpoint_id = webhook_endpoint_id.get_string_repr(),
+                    "Failed to record webhook endpoint delivery"
+                );
+                return;
+            }
+        };
+
+    let threshold = state.conf.webhooks.endpoint_pause_consecutive_failures;
+    if threshold != 0 && consecutive_failures >= threshold {
+        if let Err(error) = pause_webhook_endpoint(
+            state,
+            merchant_key_store,
+            business_profile,
+            webhook_endpoint_id,
+            consecutive_failures,
+        )
+        .await
+        {
+            logger::error!(
+                ?error,
+                webhook_endpoint_id = webhook_endpoint_id.get_string_repr(),
+                "Failed to pause webhook endpoint"
+            );
+        }
+    }
+}
+
+/// Updates the health of the endpoint, returning the number of consecutive failures. The counts
+/// of the window start with the first delivery after the previous window expired.
+async fn write_delivery_outcome(
+    state: &SessionState,
+    webhook_endpoint_id: &id_type::WebhookEndpointId,
+    is_delivered: bool,
+) -> error_stack::Result<u32, storage_impl::errors::RedisError> {
+    let conn = state.store.get_redis_conn()?;
+    let health_key = get_redis_key(HEALTH_REDIS_KEY_PREFIX, webhook_endpoint_id);
+    let deliveries_key = get_redis_key(DELIVERIES_REDIS_KEY_PREFIX, webhook_endpoint_id);
+    let now = date_time::now_unix_timestamp().to_string();
+
+    let consecutive_failures = if is_delivered {
+        conn.set_hash_fields(
+            &health_key.as_str().into(),
+            vec![
+                (CONSECUTIVE_FAILURES_FIELD, "0".to_string()),
+                (LAST_SUCCESS_AT_FIELD, now),
+            ],
+            Some(HEALTH_TTL_IN_SECONDS),
+        )
+        .await?;
+        0
+    } else {
+        let consecutive_failures = conn
+            .increment_fields_in_hash(
+                &health_key.as_str().into(),
+                &[(CONSECUTIVE_FAILURES_FIELD, 1)],
+            )
+            .await?
+            .first()
+            .copied()
+            .unwrap_or_default();
+        conn.set_hash_fields(
+            &health_key.as_str().into(),
+            vec![(LAST_FAILURE_AT_FIELD, now)],
+            Some(HEALTH_TTL_IN_SECONDS),
+        )
+        .await?;
+        u32::try_from(consecutive_failures).unwrap_or(u32::MAX)
+    };
+
+    let delivery_count = conn
+        .increment_fields_in_hash(
+            &deliveries_key.as_str().into(),
+            &[
+                (DELIVERY_COUNT_FIELD, 1),
+                (FAILURE_COUNT_FIELD, i64::from(!is_delivered)),
+            ],
+        )
+        .await?
+        .first()
+        .copied()
+        .unwrap_or_default();
+    if delivery_count == 1 {
+        conn.set_expiry(
+            &deliveries_key.as_str().into(),
+            state.conf.webhooks.endpoint_health_window_in_seconds.into(),
+        )
+        .await
+        .inspect_err(|error| {
+            logger::warn!(
+                ?error,
+                webhook_endpoint_id = webhook_endpoint_id.get_string_repr(),
+                "Failed to set expiry of webhook endpoint deliveries window"
+            );
+        })
+        .ok();
+    }
+
+    Ok(consecutive_failures)
+}
+
+/// Pauses an active endpoint and notifies the merchant. The business profile is fetched again
+/// while holding the lock on its endpoints, since the endpoints may have changed while the
+/// webhook was being delivered.
+async fn pause_webhook_endpoint(
+    state: &SessionState,
+    merchant_key_store: &domain::MerchantKeyStore,
+    business_profile: &domain::Profile,
+    webhook_endpoint_id: &id_type::WebhookEndpointId,
+    consecutive_failures: u32,
+) -> RouterResult<()> {
+    let merchant_id = &business_profile.merchant_id;
+    let profile_id = business_profile.get_id();
+
+    let paused_webhook_endpoint = utils::with_webhook_endpoints_lock(state, profile_id, async {
+        let business_profile = state
+            .store
+            .find_business_profile_by_merchant_id_profile_id(
+                merchant_key_store,
+                merchant_id,
+                profile_id,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+                id: profile_id.get_string_repr().to_owned(),
+            })?;
+
+        let Some(mut webhook_details) = business_profile.webhook_details.clone() else {
+            return Ok(None);
+        };
+        let Some(webhook_endpoint) = webhook_details
+            .multiple_webhooks_list
+            .iter_mut()
+            .flatten()
+            .find(|endpoint| {
+                &endpoint.webhook_endpoint_id == webhook_endpoint_id
+                    && endpoint.status == common_enums::OutgoingWebhookEndpointStatus::Active
+            })
+        else {
+            return Ok(None);
+        };
+        webhook_endpoint.status = common_enums::OutgoingWebhookEndpointStatus::Paused;
+        let paused_webhook_endpoint = webhook_endpoint.clone();
+
+        state
+            .store
+            .update_profile_by_profile_id(
+                merchant_key_store,
+                business_profile,
+                domain::ProfileUpdate::WebhookDetailsUpdate {
+                    webhook_details: Some(webhook_details),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to pause webhook endpoint")?;
+
+        Ok::<_, error_stack::Report<errors::ApiErrorResponse>>(Some(paused_webhook_endpoint))
+    })
+    .await?;
+    // The endpoint was paused or removed in the meantime
+    let Some(paused_webhook_endpoint) = paused_webhook_endpoint else {
+        return Ok(());
+    };
+
+    metrics::WEBHOOK_ENDPOINT_PAUSED_COUNT.add(
+        1,
+        router_env::metric_attributes!((MERCHANT_ID, merchant_id.clone())),
+    );
+    logger::warn!(
+        webhook_endpoint_id = paused_webhook_endpoint
+            .webhook_endpoint_id
+            .get_string_repr(),
+        consecutive_failures,
+        "Paused webhook endpoint after consecutive delivery failures"
+    );
+
+    #[cfg(feature = "email")]
+    send_webhook_endpoint_paused_email(
+        state,
+        merchant_key_store,
+        merchant_id,
+        profile_id,
+        &paused_webhook_endpoint,
+        consecutive_failures,
+    )
+    .await;
+
+    Ok(())
+}
+
+#[cfg(feature = "email")]
+async fn send_webhook_endpoint_paused_email(
+    state: &SessionState,
+    merchant_key_store: &domain::MerchantKeyStore,
+    merchant_id: &id_type::MerchantId,
+    profile_id: &id_type::ProfileId,
+    webhook_endpoint: &diesel_models::business_profile::MultipleWebhookDetail,
+    consecutive_failures: u32,
+) {
+    use common_utils::ext_traits::ValueExt;
+    use hyperswitch_masking::PeekInterface;
+
+    use crate::{
+        consts, services::email::types::WebhookEndpointPaused, types::api,
+        utils::user as user_utils,
+    };
+
+    let recipient_email = match state
+        .store
+        .find_merchant_account_by_merchant_id(merchant_id, merchant_key_store)
+        .await
+        .change_context(errors::ApiErrorResponse::MerchantAccountNotFound)
+        .and_then(|merchant_account| {
+            merchant_account
+                .merchant_details
+                .clone()
+                .parse_value::<api::MerchantDetails>("MerchantDetails")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+        })
+        .map(|merchant_details| merchant_details.primary_email)
+    {
+        Ok(Some(primary_email)) => match domain::UserEmail::from_pii_email(primary_email) {
+            Ok(recipient_email) => recipient_email,
+            Err(error) => {
+                logger::warn!(?error, "Invalid merchant primary email");
+                return;
+            }
+        },
+        Ok(None) => {
+            logger::warn!("Merchant has no primary email to notify of the paused webhook endpoint");
+            return;
+        }
+        Err(error) => {
+            logger::warn!(?error, "Failed to fetch merchant primary email");
+            return;
+        }
+    };
+
+    let email_contents = WebhookEndpointPaused {
+        recipient_email,
+        subject: consts::EMAIL_SUBJECT_WEBHOOK_ENDPOINT_PAUSED,
+        webhook_endpoint_id: webhook_endpoint
+            .webhook_endpoint_id
+            .get_string_repr()
+            .to_owned(),
+        profile_id: profile_id.get_string_repr().to_owned(),
+        webhook_url: webhook_endpoint.webhook_url.peek().clone(),
+        consecutive_failures,
+    };
+
+    if let Err(error) = state
+        .email_client
+        .clone()
+        .compose_and_send_email(
+            user_utils::get_base_url(state),
+            Box::new(email_contents),
+            state.conf.proxy.https_url.as_ref(),
+        )
+        .await
+    {
+        logger::warn!(
+            ?error,
+            webhook_endpoint_id = webhook_endpoint.webhook_endpoint_id.get_string_repr(),
+            "Failed to send webhook endpoint paused email"
+        );
+    }
+}
+
+/// Queues an event for a paused endpoint, identified by its initial attempt so that the event is
+/// delivered once regardless of how many of its attempts were queued
+pub async fn queue_event(
+    state: &SessionState,
+    webhook_endpoint_id: &id_type::WebhookEndpointId,
+    initial_attempt_id: &str,
+) -> error_stack::Result<(), errors::WebhooksFlowError> {
+    let conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::WebhooksFlowError::WebhookQueueingFailed)?;
+    let queue_key = get_redis_key(QUEUE_REDIS_KEY_PREFIX, webhook_endpoint_id);
+
+    conn.append_elements_to_list(
+        &queue_key.as_str().into(),
+        vec![initial_attempt_id.to_owned()],
+    )
+    .await
+    .change_context(errors::WebhooksFlowError::WebhookQueueingFailed)?;
+    conn.set_expiry(&queue_key.as_str().into(), HEALTH_TTL_IN_SECONDS)
+        .await
+        .inspect_err(|error| {
+            logger::warn!(
+                ?error,
+                webhook_endpoint_id = webhook_endpoint_id.get_string_repr(),
+                "Failed to refresh expiry of webhook endpoint queue"
+            );
+        })
+        .ok();
+
+    logger::info!(
+        webhook_endpoint_id = webhook_endpoint_id.get_string_repr(),
+        initial_attempt_id,
+        "Queued event for paused webhook endpoint"
+    );
+
+    Ok(())
+}
+
+/// Returns the delivery health of an endpoint
+#[cfg(feature = "olap")]
+pub async fn get_webhook_endpoint_health(
+    state: &SessionState,
+    profile_id: &id_type::ProfileId,
+    webhook_endpoint: diesel_models::business_profile::MultipleWebhookDetail,
+) -> RouterResult<api_models::webhook_endpoints::WebhookEndpointHealthResponse> {
+    let conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let webhook_endpoint_id = &webhook_endpoint.webhook_endpoint_id;
+
+    let health = conn
+        .get_hash_fields::<HashMap<String, String>>(
+            &get_redis_key(HEALTH_REDIS_KEY_PREFIX, webhook_endpoint_id)
+                .as_str()
+                .into(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch webhook endpoint health")?;
+    let deliveries = conn
+        .get_hash_fields::<HashMap<String, String>>(
+            &get_redis_key(DELIVERIES_REDIS_KEY_PREFIX, webhook_endpoint_id)
+                .as_str()
+                .into(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch webhook endpoint deliveries")?;
+    let queued_events_count = conn
+        .get_list_length(
+            &get_redis_key(QUEUE_REDIS_KEY_PREFIX, webhook_endpoint_id)
+                .as_str()
+                .into(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch webhook endpoint queue length")?;
+
+    let delivery_count = parse_field::<u32>(&deliveries, DELIVERY_COUNT_FIELD).unwrap_or_default();
+    let failure_count = parse_field::<u32>(&deliveries, FAILURE_COUNT_FIELD).unwrap_or_default();
+    let failure_rate =
+        (delivery_count != 0).then(|| f64::from(failure_count) / f64::from(delivery_count));
+
+    Ok(
+        api_models::webhook_endpoints::WebhookEndpointHealthResponse {
+            webhook_endpoint_id: webhook_endpoint.webhook_endpoint_id,
+            profile_id: profile_id.clone(),
+            status: webhook_endpoint.status,
+            consecutive_failures: parse_field(&health, CONSECUTIVE_FAILURES_FIELD)
+                .unwrap_or_default(),
+            delivery_count,
+            failure_count,
+            failure_rate,
+            last_success_at: parse_timestamp_field(&health, LAST_SUCCESS_AT_FIELD),
+            last_failure_at: parse_timestamp_field(&health, LAST_FAILURE_AT_FIELD),
+            queued_events_count,
+        },
+    )
+}
+
+/// Clears the health of an endpoint, so that an endpoint activated again is not paused by the
+/// failures which led to it being paused
+#[cfg(feature = "olap")]
+pub async fn reset_webhook_endpoint_health(
+    state: &SessionState,
+    webhook_endpoint_id: &id_type::WebhookEndpointId,
+) -> RouterResult<()> {
+    let conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    conn.delete_multiple_keys(&[
+        get_redis_key(HEALTH_REDIS_KEY_PREFIX, webhook_endpoint_id)
+            .as_str()
+            .into(),
+        get_redis_key(DELIVERIES_REDIS_KEY_PREFIX, webhook_endpoint_id)
+            .as_str()
+            .into(),
+    ])
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to reset webhook endpoint health")?;
+
+    Ok(())
+}
+
+/// Drops the health and the queued events of a deleted endpoint
+#[cfg(feature = "olap")]
+pub async fn delete_webhook_endpoint_health(
+    state: &SessionState,
+    webhook_endpoint_id: &id_type::WebhookEndpointId,
+) -> RouterResult<()> {
+    reset_webhook_endpoint_health(state, webhook_endpoint_id).await?;
+
+    state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .delete_key(
+            &get_redis_key(QUEUE_REDIS_KEY_PREFIX, webhook_endpoint_id)
+                .as_str()
+                .into(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to delete webhook endpoint queue")?;
+
+    Ok(())
+}
+
+/// Schedules the delivery of the events queued while an endpoint was paused, if any. A flush
+/// scheduled already delivers the events queued since, and a finished one is scheduled again.
+#[cfg(feature = "olap")]
+pub async fn schedule_queued_events_flush(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    profile_id: &id_type::ProfileId,
+    webhook_endpoint_id: &id_type::WebhookEndpointId,
+) -> RouterResult<()> {
+    let queued_events_count = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .get_list_length(
+            &get_redis_key(QUEUE_REDIS_KEY_PREFIX, webhook_endpoint_id)
+                .as_str()
+                .into(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch webhook endpoint queue length")?;
+    if queued_events_count == 0 {
+        return Ok(());
+    }
+
+    let events_per_minute = state.conf.webhooks.paused_endpoint_flush_events_per_minute;
+    fp_utils::when(events_per_minute == 0, || {
+        Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("`paused_endpoint_flush_events_per_minute` must not be zero")
+    })?;
+
+    let flush_id = common_utils::generate_id(consts::ID_LENGTH, "flush");
+    let tracking_data = WebhookEndpointFlushTrackingData {
+        flush_id: flush_id.clone(),
+        merchant_id: merchant_id.clone(),
+        profile_id: profile_id.clone(),
+        webhook_endpoint_id: webhook_endpoint_id.clone(),
+        events_per_minute,
+        succeeded_count: 0,
+        failed_count: 0,
+        failed_attempts: HashMap::new(),
+    };
+    let process_tracker_id = get_flush_process_tracker_id(merchant_id, webhook_endpoint_id);
+
+    let existing_process = state
+        .store
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the webhook endpoint flush task")?;
+
+    match existing_process {
+        Some(process) if process.status != common_enums::ProcessTrackerStatus::Finish => {
+            logger::info!(
+                webhook_endpoint_id = webhook_endpoint_id.get_string_repr(),
+                queued_events_count,
+                "Delivery of events queued for webhook endpoint is scheduled already"
+            );
+            return Ok(());
+        }
+        Some(process) => {
+            let tracking_data = serde_json::to_value(&tracking_data)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to serialize the webhook endpoint flush tracking data")?;
+            state
+                .store
+                .as_scheduler()
+                .update_process(
+                    process,
+                    storage::ProcessTrackerUpdate::Update {
+                        name: None,
+                        retry_count: Some(0),
+                        schedule_time: Some(date_time::now()),
+                        tracking_data: Some(tracking_data),
+                        business_status: Some(String::from(business_status::PENDING)),
+                        status: Some(common_enums::ProcessTrackerStatus::New),
+                        updated_at: Some(date_time::now()),
+                    },
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to reschedule the webhook endpoint flush task")?;
+        }
+        None => {
+            let process_tracker_entry = storage::ProcessTrackerNew::new(
+                process_tracker_id,
+                FLUSH_TASK,
+                storage::ProcessTrackerRunner::OutgoingWebhookEndpointFlushWorkflow,
+                FLUSH_TAGS,
+                tracking_data,
+                None,
+                date_time::now(),
+                common_types::consts::API_VERSION,
+                state.conf.application_source,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to construct the webhook endpoint flush task")?;
+
+            state
+                .store
+                .insert_process(process_tracker_entry)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to add the webhook endpoint flush task")?;
+        }
+    }
+
+    logger::info!(
+        webhook_endpoint_id = webhook_endpoint_id.get_string_repr(),
+        %flush_id,
+        queued_events_count,
+        "Scheduled delivery of events queued for webhook endpoint"
+    );
+
+    Ok(())
+}
+
+/// Delivers the next batch of events queued for an endpoint and records the progress in the
+/// tracking data. Returns whether the flush is complete, which is the case once the queue is
+/// empty or the endpoint is no longer active. Events are removed from the queue only after their
+/// delivery was attempted, and events delivered already are skipped, so that an interrupted flush
+/// neither drops nor duplicates events. Events whose delivery failed are queued again, up to
+/// `MAX_FLUSH_DELIVERY_ATTEMPTS` deliveries.
+#[cfg(feature = "olap")]
+pub async fn flush_next_batch(
+    state: &SessionState,
+    tracking_data: &mut WebhookEndpointFlushTrackingData,
+) -> RouterResult<bool> {
+    let store = state.store.as_ref();
+    let master_key = &store.get_master_key().to_vec().into();
+    let key_store = store
+        .get_merchant_key_store_by_merchant_id(&tracking_data.merchant_id, master_key)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let business_profile = store
+        .find_business_profile_by_merchant_id_profile_id(
+            &key_store,
+            &tracking_data.merchant_id,
+            &tracking_data.profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: tracking_data.profile_id.get_string_repr().to_owned(),
+        })?;
+
+    // Events queued once the endpoint is paused again are flushed when it is activated again
+    let is_active =
+        super::utils::find_webhook_endpoint(&business_profile, &tracking_data.webhook_endpoint_id)
+            .is_some_and(|endpoint| {
+                endpoint.status == common_enums::OutgoingWebhookEndpointStatus::Active
+            });
+    if !is_active {
+        logger::info!(
+            flush_id = %tracking_data.flush_id,
+            "Webhook endpoint is no longer active, stopping delivery of queued events"
+        );
+        return Ok(true);
+    }
+
+    let conn = store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let queue_key = get_redis_key(QUEUE_REDIS_KEY_PREFIX, &tracking_data.webhook_endpoint_id);
+    let initial_attempt_ids = conn
+        .get_list_elements(
+            &queue_key.as_str().into(),
+            0,
+            i64::from(tracking_data.events_per_minute).saturating_sub(1),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch events queued for webhook endpoint")?;
+
+    let mut requeued_attempt_ids = Vec::new();
+    for initial_attempt_id in &initial_attempt_ids {
+        let is_delivered = match store
+            .find_event_by_event_id(initial_attempt_id, &key_store)
+            .await
+        {
+            Ok(event) if event.is_overall_delivery_successful == Some(true) => continue,
+            Ok(event) => match super::webhook_events::redeliver_event(
+                state,
+                &key_store,
+                &tracking_data.merchant_id,
+                business_profile.clone(),
+                event,
+            )
+            .await
+            {
+                Ok(redelivered_event) => redelivered_event.is_webhook_notified,
+                Err(error) => {
+                    logger::error!(?error, %initial_attempt_id, "Failed to deliver queued event");
+                    false
+                }
+            },
+            Err(error) => {
+                logger::error!(?error, %initial_attempt_id, "Failed to find queued event");
+                false
+            }
+        };
+
+        if is_delivered {
+            tracking_data.record_delivered(initial_attempt_id);
+        } else if tracking_data.record_failed_delivery(initial_attempt_id) {
+            requeued_attempt_ids.push(initial_attempt_id.clone());
+        }
+    }
+
+    // Failed events are queued again before the batch is removed, so that they are not lost if
+    // the flush is interrupted in between
+    if !requeued_attempt_ids.is_empty() {
+        conn.append_elements_to_list(&queue_key.as_str().into(), requeued_attempt_ids)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to queue failed events again for webhook endpoint")?;
+    }
+    if !initial_attempt_ids.is_empty() {
+        conn.lpop_list_elements(&queue_key.as_str().into(), Some(initial_attempt_ids.len()))
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to remove attempted events from webhook endpoint queue")?;
+    }
+    let remaining_count = conn
+        .get_list_length(&queue_key.as_str().into())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch webhook endpoint queue length")?;
+
+    logger::info!(
+        flush_id = %tracking_data.flush_id,
+        succeeded_count = tracking_data.succeeded_count,
+        failed_count = tracking_data.failed_count,
+        remaining_count,
+        "Delivered batch of events queued for webhook endpoint"
+    );
+
+    Ok(remaining_count == 0)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    #[cfg(feature = "olap")]
+    use std::borrow::Cow;
+
+    #[cfg(feature = "olap")]
+    use common_utils::id_type::GenerateId;
+
+    use super::*;
+
+    #[cfg(feature = "olap")]
+    fn get_tracking_data() -> WebhookEndpointFlushTrackingData {
+        WebhookEndpointFlushTrackingData {
+            flush_id: "flush_test".to_string(),
+            merchant_id: id_type::MerchantId::try_from(Cow::from("merchant_test")).unwrap(),
+            profile_id: id_type::ProfileId::try_from(Cow::from("profile_test")).unwrap(),
+            webhook_endpoint_id: id_type::WebhookEndpointId::generate(),
+            events_per_minute: 10,
+            succeeded_count: 0,
+            failed_count: 0,
+            failed_attempts: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_parse_health_fields() {
+        let fields = HashMap::from([
+            (CONSECUTIVE_FAILURES_FIELD.to_string(), "3".to_string()),
+            (LAST_SUCCESS_AT_FIELD.to_string(), "1700000000".to_string()),
+            (
+                LAST_FAILURE_AT_FIELD.to_string(),
+                "not a timestamp".to_string(),
+            ),
+        ]);
+
+        assert_eq!(
+            parse_field::<u32>(&fields, CONSECUTIVE_FAILURES_FIELD),
+            Some(3)
+        );
+        assert_eq!(parse_field::<u32>(&fields, DELIVERY_COUNT_FIELD), None);
+        assert_eq!(
+            parse_timestamp_field(&fields, LAST_SUCCESS_AT_FIELD),
+            Some(time::macros::datetime!(2023-11-14 22:13:20))
+        );
+        assert_eq!(parse_timestamp_field(&fields, LAST_FAILURE_AT_FIELD), None);
+    }
+
+    #[cfg(feature = "olap")]
+    #[test]
+    fn test_failed_queued_event_is_queued_again_until_max_attempts() {
+        let mut tracking_data = get_tracking_data();
+
+        for _ in 1..MAX_FLUSH_DELIVERY_ATTEMPTS {
+            assert!(tracking_data.record_failed_delivery("evt_failing"));
+            assert_eq!(tracking_data.failed_count, 0);
+        }
+        assert!(!tracking_data.record_failed_delivery("evt_failing"));
+        assert_eq!(tracking_data.failed_count, 1);
+        assert!(tracking_data.failed_attempts.is_empty());
+    }
+
+    #[cfg(feature = "olap")]
+    #[test]
+    fn test_delivered_queued_event_clears_failed_attempts() {
+        let mut tracking_data = get_tracking_data();
+
+        assert!(tracking_data.record_failed_delivery("evt_retried"));
+        tracking_data.record_delivered("evt_retried");
+
+        assert_eq!(tracking_data.succeeded_count, 1);
+        assert_eq!(tracking_data.failed_count, 0);
+        assert!(tracking_data.failed_attempts.is_empty());
+    }
+
+    #[cfg(feature = "olap")]
+    #[test]
+    fn test_single_flush_task_per_webhook_endpoint() {
+        let merchant_id = id_type::MerchantId::try_from(Cow::from("merchant_test")).unwrap();
+        let webhook_endpoint_id = id_type::WebhookEndpointId::generate();
+        let other_webhook_endpoint_id = id_type::WebhookEndpointId::generate();
+
+        assert_eq!(
+            get_flush_process_tracker_id(&merchant_id, &webhook_endpoint_id),
+            get_flush_process_tracker_id(&merchant_id, &webhook_endpoint_id)
+        );
+        assert_ne!(
+            get_flush_process_tracker_id(&merchant_id, &webhook_endpoint_id),
+            get_flush_process_tracker_id(&merchant_id, &other_webhook_endpoint_id)
+        );
+    }
+
+    #[cfg(feature = "olap")]
+    #[test]
+    fn test_tracking_data_without_failed_attempts_is_parsed() {
+        let tracking_data = serde_json::json!({
+            "flush_id": "flush_test",
+            "merchant_id": "merchant_test",
+            "profile_id": "profile_test",
+            "webhook_endpoint_id": "whe_test",
+            "events_per_minute": 10,
+            "succeeded_count": 2,
+            "failed_count": 1,
+        });
+
+        let tracking_data: WebhookEndpointFlushTrackingData =
+            serde_json::from_value(tracking_data).unwrap();
+
+        assert!(tracking_data.failed_attempts.is_empty());
+    }
+}
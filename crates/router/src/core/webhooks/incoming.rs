@@ -30,7 +30,10 @@ use hyperswitch_interfaces::webhooks::{
 use hyperswitch_masking::{ExposeInterface, PeekInterface, Secret};
 use router_env::{instrument, tracing};
 
-use super::{types, MERCHANT_ID};
+use super::{
+    incoming_webhook_log::{self, IncomingWebhookProcessingDetails},
+    types, MERCHANT_ID,
+};
 use crate::{
     consts,
     core::{
@@ -74,17 +77,50 @@ pub async fn incoming_webhooks_wrapper<W: types::OutgoingWebhookType>(
     body: actix_web::web::Bytes,
     is_relay_webhook: bool,
 ) -> RouterResponse<serde_json::Value> {
-    let (webhook_response, webhooks_response_tracker, serialized_req) =
-        Box::pin(incoming_webhooks_core::<W>(
-            state.clone(),
-            req_state,
-            req,
-            platform.clone(),
-            connector_name_or_mca_id,
-            body.clone(),
-            is_relay_webhook,
-        ))
-        .await?;
+    let request_details = IncomingWebhookRequestDetails {
+        method: req.method().clone(),
+        uri: req.uri().clone(),
+        headers: req.headers(),
+        query_params: req.query_string().to_string(),
+        body: &body,
+    };
+
+    let stored_incoming_webhook = incoming_webhook_log::store_incoming_webhook(
+        &state,
+        platform.get_processor(),
+        connector_name_or_mca_id,
+        is_relay_webhook,
+        &request_details,
+    )
+    .await;
+
+    let mut processing_details = IncomingWebhookProcessingDetails::default();
+    let result = Box::pin(incoming_webhooks_core::<W>(
+        state.clone(),
+        req_state,
+        request_details,
+        platform.clone(),
+        connector_name_or_mca_id,
+        is_relay_webhook,
+        true,
+        &mut processing_details,
+    ))
+    .await;
+
+    if let Err(error) = &result {
+        processing_details.set_failed(error.current_context());
+    }
+    if let Some(stored_incoming_webhook) = stored_incoming_webhook {
+        incoming_webhook_log::update_incoming_webhook_log(
+            &state,
+            platform.get_processor().get_key_store(),
+            &stored_incoming_webhook,
+            processing_details,
+        )
+        .await;
+    }
+
+    let (webhook_response, webhooks_response_tracker, serialized_req) = result?;
 
     logger::info!(incoming_webhook_payload = ?serialized_req.peek());
 
@@ -108,6 +144,82 @@ pub async fn incoming_webhooks_wrapper<W: types::OutgoingWebhookType>(
     })
 }
 
+/// Processes a stored incoming webhook again, as if it was just received from the connector, and
+/// records the new outcome on the stored webhook.
+#[cfg(feature = "olap")]
+#[instrument(skip_all)]
+pub async fn reprocess_incoming_webhook<W: types::OutgoingWebhookType>(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_id: common_utils::id_type::MerchantId,
+    incoming_webhook_id: String,
+) -> RouterResponse<api_models::webhook_events::IncomingWebhookResponse> {
+    let store = state.store.as_ref();
+    let key_store = store
+        .get_merchant_key_store_by_merchant_id(
+            &merchant_id,
+            &store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let merchant_account = store
+        .find_merchant_account_by_merchant_id(&merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let stored_incoming_webhook = store
+        .find_incoming_webhook_log_by_merchant_id_id(&merchant_id, &incoming_webhook_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Incoming webhook not found".to_string(),
+        })?;
+    incoming_webhook_log::validate_reprocessable(&stored_incoming_webhook)?;
+    let stored_request = incoming_webhook_log::get_stored_request(&stored_incoming_webhook)?;
+
+    let platform = domain::Platform::new(
+        merchant_account.clone(),
+        key_store.clone(),
+        merchant_account,
+        key_store.clone(),
+        None,
+    );
+
+    let mut processing_details = IncomingWebhookProcessingDetails::default();
+    let result = Box::pin(incoming_webhooks_core::<W>(
+        state.clone(),
+        req_state,
+        stored_request.get_request_details(),
+        platform,
+        &stored_incoming_webhook.connector_name_or_mca_id,
+        stored_incoming_webhook.is_relay_webhook,
+        // Reprocessing is requested explicitly, so the delivery of the event is not deduplicated
+        false,
+        &mut processing_details,
+    ))
+    .await;
+
+    // The outcome of reprocessing is reported through the stored webhook rather than as an error
+    if let Err(error) = &result {
+        logger::error!(?error, "Failed to reprocess incoming webhook");
+        processing_details.set_failed(error.current_context());
+    }
+
+    let updated_incoming_webhook = store
+        .update_incoming_webhook_log_by_merchant_id_id(
+            &merchant_id,
+            &incoming_webhook_id,
+            processing_details.into(),
+            &key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the incoming webhook log")?;
+
+    Ok(services::ApplicationResponse::Json(
+        api_models::webhook_events::IncomingWebhookResponse::foreign_from(updated_incoming_webhook),
+    ))
+}
+
 #[cfg(feature = "v1")]
 pub async fn network_token_incoming_webhooks_wrapper<W: types::OutgoingWebhookType>(
     state: SessionState,
@@ -167,11 +279,12 @@ fn confirm_path_for_flow(
 async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
     state: SessionState,
     req_state: ReqState,
-    req: &actix_web::HttpRequest,
+    request_details: IncomingWebhookRequestDetails<'_>,
     platform: domain::Platform,
     connector_name_or_mca_id: &str,
-    body: actix_web::web::Bytes,
     is_relay_webhook: bool,
+    deduplicate: bool,
+    processing_details: &mut IncomingWebhookProcessingDetails,
 ) -> errors::RouterResult<(
     WebhookResponse<serde_json::Value>,
     WebhookResponseTracker,
@@ -189,13 +302,6 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
         .with_processor_merchant_id(platform.get_processor().get_processor_merchant_id())
         .with_provider_merchant_id(platform.get_provider().get_provider_merchant_id())
         .with_organization_id(platform.get_processor().get_account().get_org_id().clone());
-    let request_details = IncomingWebhookRequestDetails {
-        method: req.method().clone(),
-        uri: req.uri().clone(),
-        headers: req.headers(),
-        query_params: req.query_string().to_string(),
-        body: &body,
-    };
 
     // Fetch Threeds Execution Path to decode webhook Body
     // Checks if the merchant and connector is eligible for authentication service
@@ -205,6 +311,9 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
     let three_ds_execution_path =
         fetch_three_ds_execution_path(&platform, connector_name_or_mca_id, &state, &dimensions)
             .await?;
+    let (ThreeDsProcessingMode::Direct(ref mca_data)
+    | ThreeDsProcessingMode::UnifiedAuthenticationService(ref mca_data)) = three_ds_execution_path;
+    processing_details.set_merchant_connector_details(mca_data);
 
     // Each arm resolves its path (UAS vs Direct/UCS) and returns a
     // `WebhookOutcome` plus the connector/name used for acks, errors, and
@@ -321,6 +430,7 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
         &outcome,
         Ok(outcome) if outcome.event_type() == webhooks::IncomingWebhookEvent::SetupWebhook
     ) {
+        processing_details.status = Some(enums::IncomingWebhookStatus::Processed);
         return Ok((
             WebhookResponse::StatusOk,
             WebhookResponseTracker::NoEffect,
@@ -335,6 +445,7 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
             ..
         }) => {
             logger::info!(?event_type, "Webhook filtered before business logic");
+            processing_details.status = Some(enums::IncomingWebhookStatus::Skipped);
             metrics::WEBHOOK_INCOMING_FILTERED_COUNT.add(
                 1,
                 router_env::metric_attributes!((
@@ -359,6 +470,9 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
             merchant_connector_account: processed_mca,
             ack_response,
         }) => {
+            processing_details.merchant_connector_id = Some(processed_mca.get_id());
+            processing_details.source_verified = Some(source_verified);
+
             // Reconstruct request_details with the decoded body when available
             // (Direct and UAS paths), so downstream connector methods that parse
             // request_details.body (get_dispute_details, get_webhook_mandate_details,
@@ -380,38 +494,109 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
                 None => &request_details,
             };
 
-            let effect = match Box::pin(process_webhook_business_logic(
-                &state,
-                req_state,
-                &platform,
-                &connector,
-                &connector_name,
-                event_type,
-                source_verified,
-                content,
-                request_for_biz,
-                is_relay_webhook,
-                reference,
-                *processed_mca,
-                *webhook_resource_data,
-            ))
-            .await
-            {
-                Ok(effect) => effect,
-                Err(error) => match handle_incoming_webhook_error(
-                    error,
+            let merchant_id = platform.get_processor().get_account().get_id();
+            let connector_event_id = if deduplicate {
+                connector
+                    .get_webhook_event_id(&request_details)
+                    .map_err(|error| {
+                        logger::warn!(?error, "Failed to fetch the connector event ID of webhook")
+                    })
+                    .ok()
+                    .flatten()
+            } else {
+                None
+            };
+            let is_duplicate_event = match connector_event_id.as_deref() {
+                Some(connector_event_id) => {
+                    !utils::claim_connector_event_id(
+                        &state,
+                        merchant_id,
+                        &connector_name,
+                        connector_event_id,
+                    )
+                    .await
+                }
+                None => false,
+            };
+
+            let business_logic_result = if is_duplicate_event {
+                Ok(WebhookResponseTracker::Rejected {
+                    reason: enums::IncomingWebhookRejectionReason::DuplicateEvent,
+                })
+            } else {
+                Box::pin(process_webhook_business_logic(
+                    &state,
+                    req_state,
+                    &platform,
                     &connector,
-                    connector_name.as_str(),
-                    &request_details,
-                    platform.get_processor().get_account().get_id(),
-                ) {
-                    Ok((_, tracker, _)) => tracker,
-                    Err(e) => return Err(e),
-                },
+                    &connector_name,
+                    event_type,
+                    source_verified,
+                    content,
+                    request_for_biz,
+                    is_relay_webhook,
+                    reference,
+                    *processed_mca,
+                    *webhook_resource_data,
+                ))
+                .await
+            };
+
+            // Deliveries of the event are skipped only once it was applied
+            if !is_duplicate_event && business_logic_result.is_ok() {
+                if let Some(connector_event_id) = connector_event_id.as_deref() {
+                    utils::record_connector_event_id(
+                        &state,
+                        merchant_id,
+                        &connector_name,
+                        connector_event_id,
+                    )
+                    .await;
+                }
+            }
+
+            let effect = match business_logic_result {
+                Ok(WebhookResponseTracker::Rejected { reason }) => {
+                    record_rejected_webhook(
+                        processing_details,
+                        merchant_id,
+                        &connector_name,
+                        reason,
+                    );
+                    WebhookResponseTracker::Rejected { reason }
+                }
+                Ok(effect) => {
+                    processing_details.status = Some(enums::IncomingWebhookStatus::Processed);
+                    effect
+                }
+                Err(error) => {
+                    processing_details.set_failed(error.current_context());
+                    // The event is processed again when the connector retries the delivery
+                    if let Some(connector_event_id) = connector_event_id.as_deref() {
+                        utils::release_connector_event_id(
+                            &state,
+                            merchant_id,
+                            &connector_name,
+                            connector_event_id,
+                        )
+                        .await;
+                    }
+                    match handle_incoming_webhook_error(
+                        error,
+                        &connector,
+                        connector_name.as_str(),
+                        &request_details,
+                        platform.get_processor().get_account().get_id(),
+                    ) {
+                        Ok((_, tracker, _)) => tracker,
+                        Err(e) => return Err(e),
+                    }
+                }
             };
             (ack_response, effect, masked_log_payload)
         }
         Err(error) => {
+            processing_details.set_failed(error.current_context());
             return handle_incoming_webhook_error(
                 error,
                 &connector,
@@ -425,6 +610,25 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
     Ok((ack_response, webhook_effect, serialized_body))
 }
 
+/// Records an incoming webhook that was acknowledged to the connector without being applied.
+fn record_rejected_webhook(
+    processing_details: &mut IncomingWebhookProcessingDetails,
+    merchant_id: &common_utils::id_type::MerchantId,
+    connector_name: &str,
+    reason: enums::IncomingWebhookRejectionReason,
+) {
+    logger::info!(%connector_name, %reason, "Rejected incoming webhook");
+    processing_details.set_rejected(reason);
+    metrics::WEBHOOK_INCOMING_REJECTED_COUNT.add(
+        1,
+        router_env::metric_attributes!(
+            (MERCHANT_ID, merchant_id.clone()),
+            ("connector", connector_name.to_owned()),
+            ("reason", reason.to_string()),
+        ),
+    );
+}
+
 async fn fetch_three_ds_execution_path(
     platform: &domain::Platform,
     connector_name_or_mca_id: &str,
@@ -704,22 +908,8 @@ async fn process_webhook_business_logic(
         }
     };
 
-    match result_response {
-        Ok(response) => Ok(response),
-        Err(error) => {
-            let result = handle_incoming_webhook_error(
-                error,
-                connector,
-                connector_name,
-                request_details,
-                platform.get_processor().get_account().get_id(),
-            );
-            match result {
-                Ok((_, webhook_tracker, _)) => Ok(webhook_tracker),
-                Err(e) => Err(e),
-            }
-        }
-    }
+    // Errors are acknowledged to the connector, if applicable, by the caller
+    result_response
 }
 
 fn handle_incoming_webhook_error(
@@ -891,6 +1081,17 @@ async fn payments_incoming_webhook_flow(
             .await?
         };
 
+    // Events delivered out of order must not move the payment back to an earlier status
+    let is_status_regression = source_verified
+        && enums::AttemptStatus::foreign_try_from(event_type).is_ok_and(|incoming_status| {
+            utils::is_payment_attempt_status_regression(payment_attempt.status, incoming_status)
+        });
+    if is_status_regression {
+        return Ok(WebhookResponseTracker::Rejected {
+            reason: enums::IncomingWebhookRejectionReason::PaymentStatusRegression,
+        });
+    }
+
     let payments_response = match webhook_details.object_reference_id {
         webhooks::ObjectReferenceId::PaymentId(ref id) => {
             let payment_id = payment_attempt.payment_id.clone();
@@ -1264,6 +1465,13 @@ async fn payout_incoming_webhook_update_status(
         .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
         .attach_printable("failed payout status mapping from event type")?;
 
+    // Events delivered out of order must not move the payout back to an earlier status
+    if utils::is_payout_status_regression(payout_attempt.status, status) {
+        return Ok(WebhookResponseTracker::Rejected {
+            reason: enums::IncomingWebhookRejectionReason::PayoutStatusRegression,
+        });
+    }
+
     let payout_webhook_details = connector
         .get_payout_webhook_details(request_details)
         .switch()
@@ -1579,12 +1787,19 @@ async fn refunds_incoming_webhook_flow(
     let refund_id = refund.refund_id.to_owned();
     //if source verified then update refund status else trigger refund sync
     let updated_refund = if source_verified {
+        let refund_status = common_enums::RefundStatus::foreign_try_from(event_type)
+            .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+            .attach_printable("failed refund status mapping from event type")?;
+        // Events delivered out of order must not move the refund back to an earlier status
+        if utils::is_refund_status_regression(refund.refund_status, refund_status) {
+            return Ok(WebhookResponseTracker::Rejected {
+                reason: enums::IncomingWebhookRejectionReason::RefundStatusRegression,
+            });
+        }
         let refund_update = diesel_refund::RefundUpdate::StatusUpdate {
             connector_refund_id: None,
             sent_to_gateway: true,
-            refund_status: common_enums::RefundStatus::foreign_try_from(event_type)
-                .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
-                .attach_printable("failed refund status mapping from event type")?,
+            refund_status,
             updated_by: platform
                 .get_processor()
                 .get_account()
@@ -2471,6 +2686,23 @@ async fn disputes_incoming_webhook_flow(
             .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
             .attach_printable("event type to dispute status mapping failed")?;
 
+        // Events delivered out of order must not move the dispute back to an earlier stage or
+        // status
+        let is_status_regression = option_dispute.as_ref().is_some_and(|dispute| {
+            core_utils::validate_dispute_stage_and_dispute_status(
+                dispute.dispute_stage,
+                dispute.dispute_status,
+                dispute_details.dispute_stage,
+                dispute_status,
+            )
+            .is_err()
+        });
+        if is_status_regression {
+            return Ok(WebhookResponseTracker::Rejected {
+                reason: enums::IncomingWebhookRejectionReason::DisputeStatusRegression,
+            });
+        }
+
         let dispute_object = get_or_update_dispute_object(
             state.clone(),
             option_dispute.clone(),
@@ -0,0 +1,393 @@
+use std::str::FromStr;
+
+use actix_web::http::{
+    header::{HeaderMap, HeaderName, HeaderValue},
+    Method, Uri,
+};
+use api_models::webhook_events::IncomingWebhookRequestContent;
+use base64::Engine;
+use common_utils::{
+    ext_traits::{Encode, StringExt},
+    type_name,
+    types::keymanager::Identifier,
+};
+use error_stack::ResultExt;
+use hyperswitch_domain_models::type_encryption::{crypto_operation, CryptoOperation};
+use hyperswitch_interfaces::webhooks::IncomingWebhookRequestDetails;
+use hyperswitch_masking::{PeekInterface, Secret};
+use router_env::{instrument, tracing};
+
+use super::incoming::MerchantConnectorDetails;
+use crate::{
+    consts,
+    core::errors::{self, RouterResult},
+    logger,
+    routes::SessionState,
+    types::{domain, storage::enums},
+    utils::generate_id,
+};
+
+/// Details learnt while processing an incoming webhook, recorded on the stored webhook once
+/// processing completes.
+#[derive(Debug, Default)]
+pub(super) struct IncomingWebhookProcessingDetails {
+    pub connector: Option<String>,
+    pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+    pub source_verified: Option<bool>,
+    pub status: Option<enums::IncomingWebhookStatus>,
+    pub error_message: Option<String>,
+    pub rejection_reason: Option<enums::IncomingWebhookRejectionReason>,
+}
+
+impl IncomingWebhookProcessingDetails {
+    pub fn set_merchant_connector_details(&mut self, mca_details: &MerchantConnectorDetails) {
+        self.connector = Some(mca_details.connector_name.clone());
+        self.merchant_connector_id = mca_details
+            .merchant_connector_account
+            .as_ref()
+            .map(|merchant_connector_account| merchant_connector_account.get_id());
+    }
+
+    pub fn set_failed(&mut self, error: &errors::ApiErrorResponse) {
+        self.status = Some(enums::IncomingWebhookStatus::Failed);
+        self.error_message = Some(error.to_string());
+    }
+
+    pub fn set_rejected(&mut self, reason: enums::IncomingWebhookRejectionReason) {
+        self.status = Some(enums::IncomingWebhookStatus::Rejected);
+        self.rejection_reason = Some(reason);
+    }
+}
+
+impl From<IncomingWebhookProcessingDetails> for domain::IncomingWebhookLogUpdate {
+    fn from(details: IncomingWebhookProcessingDetails) -> Self {
+        Self::ProcessingUpdate {
+            connector: details.connector,
+            merchant_connector_id: details.merchant_connector_id,
+            source_verified: details.source_verified,
+            // Processing stops before a status is recorded only if it failed without an error
+            status: details
+                .status
+                .unwrap_or(enums::IncomingWebhookStatus::Received),
+            error_message: details.error_message,
+            rejection_reason: details.rejection_reason,
+        }
+    }
+}
+
+/// The request of a stored incoming webhook, from which the request details passed to the
+/// connector are borrowed when the webhook is processed again.
+pub(super) struct StoredIncomingWebhookRequest {
+    method: Method,
+    uri: Uri,
+    headers: HeaderMap,
+    body: Vec<u8>,
+}
+
+impl StoredIncomingWebhookRequest {
+    pub fn get_request_details(&self) -> IncomingWebhookRequestDetails<'_> {
+        IncomingWebhookRequestDetails {
+            method: self.method.clone(),
+            uri: self.uri.clone(),
+            headers: &self.headers,
+            query_params: self.uri.query().unwrap_or_default().to_string(),
+            body: &self.body,
+        }
+    }
+}
+
+impl TryFrom<IncomingWebhookRequestContent> for StoredIncomingWebhookRequest {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+
+    fn try_from(content: IncomingWebhookRequestContent) -> Result<Self, Self::Error> {
+        let method = Method::from_str(&content.method)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse the method of the stored incoming webhook")?;
+        let uri = Uri::from_str(content.uri.peek())
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse the URI of the stored incoming webhook")?;
+
+        let mut headers = HeaderMap::new();
+        for (name, value) in content.headers {
+            let name = HeaderName::from_str(&name)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to parse a header name of the stored incoming webhook")?;
+            let value = consts::BASE64_ENGINE
+                .decode(value.peek())
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to decode a header value of the stored incoming webhook")
+                .and_then(|value| {
+                    HeaderValue::from_bytes(&value)
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable(
+                            "Failed to parse a header value of the stored incoming webhook",
+                        )
+                })?;
+            headers.append(name, value);
+        }
+
+        let body = consts::BASE64_ENGINE
+            .decode(content.body.peek())
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to decode the body of the stored incoming webhook")?;
+
+        Ok(Self {
+            method,
+            uri,
+            headers,
+            body,
+        })
+    }
+}
+
+fn get_request_content(
+    request_details: &IncomingWebhookRequestDetails<'_>,
+) -> IncomingWebhookRequestContent {
+    IncomingWebhookRequestContent {
+        method: request_details.method.to_string(),
+        uri: Secret::new(request_details.uri.to_string()),
+        headers: request_details
+            .headers
+            .iter()
+            .map(|(name, value)| {
+                (
+                    name.to_string(),
+                    Secret::new(consts::BASE64_ENGINE.encode(value.as_bytes())),
+                )
+            })
+            .collect(),
+        body: Secret::new(consts::BASE64_ENGINE.encode(request_details.body)),
+    }
+}
+
+/// Stores an incoming webhook before it is processed. Failures are only logged, so that the
+/// webhook is processed regardless.
+#[instrument(skip_all)]
+pub(super) async fn store_incoming_webhook(
+    state: &SessionState,
+    processor: &domain::Processor,
+    connector_name_or_mca_id: &str,
+    is_relay_webhook: bool,
+    request_details: &IncomingWebhookRequestDetails<'_>,
+) -> Option<domain::IncomingWebhookLog> {
+    insert_incoming_webhook_log(
+        state,
+        processor,
+        connector_name_or_mca_id,
+        is_relay_webhook,
+        request_details,
+    )
+    .await
+    .map_err(|error| logger::error!(?error, "Failed to store the incoming webhook"))
+    .ok()
+}
+
+async fn insert_incoming_webhook_log(
+    state: &SessionState,
+    processor: &domain::Processor,
+    connector_name_or_mca_id: &str,
+    is_relay_webhook: bool,
+    request_details: &IncomingWebhookRequestDetails<'_>,
+) -> RouterResult<domain::IncomingWebhookLog> {
+    let key_store = processor.get_key_store();
+    let encoded = get_request_content(request_details)
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode incoming webhook request content")
+        .map(Secret::new)?;
+
+    let request = crypto_operation(
+        &state.into(),
+        type_name!(domain::IncomingWebhookLog),
+        CryptoOperation::Encrypt(encoded),
+        Identifier::Merchant(key_store.merchant_id.clone()),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encrypt incoming webhook request content")?;
+
+    let now = common_utils::date_time::now();
+    let incoming_webhook_log = domain::IncomingWebhookLog {
+        id: generate_id(consts::ID_LENGTH, "whlog"),
+        merchant_id: processor.get_account().get_id().clone(),
+        connector_name_or_mca_id: connector_name_or_mca_id.to_owned(),
+        connector: None,
+        merchant_connector_id: None,
+        is_relay_webhook,
+        request,
+        source_verified: None,
+        status: enums::IncomingWebhookStatus::Received,
+        error_message: None,
+        created_at: now,
+        modified_at: now,
+        rejection_reason: None,
+    };
+
+    state
+        .store
+        .insert_incoming_webhook_log(incoming_webhook_log, key_store)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert incoming webhook log")
+}
+
+/// Records the outcome of processing a stored incoming webhook. Failures are only logged, since
+/// the webhook has been processed already.
+#[instrument(skip_all)]
+pub(super) async fn update_incoming_webhook_log(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    incoming_webhook_log: &domain::IncomingWebhookLog,
+    processing_details: IncomingWebhookProcessingDetails,
+) -> Option<domain::IncomingWebhookLog> {
+    state
+        .store
+        .update_incoming_webhook_log_by_merchant_id_id(
+            &incoming_webhook_log.merchant_id,
+            &incoming_webhook_log.id,
+            processing_details.into(),
+            key_store,
+        )
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to update the incoming webhook log"))
+        .ok()
+}
+
+/// Only webhooks that were not applied can be processed again, so that an event already applied
+/// to a resource is not applied twice.
+pub(super) fn validate_reprocessable(
+    incoming_webhook_log: &domain::IncomingWebhookLog,
+) -> RouterResult<()> {
+    match incoming_webhook_log.status {
+        enums::IncomingWebhookStatus::Received | enums::IncomingWebhookStatus::Failed => Ok(()),
+        enums::IncomingWebhookStatus::Processed
+        | enums::IncomingWebhookStatus::Skipped
+        | enums::IncomingWebhookStatus::Rejected => {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "Incoming webhook with status `{}` cannot be reprocessed",
+                    incoming_webhook_log.status
+                ),
+            }
+            .into())
+        }
+    }
+}
+
+/// Decrypts the request of a stored incoming webhook.
+pub(super) fn get_stored_request(
+    incoming_webhook_log: &domain::IncomingWebhookLog,
+) -> RouterResult<StoredIncomingWebhookRequest> {
+    incoming_webhook_log
+        .request
+        .get_inner()
+        .peek()
+        .parse_struct::<IncomingWebhookRequestContent>("IncomingWebhookRequestContent")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the request of the stored incoming webhook")?
+        .try_into()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use common_utils::crypto::Encryptable;
+
+    use super::*;
+
+    fn get_incoming_webhook_log(
+        request: IncomingWebhookRequestContent,
+        status: enums::IncomingWebhookStatus,
+    ) -> domain::IncomingWebhookLog {
+        let now = common_utils::date_time::now();
+        domain::IncomingWebhookLog {
+            id: "whlog_test".to_string(),
+            merchant_id: common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from(
+                "merchant_test",
+            ))
+            .unwrap(),
+            connector_name_or_mca_id: "stripe".to_string(),
+            connector: None,
+            merchant_connector_id: None,
+            is_relay_webhook: false,
+            request: Encryptable::new(
+                Secret::new(request.encode_to_string_of_json().unwrap()),
+                Secret::new(Vec::new()),
+            ),
+            source_verified: None,
+            status,
+            error_message: None,
+            created_at: now,
+            modified_at: now,
+            rejection_reason: None,
+        }
+    }
+
+    #[test]
+    fn test_stored_request_keeps_raw_bytes() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            HeaderName::from_static("x-signature"),
+            HeaderValue::from_bytes(&[b'a', 0xe9, b'b']).unwrap(),
+        );
+        headers.append(
+            HeaderName::from_static("x-signature"),
+            HeaderValue::from_static("second"),
+        );
+        let body = [0xff, 0xfe, b'{', b'}', 0x80];
+        let request_details = IncomingWebhookRequestDetails {
+            method: Method::POST,
+            uri: Uri::from_static("/webhooks/merchant_test/stripe?source=test"),
+            headers: &headers,
+            body: &body,
+            query_params: "source=test".to_string(),
+        };
+
+        let incoming_webhook_log = get_incoming_webhook_log(
+            get_request_content(&request_details),
+            enums::IncomingWebhookStatus::Failed,
+        );
+        let stored_request = get_stored_request(&incoming_webhook_log).unwrap();
+        let stored_request_details = stored_request.get_request_details();
+
+        assert_eq!(stored_request_details.method, Method::POST);
+        assert_eq!(stored_request_details.uri, request_details.uri);
+        assert_eq!(stored_request_details.query_params, "source=test");
+        assert_eq!(stored_request_details.headers, &headers);
+        assert_eq!(stored_request_details.body, body.as_slice());
+    }
+
+    #[test]
+    fn test_only_webhooks_not_applied_are_reprocessable() {
+        let request = IncomingWebhookRequestContent {
+            method: Method::POST.to_string(),
+            uri: Secret::new("/webhooks/merchant_test/stripe".to_string()),
+            headers: Vec::new(),
+            body: Secret::new(String::new()),
+        };
+
+        for status in [
+            enums::IncomingWebhookStatus::Received,
+            enums::IncomingWebhookStatus::Failed,
+        ] {
+            let incoming_webhook_log = get_incoming_webhook_log(request.clone(), status);
+            assert!(validate_reprocessable(&incoming_webhook_log).is_ok());
+        }
+
+        for status in [
+            enums::IncomingWebhookStatus::Processed,
+            enums::IncomingWebhookStatus::Skipped,
+            enums::IncomingWebhookStatus::Rejected,
+        ] {
+            let incoming_webhook_log = get_incoming_webhook_log(request.clone(), status);
+            assert!(matches!(
+                validate_reprocessable(&incoming_webhook_log)
+                    .unwrap_err()
+                    .current_context(),
+                errors::ApiErrorResponse::PreconditionFailed { .. }
+            ));
+        }
+    }
+}
@@ -7,22 +7,22 @@ use api_models::{
 use common_enums::SurchargeEventMapper;
 use common_utils::{
     errors::CustomResult,
-    ext_traits::{Encode, StringExt},
+    ext_traits::{AsyncExt, Encode, StringExt},
     request::RequestContent,
-    type_name,
+    standard_webhooks, type_name,
     types::keymanager::Identifier,
 };
 use diesel_models::process_tracker::business_status;
 use error_stack::{report, Report, ResultExt};
 use hyperswitch_domain_models::type_encryption::{crypto_operation, CryptoOperation};
 use hyperswitch_interfaces::{consts, webhooks::WebhookResourceData};
-use hyperswitch_masking::{ExposeInterface, Mask, PeekInterface, Secret};
+use hyperswitch_masking::{ExposeInterface, Mask, Maskable, PeekInterface, Secret};
 use router_env::{
     instrument,
     tracing::{self, Instrument},
 };
 
-use super::{types, utils, MERCHANT_CONNECTOR_ACCOUNT_ID, MERCHANT_ID};
+use super::{endpoint_health, types, utils, MERCHANT_CONNECTOR_ACCOUNT_ID, MERCHANT_ID};
 #[cfg(feature = "stripe")]
 use crate::compatibility::stripe::webhooks as stripe_webhooks;
 use crate::{
@@ -189,26 +189,40 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
 
     for event_data in events_to_trigger {
         let event_type = event_data.event_type;
-        let _ = insert_event_and_spawn_webhook_delivery(
-            state.clone(),
-            &platform,
-            event_data,
-            &webhook_recipient,
-            provider_merchant_id.clone(),
-            processor_merchant_id.clone(),
-            primary_object_id.clone(),
-            primary_object_type,
-            primary_object_created_at,
-            event_class,
-        )
-        .await
-        .inspect_err(|error| {
-            logger::error!(
-                ?error,
-                "Failed to insert event and spawn webhook delivery for event type {}",
-                event_type
+        let webhook_destinations =
+            get_webhook_destinations(&event_data, &webhook_recipient.profile);
+
+        if webhook_destinations.is_empty() {
+            logger::debug!(
+                business_profile_id=?webhook_recipient.profile.get_id(),
+                "merchant webhook URL could not be obtained and no webhook endpoints are \
+                 subscribed to event type {event_type}; skipping outgoing webhooks for event"
             );
-        });
+        }
+
+        for webhook_endpoint in webhook_destinations {
+            let _ = insert_event_and_spawn_webhook_delivery(
+                state.clone(),
+                &platform,
+                event_data.clone(),
+                &webhook_recipient,
+                provider_merchant_id.clone(),
+                processor_merchant_id.clone(),
+                primary_object_id.clone(),
+                primary_object_type,
+                primary_object_created_at,
+                event_class,
+                webhook_endpoint,
+            )
+            .await
+            .inspect_err(|error| {
+                logger::error!(
+                    ?error,
+                    "Failed to insert event and spawn webhook delivery for event type {}",
+                    event_type
+                );
+            });
+        }
     }
 
     Ok(())
@@ -226,26 +240,21 @@ async fn insert_event_and_spawn_webhook_delivery(
     primary_object_type: enums::EventObjectType,
     primary_object_created_at: Option<time::PrimitiveDateTime>,
     event_class: enums::EventClass,
+    webhook_endpoint: Option<diesel_models::business_profile::MultipleWebhookDetail>,
 ) -> CustomResult<(), errors::ApiErrorResponse> {
     let now = common_utils::date_time::now();
     let delivery_attempt = enums::WebhookDeliveryAttempt::InitialAttempt;
-    let idempotent_event_id =
-        utils::get_idempotent_event_id(&primary_object_id, event_data.event_type, delivery_attempt)
-            .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
-            .attach_printable("Failed to generate idempotent event ID")?;
-
-    if let types::WebhookRecipientData::Merchant { .. } = event_data.recipient_data {
-        let webhook_url_result = get_webhook_url_from_business_profile(&webhook_recipient.profile);
-        if webhook_url_result.is_err() || webhook_url_result.as_ref().is_ok_and(String::is_empty) {
-            logger::debug!(
-                business_profile_id=?webhook_recipient.profile.get_id(),
-                %idempotent_event_id,
-                "merchant webhook URL \
-                 could not be obtained; skipping outgoing webhooks for event"
-            );
-            return Ok(());
-        }
-    };
+    let webhook_endpoint_id = webhook_endpoint
+        .as_ref()
+        .map(|endpoint| endpoint.webhook_endpoint_id.clone());
+    let idempotent_event_id = utils::get_idempotent_event_id(
+        &primary_object_id,
+        event_data.event_type,
+        delivery_attempt,
+        webhook_endpoint_id.as_ref(),
+    )
+    .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+    .attach_printable("Failed to generate idempotent event ID")?;
 
     let event_id = utils::generate_event_id();
     let event_type = event_data.event_type;
@@ -265,12 +274,27 @@ async fn insert_event_and_spawn_webhook_delivery(
                 },
             );
 
+    let webhook_endpoint_secrets = webhook_endpoint
+        .as_ref()
+        .async_map(|webhook_endpoint| {
+            utils::decrypt_webhook_endpoint_secrets(
+                &state,
+                &webhook_recipient.key_store,
+                webhook_endpoint,
+            )
+        })
+        .await
+        .transpose()
+        .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+        .attach_printable("Failed to decrypt webhook endpoint secrets")?;
     let request_content = outgoing_webhook
         .map(|outgoing_webhook_data| {
             get_outgoing_webhook_request(
                 &webhook_recipient.merchant_account,
                 outgoing_webhook_data,
                 &webhook_recipient.profile,
+                webhook_endpoint.as_ref(),
+                webhook_endpoint_secrets.as_ref(),
             )
         })
         .transpose()
@@ -327,6 +351,7 @@ async fn insert_event_and_spawn_webhook_delivery(
         processor_merchant_id: Some(processor_merchant_id.clone()),
         initiator_merchant_id: Some(webhook_recipient.key_store.merchant_id.clone()),
         recipient: Some(recipient),
+        webhook_endpoint_id,
     };
 
     let lock_value = utils::perform_redis_lock(
@@ -824,8 +849,47 @@ async fn trigger_webhook_to_merchant(
         .ok_or(errors::WebhooksFlowError::WebhookRequestConstructionFailed)
         .attach_printable("OutgoingWebhookRequestContent not found")?;
 
+    // Retries of an event keep the identifier of its initial attempt
+    let webhook_id = event
+        .initial_attempt_id
+        .clone()
+        .unwrap_or_else(|| event.event_id.clone());
+
+    // Events for a paused endpoint are queued and delivered once the endpoint is activated again
+    if let Some(webhook_endpoint_id) =
+        event
+            .webhook_endpoint_id
+            .as_ref()
+            .filter(|webhook_endpoint_id| {
+                utils::find_webhook_endpoint(&business_profile, webhook_endpoint_id).is_some_and(
+                    |endpoint| {
+                        endpoint.status == common_enums::OutgoingWebhookEndpointStatus::Paused
+                    },
+                )
+            })
+    {
+        endpoint_health::queue_event(&state, webhook_endpoint_id, &webhook_id).await?;
+        if let Some(process_tracker) = process_tracker {
+            state
+                .store
+                .as_scheduler()
+                .finish_process_with_business_status(
+                    process_tracker,
+                    business_status::WEBHOOK_ENDPOINT_PAUSED,
+                )
+                .await
+                .change_context(
+                    errors::WebhooksFlowError::OutgoingWebhookProcessTrackerTaskUpdateFailed,
+                )?;
+        }
+        return Ok((
+            event,
+            Some(report!(errors::WebhooksFlowError::WebhookEndpointPaused)),
+        ));
+    }
+
     let webhook_url = match (
-        get_webhook_url_from_business_profile(&business_profile),
+        get_webhook_url_for_event(&business_profile, event.webhook_endpoint_id.as_ref()),
         process_tracker.clone(),
     ) {
         (Ok(webhook_url), _) => Ok(webhook_url),
@@ -849,21 +913,43 @@ async fn trigger_webhook_to_merchant(
         (Err(error), None) => Err(error),
     }?;
 
-    let event_id = event.event_id;
-
-    let headers = request_content
+    let body = request_content.body.expose();
+    let mut headers: Vec<(String, Maskable<String>)> = request_content
         .headers
         .into_iter()
         .map(|(name, value)| (name, value.into_masked()))
         .collect();
+    let webhook_endpoint = event
+        .webhook_endpoint_id
+        .as_ref()
+        .and_then(|webhook_endpoint_id| {
+            utils::find_webhook_endpoint(&business_profile, webhook_endpoint_id)
+        });
+    let webhook_endpoint_secrets = webhook_endpoint
+        .async_map(|webhook_endpoint| {
+            utils::decrypt_webhook_endpoint_secrets(&state, merchant_key_store, webhook_endpoint)
+        })
+        .await
+        .transpose()
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
+        .attach_printable("Failed to decrypt webhook endpoint secrets")?;
+    headers.extend(get_standard_webhooks_headers(
+        webhook_endpoint,
+        webhook_endpoint_secrets.as_ref(),
+        &webhook_id,
+        body.as_bytes(),
+    )?);
+
+    let webhook_endpoint_id = event.webhook_endpoint_id;
+    let event_id = event.event_id;
+    let cloned_state = state.clone();
+
     let request = services::RequestBuilder::new()
         .method(services::Method::Post)
         .url(&webhook_url)
         .attach_default_headers()
         .headers(headers)
-        .set_body(RequestContent::RawBytes(
-            request_content.body.expose().into_bytes(),
-        ))
+        .set_body(RequestContent::RawBytes(body.into_bytes()))
         .build();
 
     let response = state
@@ -877,7 +963,7 @@ async fn trigger_webhook_to_merchant(
     );
     logger::debug!(outgoing_webhook_response=?response);
 
-    match response {
+    let delivery_result = match response {
         Ok(response) => {
             delivery_attempt
                 .handle_success_response(
@@ -904,7 +990,23 @@ async fn trigger_webhook_to_merchant(
                 )
                 .await
         }
+    };
+
+    if let Some(webhook_endpoint_id) = webhook_endpoint_id {
+        let is_delivered = delivery_result
+            .as_ref()
+            .is_ok_and(|(updated_event, _)| updated_event.is_webhook_notified);
+        endpoint_health::record_delivery(
+            &cloned_state,
+            merchant_key_store,
+            &business_profile,
+            &webhook_endpoint_id,
+            is_delivered,
+        )
+        .await;
     }
+
+    delivery_result
 }
 
 async fn raise_webhooks_analytics_event(
@@ -1060,15 +1162,75 @@ fn get_webhook_url_from_business_profile(
         .map(ExposeInterface::expose)
 }
 
+/// Resolves the URL an event is delivered to, which is the URL of the webhook endpoint for events
+/// delivered to an endpoint and the webhook URL of the business profile otherwise
+fn get_webhook_url_for_event(
+    business_profile: &domain::Profile,
+    webhook_endpoint_id: Option<&common_utils::id_type::WebhookEndpointId>,
+) -> CustomResult<String, errors::WebhooksFlowError> {
+    match webhook_endpoint_id {
+        Some(webhook_endpoint_id) => {
+            utils::find_webhook_endpoint(business_profile, webhook_endpoint_id)
+                .filter(|endpoint| {
+                    endpoint.status == common_enums::OutgoingWebhookEndpointStatus::Active
+                })
+                .map(|endpoint| endpoint.webhook_url.clone().expose())
+                .ok_or(errors::WebhooksFlowError::MerchantWebhookUrlNotConfigured)
+                .attach_printable_lazy(|| {
+                    format!(
+                        "Webhook endpoint {} was removed or is no longer active",
+                        webhook_endpoint_id.get_string_repr()
+                    )
+                })
+        }
+        None => get_webhook_url_from_business_profile(business_profile),
+    }
+}
+
+/// Lists the destinations an event is delivered to. Events for merchants are delivered to the
+/// webhook URL of the business profile, if configured, and to every active or paused webhook
+/// endpoint subscribed to the event type.
+fn get_webhook_destinations(
+    event_data: &types::WebhookPayload,
+    business_profile: &domain::Profile,
+) -> Vec<Option<diesel_models::business_profile::MultipleWebhookDetail>> {
+    match event_data.recipient_data {
+        types::WebhookRecipientData::Connector { .. } => vec![None],
+        types::WebhookRecipientData::Merchant { .. } => {
+            let is_profile_webhook_url_configured =
+                get_webhook_url_from_business_profile(business_profile)
+                    .is_ok_and(|webhook_url| !webhook_url.is_empty());
+
+            is_profile_webhook_url_configured
+                .then_some(None)
+                .into_iter()
+                .chain(
+                    utils::get_subscribed_webhook_endpoints(
+                        business_profile,
+                        event_data.event_type,
+                    )
+                    .into_iter()
+                    .cloned()
+                    .map(Some),
+                )
+                .collect()
+        }
+    }
+}
+
 pub(crate) fn get_outgoing_webhook_request(
     webhook_recipient_account: &domain::MerchantAccount,
     outgoing_webhook: api::OutgoingWebhook,
     business_profile: &domain::Profile,
+    webhook_endpoint: Option<&diesel_models::business_profile::MultipleWebhookDetail>,
+    webhook_endpoint_secrets: Option<&utils::WebhookEndpointSecrets>,
 ) -> CustomResult<OutgoingWebhookRequestContent, errors::WebhooksFlowError> {
     #[inline]
     fn get_outgoing_webhook_request_inner<WebhookType: types::OutgoingWebhookType>(
         outgoing_webhook: api::OutgoingWebhook,
         business_profile: &domain::Profile,
+        webhook_endpoint: Option<&diesel_models::business_profile::MultipleWebhookDetail>,
+        webhook_endpoint_secrets: Option<&utils::WebhookEndpointSecrets>,
     ) -> CustomResult<OutgoingWebhookRequestContent, errors::WebhooksFlowError> {
         let mut headers = vec![
             (
@@ -1082,7 +1244,11 @@ pub(crate) fn get_outgoing_webhook_request(
         ];
 
         let transformed_outgoing_webhook = WebhookType::from(outgoing_webhook);
-        let payment_response_hash_key = business_profile.payment_response_hash_key.clone();
+        // Webhooks delivered to an endpoint are signed with the secret of the endpoint
+        let payment_response_hash_key = webhook_endpoint_secrets
+            .and_then(|secrets| secrets.secret.clone())
+            .map(ExposeInterface::expose)
+            .or_else(|| business_profile.payment_response_hash_key.clone());
         let custom_headers = business_profile
             .outgoing_webhook_custom_http_headers
             .clone()
@@ -1104,7 +1270,11 @@ pub(crate) fn get_outgoing_webhook_request(
         let outgoing_webhooks_signature = transformed_outgoing_webhook
             .get_outgoing_webhooks_signature(payment_response_hash_key)?;
 
-        if let Some(signature) = outgoing_webhooks_signature.signature {
+        // Endpoints signed with `standard_webhooks` are signed when the webhook is sent instead
+        if let Some(signature) = outgoing_webhooks_signature
+            .signature
+            .filter(|_| !is_signed_with_standard_webhooks(webhook_endpoint))
+        {
             WebhookType::add_webhook_header(&mut headers, signature)
         }
 
@@ -1119,16 +1289,78 @@ pub(crate) fn get_outgoing_webhook_request(
 
     match webhook_recipient_account.get_compatible_connector() {
         #[cfg(feature = "stripe")]
-        Some(api_models::enums::Connector::Stripe) => get_outgoing_webhook_request_inner::<
-            stripe_webhooks::StripeOutgoingWebhook,
-        >(outgoing_webhook, business_profile),
+        Some(api_models::enums::Connector::Stripe) => {
+            get_outgoing_webhook_request_inner::<stripe_webhooks::StripeOutgoingWebhook>(
+                outgoing_webhook,
+                business_profile,
+                webhook_endpoint,
+                webhook_endpoint_secrets,
+            )
+        }
         _ => get_outgoing_webhook_request_inner::<webhooks::OutgoingWebhook>(
             outgoing_webhook,
             business_profile,
+            webhook_endpoint,
+            webhook_endpoint_secrets,
         ),
     }
 }
 
+fn is_signed_with_standard_webhooks(
+    webhook_endpoint: Option<&diesel_models::business_profile::MultipleWebhookDetail>,
+) -> bool {
+    webhook_endpoint.is_some_and(|endpoint| {
+        endpoint.signing_version
+            == Some(common_enums::OutgoingWebhookSigningVersion::StandardWebhooks)
+    })
+}
+
+/// Signs a webhook delivered to an endpoint signed with `standard_webhooks`, with the current
+/// secret of the endpoint and the secrets replaced by a rotation which have not expired yet. The
+/// headers are computed for every delivery attempt rather than stored with the event, since
+/// receivers reject webhooks whose timestamp is too old.
+fn get_standard_webhooks_headers(
+    webhook_endpoint: Option<&diesel_models::business_profile::MultipleWebhookDetail>,
+    webhook_endpoint_secrets: Option<&utils::WebhookEndpointSecrets>,
+    webhook_id: &str,
+    body: &[u8],
+) -> CustomResult<Vec<(String, Maskable<String>)>, errors::WebhooksFlowError> {
+    let Some(webhook_endpoint_secrets) =
+        webhook_endpoint_secrets.filter(|_| is_signed_with_standard_webhooks(webhook_endpoint))
+    else {
+        return Ok(Vec::new());
+    };
+
+    let secrets = webhook_endpoint_secrets
+        .secret
+        .iter()
+        .chain(webhook_endpoint_secrets.previous_secrets.iter())
+        .map(|secret| standard_webhooks::WebhookSecret::from_encoded(secret.peek()))
+        .collect::<Result<Vec<_>, _>>()
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
+        .attach_printable("Invalid secret configured for webhook endpoint")?;
+
+    let timestamp = common_utils::date_time::now_unix_timestamp();
+    let signature = standard_webhooks::sign(&secrets, webhook_id, timestamp, body)
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
+        .attach_printable("Failed to sign outgoing webhook")?;
+
+    Ok(vec![
+        (
+            standard_webhooks::WEBHOOK_ID_HEADER.to_string(),
+            webhook_id.to_string().into(),
+        ),
+        (
+            standard_webhooks::WEBHOOK_TIMESTAMP_HEADER.to_string(),
+            timestamp.to_string().into(),
+        ),
+        (
+            standard_webhooks::WEBHOOK_SIGNATURE_HEADER.to_string(),
+            signature.into_masked(),
+        ),
+    ])
+}
+
 #[derive(Debug)]
 enum ScheduleWebhookRetry {
     WithProcessTracker(Box<storage::ProcessTracker>),
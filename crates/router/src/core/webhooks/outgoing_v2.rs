@@ -47,7 +47,7 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
 ) -> CustomResult<(), errors::ApiErrorResponse> {
     let delivery_attempt = enums::WebhookDeliveryAttempt::InitialAttempt;
     let idempotent_event_id =
-        utils::get_idempotent_event_id(&primary_object_id, event_type, delivery_attempt)
+        utils::get_idempotent_event_id(&primary_object_id, event_type, delivery_attempt, None)
             .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
             .attach_printable("Failed to generate idempotent event ID")?;
     let webhook_url_result = webhook_recipient
@@ -125,6 +125,7 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
         processor_merchant_id: Some(processor_merchant_id.clone()),
         initiator_merchant_id: Some(webhook_recipient.key_store.merchant_id.clone()),
         recipient: None,
+        webhook_endpoint_id: None,
     };
 
     let event_insert_result = state
@@ -236,6 +236,7 @@ pub(crate) struct InitialAttempt;
 pub(crate) struct AutomaticRetry;
 pub(crate) struct ManualRetry;
 
+#[derive(Clone)]
 pub(crate) struct WebhookPayload {
     pub event_type: enums::EventType,
     pub event_content: Option<OutgoingWebhookContent>,
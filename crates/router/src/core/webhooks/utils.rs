@@ -3,17 +3,21 @@ use std::marker::PhantomData;
 use base64::Engine;
 use common_utils::{
     consts,
-    crypto::{self, GenerateDigest},
-    errors::CustomResult,
+    crypto::{self, Encryptable, GenerateDigest},
+    encryption::Encryption,
+    errors::{CryptoError, CustomResult},
     ext_traits::ValueExt,
-    fp_utils,
+    fp_utils, type_name,
+    types::keymanager::Identifier,
 };
 use error_stack::{Report, ResultExt};
 use hyperswitch_domain_models::{
     router_request_types::VerifyWebhookSourceRequestData,
     router_response_types::{VerifyWebhookSourceResponseData, VerifyWebhookStatus},
+    type_encryption::{crypto_operation, CryptoOperation},
 };
 use hyperswitch_interfaces::webhooks::IncomingWebhook;
+use hyperswitch_masking::{PeekInterface, Secret};
 use redis_interface as redis;
 use router_env::tracing;
 
@@ -222,12 +226,21 @@ pub(crate) fn get_idempotent_event_id(
     primary_object_id: &str,
     event_type: types::storage::enums::EventType,
     delivery_attempt: types::storage::enums::WebhookDeliveryAttempt,
+    webhook_endpoint_id: Option<&common_utils::id_type::WebhookEndpointId>,
 ) -> Result<String, Report<errors::WebhooksFlowError>> {
     use crate::types::storage::enums::WebhookDeliveryAttempt;
 
     const EVENT_ID_SUFFIX_LENGTH: usize = 8;
 
-    let common_prefix = format!("{primary_object_id}_{event_type}");
+    // Events delivered to a webhook endpoint are distinguished from those delivered to the
+    // webhook URL of the business profile
+    let common_prefix = match webhook_endpoint_id {
+        Some(webhook_endpoint_id) => format!(
+            "{primary_object_id}_{event_type}_{}",
+            webhook_endpoint_id.get_string_repr()
+        ),
+        None => format!("{primary_object_id}_{event_type}"),
+    };
 
     // Hash the common prefix with SHA256 and encode with URL-safe base64 without padding
     let digest = crypto::Sha256
@@ -311,6 +324,169 @@ pub fn is_outgoing_webhook_disabled(
     false
 }
 
+/// Finds the endpoint of the business profile that an event should be delivered to
+pub fn find_webhook_endpoint<'a>(
+    business_profile: &'a domain::Profile,
+    webhook_endpoint_id: &common_utils::id_type::WebhookEndpointId,
+) -> Option<&'a diesel_models::business_profile::MultipleWebhookDetail> {
+    business_profile
+        .webhook_details
+        .as_ref()
+        .and_then(|webhook_details| webhook_details.multiple_webhooks_list.as_ref())
+        .and_then(|webhook_endpoints| {
+            webhook_endpoints
+                .iter()
+                .find(|endpoint| &endpoint.webhook_endpoint_id == webhook_endpoint_id)
+        })
+}
+
+const WEBHOOK_ENDPOINTS_LOCK_PREFIX: &str = "WEBHOOK_ENDPOINTS_LOCK";
+
+/// Webhook endpoints are stored in the business profile, so the endpoints of a profile are changed
+/// while holding a lock on the profile, to not lose changes made concurrently.
+pub(crate) async fn with_webhook_endpoints_lock<T>(
+    state: &SessionState,
+    profile_id: &common_utils::id_type::ProfileId,
+    update: impl std::future::Future<Output = RouterResult<T>>,
+) -> RouterResult<T> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    let lock_key = format!(
+        "{WEBHOOK_ENDPOINTS_LOCK_PREFIX}_{}",
+        profile_id.get_string_repr()
+    );
+    let lock_settings = &state.conf.lock_settings;
+
+    let mut is_lock_acquired = false;
+    for _ in 0..lock_settings.lock_retries.max(1) {
+        is_lock_acquired = redis_conn
+            .set_key_if_not_exists_with_expiry(
+                &lock_key.as_str().into(),
+                "",
+                Some(i64::from(lock_settings.redis_lock_expiry_seconds)),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to acquire webhook endpoints lock")?
+            == redis_interface::SetnxReply::KeySet;
+        if is_lock_acquired {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(u64::from(
+            lock_settings.delay_between_retries_in_milliseconds,
+        )))
+        .await;
+    }
+    fp_utils::when(!is_lock_acquired, || {
+        Err(error_stack::report!(errors::ApiErrorResponse::ResourceBusy))
+            .attach_printable("Webhook endpoints of the business profile are being updated")
+    })?;
+
+    let result = update.await;
+
+    redis_conn
+        .delete_key(&lock_key.as_str().into())
+        .await
+        .inspect_err(|error| logger::error!(?error, "Failed to release webhook endpoints lock"))
+        .ok();
+
+    result
+}
+
+/// Decrypted secrets a webhook delivered to an endpoint is signed with
+#[derive(Clone, Debug, Default)]
+pub struct WebhookEndpointSecrets {
+    pub secret: Option<Secret<String>>,
+    /// Secrets replaced by a rotation which have not expired yet
+    pub previous_secrets: Vec<Secret<String>>,
+}
+
+pub async fn encrypt_webhook_endpoint_secret(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    secret: Secret<String>,
+) -> CustomResult<Encryption, CryptoError> {
+    crypto_operation(
+        &state.into(),
+        type_name!(diesel_models::business_profile::MultipleWebhookDetail),
+        CryptoOperation::Encrypt(secret),
+        Identifier::Merchant(key_store.merchant_id.clone()),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .map(Encryption::from)
+}
+
+async fn decrypt_webhook_endpoint_secret(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    secret: Encryption,
+) -> CustomResult<Secret<String>, CryptoError> {
+    crypto_operation(
+        &state.into(),
+        type_name!(diesel_models::business_profile::MultipleWebhookDetail),
+        CryptoOperation::Decrypt(secret),
+        Identifier::Merchant(key_store.merchant_id.clone()),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .map(|secret: Encryptable<Secret<String>>| secret.into_inner())
+}
+
+pub async fn decrypt_webhook_endpoint_secrets(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    webhook_endpoint: &diesel_models::business_profile::MultipleWebhookDetail,
+) -> CustomResult<WebhookEndpointSecrets, CryptoError> {
+    let secret = match webhook_endpoint.secret.clone() {
+        Some(secret) => Some(decrypt_webhook_endpoint_secret(state, key_store, secret).await?),
+        None => None,
+    };
+    let mut previous_secrets = Vec::new();
+    for previous_secret in
+        webhook_endpoint.get_active_previous_secrets(common_utils::date_time::now())
+    {
+        previous_secrets.push(
+            decrypt_webhook_endpoint_secret(state, key_store, previous_secret.secret.clone())
+                .await?,
+        );
+    }
+
+    Ok(WebhookEndpointSecrets {
+        secret,
+        previous_secrets,
+    })
+}
+
+/// Lists the endpoints of the business profile which are subscribed to the event type. Paused
+/// endpoints are included, since events for them are queued until they are activated again.
+pub fn get_subscribed_webhook_endpoints(
+    business_profile: &domain::Profile,
+    event_type: common_enums::EventType,
+) -> Vec<&diesel_models::business_profile::MultipleWebhookDetail> {
+    business_profile
+        .webhook_details
+        .as_ref()
+        .and_then(|webhook_details| webhook_details.multiple_webhooks_list.as_ref())
+        .map(|webhook_endpoints| {
+            webhook_endpoints
+                .iter()
+                .filter(|endpoint| {
+                    matches!(
+                        endpoint.status,
+                        common_enums::OutgoingWebhookEndpointStatus::Active
+                            | common_enums::OutgoingWebhookEndpointStatus::Paused
+                    ) && endpoint.events.contains(&event_type)
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
 /// Context resolved for outgoing webhook delivery, containing the recipient's
 /// merchant account, keystore, and business profile.
 pub(crate) struct WebhookRecipientContext {
@@ -556,3 +732,362 @@ where
             .attach_printable("Error while deleting redis key"),
     }
 }
+
+const WEBHOOK_EVENT_ID_PREFIX: &str = "WEBHOOK_EVENT_ID";
+const CONNECTOR_EVENT_IN_PROGRESS: &str = "in_progress";
+const CONNECTOR_EVENT_APPLIED: &str = "applied";
+
+fn get_connector_event_id_key(
+    merchant_id: &common_utils::id_type::MerchantId,
+    connector_name: &str,
+    connector_event_id: &str,
+) -> String {
+    format!(
+        "{}_{}_{}_{}",
+        WEBHOOK_EVENT_ID_PREFIX,
+        merchant_id.get_string_repr(),
+        connector_name,
+        connector_event_id
+    )
+}
+
+/// Marks a connector event as being processed, returning `false` if the same event is being
+/// processed or was applied within the deduplication window. The marker expires with the webhook
+/// lock, so that an event whose processing was interrupted is processed when delivered again.
+/// Redis failures are only logged, so that the webhook is processed regardless.
+pub(super) async fn claim_connector_event_id(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    connector_name: &str,
+    connector_event_id: &str,
+) -> bool {
+    let dedup_window_in_seconds = state.conf.webhooks.incoming_dedup_window_in_seconds;
+    if dedup_window_in_seconds == 0 {
+        return true;
+    }
+    let in_progress_expiry_in_seconds = state
+        .conf
+        .webhooks
+        .redis_lock_expiry_seconds
+        .min(dedup_window_in_seconds);
+
+    let key = get_connector_event_id_key(merchant_id, connector_name, connector_event_id);
+    let result = match state.store.get_redis_conn() {
+        Ok(redis_conn) => {
+            redis_conn
+                .set_key_if_not_exists_with_expiry(
+                    &key.as_str().into(),
+                    CONNECTOR_EVENT_IN_PROGRESS,
+                    Some(i64::from(in_progress_expiry_in_seconds)),
+                )
+                .await
+        }
+        Err(error) => Err(error),
+    };
+
+    match result {
+        Ok(redis::SetnxReply::KeySet) => true,
+        Ok(redis::SetnxReply::KeyNotSet) => {
+            logger::info!(%connector_event_id, "Duplicate delivery of connector event");
+            false
+        }
+        Err(error) => {
+            logger::error!(?error, "Failed to record the connector event ID");
+            true
+        }
+    }
+}
+
+/// Records a connector event as applied, so that deliveries of the same event are skipped for the
+/// rest of the deduplication window. Redis failures are only logged, since the webhook has been
+/// processed already.
+pub(super) async fn record_connector_event_id(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    connector_name: &str,
+    connector_event_id: &str,
+) {
+    let dedup_window_in_seconds = state.conf.webhooks.incoming_dedup_window_in_seconds;
+    if dedup_window_in_seconds == 0 {
+        return;
+    }
+
+    let key = get_connector_event_id_key(merchant_id, connector_name, connector_event_id);
+    let result = match state.store.get_redis_conn() {
+        Ok(redis_conn) => {
+            redis_conn
+                .set_key_with_expiry(
+                    &key.as_str().into(),
+                    CONNECTOR_EVENT_APPLIED,
+                    i64::from(dedup_window_in_seconds),
+                )
+                .await
+        }
+        Err(error) => Err(error),
+    };
+
+    result
+        .map_err(|error| logger::error!(?error, "Failed to record the connector event ID"))
+        .ok();
+}
+
+/// Forgets the delivery of a connector event that could not be processed, so that the event is
+/// processed when the connector delivers it again.
+pub(super) async fn release_connector_event_id(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    connector_name: &str,
+    connector_event_id: &str,
+) {
+    let key = get_connector_event_id_key(merchant_id, connector_name, connector_event_id);
+    let result = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn
+            .delete_key(&key.as_str().into())
+            .await
+            .map(|_| ()),
+        Err(error) => Err(error),
+    };
+
+    result
+        .map_err(|error| logger::error!(?error, "Failed to release the connector event ID"))
+        .ok();
+}
+
+/// The rank from which payment attempt statuses are final.
+const PAYMENT_ATTEMPT_FINAL_STATUS_RANK: u8 = 3;
+
+/// Returns whether applying a webhook would move a payment attempt back to an earlier status,
+/// for instance when a stale `processing` event arrives after the payment succeeded, or replace
+/// a final status by a different one of the same rank, for instance a failure by a success.
+pub(super) fn is_payment_attempt_status_regression(
+    current_status: common_enums::AttemptStatus,
+    incoming_status: common_enums::AttemptStatus,
+) -> bool {
+    is_status_regression(
+        get_payment_attempt_status_rank(current_status),
+        get_payment_attempt_status_rank(incoming_status),
+        PAYMENT_ATTEMPT_FINAL_STATUS_RANK,
+    ) && current_status != incoming_status
+}
+
+/// Compares the ranks of the current and incoming status, given the rank from which statuses are
+/// final. The caller is responsible for allowing an incoming status equal to the current one.
+fn is_status_regression(current_rank: u8, incoming_rank: u8, final_status_rank: u8) -> bool {
+    incoming_rank < current_rank
+        || (incoming_rank == current_rank && current_rank >= final_status_rank)
+}
+
+fn get_payment_attempt_status_rank(status: common_enums::AttemptStatus) -> u8 {
+    use common_enums::AttemptStatus;
+
+    match status {
+        AttemptStatus::Started
+        | AttemptStatus::AuthenticationPending
+        | AttemptStatus::AuthenticationSuccessful
+        | AttemptStatus::AuthenticationFailed
+        | AttemptStatus::DeviceDataCollectionPending
+        | AttemptStatus::PaymentMethodAwaited
+        | AttemptStatus::ConfirmationAwaited
+        | AttemptStatus::Authorizing
+        | AttemptStatus::CodInitiated
+        | AttemptStatus::Pending
+        | AttemptStatus::Unresolved
+        | AttemptStatus::IntegrityFailure => 0,
+        // The authorization stands, whether or not a capture or void of it went through
+        AttemptStatus::Authorized
+        | AttemptStatus::PartiallyAuthorized
+        | AttemptStatus::CaptureInitiated
+        | AttemptStatus::CaptureReview
+        | AttemptStatus::CaptureFailed
+        | AttemptStatus::VoidInitiated
+        | AttemptStatus::VoidFailed => 1,
+        // The remaining amount can still be captured
+        AttemptStatus::PartialChargedAndChargeable => 2,
+        AttemptStatus::Charged
+        | AttemptStatus::PartialCharged
+        | AttemptStatus::Voided
+        | AttemptStatus::AuthorizationFailed
+        | AttemptStatus::Failure
+        | AttemptStatus::RouterDeclined
+        | AttemptStatus::Expired => PAYMENT_ATTEMPT_FINAL_STATUS_RANK,
+        // The charge was reversed after it went through
+        AttemptStatus::VoidedPostCharge | AttemptStatus::AutoRefunded => 4,
+    }
+}
+
+/// Returns whether applying a webhook would move a refund back to an earlier status. A refund
+/// can still fail after it succeeded, for instance when the bank of the customer returns it, but
+/// a failed refund never succeeds later.
+pub(super) fn is_refund_status_regression(
+    current_status: common_enums::RefundStatus,
+    incoming_status: common_enums::RefundStatus,
+) -> bool {
+    get_refund_status_rank(incoming_status) < get_refund_status_rank(current_status)
+}
+
+fn get_refund_status_rank(status: common_enums::RefundStatus) -> u8 {
+    use common_enums::RefundStatus;
+
+    match status {
+        // A refund that failed to reach the connector may still have been processed by it
+        RefundStatus::Pending | RefundStatus::ManualReview | RefundStatus::TransactionFailure => 0,
+        RefundStatus::Success => 1,
+        RefundStatus::Failure => 2,
+    }
+}
+
+/// The rank from which payout statuses are final.
+#[cfg(feature = "payouts")]
+const PAYOUT_FINAL_STATUS_RANK: u8 = 3;
+
+/// Returns whether applying a webhook would move a payout back to an earlier status, or replace
+/// a final status by a different one of the same rank.
+#[cfg(feature = "payouts")]
+pub(super) fn is_payout_status_regression(
+    current_status: common_enums::PayoutStatus,
+    incoming_status: common_enums::PayoutStatus,
+) -> bool {
+    is_status_regression(
+        get_payout_status_rank(current_status),
+        get_payout_status_rank(incoming_status),
+        PAYOUT_FINAL_STATUS_RANK,
+    ) && current_status != incoming_status
+}
+
+#[cfg(feature = "payouts")]
+fn get_payout_status_rank(status: common_enums::PayoutStatus) -> u8 {
+    use common_enums::PayoutStatus;
+
+    match status {
+        PayoutStatus::RequiresCreation
+        | PayoutStatus::RequiresConfirmation
+        | PayoutStatus::RequiresPayoutMethodData
+        | PayoutStatus::RequiresFulfillment
+        | PayoutStatus::RequiresVendorAccountCreation => 0,
+        PayoutStatus::Initiated => 1,
+        PayoutStatus::Pending => 2,
+        PayoutStatus::Success
+        | PayoutStatus::Failed
+        | PayoutStatus::Cancelled
+        | PayoutStatus::Expired
+        | PayoutStatus::Ineligible
+        | PayoutStatus::NotPermitted => PAYOUT_FINAL_STATUS_RANK,
+        PayoutStatus::Reversed => 4,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_enums::{AttemptStatus, RefundStatus};
+
+    use super::*;
+
+    #[test]
+    fn test_payment_attempt_status_moves_forward() {
+        let transitions = [
+            (AttemptStatus::Pending, AttemptStatus::Authorized),
+            (AttemptStatus::Pending, AttemptStatus::Failure),
+            (AttemptStatus::Authorized, AttemptStatus::Charged),
+            (
+                AttemptStatus::PartialChargedAndChargeable,
+                AttemptStatus::Charged,
+            ),
+            (AttemptStatus::Charged, AttemptStatus::Charged),
+            (AttemptStatus::Charged, AttemptStatus::VoidedPostCharge),
+            (AttemptStatus::Authorized, AttemptStatus::CaptureInitiated),
+        ];
+
+        for (current_status, incoming_status) in transitions {
+            assert!(
+                !is_payment_attempt_status_regression(current_status, incoming_status),
+                "{current_status} -> {incoming_status}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_payment_attempt_status_regression() {
+        let transitions = [
+            (AttemptStatus::Charged, AttemptStatus::Pending),
+            (AttemptStatus::Charged, AttemptStatus::Authorized),
+            (
+                AttemptStatus::Charged,
+                AttemptStatus::PartialChargedAndChargeable,
+            ),
+            (AttemptStatus::Failure, AttemptStatus::Authorizing),
+            (AttemptStatus::VoidedPostCharge, AttemptStatus::Charged),
+        ];
+
+        for (current_status, incoming_status) in transitions {
+            assert!(
+                is_payment_attempt_status_regression(current_status, incoming_status),
+                "{current_status} -> {incoming_status}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_payment_attempt_final_status_is_not_replaced() {
+        let transitions = [
+            (AttemptStatus::Charged, AttemptStatus::Failure),
+            (AttemptStatus::Failure, AttemptStatus::Charged),
+            (AttemptStatus::Charged, AttemptStatus::PartialCharged),
+            (AttemptStatus::Voided, AttemptStatus::AuthorizationFailed),
+            (AttemptStatus::AuthorizationFailed, AttemptStatus::Charged),
+        ];
+
+        for (current_status, incoming_status) in transitions {
+            assert!(
+                is_payment_attempt_status_regression(current_status, incoming_status),
+                "{current_status} -> {incoming_status}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_refund_status_regression() {
+        assert!(!is_refund_status_regression(
+            RefundStatus::Pending,
+            RefundStatus::Success
+        ));
+        assert!(!is_refund_status_regression(
+            RefundStatus::Success,
+            RefundStatus::Failure
+        ));
+        assert!(is_refund_status_regression(
+            RefundStatus::Success,
+            RefundStatus::Pending
+        ));
+        assert!(is_refund_status_regression(
+            RefundStatus::Failure,
+            RefundStatus::Success
+        ));
+    }
+
+    #[cfg(feature = "payouts")]
+    #[test]
+    fn test_payout_status_regression() {
+        use common_enums::PayoutStatus;
+
+        assert!(!is_payout_status_regression(
+            PayoutStatus::Initiated,
+            PayoutStatus::Success
+        ));
+        assert!(!is_payout_status_regression(
+            PayoutStatus::Success,
+            PayoutStatus::Reversed
+        ));
+        assert!(is_payout_status_regression(
+            PayoutStatus::Success,
+            PayoutStatus::Pending
+        ));
+        assert!(is_payout_status_regression(
+            PayoutStatus::Success,
+            PayoutStatus::Failed
+        ));
+        assert!(is_payout_status_regression(
+            PayoutStatus::Reversed,
+            PayoutStatus::Success
+        ));
+    }
+}
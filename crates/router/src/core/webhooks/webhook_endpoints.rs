@@ -0,0 +1,796 @@
+use api_models::webhook_endpoints as webhook_endpoints_api;
+use common_utils::{encryption::Encryption, fp_utils, id_type::GenerateId, standard_webhooks};
+use diesel_models::business_profile::{
+    ExpiringWebhookSecret, MultipleWebhookDetail, WebhookDetails,
+};
+use error_stack::{report, ResultExt};
+use hyperswitch_masking::{PeekInterface, Secret};
+use router_env::{instrument, logger, tracing};
+
+use super::{endpoint_health, utils};
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::domain,
+};
+
+const MAX_WEBHOOK_ENDPOINTS_PER_PROFILE: usize = 16;
+
+const WEBHOOK_ENDPOINT_SECRET_LENGTH: usize = 64;
+
+/// Endpoints keep at most these many secrets replaced by a rotation which have not expired yet
+const MAX_PREVIOUS_WEBHOOK_ENDPOINT_SECRETS: usize = 2;
+
+const DEFAULT_PREVIOUS_SECRET_EXPIRY_IN_SECONDS: u32 = 24 * 60 * 60;
+
+const MAX_PREVIOUS_SECRET_EXPIRY_IN_SECONDS: u32 = 7 * 24 * 60 * 60;
+
+#[instrument(skip_all)]
+pub async fn create_webhook_endpoint(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    profile_id: common_utils::id_type::ProfileId,
+    key_store: domain::MerchantKeyStore,
+    request: webhook_endpoints_api::WebhookEndpointCreateRequest,
+) -> RouterResponse<webhook_endpoints_api::WebhookEndpointResponse> {
+    validate_webhook_url(&request.webhook_url)?;
+    validate_enabled_events(&request.enabled_events)?;
+    let signing_version = request.signing_version.unwrap_or_default();
+    let secret = match request.secret {
+        Some(secret) => {
+            validate_webhook_secret(signing_version, Some(&secret))?;
+            secret
+        }
+        None => generate_webhook_secret(signing_version),
+    };
+
+    let encrypted_secret = encrypt_webhook_secret(&state, &key_store, secret.clone()).await?;
+
+    let webhook_endpoint = utils::with_webhook_endpoints_lock(&state, &profile_id, async {
+        let business_profile =
+            find_business_profile(&state, &key_store, &merchant_id, &profile_id).await?;
+        let mut webhook_endpoints = get_webhook_endpoints(&business_profile);
+
+        fp_utils::when(
+            webhook_endpoints.len() >= MAX_WEBHOOK_ENDPOINTS_PER_PROFILE,
+            || {
+                Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                    message: format!(
+                        "A business profile can have at most {MAX_WEBHOOK_ENDPOINTS_PER_PROFILE} webhook endpoints"
+                    ),
+                }))
+            },
+        )?;
+
+        let webhook_endpoint = MultipleWebhookDetail {
+            webhook_endpoint_id: common_utils::id_type::WebhookEndpointId::generate(),
+            webhook_url: request.webhook_url,
+            events: request.enabled_events,
+            status: request
+                .status
+                .unwrap_or(common_enums::OutgoingWebhookEndpointStatus::Active),
+            secret: Some(encrypted_secret),
+            signing_version: Some(signing_version),
+            previous_secrets: None,
+        };
+        webhook_endpoints.push(webhook_endpoint.clone());
+
+        update_webhook_endpoints(&state, &key_store, business_profile, webhook_endpoints).await?;
+
+        Ok::<_, error_stack::Report<errors::ApiErrorResponse>>(webhook_endpoint)
+    })
+    .await?;
+
+    let mut response = to_webhook_endpoint_response(&profile_id, webhook_endpoint);
+    response.secret = Some(secret);
+
+    Ok(ApplicationResponse::Json(response))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_webhook_endpoint(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    profile_id: common_utils::id_type::ProfileId,
+    webhook_endpoint_id: common_utils::id_type::WebhookEndpointId,
+    key_store: domain::MerchantKeyStore,
+) -> RouterResponse<webhook_endpoints_api::WebhookEndpointResponse> {
+    let business_profile =
+        find_business_profile(&state, &key_store, &merchant_id, &profile_id).await?;
+
+    let webhook_endpoint = get_webhook_endpoints(&business_profile)
+        .into_iter()
+        .find(|endpoint| endpoint.webhook_endpoint_id == webhook_endpoint_id)
+        .ok_or_else(|| webhook_endpoint_not_found(&webhook_endpoint_id))?;
+
+    Ok(ApplicationResponse::Json(to_webhook_endpoint_response(
+        &profile_id,
+        webhook_endpoint,
+    )))
+}
+
+#[instrument(skip_all)]
+pub async fn list_webhook_endpoints(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    profile_id: common_utils::id_type::ProfileId,
+    key_store: domain::MerchantKeyStore,
+) -> RouterResponse<Vec<webhook_endpoints_api::WebhookEndpointResponse>> {
+    let business_profile =
+        find_business_profile(&state, &key_store, &merchant_id, &profile_id).await?;
+
+    let webhook_endpoints = get_webhook_endpoints(&business_profile)
+        .into_iter()
+        .map(|endpoint| to_webhook_endpoint_response(&profile_id, endpoint))
+        .collect();
+
+    Ok(ApplicationResponse::Json(webhook_endpoints))
+}
+
+#[instrument(skip_all)]
+pub async fn update_webhook_endpoint(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    profile_id: common_utils::id_type::ProfileId,
+    webhook_endpoint_id: common_utils::id_type::WebhookEndpointId,
+    key_store: domain::MerchantKeyStore,
+    request: webhook_endpoints_api::WebhookEndpointUpdateRequest,
+) -> RouterResponse<webhook_endpoints_api::WebhookEndpointResponse> {
+    if let Some(webhook_url) = &request.webhook_url {
+        validate_webhook_url(webhook_url)?;
+    }
+    if let Some(enabled_events) = &request.enabled_events {
+        validate_enabled_events(enabled_events)?;
+    }
+
+    let encrypted_secret = match request.secret.clone() {
+        Some(secret) => Some(encrypt_webhook_secret(&state, &key_store, secret).await?),
+        None => None,
+    };
+    let is_active = request.status == Some(common_enums::OutgoingWebhookEndpointStatus::Active);
+
+    let (updated_webhook_endpoint, is_activated) =
+        utils::with_webhook_endpoints_lock(&state, &profile_id, async {
+            let business_profile =
+                find_business_profile(&state, &key_store, &merchant_id, &profile_id).await?;
+            let mut webhook_endpoints = get_webhook_endpoints(&business_profile);
+
+            let webhook_endpoint = webhook_endpoints
+                .iter_mut()
+                .find(|endpoint| endpoint.webhook_endpoint_id == webhook_endpoint_id)
+                .ok_or_else(|| webhook_endpoint_not_found(&webhook_endpoint_id))?;
+            let is_activated = is_active
+                && webhook_endpoint.status != common_enums::OutgoingWebhookEndpointStatus::Active;
+
+            if let Some(status) = request.status {
+                fp_utils::when(
+                    webhook_endpoint.status
+                        == common_enums::OutgoingWebhookEndpointStatus::Deprecated
+                        && status != common_enums::OutgoingWebhookEndpointStatus::Deprecated,
+                    || {
+                        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                            message: "Deprecated webhook endpoints cannot be reactivated"
+                                .to_string(),
+                        }))
+                    },
+                )?;
+                webhook_endpoint.status = status;
+            }
+            if let Some(webhook_url) = request.webhook_url {
+                webhook_endpoint.webhook_url = webhook_url;
+            }
+            if let Some(enabled_events) = request.enabled_events {
+                webhook_endpoint.events = enabled_events;
+            }
+            if let Some(signing_version) = request.signing_version {
+                // Secrets replaced by a rotation cannot sign webhooks with a different scheme
+                if signing_version != webhook_endpoint.signing_version.unwrap_or_default() {
+                    webhook_endpoint.previous_secrets = None;
+                }
+                webhook_endpoint.signing_version = Some(signing_version);
+            }
+
+            let signing_version = webhook_endpoint.signing_version.unwrap_or_default();
+            let secret = match request.secret {
+                Some(secret) => Some(secret),
+                // The stored secret only has to be validated if the signing scheme changed
+                None if request.signing_version.is_some() => {
+                    utils::decrypt_webhook_endpoint_secrets(&state, &key_store, webhook_endpoint)
+                        .await
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Failed to decrypt webhook endpoint secret")?
+                        .secret
+                }
+                None => None,
+            };
+            if request.signing_version.is_some() || secret.is_some() {
+                validate_webhook_secret(signing_version, secret.as_ref())?;
+            }
+            if let Some(encrypted_secret) = encrypted_secret {
+                webhook_endpoint.secret = Some(encrypted_secret);
+            }
+            let updated_webhook_endpoint = webhook_endpoint.clone();
+
+            update_webhook_endpoints(&state, &key_store, business_profile, webhook_endpoints)
+                .await?;
+
+            Ok::<_, error_stack::Report<errors::ApiErrorResponse>>((
+                updated_webhook_endpoint,
+                is_activated,
+            ))
+        })
+        .await?;
+
+    // The failures which led to the endpoint being paused must not pause it again
+    if is_activated {
+        endpoint_health::reset_webhook_endpoint_health(&state, &webhook_endpoint_id).await?;
+    }
+    // Events queued while the endpoint was paused are delivered once it is active. Activating an
+    // active endpoint schedules the delivery again, should scheduling it have failed before.
+    if is_active {
+        endpoint_health::schedule_queued_events_flush(
+            &state,
+            &merchant_id,
+            &profile_id,
+            &webhook_endpoint_id,
+        )
+        .await?;
+    }
+
+    Ok(ApplicationResponse::Json(to_webhook_endpoint_response(
+        &profile_id,
+        updated_webhook_endpoint,
+    )))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_webhook_endpoint_health(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    profile_id: common_utils::id_type::ProfileId,
+    webhook_endpoint_id: common_utils::id_type::WebhookEndpointId,
+    key_store: domain::MerchantKeyStore,
+) -> RouterResponse<webhook_endpoints_api::WebhookEndpointHealthResponse> {
+    let business_profile =
+        find_business_profile(&state, &key_store, &merchant_id, &profile_id).await?;
+
+    let webhook_endpoint = get_webhook_endpoints(&business_profile)
+        .into_iter()
+        .find(|endpoint| endpoint.webhook_endpoint_id == webhook_endpoint_id)
+        .ok_or_else(|| webhook_endpoint_not_found(&webhook_endpoint_id))?;
+
+    endpoint_health::get_webhook_endpoint_health(&state, &profile_id, webhook_endpoint)
+        .await
+        .map(ApplicationResponse::Json)
+}
+
+/// Replaces the secret of an endpoint signed with `standard_webhooks`. Webhooks are also signed
+/// with the replaced secret until it expires, so that receivers can switch to the new secret
+/// without rejecting webhooks in the meantime.
+#[instrument(skip_all)]
+pub async fn rotate_webhook_endpoint_secret(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    profile_id: common_utils::id_type::ProfileId,
+    webhook_endpoint_id: common_utils::id_type::WebhookEndpointId,
+    key_store: domain::MerchantKeyStore,
+    request: webhook_endpoints_api::WebhookEndpointSecretRotateRequest,
+) -> RouterResponse<webhook_endpoints_api::WebhookEndpointResponse> {
+    let expire_current_secret_in_seconds = request
+        .expire_current_secret_in_seconds
+        .unwrap_or(DEFAULT_PREVIOUS_SECRET_EXPIRY_IN_SECONDS);
+    fp_utils::when(
+        expire_current_secret_in_seconds > MAX_PREVIOUS_SECRET_EXPIRY_IN_SECONDS,
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "`expire_current_secret_in_seconds` must not exceed {MAX_PREVIOUS_SECRET_EXPIRY_IN_SECONDS}"
+                ),
+            }))
+        },
+    )?;
+    let signing_version = common_enums::OutgoingWebhookSigningVersion::StandardWebhooks;
+    let secret = match request.secret {
+        Some(secret) => {
+            validate_webhook_secret(signing_version, Some(&secret))?;
+            secret
+        }
+        None => generate_webhook_secret(signing_version),
+    };
+
+    let encrypted_secret = encrypt_webhook_secret(&state, &key_store, secret.clone()).await?;
+
+    let updated_webhook_endpoint = utils::with_webhook_endpoints_lock(&state, &profile_id, async {
+        let business_profile =
+            find_business_profile(&state, &key_store, &merchant_id, &profile_id).await?;
+        let mut webhook_endpoints = get_webhook_endpoints(&business_profile);
+
+        let webhook_endpoint = webhook_endpoints
+            .iter_mut()
+            .find(|endpoint| endpoint.webhook_endpoint_id == webhook_endpoint_id)
+            .ok_or_else(|| webhook_endpoint_not_found(&webhook_endpoint_id))?;
+
+        fp_utils::when(
+            webhook_endpoint.signing_version.unwrap_or_default() != signing_version,
+            || {
+                Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "Secrets can only be rotated for webhook endpoints signed with `standard_webhooks`"
+                        .to_string(),
+                }))
+            },
+        )?;
+
+        let now = common_utils::date_time::now();
+        let mut previous_secrets: Vec<_> = webhook_endpoint
+            .get_active_previous_secrets(now)
+            .cloned()
+            .collect();
+        fp_utils::when(
+            previous_secrets.len() >= MAX_PREVIOUS_WEBHOOK_ENDPOINT_SECRETS,
+            || {
+                Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                    message: format!(
+                        "A webhook endpoint can have at most {MAX_PREVIOUS_WEBHOOK_ENDPOINT_SECRETS} rotated secrets which have not expired"
+                    ),
+                }))
+            },
+        )?;
+
+        if let Some(current_secret) = webhook_endpoint.secret.take() {
+            if expire_current_secret_in_seconds > 0 {
+                previous_secrets.push(ExpiringWebhookSecret {
+                    secret: current_secret,
+                    expires_at: now.saturating_add(time::Duration::seconds(
+                        expire_current_secret_in_seconds.into(),
+                    )),
+                });
+            }
+        }
+        webhook_endpoint.secret = Some(encrypted_secret);
+        webhook_endpoint.previous_secrets = Some(previous_secrets);
+        let updated_webhook_endpoint = webhook_endpoint.clone();
+
+        update_webhook_endpoints(&state, &key_store, business_profile, webhook_endpoints).await?;
+
+        Ok::<_, error_stack::Report<errors::ApiErrorResponse>>(updated_webhook_endpoint)
+    })
+    .await?;
+
+    let mut response = to_webhook_endpoint_response(&profile_id, updated_webhook_endpoint);
+    response.secret = Some(secret);
+
+    Ok(ApplicationResponse::Json(response))
+}
+
+#[instrument(skip_all)]
+pub async fn delete_webhook_endpoint(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    profile_id: common_utils::id_type::ProfileId,
+    webhook_endpoint_id: common_utils::id_type::WebhookEndpointId,
+    key_store: domain::MerchantKeyStore,
+) -> RouterResponse<webhook_endpoints_api::WebhookEndpointDeleteResponse> {
+    utils::with_webhook_endpoints_lock(&state, &profile_id, async {
+        let business_profile =
+            find_business_profile(&state, &key_store, &merchant_id, &profile_id).await?;
+        let mut webhook_endpoints = get_webhook_endpoints(&business_profile);
+
+        let endpoints_count = webhook_endpoints.len();
+        webhook_endpoints.retain(|endpoint| endpoint.webhook_endpoint_id != webhook_endpoint_id);
+        fp_utils::when(webhook_endpoints.len() == endpoints_count, || {
+            Err(webhook_endpoint_not_found(&webhook_endpoint_id))
+        })?;
+
+        update_webhook_endpoints(&state, &key_store, business_profile, webhook_endpoints).await
+    })
+    .await?;
+
+    endpoint_health::delete_webhook_endpoint_health(&state, &webhook_endpoint_id)
+        .await
+        .inspect_err(|error| {
+            logger::warn!(
+                ?error,
+                "Failed to delete health of deleted webhook endpoint"
+            );
+        })
+        .ok();
+
+    Ok(ApplicationResponse::Json(
+        webhook_endpoints_api::WebhookEndpointDeleteResponse {
+            webhook_endpoint_id,
+            profile_id,
+            deleted: true,
+        },
+    ))
+}
+
+async fn find_business_profile(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    merchant_id: &common_utils::id_type::MerchantId,
+    profile_id: &common_utils::id_type::ProfileId,
+) -> RouterResult<domain::Profile> {
+    state
+        .store
+        .find_business_profile_by_merchant_id_profile_id(key_store, merchant_id, profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })
+}
+
+async fn encrypt_webhook_secret(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    secret: Secret<String>,
+) -> RouterResult<Encryption> {
+    utils::encrypt_webhook_endpoint_secret(state, key_store, secret)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encrypt webhook endpoint secret")
+}
+
+fn get_webhook_endpoints(business_profile: &domain::Profile) -> Vec<MultipleWebhookDetail> {
+    business_profile
+        .webhook_details
+        .as_ref()
+        .and_then(|webhook_details| webhook_details.multiple_webhooks_list.clone())
+        .unwrap_or_default()
+}
+
+async fn update_webhook_endpoints(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    business_profile: domain::Profile,
+    webhook_endpoints: Vec<MultipleWebhookDetail>,
+) -> RouterResult<domain::Profile> {
+    let webhook_details = WebhookDetails {
+        multiple_webhooks_list: Some(webhook_endpoints),
+        ..business_profile
+            .webhook_details
+            .clone()
+            .unwrap_or_else(empty_webhook_details)
+    };
+    let profile_update = domain::ProfileUpdate::WebhookDetailsUpdate {
+        webhook_details: Some(webhook_details),
+    };
+
+    state
+        .store
+        .update_profile_by_profile_id(key_store, business_profile, profile_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update webhook endpoints of business profile")
+}
+
+fn empty_webhook_details() -> WebhookDetails {
+    WebhookDetails {
+        webhook_version: None,
+        webhook_username: None,
+        webhook_password: None,
+        webhook_url: None,
+        payment_created_enabled: None,
+        payment_succeeded_enabled: None,
+        payment_failed_enabled: None,
+        payment_statuses_enabled: None,
+        refund_statuses_enabled: None,
+        payout_statuses_enabled: None,
+        multiple_webhooks_list: None,
+    }
+}
+
+fn validate_webhook_url(webhook_url: &Secret<String>) -> RouterResult<()> {
+    let webhook_url = url::Url::parse(webhook_url.peek()).map_err(|_| {
+        report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`webhook_url` must be a valid URL".to_string(),
+        })
+    })?;
+
+    fp_utils::when(!matches!(webhook_url.scheme(), "http" | "https"), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`webhook_url` must be an HTTP or HTTPS URL".to_string(),
+        }))
+    })
+}
+
+fn generate_webhook_secret(
+    signing_version: common_enums::OutgoingWebhookSigningVersion,
+) -> Secret<String> {
+    match signing_version {
+        common_enums::OutgoingWebhookSigningVersion::Legacy => Secret::new(
+            common_utils::crypto::generate_cryptographically_secure_random_string(
+                WEBHOOK_ENDPOINT_SECRET_LENGTH,
+            ),
+        ),
+        common_enums::OutgoingWebhookSigningVersion::StandardWebhooks => {
+            standard_webhooks::WebhookSecret::generate().encode()
+        }
+    }
+}
+
+fn validate_webhook_secret(
+    signing_version: common_enums::OutgoingWebhookSigningVersion,
+    secret: Option<&Secret<String>>,
+) -> RouterResult<()> {
+    match signing_version {
+        common_enums::OutgoingWebhookSigningVersion::Legacy => Ok(()),
+        common_enums::OutgoingWebhookSigningVersion::StandardWebhooks => secret
+            .and_then(|secret| standard_webhooks::WebhookSecret::from_encoded(secret.peek()).ok())
+            .map(|_| ())
+            .ok_or_else(|| {
+                report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message:
+                        "Webhook endpoints signed with `standard_webhooks` require a `secret` \
+                        of 24 to 64 bytes, base64 encoded and prefixed with `whsec_`"
+                            .to_string(),
+                })
+            }),
+    }
+}
+
+fn validate_enabled_events(
+    enabled_events: &std::collections::HashSet<common_enums::EventType>,
+) -> RouterResult<()> {
+    fp_utils::when(enabled_events.is_empty(), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`enabled_events` must contain at least one event type".to_string(),
+        }))
+    })
+}
+
+fn webhook_endpoint_not_found(
+    webhook_endpoint_id: &common_utils::id_type::WebhookEndpointId,
+) -> error_stack::Report<errors::ApiErrorResponse> {
+    report!(errors::ApiErrorResponse::GenericNotFoundError {
+        message: format!(
+            "Webhook endpoint with the given id '{}' does not exist in our records",
+            webhook_endpoint_id.get_string_repr()
+        ),
+    })
+}
+
+fn to_webhook_endpoint_response(
+    profile_id: &common_utils::id_type::ProfileId,
+    webhook_endpoint: MultipleWebhookDetail,
+) -> webhook_endpoints_api::WebhookEndpointResponse {
+    let previous_secrets_expire_at = webhook_endpoint
+        .get_active_previous_secrets(common_utils::date_time::now())
+        .map(|previous_secret| previous_secret.expires_at)
+        .max();
+
+    webhook_endpoints_api::WebhookEndpointResponse {
+        webhook_endpoint_id: webhook_endpoint.webhook_endpoint_id,
+        profile_id: profile_id.clone(),
+        webhook_url: webhook_endpoint.webhook_url,
+        enabled_events: webhook_endpoint.events,
+        status: webhook_endpoint.status,
+        signing_version: webhook_endpoint.signing_version.unwrap_or_default(),
+        previous_secrets_expire_at,
+        secret: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+    use std::{
+        borrow::Cow,
+        collections::HashSet,
+        sync::{
+            atomic::{AtomicBool, Ordering},
+            Arc,
+        },
+    };
+
+    use common_utils::crypto::Encryptable;
+    use router_env::RequestId;
+    use tokio::sync::oneshot;
+
+    use super::*;
+    use crate::{
+        routes::{
+            self,
+            app::{settings::Settings, SessionStateInfo, StorageImpl},
+        },
+        services,
+    };
+
+    async fn get_session_state() -> SessionState {
+        let tx: oneshot::Sender<()> = oneshot::channel().0;
+        let app_state = Box::pin(routes::AppState::with_storage(
+            Settings::new().expect("invalid settings"),
+            StorageImpl::Mock,
+            tx,
+            Box::new(services::MockApiClient),
+            env!("CARGO_PKG_NAME"),
+        ))
+        .await;
+        let mut state = Arc::new(app_state)
+            .get_session_state(
+                &common_utils::id_type::TenantId::try_from_string("public".to_string()).unwrap(),
+                None,
+                || {},
+            )
+            .unwrap();
+        state.add_request_id(RequestId::new_generated());
+        state
+    }
+
+    fn get_key_store() -> domain::MerchantKeyStore {
+        domain::MerchantKeyStore {
+            merchant_id: common_utils::id_type::MerchantId::try_from(Cow::from("merchant_1"))
+                .unwrap(),
+            key: Encryptable::new(
+                Secret::new(services::generate_aes256_key().unwrap().to_vec()),
+                Secret::new(Vec::new()),
+            ),
+            created_at: common_utils::date_time::now(),
+        }
+    }
+
+    fn get_webhook_endpoint(secret: Option<Encryption>) -> MultipleWebhookDetail {
+        MultipleWebhookDetail {
+            webhook_endpoint_id: common_utils::id_type::WebhookEndpointId::generate(),
+            webhook_url: Secret::new("https://example.com/webhooks".to_string()),
+            events: HashSet::from([common_enums::EventType::PaymentSucceeded]),
+            status: common_enums::OutgoingWebhookEndpointStatus::Active,
+            secret,
+            signing_version: Some(common_enums::OutgoingWebhookSigningVersion::StandardWebhooks),
+            previous_secrets: None,
+        }
+    }
+
+    #[test]
+    fn test_webhook_url_must_be_http_url() {
+        assert!(validate_webhook_url(&Secret::new("https://example.com".to_string())).is_ok());
+        assert!(validate_webhook_url(&Secret::new("http://example.com".to_string())).is_ok());
+        assert!(validate_webhook_url(&Secret::new("ftp://example.com".to_string())).is_err());
+        assert!(validate_webhook_url(&Secret::new("not a url".to_string())).is_err());
+    }
+
+    #[test]
+    fn test_enabled_events_must_not_be_empty() {
+        assert!(validate_enabled_events(&HashSet::new()).is_err());
+        assert!(validate_enabled_events(&HashSet::from([
+            common_enums::EventType::PaymentSucceeded
+        ]))
+        .is_ok());
+    }
+
+    #[test]
+    fn test_generated_secrets_are_valid() {
+        for signing_version in [
+            common_enums::OutgoingWebhookSigningVersion::Legacy,
+            common_enums::OutgoingWebhookSigningVersion::StandardWebhooks,
+        ] {
+            let secret = generate_webhook_secret(signing_version);
+            assert!(validate_webhook_secret(signing_version, Some(&secret)).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_standard_webhooks_secret_must_be_prefixed_and_encoded() {
+        let signing_version = common_enums::OutgoingWebhookSigningVersion::StandardWebhooks;
+        assert!(validate_webhook_secret(signing_version, None).is_err());
+        assert!(validate_webhook_secret(
+            signing_version,
+            Some(&Secret::new("not_a_standard_webhooks_secret".to_string()))
+        )
+        .is_err());
+        assert!(validate_webhook_secret(
+            common_enums::OutgoingWebhookSigningVersion::Legacy,
+            Some(&Secret::new("any secret".to_string()))
+        )
+        .is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_webhook_endpoint_secret_is_stored_encrypted() {
+        let state = get_session_state().await;
+        let key_store = get_key_store();
+        let secret =
+            generate_webhook_secret(common_enums::OutgoingWebhookSigningVersion::StandardWebhooks);
+
+        let encrypted_secret = encrypt_webhook_secret(&state, &key_store, secret.clone())
+            .await
+            .unwrap();
+        assert_ne!(
+            encrypted_secret.get_inner().peek().as_slice(),
+            secret.peek().as_bytes()
+        );
+
+        let webhook_endpoint = get_webhook_endpoint(Some(encrypted_secret));
+        let serialized_webhook_endpoint = serde_json::to_string(&webhook_endpoint).unwrap();
+        assert!(!serialized_webhook_endpoint.contains(secret.peek()));
+
+        let secrets =
+            utils::decrypt_webhook_endpoint_secrets(&state, &key_store, &webhook_endpoint)
+                .await
+                .unwrap();
+        assert_eq!(
+            secrets.secret.as_ref().map(|secret| secret.peek().clone()),
+            Some(secret.peek().clone())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_active_previous_secrets_are_decrypted() {
+        let state = get_session_state().await;
+        let key_store = get_key_store();
+        let signing_version = common_enums::OutgoingWebhookSigningVersion::StandardWebhooks;
+        let active_secret = generate_webhook_secret(signing_version);
+        let expired_secret = generate_webhook_secret(signing_version);
+        let now = common_utils::date_time::now();
+
+        let mut webhook_endpoint = get_webhook_endpoint(None);
+        webhook_endpoint.previous_secrets = Some(vec![
+            ExpiringWebhookSecret {
+                secret: encrypt_webhook_secret(&state, &key_store, active_secret.clone())
+                    .await
+                    .unwrap(),
+                expires_at: now.saturating_add(time::Duration::hours(1)),
+            },
+            ExpiringWebhookSecret {
+                secret: encrypt_webhook_secret(&state, &key_store, expired_secret.clone())
+                    .await
+                    .unwrap(),
+                expires_at: now.saturating_sub(time::Duration::hours(1)),
+            },
+        ]);
+        let serialized_webhook_endpoint = serde_json::to_string(&webhook_endpoint).unwrap();
+        assert!(!serialized_webhook_endpoint.contains(active_secret.peek()));
+
+        let secrets =
+            utils::decrypt_webhook_endpoint_secrets(&state, &key_store, &webhook_endpoint)
+                .await
+                .unwrap();
+        assert!(secrets.secret.is_none());
+        assert_eq!(
+            secrets
+                .previous_secrets
+                .iter()
+                .map(|secret| secret.peek().clone())
+                .collect::<Vec<_>>(),
+            vec![active_secret.peek().clone()]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_webhook_endpoint_updates_of_a_profile_are_serialized() {
+        let state = get_session_state().await;
+        let profile_id = common_utils::id_type::ProfileId::try_from(Cow::from(
+            common_utils::generate_time_ordered_id("pro"),
+        ))
+        .unwrap();
+        let is_updating = Arc::new(AtomicBool::new(false));
+
+        let update = || async {
+            // No other update of the profile must be in progress
+            assert!(!is_updating.swap(true, Ordering::SeqCst));
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+            is_updating.store(false, Ordering::SeqCst);
+            Ok::<_, error_stack::Report<errors::ApiErrorResponse>>(())
+        };
+        let (first, second) = tokio::join!(
+            utils::with_webhook_endpoints_lock(&state, &profile_id, update()),
+            utils::with_webhook_endpoints_lock(&state, &profile_id, update()),
+        );
+        assert!(first.is_ok());
+        assert!(second.is_ok());
+
+        // The lock is released even if the update fails
+        let failed = utils::with_webhook_endpoints_lock(&state, &profile_id, async {
+            Err::<(), _>(report!(errors::ApiErrorResponse::InternalServerError))
+        })
+        .await;
+        assert!(failed.is_err());
+        assert!(
+            utils::with_webhook_endpoints_lock(&state, &profile_id, async {
+                Ok::<_, error_stack::Report<errors::ApiErrorResponse>>(())
+            })
+            .await
+            .is_ok()
+        );
+    }
+}
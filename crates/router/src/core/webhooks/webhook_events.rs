@@ -9,12 +9,17 @@ use crate::{
     core::errors::{self, RouterResponse, StorageErrorExt},
     routes::SessionState,
     services::ApplicationResponse,
-    types::{api, domain, storage, transformers::ForeignTryFrom},
+    types::{
+        api, domain, storage,
+        transformers::{ForeignFrom, ForeignTryFrom},
+    },
     utils::{OptionExt, StringExt},
 };
 
 const INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_LIMIT: i64 = 100;
 const INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_DAYS: i64 = 90;
+const INCOMING_WEBHOOKS_LIST_MAX_LIMIT: i64 = 100;
+const INCOMING_WEBHOOKS_LIST_MAX_DAYS: i64 = 90;
 
 #[instrument(skip(state))]
 pub async fn list_initial_delivery_attempts(
@@ -117,7 +122,19 @@ pub async fn list_initial_delivery_attempts(
             event_types,
             is_delivered,
             recipient,
+            webhook_endpoint_id,
         } => {
+            fp_utils::when(
+                webhook_endpoint_id.is_some() && profile_id.is_none(),
+                || {
+                    Err(errors::ApiErrorResponse::InvalidRequestData {
+                        message:
+                            "`profile_id` must be specified when filtering by `webhook_endpoint_id`"
+                                .to_string(),
+                    })
+                },
+            )?;
+
             let limit = match limit {
                 Some(limit) if  limit <= INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_LIMIT => Ok(Some(limit)),
                 Some(limit) if limit > INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_LIMIT => Err(
@@ -183,6 +200,7 @@ pub async fn list_initial_delivery_attempts(
                             is_delivered,
                             &key_store,
                             recipient,
+                            webhook_endpoint_id.clone(),
                         )
                         .await
                 }
@@ -215,6 +233,7 @@ pub async fn list_initial_delivery_attempts(
                             event_types,
                             is_delivered,
                             recipient,
+                            webhook_endpoint_id,
                         )
                         .await
                 }
@@ -343,17 +362,6 @@ pub async fn retry_delivery_attempt(
         ));
     }
 
-    let provider_merchant_id = event_to_retry
-        .merchant_id
-        .clone()
-        .get_required_value("merchant_id")
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Failed to read merchant ID from event to retry")?;
-    let processor_merchant_id = event_to_retry
-        .processor_merchant_id
-        .clone()
-        .unwrap_or_else(|| provider_merchant_id.clone());
-
     let business_profile_id = event_to_retry
         .business_profile_id
         .clone()
@@ -366,12 +374,55 @@ pub async fn retry_delivery_attempt(
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to find business profile")?;
 
+    let updated_event = redeliver_event(
+        &state,
+        &key_store,
+        &merchant_id,
+        business_profile,
+        event_to_retry,
+    )
+    .await?;
+
+    Ok(ApplicationResponse::Json(
+        api::webhook_events::EventRetrieveResponse::try_from(
+            domain::EventWithDeliverySuccessSource {
+                event: updated_event,
+                source: domain::DeliverySuccessSource::ListDeliveryAttempts,
+            },
+        )?,
+    ))
+}
+
+/// Delivers an event to the merchant again as a manual retry, and returns the event recording the
+/// new delivery attempt.
+#[cfg(feature = "v1")]
+pub(super) async fn redeliver_event(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    merchant_id: &common_utils::id_type::MerchantId,
+    business_profile: domain::Profile,
+    event_to_retry: domain::Event,
+) -> errors::RouterResult<domain::Event> {
+    let store = state.store.as_ref();
+
+    let provider_merchant_id = event_to_retry
+        .merchant_id
+        .clone()
+        .get_required_value("merchant_id")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to read merchant ID from event to retry")?;
+    let processor_merchant_id = event_to_retry
+        .processor_merchant_id
+        .clone()
+        .unwrap_or_else(|| provider_merchant_id.clone());
+
     let delivery_attempt = storage::enums::WebhookDeliveryAttempt::ManualRetry;
     let new_event_id = super::utils::generate_event_id();
     let idempotent_event_id = super::utils::get_idempotent_event_id(
         &event_to_retry.primary_object_id,
         event_to_retry.event_type,
         delivery_attempt,
+        event_to_retry.webhook_endpoint_id.as_ref(),
     )
     .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
     .attach_printable("Failed to generate idempotent event ID")?;
@@ -398,10 +449,11 @@ pub async fn retry_delivery_attempt(
         processor_merchant_id: Some(processor_merchant_id.clone()),
         initiator_merchant_id: Some(merchant_id.clone()),
         recipient: event_to_retry.recipient,
+        webhook_endpoint_id: event_to_retry.webhook_endpoint_id,
     };
 
     let event = store
-        .insert_event(new_event, &key_store)
+        .insert_event(new_event, key_store)
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to insert event")?;
@@ -420,7 +472,7 @@ pub async fn retry_delivery_attempt(
     Box::pin(super::outgoing::trigger_webhook_and_raise_event(
         state.clone(),
         business_profile,
-        &key_store,
+        key_store,
         provider_merchant_id,
         processor_merchant_id,
         event,
@@ -428,22 +480,105 @@ pub async fn retry_delivery_attempt(
         delivery_attempt,
         None,
         None,
-        super::types::WebhookRecipientData::Merchant { merchant_id },
+        super::types::WebhookRecipientData::Merchant {
+            merchant_id: merchant_id.clone(),
+        },
     ))
     .await;
 
-    let updated_event = store
-        .find_event_by_event_id(&new_event_id, &key_store)
+    store
+        .find_event_by_event_id(&new_event_id, key_store)
         .await
-        .to_not_found_response(errors::ApiErrorResponse::EventNotFound)?;
+        .to_not_found_response(errors::ApiErrorResponse::EventNotFound)
+}
+
+#[instrument(skip(state))]
+pub async fn list_incoming_webhooks(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    constraints: api::webhook_events::IncomingWebhookListConstraints,
+) -> RouterResponse<api::webhook_events::TotalIncomingWebhooksResponse> {
+    let store = state.store.as_ref();
+    let master_key = &store.get_master_key().to_vec().into();
+    let key_store = store
+        .get_merchant_key_store_by_merchant_id(&merchant_id, master_key)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let now = common_utils::date_time::now();
+    let list_begin_time =
+        (now.date() - time::Duration::days(INCOMING_WEBHOOKS_LIST_MAX_DAYS)).midnight();
+
+    fp_utils::when(
+        !constraints
+            .created_after
+            .zip(constraints.created_before)
+            .map(|(created_after, created_before)| created_after <= created_before)
+            .unwrap_or(true),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "The `created_after` timestamp must be an earlier timestamp compared to \
+                    the `created_before` timestamp"
+                    .to_string(),
+            })
+        },
+    )?;
+
+    let created_after = constraints
+        .created_after
+        .map_or(list_begin_time, |created_after| {
+            created_after.max(list_begin_time)
+        });
+    let created_before = constraints.created_before.unwrap_or(now);
+
+    let limit = match constraints.limit.map(i64::from) {
+        Some(limit) if limit > INCOMING_WEBHOOKS_LIST_MAX_LIMIT => {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "`limit` must be a number less than {INCOMING_WEBHOOKS_LIST_MAX_LIMIT}"
+                ),
+            })
+        }
+        Some(limit) => Ok(limit),
+        None => Ok(INCOMING_WEBHOOKS_LIST_MAX_LIMIT),
+    }?;
+    let offset = constraints.offset.map(i64::from);
+
+    let incoming_webhooks = store
+        .list_incoming_webhook_logs_by_merchant_id_constraints(
+            &merchant_id,
+            created_after,
+            created_before,
+            constraints.connector.clone(),
+            constraints.status,
+            Some(limit),
+            offset,
+            &key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list incoming webhooks with specified constraints")?;
+
+    let total_count = store
+        .count_incoming_webhook_logs_by_merchant_id_constraints(
+            &merchant_id,
+            created_after,
+            created_before,
+            constraints.connector,
+            constraints.status,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get total incoming webhooks count")?;
 
     Ok(ApplicationResponse::Json(
-        api::webhook_events::EventRetrieveResponse::try_from(
-            domain::EventWithDeliverySuccessSource {
-                event: updated_event,
-                source: domain::DeliverySuccessSource::ListDeliveryAttempts,
-            },
-        )?,
+        api::webhook_events::TotalIncomingWebhooksResponse {
+            incoming_webhooks: incoming_webhooks
+                .into_iter()
+                .map(api::webhook_events::IncomingWebhookResponse::foreign_from)
+                .collect(),
+            total_count,
+        },
     ))
 }
 
@@ -23,6 +23,7 @@ pub mod generic_link;
 pub mod gsm;
 pub mod health_check;
 pub mod hyperswitch_ai_interaction;
+pub mod incoming_webhook_log;
 pub mod kafka_store;
 pub mod locker_mock_up;
 pub mod mandate;
@@ -109,6 +110,7 @@ pub trait StorageInterface:
     + ephemeral_key::EphemeralKeyInterface
     + ephemeral_key::ClientSecretInterface
     + events::EventInterface
+    + incoming_webhook_log::IncomingWebhookLogInterface
     + file::FileMetadataInterface
     + FraudCheckInterface
     + locker_mock_up::LockerMockUpInterface
@@ -138,6 +138,7 @@ where
         is_delivered: Option<bool>,
         merchant_key_store: &domain::MerchantKeyStore,
         event_recipient: Option<common_enums::EventRecipient>,
+        webhook_endpoint_id: Option<common_utils::id_type::WebhookEndpointId>,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError>;
 
     async fn list_initial_events_by_profile_id_primary_object_id(
@@ -182,6 +183,7 @@ where
         event_recipient: Option<common_enums::EventRecipient>,
     ) -> CustomResult<i64, errors::StorageError>;
 
+    #[allow(clippy::too_many_arguments)]
     async fn count_initial_events_by_profile_id_constraints(
         &self,
         profile_id: &common_utils::id_type::ProfileId,
@@ -190,6 +192,7 @@ where
         event_types: HashSet<common_enums::EventType>,
         is_delivered: Option<bool>,
         event_recipient: Option<common_enums::EventRecipient>,
+        webhook_endpoint_id: Option<common_utils::id_type::WebhookEndpointId>,
     ) -> CustomResult<i64, errors::StorageError>;
 
     #[allow(clippy::too_many_arguments)]
@@ -600,6 +603,7 @@ impl EventInterface for Store {
         is_delivered: Option<bool>,
         merchant_key_store: &domain::MerchantKeyStore,
         event_recipient: Option<common_enums::EventRecipient>,
+        webhook_endpoint_id: Option<common_utils::id_type::WebhookEndpointId>,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
         let conn = self
             .get_replica_pool()
@@ -616,6 +620,7 @@ impl EventInterface for Store {
             event_types,
             is_delivered,
             event_recipient,
+            webhook_endpoint_id,
         )
         .await
         .map_err(|error| report!(errors::StorageError::from(error)))
@@ -749,6 +754,7 @@ impl EventInterface for Store {
             .change_context(errors::StorageError::DecryptionError)
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn count_initial_events_by_profile_id_constraints(
         &self,
         profile_id: &common_utils::id_type::ProfileId,
@@ -757,6 +763,7 @@ impl EventInterface for Store {
         event_types: HashSet<common_enums::EventType>,
         is_delivered: Option<bool>,
         event_recipient: Option<common_enums::EventRecipient>,
+        webhook_endpoint_id: Option<common_utils::id_type::WebhookEndpointId>,
     ) -> CustomResult<i64, errors::StorageError> {
         let conn = self
             .get_replica_pool()
@@ -771,6 +778,7 @@ impl EventInterface for Store {
             event_types,
             is_delivered,
             event_recipient,
+            webhook_endpoint_id,
         )
         .await
         .map_err(|error| report!(errors::StorageError::from(error)))
@@ -1175,6 +1183,7 @@ impl EventInterface for MockDb {
         is_delivered: Option<bool>,
         merchant_key_store: &domain::MerchantKeyStore,
         event_recipient: Option<common_enums::EventRecipient>,
+        webhook_endpoint_id: Option<common_utils::id_type::WebhookEndpointId>,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
         let locked_events = self.events.lock().await;
         let events_iter = locked_events.iter().filter(|event| {
@@ -1184,7 +1193,9 @@ impl EventInterface for MockDb {
                 && (event.created_at <= created_before)
                 && (event_types.is_empty() || event_types.contains(&event.event_type))
                 && (event.is_overall_delivery_successful == is_delivered)
-                && (event_recipient.is_none() || event_recipient == event.recipient);
+                && (event_recipient.is_none() || event_recipient == event.recipient)
+                && (webhook_endpoint_id.is_none()
+                    || event.webhook_endpoint_id == webhook_endpoint_id);
 
             check
         });
@@ -1578,6 +1589,7 @@ impl EventInterface for MockDb {
             .change_context(errors::StorageError::DecryptionError)
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn count_initial_events_by_profile_id_constraints(
         &self,
         profile_id: &common_utils::id_type::ProfileId,
@@ -1586,6 +1598,7 @@ impl EventInterface for MockDb {
         event_types: HashSet<common_enums::EventType>,
         is_delivered: Option<bool>,
         event_recipient: Option<common_enums::EventRecipient>,
+        webhook_endpoint_id: Option<common_utils::id_type::WebhookEndpointId>,
     ) -> CustomResult<i64, errors::StorageError> {
         let locked_events = self.events.lock().await;
 
@@ -1596,7 +1609,9 @@ impl EventInterface for MockDb {
                 && (event.created_at <= created_before)
                 && (event_types.is_empty() || event_types.contains(&event.event_type))
                 && (event.is_overall_delivery_successful == is_delivered)
-                && (event_recipient.is_none() || event_recipient == event.recipient);
+                && (event_recipient.is_none() || event_recipient == event.recipient)
+                && (webhook_endpoint_id.is_none()
+                    || event.webhook_endpoint_id == webhook_endpoint_id);
 
             check
         });
@@ -1777,6 +1792,7 @@ mod tests {
                     processor_merchant_id: Some(merchant_id.to_owned()),
                     initiator_merchant_id: Some(merchant_id.to_owned()),
                     recipient: Some(enums::EventRecipient::Merchant),
+                    webhook_endpoint_id: None,
                 },
                 &merchant_key_store,
             )
@@ -1894,6 +1910,7 @@ mod tests {
                     processor_merchant_id: Some(merchant_id.to_owned()),
                     initiator_merchant_id: Some(merchant_id.to_owned()),
                     recipient: Some(enums::EventRecipient::Merchant),
+                    webhook_endpoint_id: None,
                 },
                 &merchant_key_store,
             )
@@ -2111,6 +2128,7 @@ mod tests {
             network_tokenization_credentials: None,
             payment_method_blocking: None,
             default_fallback_routing: None,
+            routing_timezone: None,
         });
 
         let business_profile = state
@@ -2148,6 +2166,7 @@ mod tests {
             initiator: None,
             sdk_authorization: None,
             applied_offer: None,
+            dcc_details: None,
             connector: None,
             customer: None,
             disputes: None,
@@ -1,3 +1,4 @@
+use async_bb8_diesel::AsyncConnection;
 use diesel_models::gsm as storage;
 use error_stack::{report, ResultExt};
 use router_env::{instrument, tracing};
@@ -31,6 +32,10 @@ pub trait GsmInterface {
         code: String,
         message: String,
     ) -> CustomResult<hyperswitch_domain_models::gsm::GatewayStatusMap, errors::StorageError>;
+    async fn list_gsm_rules(
+        &self,
+        connector: Option<String>,
+    ) -> CustomResult<Vec<hyperswitch_domain_models::gsm::GatewayStatusMap>, errors::StorageError>;
     async fn update_gsm_rule(
         &self,
         connector: String,
@@ -40,6 +45,13 @@ pub trait GsmInterface {
         message: String,
         data: hyperswitch_domain_models::gsm::GatewayStatusMappingUpdate,
     ) -> CustomResult<hyperswitch_domain_models::gsm::GatewayStatusMap, errors::StorageError>;
+    /// Creates the new rules and replaces the existing rules in a single transaction, so that
+    /// either all of the rules are written or none of them are
+    async fn apply_gsm_rules(
+        &self,
+        new_rules: Vec<hyperswitch_domain_models::gsm::GatewayStatusMap>,
+        updated_rules: Vec<hyperswitch_domain_models::gsm::GatewayStatusMap>,
+    ) -> CustomResult<(), errors::StorageError>;
 
     async fn delete_gsm_rule(
         &self,
@@ -108,6 +120,25 @@ impl GsmInterface for Store {
             .attach_printable("Failed to convert gsm diesel models to domain models")
     }
 
+    #[instrument(skip_all)]
+    async fn list_gsm_rules(
+        &self,
+        connector: Option<String>,
+    ) -> CustomResult<Vec<hyperswitch_domain_models::gsm::GatewayStatusMap>, errors::StorageError>
+    {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::GatewayStatusMap::list(&conn, connector)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))?
+            .into_iter()
+            .map(|gsm_db_record| {
+                hyperswitch_domain_models::gsm::GatewayStatusMap::try_from(gsm_db_record)
+                    .change_context(errors::StorageError::DeserializationFailed)
+                    .attach_printable("Failed to convert gsm diesel models to domain models")
+            })
+            .collect()
+    }
+
     #[instrument(skip_all)]
     async fn update_gsm_rule(
         &self,
@@ -138,6 +169,60 @@ impl GsmInterface for Store {
             .attach_printable("Failed to convert gsm diesel models to domain models")
     }
 
+    #[instrument(skip_all)]
+    async fn apply_gsm_rules(
+        &self,
+        new_rules: Vec<hyperswitch_domain_models::gsm::GatewayStatusMap>,
+        updated_rules: Vec<hyperswitch_domain_models::gsm::GatewayStatusMap>,
+    ) -> CustomResult<(), errors::StorageError> {
+        let new_rules = new_rules
+            .into_iter()
+            .map(diesel_models::gsm::GatewayStatusMappingNew::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .change_context(errors::StorageError::SerializationFailed)
+            .attach_printable("Failed to convert gsm domain models to diesel models")?;
+        let updated_rules = updated_rules
+            .into_iter()
+            .map(|rule| {
+                let key = (
+                    rule.connector.clone(),
+                    rule.flow.clone(),
+                    rule.sub_flow.clone(),
+                    rule.code.clone(),
+                    rule.message.clone(),
+                );
+                diesel_models::gsm::GatewayStatusMappingUpdate::try_from(
+                    hyperswitch_domain_models::gsm::GatewayStatusMappingUpdate::from(rule),
+                )
+                .map(|update| (key, update))
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .change_context(errors::StorageError::SerializationFailed)
+            .attach_printable("Failed to convert gsm domain models to diesel models")?;
+
+        let conn = connection::pg_connection_write(self).await?;
+        conn.transaction_async(|conn| async move {
+            for rule in new_rules {
+                rule.insert(&conn)
+                    .await
+                    .map_err(errors::StorageError::from)?;
+            }
+
+            for ((connector, flow, sub_flow, code, message), update) in updated_rules {
+                storage::GatewayStatusMap::update(
+                    &conn, connector, flow, sub_flow, code, message, update,
+                )
+                .await
+                .map_err(errors::StorageError::from)?;
+            }
+
+            Ok::<_, errors::StorageError>(())
+        })
+        .await?;
+
+        Ok(())
+    }
+
     #[instrument(skip_all)]
     async fn delete_gsm_rule(
         &self,
@@ -185,6 +270,14 @@ impl GsmInterface for MockDb {
         Err(errors::StorageError::MockDbError)?
     }
 
+    async fn list_gsm_rules(
+        &self,
+        _connector: Option<String>,
+    ) -> CustomResult<Vec<hyperswitch_domain_models::gsm::GatewayStatusMap>, errors::StorageError>
+    {
+        Err(errors::StorageError::MockDbError)?
+    }
+
     async fn update_gsm_rule(
         &self,
         _connector: String,
@@ -197,6 +290,14 @@ impl GsmInterface for MockDb {
         Err(errors::StorageError::MockDbError)?
     }
 
+    async fn apply_gsm_rules(
+        &self,
+        _new_rules: Vec<hyperswitch_domain_models::gsm::GatewayStatusMap>,
+        _updated_rules: Vec<hyperswitch_domain_models::gsm::GatewayStatusMap>,
+    ) -> CustomResult<(), errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
     async fn delete_gsm_rule(
         &self,
         _connector: String,
@@ -0,0 +1,340 @@
+use error_stack::{report, ResultExt};
+use hyperswitch_domain_models::behaviour::{Conversion, ReverseConversion};
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::domain;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    services::Store,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait IncomingWebhookLogInterface {
+    async fn insert_incoming_webhook_log(
+        &self,
+        incoming_webhook_log: domain::IncomingWebhookLog,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookLog, errors::StorageError>;
+
+    async fn find_incoming_webhook_log_by_merchant_id_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        id: &str,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookLog, errors::StorageError>;
+
+    async fn update_incoming_webhook_log_by_merchant_id_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        id: &str,
+        incoming_webhook_log_update: domain::IncomingWebhookLogUpdate,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookLog, errors::StorageError>;
+
+    #[allow(clippy::too_many_arguments)]
+    async fn list_incoming_webhook_logs_by_merchant_id_constraints(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+        connector: Option<String>,
+        status: Option<common_enums::IncomingWebhookStatus>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::IncomingWebhookLog>, errors::StorageError>;
+
+    async fn count_incoming_webhook_logs_by_merchant_id_constraints(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+        connector: Option<String>,
+        status: Option<common_enums::IncomingWebhookStatus>,
+    ) -> CustomResult<i64, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl IncomingWebhookLogInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_incoming_webhook_log(
+        &self,
+        incoming_webhook_log: domain::IncomingWebhookLog,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookLog, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        incoming_webhook_log
+            .construct_new()
+            .await
+            .change_context(errors::StorageError::EncryptionError)?
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))?
+            .convert(
+                self.get_keymanager_state()
+                    .attach_printable("Missing KeyManagerState")?,
+                merchant_key_store.key.get_inner(),
+                merchant_key_store.merchant_id.clone().into(),
+            )
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+    }
+
+    #[instrument(skip_all)]
+    async fn find_incoming_webhook_log_by_merchant_id_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        id: &str,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookLog, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::IncomingWebhookLog::find_by_merchant_id_id(&conn, merchant_id, id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))?
+            .convert(
+                self.get_keymanager_state()
+                    .attach_printable("Missing KeyManagerState")?,
+                merchant_key_store.key.get_inner(),
+                merchant_key_store.merchant_id.clone().into(),
+            )
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+    }
+
+    #[instrument(skip_all)]
+    async fn update_incoming_webhook_log_by_merchant_id_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        id: &str,
+        incoming_webhook_log_update: domain::IncomingWebhookLogUpdate,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookLog, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::IncomingWebhookLog::update_by_merchant_id_id(
+            &conn,
+            merchant_id,
+            id,
+            incoming_webhook_log_update.into(),
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))?
+        .convert(
+            self.get_keymanager_state()
+                .attach_printable("Missing KeyManagerState")?,
+            merchant_key_store.key.get_inner(),
+            merchant_key_store.merchant_id.clone().into(),
+        )
+        .await
+        .change_context(errors::StorageError::DecryptionError)
+    }
+
+    #[instrument(skip_all)]
+    async fn list_incoming_webhook_logs_by_merchant_id_constraints(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+        connector: Option<String>,
+        status: Option<common_enums::IncomingWebhookStatus>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::IncomingWebhookLog>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        let incoming_webhook_logs = storage::IncomingWebhookLog::list_by_merchant_id_constraints(
+            &conn,
+            merchant_id,
+            created_after,
+            created_before,
+            connector,
+            status,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))?;
+
+        let mut domain_incoming_webhook_logs = Vec::with_capacity(incoming_webhook_logs.len());
+        for incoming_webhook_log in incoming_webhook_logs.into_iter() {
+            domain_incoming_webhook_logs.push(
+                incoming_webhook_log
+                    .convert(
+                        self.get_keymanager_state()
+                            .attach_printable("Missing KeyManagerState")?,
+                        merchant_key_store.key.get_inner(),
+                        merchant_key_store.merchant_id.clone().into(),
+                    )
+                    .await
+                    .change_context(errors::StorageError::DecryptionError)?,
+            );
+        }
+
+        Ok(domain_incoming_webhook_logs)
+    }
+
+    #[instrument(skip_all)]
+    async fn count_incoming_webhook_logs_by_merchant_id_constraints(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+        connector: Option<String>,
+        status: Option<common_enums::IncomingWebhookStatus>,
+    ) -> CustomResult<i64, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::IncomingWebhookLog::count_by_merchant_id_constraints(
+            &conn,
+            merchant_id,
+            created_after,
+            created_before,
+            connector,
+            status,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl IncomingWebhookLogInterface for MockDb {
+    async fn insert_incoming_webhook_log(
+        &self,
+        _incoming_webhook_log: domain::IncomingWebhookLog,
+        _merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookLog, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_incoming_webhook_log_by_merchant_id_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _id: &str,
+        _merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookLog, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_incoming_webhook_log_by_merchant_id_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _id: &str,
+        _incoming_webhook_log_update: domain::IncomingWebhookLogUpdate,
+        _merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookLog, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_incoming_webhook_logs_by_merchant_id_constraints(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _created_after: time::PrimitiveDateTime,
+        _created_before: time::PrimitiveDateTime,
+        _connector: Option<String>,
+        _status: Option<common_enums::IncomingWebhookStatus>,
+        _limit: Option<i64>,
+        _offset: Option<i64>,
+        _merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::IncomingWebhookLog>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn count_incoming_webhook_logs_by_merchant_id_constraints(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _created_after: time::PrimitiveDateTime,
+        _created_before: time::PrimitiveDateTime,
+        _connector: Option<String>,
+        _status: Option<common_enums::IncomingWebhookStatus>,
+    ) -> CustomResult<i64, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl IncomingWebhookLogInterface for KafkaStore {
+    async fn insert_incoming_webhook_log(
+        &self,
+        incoming_webhook_log: domain::IncomingWebhookLog,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookLog, errors::StorageError> {
+        self.diesel_store
+            .insert_incoming_webhook_log(incoming_webhook_log, merchant_key_store)
+            .await
+    }
+
+    async fn find_incoming_webhook_log_by_merchant_id_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        id: &str,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookLog, errors::StorageError> {
+        self.diesel_store
+            .find_incoming_webhook_log_by_merchant_id_id(merchant_id, id, merchant_key_store)
+            .await
+    }
+
+    async fn update_incoming_webhook_log_by_merchant_id_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        id: &str,
+        incoming_webhook_log_update: domain::IncomingWebhookLogUpdate,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookLog, errors::StorageError> {
+        self.diesel_store
+            .update_incoming_webhook_log_by_merchant_id_id(
+                merchant_id,
+                id,
+                incoming_webhook_log_update,
+                merchant_key_store,
+            )
+            .await
+    }
+
+    async fn list_incoming_webhook_logs_by_merchant_id_constraints(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+        connector: Option<String>,
+        status: Option<common_enums::IncomingWebhookStatus>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::IncomingWebhookLog>, errors::StorageError> {
+        self.diesel_store
+            .list_incoming_webhook_logs_by_merchant_id_constraints(
+                merchant_id,
+                created_after,
+                created_before,
+                connector,
+                status,
+                limit,
+                offset,
+                merchant_key_store,
+            )
+            .await
+    }
+
+    async fn count_incoming_webhook_logs_by_merchant_id_constraints(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+        connector: Option<String>,
+        status: Option<common_enums::IncomingWebhookStatus>,
+    ) -> CustomResult<i64, errors::StorageError> {
+        self.diesel_store
+            .count_incoming_webhook_logs_by_merchant_id_constraints(
+                merchant_id,
+                created_after,
+                created_before,
+                connector,
+                status,
+            )
+            .await
+    }
+}
@@ -991,6 +991,7 @@ impl EventInterface for KafkaStore {
         is_delivered: Option<bool>,
         merchant_key_store: &domain::MerchantKeyStore,
         event_recipient: Option<common_enums::EventRecipient>,
+        webhook_endpoint_id: Option<id_type::WebhookEndpointId>,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
         self.diesel_store
             .list_initial_events_by_profile_id_constraints(
@@ -1003,6 +1004,7 @@ impl EventInterface for KafkaStore {
                 is_delivered,
                 merchant_key_store,
                 event_recipient,
+                webhook_endpoint_id,
             )
             .await
     }
@@ -1062,6 +1064,7 @@ impl EventInterface for KafkaStore {
             .await
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn count_initial_events_by_profile_id_constraints(
         &self,
         profile_id: &id_type::ProfileId,
@@ -1070,6 +1073,7 @@ impl EventInterface for KafkaStore {
         event_types: HashSet<common_enums::EventType>,
         is_delivered: Option<bool>,
         event_recipient: Option<common_enums::EventRecipient>,
+        webhook_endpoint_id: Option<id_type::WebhookEndpointId>,
     ) -> CustomResult<i64, errors::StorageError> {
         self.diesel_store
             .count_initial_events_by_profile_id_constraints(
@@ -1079,6 +1083,7 @@ impl EventInterface for KafkaStore {
                 event_types,
                 is_delivered,
                 event_recipient,
+                webhook_endpoint_id,
             )
             .await
     }
@@ -3564,6 +3569,14 @@ impl GsmInterface for KafkaStore {
             .await
     }
 
+    async fn list_gsm_rules(
+        &self,
+        connector: Option<String>,
+    ) -> CustomResult<Vec<hyperswitch_domain_models::gsm::GatewayStatusMap>, errors::StorageError>
+    {
+        self.diesel_store.list_gsm_rules(connector).await
+    }
+
     async fn update_gsm_rule(
         &self,
         connector: String,
@@ -3578,6 +3591,16 @@ impl GsmInterface for KafkaStore {
             .await
     }
 
+    async fn apply_gsm_rules(
+        &self,
+        new_rules: Vec<hyperswitch_domain_models::gsm::GatewayStatusMap>,
+        updated_rules: Vec<hyperswitch_domain_models::gsm::GatewayStatusMap>,
+    ) -> CustomResult<(), errors::StorageError> {
+        self.diesel_store
+            .apply_gsm_rules(new_rules, updated_rules)
+            .await
+    }
+
     async fn delete_gsm_rule(
         &self,
         connector: String,
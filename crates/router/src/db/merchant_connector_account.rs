@@ -248,6 +248,7 @@ mod merchant_connector_account_cache_tests {
             additional_merchant_data: None,
             version: common_types::consts::API_VERSION,
             connector_webhook_registration_details: None,
+            fee_schedule: None,
         };
 
         db.insert_merchant_connector_account(mca.clone(), &merchant_key)
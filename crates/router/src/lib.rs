@@ -134,6 +134,7 @@ pub mod headers {
     pub const CONTENT_TYPE: &str = "Content-Type";
     pub const DATE: &str = "Date";
     pub const IDEMPOTENCY_KEY: &str = "Idempotency-Key";
+    pub const IDEMPOTENT_REPLAYED: &str = "Idempotent-Replayed";
     pub const NONCE: &str = "nonce";
     pub const TIMESTAMP: &str = "Timestamp";
     pub const TOKEN: &str = "token";
@@ -152,6 +153,10 @@ pub mod headers {
     pub const X_DATE: &str = "X-Date";
     pub const X_WEBHOOK_SIGNATURE: &str = "X-Webhook-Signature-512";
     pub const X_REQUEST_ID: &str = "X-Request-Id";
+    // Lowercase as these are used to build static header names
+    pub const X_RATELIMIT_LIMIT: &str = "x-ratelimit-limit";
+    pub const X_RATELIMIT_REMAINING: &str = "x-ratelimit-remaining";
+    pub const X_RATELIMIT_RESET: &str = "x-ratelimit-reset";
     pub const X_PROFILE_ID: &str = "X-Profile-Id";
     pub const STRIPE_COMPATIBLE_WEBHOOK_SIGNATURE: &str = "Stripe-Signature";
     pub const STRIPE_COMPATIBLE_CONNECT_ACCOUNT: &str = "Stripe-Account";
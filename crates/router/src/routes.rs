@@ -46,6 +46,8 @@ pub mod poll;
 pub mod profile_acquirer;
 #[cfg(feature = "olap")]
 pub mod profiles;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod rate_limit;
 pub mod refunds;
 #[cfg(feature = "v2")]
 pub mod revenue_recovery_data_backfill;
@@ -70,6 +72,8 @@ pub mod verification;
 #[cfg(feature = "olap")]
 pub mod verify_connector;
 #[cfg(all(feature = "olap", feature = "v1"))]
+pub mod webhook_endpoints;
+#[cfg(all(feature = "olap", feature = "v1"))]
 pub mod webhook_events;
 pub mod webhooks;
 
@@ -54,6 +54,8 @@ use super::pm_auth;
 use super::poll;
 #[cfg(feature = "v2")]
 use super::proxy;
+#[cfg(all(feature = "olap", feature = "v1"))]
+use super::rate_limit;
 #[cfg(all(feature = "v2", feature = "revenue_recovery", feature = "oltp"))]
 use super::recovery_webhooks::*;
 #[cfg(all(feature = "oltp", feature = "v2"))]
@@ -102,6 +104,8 @@ use crate::routes::feature_matrix;
 use crate::routes::fraud_check as frm_routes;
 #[cfg(all(feature = "olap", feature = "v1"))]
 use crate::routes::profile_acquirer;
+#[cfg(all(feature = "olap", feature = "v1"))]
+use crate::routes::webhook_endpoints;
 pub use crate::{
     configs::settings,
     db::{
@@ -1100,6 +1104,9 @@ impl Payments {
                 .service(
                     web::resource("/{payment_id}/extend_authorization").route(web::post().to(payments::payments_extend_authorization)),
                 )
+                .service(
+                    web::resource("/{payment_id}/dcc_quote").route(web::post().to(payments::payments_dcc_quote)),
+                )
                 .service(
                     web::resource("/{payment_id}/{merchant_id}/authorize/{connector}")
                         .route(web::post().to(payments::post_3ds_payments_authorize))
@@ -1216,6 +1223,7 @@ impl Routing {
                     routing::routing_unlink_config(state, req, payload, None)
                 })),
             )
+            .service(web::resource("/canary").route(web::post().to(routing::routing_update_canary)))
             .service(
                 web::resource("/decision")
                     .route(web::put().to(routing::upsert_decision_manager_config))
@@ -1381,6 +1389,14 @@ impl Routing {
                     },
                 )),
             )
+            .service(
+                web::resource("/{algorithm_id}/backtest")
+                    .route(web::post().to(routing::routing_backtest_create)),
+            )
+            .service(
+                web::resource("/backtest/{backtest_id}")
+                    .route(web::get().to(routing::routing_backtest_retrieve)),
+            )
             .service(
                 web::resource("/rule/evaluate")
                     .route(web::post().to(routing::evaluate_routing_rule)),
@@ -2071,6 +2087,12 @@ impl MerchantAccount {
                     .route(web::post().to(admin::merchant_account_toggle_kv))
                     .route(web::get().to(admin::merchant_account_kv_status)),
             )
+            .service(
+                web::resource("/{id}/rate_limits")
+                    .route(web::post().to(rate_limit::upsert_rate_limit_config))
+                    .route(web::get().to(rate_limit::retrieve_rate_limit_config))
+                    .route(web::delete().to(rate_limit::delete_rate_limit_config)),
+            )
             .service(
                 web::resource("/transfer")
                     .route(web::post().to(admin::merchant_account_transfer_keys)),
@@ -2680,6 +2702,25 @@ impl Profile {
                 .service(
                     web::resource("/toggle_connector_agnostic_mit")
                         .route(web::post().to(profiles::toggle_connector_agnostic_mit)),
+                )
+                .service(
+                    web::resource("/webhook_endpoints")
+                        .route(web::post().to(webhook_endpoints::create_webhook_endpoint))
+                        .route(web::get().to(webhook_endpoints::list_webhook_endpoints)),
+                )
+                .service(
+                    web::resource("/webhook_endpoints/{webhook_endpoint_id}")
+                        .route(web::get().to(webhook_endpoints::retrieve_webhook_endpoint))
+                        .route(web::post().to(webhook_endpoints::update_webhook_endpoint))
+                        .route(web::delete().to(webhook_endpoints::delete_webhook_endpoint)),
+                )
+                .service(
+                    web::resource("/webhook_endpoints/{webhook_endpoint_id}/rotate_secret")
+                        .route(web::post().to(webhook_endpoints::rotate_webhook_endpoint_secret)),
+                )
+                .service(
+                    web::resource("/webhook_endpoints/{webhook_endpoint_id}/health")
+                        .route(web::get().to(webhook_endpoints::retrieve_webhook_endpoint_health)),
                 ),
         );
 
@@ -2719,6 +2760,13 @@ impl Gsm {
             .service(web::resource("/get").route(web::post().to(gsm::get_gsm_rule)))
             .service(web::resource("/update").route(web::post().to(gsm::update_gsm_rule)))
             .service(web::resource("/delete").route(web::post().to(gsm::delete_gsm_rule)))
+            .service(web::resource("/import").route(web::post().to(gsm::import_gsm_rules)))
+            .service(web::resource("/import/csv").route(web::post().to(gsm::import_gsm_rules_csv)))
+            .service(web::resource("/export").route(web::get().to(gsm::export_gsm_rules)))
+            .service(
+                web::resource("/unmapped_errors")
+                    .route(web::post().to(gsm::retrieve_unmapped_errors)),
+            )
     }
 }
 
@@ -2731,6 +2779,13 @@ impl Gsm {
             .service(web::resource("/get").route(web::post().to(gsm::get_gsm_rule)))
             .service(web::resource("/update").route(web::post().to(gsm::update_gsm_rule)))
             .service(web::resource("/delete").route(web::post().to(gsm::delete_gsm_rule)))
+            .service(web::resource("/import").route(web::post().to(gsm::import_gsm_rules)))
+            .service(web::resource("/import/csv").route(web::post().to(gsm::import_gsm_rules_csv)))
+            .service(web::resource("/export").route(web::get().to(gsm::export_gsm_rules)))
+            .service(
+                web::resource("/unmapped_errors")
+                    .route(web::post().to(gsm::retrieve_unmapped_errors)),
+            )
     }
 }
 pub struct Chat;
@@ -3317,6 +3372,27 @@ impl WebhookEvents {
                     .service(web::resource("").route(
                         web::post().to(webhook_events::list_initial_webhook_delivery_attempts),
                     ))
+                    .service(
+                        web::scope("/redeliver")
+                            .service(web::resource("").route(
+                                web::post().to(webhook_events::create_webhook_bulk_redelivery),
+                            ))
+                            .service(web::resource("/{redelivery_id}").route(
+                                web::get().to(webhook_events::retrieve_webhook_bulk_redelivery),
+                            )),
+                    )
+                    .service(
+                        web::scope("/incoming")
+                            .service(
+                                web::resource("/list")
+                                    .route(web::post().to(webhook_events::list_incoming_webhooks)),
+                            )
+                            .service(
+                                web::resource("/{incoming_webhook_id}/reprocess").route(
+                                    web::post().to(webhook_events::reprocess_incoming_webhook),
+                                ),
+                            ),
+                    )
                     .service(
                         web::scope("/{event_id}")
                             .service(web::resource("attempts").route(
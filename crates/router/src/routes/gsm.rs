@@ -1,4 +1,5 @@
-use actix_web::{web, HttpRequest, Responder};
+use actix_multipart::form::MultipartForm;
+use actix_web::{web, HttpRequest, HttpResponse, Responder};
 use api_models::gsm as gsm_api_types;
 use router_env::{instrument, tracing, Flow};
 
@@ -162,3 +163,138 @@ pub async fn delete_gsm_rule(
     ))
     .await
 }
+
+/// Gsm - Import
+///
+/// To create or update Gsm Rules in bulk
+#[utoipa::path(
+    post,
+    path = "/gsm/import",
+    request_body(
+        content = GsmBulkImportRequest,
+    ),
+    responses(
+        (status = 200, description = "Gsm rules imported", body = GsmBulkImportResponse),
+        (status = 400, description = "Missing Mandatory fields")
+    ),
+    tag = "Gsm",
+    operation_id = "Import Gsm Rules",
+    security(("admin_api_key" = [])),
+)]
+#[instrument(skip_all, fields(flow = ?Flow::GsmRulesImport))]
+pub async fn import_gsm_rules(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<gsm_api_types::GsmBulkImportRequest>,
+) -> impl Responder {
+    let payload = json_payload.into_inner();
+
+    let flow = Flow::GsmRulesImport;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| gsm::bulk::import_gsm_rules(state, payload),
+        &ADMIN_API_AUTH,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::GsmRulesImport))]
+pub async fn import_gsm_rules_csv(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    MultipartForm(form): MultipartForm<gsm::bulk::GsmBulkImportForm>,
+) -> HttpResponse {
+    let flow = Flow::GsmRulesImport;
+    let payload = match gsm::bulk::get_bulk_import_request(form) {
+        Ok(payload) => payload,
+        Err(e) => return api::log_and_return_error_response(e.into()),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| gsm::bulk::import_gsm_rules(state, payload),
+        &ADMIN_API_AUTH,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Gsm - Export
+///
+/// To export Gsm Rules in the format accepted by the import
+#[utoipa::path(
+    get,
+    path = "/gsm/export",
+    params(GsmExportRequest),
+    responses(
+        (status = 200, description = "Gsm rules exported", body = GsmExportResponse),
+    ),
+    tag = "Gsm",
+    operation_id = "Export Gsm Rules",
+    security(("admin_api_key" = [])),
+)]
+#[instrument(skip_all, fields(flow = ?Flow::GsmRulesExport))]
+pub async fn export_gsm_rules(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query_payload: web::Query<gsm_api_types::GsmExportRequest>,
+) -> impl Responder {
+    let payload = query_payload.into_inner();
+
+    let flow = Flow::GsmRulesExport;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| gsm::bulk::export_gsm_rules(state, payload),
+        &ADMIN_API_AUTH,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Gsm - Unmapped Errors
+///
+/// To list the connector errors seen in payments for which no Gsm Rule exists
+#[utoipa::path(
+    post,
+    path = "/gsm/unmapped_errors",
+    request_body(
+        content = GsmUnmappedErrorsRequest,
+    ),
+    responses(
+        (status = 200, description = "Unmapped errors retrieved", body = GsmUnmappedErrorsResponse),
+        (status = 400, description = "Missing Mandatory fields")
+    ),
+    tag = "Gsm",
+    operation_id = "Retrieve Unmapped Gsm Errors",
+    security(("admin_api_key" = [])),
+)]
+#[cfg(feature = "olap")]
+#[instrument(skip_all, fields(flow = ?Flow::GsmUnmappedErrorsRetrieve))]
+pub async fn retrieve_unmapped_errors(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<gsm_api_types::GsmUnmappedErrorsRequest>,
+) -> impl Responder {
+    let payload = json_payload.into_inner();
+
+    let flow = Flow::GsmUnmappedErrorsRetrieve;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| gsm::bulk::retrieve_unmapped_errors(state, payload),
+        &ADMIN_API_AUTH,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
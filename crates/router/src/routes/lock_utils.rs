@@ -1,6 +1,8 @@
-use router_env::Flow;
+use std::str::FromStr;
 
-#[derive(Clone, Debug, strum::Display)]
+use router_env::{types::FlowMetric, Flow};
+
+#[derive(Clone, Debug, strum::Display, strum::EnumString)]
 #[strum(serialize_all = "snake_case")]
 pub enum ApiIdentifier {
     Payments,
@@ -53,6 +55,15 @@ pub enum ApiIdentifier {
     RecoveryRecovery,
     Superposition,
     CardIssuers,
+    Idempotency,
+    RateLimit,
+}
+
+impl ApiIdentifier {
+    /// Flows which are not part of the router do not belong to any API identifier
+    pub fn from_flow_metric(flow: &impl FlowMetric) -> Option<Self> {
+        Flow::from_str(&flow.to_string()).ok().map(Self::from)
+    }
 }
 
 impl From<Flow> for ApiIdentifier {
@@ -72,7 +83,10 @@ impl From<Flow> for ApiIdentifier {
             Flow::RoutingCreateConfig
             | Flow::RoutingLinkConfig
             | Flow::RoutingUnlinkConfig
+            | Flow::RoutingUpdateCanary
             | Flow::RoutingRetrieveConfig
+            | Flow::RoutingBacktestCreate
+            | Flow::RoutingBacktestRetrieve
             | Flow::DecisionEngineSsoRedirect
             | Flow::RoutingRetrieveActiveConfig
             | Flow::RoutingRetrieveDefaultConfig
@@ -111,6 +125,9 @@ impl From<Flow> for ApiIdentifier {
             Flow::BatchBlocklistUpload => Self::Blocklist,
             Flow::GetBatchBlocklistJobStatus => Self::Blocklist,
             Flow::ListBatchBlocklistJobs => Self::Blocklist,
+            Flow::RateLimitConfigUpsert
+            | Flow::RateLimitConfigRetrieve
+            | Flow::RateLimitConfigDelete => Self::RateLimit,
             Flow::MerchantConnectorsCreate
             | Flow::MerchantConnectorsRetrieve
             | Flow::MerchantConnectorsUpdate
@@ -175,6 +192,7 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PaymentsRedirect
             | Flow::PaymentsIncrementalAuthorization
             | Flow::PaymentsExtendAuthorization
+            | Flow::PaymentsDccQuote
             | Flow::PaymentsExternalAuthentication
             | Flow::PaymentsAuthorize
             | Flow::GetExtendedCardInfo
@@ -228,6 +246,17 @@ impl From<Flow> for ApiIdentifier {
             | Flow::WebhookEventInitialDeliveryAttemptList
             | Flow::WebhookEventDeliveryAttemptList
             | Flow::WebhookEventDeliveryRetry
+            | Flow::WebhookEventBulkRedelivery
+            | Flow::WebhookEventBulkRedeliveryRetrieve
+            | Flow::IncomingWebhookList
+            | Flow::IncomingWebhookReprocess
+            | Flow::WebhookEndpointCreate
+            | Flow::WebhookEndpointRetrieve
+            | Flow::WebhookEndpointList
+            | Flow::WebhookEndpointUpdate
+            | Flow::WebhookEndpointDelete
+            | Flow::WebhookEndpointSecretRotate
+            | Flow::WebhookEndpointHealthRetrieve
             | Flow::RecoveryIncomingWebhookReceive
             | Flow::IncomingNetworkTokenWebhookReceive => Self::Webhooks,
             Flow::ApiKeyCreate
@@ -266,7 +295,10 @@ impl From<Flow> for ApiIdentifier {
             Flow::GsmRuleCreate
             | Flow::GsmRuleRetrieve
             | Flow::GsmRuleUpdate
-            | Flow::GsmRuleDelete => Self::Gsm,
+            | Flow::GsmRuleDelete
+            | Flow::GsmRulesImport
+            | Flow::GsmRulesExport
+            | Flow::GsmUnmappedErrorsRetrieve => Self::Gsm,
             Flow::ApplePayCertificatesMigration => Self::ApplePayCertificatesMigration,
             Flow::UserConnectAccount
             | Flow::UserSignUp
@@ -13,6 +13,7 @@ counter_metric!(KV_MISS, GLOBAL_METER); // No. of KV misses
 // API Level Metrics
 counter_metric!(REQUESTS_RECEIVED, GLOBAL_METER);
 histogram_metric_f64!(REQUEST_TIME, GLOBAL_METER);
+counter_metric!(RATE_LIMIT_CHECK_FAILURE_COUNT, GLOBAL_METER); // No. of requests let through as the rate limit could not be checked
 
 histogram_metric_f64!(
     PAYMENT_OPERATION_DURATION,
@@ -12,5 +12,8 @@ pub fn track_response_status_code<Q>(response: &ApplicationResponse<Q>) -> i64 {
         | ApplicationResponse::JsonWithHeaders(_)
         | ApplicationResponse::IncomingWebhookEvent { .. } => 200,
         ApplicationResponse::JsonForRedirection(_) => 302,
+        ApplicationResponse::IdempotentReplay(stored_response) => {
+            i64::from(stored_response.status_code)
+        }
     }
 }
@@ -0,0 +1,84 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::rate_limit as rate_limit_api;
+use common_utils::id_type;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, rate_limit},
+    services::{api, authentication as auth},
+};
+
+/// Rate Limits - Upsert
+///
+/// Replace the rate limit rules of the Merchant Account
+#[instrument(skip_all, fields(flow = ?Flow::RateLimitConfigUpsert))]
+pub async fn upsert_rate_limit_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<id_type::MerchantId>,
+    json_payload: web::Json<rate_limit_api::RateLimitConfigRequest>,
+) -> HttpResponse {
+    let flow = Flow::RateLimitConfigUpsert;
+    let mut payload = json_payload.into_inner();
+    payload.merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| rate_limit::upsert_rate_limit_config(state, payload),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Rate Limits - Retrieve
+///
+/// Retrieve the rate limit rules of the Merchant Account
+#[instrument(skip_all, fields(flow = ?Flow::RateLimitConfigRetrieve))]
+pub async fn retrieve_rate_limit_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<id_type::MerchantId>,
+) -> HttpResponse {
+    let flow = Flow::RateLimitConfigRetrieve;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        merchant_id,
+        |state, _, merchant_id, _| rate_limit::retrieve_rate_limit_config(state, merchant_id),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Rate Limits - Delete
+///
+/// Delete the rate limit rules of the Merchant Account
+#[instrument(skip_all, fields(flow = ?Flow::RateLimitConfigDelete))]
+pub async fn delete_rate_limit_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<id_type::MerchantId>,
+) -> HttpResponse {
+    let flow = Flow::RateLimitConfigDelete;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        merchant_id,
+        |state, _, merchant_id, _| rate_limit::delete_rate_limit_config(state, merchant_id),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
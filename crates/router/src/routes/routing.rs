@@ -178,6 +178,7 @@ pub async fn routing_link_config(
                 transaction_type
                     .or(json_payload.transaction_type)
                     .unwrap_or(enums::TransactionType::Payment),
+                json_payload.canary_traffic_percentage,
             )
         },
         auth::auth_type(
@@ -292,6 +293,91 @@ pub async fn routing_retrieve_config(
     .await
 }
 
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn routing_backtest_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::RoutingId>,
+    json_payload: web::Json<routing_types::RoutingBacktestRequest>,
+) -> impl Responder {
+    let flow = Flow::RoutingBacktestCreate;
+    let wrapper = routing_types::RoutingBacktestWrapper {
+        algorithm_id: path.into_inner(),
+        request: json_payload.into_inner(),
+    };
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        wrapper,
+        |state, auth: auth::AuthenticationData, wrapper, _| {
+            let profile_id = auth.profile.map(|profile| profile.get_id().clone());
+            routing::backtest::create_routing_backtest(
+                state,
+                auth.platform.get_processor().clone(),
+                profile_id,
+                wrapper.algorithm_id,
+                wrapper.request,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                allow_connected_scope_operation: true,
+                allow_platform_self_operation: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfileRoutingWrite,
+                allow_connected: true,
+                allow_platform: false,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn routing_backtest_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let backtest_id = path.into_inner();
+    let flow = Flow::RoutingBacktestRetrieve;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        backtest_id,
+        |state, auth: auth::AuthenticationData, backtest_id, _| {
+            let profile_id = auth.profile.map(|profile| profile.get_id().clone());
+            routing::backtest::retrieve_routing_backtest(
+                state,
+                auth.platform.get_processor().clone(),
+                profile_id,
+                backtest_id,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                allow_connected_scope_operation: true,
+                allow_platform_self_operation: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfileRoutingRead,
+                allow_connected: true,
+                allow_platform: false,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(all(feature = "olap", feature = "v2"))]
 #[instrument(skip_all)]
 pub async fn routing_retrieve_config(
@@ -514,6 +600,45 @@ pub async fn routing_unlink_config(
     .await
 }
 
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn routing_update_canary(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: web::Json<routing_types::RoutingCanaryRequest>,
+) -> impl Responder {
+    let flow = Flow::RoutingUpdateCanary;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload.into_inner(),
+        |state, auth: auth::AuthenticationData, payload_req, _| {
+            let profile_id = auth.profile.map(|profile| profile.get_id().clone());
+            routing::update_routing_canary(
+                state,
+                auth.platform.get_processor().clone(),
+                payload_req,
+                profile_id,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                allow_connected_scope_operation: true,
+                allow_platform_self_operation: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfileRoutingWrite,
+                allow_connected: true,
+                allow_platform: false,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(all(feature = "olap", feature = "v2"))]
 #[instrument(skip_all)]
 pub async fn routing_update_default_config(
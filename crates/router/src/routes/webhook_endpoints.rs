@@ -0,0 +1,321 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::webhook_endpoints::{
+    WebhookEndpointCreateRequest, WebhookEndpointSecretRotateRequest, WebhookEndpointUpdateRequest,
+};
+use router_env::{instrument, tracing, Flow};
+
+use crate::{
+    core::{api_locking, webhooks::webhook_endpoints},
+    routes::AppState,
+    services::{api, authentication as auth, authorization::permissions::Permission},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEndpointCreate))]
+pub async fn create_webhook_endpoint(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::ProfileId,
+    )>,
+    json_payload: web::Json<WebhookEndpointCreateRequest>,
+) -> impl Responder {
+    let flow = Flow::WebhookEndpointCreate;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth_data: auth::AuthenticationData, request, _| {
+            let processor = auth_data.platform.get_processor();
+            webhook_endpoints::create_webhook_endpoint(
+                state,
+                processor.get_account().get_id().clone(),
+                profile_id.clone(),
+                processor.get_key_store().clone(),
+                request,
+            )
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuthWithMerchantIdFromRouteAllowPlatform(merchant_id.clone()),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id: merchant_id.clone(),
+                profile_id: profile_id.clone(),
+                required_permission: Permission::ProfileWebhookEventWrite,
+                allow_connected: true,
+                allow_platform: true,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEndpointList))]
+pub async fn list_webhook_endpoints(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::ProfileId,
+    )>,
+) -> impl Responder {
+    let flow = Flow::WebhookEndpointList;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        profile_id.clone(),
+        |state, auth_data: auth::AuthenticationData, profile_id, _| {
+            let processor = auth_data.platform.get_processor();
+            webhook_endpoints::list_webhook_endpoints(
+                state,
+                processor.get_account().get_id().clone(),
+                profile_id,
+                processor.get_key_store().clone(),
+            )
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuthWithMerchantIdFromRouteAllowPlatform(merchant_id.clone()),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id: merchant_id.clone(),
+                profile_id,
+                required_permission: Permission::ProfileWebhookEventRead,
+                allow_connected: true,
+                allow_platform: true,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEndpointRetrieve))]
+pub async fn retrieve_webhook_endpoint(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::ProfileId,
+        common_utils::id_type::WebhookEndpointId,
+    )>,
+) -> impl Responder {
+    let flow = Flow::WebhookEndpointRetrieve;
+    let (merchant_id, profile_id, webhook_endpoint_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        webhook_endpoint_id,
+        |state, auth_data: auth::AuthenticationData, webhook_endpoint_id, _| {
+            let processor = auth_data.platform.get_processor();
+            webhook_endpoints::retrieve_webhook_endpoint(
+                state,
+                processor.get_account().get_id().clone(),
+                profile_id.clone(),
+                webhook_endpoint_id,
+                processor.get_key_store().clone(),
+            )
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuthWithMerchantIdFromRouteAllowPlatform(merchant_id.clone()),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id: merchant_id.clone(),
+                profile_id: profile_id.clone(),
+                required_permission: Permission::ProfileWebhookEventRead,
+                allow_connected: true,
+                allow_platform: true,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEndpointUpdate))]
+pub async fn update_webhook_endpoint(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::ProfileId,
+        common_utils::id_type::WebhookEndpointId,
+    )>,
+    json_payload: web::Json<WebhookEndpointUpdateRequest>,
+) -> impl Responder {
+    let flow = Flow::WebhookEndpointUpdate;
+    let (merchant_id, profile_id, webhook_endpoint_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth_data: auth::AuthenticationData, request, _| {
+            let processor = auth_data.platform.get_processor();
+            webhook_endpoints::update_webhook_endpoint(
+                state,
+                processor.get_account().get_id().clone(),
+                profile_id.clone(),
+                webhook_endpoint_id.clone(),
+                processor.get_key_store().clone(),
+                request,
+            )
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuthWithMerchantIdFromRouteAllowPlatform(merchant_id.clone()),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id: merchant_id.clone(),
+                profile_id: profile_id.clone(),
+                required_permission: Permission::ProfileWebhookEventWrite,
+                allow_connected: true,
+                allow_platform: true,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEndpointSecretRotate))]
+pub async fn rotate_webhook_endpoint_secret(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::ProfileId,
+        common_utils::id_type::WebhookEndpointId,
+    )>,
+    json_payload: web::Json<WebhookEndpointSecretRotateRequest>,
+) -> impl Responder {
+    let flow = Flow::WebhookEndpointSecretRotate;
+    let (merchant_id, profile_id, webhook_endpoint_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth_data: auth::AuthenticationData, request, _| {
+            let processor = auth_data.platform.get_processor();
+            webhook_endpoints::rotate_webhook_endpoint_secret(
+                state,
+                processor.get_account().get_id().clone(),
+                profile_id.clone(),
+                webhook_endpoint_id.clone(),
+                processor.get_key_store().clone(),
+                request,
+            )
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuthWithMerchantIdFromRouteAllowPlatform(merchant_id.clone()),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id: merchant_id.clone(),
+                profile_id: profile_id.clone(),
+                required_permission: Permission::ProfileWebhookEventWrite,
+                allow_connected: true,
+                allow_platform: true,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEndpointDelete))]
+pub async fn delete_webhook_endpoint(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::ProfileId,
+        common_utils::id_type::WebhookEndpointId,
+    )>,
+) -> impl Responder {
+    let flow = Flow::WebhookEndpointDelete;
+    let (merchant_id, profile_id, webhook_endpoint_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        webhook_endpoint_id,
+        |state, auth_data: auth::AuthenticationData, webhook_endpoint_id, _| {
+            let processor = auth_data.platform.get_processor();
+            webhook_endpoints::delete_webhook_endpoint(
+                state,
+                processor.get_account().get_id().clone(),
+                profile_id.clone(),
+                webhook_endpoint_id,
+                processor.get_key_store().clone(),
+            )
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuthWithMerchantIdFromRouteAllowPlatform(merchant_id.clone()),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id: merchant_id.clone(),
+                profile_id: profile_id.clone(),
+                required_permission: Permission::ProfileWebhookEventWrite,
+                allow_connected: true,
+                allow_platform: true,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEndpointHealthRetrieve))]
+pub async fn retrieve_webhook_endpoint_health(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::ProfileId,
+        common_utils::id_type::WebhookEndpointId,
+    )>,
+) -> impl Responder {
+    let flow = Flow::WebhookEndpointHealthRetrieve;
+    let (merchant_id, profile_id, webhook_endpoint_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        webhook_endpoint_id,
+        |state, auth_data: auth::AuthenticationData, webhook_endpoint_id, _| {
+            let processor = auth_data.platform.get_processor();
+            webhook_endpoints::retrieve_webhook_endpoint_health(
+                state,
+                processor.get_account().get_id().clone(),
+                profile_id.clone(),
+                webhook_endpoint_id,
+                processor.get_key_store().clone(),
+            )
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuthWithMerchantIdFromRouteAllowPlatform(merchant_id.clone()),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id: merchant_id.clone(),
+                profile_id: profile_id.clone(),
+                required_permission: Permission::ProfileWebhookEventRead,
+                allow_connected: true,
+                allow_platform: true,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -1,6 +1,8 @@
 use actix_web::{web, HttpRequest, Responder};
 use router_env::{instrument, tracing, Flow};
 
+#[cfg(feature = "v1")]
+use crate::{core::api_locking::GetLockingInput, routes::lock_utils};
 use crate::{
     core::{api_locking, webhooks::webhook_events},
     routes::AppState,
@@ -10,7 +12,10 @@ use crate::{
         authorization::permissions::Permission,
     },
     types::api::webhook_events::{
-        EventListConstraints, EventListRequestInternal, WebhookDeliveryAttemptListRequestInternal,
+        EventListConstraints, EventListRequestInternal, EventsBulkRedeliveryRequest,
+        EventsBulkRedeliveryRequestInternal, EventsBulkRedeliveryRetrieveRequestInternal,
+        IncomingWebhookListConstraints, IncomingWebhookListRequestInternal,
+        IncomingWebhookReprocessRequestInternal, WebhookDeliveryAttemptListRequestInternal,
         WebhookDeliveryRetryRequestInternal,
     },
 };
@@ -176,3 +181,195 @@ pub async fn retry_webhook_delivery_attempt(
     ))
     .await
 }
+
+#[cfg(feature = "v1")]
+impl GetLockingInput for EventsBulkRedeliveryRequestInternal {
+    fn get_locking_input<F>(&self, flow: F) -> api_locking::LockAction
+    where
+        F: router_env::types::FlowMetric,
+        lock_utils::ApiIdentifier: From<F>,
+    {
+        api_locking::LockAction::Hold {
+            input: api_locking::LockingInput {
+                unique_locking_key: self.request.profile_id.get_string_repr().to_owned(),
+                api_identifier: lock_utils::ApiIdentifier::from(flow),
+                override_lock_retries: None,
+            },
+        }
+    }
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventBulkRedelivery))]
+#[cfg(feature = "v1")]
+pub async fn create_webhook_bulk_redelivery(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    json_payload: web::Json<EventsBulkRedeliveryRequest>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventBulkRedelivery;
+    let merchant_id = path.into_inner();
+
+    let request_internal = EventsBulkRedeliveryRequestInternal {
+        merchant_id: merchant_id.clone(),
+        request: json_payload.into_inner(),
+    };
+    let locking_action = request_internal.get_locking_input(flow.clone());
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _: (), request_internal, _| {
+            crate::core::webhooks::bulk_redelivery::create_bulk_redelivery(
+                state,
+                request_internal.merchant_id,
+                request_internal.request,
+            )
+        },
+        &auth::JWTAuthMerchantFromRoute {
+            merchant_id,
+            required_permission: Permission::MerchantWebhookEventWrite,
+            allow_connected: true,
+            allow_platform: true,
+        },
+        locking_action,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventBulkRedeliveryRetrieve))]
+#[cfg(feature = "v1")]
+pub async fn retrieve_webhook_bulk_redelivery(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(common_utils::id_type::MerchantId, String)>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventBulkRedeliveryRetrieve;
+    let (merchant_id, redelivery_id) = path.into_inner();
+
+    let request_internal = EventsBulkRedeliveryRetrieveRequestInternal {
+        merchant_id: merchant_id.clone(),
+        redelivery_id,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _: (), request_internal, _| {
+            crate::core::webhooks::bulk_redelivery::retrieve_bulk_redelivery(
+                state,
+                request_internal.merchant_id,
+                request_internal.redelivery_id,
+            )
+        },
+        &auth::JWTAuthMerchantFromRoute {
+            merchant_id,
+            required_permission: Permission::MerchantWebhookEventRead,
+            allow_connected: true,
+            allow_platform: true,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::IncomingWebhookList))]
+pub async fn list_incoming_webhooks(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    json_payload: web::Json<IncomingWebhookListConstraints>,
+) -> impl Responder {
+    let flow = Flow::IncomingWebhookList;
+    let merchant_id = path.into_inner();
+
+    let request_internal = IncomingWebhookListRequestInternal {
+        merchant_id: merchant_id.clone(),
+        constraints: json_payload.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _: (), request_internal, _| {
+            webhook_events::list_incoming_webhooks(
+                state,
+                request_internal.merchant_id,
+                request_internal.constraints,
+            )
+        },
+        &auth::JWTAuthMerchantFromRoute {
+            merchant_id,
+            required_permission: Permission::MerchantWebhookEventRead,
+            allow_connected: true,
+            allow_platform: true,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+impl GetLockingInput for IncomingWebhookReprocessRequestInternal {
+    fn get_locking_input<F>(&self, flow: F) -> api_locking::LockAction
+    where
+        F: router_env::types::FlowMetric,
+        lock_utils::ApiIdentifier: From<F>,
+    {
+        api_locking::LockAction::Hold {
+            input: api_locking::LockingInput {
+                unique_locking_key: self.incoming_webhook_id.clone(),
+                api_identifier: lock_utils::ApiIdentifier::from(flow),
+                override_lock_retries: None,
+            },
+        }
+    }
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::IncomingWebhookReprocess))]
+#[cfg(feature = "v1")]
+pub async fn reprocess_incoming_webhook(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(common_utils::id_type::MerchantId, String)>,
+) -> impl Responder {
+    let flow = Flow::IncomingWebhookReprocess;
+    let (merchant_id, incoming_webhook_id) = path.into_inner();
+
+    let request_internal = IncomingWebhookReprocessRequestInternal {
+        merchant_id: merchant_id.clone(),
+        incoming_webhook_id,
+    };
+    let locking_action = request_internal.get_locking_input(flow.clone());
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _: (), request_internal, req_state| {
+            crate::core::webhooks::incoming::reprocess_incoming_webhook::<
+                api_models::webhooks::OutgoingWebhook,
+            >(
+                state,
+                req_state,
+                request_internal.merchant_id,
+                request_internal.incoming_webhook_id,
+            )
+        },
+        &auth::JWTAuthMerchantFromRoute {
+            merchant_id,
+            required_permission: Permission::MerchantWebhookEventWrite,
+            allow_connected: true,
+            allow_platform: true,
+        },
+        locking_action,
+    ))
+    .await
+}
@@ -28,7 +28,7 @@ use hyperswitch_domain_models::router_data_v2::flow_common_types as common_types
 pub use hyperswitch_domain_models::{
     api::{
         ApplicationResponse, GenericExpiredLinkData, GenericLinkFormData, GenericLinkStatusData,
-        GenericLinks, PaymentLinkAction, RedirectionFormData, WebhookResponse,
+        GenericLinks, PaymentLinkAction, RedirectionFormData, StoredResponse, WebhookResponse,
     },
     payment_method_data::PaymentMethodData,
     router_response_types::RedirectForm,
@@ -53,7 +53,7 @@ use router_env::{instrument, tracing, RequestId, Tag};
 use serde::Serialize;
 
 use super::{
-    authentication::AuthenticateAndFetch,
+    authentication::AuthenticateAndFetch, authorization,
     connector_integration_interface::BoxedConnectorIntegrationInterface,
 };
 use crate::{
@@ -61,6 +61,7 @@ use crate::{
     core::{
         api_locking,
         errors::{self, CustomResult},
+        idempotency, rate_limit,
     },
     events::api_logs::{ApiEvent, ApiEventMetric, ApiEventsType},
     headers, logger,
@@ -171,6 +172,7 @@ pub async fn server_wrap_util<'a, 'b, U, T, Q, F, Fut, E, OErr>(
     func: F,
     api_auth: &dyn AuthenticateAndFetch<U, SessionState>,
     lock_action: api_locking::LockAction,
+    idempotency_action: idempotency::IdempotencyAction,
 ) -> CustomResult<ApplicationResponse<Q>, OErr>
 where
     F: Fn(SessionState, U, T, ReqState) -> Fut,
@@ -265,15 +267,81 @@ where
 
     tracing::Span::current().record("merchant_id", merchant_id.get_string_repr().to_owned());
 
+    authorization::check_api_key_permissions(flow, request.method(), &auth_type).switch()?;
+
+    // Requests are not throttled if the rate limit cannot be checked
+    match rate_limit::check_rate_limit(&session_state, flow, &auth_type).await {
+        Ok(Some(rate_limit_status)) => {
+            let is_limited = rate_limit_status.is_limited();
+            request.extensions_mut().insert(rate_limit_status);
+            if is_limited {
+                return Err(Report::new(
+                    errors::ApiErrorResponse::RateLimitExceeded.switch(),
+                ));
+            }
+        }
+        Ok(None) => {}
+        Err(error) => {
+            metrics::RATE_LIMIT_CHECK_FAILURE_COUNT.add(
+                1,
+                router_env::metric_attributes!(
+                    ("flow", flow.to_string()),
+                    ("merchant_id", merchant_id.get_string_repr().to_owned()),
+                ),
+            );
+            logger::error!(
+                ?error,
+                flow = %flow,
+                "Failed to check rate limit, allowing the request"
+            );
+        }
+    }
+
+    let idempotency_input = idempotency_action
+        .get_idempotency_input(flow, request, &payload)
+        .switch()?;
+    let lock_action = match idempotency_input.as_ref() {
+        Some(input) => lock_action.with_additional_input(input.get_locking_input()),
+        None => lock_action,
+    };
+
     let output = {
         lock_action
             .clone()
             .perform_locking_action(&session_state, merchant_id.to_owned())
             .await
             .switch()?;
-        let res = func(session_state.clone(), auth_out, payload, request_state)
-            .await
-            .switch();
+        let stored_response = match idempotency_input.as_ref() {
+            Some(input) => {
+                input
+                    .get_stored_response(&session_state, &merchant_id)
+                    .await
+            }
+            None => Ok(None),
+        };
+        let res = match stored_response {
+            Ok(Some(stored_response)) => Ok(ApplicationResponse::IdempotentReplay(Box::new(
+                stored_response,
+            ))),
+            Ok(None) => {
+                let res = func(session_state.clone(), auth_out, payload, request_state)
+                    .await
+                    .switch();
+                if let (Some(input), Ok(response)) = (idempotency_input.as_ref(), res.as_ref()) {
+                    // The operation has already been performed, failing the request here would
+                    // only make the client retry it
+                    input
+                        .store_response(&session_state, &merchant_id, response)
+                        .await
+                        .inspect_err(|error| {
+                            logger::error!(?error, "Failed to store idempotent response")
+                        })
+                        .ok();
+                }
+                res
+            }
+            Err(error) => Err(error).switch(),
+        };
         lock_action
             .free_lock_action(&session_state, merchant_id.to_owned())
             .await
@@ -440,6 +508,9 @@ where
         tag = ?Tag::BeginRequest, payload = ?payload,
     headers = ?incoming_header_to_log);
 
+    let idempotency_action =
+        idempotency::IdempotencyAction::from_request(request, &state.conf.idempotency);
+
     let server_wrap_util_res = server_wrap_util(
         &flow,
         state.clone(),
@@ -449,6 +520,7 @@ where
         func,
         api_auth,
         lock_action,
+        idempotency_action,
     )
     .await
     .map(|response| {
@@ -590,8 +662,12 @@ where
             }
         }
         Ok(ApplicationResponse::IncomingWebhookEvent { .. }) => http_response_ok(),
+        Ok(ApplicationResponse::IdempotentReplay(stored_response)) => {
+            http_response_idempotent_replay(*stored_response)
+        }
         Err(error) => log_and_return_error_response(error),
     };
+    let res = add_rate_limit_headers(request, res);
 
     let response_code = res.status().as_u16();
     tracing::Span::current().record("status_code", response_code);
@@ -619,6 +695,24 @@ pub fn http_response_json<T: body::MessageBody + 'static>(response: T) -> HttpRe
         .body(response)
 }
 
+pub fn http_response_idempotent_replay(stored_response: StoredResponse) -> HttpResponse {
+    HttpResponse::build(
+        http::StatusCode::from_u16(stored_response.status_code).unwrap_or(http::StatusCode::OK),
+    )
+    .content_type(mime::APPLICATION_JSON)
+    .insert_header((headers::IDEMPOTENT_REPLAYED, "true"))
+    .body(stored_response.body.peek().to_string())
+}
+
+pub fn add_rate_limit_headers(request: &HttpRequest, mut response: HttpResponse) -> HttpResponse {
+    if let Some(rate_limit_status) = request.extensions().get::<rate_limit::RateLimitStatus>() {
+        for (header_name, header_value) in rate_limit_status.get_headers() {
+            response.headers_mut().insert(header_name, header_value);
+        }
+    }
+    response
+}
+
 pub fn http_server_error_json_response<T: body::MessageBody + 'static>(
     response: T,
 ) -> HttpResponse {
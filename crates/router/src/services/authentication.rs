@@ -11,6 +11,7 @@ use api_models::payouts;
 use async_trait::async_trait;
 use base64::Engine;
 use common_enums::{MerchantAccountType, TokenPurpose};
+use common_types::api_keys::ApiKeyPermissions;
 use common_utils::{date_time, fp_utils, id_type};
 #[cfg(feature = "v2")]
 use diesel_models::ephemeral_key;
@@ -148,6 +149,8 @@ pub enum AuthenticationType {
     ApiKey {
         merchant_id: id_type::MerchantId,
         key_id: id_type::ApiKeyId,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        permissions: Option<ApiKeyPermissions>,
     },
     AdminApiKey,
     AdminApiAuthWithMerchantId {
@@ -222,6 +225,7 @@ impl AuthenticationType {
             Self::ApiKey {
                 merchant_id,
                 key_id: _,
+                permissions: _,
             }
             | Self::AdminApiAuthWithMerchantId { merchant_id }
             | Self::MerchantId { merchant_id }
@@ -645,6 +649,8 @@ where
                 .attach_printable("API key has expired");
         }
 
+        get_profile_id_for_api_key(Some(profile_id.clone()), stored_api_key.profile_id.as_ref())?;
+
         let key_store = state
             .store()
             .get_merchant_key_store_by_merchant_id(
@@ -701,6 +707,7 @@ where
             AuthenticationType::ApiKey {
                 merchant_id: initiator_merchant.get_id().clone(),
                 key_id: stored_api_key.key_id,
+                permissions: stored_api_key.permissions,
             },
         ))
     }
@@ -768,6 +775,8 @@ where
                     field_name: "X-Profile-Id",
                 })
                 .change_context(errors::ApiErrorResponse::Unauthorized)?;
+        let profile_id =
+            get_profile_id_for_api_key(profile_id, stored_api_key.profile_id.as_ref())?;
 
         let initiator_merchant = state
             .store()
@@ -824,6 +833,7 @@ where
             AuthenticationType::ApiKey {
                 merchant_id: initiator_merchant.get_id().clone(),
                 key_id: stored_api_key.key_id,
+                permissions: stored_api_key.permissions,
             },
         ))
     }
@@ -996,6 +1006,8 @@ where
                 .attach_printable("API key has expired");
         }
 
+        reject_profile_restricted_api_key(stored_api_key.profile_id.as_ref())?;
+
         let key_store = state
             .store()
             .get_merchant_key_store_by_merchant_id(
@@ -1035,6 +1047,7 @@ where
             AuthenticationType::ApiKey {
                 merchant_id: merchant_account.get_id().clone(),
                 key_id: stored_api_key.key_id,
+                permissions: stored_api_key.permissions,
             },
         ))
     }
@@ -1081,6 +1094,8 @@ where
                 .attach_printable("API key has expired");
         }
 
+        reject_profile_restricted_api_key(stored_api_key.profile_id.as_ref())?;
+
         let key_store = state
             .store()
             .get_merchant_key_store_by_merchant_id(
@@ -1138,6 +1153,7 @@ where
             AuthenticationType::ApiKey {
                 merchant_id: initiator_merchant_account.get_id().clone(),
                 key_id: stored_api_key.key_id,
+                permissions: stored_api_key.permissions,
             },
         ))
     }
@@ -1237,6 +1253,8 @@ where
                 .attach_printable("API key has expired");
         }
 
+        reject_profile_restricted_api_key(stored_api_key.profile_id.as_ref())?;
+
         let (platform_key_store, platform_merchant) =
             Self::fetch_key_store_and_account(&stored_api_key.merchant_id, state).await?;
 
@@ -1278,6 +1296,7 @@ where
             AuthenticationType::ApiKey {
                 merchant_id: platform_merchant.get_id().clone(),
                 key_id: stored_api_key.key_id,
+                permissions: stored_api_key.permissions,
             },
         ))
     }
@@ -1355,6 +1374,17 @@ where
                     merchant_id: Some(merchant_id),
                     key_id: Some(key_id),
                 } => {
+                    let stored_api_key = state
+                        .store()
+                        .find_api_key_by_merchant_id_key_id_optional(&merchant_id, &key_id)
+                        .await
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Failed to retrieve API key")?
+                        .ok_or(report!(errors::ApiErrorResponse::Unauthorized))
+                        .attach_printable("API key not found")?;
+                    let profile_id =
+                        get_profile_id_for_api_key(profile_id, stored_api_key.profile_id.as_ref())?;
+
                     let auth = construct_authentication_data(
                         state,
                         &merchant_id,
@@ -1369,6 +1399,7 @@ where
                         AuthenticationType::ApiKey {
                             merchant_id: merchant_id.clone(),
                             key_id,
+                            permissions: stored_api_key.permissions,
                         },
                     ))
                 }
@@ -1997,6 +2028,8 @@ where
                 .attach_printable("API key has expired");
         }
 
+        reject_profile_restricted_api_key(stored_api_key.profile_id.as_ref())?;
+
         let key_store = state
             .store()
             .get_merchant_key_store_by_merchant_id(
@@ -2037,6 +2070,7 @@ where
                 AuthenticationType::ApiKey {
                     merchant_id: stored_api_key.merchant_id,
                     key_id: stored_api_key.key_id,
+                    permissions: stored_api_key.permissions,
                 },
             ));
         }
@@ -2148,6 +2182,8 @@ where
                 .attach_printable("API key has expired");
         }
 
+        reject_profile_restricted_api_key(stored_api_key.profile_id.as_ref())?;
+
         if fallback_merchant_ids
             .merchant_ids
             .contains(&stored_api_key.merchant_id)
@@ -3032,6 +3068,8 @@ where
                 .attach_printable("API key has expired");
         }
 
+        get_profile_id_for_api_key(Some(profile_id.clone()), stored_api_key.profile_id.as_ref())?;
+
         let key_store = state
             .store()
             .get_merchant_key_store_by_merchant_id(
@@ -3092,6 +3130,7 @@ where
             AuthenticationType::ApiKey {
                 merchant_id: initiator_merchant.get_id().clone(),
                 key_id: stored_api_key.key_id,
+                permissions: stored_api_key.permissions,
             },
         ))
     }
@@ -6224,6 +6263,42 @@ where
         })
 }
 
+/// Restricted API keys can only be used for the profile they were created for, requests which do
+/// not specify a profile are scoped to that profile.
+fn get_profile_id_for_api_key(
+    requested_profile_id: Option<id_type::ProfileId>,
+    restricted_profile_id: Option<&id_type::ProfileId>,
+) -> RouterResult<Option<id_type::ProfileId>> {
+    match (requested_profile_id, restricted_profile_id) {
+        (Some(requested_profile_id), Some(restricted_profile_id))
+            if &requested_profile_id != restricted_profile_id =>
+        {
+            Err(report!(errors::ApiErrorResponse::AccessForbidden {
+                resource: requested_profile_id.get_string_repr().to_owned(),
+            }))
+            .attach_printable("API key is restricted to a different profile")
+        }
+        (requested_profile_id, restricted_profile_id) => {
+            Ok(requested_profile_id.or_else(|| restricted_profile_id.cloned()))
+        }
+    }
+}
+
+/// Organization wide operations cannot be scoped to a single profile, so they are not allowed
+/// with API keys restricted to a profile.
+#[cfg(feature = "v1")]
+fn reject_profile_restricted_api_key(
+    restricted_profile_id: Option<&id_type::ProfileId>,
+) -> RouterResult<()> {
+    match restricted_profile_id {
+        Some(restricted_profile_id) => Err(report!(errors::ApiErrorResponse::AccessForbidden {
+            resource: restricted_profile_id.get_string_repr().to_owned(),
+        }))
+        .attach_printable("API key restricted to a profile used for an organization operation"),
+        None => Ok(()),
+    }
+}
+
 pub fn get_api_key(headers: &HeaderMap) -> RouterResult<&str> {
     get_header_value_by_key("api-key".into(), headers)?.get_required_value("api_key")
 }
@@ -6727,3 +6802,62 @@ impl ExternalToken {
         )?)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::borrow::Cow;
+
+    use super::*;
+
+    fn get_profile_id(profile_id: &'static str) -> id_type::ProfileId {
+        id_type::ProfileId::try_from(Cow::from(profile_id)).unwrap()
+    }
+
+    #[test]
+    fn test_unrestricted_api_key_uses_requested_profile() {
+        assert_eq!(get_profile_id_for_api_key(None, None).unwrap(), None);
+        assert_eq!(
+            get_profile_id_for_api_key(Some(get_profile_id("pro_1")), None).unwrap(),
+            Some(get_profile_id("pro_1"))
+        );
+    }
+
+    #[test]
+    fn test_restricted_api_key_is_scoped_to_its_profile() {
+        let restricted_profile_id = get_profile_id("pro_1");
+        assert_eq!(
+            get_profile_id_for_api_key(None, Some(&restricted_profile_id)).unwrap(),
+            Some(restricted_profile_id.clone())
+        );
+        assert_eq!(
+            get_profile_id_for_api_key(Some(get_profile_id("pro_1")), Some(&restricted_profile_id))
+                .unwrap(),
+            Some(restricted_profile_id.clone())
+        );
+    }
+
+    #[test]
+    fn test_restricted_api_key_is_rejected_for_other_profile() {
+        let error = get_profile_id_for_api_key(
+            Some(get_profile_id("pro_2")),
+            Some(&get_profile_id("pro_1")),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::AccessForbidden { .. }
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "v1")]
+    fn test_restricted_api_key_is_rejected_for_organization_operations() {
+        assert!(reject_profile_restricted_api_key(None).is_ok());
+        let error = reject_profile_restricted_api_key(Some(&get_profile_id("pro_1"))).unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::AccessForbidden { .. }
+        ));
+    }
+}
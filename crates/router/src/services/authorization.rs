@@ -1,13 +1,14 @@
+use common_enums::{PermissionScope, Resource};
 use common_utils::id_type;
 use error_stack::ResultExt;
 use redis_interface::RedisConnectionWithContext;
-use router_env::logger;
+use router_env::{logger, types::FlowMetric};
 
-use super::authentication::AuthToken;
+use super::authentication::{AuthToken, AuthenticationType};
 use crate::{
     consts,
     core::errors::{ApiErrorResponse, RouterResult, StorageErrorExt},
-    routes::app::SessionStateInfo,
+    routes::{app::SessionStateInfo, lock_utils::ApiIdentifier},
 };
 
 #[cfg(feature = "olap")]
@@ -143,6 +144,94 @@ pub fn check_tenant(
     Ok(())
 }
 
+/// Restricted API keys can only access the resources they were granted permissions on, with
+/// requests using safe methods requiring `read` access and all other requests `write` access.
+pub fn check_api_key_permissions(
+    flow: &impl FlowMetric,
+    method: &http::Method,
+    auth_type: &AuthenticationType,
+) -> RouterResult<()> {
+    let AuthenticationType::ApiKey {
+        permissions: Some(permissions),
+        ..
+    } = auth_type
+    else {
+        return Ok(());
+    };
+
+    let scope = if method.is_safe() {
+        PermissionScope::Read
+    } else {
+        PermissionScope::Write
+    };
+
+    ApiIdentifier::from_flow_metric(flow)
+        .and_then(get_api_key_resource)
+        .is_some_and(|resource| permissions.is_permitted(resource, scope))
+        .then_some(())
+        .ok_or(ApiErrorResponse::AccessForbidden {
+            resource: flow.to_string(),
+        })
+        .attach_printable("API key does not have the permissions required for this flow")
+}
+
+/// Resource guarding the APIs of each identifier, APIs without a resource can only be accessed
+/// with unrestricted API keys
+fn get_api_key_resource(api_identifier: ApiIdentifier) -> Option<Resource> {
+    match api_identifier {
+        ApiIdentifier::Payments | ApiIdentifier::PaymentLink => Some(Resource::Payment),
+        ApiIdentifier::Refunds => Some(Resource::Refund),
+        ApiIdentifier::Disputes => Some(Resource::Dispute),
+        ApiIdentifier::Mandates => Some(Resource::Mandate),
+        ApiIdentifier::Customers | ApiIdentifier::PaymentMethods | ApiIdentifier::Ephemeral => {
+            Some(Resource::Customer)
+        }
+        ApiIdentifier::Payouts => Some(Resource::Payout),
+        ApiIdentifier::ApiKeys => Some(Resource::ApiKey),
+        ApiIdentifier::MerchantAccount | ApiIdentifier::Profile => Some(Resource::Account),
+        ApiIdentifier::MerchantConnector => Some(Resource::Connector),
+        ApiIdentifier::Routing => Some(Resource::Routing),
+        ApiIdentifier::Subscription => Some(Resource::Subscription),
+        ApiIdentifier::ThreeDsDecisionRule => Some(Resource::ThreeDsDecisionManager),
+        ApiIdentifier::Webhooks => Some(Resource::WebhookEvent),
+        ApiIdentifier::Organization
+        | ApiIdentifier::MerchantConnectorWebhookManagement
+        | ApiIdentifier::Configs
+        | ApiIdentifier::Health
+        | ApiIdentifier::PaymentMethodAuth
+        | ApiIdentifier::CardsInfo
+        | ApiIdentifier::Files
+        | ApiIdentifier::Cache
+        | ApiIdentifier::Verification
+        | ApiIdentifier::Blocklist
+        | ApiIdentifier::Forex
+        | ApiIdentifier::RustLockerMigration
+        | ApiIdentifier::Gsm
+        | ApiIdentifier::Role
+        | ApiIdentifier::User
+        | ApiIdentifier::UserRole
+        | ApiIdentifier::ConnectorOnboarding
+        | ApiIdentifier::AiWorkflow
+        | ApiIdentifier::Poll
+        | ApiIdentifier::ApplePayCertificatesMigration
+        | ApiIdentifier::Relay
+        | ApiIdentifier::Documentation
+        | ApiIdentifier::CardNetworkTokenization
+        | ApiIdentifier::Hypersense
+        | ApiIdentifier::PaymentMethodSession
+        | ApiIdentifier::ProcessTracker
+        | ApiIdentifier::Authentication
+        | ApiIdentifier::Proxy
+        | ApiIdentifier::ProfileAcquirer
+        | ApiIdentifier::GenericTokenization
+        | ApiIdentifier::RecoveryRecovery
+        | ApiIdentifier::Superposition
+        | ApiIdentifier::CardIssuers
+        | ApiIdentifier::Idempotency
+        | ApiIdentifier::RateLimit => None,
+    }
+}
+
 fn get_redis_connection_for_global_tenant<A: SessionStateInfo>(
     state: &A,
 ) -> RouterResult<RedisConnectionWithContext> {
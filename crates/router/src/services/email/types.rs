@@ -81,6 +81,17 @@ pub enum EmailBody {
         api_key_name: String,
         prefix: String,
     },
+    ReportGenerated {
+        report_id: String,
+        report_type: String,
+        download_url: String,
+    },
+    WebhookEndpointPaused {
+        webhook_endpoint_id: String,
+        profile_id: String,
+        webhook_url: String,
+        consecutive_failures: u32,
+    },
     WelcomeToCommunity,
     RoleDeleted {
         user_name: String,
@@ -250,6 +261,28 @@ Email         : {user_email}
                 prefix = prefix,
                 expires_in = expires_in,
             ),
+            EmailBody::ReportGenerated {
+                report_id,
+                report_type,
+                download_url,
+            } => format!(
+                include_str!("assets/report_generated.html"),
+                report_id = report_id,
+                report_type = report_type,
+                download_url = download_url,
+            ),
+            EmailBody::WebhookEndpointPaused {
+                webhook_endpoint_id,
+                profile_id,
+                webhook_url,
+                consecutive_failures,
+            } => format!(
+                include_str!("assets/webhook_endpoint_paused.html"),
+                webhook_endpoint_id = webhook_endpoint_id,
+                profile_id = profile_id,
+                webhook_url = webhook_url,
+                consecutive_failures = consecutive_failures,
+            ),
             EmailBody::WelcomeToCommunity => {
                 include_str!("assets/welcome_to_community.html").to_string()
             }
@@ -707,6 +740,62 @@ impl EmailData for ApiKeyExpiryReminder {
     }
 }
 
+pub struct ReportGenerated {
+    pub recipient_email: domain::UserEmail,
+    pub subject: &'static str,
+    pub report_id: String,
+    pub report_type: String,
+    pub download_url: String,
+}
+
+#[async_trait::async_trait]
+impl EmailData for ReportGenerated {
+    async fn get_email_data(&self, _base_url: &str) -> CustomResult<EmailContents, EmailError> {
+        let recipient = self.recipient_email.clone().into_inner();
+
+        let body = html::get_html_body(EmailBody::ReportGenerated {
+            report_id: self.report_id.clone(),
+            report_type: self.report_type.clone(),
+            download_url: self.download_url.clone(),
+        });
+
+        Ok(EmailContents {
+            subject: self.subject.to_string(),
+            body: external_services::email::IntermediateString::new(body),
+            recipient,
+        })
+    }
+}
+
+pub struct WebhookEndpointPaused {
+    pub recipient_email: domain::UserEmail,
+    pub subject: &'static str,
+    pub webhook_endpoint_id: String,
+    pub profile_id: String,
+    pub webhook_url: String,
+    pub consecutive_failures: u32,
+}
+
+#[async_trait::async_trait]
+impl EmailData for WebhookEndpointPaused {
+    async fn get_email_data(&self, _base_url: &str) -> CustomResult<EmailContents, EmailError> {
+        let recipient = self.recipient_email.clone().into_inner();
+
+        let body = html::get_html_body(EmailBody::WebhookEndpointPaused {
+            webhook_endpoint_id: self.webhook_endpoint_id.clone(),
+            profile_id: self.profile_id.clone(),
+            webhook_url: self.webhook_url.clone(),
+            consecutive_failures: self.consecutive_failures,
+        });
+
+        Ok(EmailContents {
+            subject: self.subject.to_string(),
+            body: external_services::email::IntermediateString::new(body),
+            recipient,
+        })
+    }
+}
+
 pub struct WelcomeToCommunity {
     pub recipient_email: domain::UserEmail,
 }
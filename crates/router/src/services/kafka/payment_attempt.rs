@@ -70,6 +70,9 @@ pub struct KafkaPaymentAttempt<'a> {
     pub card_network: Option<String>,
     pub card_discovery: Option<String>,
     pub routing_approach: Option<storage_enums::RoutingApproach>,
+    pub routing_algorithm_id: Option<&'a id_type::RoutingId>,
+    pub expected_processing_fee: Option<MinorUnit>,
+    pub dcc_details: Option<&'a common_types::payments::DccDetails>,
     pub debit_routing_savings: Option<MinorUnit>,
     pub signature_network: Option<common_enums::CardNetwork>,
     pub is_issuer_regulated: Option<bool>,
@@ -140,6 +143,9 @@ impl<'a> KafkaPaymentAttempt<'a> {
                 .card_discovery
                 .map(|discovery| discovery.to_string()),
             routing_approach: attempt.routing_approach.clone(),
+            routing_algorithm_id: attempt.routing_algorithm_id.as_ref(),
+            expected_processing_fee: attempt.expected_processing_fee,
+            dcc_details: attempt.dcc_details.as_ref(),
             debit_routing_savings: attempt.debit_routing_savings,
             signature_network: card_payment_method_data
                 .as_ref()
@@ -289,6 +289,7 @@ impl ForeignTryFrom<domain::Profile> for ProfileResponse {
             is_l2_l3_enabled: Some(item.is_l2_l3_enabled),
             network_tokenization_credentials,
             payment_method_blocking: item.payment_method_blocking.map(ForeignInto::foreign_into),
+            routing_timezone: item.routing_timezone,
             surcharge_connector_details: item
                 .surcharge_connector_details
                 .map(ForeignFrom::foreign_from),
@@ -571,6 +572,7 @@ pub async fn create_profile_from_merchant_account(
         network_tokenization_credentials: None, // since credentials are at merchant level, they should not be in the profile, tracked in issue #15134
         payment_method_blocking: None,
         default_fallback_routing: None,
+        routing_timezone: None,
         surcharge_connector_details: request
             .surcharge_connector_details
             .map(ForeignInto::foreign_into),
@@ -1,6 +1,11 @@
 pub use api_models::webhook_events::{
     EventListConstraints, EventListConstraintsInternal, EventListItemResponse,
-    EventListRequestInternal, EventRetrieveResponse, OutgoingWebhookRequestContent,
-    OutgoingWebhookResponseContent, TotalEventsResponse, WebhookDeliveryAttemptListRequestInternal,
+    EventListRequestInternal, EventRetrieveResponse, EventsBulkRedeliveryRequest,
+    EventsBulkRedeliveryRequestInternal, EventsBulkRedeliveryResponse,
+    EventsBulkRedeliveryRetrieveRequestInternal, EventsBulkRedeliveryStatus,
+    IncomingWebhookListConstraints, IncomingWebhookListRequestInternal,
+    IncomingWebhookReprocessRequestInternal, IncomingWebhookRequestContent,
+    IncomingWebhookResponse, OutgoingWebhookRequestContent, OutgoingWebhookResponseContent,
+    TotalEventsResponse, TotalIncomingWebhooksResponse, WebhookDeliveryAttemptListRequestInternal,
     WebhookDeliveryRetryRequestInternal,
 };
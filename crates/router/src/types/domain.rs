@@ -45,6 +45,7 @@ pub use merchant_account::*;
 
 mod address;
 mod event;
+mod incoming_webhook_log;
 mod merchant_connector_account;
 mod merchant_key_store {
     pub use hyperswitch_domain_models::merchant_key_store::MerchantKeyStore;
@@ -94,6 +95,7 @@ pub use business_profile::*;
 pub use callback_mapper::*;
 pub use consts::*;
 pub use event::*;
+pub use incoming_webhook_log::*;
 pub use merchant_connector_account::*;
 pub use merchant_connector_webhook_management::*;
 pub use merchant_key_store::*;
@@ -81,6 +81,10 @@ pub struct Event {
 
     /// The intended recipient of the webhook event.
     pub recipient: Option<EventRecipient>,
+
+    /// The webhook endpoint of the business profile to which the event is delivered. Events
+    /// without an endpoint are delivered to the webhook URL configured on the business profile.
+    pub webhook_endpoint_id: Option<common_utils::id_type::WebhookEndpointId>,
 }
 
 /// The API that is asking for this event's delivery-success value.
@@ -167,6 +171,7 @@ impl super::behaviour::Conversion for Event {
             processor_merchant_id: self.processor_merchant_id,
             initiator_merchant_id: self.initiator_merchant_id,
             recipient: self.recipient,
+            webhook_endpoint_id: self.webhook_endpoint_id,
         })
     }
 
@@ -220,6 +225,7 @@ impl super::behaviour::Conversion for Event {
             processor_merchant_id: item.processor_merchant_id,
             initiator_merchant_id: item.initiator_merchant_id,
             recipient: item.recipient,
+            webhook_endpoint_id: item.webhook_endpoint_id,
         })
     }
 
@@ -245,6 +251,7 @@ impl super::behaviour::Conversion for Event {
             processor_merchant_id: self.processor_merchant_id,
             initiator_merchant_id: self.initiator_merchant_id,
             recipient: self.recipient,
+            webhook_endpoint_id: self.webhook_endpoint_id,
         })
     }
 }
@@ -0,0 +1,174 @@
+use common_utils::{
+    crypto::Encryptable,
+    date_time, type_name,
+    types::keymanager::{Identifier, KeyManagerState},
+};
+use diesel_models::{
+    enums::IncomingWebhookStatus, incoming_webhook_log::IncomingWebhookLogUpdateInternal,
+};
+use error_stack::ResultExt;
+use hyperswitch_masking::{PeekInterface, Secret};
+
+use crate::{
+    errors::{CustomResult, ValidationError},
+    types::domain::types,
+};
+
+#[derive(Clone, Debug)]
+pub struct IncomingWebhookLog {
+    /// A string that uniquely identifies the incoming webhook.
+    pub id: String,
+
+    /// Merchant Account identifier for which the webhook was received.
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// The connector name or merchant connector account ID the webhook was received for, as
+    /// present in the webhook URL. Used to process the webhook again.
+    pub connector_name_or_mca_id: String,
+
+    /// The name of the connector that sent the webhook, once resolved.
+    pub connector: Option<String>,
+
+    /// The merchant connector account the webhook was processed with.
+    pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+
+    /// Indicates whether the webhook was received on the relay webhook URL.
+    pub is_relay_webhook: bool,
+
+    /// The encrypted method, URI, headers and body of the webhook.
+    pub request: Encryptable<Secret<String>>,
+
+    /// Indicates whether the source of the webhook was verified.
+    pub source_verified: Option<bool>,
+
+    /// The outcome of processing the webhook.
+    pub status: IncomingWebhookStatus,
+
+    /// The error that occurred while processing the webhook, if any.
+    pub error_message: Option<String>,
+
+    /// The timestamp when the webhook was received.
+    pub created_at: time::PrimitiveDateTime,
+
+    /// The timestamp when the webhook was last processed.
+    pub modified_at: time::PrimitiveDateTime,
+
+    /// The reason the webhook was rejected, if it was.
+    pub rejection_reason: Option<IncomingWebhookRejectionReason>,
+}
+
+#[derive(Debug)]
+pub enum IncomingWebhookLogUpdate {
+    ProcessingUpdate {
+        connector: Option<String>,
+        merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+        source_verified: Option<bool>,
+        status: IncomingWebhookStatus,
+        error_message: Option<String>,
+        rejection_reason: Option<IncomingWebhookRejectionReason>,
+    },
+}
+
+impl From<IncomingWebhookLogUpdate> for IncomingWebhookLogUpdateInternal {
+    fn from(incoming_webhook_log_update: IncomingWebhookLogUpdate) -> Self {
+        match incoming_webhook_log_update {
+            IncomingWebhookLogUpdate::ProcessingUpdate {
+                connector,
+                merchant_connector_id,
+                source_verified,
+                status,
+                error_message,
+                rejection_reason,
+            } => Self {
+                connector,
+                merchant_connector_id,
+                source_verified,
+                status: Some(status),
+                error_message: Some(error_message),
+                modified_at: date_time::now(),
+                rejection_reason: Some(rejection_reason),
+            },
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl super::behaviour::Conversion for IncomingWebhookLog {
+    type DstType = diesel_models::incoming_webhook_log::IncomingWebhookLog;
+    type NewDstType = diesel_models::incoming_webhook_log::IncomingWebhookLogNew;
+
+    async fn convert(self) -> CustomResult<Self::DstType, ValidationError> {
+        Ok(diesel_models::incoming_webhook_log::IncomingWebhookLog {
+            id: self.id,
+            merchant_id: self.merchant_id,
+            connector_name_or_mca_id: self.connector_name_or_mca_id,
+            connector: self.connector,
+            merchant_connector_id: self.merchant_connector_id,
+            is_relay_webhook: self.is_relay_webhook,
+            request: self.request.into(),
+            source_verified: self.source_verified,
+            status: self.status,
+            error_message: self.error_message,
+            created_at: self.created_at,
+            modified_at: self.modified_at,
+            rejection_reason: self.rejection_reason,
+        })
+    }
+
+    async fn convert_back(
+        state: &KeyManagerState,
+        item: Self::DstType,
+        key: &Secret<Vec<u8>>,
+        key_manager_identifier: Identifier,
+    ) -> CustomResult<Self, ValidationError>
+    where
+        Self: Sized,
+    {
+        let request = types::crypto_operation(
+            state,
+            type_name!(Self::DstType),
+            types::CryptoOperation::Decrypt(item.request),
+            key_manager_identifier,
+            key.peek(),
+        )
+        .await
+        .and_then(|val| val.try_into_operation())
+        .change_context(ValidationError::InvalidValue {
+            message: "Failed while decrypting incoming webhook data".to_string(),
+        })?;
+
+        Ok(Self {
+            id: item.id,
+            merchant_id: item.merchant_id,
+            connector_name_or_mca_id: item.connector_name_or_mca_id,
+            connector: item.connector,
+            merchant_connector_id: item.merchant_connector_id,
+            is_relay_webhook: item.is_relay_webhook,
+            request,
+            source_verified: item.source_verified,
+            status: item.status,
+            error_message: item.error_message,
+            created_at: item.created_at,
+            modified_at: item.modified_at,
+            rejection_reason: item.rejection_reason,
+        })
+    }
+
+    async fn construct_new(self) -> CustomResult<Self::NewDstType, ValidationError> {
+        Ok(diesel_models::incoming_webhook_log::IncomingWebhookLogNew {
+            id: self.id,
+            merchant_id: self.merchant_id,
+            connector_name_or_mca_id: self.connector_name_or_mca_id,
+            connector: self.connector,
+            merchant_connector_id: self.merchant_connector_id,
+            is_relay_webhook: self.is_relay_webhook,
+            request: self.request.into(),
+            source_verified: self.source_verified,
+            status: self.status,
+            error_message: self.error_message,
+            created_at: self.created_at,
+            modified_at: self.modified_at,
+            rejection_reason: self.rejection_reason,
+        })
+    }
+}
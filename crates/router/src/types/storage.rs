@@ -24,6 +24,7 @@ pub mod fraud_check;
 pub mod generic_link;
 pub mod gsm;
 pub mod hyperswitch_ai_interaction;
+pub mod incoming_webhook_log;
 #[cfg(feature = "kv_store")]
 pub mod kv;
 pub mod locker_mock_up;
@@ -76,7 +77,7 @@ pub use self::{
     callback_mapper::*, capture::*, card_issuer::*, cards_info::*, configs::*, customers::*,
     dashboard_metadata::*, dispute::*, dynamic_routing_stats::*, ephemeral_key::*, events::*,
     file::*, fraud_check::*, generic_link::*, gsm::*, hyperswitch_ai_interaction::*,
-    locker_mock_up::*, mandate::*, merchant_account::*, merchant_connector_account::*,
+    incoming_webhook_log::*, locker_mock_up::*, mandate::*, merchant_account::*, merchant_connector_account::*,
     merchant_key_store::*, payment_link::*, payment_method::*, process_tracker::*, refund::*,
     reverse_lookup::*, role::*, routing_algorithm::*, unified_translations::*, user::*,
     user_authentication_method::*, user_role::*,
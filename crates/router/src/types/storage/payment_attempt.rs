@@ -230,6 +230,10 @@ mod tests {
             created_by: None,
             setup_future_usage_applied: Default::default(),
             routing_approach: Default::default(),
+            routing_algorithm_id: Default::default(),
+            expected_processing_fee: Default::default(),
+            expected_authorization_expiry: Default::default(),
+            dcc_details: Default::default(),
             connector_request_reference_id: Default::default(),
             network_transaction_id: Default::default(),
             network_transaction_link_id: Default::default(),
@@ -355,6 +359,10 @@ mod tests {
             created_by: None,
             setup_future_usage_applied: Default::default(),
             routing_approach: Default::default(),
+            routing_algorithm_id: Default::default(),
+            expected_processing_fee: Default::default(),
+            expected_authorization_expiry: Default::default(),
+            dcc_details: Default::default(),
             connector_request_reference_id: Default::default(),
             network_transaction_id: Default::default(),
             network_transaction_link_id: Default::default(),
@@ -494,6 +502,10 @@ mod tests {
             created_by: None,
             setup_future_usage_applied: Default::default(),
             routing_approach: Default::default(),
+            routing_algorithm_id: Default::default(),
+            expected_processing_fee: Default::default(),
+            expected_authorization_expiry: Default::default(),
+            dcc_details: Default::default(),
             connector_request_reference_id: Default::default(),
             network_transaction_id: Default::default(),
             network_transaction_link_id: Default::default(),
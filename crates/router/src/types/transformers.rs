@@ -454,6 +454,45 @@ impl ForeignTryFrom<payments::PaymentMethodData> for api_enums::PaymentMethod {
     }
 }
 
+impl ForeignTryFrom<api_models::webhooks::IncomingWebhookEvent> for storage_enums::AttemptStatus {
+    type Error = errors::ValidationError;
+
+    fn foreign_try_from(
+        value: api_models::webhooks::IncomingWebhookEvent,
+    ) -> Result<Self, Self::Error> {
+        match value {
+            api_models::webhooks::IncomingWebhookEvent::PaymentIntentSuccess
+            | api_models::webhooks::IncomingWebhookEvent::PaymentIntentCaptureSuccess => {
+                Ok(Self::Charged)
+            }
+            api_models::webhooks::IncomingWebhookEvent::PaymentIntentProcessing => {
+                Ok(Self::Pending)
+            }
+            api_models::webhooks::IncomingWebhookEvent::PaymentIntentAuthorizationSuccess => {
+                Ok(Self::Authorized)
+            }
+            api_models::webhooks::IncomingWebhookEvent::PaymentIntentFailure => Ok(Self::Failure),
+            api_models::webhooks::IncomingWebhookEvent::PaymentIntentAuthorizationFailure => {
+                Ok(Self::AuthorizationFailed)
+            }
+            api_models::webhooks::IncomingWebhookEvent::PaymentIntentCaptureFailure => {
+                Ok(Self::CaptureFailed)
+            }
+            api_models::webhooks::IncomingWebhookEvent::PaymentIntentCancelled => Ok(Self::Voided),
+            api_models::webhooks::IncomingWebhookEvent::PaymentIntentCancelFailure => {
+                Ok(Self::VoidFailed)
+            }
+            api_models::webhooks::IncomingWebhookEvent::PaymentIntentExpired => Ok(Self::Expired),
+            api_models::webhooks::IncomingWebhookEvent::PaymentActionRequired => {
+                Ok(Self::AuthenticationPending)
+            }
+            _ => Err(errors::ValidationError::IncorrectValueProvided {
+                field_name: "incoming_webhook_event_type",
+            }),
+        }
+    }
+}
+
 impl ForeignTryFrom<api_models::webhooks::IncomingWebhookEvent> for storage_enums::RefundStatus {
     type Error = errors::ValidationError;
 
@@ -664,6 +703,8 @@ impl
             api_key: StrongSecret::from(plaintext_api_key.peek().to_owned()),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            permissions: api_key.permissions,
+            profile_id: api_key.profile_id,
         }
     }
 }
@@ -678,6 +719,8 @@ impl ForeignFrom<diesel_models::api_keys::ApiKey> for api_models::api_keys::Retr
             prefix: api_key.prefix.into(),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            permissions: api_key.permissions,
+            profile_id: api_key.profile_id,
         }
     }
 }
@@ -1263,6 +1306,7 @@ impl ForeignTryFrom<domain::MerchantConnectorAccount>
                 })
                 .transpose()?,
             webhook_setup_capabilities: None,
+            fee_schedule: item.fee_schedule,
         };
         Ok(response)
     }
@@ -2152,12 +2196,13 @@ impl ForeignTryFrom<api_types::webhook_events::EventListConstraints>
                 || item.limit.is_some()
                 || item.offset.is_some()
                 || item.event_classes.is_some()
-                || item.event_types.is_some())
+                || item.event_types.is_some()
+                || item.webhook_endpoint_id.is_some())
         {
             return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
                 message:
                      "Either `object_id` alone, or `event_id` alone, or one or more of \
-                                `created_after`, `created_before`, `limit`, `offset`, `event_classes` and `event_types` must be specified"
+                                `created_after`, `created_before`, `limit`, `offset`, `event_classes`, `event_types` and `webhook_endpoint_id` must be specified"
                         .to_string()
             }));
         }
@@ -2179,6 +2224,7 @@ impl ForeignTryFrom<api_types::webhook_events::EventListConstraints>
                 event_types: item.event_types,
                 is_delivered: item.is_delivered,
                 recipient: item.recipient,
+                webhook_endpoint_id: item.webhook_endpoint_id,
             }),
 
             (Some(_), Some(_)) => Err(report!(errors::ApiErrorResponse::PreconditionFailed {
@@ -2227,6 +2273,7 @@ impl TryFrom<domain::EventWithDeliverySuccessSource>
             is_delivery_successful,
             initial_attempt_id,
             processor_merchant_id: item.processor_merchant_id,
+            webhook_endpoint_id: item.webhook_endpoint_id,
             created: item.created_at,
         })
     }
@@ -2274,6 +2321,27 @@ impl TryFrom<domain::EventWithDeliverySuccessSource>
     }
 }
 
+#[cfg(feature = "olap")]
+impl ForeignFrom<domain::IncomingWebhookLog>
+    for api_models::webhook_events::IncomingWebhookResponse
+{
+    fn foreign_from(item: domain::IncomingWebhookLog) -> Self {
+        Self {
+            incoming_webhook_id: item.id,
+            merchant_id: item.merchant_id,
+            connector: item.connector,
+            merchant_connector_id: item.merchant_connector_id,
+            is_relay_webhook: item.is_relay_webhook,
+            source_verified: item.source_verified,
+            status: item.status,
+            error_message: item.error_message,
+            rejection_reason: item.rejection_reason,
+            created: item.created_at,
+            modified: item.modified_at,
+        }
+    }
+}
+
 impl ForeignFrom<api_models::admin::AuthenticationConnectorDetails>
     for diesel_models::business_profile::AuthenticationConnectorDetails
 {
@@ -459,5 +459,6 @@ pub async fn build_cloned_connector_create_request(
         connector_wallets_details: source_mca.connector_wallets_details,
         status: Some(source_mca.status),
         additional_merchant_data: source_mca.additional_merchant_data,
+        fee_schedule: source_mca.fee_schedule,
     })
 }
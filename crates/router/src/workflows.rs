@@ -2,6 +2,11 @@
 pub mod api_key_expiry;
 #[cfg(feature = "payouts")]
 pub mod attach_payout_account_workflow;
+pub mod authorization_expiry;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod outgoing_webhook_bulk_redelivery;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod outgoing_webhook_endpoint_flush;
 pub mod outgoing_webhook_retry;
 pub mod payment_method_modular_backward_compat;
 pub mod payment_method_modular_forward_compat;
@@ -11,6 +16,17 @@ pub mod post_capture_void_sync;
 
 pub mod refund_router;
 
+#[cfg(feature = "olap")]
+pub mod report_generation;
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod routing_backtest;
+
+pub mod scheduled_capture;
+
+#[cfg(feature = "v2")]
+pub mod split_payment_reversal;
+
 pub mod tokenized_data;
 
 pub mod revenue_recovery;
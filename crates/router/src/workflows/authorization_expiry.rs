@@ -0,0 +1,332 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors,
+};
+
+#[cfg(feature = "v1")]
+use crate::core::payments::authorization_expiry::{self, AuthorizationExpiryAction};
+use crate::{
+    core::{
+        configs,
+        payments::{self as payment_flows, operations},
+    },
+    db::StorageInterface,
+    errors,
+    routes::SessionState,
+    services,
+    types::{
+        api, domain,
+        storage::{self, enums},
+    },
+};
+
+pub struct AuthorizationExpiryWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for AuthorizationExpiryWorkflow {
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        todo!()
+    }
+
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: api::PaymentsAuthorizationExpiryTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("PaymentsAuthorizationExpiryTrackingData")?;
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_processor_merchant_id(
+                &tracking_data.payment_id,
+                &tracking_data.merchant_id,
+                &key_store,
+                merchant_account.storage_scheme,
+            )
+            .await?;
+
+        // The payment was captured, cancelled or has expired in the meantime
+        if !matches!(
+            payment_intent.status,
+            enums::IntentStatus::RequiresCapture
+                | enums::IntentStatus::PartiallyAuthorizedAndRequiresCapture
+        ) {
+            return db
+                .as_scheduler()
+                .finish_process_with_business_status(
+                    process,
+                    business_status::RESOURCE_STATUS_MISMATCH,
+                )
+                .await
+                .map_err(Into::into);
+        }
+
+        let profile_id = payment_intent
+            .profile_id
+            .clone()
+            .ok_or(sch_errors::ProcessTrackerError::MissingRequiredField)?;
+
+        let action = authorization_expiry::get_authorization_expiry_action(
+            state,
+            &tracking_data.merchant_id,
+            &profile_id,
+        )
+        .await;
+
+        // The action was disabled for the profile after the task was scheduled
+        if !action.is_enabled() {
+            return db
+                .as_scheduler()
+                .finish_process_with_business_status(process, business_status::REVOKED)
+                .await
+                .map_err(Into::into);
+        }
+
+        let payment_attempt = db
+            .find_payment_attempt_by_payment_id_processor_merchant_id_attempt_id(
+                &payment_intent.payment_id,
+                &tracking_data.merchant_id,
+                payment_intent.active_attempt.get_id().as_str(),
+                merchant_account.storage_scheme,
+                &key_store,
+            )
+            .await?;
+
+        // The authorization was extended after the task was scheduled, so the action is deferred
+        // until shortly before the stored expiry
+        let is_action_due = |expires_at| {
+            authorization_expiry::is_authorization_expiry_action_due(
+                &state.conf.authorization_expiry,
+                expires_at,
+                common_utils::date_time::now(),
+            )
+        };
+        if let Some(expires_at) = payment_attempt
+            .expected_authorization_expiry
+            .filter(|expires_at| !is_action_due(*expires_at))
+        {
+            let schedule_time = authorization_expiry::get_authorization_expiry_schedule_time(
+                &state.conf.authorization_expiry,
+                expires_at,
+            );
+            return db
+                .as_scheduler()
+                .reset_process(process, schedule_time)
+                .await
+                .map_err(Into::into);
+        }
+
+        let business_profile = db
+            .find_business_profile_by_profile_id(&key_store, &profile_id)
+            .await?;
+
+        let platform = domain::Platform::new(
+            merchant_account.clone(),
+            key_store.clone(),
+            merchant_account.clone(),
+            key_store.clone(),
+            None,
+        );
+
+        let dimensions = configs::dimension_state::Dimensions::new()
+            .with_processor_merchant_id(platform.get_processor().get_processor_merchant_id())
+            .with_provider_merchant_id(platform.get_provider().get_provider_merchant_id());
+
+        // Extending the authorization is preferred over the configured action, as long as the
+        // connector pushes the expiry further
+        if payment_attempt
+            .request_extended_authorization
+            .is_some_and(|request_extended_authorization| request_extended_authorization.is_true())
+        {
+            let extension_result = Box::pin(payment_flows::payments_operation_core::<
+                api::ExtendAuthorization,
+                _,
+                _,
+                _,
+                payment_flows::PaymentData<api::ExtendAuthorization>,
+            >(
+                state,
+                state.get_req_state(),
+                &platform,
+                None,
+                operations::PaymentExtendAuthorization,
+                api::PaymentsExtendAuthorizationRequest {
+                    payment_id: tracking_data.payment_id.clone(),
+                },
+                payment_flows::CallConnectorAction::Trigger,
+                None,
+                services::AuthFlow::Merchant,
+                None,
+                hyperswitch_domain_models::payments::HeaderPayload::default(),
+                &dimensions,
+                None,
+            ))
+            .await;
+
+            match extension_result {
+                Ok((payment_data, _, _, _))
+                    if payment_data.payment_attempt.capture_before
+                        > payment_attempt.capture_before =>
+                {
+                    let expires_at = authorization_expiry::get_expected_authorization_expiry(
+                        &state.conf.authorization_expiry,
+                        &payment_data.payment_attempt,
+                        business_profile.merchant_category_code.as_ref(),
+                        common_utils::date_time::now(),
+                    );
+                    authorization_expiry::update_expected_authorization_expiry(
+                        db,
+                        &key_store,
+                        merchant_account.storage_scheme,
+                        payment_data.payment_attempt,
+                        expires_at,
+                    )
+                    .await?;
+                    logger::info!(
+                        payment_id = ?tracking_data.payment_id,
+                        ?expires_at,
+                        "Extended the authorization of the payment"
+                    );
+
+                    let schedule_time =
+                        authorization_expiry::get_authorization_expiry_schedule_time(
+                            &state.conf.authorization_expiry,
+                            expires_at,
+                        );
+                    return db
+                        .as_scheduler()
+                        .reset_process(process, schedule_time)
+                        .await
+                        .map_err(Into::into);
+                }
+                Ok(_) => logger::info!(
+                    payment_id = ?tracking_data.payment_id,
+                    "The connector did not extend the authorization of the payment"
+                ),
+                Err(error) => logger::warn!(
+                    ?error,
+                    payment_id = ?tracking_data.payment_id,
+                    "Failed to extend the authorization of the payment"
+                ),
+            }
+        }
+
+        // The outgoing webhooks for the outcome of a capture or void are triggered by the payments
+        // core
+        match action {
+            AuthorizationExpiryAction::Notify => {
+                authorization_expiry::trigger_authorization_expiring_webhook(
+                    state,
+                    &platform,
+                    business_profile,
+                    payment_attempt,
+                )
+                .await?;
+            }
+            AuthorizationExpiryAction::Capture => {
+                let capture_request = api::PaymentsCaptureRequest {
+                    payment_id: tracking_data.payment_id.clone(),
+                    merchant_id: Some(tracking_data.merchant_id.clone()),
+                    ..Default::default()
+                };
+
+                Box::pin(payment_flows::payments_operation_core::<
+                    api::Capture,
+                    _,
+                    _,
+                    _,
+                    payment_flows::PaymentData<api::Capture>,
+                >(
+                    state,
+                    state.get_req_state(),
+                    &platform,
+                    None,
+                    operations::PaymentCapture,
+                    capture_request,
+                    payment_flows::CallConnectorAction::Trigger,
+                    None,
+                    services::AuthFlow::Merchant,
+                    None,
+                    hyperswitch_domain_models::payments::HeaderPayload::default(),
+                    &dimensions,
+                    None,
+                ))
+                .await?;
+            }
+            AuthorizationExpiryAction::Void => {
+                let cancel_request = api::PaymentsCancelRequest {
+                    payment_id: tracking_data.payment_id.clone(),
+                    cancellation_reason: Some("Authorization about to expire".to_string()),
+                    ..Default::default()
+                };
+
+                Box::pin(payment_flows::payments_operation_core::<
+                    api::Void,
+                    _,
+                    _,
+                    _,
+                    payment_flows::PaymentData<api::Void>,
+                >(
+                    state,
+                    state.get_req_state(),
+                    &platform,
+                    None,
+                    operations::PaymentCancel,
+                    cancel_request,
+                    payment_flows::CallConnectorAction::Trigger,
+                    None,
+                    services::AuthFlow::Merchant,
+                    None,
+                    hyperswitch_domain_models::payments::HeaderPayload::default(),
+                    &dimensions,
+                    None,
+                ))
+                .await?;
+            }
+            AuthorizationExpiryAction::None => {}
+        }
+
+        logger::info!(
+            payment_id = ?tracking_data.payment_id,
+            ?action,
+            "Acted on the payment before its authorization expires"
+        );
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -0,0 +1,121 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use router_env::{instrument, tracing};
+use scheduler::{
+    consumer::{self, types::process_data},
+    utils as pt_utils,
+    workflows::ProcessTrackerWorkflow,
+};
+
+use crate::{
+    core::{
+        errors,
+        webhooks::bulk_redelivery::{self, EventsBulkRedeliveryTrackingData},
+    },
+    logger::{error, info},
+    routes::SessionState,
+    types::storage::{self, enums},
+};
+
+pub struct OutgoingWebhookBulkRedeliveryWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookBulkRedeliveryWorkflow {
+    #[instrument(skip_all)]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let batch_started_at = common_utils::date_time::now();
+
+        let mut tracking_data: EventsBulkRedeliveryTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("EventsBulkRedeliveryTrackingData")?;
+
+        match bulk_redelivery::redeliver_next_batch(state, &mut tracking_data).await {
+            Ok(is_complete) => {
+                let (status, business_status) = if is_complete {
+                    info!(
+                        redelivery_id = %tracking_data.redelivery_id,
+                        "Completed bulk redelivery of events"
+                    );
+                    (
+                        enums::ProcessTrackerStatus::Finish,
+                        Some(String::from(business_status::COMPLETED_BY_PT)),
+                    )
+                } else {
+                    (enums::ProcessTrackerStatus::New, None)
+                };
+
+                // The next batch is sent one interval after this one started, which limits the
+                // send rate to the configured number of events per interval
+                let schedule_time = batch_started_at
+                    .saturating_add(bulk_redelivery::BULK_REDELIVERY_INTERVAL)
+                    .max(common_utils::date_time::now());
+                let tracking_data = serde_json::to_value(&tracking_data)
+                    .map_err(|_| errors::ProcessTrackerError::SerializationFailed)?;
+
+                db.as_scheduler()
+                    .update_process(
+                        process,
+                        storage::ProcessTrackerUpdate::Update {
+                            name: None,
+                            retry_count: Some(0),
+                            schedule_time: Some(schedule_time),
+                            tracking_data: Some(tracking_data),
+                            business_status,
+                            status: Some(status),
+                            updated_at: Some(common_utils::date_time::now()),
+                        },
+                    )
+                    .await?;
+            }
+            Err(error) => {
+                let retry_count = process.retry_count;
+                error!(
+                    redelivery_id = %tracking_data.redelivery_id,
+                    ?error,
+                    retry_count,
+                    "Failed to redeliver batch of events"
+                );
+
+                let mapping = process_data::RetryMapping::default();
+                let time_delta = if retry_count == 0 {
+                    Some(mapping.start_after)
+                } else {
+                    pt_utils::get_delay(retry_count + 1, &mapping.frequencies)
+                };
+
+                match pt_utils::get_time_from_delta(time_delta) {
+                    Some(schedule_time) => {
+                        db.as_scheduler()
+                            .retry_process(process, schedule_time)
+                            .await?;
+                    }
+                    None => {
+                        db.as_scheduler()
+                            .finish_process_with_business_status(
+                                process,
+                                business_status::RETRIES_EXCEEDED,
+                            )
+                            .await?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -7,7 +7,7 @@ use api_models::{
 };
 use common_utils::{
     consts::DEFAULT_LOCALE,
-    ext_traits::{StringExt, ValueExt},
+    ext_traits::{AsyncExt, StringExt, ValueExt},
     id_type,
 };
 use diesel_models::process_tracker::business_status;
@@ -86,15 +86,6 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
             )
             .await?;
 
-        let event_id = webhooks_core::utils::generate_event_id();
-        let idempotent_event_id = webhooks_core::utils::get_idempotent_event_id(
-            &tracking_data.primary_object_id,
-            tracking_data.event_type,
-            delivery_attempt,
-        )
-        .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
-        .attach_printable("Failed to generate idempotent event ID")?;
-
         // Look up the initial event by event_id alone (event_id is globally unique).
         // This works regardless of which merchant owns the event and also handles
         // old tracking data created before `initial_attempt_id` was tracked.
@@ -108,6 +99,16 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
             .find_event_by_event_id(&initial_attempt_id, &webhook_key_store)
             .await?;
 
+        let event_id = webhooks_core::utils::generate_event_id();
+        let idempotent_event_id = webhooks_core::utils::get_idempotent_event_id(
+            &tracking_data.primary_object_id,
+            tracking_data.event_type,
+            delivery_attempt,
+            initial_event.webhook_endpoint_id.as_ref(),
+        )
+        .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+        .attach_printable("Failed to generate idempotent event ID")?;
+
         let now = common_utils::date_time::now();
         let new_event = domain::Event {
             event_id,
@@ -134,6 +135,7 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
                 .initiator_merchant_id
                 .or(Some(webhook_key_store.merchant_id.clone())),
             recipient: initial_event.recipient,
+            webhook_endpoint_id: initial_event.webhook_endpoint_id,
         };
 
         let event = db
@@ -245,12 +247,41 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
                                 platform.get_processor().get_account()
                             };
 
+                        let webhook_endpoint =
+                            event
+                                .webhook_endpoint_id
+                                .as_ref()
+                                .and_then(|webhook_endpoint_id| {
+                                    webhooks_core::utils::find_webhook_endpoint(
+                                        &business_profile,
+                                        webhook_endpoint_id,
+                                    )
+                                });
+                        let webhook_endpoint_secrets = webhook_endpoint
+                            .async_map(|webhook_endpoint| {
+                                webhooks_core::utils::decrypt_webhook_endpoint_secrets(
+                                    state,
+                                    &webhook_key_store,
+                                    webhook_endpoint,
+                                )
+                            })
+                            .await
+                            .transpose()
+                            .map_err(|error| {
+                                logger::error!(
+                                    ?error,
+                                    "Failed to decrypt webhook endpoint secrets"
+                                );
+                                errors::ProcessTrackerError::EApiErrorResponse
+                            })?;
                         let request_content = outgoing_webhook
                             .map(|outgoing_webhook_data| {
                                 webhooks_core::get_outgoing_webhook_request(
                                     webhook_recipient_account,
                                     outgoing_webhook_data,
                                     &business_profile,
+                                    webhook_endpoint,
+                                    webhook_endpoint_secrets.as_ref(),
                                 )
                             })
                             .transpose()
@@ -509,7 +540,8 @@ async fn get_outgoing_webhook_content_and_event_type(
                 | ApplicationResponse::GenericLinkForm(_)
                 | ApplicationResponse::PaymentLinkForm(_)
                 | ApplicationResponse::FileData(_)
-                | ApplicationResponse::IncomingWebhookEvent { .. } => {
+                | ApplicationResponse::IncomingWebhookEvent { .. }
+                | ApplicationResponse::IdempotentReplay(_) => {
                     Err(errors::ProcessTrackerError::ResourceFetchingFailed {
                         resource_name: tracking_data.primary_object_id.clone(),
                     })
@@ -518,6 +550,16 @@ async fn get_outgoing_webhook_content_and_event_type(
             let event_type: Option<EventType> = payments_response.status.into();
             logger::debug!(current_resource_status=%payments_response.status);
 
+            // The notification of an expiring authorization remains relevant for as long as the
+            // authorization is held
+            let event_type = match (tracking_data.event_type, event_type) {
+                (
+                    EventType::PaymentAuthorizationExpiring,
+                    Some(EventType::PaymentAuthorized | EventType::PaymentPartiallyAuthorized),
+                ) => Some(EventType::PaymentAuthorizationExpiring),
+                (_, event_type) => event_type,
+            };
+
             Ok((
                 OutgoingWebhookContent::PaymentDetails(Box::new(payments_response)),
                 event_type,
@@ -570,7 +612,8 @@ async fn get_outgoing_webhook_content_and_event_type(
                     | ApplicationResponse::GenericLinkForm(_)
                     | ApplicationResponse::PaymentLinkForm(_)
                     | ApplicationResponse::FileData(_)
-                    | ApplicationResponse::IncomingWebhookEvent { .. } => {
+                    | ApplicationResponse::IncomingWebhookEvent { .. }
+                    | ApplicationResponse::IdempotentReplay(_) => {
                         Err(errors::ProcessTrackerError::ResourceFetchingFailed {
                             resource_name: tracking_data.primary_object_id.clone(),
                         })
@@ -602,7 +645,8 @@ async fn get_outgoing_webhook_content_and_event_type(
                 | ApplicationResponse::GenericLinkForm(_)
                 | ApplicationResponse::PaymentLinkForm(_)
                 | ApplicationResponse::FileData(_)
-                | ApplicationResponse::IncomingWebhookEvent { .. } => {
+                | ApplicationResponse::IncomingWebhookEvent { .. }
+                | ApplicationResponse::IdempotentReplay(_) => {
                     Err(errors::ProcessTrackerError::ResourceFetchingFailed {
                         resource_name: tracking_data.primary_object_id.clone(),
                     })
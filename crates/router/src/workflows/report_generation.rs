@@ -0,0 +1,290 @@
+use api_models::analytics::GenerateReportRequest;
+use common_utils::{crypto::SignMessage, ext_traits::ValueExt, request::RequestContent};
+use diesel_models::process_tracker::business_status;
+use error_stack::{report, ResultExt};
+use hyperswitch_masking::Maskable;
+use router_env::{instrument, tracing};
+use scheduler::{
+    consumer::{self, types::process_data},
+    utils as pt_utils,
+    workflows::ProcessTrackerWorkflow,
+};
+
+use crate::{
+    core::{
+        errors::{self, RouterResult},
+        reports::{self, ReportGeneratedNotification, ReportGenerationTrackingData},
+    },
+    headers,
+    logger::{error, info, warn},
+    routes::SessionState,
+    services,
+    types::storage,
+    utils,
+};
+
+pub struct ReportGenerationWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for ReportGenerationWorkflow {
+    /// Generates the report and stores it, retrying on failure. Notifications are sent once the
+    /// report is stored and are not retried, as the report can be downloaded regardless.
+    #[instrument(skip_all)]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+
+        let tracking_data: ReportGenerationTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("ReportGenerationTrackingData")?;
+        let report_id = &tracking_data.report_id;
+
+        match store_report(state, &tracking_data).await {
+            Ok(download_url) => {
+                info!(%report_id, report_type = %tracking_data.kind, "Generated report");
+
+                #[cfg(feature = "email")]
+                send_report_emails(state, &tracking_data, &download_url).await;
+
+                if let Err(error) = notify_return_url(state, &tracking_data, &download_url).await {
+                    warn!(%report_id, ?error, "Failed to notify report return URL");
+                }
+
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                    .await?;
+            }
+            Err(error) => {
+                let retry_count = process.retry_count;
+                error!(%report_id, ?error, retry_count, "Failed to generate report");
+
+                let mapping = process_data::RetryMapping::default();
+                let time_delta = if retry_count == 0 {
+                    Some(mapping.start_after)
+                } else {
+                    pt_utils::get_delay(retry_count + 1, &mapping.frequencies)
+                };
+
+                match pt_utils::get_time_from_delta(time_delta) {
+                    Some(schedule_time) => {
+                        db.as_scheduler()
+                            .retry_process(process, schedule_time)
+                            .await?;
+                    }
+                    None => {
+                        db.as_scheduler()
+                            .finish_process_with_business_status(
+                                process,
+                                business_status::RETRIES_EXCEEDED,
+                            )
+                            .await?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
+
+/// Number of report pages buffered between the report generation and its upload, the report is
+/// generated only as fast as it can be uploaded.
+const REPORT_PAGE_BUFFER_SIZE: usize = 2;
+
+/// Generates the report from the analytics provider and uploads it to the file storage a page at
+/// a time, returning the URL it can be downloaded from.
+async fn store_report(
+    state: &SessionState,
+    tracking_data: &ReportGenerationTrackingData,
+) -> RouterResult<String> {
+    let auth = &tracking_data.request.auth;
+    let file_key = reports::get_report_file_key(auth, &tracking_data.report_id)?;
+    let (pages_sender, pages_receiver) = tokio::sync::mpsc::channel(REPORT_PAGE_BUFFER_SIZE);
+
+    let generate_report = async {
+        state
+            .pool
+            .generate_report(
+                tracking_data.kind,
+                &tracking_data.request.request,
+                auth,
+                pages_sender,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to generate report")
+    };
+    let upload_report = async {
+        state
+            .file_storage_client
+            .upload_file_stream(&file_key, pages_receiver)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to upload report")
+    };
+
+    // The upload is only completed once the report has been generated in full, it is cancelled
+    // without being stored if the generation fails
+    tokio::try_join!(generate_report, upload_report)?;
+
+    Ok(reports::get_report_download_url(
+        &state.base_url,
+        auth,
+        &tracking_data.report_id,
+    ))
+}
+
+#[cfg(feature = "email")]
+async fn send_report_emails(
+    state: &SessionState,
+    tracking_data: &ReportGenerationTrackingData,
+    download_url: &str,
+) {
+    use crate::{
+        consts, services::email::types::ReportGenerated, types::domain::UserEmail,
+        utils::user as user_utils,
+    };
+
+    let recipients = std::iter::once(&tracking_data.request.email).chain(
+        tracking_data
+            .request
+            .request
+            .emails
+            .iter()
+            .flatten()
+            .filter(|email| **email != tracking_data.request.email),
+    );
+
+    for recipient in recipients {
+        let recipient_email = match UserEmail::from_pii_email(recipient.clone()) {
+            Ok(recipient_email) => recipient_email,
+            Err(error) => {
+                warn!(?error, "Invalid report recipient email");
+                continue;
+            }
+        };
+
+        let email_contents = ReportGenerated {
+            recipient_email,
+            subject: consts::EMAIL_SUBJECT_REPORT_GENERATED,
+            report_id: tracking_data.report_id.clone(),
+            report_type: tracking_data.kind.to_string(),
+            download_url: download_url.to_owned(),
+        };
+
+        if let Err(error) = state
+            .email_client
+            .clone()
+            .compose_and_send_email(
+                user_utils::get_base_url(state),
+                Box::new(email_contents),
+                state.conf.proxy.https_url.as_ref(),
+            )
+            .await
+        {
+            warn!(report_id = %tracking_data.report_id, ?error, "Failed to send report email");
+        }
+    }
+}
+
+/// The payment response hash key of the merchant account or profile the report was requested by.
+/// It is not stored in the tracking data of the task, so it is looked up when it is needed.
+async fn get_payment_response_hash_key(
+    state: &SessionState,
+    request: &GenerateReportRequest,
+) -> RouterResult<Option<String>> {
+    let Some(merchant_id) = &request.merchant_id else {
+        return Ok(None);
+    };
+
+    let key_store = state
+        .store
+        .get_merchant_key_store_by_merchant_id(
+            merchant_id,
+            &state.store.get_master_key().to_vec().into(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch merchant key store")?;
+
+    utils::get_payment_response_hash_key(state.store.as_ref(), &key_store, &request.auth)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch payment response hash key")
+}
+
+/// Posts the report details to the `return_url` of the request, signing the payload with the
+/// payment response hash key when one is configured.
+async fn notify_return_url(
+    state: &SessionState,
+    tracking_data: &ReportGenerationTrackingData,
+    download_url: &str,
+) -> RouterResult<()> {
+    let Some(return_url) = &tracking_data.request.request.return_url else {
+        return Ok(());
+    };
+
+    let payload = serde_json::to_string(&ReportGeneratedNotification {
+        report_id: tracking_data.report_id.clone(),
+        report_type: tracking_data.kind,
+        download_url: download_url.to_owned(),
+    })
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to serialize report notification")?;
+
+    let mut request_headers: Vec<(String, Maskable<String>)> = vec![(
+        headers::CONTENT_TYPE.to_string(),
+        mime::APPLICATION_JSON.essence_str().to_string().into(),
+    )];
+    if let Some(hash_key) = get_payment_response_hash_key(state, &tracking_data.request).await? {
+        let signature = common_utils::crypto::HmacSha512
+            .sign_message(hash_key.as_bytes(), payload.as_bytes())
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to sign report notification")?;
+        request_headers.push((
+            headers::X_WEBHOOK_SIGNATURE.to_string(),
+            hex::encode(signature).into(),
+        ));
+    }
+
+    let request = services::RequestBuilder::new()
+        .method(services::Method::Post)
+        .url(return_url.get_string_repr())
+        .attach_default_headers()
+        .headers(request_headers)
+        .set_body(RequestContent::RawBytes(payload.into_bytes()))
+        .build();
+
+    let response = state
+        .api_client
+        .send_request(state, request, None, false)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to send report notification")?;
+
+    if !response.status().is_success() {
+        Err(report!(errors::ApiErrorResponse::InternalServerError)).attach_printable_lazy(
+            || {
+                format!(
+                    "Report return URL responded with status {}",
+                    response.status()
+                )
+            },
+        )?;
+    }
+
+    Ok(())
+}
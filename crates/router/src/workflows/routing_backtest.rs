@@ -0,0 +1,86 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use router_env::{instrument, tracing};
+use scheduler::{
+    consumer::{self, types::process_data},
+    utils as pt_utils,
+    workflows::ProcessTrackerWorkflow,
+};
+
+use crate::{
+    core::{
+        errors,
+        routing::backtest::{self, RoutingBacktestTrackingData},
+    },
+    logger::{error, info},
+    routes::SessionState,
+    types::storage,
+};
+
+pub struct RoutingBacktestWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for RoutingBacktestWorkflow {
+    #[instrument(skip_all)]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+
+        let tracking_data: RoutingBacktestTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("RoutingBacktestTrackingData")?;
+        let backtest_id = &tracking_data.backtest_id;
+
+        match backtest::run_routing_backtest(state, &tracking_data).await {
+            Ok(()) => {
+                info!(%backtest_id, "Completed routing backtest");
+
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                    .await?;
+            }
+            Err(error) => {
+                let retry_count = process.retry_count;
+                error!(%backtest_id, ?error, retry_count, "Failed to run routing backtest");
+
+                let mapping = process_data::RetryMapping::default();
+                let time_delta = if retry_count == 0 {
+                    Some(mapping.start_after)
+                } else {
+                    pt_utils::get_delay(retry_count + 1, &mapping.frequencies)
+                };
+
+                match pt_utils::get_time_from_delta(time_delta) {
+                    Some(schedule_time) => {
+                        db.as_scheduler()
+                            .retry_process(process, schedule_time)
+                            .await?;
+                    }
+                    None => {
+                        db.as_scheduler()
+                            .finish_process_with_business_status(
+                                process,
+                                business_status::RETRIES_EXCEEDED,
+                            )
+                            .await?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
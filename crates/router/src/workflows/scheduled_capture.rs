@@ -0,0 +1,180 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors,
+};
+
+use crate::{
+    core::{
+        configs,
+        payments::{self as payment_flows, operations},
+    },
+    db::StorageInterface,
+    errors,
+    routes::SessionState,
+    services,
+    types::{
+        api, domain,
+        storage::{self, enums},
+    },
+};
+
+/// The interval at which the capture is retried while the authorization of the payment is still
+/// in progress, for instance when the customer is yet to complete 3DS.
+const AUTHORIZATION_PENDING_RETRY_INTERVAL: time::Duration = time::Duration::hours(1);
+
+/// The number of times the capture is retried while the authorization is still in progress
+/// before the schedule is given up.
+const AUTHORIZATION_PENDING_MAX_RETRIES: i32 = 24;
+
+pub struct ScheduledCaptureWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for ScheduledCaptureWorkflow {
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        todo!()
+    }
+
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: api::PaymentsScheduledCaptureTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("PaymentsScheduledCaptureTrackingData")?;
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_processor_merchant_id(
+                &tracking_data.payment_id,
+                &tracking_data.merchant_id,
+                &key_store,
+                merchant_account.storage_scheme,
+            )
+            .await?;
+
+        match payment_intent.status {
+            enums::IntentStatus::RequiresCapture
+            | enums::IntentStatus::PartiallyAuthorizedAndRequiresCapture => {}
+            // The payment is yet to be authorized, check again later
+            enums::IntentStatus::Processing
+            | enums::IntentStatus::RequiresCustomerAction
+            | enums::IntentStatus::RequiresMerchantAction => {
+                return if process.retry_count >= AUTHORIZATION_PENDING_MAX_RETRIES {
+                    logger::info!(
+                        payment_id = ?tracking_data.payment_id,
+                        "Payment was not authorized in time for its scheduled capture"
+                    );
+                    db.as_scheduler()
+                        .finish_process_with_business_status(
+                            process,
+                            business_status::RETRIES_EXCEEDED,
+                        )
+                        .await
+                        .map_err(Into::into)
+                } else {
+                    let schedule_time =
+                        common_utils::date_time::now() + AUTHORIZATION_PENDING_RETRY_INTERVAL;
+                    db.as_scheduler()
+                        .retry_process(process, schedule_time)
+                        .await
+                        .map_err(Into::into)
+                };
+            }
+            // The payment was captured, cancelled or failed in the meantime
+            _ => {
+                return db
+                    .as_scheduler()
+                    .finish_process_with_business_status(
+                        process,
+                        business_status::RESOURCE_STATUS_MISMATCH,
+                    )
+                    .await
+                    .map_err(Into::into);
+            }
+        }
+
+        let platform = domain::Platform::new(
+            merchant_account.clone(),
+            key_store.clone(),
+            merchant_account.clone(),
+            key_store.clone(),
+            None,
+        );
+
+        let dimensions = configs::dimension_state::Dimensions::new()
+            .with_processor_merchant_id(platform.get_processor().get_processor_merchant_id())
+            .with_provider_merchant_id(platform.get_provider().get_provider_merchant_id());
+
+        let capture_request = api::PaymentsCaptureRequest {
+            payment_id: tracking_data.payment_id.clone(),
+            merchant_id: Some(tracking_data.merchant_id.clone()),
+            ..Default::default()
+        };
+
+        // The outgoing webhook for the outcome of the capture is triggered by the payments core
+        let (payment_data, _, _, _) = Box::pin(payment_flows::payments_operation_core::<
+            api::Capture,
+            _,
+            _,
+            _,
+            payment_flows::PaymentData<api::Capture>,
+        >(
+            state,
+            state.get_req_state(),
+            &platform,
+            None,
+            operations::PaymentCapture,
+            capture_request,
+            payment_flows::CallConnectorAction::Trigger,
+            None,
+            services::AuthFlow::Merchant,
+            None,
+            hyperswitch_domain_models::payments::HeaderPayload::default(),
+            &dimensions,
+            None,
+        ))
+        .await?;
+
+        logger::info!(
+            payment_id = ?tracking_data.payment_id,
+            attempt_status = ?payment_data.payment_attempt.status,
+            "Captured payment on schedule"
+        );
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -0,0 +1,158 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors,
+};
+
+use crate::{
+    core::{
+        refunds_v2,
+        split_payments::{self, SplitPaymentReversal},
+    },
+    db::StorageInterface,
+    errors,
+    routes::SessionState,
+    types::{
+        domain,
+        storage::{self, enums},
+    },
+};
+
+/// The number of times the pending refunds are synced before the legs they reverse are
+/// considered as not reversed.
+const SPLIT_PAYMENT_REVERSAL_MAX_RETRIES: i32 = 96;
+
+pub struct SplitPaymentReversalWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for SplitPaymentReversalWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: api_models::payments::SplitPaymentReversalTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("SplitPaymentReversalTrackingData")?;
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        let platform = domain::Platform::new(
+            merchant_account.clone(),
+            key_store.clone(),
+            merchant_account.clone(),
+            key_store.clone(),
+            None,
+        );
+        let storage_scheme = merchant_account.storage_scheme;
+
+        let payment_intent = db
+            .find_payment_intent_by_id(&tracking_data.payment_id, &key_store, storage_scheme)
+            .await?;
+
+        // The status of the payment was changed by another flow in the meantime
+        if payment_intent.status != enums::IntentStatus::Processing {
+            return db
+                .as_scheduler()
+                .finish_process_with_business_status(
+                    process,
+                    business_status::RESOURCE_STATUS_MISMATCH,
+                )
+                .await
+                .map_err(Into::into);
+        }
+
+        let mut reversal = SplitPaymentReversal {
+            unreversed_amount: tracking_data.unreversed_amount,
+            ..Default::default()
+        };
+        for refund_id in &tracking_data.pending_refund_ids {
+            let refund = db.find_refund_by_id(refund_id, storage_scheme).await?;
+
+            let refund = match refund.refund_status {
+                enums::RefundStatus::Pending | enums::RefundStatus::ManualReview => {
+                    let payment_attempt = db
+                        .find_payment_attempt_by_id(&key_store, &refund.attempt_id, storage_scheme)
+                        .await?;
+
+                    let (refund, _) = Box::pin(refunds_v2::sync_refund_with_gateway(
+                        state,
+                        platform.get_processor(),
+                        &payment_attempt,
+                        &payment_intent,
+                        &refund,
+                        None,
+                    ))
+                    .await?;
+                    refund
+                }
+                enums::RefundStatus::Success
+                | enums::RefundStatus::Failure
+                | enums::RefundStatus::TransactionFailure => refund,
+            };
+
+            reversal.add_refund(refund.id, refund.refund_status.into(), refund.refund_amount);
+        }
+
+        if !reversal.pending_refund_ids.is_empty() {
+            if process.retry_count < SPLIT_PAYMENT_REVERSAL_MAX_RETRIES {
+                let schedule_time = common_utils::date_time::now()
+                    + split_payments::SPLIT_PAYMENT_REVERSAL_SYNC_INTERVAL;
+                return db
+                    .as_scheduler()
+                    .retry_process(process, schedule_time)
+                    .await
+                    .map_err(Into::into);
+            }
+
+            logger::info!(
+                payment_id = ?tracking_data.payment_id,
+                "Refunds reversing the split payment did not complete in time"
+            );
+            reversal.mark_pending_as_unreversed();
+        }
+
+        let (intent_status, amount_captured) = reversal.get_intent_status();
+        split_payments::update_split_payment_status(
+            state,
+            platform.get_processor(),
+            payment_intent,
+            intent_status,
+            amount_captured,
+        )
+        .await?;
+
+        logger::info!(
+            payment_id = ?tracking_data.payment_id,
+            ?intent_status,
+            "Completed the reversal of the split payment"
+        );
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -50,7 +50,7 @@ pub enum Tag {
 }
 
 /// API Flow
-#[derive(Debug, Display, Clone, PartialEq, Eq)]
+#[derive(Debug, Display, Clone, PartialEq, Eq, EnumString)]
 pub enum Flow {
     /// Health check
     HealthCheck,
@@ -110,6 +110,12 @@ pub enum Flow {
     ConfigKeyUpdate,
     /// ConfigKey Delete flow.
     ConfigKeyDelete,
+    /// Rate limit config upsert flow.
+    RateLimitConfigUpsert,
+    /// Rate limit config retrieve flow.
+    RateLimitConfigRetrieve,
+    /// Rate limit config delete flow.
+    RateLimitConfigDelete,
     /// Customers create flow.
     CustomersCreate,
     /// Customers retrieve flow.
@@ -275,8 +281,14 @@ pub enum Flow {
     RoutingLinkConfig,
     /// Routing link config
     RoutingUnlinkConfig,
+    /// Routing canary ramp, promote or rollback
+    RoutingUpdateCanary,
     /// Routing retrieve config
     RoutingRetrieveConfig,
+    /// Routing backtest create
+    RoutingBacktestCreate,
+    /// Routing backtest retrieve
+    RoutingBacktestRetrieve,
     /// Decision engine merchant SSO redirect
     DecisionEngineSsoRedirect,
     /// Routing retrieve active config
@@ -423,6 +435,12 @@ pub enum Flow {
     ApplePayCertificatesMigration,
     /// Gsm Rule Delete flow
     GsmRuleDelete,
+    /// Gsm Rules bulk import flow
+    GsmRulesImport,
+    /// Gsm Rules export flow
+    GsmRulesExport,
+    /// Gsm unmapped connector errors retrieve flow
+    GsmUnmappedErrorsRetrieve,
     /// Get data from embedded flow
     GetDataFromHyperswitchAiFlow,
     // List all chat interactions
@@ -531,6 +549,8 @@ pub enum Flow {
     PaymentsIncrementalAuthorization,
     /// Extend Authorization flow
     PaymentsExtendAuthorization,
+    /// Dynamic currency conversion quote flow
+    PaymentsDccQuote,
     /// Get action URL for connector onboarding
     GetActionUrl,
     /// Sync connector onboarding status
@@ -633,6 +653,28 @@ pub enum Flow {
     WebhookEventDeliveryAttemptList,
     /// Manually retry the delivery for a webhook event
     WebhookEventDeliveryRetry,
+    /// Redeliver webhook events matching a filter in bulk
+    WebhookEventBulkRedelivery,
+    /// Retrieve the progress of a bulk redelivery of webhook events
+    WebhookEventBulkRedeliveryRetrieve,
+    /// List stored incoming webhooks
+    IncomingWebhookList,
+    /// Process a stored incoming webhook again
+    IncomingWebhookReprocess,
+    /// Create a webhook endpoint for a business profile
+    WebhookEndpointCreate,
+    /// Retrieve a webhook endpoint of a business profile
+    WebhookEndpointRetrieve,
+    /// List the webhook endpoints of a business profile
+    WebhookEndpointList,
+    /// Update a webhook endpoint of a business profile
+    WebhookEndpointUpdate,
+    /// Delete a webhook endpoint of a business profile
+    WebhookEndpointDelete,
+    /// Rotate the secret of a webhook endpoint of a business profile
+    WebhookEndpointSecretRotate,
+    /// Retrieve the delivery health of a webhook endpoint of a business profile
+    WebhookEndpointHealthRetrieve,
     /// Retrieve status of the Poll
     RetrievePollStatus,
     /// Toggles the extended card info feature in profile level
@@ -653,6 +653,7 @@ impl ForeignFrom<domain::ProfileUpdate> for ProfileUpdateInternal {
                     surcharge_connector_details,
                     network_tokenization_credentials,
                     payment_method_blocking,
+                    routing_timezone,
                 } = *update;
 
                 let is_external_vault_enabled = match is_external_vault_enabled {
@@ -726,6 +727,7 @@ impl ForeignFrom<domain::ProfileUpdate> for ProfileUpdateInternal {
                         .map(Encryption::from),
                     payment_method_blocking,
                     default_fallback_routing: None,
+                    routing_timezone,
                 }
             }
             domain::ProfileUpdate::RoutingAlgorithmUpdate {
@@ -793,6 +795,7 @@ impl ForeignFrom<domain::ProfileUpdate> for ProfileUpdateInternal {
                 network_tokenization_credentials: None,
                 payment_method_blocking: None,
                 default_fallback_routing: None,
+                routing_timezone: None,
             },
             domain::ProfileUpdate::DynamicRoutingAlgorithmUpdate {
                 dynamic_routing_algorithm,
@@ -857,6 +860,7 @@ impl ForeignFrom<domain::ProfileUpdate> for ProfileUpdateInternal {
                 network_tokenization_credentials: None,
                 payment_method_blocking: None,
                 default_fallback_routing: None,
+                routing_timezone: None,
             },
             domain::ProfileUpdate::ExtendedCardInfoUpdate {
                 is_extended_card_info_enabled,
@@ -921,6 +925,7 @@ impl ForeignFrom<domain::ProfileUpdate> for ProfileUpdateInternal {
                 network_tokenization_credentials: None,
                 payment_method_blocking: None,
                 default_fallback_routing: None,
+                routing_timezone: None,
             },
             domain::ProfileUpdate::ConnectorAgnosticMitUpdate {
                 is_connector_agnostic_mit_enabled,
@@ -985,6 +990,7 @@ impl ForeignFrom<domain::ProfileUpdate> for ProfileUpdateInternal {
                 network_tokenization_credentials: None,
                 payment_method_blocking: None,
                 default_fallback_routing: None,
+                routing_timezone: None,
             },
             domain::ProfileUpdate::NetworkTokenizationUpdate {
                 is_network_tokenization_enabled,
@@ -1051,6 +1057,7 @@ impl ForeignFrom<domain::ProfileUpdate> for ProfileUpdateInternal {
                     .map(Encryption::from),
                 payment_method_blocking: None,
                 default_fallback_routing: None,
+                routing_timezone: None,
             },
             domain::ProfileUpdate::CardTestingSecretKeyUpdate {
                 card_testing_secret_key,
@@ -1115,6 +1122,7 @@ impl ForeignFrom<domain::ProfileUpdate> for ProfileUpdateInternal {
                 network_tokenization_credentials: None,
                 payment_method_blocking: None,
                 default_fallback_routing: None,
+                routing_timezone: None,
             },
             domain::ProfileUpdate::AcquirerConfigBucketUpdate {
                 acquirer_config_map,
@@ -1180,6 +1188,7 @@ impl ForeignFrom<domain::ProfileUpdate> for ProfileUpdateInternal {
                 network_tokenization_credentials: None,
                 payment_method_blocking: None,
                 default_fallback_routing: None,
+                routing_timezone: None,
             },
             domain::ProfileUpdate::DefaultRoutingFallbackUpdate {
                 default_fallback_routing,
@@ -1243,6 +1252,70 @@ impl ForeignFrom<domain::ProfileUpdate> for ProfileUpdateInternal {
                 is_l2_l3_enabled: None,
                 payment_method_blocking: None,
                 default_fallback_routing,
+                routing_timezone: None,
+                network_tokenization_credentials: None,
+            },
+            domain::ProfileUpdate::WebhookDetailsUpdate { webhook_details } => Self {
+                profile_name: None,
+                modified_at: now,
+                return_url: None,
+                enable_payment_response_hash: None,
+                payment_response_hash_key: None,
+                redirect_to_merchant_with_http_post: None,
+                webhook_details,
+                metadata: None,
+                routing_algorithm: None,
+                intent_fulfillment_time: None,
+                order_fulfillment_time: None,
+                frm_routing_algorithm: None,
+                payout_routing_algorithm: None,
+                is_recon_enabled: None,
+                applepay_verified_domains: None,
+                payment_link_config: None,
+                session_expiry: None,
+                authentication_connector_details: None,
+                payout_link_config: None,
+                is_extended_card_info_enabled: None,
+                extended_card_info_config: None,
+                is_connector_agnostic_mit_enabled: None,
+                use_billing_as_payment_method_billing: None,
+                collect_shipping_details_from_wallet_connector: None,
+                collect_billing_details_from_wallet_connector: None,
+                outgoing_webhook_custom_http_headers: None,
+                always_collect_billing_details_from_wallet_connector: None,
+                always_collect_shipping_details_from_wallet_connector: None,
+                tax_connector_id: None,
+                is_tax_connector_enabled: None,
+                dynamic_routing_algorithm: None,
+                is_network_tokenization_enabled: None,
+                is_auto_retries_enabled: None,
+                max_auto_retries_enabled: None,
+                always_request_extended_authorization: None,
+                is_click_to_pay_enabled: None,
+                authentication_product_ids: None,
+                card_testing_guard_config: None,
+                card_testing_secret_key: None,
+                is_clear_pan_retries_enabled: None,
+                force_3ds_challenge: None,
+                is_debit_routing_enabled: None,
+                merchant_business_country: None,
+                is_iframe_redirection_enabled: None,
+                is_pre_network_tokenization_enabled: None,
+                three_ds_decision_rule_algorithm: None,
+                acquirer_config_map: None,
+                merchant_category_code: None,
+                merchant_country_code: None,
+                dispute_polling_interval: None,
+                is_manual_retry_enabled: None,
+                always_enable_overcapture: None,
+                is_external_vault_enabled: None,
+                external_vault_connector_details: None,
+                billing_processor_id: None,
+                surcharge_connector_details: None,
+                is_l2_l3_enabled: None,
+                payment_method_blocking: None,
+                default_fallback_routing: None,
+                routing_timezone: None,
                 network_tokenization_credentials: None,
             },
         }
@@ -1335,6 +1408,7 @@ impl Conversion for domain::Profile {
                 .map(|name| name.into()),
             payment_method_blocking: self.payment_method_blocking,
             default_fallback_routing: self.default_fallback_routing,
+            routing_timezone: self.routing_timezone,
         })
     }
 
@@ -1486,6 +1560,7 @@ impl Conversion for domain::Profile {
             network_tokenization_credentials,
             payment_method_blocking: item.payment_method_blocking,
             default_fallback_routing: item.default_fallback_routing,
+            routing_timezone: item.routing_timezone,
         }
         .into())
     }
@@ -1563,6 +1638,7 @@ impl Conversion for domain::Profile {
                 .map(|name| name.into()),
             payment_method_blocking: self.payment_method_blocking,
             default_fallback_routing: self.default_fallback_routing,
+            routing_timezone: self.routing_timezone,
         })
     }
 }
@@ -1650,6 +1726,7 @@ impl ForeignFrom<domain::ProfileUpdate> for ProfileUpdateInternal {
                     tax_connector_id: None,
                     is_tax_connector_enabled: None,
                     is_l2_l3_enabled: None,
+                    routing_timezone: None,
                     is_network_tokenization_enabled,
                     is_auto_retries_enabled: None,
                     max_auto_retries_enabled: None,
@@ -1710,6 +1787,7 @@ impl ForeignFrom<domain::ProfileUpdate> for ProfileUpdateInternal {
                 tax_connector_id: None,
                 is_tax_connector_enabled: None,
                 is_l2_l3_enabled: None,
+                routing_timezone: None,
                 is_network_tokenization_enabled: None,
                 is_auto_retries_enabled: None,
                 max_auto_retries_enabled: None,
@@ -1768,6 +1846,7 @@ impl ForeignFrom<domain::ProfileUpdate> for ProfileUpdateInternal {
                 tax_connector_id: None,
                 is_tax_connector_enabled: None,
                 is_l2_l3_enabled: None,
+                routing_timezone: None,
                 is_network_tokenization_enabled: None,
                 is_auto_retries_enabled: None,
                 max_auto_retries_enabled: None,
@@ -1805,6 +1884,7 @@ impl ForeignFrom<domain::ProfileUpdate> for ProfileUpdateInternal {
                 applepay_verified_domains: None,
                 payment_link_config: None,
                 is_l2_l3_enabled: None,
+                routing_timezone: None,
                 session_expiry: None,
                 authentication_connector_details: None,
                 payout_link_config: None,
@@ -1862,6 +1942,7 @@ impl ForeignFrom<domain::ProfileUpdate> for ProfileUpdateInternal {
                 is_recon_enabled: None,
                 applepay_verified_domains: None,
                 is_l2_l3_enabled: None,
+                routing_timezone: None,
                 payment_link_config: None,
                 session_expiry: None,
                 authentication_connector_details: None,
@@ -1914,6 +1995,7 @@ impl ForeignFrom<domain::ProfileUpdate> for ProfileUpdateInternal {
                 return_url: None,
                 enable_payment_response_hash: None,
                 is_l2_l3_enabled: None,
+                routing_timezone: None,
                 payment_response_hash_key: None,
                 redirect_to_merchant_with_http_post: None,
                 webhook_details: None,
@@ -2005,6 +2087,7 @@ impl ForeignFrom<domain::ProfileUpdate> for ProfileUpdateInternal {
                 is_click_to_pay_enabled: None,
                 authentication_product_ids: None,
                 is_l2_l3_enabled: None,
+                routing_timezone: None,
                 three_ds_decision_manager_config: None,
                 card_testing_guard_config: None,
                 card_testing_secret_key: None,
@@ -2066,6 +2149,7 @@ impl ForeignFrom<domain::ProfileUpdate> for ProfileUpdateInternal {
                 card_testing_guard_config: None,
                 card_testing_secret_key: None,
                 is_l2_l3_enabled: None,
+                routing_timezone: None,
                 is_clear_pan_retries_enabled: None,
                 is_debit_routing_enabled: None,
                 merchant_business_country: None,
@@ -2126,6 +2210,7 @@ impl ForeignFrom<domain::ProfileUpdate> for ProfileUpdateInternal {
                 is_clear_pan_retries_enabled: None,
                 is_debit_routing_enabled: None,
                 is_l2_l3_enabled: None,
+                routing_timezone: None,
                 merchant_business_country: None,
                 revenue_recovery_retry_algorithm_type: None,
                 revenue_recovery_retry_algorithm_data: None,
@@ -2167,6 +2252,7 @@ impl ForeignFrom<domain::ProfileUpdate> for ProfileUpdateInternal {
                 always_collect_shipping_details_from_wallet_connector: None,
                 routing_algorithm_id: None,
                 is_l2_l3_enabled: None,
+                routing_timezone: None,
                 payout_routing_algorithm_id: None,
                 order_fulfillment_time: None,
                 order_fulfillment_time_origin: None,
@@ -2278,6 +2364,7 @@ impl Conversion for domain::Profile {
             split_txns_enabled: Some(self.split_txns_enabled),
             is_manual_retry_enabled: None,
             is_l2_l3_enabled: None,
+            routing_timezone: None,
             always_enable_overcapture: None,
             billing_processor_id: self.billing_processor_id,
             surcharge_connector_details: self.surcharge_connector_details,
@@ -2458,6 +2545,7 @@ impl Conversion for domain::Profile {
             external_vault_connector_details: self.external_vault_connector_details,
             merchant_category_code: self.merchant_category_code,
             is_l2_l3_enabled: None,
+            routing_timezone: None,
             merchant_country_code: self.merchant_country_code,
             split_txns_enabled: Some(self.split_txns_enabled),
             billing_processor_id: self.billing_processor_id,
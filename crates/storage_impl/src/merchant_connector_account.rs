@@ -791,6 +791,10 @@ impl<T: DatabaseStore> MerchantConnectorAccountInterface for RouterStore<T> {
                             )
                             .into(),
                         ),
+                        cache::CacheKind::CGraph(
+                            cache::get_cost_based_mcas_cache_key(&_merchant_id, &_profile_id)
+                                .into(),
+                        ),
                     ],
                     || update,
                 ))
@@ -888,6 +892,9 @@ impl<T: DatabaseStore> MerchantConnectorAccountInterface for RouterStore<T> {
                         )
                         .into(),
                     ),
+                    cache::CacheKind::CGraph(
+                        cache::get_cost_based_mcas_cache_key(&_merchant_id, &_profile_id).into(),
+                    ),
                     cache::CacheKind::PmFiltersCGraph(
                         format!(
                             "pm_filters_cgraph_{}_{}",
@@ -965,6 +972,9 @@ impl<T: DatabaseStore> MerchantConnectorAccountInterface for RouterStore<T> {
                         )
                         .into(),
                     ),
+                    cache::CacheKind::CGraph(
+                        cache::get_cost_based_mcas_cache_key(&_merchant_id, &_profile_id).into(),
+                    ),
                     cache::CacheKind::PmFiltersCGraph(
                         format!(
                             "pm_filters_cgraph_{}_{}",
@@ -1041,6 +1051,9 @@ impl<T: DatabaseStore> MerchantConnectorAccountInterface for RouterStore<T> {
                         )
                         .into(),
                     ),
+                    cache::CacheKind::CGraph(
+                        cache::get_cost_based_mcas_cache_key(&mca.merchant_id, &_profile_id).into(),
+                    ),
                     cache::CacheKind::PmFiltersCGraph(
                         format!(
                             "pm_filters_cgraph_{}_{}",
@@ -1106,6 +1119,9 @@ impl<T: DatabaseStore> MerchantConnectorAccountInterface for RouterStore<T> {
                         )
                         .into(),
                     ),
+                    cache::CacheKind::CGraph(
+                        cache::get_cost_based_mcas_cache_key(&mca.merchant_id, &_profile_id).into(),
+                    ),
                     cache::CacheKind::PmFiltersCGraph(
                         format!(
                             "pm_filters_cgraph_{}_{}",
@@ -1373,6 +1389,7 @@ impl MerchantConnectorAccountInterface for MockDb {
             additional_merchant_data: t.additional_merchant_data.map(|data| data.into()),
             version: t.version,
             connector_webhook_registration_details: t.connector_webhook_registration_details,
+            fee_schedule: t.fee_schedule,
         };
         accounts.push(account.clone());
         account
@@ -240,6 +240,10 @@ impl PaymentAttemptInterface for MockDb {
             created_by: payment_attempt.created_by,
             setup_future_usage_applied: payment_attempt.setup_future_usage_applied,
             routing_approach: payment_attempt.routing_approach,
+            routing_algorithm_id: payment_attempt.routing_algorithm_id,
+            expected_processing_fee: payment_attempt.expected_processing_fee,
+            expected_authorization_expiry: payment_attempt.expected_authorization_expiry,
+            dcc_details: payment_attempt.dcc_details,
             connector_request_reference_id: payment_attempt.connector_request_reference_id,
             debit_routing_savings: None,
             network_transaction_id: payment_attempt.network_transaction_id,
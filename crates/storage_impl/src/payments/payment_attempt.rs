@@ -805,6 +805,10 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                     created_by: payment_attempt.created_by.clone(),
                     setup_future_usage_applied: payment_attempt.setup_future_usage_applied,
                     routing_approach: payment_attempt.routing_approach.clone(),
+                    routing_algorithm_id: payment_attempt.routing_algorithm_id.clone(),
+                    expected_processing_fee: payment_attempt.expected_processing_fee,
+                    expected_authorization_expiry: payment_attempt.expected_authorization_expiry,
+                    dcc_details: payment_attempt.dcc_details.clone(),
                     connector_request_reference_id: payment_attempt
                         .connector_request_reference_id
                         .clone(),
@@ -66,6 +66,20 @@ pub static SURCHARGE_CACHE: LazyLock<Cache> =
 pub static CGRAPH_CACHE: LazyLock<Cache> =
     LazyLock::new(|| Cache::new("CGRAPH_CACHE", CACHE_TTL, CACHE_TTI, Some(MAX_CAPACITY)));
 
+/// Key of the enabled merchant connector accounts of a profile in the [`CGRAPH_CACHE`], which cost
+/// based routing ranks connectors by. It is invalidated along with the constraint graph built from
+/// the same accounts.
+pub fn get_cost_based_mcas_cache_key(
+    merchant_id: &common_utils::id_type::MerchantId,
+    profile_id: &common_utils::id_type::ProfileId,
+) -> String {
+    format!(
+        "cost_based_mcas_{}_{}",
+        merchant_id.get_string_repr(),
+        profile_id.get_string_repr()
+    )
+}
+
 /// PM Filter CGraph Cache
 pub static PM_FILTERS_CGRAPH_CACHE: LazyLock<Cache> = LazyLock::new(|| {
     Cache::new(
@@ -0,0 +1 @@
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'payment_authorization_expiring';
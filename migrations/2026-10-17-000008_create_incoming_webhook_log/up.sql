@@ -0,0 +1,17 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS incoming_webhook_log (
+    id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    connector_name_or_mca_id VARCHAR(64) NOT NULL,
+    connector VARCHAR(64),
+    merchant_connector_id VARCHAR(64),
+    is_relay_webhook BOOLEAN NOT NULL,
+    request BYTEA NOT NULL,
+    source_verified BOOLEAN,
+    status VARCHAR(32) NOT NULL,
+    error_message TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS incoming_webhook_log_merchant_id_created_at_index ON incoming_webhook_log (merchant_id, created_at DESC);
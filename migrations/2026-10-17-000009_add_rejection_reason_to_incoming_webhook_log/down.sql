@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE incoming_webhook_log DROP COLUMN IF EXISTS rejection_reason;
@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TABLE incoming_webhook_log
+ADD COLUMN IF NOT EXISTS rejection_reason VARCHAR(64);